@@ -273,6 +273,17 @@ fn bench_smm_w100(b: &mut test::Bencher) {
 	b.iter(|| method.next(iter.next().unwrap()))
 }
 
+#[bench]
+fn bench_smm_w250(b: &mut test::Bencher) {
+	let candles: Vec<_> = RandomCandles::new().take(1000).map(|c| c.close).collect();
+	let mut iter = candles.iter().cycle().copied();
+	let mut method = SMM::new(250, candles[0]).unwrap();
+	for _ in 0..250 {
+		method.next(iter.next().unwrap());
+	}
+	b.iter(|| method.next(iter.next().unwrap()))
+}
+
 // HMA -----------------------------------------------------------------------------------
 #[bench]
 fn bench_hma_w10(b: &mut test::Bencher) {
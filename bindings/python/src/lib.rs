@@ -0,0 +1,5 @@
+//! Python extension module `yata` built by [`maturin`](https://www.maturin.rs)
+//!
+//! All the bindings are defined in `yata::python`, this crate only links them into a shared library.
+
+pub use yata::python::*;
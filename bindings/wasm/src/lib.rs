@@ -0,0 +1,5 @@
+//! WebAssembly package `yata` built by [`wasm-pack`](https://rustwasm.github.io/wasm-pack)
+//!
+//! All the bindings are defined in `yata::wasm`, this crate only links them into a WebAssembly module.
+
+pub use yata::wasm::*;
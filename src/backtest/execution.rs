@@ -0,0 +1,111 @@
+use crate::core::{Error, PeriodType, ValueType};
+
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
+
+/// Commission paid for every filled order
+#[derive(Debug, Clone, Copy, PartialEq)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub enum Commission {
+	/// Commission in basis points (1/100 of percent) of the order notional value
+	Bps(ValueType),
+
+	/// Fixed commission of every order in units of the equity
+	Absolute(ValueType),
+}
+
+impl Commission {
+	/// Returns commission for the order of the given `notional` value
+	#[must_use]
+	pub fn of(self, notional: ValueType) -> ValueType {
+		match self {
+			Self::Bps(bps) => notional.abs() * bps / 10_000.0,
+			Self::Absolute(value) => value,
+		}
+	}
+
+	pub(super) fn validate(self) -> Result<(), Error> {
+		let (Self::Bps(value) | Self::Absolute(value)) = self;
+
+		if value >= 0.0 && value.is_finite() {
+			Ok(())
+		} else {
+			Err(Error::WrongConfigParameter("commission".into()))
+		}
+	}
+}
+
+impl Default for Commission {
+	fn default() -> Self {
+		Self::Bps(0.0)
+	}
+}
+
+/// Rule of choosing a quantity of every opened position
+#[derive(Debug, Clone, Copy, PartialEq)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub enum Sizing {
+	/// Fixed quantity of every position
+	Fixed(ValueType),
+
+	/// Notional value of every position is the fixed ratio of the current equity
+	///
+	/// F.e. `1.0` means the whole equity, `0.5` means half of the equity and `2.0` means twofold leverage.
+	Percent(ValueType),
+
+	/// Quantity of every position is chosen so that the price move of `multiplier` ATRs (average true ranges)
+	/// changes the equity by the `risk` ratio
+	Atr {
+		/// Ratio of the equity at risk
+		risk: ValueType,
+
+		/// Count of ATRs the risk is measured by
+		multiplier: ValueType,
+
+		/// ATR period
+		period: PeriodType,
+	},
+}
+
+impl Sizing {
+	/// Returns quantity of the position for the given `equity`, fill `price` and current `atr` value
+	#[must_use]
+	pub fn quantity(self, equity: ValueType, price: ValueType, atr: ValueType) -> ValueType {
+		match self {
+			Self::Fixed(quantity) => quantity,
+			Self::Percent(ratio) => equity * ratio / price,
+			Self::Atr {
+				risk, multiplier, ..
+			} => equity * risk / (multiplier * atr),
+		}
+	}
+
+	pub(super) fn validate(self) -> Result<(), Error> {
+		let is_valid = match self {
+			Self::Fixed(value) | Self::Percent(value) => value > 0.0 && value.is_finite(),
+			Self::Atr {
+				risk,
+				multiplier,
+				period,
+			} => {
+				risk > 0.0
+					&& risk.is_finite()
+					&& multiplier > 0.0
+					&& multiplier.is_finite()
+					&& period > 0
+			}
+		};
+
+		if is_valid {
+			Ok(())
+		} else {
+			Err(Error::WrongConfigParameter("sizing".into()))
+		}
+	}
+}
+
+impl Default for Sizing {
+	fn default() -> Self {
+		Self::Percent(1.0)
+	}
+}
@@ -0,0 +1,320 @@
+use super::optimizer::evaluate;
+use super::{Backtest, Objective, Optimized, Optimizer};
+use crate::core::{Error, IndicatorConfig, IndicatorInitializer, ParamValue, ValueType, OHLC};
+use crate::helpers::Rng;
+use alloc::collections::BTreeMap;
+use alloc::string::String;
+use alloc::vec::Vec;
+
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
+
+type Genome = Vec<usize>;
+
+/// Genetic algorithm search over the combinations of the parameters values
+///
+/// Every individual of the population is a vector of the parameters values. On every generation
+/// the `elite` best individuals survive as is and the rest of the population is replaced by the children
+/// of the parents selected by tournaments. Every child takes every value from one of its parents (uniform crossover)
+/// and then every value may be replaced by a random one with the `mutation` probability.
+///
+/// Unlike [`GridSearch`](super::GridSearch), evaluates only a part of all the combinations, so it fits indicators
+/// with many parameters, like [`IchimokuCloud`](crate::indicators::IchimokuCloud) or [`KnowSureThing`](crate::indicators::KnowSureThing).
+/// The search is deterministic for the same `seed`.
+///
+/// Combinations, which are not valid for the indicator, are never selected as parents.
+///
+/// # Examples
+///
+/// ```
+/// use yata::backtest::{Backtest, BacktestReport, GeneticSearch, Optimizer};
+/// use yata::helpers::RandomCandles;
+/// use yata::indicators::IchimokuCloud;
+///
+/// let candles: Vec<_> = RandomCandles::new().take(300).collect();
+///
+/// let genetic = GeneticSearch::new()
+///     .param("l1", 5..=15)
+///     .param("l2", 16..=40)
+///     .param("l3", 41..=80)
+///     .population(16)
+///     .generations(5);
+///
+/// let optimized = genetic
+///     .optimize(&Backtest::default(), &IchimokuCloud::default(), &candles, BacktestReport::total_return)
+///     .unwrap();
+///
+/// assert!(optimized.evaluated <= 16 * 5);
+/// assert!((5..=15).contains(&optimized.config.l1));
+/// ```
+#[derive(Debug, Clone)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct GeneticSearch {
+	params: Vec<(String, Vec<ParamValue>)>,
+	population: usize,
+	generations: usize,
+	elite: usize,
+	mutation: ValueType,
+	seed: u32,
+}
+
+impl GeneticSearch {
+	/// Creates an empty search with the population of `20` individuals, `10` generations,
+	/// `2` elite individuals and mutation probability `0.1`
+	#[must_use]
+	pub const fn new() -> Self {
+		Self {
+			params: Vec::new(),
+			population: 20,
+			generations: 10,
+			elite: 2,
+			mutation: 0.1,
+			seed: 0x9E37_79B9,
+		}
+	}
+
+	/// Adds the parameter `name` with the list of its `values` to the search
+	#[must_use]
+	pub fn param<S, I>(mut self, name: S, values: I) -> Self
+	where
+		S: Into<String>,
+		I: IntoIterator,
+		I::Item: Into<ParamValue>,
+	{
+		self.params
+			.push((name.into(), values.into_iter().map(Into::into).collect()));
+		self
+	}
+
+	/// Sets count of the individuals in every generation
+	#[must_use]
+	pub const fn population(mut self, population: usize) -> Self {
+		self.population = population;
+		self
+	}
+
+	/// Sets count of the generations
+	#[must_use]
+	pub const fn generations(mut self, generations: usize) -> Self {
+		self.generations = generations;
+		self
+	}
+
+	/// Sets count of the best individuals, which are passed into the next generation as is
+	#[must_use]
+	pub const fn elite(mut self, elite: usize) -> Self {
+		self.elite = elite;
+		self
+	}
+
+	/// Sets probability of every value of the child to be replaced by a random one. Range in \[`0.0`; `1.0`\]
+	#[must_use]
+	pub const fn mutation(mut self, mutation: ValueType) -> Self {
+		self.mutation = mutation;
+		self
+	}
+
+	/// Sets seed of the random generator
+	#[must_use]
+	pub const fn seed(mut self, seed: u32) -> Self {
+		self.seed = seed;
+		self
+	}
+
+	fn validate(&self) -> bool {
+		self.population > 0
+			&& self.generations > 0
+			&& self.elite <= self.population
+			&& (0.0..=1.0).contains(&self.mutation)
+			&& self.params.iter().all(|(_, values)| !values.is_empty())
+	}
+
+	fn random(&self, rng: &mut Rng) -> Genome {
+		self.params
+			.iter()
+			.map(|(_, values)| rng.below(values.len()))
+			.collect()
+	}
+
+	fn values(&self, genome: &[usize]) -> Vec<(String, ParamValue)> {
+		self.params
+			.iter()
+			.zip(genome)
+			.map(|((name, values), &index)| (name.clone(), values[index].clone()))
+			.collect()
+	}
+
+	fn crossover(&self, a: &[usize], b: &[usize], rng: &mut Rng) -> Genome {
+		self.params
+			.iter()
+			.zip(a.iter().zip(b))
+			.map(|((_, values), (&a, &b))| {
+				if rng.chance(self.mutation) {
+					rng.below(values.len())
+				} else if rng.chance(0.5) {
+					a
+				} else {
+					b
+				}
+			})
+			.collect()
+	}
+}
+
+impl Default for GeneticSearch {
+	fn default() -> Self {
+		Self::new()
+	}
+}
+
+fn fitness(score: Option<ValueType>) -> ValueType {
+	score.unwrap_or(ValueType::NEG_INFINITY)
+}
+
+impl Optimizer for GeneticSearch {
+	fn optimize<T, C>(
+		&self,
+		backtest: &Backtest,
+		config: &C,
+		candles: &[T],
+		objective: Objective,
+	) -> Result<Optimized<C>, Error>
+	where
+		T: OHLC,
+		C: IndicatorConfig + IndicatorInitializer<T>,
+	{
+		if !self.validate() {
+			return Err(Error::WrongConfig);
+		}
+
+		let mut rng = Rng::new(self.seed);
+		let mut scores: BTreeMap<Genome, Option<ValueType>> = BTreeMap::new();
+		let mut best: Option<Optimized<C>> = None;
+
+		let mut population: Vec<Genome> = (0..self.population)
+			.map(|_| self.random(&mut rng))
+			.collect();
+
+		for generation in 0..self.generations {
+			let mut ranked: Vec<(Genome, Option<ValueType>)> = population
+				.into_iter()
+				.map(|genome| {
+					let score = *scores.entry(genome.clone()).or_insert_with(|| {
+						let values = self.values(&genome);
+						let (config, score) =
+							evaluate(backtest, config, &values, candles, objective)?;
+
+						if best.as_ref().is_none_or(|best| score > best.score) {
+							best = Some(Optimized {
+								config,
+								score,
+								evaluated: 0,
+							});
+						}
+
+						Some(score)
+					});
+
+					(genome, score)
+				})
+				.collect();
+
+			if generation + 1 == self.generations {
+				break;
+			}
+
+			ranked.sort_by(|a, b| fitness(b.1).total_cmp(&fitness(a.1)));
+
+			let select = |rng: &mut Rng| {
+				let a = &ranked[rng.below(ranked.len())];
+				let b = &ranked[rng.below(ranked.len())];
+
+				if fitness(a.1) >= fitness(b.1) {
+					a.0.clone()
+				} else {
+					b.0.clone()
+				}
+			};
+
+			let children: Vec<Genome> = (self.elite..self.population)
+				.map(|_| {
+					let a = select(&mut rng);
+					let b = select(&mut rng);
+					self.crossover(&a, &b, &mut rng)
+				})
+				.collect();
+
+			population = ranked
+				.iter()
+				.take(self.elite)
+				.map(|(genome, _)| genome.clone())
+				.chain(children)
+				.collect();
+		}
+
+		let evaluated = scores.values().filter(|score| score.is_some()).count();
+
+		best.map(|best| Optimized { evaluated, ..best })
+			.ok_or(Error::WrongConfig)
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	use super::GeneticSearch;
+	use crate::backtest::{Backtest, BacktestReport, GridSearch, Optimizer};
+	use crate::core::Candle;
+	use crate::helpers::{assert_eq_float, RandomCandles};
+	use crate::indicators::Trix;
+	use alloc::vec::Vec;
+
+	#[test]
+	fn test_genetic_search() {
+		let candles: Vec<Candle> = RandomCandles::new().take(200).collect();
+		let backtest = Backtest::default();
+		let objective = BacktestReport::total_return;
+
+		let genetic = GeneticSearch::new()
+			.param("period1", [5, 10, 1, 20])
+			.param("period2", [2, 4, 6])
+			.population(8)
+			.generations(10)
+			.mutation(0.3);
+
+		let optimized = genetic
+			.optimize(&backtest, &Trix::default(), &candles, objective)
+			.unwrap();
+
+		let score = objective(&backtest.run(optimized.config, &candles).unwrap());
+		assert_eq_float(score, optimized.score);
+		assert!(optimized.evaluated <= 9);
+
+		// search space is small enough to be covered entirely
+		let grid = GridSearch::new()
+			.param("period1", [5, 10, 1, 20])
+			.param("period2", [2, 4, 6])
+			.optimize(&backtest, &Trix::default(), &candles, objective)
+			.unwrap();
+		assert_eq_float(grid.score, optimized.score);
+
+		let again = genetic
+			.optimize(&backtest, &Trix::default(), &candles, objective)
+			.unwrap();
+		assert_eq!(again.evaluated, optimized.evaluated);
+		assert_eq_float(again.score, optimized.score);
+
+		assert!(genetic
+			.clone()
+			.elite(10)
+			.optimize(&backtest, &Trix::default(), &candles, objective)
+			.is_err());
+		assert!(genetic
+			.mutation(1.5)
+			.optimize(&backtest, &Trix::default(), &candles, objective)
+			.is_err());
+		assert!(GeneticSearch::new()
+			.param("period1", [1])
+			.optimize(&backtest, &Trix::default(), &candles, objective)
+			.is_err());
+	}
+}
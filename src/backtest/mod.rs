@@ -0,0 +1,535 @@
+//! Simple backtesting of indicators signals
+//!
+//! [`Backtest`] feeds a candle history through an indicator and interprets one of its signals as entries and exits.
+//! Positive signal (buy) opens a long position and closes a short one.
+//! Negative signal (sell) opens a short position and closes a long one.
+//! Which positions may be opened is defined by [`PositionModel`].
+//!
+//! All the orders are filled by the *close* price of the candle, which produced the signal,
+//! worsened by the `slippage`. Every filled order pays [`Commission`] and quantity of every position is chosen by [`Sizing`].
+//!
+//! # Examples
+//!
+//! ```
+//! use yata::backtest::{Backtest, Commission, PositionModel, Sizing};
+//! use yata::helpers::RandomCandles;
+//! use yata::indicators::MACD;
+//!
+//! let candles: Vec<_> = RandomCandles::new().take(200).collect();
+//!
+//! let backtest = Backtest {
+//!     model: PositionModel::LongOnly,
+//!     capital: 10_000.0,
+//!     commission: Commission::Bps(5.0),
+//!     slippage: 0.0005,
+//!     sizing: Sizing::Atr { risk: 0.01, multiplier: 2.0, period: 14 },
+//!     ..Backtest::default()
+//! };
+//!
+//! let report = backtest.run(MACD::default(), &candles).unwrap();
+//!
+//! assert_eq!(report.equity.len(), candles.len());
+//! assert!(report.positions.iter().all(|position| position.exit_index >= position.entry_index));
+//! println!("Total return: {}", report.total_return());
+//! ```
+
+mod execution;
+pub use execution::{Commission, Sizing};
+mod genetic;
+pub use genetic::GeneticSearch;
+mod optimizer;
+pub use optimizer::{GridSearch, Objective, Optimized, Optimizer};
+mod walk_forward;
+pub use walk_forward::{WalkForward, WalkForwardReport, WalkForwardWindow};
+
+#[cfg(not(feature = "std"))]
+use crate::core::Float as _;
+use crate::core::{
+	Error, IndicatorInitializer, IndicatorInstance, Method, Series, ValueType, OHLC,
+};
+use crate::methods::RMA;
+use alloc::vec::Vec;
+
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
+
+/// Defines which positions may be opened by the signals
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub enum PositionModel {
+	/// Buy signals open long positions, sell signals only close them
+	LongOnly,
+
+	/// Sell signals open short positions, buy signals only close them
+	ShortOnly,
+
+	/// Buy and sell signals reverse the position
+	LongShort,
+}
+
+/// Side of the position
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub enum Side {
+	/// Position profits when the price rises
+	Long,
+
+	/// Position profits when the price falls
+	Short,
+}
+
+impl Side {
+	/// Returns `1.0` for [`Side::Long`] and `-1.0` for [`Side::Short`]
+	#[must_use]
+	pub const fn sign(self) -> ValueType {
+		match self {
+			Self::Long => 1.0,
+			Self::Short => -1.0,
+		}
+	}
+}
+
+/// Closed position of the backtest
+#[derive(Debug, Clone, Copy, PartialEq)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct Position {
+	/// Side of the position
+	pub side: Side,
+
+	/// Index of the candle, which opened the position
+	pub entry_index: usize,
+
+	/// Price the position was opened by
+	pub entry_price: ValueType,
+
+	/// Index of the candle, which closed the position
+	pub exit_index: usize,
+
+	/// Price the position was closed by
+	pub exit_price: ValueType,
+
+	/// Quantity of the position
+	pub quantity: ValueType,
+
+	/// Total commission paid for opening and closing the position
+	pub commission: ValueType,
+}
+
+impl Position {
+	/// Returns relative profit of the position without commission
+	///
+	/// F.e. `0.1` means +10% and `-0.05` means -5%.
+	#[must_use]
+	pub fn profit(&self) -> ValueType {
+		self.side.sign() * (self.exit_price - self.entry_price) / self.entry_price
+	}
+
+	/// Returns profit of the position in units of the equity after the commission is paid
+	#[must_use]
+	pub fn pnl(&self) -> ValueType {
+		(self.side.sign() * (self.exit_price - self.entry_price))
+			.mul_add(self.quantity, -self.commission)
+	}
+}
+
+/// Result of the [`Backtest`]
+#[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct BacktestReport {
+	/// Initial equity
+	pub capital: ValueType,
+
+	/// Equity at the close of every candle
+	///
+	/// Open position is valued by the *close* price of the candle, commission is already subtracted.
+	pub equity: Series,
+
+	/// All the positions in the order they were closed
+	///
+	/// Position, which is still open after the last candle, is closed by the last candle.
+	pub positions: Vec<Position>,
+}
+
+impl BacktestReport {
+	/// Returns relative profit over the whole backtest
+	///
+	/// F.e. `0.1` means +10% and `-0.05` means -5%.
+	#[must_use]
+	pub fn total_return(&self) -> ValueType {
+		self.equity
+			.last()
+			.map_or(0.0, |&equity| equity / self.capital - 1.0)
+	}
+}
+
+/// Backtest configuration
+#[derive(Debug, Clone, Copy, PartialEq)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct Backtest {
+	/// Index of the indicator signal to trade by
+	///
+	/// Default is `0`.
+	pub signal: usize,
+
+	/// Which positions may be opened
+	///
+	/// Default is [`PositionModel::LongShort`].
+	pub model: PositionModel,
+
+	/// Minimal absolute [ratio](crate::core::Action::ratio) of the signal to act on. Range in \[`0.0`; `1.0`\]
+	///
+	/// Any non-zero signal is acted on when it is `0.0`. Default is `0.0`.
+	pub threshold: ValueType,
+
+	/// Initial equity
+	///
+	/// Default is `1.0`, so the equity is relative to the initial one.
+	pub capital: ValueType,
+
+	/// Commission of every filled order
+	///
+	/// Default is [`Commission::Bps(0.0)`](Commission::Bps).
+	pub commission: Commission,
+
+	/// Relative worsening of every fill price. Range in \[`0.0`; `1.0`)
+	///
+	/// Buy orders are filled by `price * (1 + slippage)`, sell orders are filled by `price * (1 - slippage)`.
+	/// Default is `0.0`.
+	pub slippage: ValueType,
+
+	/// Quantity of every opened position
+	///
+	/// Default is [`Sizing::Percent(1.0)`](Sizing::Percent), so the whole equity is invested.
+	pub sizing: Sizing,
+}
+
+impl Backtest {
+	/// Validates the backtest configuration
+	///
+	/// Returns an error with the name of the first invalid field.
+	pub fn validate(&self) -> Result<(), Error> {
+		if !(0.0..=1.0).contains(&self.threshold) {
+			return Err(Error::WrongConfigParameter("threshold".into()));
+		}
+
+		if !(self.capital > 0.0 && self.capital.is_finite()) {
+			return Err(Error::WrongConfigParameter("capital".into()));
+		}
+
+		if !(0.0..1.0).contains(&self.slippage) {
+			return Err(Error::WrongConfigParameter("slippage".into()));
+		}
+
+		self.commission.validate()?;
+		self.sizing.validate()
+	}
+
+	/// Initializes the indicator by the first candle and runs the backtest over all the `candles`
+	///
+	/// Returns an empty report if there are no candles.
+	pub fn run<T, C>(&self, config: C, candles: &[T]) -> Result<BacktestReport, Error>
+	where
+		T: OHLC,
+		C: IndicatorInitializer<T>,
+		C::Instance: IndicatorInstance<T>,
+	{
+		self.validate()?;
+
+		let Some(&first) = candles.first() else {
+			return Ok(BacktestReport {
+				capital: self.capital,
+				equity: Series::empty(),
+				positions: Vec::new(),
+			});
+		};
+
+		let mut state = config.init(first)?;
+		self.run_instance(&mut state, candles)
+	}
+
+	/// Runs the backtest of the already initialized indicator **State** over the `candles`
+	pub fn run_instance<T, I>(&self, state: &mut I, candles: &[T]) -> Result<BacktestReport, Error>
+	where
+		T: OHLC,
+		I: IndicatorInstance<T>,
+	{
+		self.validate()?;
+
+		if self.signal >= usize::from(state.size().1) {
+			return Err(Error::WrongConfigParameter("signal".into()));
+		}
+
+		let mut equity = Series::new(candles.len());
+		let mut positions = Vec::new();
+		let mut open: Option<Position> = None;
+		let mut cash = self.capital;
+		let mut atr = None;
+		let mut prev_candle = None;
+
+		for (index, &candle) in candles.iter().enumerate() {
+			let price = candle.close();
+			let ratio = state
+				.next(candle)
+				.signal(self.signal)
+				.ratio()
+				.unwrap_or(0.0);
+
+			let tr =
+				prev_candle.map_or_else(|| candle.high() - candle.low(), |prev| candle.tr(&prev));
+			prev_candle = Some(candle);
+
+			// ATR is evaluated only for the ATR based sizing, otherwise true range is just ignored
+			let atr_value = match (&mut atr, self.sizing) {
+				(Some(atr), _) => RMA::next(atr, tr),
+				(None, Sizing::Atr { period, .. }) => {
+					atr = Some(RMA::new(period, tr)?);
+					tr
+				}
+				(None, _) => tr,
+			};
+
+			let side = if ratio > 0.0 && ratio >= self.threshold {
+				Some(Side::Long)
+			} else if ratio < 0.0 && -ratio >= self.threshold {
+				Some(Side::Short)
+			} else {
+				None
+			};
+
+			let is_last = index + 1 == candles.len();
+
+			if let Some(mut position) = open {
+				if is_last || side.is_some_and(|side| side != position.side) {
+					let exit_price = self.fill_price(price, -position.side.sign());
+					let commission = self.commission.of(position.quantity * exit_price);
+
+					position.exit_index = index;
+					position.exit_price = exit_price;
+					position.commission += commission;
+
+					cash +=
+						(position.side.sign() * position.quantity).mul_add(exit_price, -commission);
+					positions.push(position);
+					open = None;
+				}
+			}
+
+			let can_open = match (side, self.model) {
+				(Some(Side::Long), PositionModel::LongOnly | PositionModel::LongShort)
+				| (Some(Side::Short), PositionModel::ShortOnly | PositionModel::LongShort) => !is_last,
+				_ => false,
+			};
+
+			if let (None, Some(side), true) = (open, side, can_open) {
+				let entry_price = self.fill_price(price, side.sign());
+				let quantity = self.sizing.quantity(cash, entry_price, atr_value);
+
+				if quantity > 0.0 && quantity.is_finite() {
+					let commission = self.commission.of(quantity * entry_price);
+					cash -= (side.sign() * quantity).mul_add(entry_price, commission);
+
+					open = Some(Position {
+						side,
+						entry_index: index,
+						entry_price,
+						exit_index: index,
+						exit_price: entry_price,
+						quantity,
+						commission,
+					});
+				}
+			}
+
+			equity.push(open.map_or(cash, |position| {
+				(position.side.sign() * position.quantity).mul_add(price, cash)
+			}));
+		}
+
+		Ok(BacktestReport {
+			capital: self.capital,
+			equity,
+			positions,
+		})
+	}
+
+	/// Returns fill price of the order with the `sign` of `1.0` for buying and `-1.0` for selling
+	fn fill_price(&self, price: ValueType, sign: ValueType) -> ValueType {
+		price * sign.mul_add(self.slippage, 1.0)
+	}
+}
+
+impl Default for Backtest {
+	fn default() -> Self {
+		Self {
+			signal: 0,
+			model: PositionModel::LongShort,
+			threshold: 0.0,
+			capital: 1.0,
+			commission: Commission::default(),
+			slippage: 0.0,
+			sizing: Sizing::default(),
+		}
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	use super::{Backtest, Commission, PositionModel, Side, Sizing};
+	use crate::core::{Action, Candle, IndicatorInstance, IndicatorResult, ValueType};
+	use crate::helpers::assert_eq_float;
+	use crate::indicators::example::Example;
+	use alloc::vec::Vec;
+
+	/// Produces the predefined signals
+	#[derive(Debug, Clone, Copy)]
+	struct Signals<'a>(&'a [i8], usize, Example);
+
+	impl IndicatorInstance<Candle> for Signals<'_> {
+		type Config = Example;
+
+		fn config(&self) -> &Self::Config {
+			&self.2
+		}
+
+		fn next(&mut self, _: Candle) -> IndicatorResult {
+			let signal = Action::from(self.0[self.1]);
+			self.1 += 1;
+			IndicatorResult::new(&[], &[signal])
+		}
+
+		fn size(&self) -> (u8, u8) {
+			(0, 1)
+		}
+	}
+
+	#[test]
+	fn test_backtest() {
+		let prices = [10.0, 10.0, 12.0, 11.0, 11.0, 9.0, 9.0, 10.0];
+		let signals = [0, 1, 0, 0, -1, 0, 1, 0];
+		let candles: Vec<Candle> = prices
+			.iter()
+			.map(|&price| (price, price, price, price, 1.0).into())
+			.collect();
+
+		let long_short = Backtest::default()
+			.run_instance(&mut Signals(&signals, 0, Example::default()), &candles)
+			.unwrap();
+
+		let sides: Vec<_> = long_short.positions.iter().map(|p| p.side).collect();
+		assert_eq!(sides, [Side::Long, Side::Short, Side::Long]);
+		assert_eq!(long_short.positions[0].entry_index, 1);
+		assert_eq!(long_short.positions[0].exit_index, 4);
+		assert_eq!(long_short.positions[2].exit_index, 7);
+
+		let expected = [1.0, 1.0, 1.2, 1.1, 1.1, 1.3, 1.3, 1.3 * 10.0 / 9.0];
+		for (&value, &expected) in long_short.equity.iter().zip(&expected) {
+			assert_eq_float(expected, value);
+		}
+
+		let long_only = Backtest {
+			model: PositionModel::LongOnly,
+			commission: Commission::Bps(100.0),
+			..Backtest::default()
+		}
+		.run_instance(&mut Signals(&signals, 0, Example::default()), &candles)
+		.unwrap();
+
+		assert_eq!(long_only.positions.len(), 2);
+		let cash = ValueType::mul_add(1.1, 0.99, -0.01);
+		assert_eq_float(
+			cash * ValueType::mul_add(10.0 / 9.0, 0.99, -0.01),
+			long_only.total_return() + 1.0,
+		);
+
+		let threshold = Backtest {
+			threshold: 1.0,
+			..Backtest::default()
+		};
+		assert_eq!(
+			threshold
+				.run_instance(
+					&mut Signals(&[0, 0, 1, 0], 0, Example::default()),
+					&candles[..4]
+				)
+				.unwrap()
+				.positions
+				.len(),
+			1
+		);
+
+		let wrong = Backtest {
+			signal: 1,
+			..Backtest::default()
+		};
+		assert!(wrong
+			.run_instance(&mut Signals(&signals, 0, Example::default()), &candles)
+			.is_err());
+	}
+
+	#[test]
+	fn test_backtest_execution() {
+		let prices = [10.0, 10.0, 12.0, 11.0, 11.0, 9.0, 9.0, 10.0];
+		let signals = [0, 1, 0, 0, -1, 0, 1, 0];
+		let candles: Vec<Candle> = prices
+			.iter()
+			.map(|&price| (price, price + 1.0, price - 1.0, price, 1.0).into())
+			.collect();
+
+		let fixed = Backtest {
+			model: PositionModel::LongOnly,
+			capital: 100.0,
+			commission: Commission::Absolute(0.5),
+			slippage: 0.1,
+			sizing: Sizing::Fixed(1.0),
+			..Backtest::default()
+		}
+		.run_instance(&mut Signals(&signals, 0, Example::default()), &candles)
+		.unwrap();
+
+		assert_eq_float(11.0, fixed.positions[0].entry_price);
+		assert_eq_float(9.9, fixed.positions[0].exit_price);
+		assert_eq_float(-2.1, fixed.positions[0].pnl());
+		assert_eq_float(100.5, fixed.equity[2]);
+		assert_eq_float(96.0, fixed.equity[7]);
+		assert_eq_float(-0.04, fixed.total_return());
+
+		// true range of every candle is 2.0, so the stop distance is 4.0
+		let atr = Backtest {
+			capital: 100.0,
+			sizing: Sizing::Atr {
+				risk: 0.02,
+				multiplier: 2.0,
+				period: 3,
+			},
+			..Backtest::default()
+		}
+		.run_instance(
+			&mut Signals(&[1, 0, 0], 0, Example::default()),
+			&[candles[0]; 3],
+		)
+		.unwrap();
+
+		assert_eq_float(0.5, atr.positions[0].quantity);
+
+		for wrong in &[
+			Backtest {
+				capital: 0.0,
+				..Backtest::default()
+			},
+			Backtest {
+				slippage: 1.0,
+				..Backtest::default()
+			},
+			Backtest {
+				commission: Commission::Bps(-1.0),
+				..Backtest::default()
+			},
+			Backtest {
+				sizing: Sizing::Percent(0.0),
+				..Backtest::default()
+			},
+		] {
+			assert!(wrong.validate().is_err());
+		}
+	}
+}
@@ -0,0 +1,248 @@
+use super::{Backtest, BacktestReport};
+use crate::core::{Error, IndicatorConfig, IndicatorInitializer, ParamValue, ValueType, OHLC};
+use alloc::string::String;
+use alloc::vec::Vec;
+
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
+
+/// Function, which scores the backtest result. The greater score is the better one
+///
+/// F.e. [`BacktestReport::total_return`].
+pub type Objective = fn(&BacktestReport) -> ValueType;
+
+/// The best indicator **Configuration** found by an [`Optimizer`]
+#[derive(Debug, Clone)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct Optimized<C> {
+	/// Indicator **Configuration**
+	pub config: C,
+
+	/// Score of the **Configuration** by the [`Objective`]
+	pub score: ValueType,
+
+	/// Count of the backtested **Configurations**
+	pub evaluated: usize,
+}
+
+/// Searches for the indicator **Configuration** parameters with the best [`Objective`] score
+pub trait Optimizer {
+	/// Returns the best **Configuration** based on the `config` by backtesting it over the `candles`
+	///
+	/// Returns an error if there is no valid **Configuration** to backtest.
+	fn optimize<T, C>(
+		&self,
+		backtest: &Backtest,
+		config: &C,
+		candles: &[T],
+		objective: Objective,
+	) -> Result<Optimized<C>, Error>
+	where
+		T: OHLC,
+		C: IndicatorConfig + IndicatorInitializer<T>;
+}
+
+/// Backtests the `config` with the parameters `values` and returns its score
+///
+/// Returns `None` if the parameters can not be applied or the score is not finite.
+pub(super) fn evaluate<T, C>(
+	backtest: &Backtest,
+	config: &C,
+	values: &[(String, ParamValue)],
+	candles: &[T],
+	objective: Objective,
+) -> Option<(C, ValueType)>
+where
+	T: OHLC,
+	C: IndicatorConfig + IndicatorInitializer<T>,
+{
+	let mut config = config.clone();
+
+	for (name, value) in values {
+		config.set_value(name, value.clone()).ok()?;
+	}
+
+	if !config.validate() {
+		return None;
+	}
+
+	let report = backtest.run(config.clone(), candles).ok()?;
+	let score = objective(&report);
+
+	if score.is_finite() {
+		Some((config, score))
+	} else {
+		None
+	}
+}
+
+/// Exhaustive search over all the combinations of the parameters values
+///
+/// Combinations, which are not valid for the indicator, are skipped.
+///
+/// # Examples
+///
+/// ```
+/// use yata::backtest::{Backtest, BacktestReport, GridSearch, Optimizer};
+/// use yata::helpers::RandomCandles;
+/// use yata::indicators::Trix;
+///
+/// let candles: Vec<_> = RandomCandles::new().take(200).collect();
+///
+/// let grid = GridSearch::new()
+///     .param("period1", [10, 15, 20])
+///     .param("period2", [3, 5]);
+///
+/// let optimized = grid
+///     .optimize(&Backtest::default(), &Trix::default(), &candles, BacktestReport::total_return)
+///     .unwrap();
+///
+/// assert_eq!(optimized.evaluated, 6);
+/// assert!([10, 15, 20].contains(&optimized.config.period1));
+/// ```
+#[derive(Debug, Clone, Default)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct GridSearch {
+	params: Vec<(String, Vec<ParamValue>)>,
+}
+
+impl GridSearch {
+	/// Creates an empty grid
+	#[must_use]
+	pub const fn new() -> Self {
+		Self { params: Vec::new() }
+	}
+
+	/// Adds the parameter `name` with the list of its `values` to the grid
+	#[must_use]
+	pub fn param<S, I>(mut self, name: S, values: I) -> Self
+	where
+		S: Into<String>,
+		I: IntoIterator,
+		I::Item: Into<ParamValue>,
+	{
+		self.params
+			.push((name.into(), values.into_iter().map(Into::into).collect()));
+		self
+	}
+
+	/// Returns count of all the combinations of the parameters values
+	///
+	/// Empty grid has the single combination, which is the initial **Configuration** itself.
+	#[must_use]
+	pub fn len(&self) -> usize {
+		self.params.iter().map(|(_, values)| values.len()).product()
+	}
+
+	/// Returns `true` if there are no combinations
+	#[must_use]
+	pub fn is_empty(&self) -> bool {
+		self.len() == 0
+	}
+
+	/// Returns the combination of the parameters values at the `index`
+	fn combination(&self, mut index: usize) -> Vec<(String, ParamValue)> {
+		self.params
+			.iter()
+			.map(|(name, values)| {
+				let value = values[index % values.len()].clone();
+				index /= values.len();
+				(name.clone(), value)
+			})
+			.collect()
+	}
+}
+
+impl Optimizer for GridSearch {
+	fn optimize<T, C>(
+		&self,
+		backtest: &Backtest,
+		config: &C,
+		candles: &[T],
+		objective: Objective,
+	) -> Result<Optimized<C>, Error>
+	where
+		T: OHLC,
+		C: IndicatorConfig + IndicatorInitializer<T>,
+	{
+		let mut best: Option<Optimized<C>> = None;
+		let mut evaluated = 0;
+
+		for index in 0..self.len() {
+			let values = self.combination(index);
+
+			let Some((config, score)) = evaluate(backtest, config, &values, candles, objective)
+			else {
+				continue;
+			};
+
+			evaluated += 1;
+
+			if best.as_ref().is_none_or(|best| score > best.score) {
+				best = Some(Optimized {
+					config,
+					score,
+					evaluated: 0,
+				});
+			}
+		}
+
+		best.map(|best| Optimized { evaluated, ..best })
+			.ok_or(Error::WrongConfig)
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	use super::{GridSearch, Optimizer};
+	use crate::backtest::{Backtest, BacktestReport};
+	use crate::core::{Candle, IndicatorConfig, ParamValue};
+	use crate::helpers::{assert_eq_float, RandomCandles};
+	use crate::indicators::Trix;
+	use alloc::vec::Vec;
+
+	#[test]
+	fn test_grid_search() {
+		let candles: Vec<Candle> = RandomCandles::new().take(200).collect();
+		let backtest = Backtest::default();
+		let objective = BacktestReport::total_return;
+
+		let grid = GridSearch::new()
+			.param("period1", [5, 10, 1, 20])
+			.param("period2", [2, 4, 6]);
+		assert_eq!(grid.len(), 12);
+
+		let optimized = grid
+			.optimize(&backtest, &Trix::default(), &candles, objective)
+			.unwrap();
+
+		// period1 = 1 is not valid
+		assert_eq!(optimized.evaluated, 9);
+
+		let mut best = None;
+		// the first parameter of the grid changes the most frequently
+		for &period2 in &[2, 4, 6] {
+			for &period1 in &[5, 10, 20] {
+				let mut trix = Trix::default();
+				trix.set_value("period1", ParamValue::from(period1))
+					.unwrap();
+				trix.set_value("period2", ParamValue::from(period2))
+					.unwrap();
+
+				let score = objective(&backtest.run(trix, &candles).unwrap());
+				if best.is_none_or(|(best, _)| score > best) {
+					best = Some((score, trix));
+				}
+			}
+		}
+
+		let (score, trix) = best.unwrap();
+		assert_eq_float(score, optimized.score);
+		assert_eq!(trix.period1, optimized.config.period1);
+
+		assert!(GridSearch::new()
+			.param("period1", [1])
+			.optimize(&backtest, &Trix::default(), &candles, objective)
+			.is_err());
+	}
+}
@@ -0,0 +1,285 @@
+use super::{Backtest, Objective, Optimizer};
+use crate::core::{
+	Error, IndicatorConfig, IndicatorInitializer, IndicatorInstance, ValueType, OHLC,
+};
+use alloc::vec::Vec;
+use core::ops::Range;
+
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
+
+#[cfg(not(feature = "std"))]
+use crate::core::Float as _;
+
+/// Single in-sample/out-of-sample step of the [`WalkForward`] analysis
+#[derive(Debug, Clone)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct WalkForwardWindow<C> {
+	/// Range of the candles the **Configuration** was optimized over
+	pub in_sample: Range<usize>,
+
+	/// Range of the candles the **Configuration** was evaluated over
+	pub out_of_sample: Range<usize>,
+
+	/// The best **Configuration** of the in-sample candles
+	pub config: C,
+
+	/// Score of the **Configuration** over the in-sample candles
+	pub in_sample_score: ValueType,
+
+	/// Score of the **Configuration** over the out-of-sample candles
+	pub out_of_sample_score: ValueType,
+}
+
+/// Result of the [`WalkForward`] analysis
+#[derive(Debug, Clone)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct WalkForwardReport<C> {
+	/// All the windows in the order of the history
+	pub windows: Vec<WalkForwardWindow<C>>,
+}
+
+impl<C> WalkForwardReport<C> {
+	/// Returns mean in-sample score
+	#[must_use]
+	pub fn mean_in_sample(&self) -> ValueType {
+		mean(self.windows.iter().map(|w| w.in_sample_score))
+	}
+
+	/// Returns mean out-of-sample score
+	#[must_use]
+	pub fn mean_out_of_sample(&self) -> ValueType {
+		mean(self.windows.iter().map(|w| w.out_of_sample_score))
+	}
+
+	/// Returns standard deviation of out-of-sample scores
+	///
+	/// The lower it is, the more stable the optimized **Configurations** are.
+	#[must_use]
+	pub fn out_of_sample_deviation(&self) -> ValueType {
+		let mean_value = self.mean_out_of_sample();
+		let variance = mean(self.windows.iter().map(|w| {
+			let delta = w.out_of_sample_score - mean_value;
+			delta * delta
+		}));
+
+		variance.sqrt()
+	}
+
+	/// Returns walk-forward efficiency: ratio of mean out-of-sample score to mean in-sample score
+	///
+	/// Values far below `1.0` mean the parameters are overfitted to the in-sample history.
+	#[must_use]
+	pub fn efficiency(&self) -> ValueType {
+		self.mean_out_of_sample() / self.mean_in_sample()
+	}
+
+	/// Returns ratio of the windows with positive out-of-sample score
+	#[must_use]
+	pub fn positive_ratio(&self) -> ValueType {
+		mean(self.windows.iter().map(|w| {
+			if w.out_of_sample_score > 0.0 {
+				1.0
+			} else {
+				0.0
+			}
+		}))
+	}
+}
+
+fn mean<I: Iterator<Item = ValueType>>(values: I) -> ValueType {
+	let (sum, count) = values.fold((0.0, 0.0), |(sum, count), value| (sum + value, count + 1.0));
+
+	if count > 0.0 {
+		sum / count
+	} else {
+		ValueType::NAN
+	}
+}
+
+/// Walk-forward analysis of the indicator parameters optimization
+///
+/// The history is split into rolling windows of `in_sample` candles followed by `out_of_sample` candles.
+/// Every window is shifted by `out_of_sample` candles, so out-of-sample ranges follow each other without overlapping.
+///
+/// The **Configuration** is optimized over the in-sample candles and then evaluated over the out-of-sample candles.
+/// Indicator **State** is warmed up by the in-sample candles before the out-of-sample evaluation.
+///
+/// # Examples
+///
+/// ```
+/// use yata::backtest::{Backtest, BacktestReport, GridSearch, WalkForward};
+/// use yata::helpers::RandomCandles;
+/// use yata::indicators::Trix;
+///
+/// let candles: Vec<_> = RandomCandles::new().take(200).collect();
+/// let grid = GridSearch::new().param("period1", [10, 15, 20]);
+///
+/// let report = WalkForward::new(100, 25)
+///     .unwrap()
+///     .run(&grid, &Backtest::default(), &Trix::default(), &candles, BacktestReport::total_return)
+///     .unwrap();
+///
+/// assert_eq!(report.windows.len(), 4);
+/// assert_eq!(report.windows[1].out_of_sample, 125..150);
+/// println!("efficiency: {}", report.efficiency());
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct WalkForward {
+	in_sample: usize,
+	out_of_sample: usize,
+}
+
+impl WalkForward {
+	/// Creates a new `WalkForward` analysis with the given counts of candles
+	///
+	/// Returns an error if any of the counts is zero.
+	pub const fn new(in_sample: usize, out_of_sample: usize) -> Result<Self, Error> {
+		if in_sample == 0 || out_of_sample == 0 {
+			return Err(Error::WrongConfig);
+		}
+
+		Ok(Self {
+			in_sample,
+			out_of_sample,
+		})
+	}
+
+	/// Returns count of in-sample candles of every window
+	#[must_use]
+	pub const fn in_sample(&self) -> usize {
+		self.in_sample
+	}
+
+	/// Returns count of out-of-sample candles of every window
+	#[must_use]
+	pub const fn out_of_sample(&self) -> usize {
+		self.out_of_sample
+	}
+
+	/// Returns all the windows `(in_sample, out_of_sample)` over the history of `len` candles
+	///
+	/// The last incomplete window is omitted.
+	pub fn windows(&self, len: usize) -> impl Iterator<Item = (Range<usize>, Range<usize>)> + '_ {
+		(0..)
+			.map(move |i| i * self.out_of_sample)
+			.take_while(move |&start| start + self.in_sample + self.out_of_sample <= len)
+			.map(move |start| {
+				let middle = start + self.in_sample;
+				(start..middle, middle..middle + self.out_of_sample)
+			})
+	}
+
+	/// Runs the analysis of the `config` over the `candles` using the `optimizer`
+	///
+	/// Returns an error if there are not enough candles for a single window
+	/// or if the `optimizer` fails over any of the windows.
+	pub fn run<O, T, C>(
+		&self,
+		optimizer: &O,
+		backtest: &Backtest,
+		config: &C,
+		candles: &[T],
+		objective: Objective,
+	) -> Result<WalkForwardReport<C>, Error>
+	where
+		O: Optimizer,
+		T: OHLC,
+		C: IndicatorConfig + IndicatorInitializer<T>,
+	{
+		let windows = self
+			.windows(candles.len())
+			.map(|(in_sample, out_of_sample)| {
+				let best =
+					optimizer.optimize(backtest, config, &candles[in_sample.clone()], objective)?;
+
+				let mut state = best.config.clone().init(candles[in_sample.start])?;
+				state.over(&candles[in_sample.clone()]);
+
+				let report = backtest.run_instance(&mut state, &candles[out_of_sample.clone()])?;
+
+				Ok(WalkForwardWindow {
+					in_sample,
+					out_of_sample,
+					config: best.config,
+					in_sample_score: best.score,
+					out_of_sample_score: objective(&report),
+				})
+			})
+			.collect::<Result<Vec<_>, Error>>()?;
+
+		if windows.is_empty() {
+			return Err(Error::InvalidCandles);
+		}
+
+		Ok(WalkForwardReport { windows })
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	use super::WalkForward;
+	use crate::backtest::{Backtest, BacktestReport, GridSearch, Optimizer};
+	use crate::core::{Candle, IndicatorInitializer, IndicatorInstance, ValueType};
+	use crate::helpers::{assert_eq_float, RandomCandles};
+	use crate::indicators::Trix;
+	use alloc::vec::Vec;
+	use core::convert::TryFrom;
+
+	#[test]
+	fn test_walk_forward() {
+		let candles: Vec<Candle> = RandomCandles::new().take(200).collect();
+		let backtest = Backtest::default();
+		let objective = BacktestReport::total_return;
+		let grid = GridSearch::new()
+			.param("period1", [5, 10, 15])
+			.param("period2", [2, 4]);
+
+		let walk_forward = WalkForward::new(60, 30).unwrap();
+		let report = walk_forward
+			.run(&grid, &backtest, &Trix::default(), &candles, objective)
+			.unwrap();
+
+		assert_eq!(report.windows.len(), 4);
+
+		for (i, window) in report.windows.iter().enumerate() {
+			assert_eq!(window.in_sample, i * 30..i * 30 + 60);
+			assert_eq!(window.out_of_sample, i * 30 + 60..i * 30 + 90);
+
+			let optimized = grid
+				.optimize(
+					&backtest,
+					&Trix::default(),
+					&candles[window.in_sample.clone()],
+					objective,
+				)
+				.unwrap();
+			assert_eq_float(optimized.score, window.in_sample_score);
+
+			let mut state = window.config.init(candles[window.in_sample.start]).unwrap();
+			state.over(&candles[window.in_sample.clone()]);
+			let out = backtest
+				.run_instance(&mut state, &candles[window.out_of_sample.clone()])
+				.unwrap();
+			assert_eq_float(objective(&out), window.out_of_sample_score);
+		}
+
+		let positive = report
+			.windows
+			.iter()
+			.filter(|w| w.out_of_sample_score > 0.0)
+			.count();
+		assert_eq_float(
+			ValueType::from(u8::try_from(positive).unwrap()) / 4.0,
+			report.positive_ratio(),
+		);
+		assert!(report.out_of_sample_deviation() >= 0.0);
+
+		assert!(WalkForward::new(0, 10).is_err());
+		assert!(WalkForward::new(150, 60)
+			.unwrap()
+			.run(&grid, &backtest, &Trix::default(), &candles, objective)
+			.is_err());
+	}
+}
@@ -1,8 +1,11 @@
 use crate::core::ValueType;
+use core::fmt;
+use core::ops::{Neg, Sub};
 #[cfg(feature = "serde")]
 use serde::{Deserialize, Serialize};
-use std::fmt;
-use std::ops::{Neg, Sub};
+
+#[cfg(not(feature = "std"))]
+use crate::core::Float as _;
 
 type SignalType = u8;
 const BOUND: SignalType = SignalType::MAX;
@@ -85,6 +88,94 @@ impl Action {
 	pub const fn is_some(self) -> bool {
 		!self.is_none()
 	}
+
+	/// Returns the opposite signal. Same as `-action`
+	#[must_use]
+	pub const fn invert(self) -> Self {
+		match self {
+			Self::None => Self::None,
+			Self::Buy(value) => Self::Sell(value),
+			Self::Sell(value) => Self::Buy(value),
+		}
+	}
+
+	/// Confirms signal by the `other` one
+	///
+	/// Returns the weakest of both signals if they have the same direction. Otherwise returns `None`.
+	///
+	/// ```
+	/// use yata::core::Action;
+	///
+	/// assert_eq!(Action::BUY_ALL.and(Action::Buy(100)), Action::Buy(100));
+	/// assert_eq!(Action::Sell(50).and(Action::SELL_ALL), Action::Sell(50));
+	/// assert_eq!(Action::BUY_ALL.and(Action::SELL_ALL), Action::None);
+	/// assert_eq!(Action::BUY_ALL.and(Action::None), Action::None);
+	/// ```
+	#[must_use]
+	pub fn and(self, other: Self) -> Self {
+		match (self, other) {
+			(Self::Buy(a), Self::Buy(b)) => Self::Buy(a.min(b)),
+			(Self::Sell(a), Self::Sell(b)) => Self::Sell(a.min(b)),
+			_ => Self::None,
+		}
+	}
+
+	/// Returns the signal if it exists. Otherwise returns the `other` one
+	///
+	/// ```
+	/// use yata::core::Action;
+	///
+	/// assert_eq!(Action::None.or(Action::SELL_ALL), Action::SELL_ALL);
+	/// assert_eq!(Action::Buy(10).or(Action::SELL_ALL), Action::Buy(10));
+	/// ```
+	#[must_use]
+	pub const fn or(self, other: Self) -> Self {
+		match self {
+			Self::None => other,
+			_ => self,
+		}
+	}
+
+	/// Merges signals into a single one by weighted average of their [ratios](Action::ratio)
+	///
+	/// Missing signals are counted as neutral ones, so they weaken the result.
+	/// Returns `None` if there are no signals at all or if total weight is zero.
+	///
+	/// ```
+	/// use yata::core::Action;
+	///
+	/// let merged = Action::weighted([(Action::BUY_ALL, 3.0), (Action::SELL_ALL, 1.0)]);
+	/// assert_eq!(merged, Action::from(0.5));
+	///
+	/// let merged = Action::weighted([(Action::BUY_ALL, 1.0), (Action::None, 1.0)]);
+	/// assert_eq!(merged, Action::from(0.5));
+	///
+	/// assert_eq!(Action::weighted([(Action::None, 1.0)]), Action::None);
+	/// ```
+	#[must_use]
+	pub fn weighted<I>(actions: I) -> Self
+	where
+		I: IntoIterator<Item = (Self, ValueType)>,
+	{
+		let mut has_signal = false;
+		let mut sum = 0.0;
+		let mut total_weight = 0.0;
+
+		for (action, weight) in actions {
+			if let Some(ratio) = action.ratio() {
+				has_signal = true;
+				sum += ratio * weight;
+			}
+
+			total_weight += weight.abs();
+		}
+
+		if has_signal && total_weight > 0.0 {
+			(sum / total_weight).into()
+		} else {
+			Self::None
+		}
+	}
 }
 
 impl PartialEq for Action {
@@ -242,11 +333,7 @@ impl Neg for Action {
 	type Output = Self;
 
 	fn neg(self) -> Self::Output {
-		match self {
-			Self::None => Self::None,
-			Self::Buy(value) => Self::Sell(value),
-			Self::Sell(value) => Self::Buy(value),
-		}
+		self.invert()
 	}
 }
 
@@ -301,7 +388,7 @@ impl fmt::Display for Action {
 mod tests {
 	use super::{Action, BOUND};
 	use crate::core::ValueType;
-	use std::cmp::Ordering;
+	use core::cmp::Ordering;
 
 	#[test]
 	fn test_action_ratio() {
@@ -392,6 +479,7 @@ mod tests {
 			1e-15
 		};
 
+		#[cfg(feature = "std")]
 		println!("{}", delta);
 		(0..=BOUND).for_each(|x| {
 			let xx = x as ValueType;
@@ -469,4 +557,45 @@ mod tests {
 		assert_ne!(Action::Sell(2), Action::Sell(5));
 		assert_eq!(Action::Sell(5), Action::Sell(5));
 	}
+
+	#[test]
+	fn test_action_combinators() {
+		let actions = [
+			Action::None,
+			Action::BUY_ALL,
+			Action::SELL_ALL,
+			Action::Buy(100),
+			Action::Sell(10),
+			Action::Buy(0),
+		];
+
+		for &a in &actions {
+			assert_eq!(a.invert(), -a);
+			assert_eq!(a.invert().invert(), a);
+			assert_eq!(a.and(a), a);
+			assert_eq!(a.or(Action::None), a);
+			assert_eq!(Action::None.or(a), a);
+
+			for &b in &actions {
+				assert_eq!(a.and(b), b.and(a));
+				assert_eq!(a.invert().and(b.invert()), a.and(b).invert());
+
+				if a.is_some() {
+					assert_eq!(a.or(b), a);
+				}
+
+				let merged = Action::weighted([(a, 1.0), (b, 1.0)]);
+				match (a.ratio(), b.ratio()) {
+					(None, None) => assert_eq!(merged, Action::None),
+					(r1, r2) => {
+						let expected = r1.unwrap_or(0.0) * 0.5 + r2.unwrap_or(0.0) * 0.5;
+						assert_eq!(merged, Action::from(expected));
+					}
+				}
+			}
+		}
+
+		assert_eq!(Action::weighted([(Action::BUY_ALL, 0.0)]), Action::None);
+		assert_eq!(Action::weighted(core::iter::empty()), Action::None);
+	}
 }
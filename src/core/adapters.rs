@@ -0,0 +1,291 @@
+use super::{
+	Candle, Error, IndicatorInitializer, IndicatorInstance, IndicatorResult, Method, SourcedCandle,
+	TimedCandle, Trade, ValueType, OHLC,
+};
+use core::borrow::Borrow;
+
+/// Conversion of iterator items into candles for [`IteratorExt::indicator`]
+///
+/// Implemented for every candle type of the crate and for references to any [`OHLC`] type,
+/// so both `candles.iter()` and `candles.into_iter()` may be used.
+pub trait ToCandle: Copy {
+	/// Type of the candle
+	type Candle: OHLC;
+
+	/// Returns the candle
+	fn to_candle(self) -> Self::Candle;
+}
+
+impl<T: OHLC> ToCandle for &T {
+	type Candle = T;
+
+	#[inline]
+	fn to_candle(self) -> Self::Candle {
+		*self
+	}
+}
+
+macro_rules! impl_to_candle {
+	($($t:ty),*) => {
+		$(
+			impl ToCandle for $t {
+				type Candle = Self;
+
+				#[inline]
+				fn to_candle(self) -> Self::Candle {
+					self
+				}
+			}
+		)*
+	};
+}
+
+impl_to_candle!(
+	Candle,
+	TimedCandle,
+	Trade,
+	(ValueType, ValueType, ValueType, ValueType),
+	(ValueType, ValueType, ValueType, ValueType, ValueType)
+);
+
+impl<T: OHLC> ToCandle for SourcedCandle<T> {
+	type Candle = Self;
+
+	#[inline]
+	fn to_candle(self) -> Self::Candle {
+		self
+	}
+}
+
+/// Plugs methods and indicators into iterator chains
+///
+/// Implemented for every [`Iterator`].
+///
+/// # Examples
+///
+/// ```
+/// use yata::prelude::*;
+/// use yata::helpers::RandomCandles;
+/// use yata::indicators::Trix;
+/// use yata::methods::SMA;
+///
+/// let candles: Vec<_> = RandomCandles::new().take(20).collect();
+///
+/// let results: Vec<_> = candles.iter().indicator(Trix::default()).unwrap().collect();
+/// assert_eq!(results.len(), candles.len());
+///
+/// let values = [1.0, 2.0, 3.0, 4.0];
+/// let averages: Vec<_> = values.iter().method(SMA::new(2, values[0]).unwrap()).collect();
+/// assert_eq!(averages, vec![1.0, 1.5, 2.5, 3.5]);
+/// ```
+pub trait IteratorExt: Iterator + Sized {
+	/// Creates an iterator which passes every item through the `method`
+	///
+	/// Items may be either values or references to the values of the method's input type.
+	fn method<M>(self, method: M) -> MethodIter<Self, M>
+	where
+		M: Method,
+		Self::Item: Borrow<M::Input>,
+	{
+		MethodIter { iter: self, method }
+	}
+
+	/// Creates an iterator which passes every candle through the indicator with the `config`
+	///
+	/// The indicator is initialized by the first candle, which is also the first input candle,
+	/// so the count of results is always equal to the count of candles.
+	///
+	/// Returns an error if the indicator cannot be initialized.
+	fn indicator<C>(mut self, config: C) -> Result<IndicatorIter<Self, C::Instance>, Error>
+	where
+		Self::Item: ToCandle,
+		C: IndicatorInitializer<<Self::Item as ToCandle>::Candle>,
+	{
+		let first = self.next();
+		let instance = first
+			.as_ref()
+			.map(|&item| config.init(item.to_candle()))
+			.transpose()?;
+
+		Ok(IndicatorIter {
+			iter: self,
+			first,
+			instance,
+		})
+	}
+}
+
+impl<I: Iterator> IteratorExt for I {}
+
+/// Iterator over the outputs of a method
+///
+/// Created by [`IteratorExt::method`].
+#[derive(Debug, Clone)]
+pub struct MethodIter<I, M> {
+	iter: I,
+	method: M,
+}
+
+impl<I, M> MethodIter<I, M> {
+	/// Returns a reference to the method
+	pub const fn method(&self) -> &M {
+		&self.method
+	}
+
+	/// Returns the method, dropping the rest of the input
+	#[allow(clippy::missing_const_for_fn)]
+	pub fn into_method(self) -> M {
+		self.method
+	}
+}
+
+impl<I, M> Iterator for MethodIter<I, M>
+where
+	I: Iterator,
+	I::Item: Borrow<M::Input>,
+	M: Method,
+{
+	type Item = M::Output;
+
+	#[inline]
+	fn next(&mut self) -> Option<Self::Item> {
+		let value = *self.iter.next()?.borrow();
+		Some(self.method.next(value))
+	}
+
+	#[inline]
+	fn size_hint(&self) -> (usize, Option<usize>) {
+		self.iter.size_hint()
+	}
+}
+
+/// Iterator over the results of an indicator
+///
+/// Created by [`IteratorExt::indicator`].
+#[derive(Debug, Clone)]
+pub struct IndicatorIter<I: Iterator, S> {
+	iter: I,
+	first: Option<I::Item>,
+	instance: Option<S>,
+}
+
+impl<I: Iterator, S> IndicatorIter<I, S> {
+	/// Returns a reference to the indicator **State**, or `None` if there were no candles
+	pub const fn instance(&self) -> Option<&S> {
+		self.instance.as_ref()
+	}
+
+	/// Returns the indicator **State**, dropping the rest of the input
+	#[allow(clippy::missing_const_for_fn)]
+	pub fn into_instance(self) -> Option<S> {
+		self.instance
+	}
+}
+
+impl<I, S> Iterator for IndicatorIter<I, S>
+where
+	I: Iterator,
+	I::Item: ToCandle,
+	S: IndicatorInstance<<I::Item as ToCandle>::Candle>,
+{
+	type Item = IndicatorResult;
+
+	#[inline]
+	fn next(&mut self) -> Option<Self::Item> {
+		let item = match self.first.take() {
+			Some(item) => item,
+			None => self.iter.next()?,
+		};
+
+		self.instance
+			.as_mut()
+			.map(|instance| instance.next(item.to_candle()))
+	}
+
+	#[inline]
+	fn size_hint(&self) -> (usize, Option<usize>) {
+		let first = usize::from(self.first.is_some());
+		let (lower, upper) = self.iter.size_hint();
+
+		(
+			lower.saturating_add(first),
+			upper.and_then(|upper| upper.checked_add(first)),
+		)
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	use super::IteratorExt;
+	use crate::core::{
+		IndicatorConfig, IndicatorInitializer, IndicatorInstance, Method, ValueType,
+	};
+	use crate::helpers::RandomCandles;
+	use crate::indicators::{Trix, MACD};
+	use crate::methods::{StDev, EMA};
+	use alloc::vec::Vec;
+
+	#[test]
+	fn test_method_iter() {
+		let values: Vec<ValueType> = RandomCandles::new().take(50).map(|c| c.close).collect();
+		let expected = EMA::new(5, values[0]).unwrap().over(values.iter().copied());
+
+		let by_ref: Vec<_> = values
+			.iter()
+			.method(EMA::new(5, values[0]).unwrap())
+			.collect();
+		let by_value: Vec<_> = values
+			.clone()
+			.into_iter()
+			.method(EMA::new(5, values[0]).unwrap())
+			.collect();
+
+		assert_eq!(expected.as_slice(), by_ref.as_slice());
+		assert_eq!(expected.as_slice(), by_value.as_slice());
+
+		let chained = values
+			.iter()
+			.method(EMA::new(5, values[0]).unwrap())
+			.method(StDev::new(3, values[0]).unwrap());
+		assert_eq!(chained.size_hint(), (values.len(), Some(values.len())));
+		assert_eq!(chained.count(), values.len());
+	}
+
+	#[test]
+	fn test_indicator_iter() {
+		let candles: Vec<_> = RandomCandles::new().take(50).collect();
+		let expected = MACD::default().init(candles[0]).unwrap().over(&candles);
+
+		let iter = candles.iter().indicator(MACD::default()).unwrap();
+		assert_eq!(iter.size_hint(), (candles.len(), Some(candles.len())));
+
+		let results: Vec<_> = iter.collect();
+		assert!(results
+			.iter()
+			.zip(&expected)
+			.all(|(a, b)| a.values() == b.values() && a.signals() == b.signals()));
+		assert_eq!(results.len(), expected.len());
+
+		let results: Vec<_> = candles
+			.clone()
+			.into_iter()
+			.indicator(MACD::default())
+			.unwrap()
+			.collect();
+		assert!(results
+			.iter()
+			.zip(&expected)
+			.all(|(a, b)| a.values() == b.values() && a.signals() == b.signals()));
+		assert_eq!(results.len(), expected.len());
+
+		let empty: [crate::core::Candle; 0] = [];
+		let mut iter = empty.iter().indicator(Trix::default()).unwrap();
+		assert!(iter.instance().is_none());
+		assert!(iter.next().is_none());
+
+		let mut trix = Trix::default();
+		trix.period1 = 0;
+		assert!(!trix.validate());
+		assert!(candles.iter().indicator(trix).is_err());
+	}
+}
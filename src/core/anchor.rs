@@ -0,0 +1,483 @@
+use super::{Error, Method, PeriodType, ResampleBy};
+use core::fmt;
+
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
+
+/// Trigger which splits the timeseries into periods (sessions) by firing at the first value of every new period
+///
+/// See [`Anchored`].
+pub trait Anchor: fmt::Debug {
+	/// Checks the next value at the `timestamp` (in milliseconds since the Unix epoch), if it is known
+	///
+	/// Returns `true` if the value starts a new period.
+	fn fires(&mut self, timestamp: Option<i64>) -> bool;
+
+	/// Resets the anchor state, so the next value continues the current period
+	fn reset(&mut self);
+}
+
+/// [`Anchor`] which never fires when there is no anchor
+impl<A: Anchor> Anchor for Option<A> {
+	fn fires(&mut self, timestamp: Option<i64>) -> bool {
+		self.as_mut().is_some_and(|anchor| anchor.fires(timestamp))
+	}
+
+	fn reset(&mut self) {
+		if let Some(anchor) = self {
+			anchor.reset();
+		}
+	}
+}
+
+/// [`Anchor`] which fires when any of the two anchors fires
+///
+/// When one of the anchors fires, the other one is reset, so both of them count the new period from the same value.
+/// F.e. `(ExternalAnchor, EveryBars)` fires every `count` values since the last external event.
+impl<A: Anchor, B: Anchor> Anchor for (A, B) {
+	fn fires(&mut self, timestamp: Option<i64>) -> bool {
+		if self.0.fires(timestamp) {
+			self.1.reset();
+			self.1.fires(timestamp);
+		} else if self.1.fires(timestamp) {
+			self.0.reset();
+			self.0.fires(timestamp);
+		} else {
+			return false;
+		}
+
+		true
+	}
+
+	fn reset(&mut self) {
+		self.0.reset();
+		self.1.reset();
+	}
+}
+
+/// [`Anchor`] which fires every `count` values
+///
+/// Count should be > 0.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct EveryBars {
+	count: usize,
+	passed: usize,
+}
+
+impl EveryBars {
+	/// Creates a new anchor which fires every `count` values
+	///
+	/// Returns an error if `count` is zero.
+	pub const fn new(count: usize) -> Result<Self, Error> {
+		if count == 0 {
+			return Err(Error::WrongMethodParameters);
+		}
+
+		Ok(Self { count, passed: 0 })
+	}
+
+	/// Returns count of values in the period
+	#[must_use]
+	pub const fn count(&self) -> usize {
+		self.count
+	}
+}
+
+impl Anchor for EveryBars {
+	fn fires(&mut self, _: Option<i64>) -> bool {
+		self.passed += 1;
+
+		if self.passed > self.count {
+			self.passed = 1;
+			true
+		} else {
+			false
+		}
+	}
+
+	fn reset(&mut self) {
+		self.passed = 0;
+	}
+}
+
+/// [`Anchor`] which fires when the timestamp rolls over to the next period of `duration` milliseconds, aligned to the Unix epoch
+///
+/// F.e. the duration of `86_400_000` starts a new period at every midnight UTC.
+/// Values with unknown timestamps never fire it. Timestamps must be passed in the increasing order.
+///
+/// Duration should be > 0.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct TimeBoundary {
+	duration: i64,
+	period: Option<i64>,
+}
+
+impl TimeBoundary {
+	/// Creates a new anchor with the period of `duration` milliseconds
+	///
+	/// Returns an error if `duration` is not positive.
+	pub const fn new(duration: i64) -> Result<Self, Error> {
+		if duration <= 0 {
+			return Err(Error::WrongMethodParameters);
+		}
+
+		Ok(Self {
+			duration,
+			period: None,
+		})
+	}
+
+	/// Returns the duration of the period in milliseconds
+	#[must_use]
+	pub const fn duration(&self) -> i64 {
+		self.duration
+	}
+}
+
+impl Anchor for TimeBoundary {
+	fn fires(&mut self, timestamp: Option<i64>) -> bool {
+		let Some(timestamp) = timestamp else {
+			return false;
+		};
+
+		let period = timestamp.div_euclid(self.duration);
+		let fires = self.period.is_some_and(|current| current != period);
+		self.period = Some(period);
+
+		fires
+	}
+
+	fn reset(&mut self) {
+		self.period = None;
+	}
+}
+
+/// [`Anchor`] which fires at the next value after the [`fire`](ExternalAnchor::fire) call
+///
+/// Use it when the periods are defined by the external events, f.e. by the news or by the signals of other indicators.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct ExternalAnchor {
+	fired: bool,
+}
+
+impl ExternalAnchor {
+	/// Starts a new period at the next value
+	pub const fn fire(&mut self) {
+		self.fired = true;
+	}
+}
+
+impl Anchor for ExternalAnchor {
+	fn fires(&mut self, _: Option<i64>) -> bool {
+		core::mem::take(&mut self.fired)
+	}
+
+	fn reset(&mut self) {
+		self.fired = false;
+	}
+}
+
+/// [`Anchor`] which fires by the [`ResampleBy`] rule
+///
+/// It is [`EveryBars`] for [`ResampleBy::Count`] and [`TimeBoundary`] for [`ResampleBy::Duration`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub enum SessionAnchor {
+	/// Fires every `count` values
+	Bars(EveryBars),
+
+	/// Fires when the timestamp rolls over to the next period
+	Time(TimeBoundary),
+}
+
+impl SessionAnchor {
+	/// Creates a new anchor by the `rule`
+	///
+	/// Returns an error if count or duration of the `rule` is not positive.
+	pub fn new(rule: ResampleBy) -> Result<Self, Error> {
+		match rule {
+			ResampleBy::Count(count) => EveryBars::new(count).map(Self::Bars),
+			ResampleBy::Duration(duration) => TimeBoundary::new(duration).map(Self::Time),
+		}
+	}
+}
+
+impl Anchor for SessionAnchor {
+	fn fires(&mut self, timestamp: Option<i64>) -> bool {
+		match self {
+			Self::Bars(anchor) => anchor.fires(timestamp),
+			Self::Time(anchor) => anchor.fires(timestamp),
+		}
+	}
+
+	fn reset(&mut self) {
+		match self {
+			Self::Bars(anchor) => anchor.reset(),
+			Self::Time(anchor) => anchor.reset(),
+		}
+	}
+}
+
+/// Wraps a [`Method`] and resets it every time the [`Anchor`] fires
+///
+/// The method is reset by the first value of the new period right before the value is passed into it,
+/// so f.e. anchored cumulative methods accumulate only the values of the current period.
+/// If the method cannot be reset by the value, it is tried again with the next one.
+///
+/// Timestamps are passed to the anchor only by [`next_at`](Anchored::next_at).
+///
+/// # Examples
+///
+/// ```
+/// use yata::core::{Anchored, EveryBars, ExternalAnchor};
+/// use yata::methods::Integral;
+/// use yata::prelude::*;
+///
+/// // sum over every 3 values
+/// let mut sum = Anchored::<Integral, _>::new((EveryBars::new(3).unwrap(), 0), 1.0).unwrap();
+///
+/// let sums = sum.over([1.0, 2.0, 3.0, 4.0, 5.0]);
+/// assert_eq!(sums.as_slice(), &[1.0, 3.0, 6.0, 4.0, 9.0]);
+///
+/// // sum since the last external event
+/// let mut sum = Anchored::<Integral, _>::new((ExternalAnchor::default(), 0), 1.0).unwrap();
+///
+/// assert_eq!(sum.next(1.0), 1.0);
+/// assert_eq!(sum.next(2.0), 3.0);
+///
+/// sum.anchor_mut().fire();
+/// assert_eq!(sum.next(3.0), 3.0);
+/// ```
+///
+/// ```
+/// use yata::core::{Anchored, TimeBoundary};
+/// use yata::methods::Integral;
+/// use yata::prelude::*;
+///
+/// const HOUR: i64 = 3_600_000;
+///
+/// // daily sum
+/// let anchor = TimeBoundary::new(24 * HOUR).unwrap();
+/// let mut sum = Anchored::<Integral, _>::new((anchor, 0), 1.0).unwrap();
+///
+/// assert_eq!(sum.next_at(22 * HOUR, 1.0), 1.0);
+/// assert_eq!(sum.next_at(23 * HOUR, 2.0), 3.0);
+/// assert_eq!(sum.next_at(25 * HOUR, 3.0), 3.0);
+/// ```
+///
+/// # See also
+///
+/// [`SessionVWAP`](crate::methods::SessionVWAP), [`AnchoredVWAP`](crate::indicators::AnchoredVWAP),
+/// [`PivotPoints`](crate::indicators::PivotPoints)
+#[derive(Debug, Clone)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct Anchored<M, A> {
+	method: M,
+	anchor: A,
+	pending: bool,
+}
+
+impl<M: Method, A: Anchor> Anchored<M, A> {
+	/// Returns a reference to the wrapped method
+	#[must_use]
+	pub const fn method(&self) -> &M {
+		&self.method
+	}
+
+	/// Returns a reference to the anchor
+	#[must_use]
+	pub const fn anchor(&self) -> &A {
+		&self.anchor
+	}
+
+	/// Returns a mutable reference to the anchor, f.e. to [`fire`](ExternalAnchor::fire) the [`ExternalAnchor`]
+	pub const fn anchor_mut(&mut self) -> &mut A {
+		&mut self.anchor
+	}
+
+	/// Evaluates the `value` at the `timestamp` in milliseconds since the Unix epoch
+	pub fn next_at(&mut self, timestamp: i64, value: M::Input) -> M::Output {
+		self.step(Some(timestamp), value)
+	}
+
+	fn step(&mut self, timestamp: Option<i64>, value: M::Input) -> M::Output {
+		if self.anchor.fires(timestamp) {
+			self.pending = true;
+		}
+
+		if self.pending && self.method.reset(value).is_ok() {
+			self.pending = false;
+		}
+
+		self.method.next(value)
+	}
+}
+
+impl<M: Method, A: Anchor> Method for Anchored<M, A> {
+	type Params = (A, M::Params);
+	type Input = M::Input;
+	type Output = M::Output;
+
+	fn new((anchor, params): Self::Params, value: Self::Input) -> Result<Self, Error> {
+		Ok(Self {
+			method: M::new(params, value)?,
+			anchor,
+			pending: false,
+		})
+	}
+
+	#[inline]
+	fn next(&mut self, value: Self::Input) -> Self::Output {
+		self.step(None, value)
+	}
+
+	fn min_periods(&self) -> PeriodType {
+		self.method.min_periods()
+	}
+
+	fn reset(&mut self, value: Self::Input) -> Result<(), Error> {
+		self.method.reset(value)?;
+		self.anchor.reset();
+		self.pending = false;
+
+		Ok(())
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	use super::{Anchor, Anchored, EveryBars, ExternalAnchor, SessionAnchor, TimeBoundary};
+	use crate::core::{Method, NanGuard, NanPolicy, ResampleBy, ValueType};
+	use crate::helpers::{assert_eq_float, RandomCandles};
+	use crate::methods::{Integral, SMA};
+	use alloc::vec::Vec;
+
+	#[test]
+	fn test_anchors() {
+		let mut every = EveryBars::new(3).unwrap();
+		let fires: Vec<_> = (0..10).map(|_| every.fires(None)).collect();
+		assert_eq!(
+			fires,
+			[false, false, false, true, false, false, true, false, false, true]
+		);
+
+		every.reset();
+		assert!(!every.fires(None));
+		assert!(EveryBars::new(0).is_err());
+
+		let mut boundary = TimeBoundary::new(10).unwrap();
+		assert!(!boundary.fires(Some(-3)));
+		assert!(boundary.fires(Some(2)));
+		assert!(!boundary.fires(None));
+		assert!(!boundary.fires(Some(9)));
+		assert!(boundary.fires(Some(35)));
+		boundary.reset();
+		assert!(!boundary.fires(Some(41)));
+		assert!(TimeBoundary::new(0).is_err());
+
+		let mut external = ExternalAnchor::default();
+		assert!(!external.fires(None));
+		external.fire();
+		assert!(external.fires(Some(1)));
+		assert!(!external.fires(Some(2)));
+	}
+
+	#[test]
+	fn test_combined_anchors() {
+		let mut none: Option<EveryBars> = None;
+		assert!((0..10).all(|_| !none.fires(Some(1))));
+
+		// external event starts the count of values over
+		let mut pair = (ExternalAnchor::default(), EveryBars::new(3).ok());
+		let mut fires = Vec::new();
+		for i in 0..12 {
+			if i == 5 {
+				pair.0.fire();
+			}
+			fires.push(pair.fires(None));
+		}
+		assert_eq!(
+			fires,
+			[false, false, false, true, false, true, false, false, true, false, false, true]
+		);
+
+		let mut pair = (
+			ExternalAnchor::default(),
+			SessionAnchor::new(ResampleBy::Duration(10)).unwrap(),
+		);
+		assert!(!pair.fires(Some(1)));
+		assert!(pair.fires(Some(12)));
+		pair.0.fire();
+		assert!(pair.fires(Some(15)));
+		assert!(!pair.fires(Some(19)));
+		assert!(pair.fires(Some(20)));
+
+		assert!(SessionAnchor::new(ResampleBy::Count(0)).is_err());
+		assert!(SessionAnchor::new(ResampleBy::Duration(-1)).is_err());
+	}
+
+	#[test]
+	fn test_anchored() {
+		let src: Vec<ValueType> = RandomCandles::new().take(300).map(|x| x.close).collect();
+
+		for count in [1, 2, 10, 33] {
+			let every = EveryBars::new(count).unwrap();
+			let boundary = TimeBoundary::new(count as i64 * 10).unwrap();
+
+			let mut sum = Anchored::<Integral, _>::new((every, 0), src[0]).unwrap();
+			let mut timed = Anchored::<Integral, _>::new((boundary, 0), src[0]).unwrap();
+			let mut external =
+				Anchored::<Integral, _>::new((ExternalAnchor::default(), 0), src[0]).unwrap();
+			let mut sma = Anchored::<SMA, _>::new((every, 5), src[0]).unwrap();
+			let mut sma_manual = SMA::new(5, src[0]).unwrap();
+
+			for (i, &x) in src.iter().enumerate() {
+				let start = i - i % count;
+				let expected: ValueType = src[start..=i].iter().sum();
+
+				if i > 0 && i % count == 0 {
+					external.anchor_mut().fire();
+					sma_manual.reset(x).unwrap();
+				}
+
+				assert_eq_float(expected, sum.next(x));
+				assert_eq_float(expected, timed.next_at(i as i64 * 10 + 5, x));
+				assert_eq_float(expected, external.next(x));
+				assert_eq_float(sma_manual.next(x), sma.next(x));
+			}
+
+			let expected = Anchored::<Integral, _>::new((every, 0), src[0])
+				.unwrap()
+				.over(src.iter().copied());
+
+			sum.reset(src[0]).unwrap();
+			timed.reset(src[0]).unwrap();
+
+			let first_sums = sum.over(src.iter().copied());
+			for (i, &x) in src.iter().enumerate() {
+				assert_eq_float(first_sums[i], timed.next_at(i as i64 * 10 + 5, x));
+			}
+			assert_eq!(expected.as_slice(), first_sums.as_slice());
+		}
+	}
+
+	#[test]
+	fn test_anchored_pending_reset() {
+		let mut sum = Anchored::<NanGuard<Integral>, _>::new(
+			(ExternalAnchor::default(), (NanPolicy::SubstituteLast, 0)),
+			1.0,
+		)
+		.unwrap();
+
+		assert_eq!(sum.next(1.0), 1.0);
+		sum.anchor_mut().fire();
+
+		// `NanGuard` cannot be reset by `NaN`, so it is reset by the next value
+		assert_eq!(sum.next(ValueType::NAN), 2.0);
+		assert_eq!(sum.next(2.0), 2.0);
+		assert_eq!(sum.method().invalid_count(), 0);
+	}
+}
@@ -0,0 +1,455 @@
+use super::{Error, Resampler, TimedCandle, Timestamped, ValueType, OHLC, OHLCV};
+use alloc::vec::Vec;
+
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
+
+/// Common interface of the converters from a stream of trades or candles into a stream of bars
+///
+/// Single input may complete any count of bars, so completed bars are passed into `emit` callback.
+pub trait BarConverter {
+	/// Pushes the next `input` and calls `emit` for every completed bar
+	fn push<T, F>(&mut self, input: T, emit: F)
+	where
+		T: OHLCV + Timestamped,
+		F: FnMut(TimedCandle);
+
+	/// Returns the unfinished bar if there is any
+	fn current(&self) -> Option<TimedCandle>;
+
+	/// Returns the unfinished bar if there is any and starts a new one
+	fn flush(&mut self) -> Option<TimedCandle>;
+
+	/// Pushes every input and returns all the completed bars
+	///
+	/// ```
+	/// use yata::core::{BarConverter, TickBars, Trade, ValueType};
+	///
+	/// let trades = (0..10).map(|i| Trade::new(i, 100.0 + i as ValueType, 1.0));
+	/// let bars = TickBars::new(3).unwrap().convert(trades);
+	///
+	/// assert_eq!(bars.len(), 3);
+	/// assert_eq!(bars[2].open, 106.0);
+	/// ```
+	fn convert<I>(&mut self, inputs: I) -> Vec<TimedCandle>
+	where
+		I: IntoIterator,
+		I::Item: OHLCV + Timestamped,
+	{
+		let mut bars = Vec::new();
+		for input in inputs {
+			self.push(input, |bar| bars.push(bar));
+		}
+
+		bars
+	}
+}
+
+impl BarConverter for Resampler {
+	fn push<T, F>(&mut self, input: T, mut emit: F)
+	where
+		T: OHLCV + Timestamped,
+		F: FnMut(TimedCandle),
+	{
+		if let Some(bar) = self.next(input) {
+			emit(bar);
+		}
+	}
+
+	fn current(&self) -> Option<TimedCandle> {
+		Self::current(self)
+	}
+
+	fn flush(&mut self) -> Option<TimedCandle> {
+		Self::flush(self)
+	}
+}
+
+/// Single trade with its time, price and volume
+///
+/// Implements [`OHLC`] with all the prices equal to the trade `price`, so it may be passed into any [`BarConverter`].
+#[derive(Debug, Clone, Copy, Default, PartialEq, PartialOrd)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct Trade {
+	/// Time of the trade in milliseconds since the Unix epoch
+	pub timestamp: i64,
+
+	/// Price of the trade
+	pub price: ValueType,
+
+	/// Volume of the trade
+	pub volume: ValueType,
+}
+
+impl Trade {
+	/// Creates a new `Trade`
+	#[must_use]
+	pub const fn new(timestamp: i64, price: ValueType, volume: ValueType) -> Self {
+		Self {
+			timestamp,
+			price,
+			volume,
+		}
+	}
+}
+
+impl OHLC for Trade {
+	#[inline]
+	fn open(&self) -> ValueType {
+		self.price
+	}
+
+	#[inline]
+	fn high(&self) -> ValueType {
+		self.price
+	}
+
+	#[inline]
+	fn low(&self) -> ValueType {
+		self.price
+	}
+
+	#[inline]
+	fn close(&self) -> ValueType {
+		self.price
+	}
+}
+
+impl OHLCV for Trade {
+	#[inline]
+	fn volume(&self) -> ValueType {
+		self.volume
+	}
+}
+
+impl Timestamped for Trade {
+	#[inline]
+	fn timestamp(&self) -> i64 {
+		self.timestamp
+	}
+}
+
+/// Converts a stream of trades or candles into range bars
+///
+/// Every range bar covers exactly `range` of price between its *high* and *low* and closes at one of them.
+/// The next bar opens at the close of the previous one.
+///
+/// Candles are processed as the path *open* → *low* → *high* → *close* for the rising candles
+/// and *open* → *high* → *low* → *close* for the falling ones, so a single candle may complete several bars.
+/// *Volume* of the input is fully added to the bar, which is unfinished after the input is processed.
+///
+/// # Examples
+///
+/// ```
+/// use yata::core::{BarConverter, RangeBars, Trade};
+///
+/// let mut range_bars = RangeBars::new(1.0).unwrap();
+/// let prices = [10.0, 10.5, 9.8, 10.8, 11.0, 13.5];
+/// let trades = prices.iter().zip(0..).map(|(&price, i)| Trade::new(i, price, 1.0));
+///
+/// let bars = range_bars.convert(trades);
+///
+/// assert_eq!(bars.len(), 3);
+/// assert_eq!((bars[0].open, bars[0].high, bars[0].low, bars[0].close), (10.0, 10.8, 9.8, 10.8));
+/// assert_eq!((bars[1].open, bars[1].high, bars[1].low, bars[1].close), (10.8, 11.8, 10.8, 11.8));
+/// assert_eq!((bars[2].open, bars[2].close), (11.8, 12.8));
+/// assert_eq!(range_bars.current().unwrap().close, 13.5);
+/// ```
+#[derive(Debug, Clone, Copy)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct RangeBars {
+	range: ValueType,
+	bar: Option<TimedCandle>,
+}
+
+impl RangeBars {
+	/// Creates a new `RangeBars` converter with bars of price `range`
+	///
+	/// Returns an error if `range` is not positive.
+	pub fn new(range: ValueType) -> Result<Self, Error> {
+		if !(range > 0.0 && range.is_finite()) {
+			return Err(Error::WrongMethodParameters);
+		}
+
+		Ok(Self { range, bar: None })
+	}
+
+	/// Returns price range of the bars
+	#[must_use]
+	pub const fn range(&self) -> ValueType {
+		self.range
+	}
+
+	fn visit<F: FnMut(TimedCandle)>(&mut self, timestamp: i64, price: ValueType, emit: &mut F) {
+		let Some(mut bar) = self.bar else {
+			self.bar = Some(TimedCandle::new(
+				timestamp,
+				(price, price, price, price, 0.0),
+			));
+			return;
+		};
+
+		loop {
+			let edge = if price > bar.low + self.range {
+				bar.low + self.range
+			} else if price < bar.high - self.range {
+				bar.high - self.range
+			} else {
+				bar.high = bar.high.max(price);
+				bar.low = bar.low.min(price);
+				bar.close = price;
+				break;
+			};
+
+			bar.high = bar.high.max(edge);
+			bar.low = bar.low.min(edge);
+			bar.close = edge;
+			emit(bar);
+
+			bar = TimedCandle::new(timestamp, (edge, edge, edge, edge, 0.0));
+		}
+
+		self.bar = Some(bar);
+	}
+}
+
+impl BarConverter for RangeBars {
+	fn push<T, F>(&mut self, input: T, mut emit: F)
+	where
+		T: OHLCV + Timestamped,
+		F: FnMut(TimedCandle),
+	{
+		let timestamp = input.timestamp();
+		let (first, second) = if input.close() >= input.open() {
+			(input.low(), input.high())
+		} else {
+			(input.high(), input.low())
+		};
+
+		for &price in &[input.open(), first, second, input.close()] {
+			self.visit(timestamp, price, &mut emit);
+		}
+
+		if let Some(bar) = self.bar.as_mut() {
+			bar.volume += input.volume();
+		}
+	}
+
+	fn current(&self) -> Option<TimedCandle> {
+		self.bar
+	}
+
+	fn flush(&mut self) -> Option<TimedCandle> {
+		self.bar.take()
+	}
+}
+
+/// Converts a stream of trades or candles into bars of the fixed count of inputs
+///
+/// Same as [`Resampler::by_count`].
+#[derive(Debug, Clone, Copy)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct TickBars(Resampler);
+
+impl TickBars {
+	/// Creates a new `TickBars` converter with bars of `count` inputs
+	///
+	/// Returns an error if `count` is zero.
+	pub fn new(count: usize) -> Result<Self, Error> {
+		Resampler::by_count(count).map(Self)
+	}
+}
+
+impl BarConverter for TickBars {
+	fn push<T, F>(&mut self, input: T, emit: F)
+	where
+		T: OHLCV + Timestamped,
+		F: FnMut(TimedCandle),
+	{
+		self.0.push(input, emit);
+	}
+
+	fn current(&self) -> Option<TimedCandle> {
+		self.0.current()
+	}
+
+	fn flush(&mut self) -> Option<TimedCandle> {
+		self.0.flush()
+	}
+}
+
+/// Converts a stream of trades or candles into bars of the fixed *volume*
+///
+/// The bar is completed by the input, which makes the accumulated *volume* reach `volume`.
+/// Inputs are never split between bars, so the *volume* of the bar may be greater than `volume`.
+///
+/// # Examples
+///
+/// ```
+/// use yata::core::{BarConverter, Trade, VolumeBars};
+///
+/// let volumes = [3.0, 4.0, 5.0, 1.0, 9.0, 2.0];
+/// let trades = volumes.iter().zip(0..).map(|(&volume, i)| Trade::new(i, 100.0, volume));
+///
+/// let mut volume_bars = VolumeBars::new(10.0).unwrap();
+/// let bars = volume_bars.convert(trades);
+///
+/// assert_eq!(bars.len(), 2);
+/// assert_eq!((bars[0].timestamp, bars[0].volume), (0, 12.0));
+/// assert_eq!((bars[1].timestamp, bars[1].volume), (3, 10.0));
+/// assert_eq!(volume_bars.current().unwrap().volume, 2.0);
+/// ```
+#[derive(Debug, Clone, Copy)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct VolumeBars {
+	volume: ValueType,
+	bar: Option<TimedCandle>,
+}
+
+impl VolumeBars {
+	/// Creates a new `VolumeBars` converter with bars of `volume`
+	///
+	/// Returns an error if `volume` is not positive.
+	pub fn new(volume: ValueType) -> Result<Self, Error> {
+		if !(volume > 0.0 && volume.is_finite()) {
+			return Err(Error::WrongMethodParameters);
+		}
+
+		Ok(Self { volume, bar: None })
+	}
+
+	/// Returns *volume* of the bars
+	#[must_use]
+	pub const fn volume(&self) -> ValueType {
+		self.volume
+	}
+}
+
+impl BarConverter for VolumeBars {
+	fn push<T, F>(&mut self, input: T, mut emit: F)
+	where
+		T: OHLCV + Timestamped,
+		F: FnMut(TimedCandle),
+	{
+		let bar = self.bar.map_or_else(
+			|| TimedCandle::new(input.timestamp(), input),
+			|bar| TimedCandle {
+				high: bar.high.max(input.high()),
+				low: bar.low.min(input.low()),
+				close: input.close(),
+				volume: bar.volume + input.volume(),
+				..bar
+			},
+		);
+
+		if bar.volume >= self.volume {
+			self.bar = None;
+			emit(bar);
+		} else {
+			self.bar = Some(bar);
+		}
+	}
+
+	fn current(&self) -> Option<TimedCandle> {
+		self.bar
+	}
+
+	fn flush(&mut self) -> Option<TimedCandle> {
+		self.bar.take()
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	use super::{BarConverter, RangeBars, TickBars, Trade, VolumeBars};
+	use crate::core::{Candle, Resampler, TimedCandle, ValueType, OHLCV};
+	use crate::helpers::{assert_eq_float, RandomCandles};
+	use alloc::vec::Vec;
+
+	fn timed_candles() -> Vec<TimedCandle> {
+		(0..)
+			.zip(RandomCandles::new().take(200))
+			.map(|(i, candle): (i64, Candle)| TimedCandle::new(i, candle))
+			.collect()
+	}
+
+	#[test]
+	fn test_range_bars() {
+		let candles = timed_candles();
+
+		for &range in &[0.5, 1.0, 2.5, 10.0] {
+			let mut converter = RangeBars::new(range).unwrap();
+			let mut bars = converter.convert(candles.iter().copied());
+			bars.extend(converter.flush());
+
+			let (last, completed) = bars.split_last().unwrap();
+			assert!(last.high - last.low <= range);
+
+			for pair in completed.windows(2) {
+				assert_eq_float(pair[0].close, pair[1].open);
+			}
+
+			for bar in completed {
+				assert_eq_float(range, bar.high - bar.low);
+				assert!(bar.close >= bar.high || bar.close <= bar.low);
+			}
+
+			let volume: ValueType = candles.iter().map(OHLCV::volume).sum();
+			let volume2: ValueType = bars.iter().map(|bar| bar.volume).sum();
+			assert_eq_float(volume, volume2);
+		}
+
+		assert!(RangeBars::new(0.0).is_err());
+		assert!(RangeBars::new(ValueType::NAN).is_err());
+	}
+
+	#[test]
+	fn test_tick_bars() {
+		let candles = timed_candles();
+
+		let bars = TickBars::new(7).unwrap().convert(candles.iter().copied());
+		let expected = Resampler::by_count(7)
+			.unwrap()
+			.convert(candles.iter().copied());
+
+		assert_eq!(bars, expected);
+		assert_eq!(bars.len(), candles.len() / 7);
+	}
+
+	#[test]
+	fn test_volume_bars() {
+		let trades: Vec<_> = timed_candles()
+			.iter()
+			.map(|candle| Trade::new(candle.timestamp, candle.close, candle.volume))
+			.collect();
+
+		for &volume in &[1.0, 5.0, 30.0] {
+			let mut converter = VolumeBars::new(volume).unwrap();
+			let bars = converter.convert(trades.iter().copied());
+
+			let mut start = 0;
+			for bar in &bars {
+				// count of trades, which are needed to reach the volume
+				let count = trades[start..]
+					.iter()
+					.scan(0.0, |sum, trade| {
+						let before = *sum;
+						*sum += trade.volume;
+						Some(before)
+					})
+					.take_while(|&before| before < volume)
+					.count();
+				let end = start + count;
+				let sum: ValueType = trades[start..end].iter().map(|trade| trade.volume).sum();
+
+				assert_eq!(bar.timestamp, trades[start].timestamp);
+				assert_eq_float(sum, bar.volume);
+				start = end;
+			}
+
+			assert!(converter.current().map_or(0.0, |bar| bar.volume) < volume);
+		}
+
+		assert!(VolumeBars::new(-1.0).is_err());
+	}
+}
@@ -0,0 +1,213 @@
+use super::{Candle, ValueType, OHLCV};
+
+#[cfg(not(feature = "std"))]
+use crate::core::Float as _;
+
+impl Candle {
+	/// Merges the `candles` into a single candle
+	///
+	/// *Open* is taken from the first candle, *close* from the last one,
+	/// *high* and *low* are the extremes over all the candles and *volume* is the sum of all the volumes.
+	///
+	/// Returns `None` if there are no candles.
+	///
+	/// # Examples
+	///
+	/// ```
+	/// use yata::core::Candle;
+	///
+	/// let candles = [
+	///     Candle::from((3.0, 5.0, 2.0, 4.0, 10.0)),
+	///     Candle::from((4.0, 7.0, 3.0, 6.0, 20.0)),
+	/// ];
+	///
+	/// let merged = Candle::merge(&candles).unwrap();
+	/// assert_eq!(merged, Candle::from((3.0, 7.0, 2.0, 6.0, 30.0)));
+	///
+	/// assert!(Candle::merge(&candles[..0]).is_none());
+	/// ```
+	pub fn merge<'a, T, I>(candles: I) -> Option<Self>
+	where
+		T: OHLCV + 'a,
+		I: IntoIterator<Item = &'a T>,
+	{
+		let mut candles = candles.into_iter();
+		let first = Self::from_candle(candles.next()?);
+
+		Some(candles.fold(first, |merged, candle| Self {
+			high: merged.high.max(candle.high()),
+			low: merged.low.min(candle.low()),
+			close: candle.close(),
+			volume: merged.volume + candle.volume(),
+			..merged
+		}))
+	}
+
+	/// Adjusts the candle for the split of the instrument by the `ratio`
+	///
+	/// Prices are divided by the `ratio` and the volume is multiplied by it,
+	/// so f.e. `ratio` of `2.0` means a 2-for-1 split and `0.1` means a 1-for-10 reverse split.
+	///
+	/// # Examples
+	///
+	/// ```
+	/// use yata::core::Candle;
+	///
+	/// let candle = Candle::from((30.0, 50.0, 20.0, 40.0, 10.0));
+	/// assert_eq!(candle.split(2.0), Candle::from((15.0, 25.0, 10.0, 20.0, 20.0)));
+	/// ```
+	#[must_use]
+	pub fn split(self, ratio: ValueType) -> Self {
+		Self {
+			open: self.open / ratio,
+			high: self.high / ratio,
+			low: self.low / ratio,
+			close: self.close / ratio,
+			volume: self.volume * ratio,
+		}
+	}
+
+	/// Creates a synthetic spread candle `a - k * b` of two instruments over the same period
+	///
+	/// *Open* and *close* are exact. The path of the spread inside the period is unknown, so *high* and *low*
+	/// are taken from the spreads of highs and lows of the legs and then extended to cover *open* and *close*.
+	/// *Volume* is the minimum of the volumes of the legs.
+	///
+	/// # Examples
+	///
+	/// ```
+	/// use yata::core::Candle;
+	/// use yata::prelude::*;
+	///
+	/// let a = Candle::from((100.0, 104.0, 99.0, 103.0, 10.0));
+	/// let b = Candle::from((50.0, 51.0, 49.0, 50.0, 30.0));
+	///
+	/// let spread = Candle::spread(&a, &b, 2.0);
+	/// assert_eq!(spread.open(), 0.0);
+	/// assert_eq!(spread.close(), 3.0);
+	/// assert_eq!(spread.high(), 3.0);
+	/// assert_eq!(spread.low(), 0.0);
+	/// assert_eq!(spread.volume(), 10.0);
+	/// ```
+	pub fn spread<A: OHLCV, B: OHLCV>(a: &A, b: &B, k: ValueType) -> Self {
+		Self::synthetic(a, b, |a, b| k.mul_add(-b, a))
+	}
+
+	/// Creates a synthetic ratio candle `a / b` of two instruments over the same period
+	///
+	/// *High*, *low* and *volume* are evaluated the same way as in [`spread`](Candle::spread).
+	///
+	/// # Examples
+	///
+	/// ```
+	/// use yata::core::Candle;
+	/// use yata::prelude::*;
+	///
+	/// let a = Candle::from((100.0, 104.0, 99.0, 103.0, 10.0));
+	/// let b = Candle::from((50.0, 52.0, 49.5, 51.5, 30.0));
+	///
+	/// let ratio = Candle::ratio(&a, &b);
+	/// assert_eq!(ratio.open(), 2.0);
+	/// assert_eq!(ratio.close(), 2.0);
+	/// assert_eq!(ratio.volume(), 10.0);
+	/// assert!(OHLC::validate(&ratio));
+	/// ```
+	pub fn ratio<A: OHLCV, B: OHLCV>(a: &A, b: &B) -> Self {
+		Self::synthetic(a, b, |a, b| a / b)
+	}
+
+	fn from_candle<T: OHLCV>(candle: &T) -> Self {
+		Self {
+			open: candle.open(),
+			high: candle.high(),
+			low: candle.low(),
+			close: candle.close(),
+			volume: candle.volume(),
+		}
+	}
+
+	fn synthetic<A, B, F>(a: &A, b: &B, f: F) -> Self
+	where
+		A: OHLCV,
+		B: OHLCV,
+		F: Fn(ValueType, ValueType) -> ValueType,
+	{
+		let open = f(a.open(), b.open());
+		let close = f(a.close(), b.close());
+		let highs = f(a.high(), b.high());
+		let lows = f(a.low(), b.low());
+
+		Self {
+			open,
+			high: open.max(close).max(highs).max(lows),
+			low: open.min(close).min(highs).min(lows),
+			close,
+			volume: a.volume().min(b.volume()),
+		}
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	use crate::core::{Candle, TimedCandle, OHLC, OHLCV};
+	use crate::helpers::{assert_eq_float, RandomCandles};
+	use alloc::vec::Vec;
+
+	#[test]
+	fn test_candle_merge() {
+		let candles: Vec<_> = RandomCandles::new().take(20).collect();
+
+		for length in 1..candles.len() {
+			let slice = &candles[..length];
+			let merged = Candle::merge(slice).unwrap();
+
+			assert_eq!(merged.open, slice[0].open);
+			assert_eq!(merged.close, slice[length - 1].close);
+			assert!(slice
+				.iter()
+				.all(|c| c.high <= merged.high && c.low >= merged.low));
+			assert_eq_float(slice.iter().map(|c| c.volume).sum(), merged.volume);
+			assert!(OHLCV::validate(&merged));
+		}
+
+		let timed: Vec<_> = candles
+			.iter()
+			.enumerate()
+			.map(|(i, &c)| TimedCandle::new(i as i64, c))
+			.collect();
+		assert_eq!(Candle::merge(&timed), Candle::merge(&candles));
+	}
+
+	#[test]
+	fn test_candle_split() {
+		for candle in RandomCandles::new().take(20) {
+			let split = candle.split(4.0);
+
+			assert_eq_float(candle.tp(), split.tp() * 4.0);
+			assert_eq_float(candle.volumed_price(), split.volumed_price());
+			assert_eq!(split.split(0.25), candle);
+		}
+	}
+
+	#[test]
+	fn test_synthetic_candles() {
+		let a: Vec<_> = RandomCandles::new().take(50).collect();
+		let b: Vec<_> = RandomCandles::new().skip(50).take(50).collect();
+
+		for (a, b) in a.iter().zip(&b) {
+			for k in [-1.5, 0.0, 0.5, 2.0] {
+				let spread = Candle::spread(a, b, k);
+				assert_eq_float(a.open - k * b.open, spread.open);
+				assert_eq_float(a.close - k * b.close, spread.close);
+				assert!(spread.high >= spread.open.max(spread.close));
+				assert!(spread.low <= spread.open.min(spread.close));
+				assert!(spread.high >= spread.low);
+			}
+
+			let ratio = Candle::ratio(a, b);
+			assert_eq_float(a.close / b.close, ratio.close);
+			assert_eq!(ratio.volume, a.volume.min(b.volume));
+			assert!(OHLC::validate(&ratio));
+		}
+	}
+}
@@ -1,10 +1,14 @@
 #[cfg(feature = "serde")]
 use serde::{Deserialize, Serialize};
 
-use std::convert::TryFrom;
-use std::str::FromStr;
+use alloc::string::String;
+use alloc::string::ToString;
+use core::convert::TryFrom;
+use core::fmt;
+use core::ops::Deref;
+use core::str::FromStr;
 
-use crate::core::{Error, Sequence, ValueType, OHLC, OHLCV};
+use crate::core::{Error, Sequence, Timestamped, ValueType, OHLC, OHLCV};
 
 /// Source enum represents common parts of a *Candle*
 #[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd)]
@@ -29,11 +33,24 @@ pub enum Source {
 	/// Typical price of a candle
 	TP,
 
+	/// (*Open*+*High*+*Low*+*Close*)/4 part of a candle
+	OHLC4,
+
+	/// Weighted close price of a candle: (*High*+*Low*+2\**Close*)/4
+	WClose,
+
 	/// *Volume* part of a candle
+	///
+	/// Available only for the candles implementing [`OHLCV`], otherwise `NaN` is returned.
 	Volume,
 
 	/// Same as `typical price * volume`
 	VolumedPrice,
+
+	/// User defined value of a candle
+	///
+	/// Available only for the [`SourcedCandle`], otherwise `NaN` is returned.
+	Custom,
 }
 
 impl FromStr for Source {
@@ -47,14 +64,37 @@ impl FromStr for Source {
 			"volume" => Ok(Self::Volume),
 			"tp" => Ok(Self::TP),
 			"hl2" => Ok(Self::HL2),
+			"ohlc4" => Ok(Self::OHLC4),
+			"wclose" => Ok(Self::WClose),
 			"open" => Ok(Self::Open),
 			"volumed_price" => Ok(Self::VolumedPrice),
+			"custom" => Ok(Self::Custom),
 
 			value => Err(Error::SourceParse(value.to_string())),
 		}
 	}
 }
 
+impl fmt::Display for Source {
+	fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+		let name = match self {
+			Self::Close => "close",
+			Self::Open => "open",
+			Self::High => "high",
+			Self::Low => "low",
+			Self::HL2 => "hl2",
+			Self::TP => "tp",
+			Self::OHLC4 => "ohlc4",
+			Self::WClose => "wclose",
+			Self::Volume => "volume",
+			Self::VolumedPrice => "volumed_price",
+			Self::Custom => "custom",
+		};
+
+		f.write_str(name)
+	}
+}
+
 impl TryFrom<&str> for Source {
 	type Error = Error;
 
@@ -178,5 +218,289 @@ impl PartialEq for Candle {
 
 impl Eq for Candle {}
 
+/// Candlestick structure with the time of its period
+///
+/// Implements [`OHLC`], [`OHLCV`] and [`Timestamped`]. `timestamp` is a count of milliseconds since the Unix epoch.
+///
+/// ```
+/// use yata::core::{Candle, TimedCandle, Timestamped};
+/// use yata::prelude::*;
+///
+/// let candle: Candle = (3.0, 5.0, 2.0, 4.0, 50.0).into();
+/// let timed = TimedCandle::new(1_600_000_000_000, candle);
+///
+/// assert_eq!(timed.timestamp(), 1_600_000_000_000);
+/// assert_eq!(timed.close(), 4.0);
+/// assert_eq!(timed.volume(), 50.0);
+/// assert_eq!(Candle::from(timed), candle);
+/// ```
+#[derive(Debug, Clone, Copy, Default, PartialOrd)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct TimedCandle {
+	/// Time of the beginning of the candle in milliseconds since the Unix epoch
+	pub timestamp: i64,
+
+	/// *Open* value of the candle
+	pub open: ValueType,
+
+	/// *High* value of the candle
+	pub high: ValueType,
+
+	/// *Low* value of the candle
+	pub low: ValueType,
+
+	/// *Close* value of the candle
+	pub close: ValueType,
+
+	/// *Volume* value of the candle
+	pub volume: ValueType,
+}
+
+impl TimedCandle {
+	/// Creates a new `TimedCandle` from the `candle` values at the `timestamp`
+	pub fn new<T: OHLCV>(timestamp: i64, candle: T) -> Self {
+		Self {
+			timestamp,
+			open: candle.open(),
+			high: candle.high(),
+			low: candle.low(),
+			close: candle.close(),
+			volume: candle.volume(),
+		}
+	}
+}
+
+impl OHLC for TimedCandle {
+	#[inline]
+	fn open(&self) -> ValueType {
+		self.open
+	}
+
+	#[inline]
+	fn high(&self) -> ValueType {
+		self.high
+	}
+
+	#[inline]
+	fn low(&self) -> ValueType {
+		self.low
+	}
+
+	#[inline]
+	fn close(&self) -> ValueType {
+		self.close
+	}
+}
+
+impl OHLCV for TimedCandle {
+	#[inline]
+	fn volume(&self) -> ValueType {
+		self.volume
+	}
+}
+
+impl Timestamped for TimedCandle {
+	#[inline]
+	fn timestamp(&self) -> i64 {
+		self.timestamp
+	}
+}
+
+impl From<(i64, Candle)> for TimedCandle {
+	fn from(value: (i64, Candle)) -> Self {
+		Self::new(value.0, value.1)
+	}
+}
+
+impl From<TimedCandle> for Candle {
+	fn from(value: TimedCandle) -> Self {
+		Self {
+			open: value.open,
+			high: value.high,
+			low: value.low,
+			close: value.close,
+			volume: value.volume,
+		}
+	}
+}
+
+impl PartialEq for TimedCandle {
+	fn eq(&self, other: &Self) -> bool {
+		self.timestamp == other.timestamp && Candle::from(*self) == Candle::from(*other)
+	}
+}
+
+impl Eq for TimedCandle {}
+
+/// Candle wrapper, which is guaranteed to pass [`OHLCV::validate`]
+///
+/// Values of the candle are checked once when it is wrapped, so data bugs of the feed
+/// are surfaced as [`Error::InvalidCandles`] instead of a mysterious indicator output.
+///
+/// The wrapped candle is accessible through [`Deref`], so it may be passed to indicators as `*strict`.
+///
+/// ```
+/// use yata::core::{Candle, StrictCandle, ValueType};
+/// use yata::prelude::*;
+///
+/// let candle: Candle = (3.0, 5.0, 2.0, 4.0, 50.0).into();
+/// let strict = StrictCandle::new(candle).unwrap();
+/// assert_eq!(strict.close(), 4.0);
+/// assert_eq!(*strict, candle);
+/// assert_eq!(strict.into_inner(), candle);
+///
+/// // open is more than high
+/// assert!(StrictCandle::new(Candle { open: 6.0, ..candle }).is_err());
+/// // negative volume
+/// assert!(StrictCandle::new(Candle { volume: -1.0, ..candle }).is_err());
+/// // not finite value
+/// assert!(StrictCandle::new(Candle { low: ValueType::NAN, ..candle }).is_err());
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(Serialize), serde(transparent))]
+pub struct StrictCandle<T>(T);
+
+impl<T: OHLCV> StrictCandle<T> {
+	/// Wraps the `candle` if it passes [`OHLCV::validate`], otherwise returns [`Error::InvalidCandles`]
+	pub fn new(candle: T) -> Result<Self, Error> {
+		if OHLCV::validate(&candle) {
+			Ok(Self(candle))
+		} else {
+			Err(Error::InvalidCandles)
+		}
+	}
+
+	/// Returns a reference to the wrapped candle
+	pub const fn candle(&self) -> &T {
+		&self.0
+	}
+
+	/// Unwraps the candle
+	pub const fn into_inner(self) -> T {
+		self.0
+	}
+}
+
+impl<T> Deref for StrictCandle<T> {
+	type Target = T;
+
+	#[inline]
+	fn deref(&self) -> &Self::Target {
+		&self.0
+	}
+}
+
+impl From<StrictCandle<Self>> for Candle {
+	fn from(value: StrictCandle<Self>) -> Self {
+		value.0
+	}
+}
+
+impl From<StrictCandle<Self>> for TimedCandle {
+	fn from(value: StrictCandle<Self>) -> Self {
+		value.0
+	}
+}
+
+/// Candle wrapper, which carries a user defined value for [`Source::Custom`]
+///
+/// Any indicator with the `source` parameter may be driven by an arbitrary function of the candle,
+/// f.e. mid-price of the bid and the ask or a synthetic spread. Just wrap every candle and set the `source` to [`Source::Custom`].
+/// All the other values are taken from the wrapped candle.
+///
+/// ```
+/// use yata::core::{Candle, SourcedCandle, Source};
+/// use yata::helpers::RandomCandles;
+/// use yata::indicators::MACD;
+/// use yata::prelude::*;
+///
+/// let mid = |candle: &Candle| (candle.high + candle.close) / 2.0;
+/// let candles: Vec<_> = RandomCandles::new()
+///     .take(20)
+///     .map(|candle| SourcedCandle::new(candle, mid))
+///     .collect();
+///
+/// let macd = MACD { source: Source::Custom, ..MACD::default() };
+/// let results = macd.over(&candles).unwrap();
+///
+/// let closes: Vec<_> = candles.iter().map(|c| Candle { close: c.custom, ..c.candle }).collect();
+/// let expected = MACD::default().over(&closes).unwrap();
+///
+/// assert!(results.iter().zip(&expected).all(|(a, b)| a.values() == b.values()));
+/// assert!(OHLC::source(&candles[0].candle, Source::Custom).is_nan());
+/// ```
+#[derive(Debug, Clone, Copy, Default, PartialEq)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct SourcedCandle<T> {
+	/// Wrapped candle
+	pub candle: T,
+
+	/// Value returned for [`Source::Custom`]
+	pub custom: ValueType,
+}
+
+impl<T> SourcedCandle<T> {
+	/// Wraps the `candle` with the value of the function `f` of it
+	pub fn new<F>(candle: T, f: F) -> Self
+	where
+		F: FnOnce(&T) -> ValueType,
+	{
+		let custom = f(&candle);
+		Self { candle, custom }
+	}
+}
+
+impl<T: OHLC> OHLC for SourcedCandle<T> {
+	#[inline]
+	fn open(&self) -> ValueType {
+		self.candle.open()
+	}
+
+	#[inline]
+	fn high(&self) -> ValueType {
+		self.candle.high()
+	}
+
+	#[inline]
+	fn low(&self) -> ValueType {
+		self.candle.low()
+	}
+
+	#[inline]
+	fn close(&self) -> ValueType {
+		self.candle.close()
+	}
+
+	#[inline]
+	fn source(&self, source: Source) -> ValueType {
+		match source {
+			Source::Custom => self.custom,
+			_ => OHLC::source(&self.candle, source),
+		}
+	}
+}
+
+impl<T: OHLCV> OHLCV for SourcedCandle<T> {
+	#[inline]
+	fn volume(&self) -> ValueType {
+		self.candle.volume()
+	}
+
+	#[inline]
+	fn source(&self, source: Source) -> ValueType {
+		match source {
+			Source::Custom => self.custom,
+			_ => OHLCV::source(&self.candle, source),
+		}
+	}
+}
+
+impl<T: Timestamped> Timestamped for SourcedCandle<T> {
+	#[inline]
+	fn timestamp(&self) -> i64 {
+		self.candle.timestamp()
+	}
+}
+
 /// Just an alias for the Sequence of any `T`
 pub type Candles<T> = Sequence<T>;
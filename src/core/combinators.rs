@@ -0,0 +1,306 @@
+use super::{Error, Method, PeriodType, ValueType};
+use alloc::vec::Vec;
+use core::fmt;
+
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
+
+/// Chain of two methods, where output of the first method is an input of the second one
+///
+/// Initial value of the second method is the output of the first method for the initial value.
+///
+/// Created by [`Method::then`].
+///
+/// # Examples
+///
+/// ```
+/// use yata::core::Then;
+/// use yata::methods::{Derivative, EMA};
+/// use yata::prelude::*;
+///
+/// let mut slope = EMA::new(3, 1.0).unwrap().then(Derivative::new(1, 1.0).unwrap());
+///
+/// assert_eq!(slope.next(3.0), 1.0);
+///
+/// // the same chain may be created from the parameters
+/// let mut slope = Then::<EMA, Derivative>::new((3, 1), 1.0).unwrap();
+///
+/// assert_eq!(slope.next(3.0), 1.0);
+/// ```
+#[derive(Debug, Clone)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct Then<A, B> {
+	first: A,
+	second: B,
+}
+
+impl<A, B> Then<A, B> {
+	pub(super) const fn from_parts(first: A, second: B) -> Self {
+		Self { first, second }
+	}
+
+	/// Returns a reference to the first method of the chain
+	pub const fn first(&self) -> &A {
+		&self.first
+	}
+
+	/// Returns a reference to the second method of the chain
+	pub const fn second(&self) -> &B {
+		&self.second
+	}
+}
+
+impl<A, B> Method for Then<A, B>
+where
+	A: Method + Clone,
+	B: Method<Input = A::Output>,
+{
+	type Params = (A::Params, B::Params);
+	type Input = A::Input;
+	type Output = B::Output;
+
+	fn new((first, second): Self::Params, value: Self::Input) -> Result<Self, Error> {
+		let first = A::new(first, value)?;
+		let second = B::new(second, first.peek(value))?;
+
+		Ok(Self { first, second })
+	}
+
+	#[inline]
+	fn next(&mut self, value: Self::Input) -> Self::Output {
+		self.second.next(self.first.next(value))
+	}
+
+	fn min_periods(&self) -> PeriodType {
+		self.first
+			.min_periods()
+			.saturating_add(self.second.min_periods())
+			.saturating_sub(1)
+	}
+
+	fn reset(&mut self, value: Self::Input) -> Result<(), Error> {
+		self.first.reset(value)?;
+		self.second.reset(self.first.peek(value))
+	}
+
+	fn save_undo(&self, stack: &mut Vec<ValueType>) -> bool {
+		self.first.save_undo(stack) && self.second.save_undo(stack)
+	}
+
+	fn restore_undo(&mut self, stack: &mut Vec<ValueType>) {
+		self.second.restore_undo(stack);
+		self.first.restore_undo(stack);
+	}
+}
+
+/// Pair of methods over the same input, which outputs a tuple of both outputs
+///
+/// Created by [`Method::zip`].
+///
+/// # Examples
+///
+/// ```
+/// use yata::methods::{SMA, StDev};
+/// use yata::prelude::*;
+///
+/// let mut bands = SMA::new(3, 1.0).unwrap().zip(StDev::new(3, 1.0).unwrap());
+///
+/// bands.next(1.0);
+/// let (middle, deviation) = bands.next(4.0);
+///
+/// assert_eq!(middle, 2.0);
+/// assert!(deviation > 0.0);
+/// ```
+#[derive(Debug, Clone)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct Zip<A, B> {
+	first: A,
+	second: B,
+}
+
+impl<A, B> Zip<A, B> {
+	pub(super) const fn from_parts(first: A, second: B) -> Self {
+		Self { first, second }
+	}
+
+	/// Returns a reference to the first method of the pair
+	pub const fn first(&self) -> &A {
+		&self.first
+	}
+
+	/// Returns a reference to the second method of the pair
+	pub const fn second(&self) -> &B {
+		&self.second
+	}
+}
+
+impl<A, B> Method for Zip<A, B>
+where
+	A: Method,
+	B: Method<Input = A::Input>,
+{
+	type Params = (A::Params, B::Params);
+	type Input = A::Input;
+	type Output = (A::Output, B::Output);
+
+	fn new((first, second): Self::Params, value: Self::Input) -> Result<Self, Error> {
+		Ok(Self {
+			first: A::new(first, value)?,
+			second: B::new(second, value)?,
+		})
+	}
+
+	#[inline]
+	fn next(&mut self, value: Self::Input) -> Self::Output {
+		(self.first.next(value), self.second.next(value))
+	}
+
+	fn min_periods(&self) -> PeriodType {
+		self.first.min_periods().max(self.second.min_periods())
+	}
+
+	fn reset(&mut self, value: Self::Input) -> Result<(), Error> {
+		self.first.reset(value)?;
+		self.second.reset(value)
+	}
+
+	fn save_undo(&self, stack: &mut Vec<ValueType>) -> bool {
+		self.first.save_undo(stack) && self.second.save_undo(stack)
+	}
+
+	fn restore_undo(&mut self, stack: &mut Vec<ValueType>) {
+		self.second.restore_undo(stack);
+		self.first.restore_undo(stack);
+	}
+}
+
+/// Method, which output is transformed by the function
+///
+/// Created by [`Method::map`].
+///
+/// # Examples
+///
+/// ```
+/// use yata::core::{Map, ValueType};
+/// use yata::methods::{SMA, StDev};
+/// use yata::prelude::*;
+///
+/// let mut upper = SMA::new(3, 1.0)
+///     .unwrap()
+///     .zip(StDev::new(3, 1.0).unwrap())
+///     .map(|(middle, deviation)| middle + 2.0 * deviation);
+///
+/// assert_eq!(upper.next(1.0), 1.0);
+///
+/// // the same method may be created from the parameters
+/// let mut double = Map::<SMA, _>::new((2, |x: ValueType| x * 2.0), 1.0).unwrap();
+///
+/// assert_eq!(double.next(3.0), 4.0);
+/// ```
+#[derive(Clone)]
+pub struct Map<M, F> {
+	method: M,
+	f: F,
+}
+
+impl<M, F> Map<M, F> {
+	pub(super) const fn from_parts(method: M, f: F) -> Self {
+		Self { method, f }
+	}
+
+	/// Returns a reference to the wrapped method
+	pub const fn method(&self) -> &M {
+		&self.method
+	}
+}
+
+impl<M: fmt::Debug, F> fmt::Debug for Map<M, F> {
+	fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+		f.debug_struct("Map")
+			.field("method", &self.method)
+			.finish_non_exhaustive()
+	}
+}
+
+impl<M, F, O> Method for Map<M, F>
+where
+	M: Method,
+	F: Fn(M::Output) -> O,
+	O: Copy,
+{
+	type Params = (M::Params, F);
+	type Input = M::Input;
+	type Output = O;
+
+	fn new((params, f): Self::Params, value: Self::Input) -> Result<Self, Error> {
+		Ok(Self {
+			method: M::new(params, value)?,
+			f,
+		})
+	}
+
+	#[inline]
+	fn next(&mut self, value: Self::Input) -> Self::Output {
+		(self.f)(self.method.next(value))
+	}
+
+	fn min_periods(&self) -> PeriodType {
+		self.method.min_periods()
+	}
+
+	fn reset(&mut self, value: Self::Input) -> Result<(), Error> {
+		self.method.reset(value)
+	}
+
+	fn save_undo(&self, stack: &mut Vec<ValueType>) -> bool {
+		self.method.save_undo(stack)
+	}
+
+	fn restore_undo(&mut self, stack: &mut Vec<ValueType>) {
+		self.method.restore_undo(stack);
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	use super::{Map, Then, Zip};
+	use crate::core::{Method, ValueType};
+	use crate::helpers::{assert_eq_float, RandomCandles};
+	use crate::methods::{Derivative, StDev, EMA, SMA};
+	use alloc::vec::Vec;
+
+	#[test]
+	fn test_combinators() {
+		let values: Vec<ValueType> = RandomCandles::new().take(100).map(|c| c.close).collect();
+
+		let mut then = Then::<EMA, Derivative>::new((5, 2), values[0]).unwrap();
+		let mut zip = Zip::<SMA, StDev>::new((4, 6), values[0]).unwrap();
+		let mut map = Map::<EMA, _>::new((5, |x: ValueType| -x), values[0]).unwrap();
+
+		let mut ema = EMA::new(5, values[0]).unwrap();
+		let mut derivative = Derivative::new(2, values[0]).unwrap();
+		let mut sma = SMA::new(4, values[0]).unwrap();
+		let mut st_dev = StDev::new(6, values[0]).unwrap();
+
+		for (i, &value) in values.iter().enumerate() {
+			if i == 50 {
+				then.reset(value).unwrap();
+				zip.reset(value).unwrap();
+				map.reset(value).unwrap();
+
+				ema = EMA::new(5, value).unwrap();
+				derivative = Derivative::new(2, value).unwrap();
+				sma = SMA::new(4, value).unwrap();
+				st_dev = StDev::new(6, value).unwrap();
+			}
+
+			let ema_value = ema.next(value);
+			assert_eq_float(derivative.next(ema_value), then.next(value));
+			assert_eq_float(-ema_value, map.next(value));
+
+			let (a, b) = zip.next(value);
+			assert_eq_float(sma.next(value), a);
+			assert_eq_float(st_dev.next(value), b);
+		}
+	}
+}
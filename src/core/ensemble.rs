@@ -0,0 +1,238 @@
+use super::{Action, BoxedIndicatorInstance, Error, ValueType, OHLC};
+use alloc::string::String;
+use alloc::vec::Vec;
+
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
+
+/// Rule of merging signals of the [`Ensemble`] members
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub enum Vote {
+	/// Every member with a signal has a single vote, weights are ignored
+	///
+	/// Returns [`Action::BUY_ALL`] if there are more buy signals than sell signals,
+	/// [`Action::SELL_ALL`] if there are more sell signals and [`Action::None`] otherwise.
+	Majority,
+
+	/// Weighted average of the signals [ratios](Action::ratio)
+	///
+	/// See [`Action::weighted`].
+	Weighted,
+}
+
+#[derive(Debug)]
+struct Member<T: OHLC> {
+	name: String,
+	instance: BoxedIndicatorInstance<T>,
+	signal: usize,
+	weight: ValueType,
+}
+
+/// Owns several indicator **States** and merges their signals into a single [`Action`] on every candle
+///
+/// Every member has a unique name, so its weight may be changed later by [`set_weight`](Ensemble::set_weight).
+///
+/// # Examples
+///
+/// ```
+/// use yata::core::{Candle, Ensemble, Vote};
+/// use yata::helpers::RandomCandles;
+/// use yata::indicators::{RelativeStrengthIndex, Trix, MACD};
+/// use yata::prelude::dd::*;
+///
+/// let mut candles = RandomCandles::new();
+/// let first = candles.first();
+///
+/// let mut ensemble: Ensemble<Candle> = Ensemble::new(Vote::Weighted);
+/// ensemble.add("macd", MACD::default().init(first).unwrap(), 0, 2.0).unwrap();
+/// ensemble.add("trix", Trix::default().init(first).unwrap(), 0, 1.0).unwrap();
+/// ensemble.add("rsi", RelativeStrengthIndex::default().init(first).unwrap(), 0, 1.0).unwrap();
+///
+/// ensemble.set_weight("trix", 0.5).unwrap();
+/// assert_eq!(ensemble.weight("trix"), Some(0.5));
+/// assert!(ensemble.set_weight("cci", 1.0).is_err());
+///
+/// for candle in candles.take(100) {
+///     let signal = ensemble.next(candle);
+///     println!("{:?}", signal);
+/// }
+/// ```
+#[derive(Debug)]
+pub struct Ensemble<T: OHLC> {
+	vote: Vote,
+	members: Vec<Member<T>>,
+}
+
+impl<T: OHLC> Ensemble<T> {
+	/// Creates an empty `Ensemble` with the merging rule `vote`
+	#[must_use]
+	pub const fn new(vote: Vote) -> Self {
+		Self {
+			vote,
+			members: Vec::new(),
+		}
+	}
+
+	/// Returns the merging rule
+	#[must_use]
+	pub const fn vote(&self) -> Vote {
+		self.vote
+	}
+
+	/// Adds the indicator **State** `instance` with the unique `name`
+	///
+	/// Signal at index `signal` of the indicator is merged with the `weight`.
+	///
+	/// Returns an error if the `name` is already used, if there is no signal at index `signal` or if the `weight` is not finite.
+	pub fn add<S: Into<String>>(
+		&mut self,
+		name: S,
+		instance: BoxedIndicatorInstance<T>,
+		signal: usize,
+		weight: ValueType,
+	) -> Result<&mut Self, Error> {
+		let name = name.into();
+
+		if self.members.iter().any(|member| member.name == name)
+			|| signal >= usize::from(instance.size().1)
+			|| !weight.is_finite()
+		{
+			return Err(Error::WrongConfigParameter(name));
+		}
+
+		self.members.push(Member {
+			name,
+			instance,
+			signal,
+			weight,
+		});
+
+		Ok(self)
+	}
+
+	/// Returns the weight of the member with the `name`
+	#[must_use]
+	pub fn weight(&self, name: &str) -> Option<ValueType> {
+		self.member(name).map(|member| member.weight)
+	}
+
+	/// Changes the weight of the member with the `name`
+	///
+	/// Returns [`Error::UnknownIndicator`] if there is no such member.
+	pub fn set_weight(&mut self, name: &str, weight: ValueType) -> Result<(), Error> {
+		if !weight.is_finite() {
+			return Err(Error::WrongConfigParameter(name.into()));
+		}
+
+		let member = self
+			.members
+			.iter_mut()
+			.find(|member| member.name == name)
+			.ok_or_else(|| Error::UnknownIndicator(name.into()))?;
+
+		member.weight = weight;
+		Ok(())
+	}
+
+	/// Returns names of all the members in the order they were added
+	pub fn names(&self) -> impl Iterator<Item = &str> {
+		self.members.iter().map(|member| member.name.as_str())
+	}
+
+	/// Returns count of the members
+	#[must_use]
+	pub const fn len(&self) -> usize {
+		self.members.len()
+	}
+
+	/// Returns `true` if there are no members
+	#[must_use]
+	pub const fn is_empty(&self) -> bool {
+		self.members.is_empty()
+	}
+
+	/// Evaluates every member over the `candle` and returns the merged signal
+	pub fn next(&mut self, candle: T) -> Action {
+		let signals = self.members.iter_mut().map(|member| {
+			let signal = member.instance.next(candle).signal(member.signal);
+			(signal, member.weight)
+		});
+
+		match self.vote {
+			Vote::Weighted => Action::weighted(signals),
+			Vote::Majority => {
+				let balance: i32 = signals.map(|(signal, _)| i32::from(signal.analog())).sum();
+
+				match balance {
+					0 => Action::None,
+					balance if balance > 0 => Action::BUY_ALL,
+					_ => Action::SELL_ALL,
+				}
+			}
+		}
+	}
+
+	fn member(&self, name: &str) -> Option<&Member<T>> {
+		self.members.iter().find(|member| member.name == name)
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	use super::{Ensemble, Vote};
+	use crate::core::{Action, Candle, IndicatorConfigDyn, ValueType};
+	use crate::helpers::RandomCandles;
+	use crate::indicators::{Trix, MACD};
+	use alloc::vec::Vec;
+
+	#[test]
+	fn test_ensemble() {
+		let candles: Vec<Candle> = RandomCandles::new().take(200).collect();
+		let mut macd = MACD::default().init(candles[0]).unwrap();
+		let mut trix = Trix::default().init(candles[0]).unwrap();
+
+		let mut majority = Ensemble::new(Vote::Majority);
+		let mut weighted = Ensemble::new(Vote::Weighted);
+
+		for ensemble in vec![&mut majority, &mut weighted] {
+			ensemble
+				.add("macd", MACD::default().init(candles[0]).unwrap(), 0, 3.0)
+				.unwrap()
+				.add("trix", Trix::default().init(candles[0]).unwrap(), 0, 1.0)
+				.unwrap();
+
+			assert!(ensemble
+				.add("trix", Trix::default().init(candles[0]).unwrap(), 0, 1.0)
+				.is_err());
+			assert!(ensemble
+				.add("other", Trix::default().init(candles[0]).unwrap(), 5, 1.0)
+				.is_err());
+		}
+
+		assert_eq!(weighted.names().collect::<Vec<_>>(), ["macd", "trix"]);
+		assert_eq!(weighted.len(), 2);
+
+		for &candle in &candles {
+			let a = macd.next(candle).signal(0);
+			let b = trix.next(candle).signal(0);
+
+			let expected = match i32::from(a.analog()) + i32::from(b.analog()) {
+				0 => Action::None,
+				x if x > 0 => Action::BUY_ALL,
+				_ => Action::SELL_ALL,
+			};
+
+			assert_eq!(majority.next(candle), expected);
+			assert_eq!(
+				weighted.next(candle),
+				Action::weighted([(a, 3.0), (b, 1.0)])
+			);
+		}
+
+		assert!(weighted.set_weight("macd", ValueType::NAN).is_err());
+		assert!(weighted.set_weight("cci", 1.0).is_err());
+		weighted.set_weight("macd", 0.0).unwrap();
+		assert_eq!(weighted.weight("macd"), Some(0.0));
+	}
+}
@@ -1,21 +1,166 @@
+use super::PeriodType;
+use alloc::boxed::Box;
+use alloc::string::String;
+use core::fmt;
+
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
+
 /// Crate errors enum
+///
+/// Implements [`Display`](fmt::Display) and [`std::error::Error`], so it may be used together with any error handling crate.
+///
+/// ```
+/// use yata::prelude::dd::*;
+/// use yata::core::Error;
+/// use yata::indicators::create;
+///
+/// let mut config = create::<Candle>("MACD").unwrap();
+/// let error = config.set("period", "10".to_string()).unwrap();
+///
+/// assert_eq!(error.indicator(), Some("MACD"));
+/// assert!(matches!(error.kind(), Error::UnknownParameter(name) if name == "period"));
+/// assert_eq!(error.to_string(), "MACD: unknown parameter `period`");
+/// ```
 #[derive(Debug, Clone)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 pub enum Error {
 	/// Error parsing string to [`Source`](crate::core::Source)
 	SourceParse(String),
 
-	/// Error parsing indicator parameter
+	/// Error parsing indicator parameter: the name of the parameter and the value which failed to be parsed
 	ParameterParse(String, String),
 
+	/// There is no indicator parameter with such name
+	UnknownParameter(String),
+
 	/// Invalid parameters for method creation
 	WrongMethodParameters,
 
 	/// Invalid indicator config error
 	WrongConfig,
 
+	/// Invalid indicator config error caused by the parameter with such name, which is out of its [range](crate::core::ParamDescriptor)
+	WrongConfigParameter(String),
+
+	/// There is no indicator with such name
+	UnknownIndicator(String),
+
 	/// Invalid candles error
 	InvalidCandles,
 
+	/// Not enough candles to evaluate the result: the value is the required count of candles
+	NotReady(PeriodType),
+
+	/// Error parsing JSON
+	JsonParse(String),
+
+	/// Error parsing CSV
+	CsvParse(String),
+
+	/// Invalid state snapshot error
+	InvalidState(String),
+
+	/// Error caused by the indicator with such name
+	///
+	/// See [`Error::in_indicator`].
+	Indicator(String, Box<Self>),
+
 	/// Any other error
 	Other(String),
 }
+
+impl Error {
+	/// Attaches the indicator `name` to the error
+	///
+	/// Does nothing if the error already has an indicator name.
+	#[must_use]
+	pub fn in_indicator(self, name: impl Into<String>) -> Self {
+		match self {
+			Self::Indicator(..) => self,
+			error => Self::Indicator(name.into(), Box::new(error)),
+		}
+	}
+
+	/// Returns the name of the indicator caused the error, if any
+	#[must_use]
+	pub fn indicator(&self) -> Option<&str> {
+		match self {
+			Self::Indicator(name, _) => Some(name),
+			_ => None,
+		}
+	}
+
+	/// Returns the error itself without the attached indicator name
+	///
+	/// Useful for matching the error variants.
+	#[must_use]
+	pub fn kind(&self) -> &Self {
+		match self {
+			Self::Indicator(_, error) => error.kind(),
+			error => error,
+		}
+	}
+}
+
+impl fmt::Display for Error {
+	fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+		match self {
+			Self::SourceParse(value) => write!(f, "unknown source `{value}`"),
+			Self::ParameterParse(name, value) => {
+				write!(f, "unable to parse `{value}` for parameter `{name}`")
+			}
+			Self::UnknownParameter(name) => write!(f, "unknown parameter `{name}`"),
+			Self::WrongMethodParameters => f.write_str("wrong method parameters"),
+			Self::WrongConfig => f.write_str("wrong indicator configuration"),
+			Self::WrongConfigParameter(name) => write!(f, "parameter `{name}` is out of range"),
+			Self::UnknownIndicator(name) => write!(f, "unknown indicator `{name}`"),
+			Self::InvalidCandles => f.write_str("invalid candles"),
+			Self::NotReady(count) => write!(f, "not enough candles, at least {count} required"),
+			Self::JsonParse(message) => write!(f, "unable to parse JSON: {message}"),
+			Self::CsvParse(message) => write!(f, "unable to parse CSV: {message}"),
+			Self::InvalidState(message) => write!(f, "invalid state: {message}"),
+			Self::Indicator(name, error) => write!(f, "{name}: {error}"),
+			Self::Other(message) => f.write_str(message),
+		}
+	}
+}
+
+#[cfg(feature = "std")]
+impl std::error::Error for Error {
+	fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+		match self {
+			Self::Indicator(_, error) => Some(error.as_ref()),
+			_ => None,
+		}
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	use super::Error;
+	use alloc::string::ToString;
+	#[cfg(feature = "std")]
+	use std::error::Error as _;
+
+	#[test]
+	fn test_error() {
+		let error = Error::UnknownParameter("period".into());
+		assert!(error.indicator().is_none());
+		#[cfg(feature = "std")]
+		assert!(error.source().is_none());
+		assert_eq!(error.to_string(), "unknown parameter `period`");
+
+		let error = error.in_indicator("MACD").in_indicator("RSI");
+		assert_eq!(error.indicator(), Some("MACD"));
+		assert!(matches!(error.kind(), Error::UnknownParameter(_)));
+		#[cfg(feature = "std")]
+		assert!(error.source().is_some());
+		assert_eq!(error.to_string(), "MACD: unknown parameter `period`");
+
+		assert_eq!(
+			Error::NotReady(10).to_string(),
+			"not enough candles, at least 10 required"
+		);
+	}
+}
@@ -0,0 +1,98 @@
+//! Float math for `no_std` builds
+//!
+//! Without `std` primitive float types have no math methods (like `sqrt` or `mul_add`), so this trait
+//! provides them by [`libm`](https://docs.rs/libm) under the same names. With `std` feature enabled the trait does not exist
+//! and inherent methods are used.
+
+/// Float math methods, which are available only with `std`
+pub trait Float: Sized {
+	fn mul_add(self, a: Self, b: Self) -> Self;
+	fn sqrt(self) -> Self;
+	fn ln(self) -> Self;
+	fn exp(self) -> Self;
+	fn exp2(self) -> Self;
+	fn powi(self, n: i32) -> Self;
+	fn sin(self) -> Self;
+	fn cos(self) -> Self;
+	fn tan(self) -> Self;
+	fn atan(self) -> Self;
+	fn atanh(self) -> Self;
+	fn floor(self) -> Self;
+	fn round(self) -> Self;
+}
+
+macro_rules! impl_float {
+	($t:ty, $fma:ident, $sqrt:ident, $log:ident, $exp:ident, $exp2:ident, $pow:ident, $sin:ident, $cos:ident, $tan:ident, $atan:ident, $atanh:ident, $floor:ident, $round:ident) => {
+		impl Float for $t {
+			#[inline]
+			fn mul_add(self, a: Self, b: Self) -> Self {
+				libm::$fma(self, a, b)
+			}
+
+			#[inline]
+			fn sqrt(self) -> Self {
+				libm::$sqrt(self)
+			}
+
+			#[inline]
+			fn ln(self) -> Self {
+				libm::$log(self)
+			}
+
+			#[inline]
+			fn exp(self) -> Self {
+				libm::$exp(self)
+			}
+
+			#[inline]
+			fn exp2(self) -> Self {
+				libm::$exp2(self)
+			}
+
+			#[inline]
+			fn powi(self, n: i32) -> Self {
+				libm::$pow(self, n as Self)
+			}
+
+			#[inline]
+			fn sin(self) -> Self {
+				libm::$sin(self)
+			}
+
+			#[inline]
+			fn cos(self) -> Self {
+				libm::$cos(self)
+			}
+
+			#[inline]
+			fn tan(self) -> Self {
+				libm::$tan(self)
+			}
+
+			#[inline]
+			fn atan(self) -> Self {
+				libm::$atan(self)
+			}
+
+			#[inline]
+			fn atanh(self) -> Self {
+				libm::$atanh(self)
+			}
+
+			#[inline]
+			fn floor(self) -> Self {
+				libm::$floor(self)
+			}
+
+			#[inline]
+			fn round(self) -> Self {
+				libm::$round(self)
+			}
+		}
+	};
+}
+
+impl_float!(f64, fma, sqrt, log, exp, exp2, pow, sin, cos, tan, atan, atanh, floor, round);
+impl_float!(
+	f32, fmaf, sqrtf, logf, expf, exp2f, powf, sinf, cosf, tanf, atanf, atanhf, floorf, roundf
+);
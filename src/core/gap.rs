@@ -0,0 +1,301 @@
+use super::{
+	Error, IndicatorInitializer, IndicatorInstance, IndicatorResult, Method, PeriodType, OHLC,
+};
+use core::fmt;
+
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
+
+/// Policy of processing inputs, which are flagged as a gap in the data or as a session boundary
+///
+/// Recursive methods like [`EMA`](crate::methods::EMA) keep the effect of any input forever,
+/// so the jump over weekend or holiday gap silently distorts all the further values.
+///
+/// See [`GapGuard`] and [`IndicatorGapGuard`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub enum GapPolicy {
+	/// Gap inputs are ignored and the last output is returned again
+	Skip,
+
+	/// Gap inputs are replaced by the last regular input
+	ForwardFill,
+
+	/// The state is reset by the gap input, so it starts over from it
+	Reset,
+}
+
+/// Wraps a [`Method`] and applies [`GapPolicy`] to the inputs, flagged as gaps
+///
+/// Input of `GapGuard` is a tuple of the wrapped method input and a gap flag.
+/// The first input is never treated as a gap.
+///
+/// If the wrapped method can not be [reset](Method::reset) by the gap input, then the gap input is skipped.
+///
+/// # Examples
+///
+/// ```
+/// use yata::core::{GapGuard, GapPolicy};
+/// use yata::methods::EMA;
+/// use yata::prelude::*;
+///
+/// let mut ema = GapGuard::<EMA>::new((GapPolicy::Reset, 3), (10.0, false)).unwrap();
+///
+/// ema.next((12.0, false));
+/// assert_eq!(ema.next((20.0, true)), 20.0);
+///
+/// let mut ema = GapGuard::<EMA>::new((GapPolicy::Skip, 3), (10.0, false)).unwrap();
+///
+/// assert_eq!(ema.next((12.0, false)), 11.0);
+/// assert_eq!(ema.next((20.0, true)), 11.0);
+/// ```
+#[derive(Clone)]
+pub struct GapGuard<M: Method> {
+	method: M,
+	policy: GapPolicy,
+	last_input: M::Input,
+	last_output: Option<M::Output>,
+}
+
+impl<M: Method> GapGuard<M> {
+	/// Returns the applied [`GapPolicy`]
+	pub const fn policy(&self) -> GapPolicy {
+		self.policy
+	}
+
+	/// Returns a reference to the wrapped method
+	pub const fn method(&self) -> &M {
+		&self.method
+	}
+}
+
+impl<M: Method> fmt::Debug for GapGuard<M> {
+	fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+		f.debug_struct("GapGuard")
+			.field("method", &self.method)
+			.field("policy", &self.policy)
+			.finish_non_exhaustive()
+	}
+}
+
+impl<M: Method> Method for GapGuard<M> {
+	type Params = (GapPolicy, M::Params);
+	type Input = (M::Input, bool);
+	type Output = M::Output;
+
+	fn new((policy, params): Self::Params, (value, _): Self::Input) -> Result<Self, Error> {
+		Ok(Self {
+			method: M::new(params, value)?,
+			policy,
+			last_input: value,
+			last_output: None,
+		})
+	}
+
+	#[inline]
+	fn next(&mut self, (value, gap): Self::Input) -> Self::Output {
+		let output = match (gap, self.policy, self.last_output) {
+			(false, _, _) | (true, _, None) => self.method.next(value),
+			(true, GapPolicy::Skip, Some(output)) => return output,
+			(true, GapPolicy::ForwardFill, Some(_)) => return self.next((self.last_input, false)),
+			(true, GapPolicy::Reset, Some(output)) => {
+				if self.method.reset(value).is_err() {
+					return output;
+				}
+
+				self.method.next(value)
+			}
+		};
+
+		self.last_input = value;
+		self.last_output = Some(output);
+		output
+	}
+
+	fn min_periods(&self) -> PeriodType {
+		self.method.min_periods()
+	}
+
+	fn reset(&mut self, (value, _): Self::Input) -> Result<(), Error> {
+		self.method.reset(value)?;
+		self.last_input = value;
+		self.last_output = None;
+		Ok(())
+	}
+}
+
+/// Wraps an indicator **State** and applies [`GapPolicy`] to the candles, flagged as gaps
+///
+/// With [`GapPolicy::ForwardFill`] the last regular candle is repeated instead of the gap one.
+/// If the **State** can not be [reset](IndicatorInstance::reset) by the gap candle, then the gap candle is skipped.
+///
+/// # Examples
+///
+/// ```
+/// use yata::core::{GapPolicy, IndicatorGapGuard};
+/// use yata::helpers::RandomCandles;
+/// use yata::indicators::Trix;
+/// use yata::prelude::*;
+///
+/// let candles: Vec<_> = RandomCandles::new().take(30).collect();
+/// let trix = Trix::default().init(candles[0]).unwrap();
+/// let mut guard = IndicatorGapGuard::new(trix, GapPolicy::Reset);
+///
+/// for &candle in &candles[..20] {
+///     guard.next(candle, false);
+/// }
+///
+/// // new session starts
+/// let result = guard.next(candles[20], true);
+///
+/// let mut trix = Trix::default().init(candles[20]).unwrap();
+/// assert_eq!(result.values(), trix.next(candles[20]).values());
+/// ```
+#[derive(Debug, Clone)]
+pub struct IndicatorGapGuard<I, T> {
+	instance: I,
+	policy: GapPolicy,
+	last_candle: Option<T>,
+	last_result: Option<IndicatorResult>,
+}
+
+impl<I, T> IndicatorGapGuard<I, T>
+where
+	T: OHLC,
+	I: IndicatorInstance<T>,
+	I::Config: IndicatorInitializer<T, Instance = I>,
+{
+	/// Wraps an indicator **State** with the gap `policy`
+	pub const fn new(instance: I, policy: GapPolicy) -> Self {
+		Self {
+			instance,
+			policy,
+			last_candle: None,
+			last_result: None,
+		}
+	}
+
+	/// Returns the applied [`GapPolicy`]
+	pub const fn policy(&self) -> GapPolicy {
+		self.policy
+	}
+
+	/// Returns a reference to the wrapped indicator **State**
+	pub const fn instance(&self) -> &I {
+		&self.instance
+	}
+
+	/// Unwraps the indicator **State**
+	pub fn into_inner(self) -> I {
+		self.instance
+	}
+
+	/// Evaluates the next `candle`, which is a gap or a session boundary if `gap` is `true`
+	pub fn next(&mut self, candle: T, gap: bool) -> IndicatorResult {
+		let result = match (gap, self.policy, &self.last_result, self.last_candle) {
+			(true, GapPolicy::Skip, Some(result), _) => return result.clone(),
+			(true, GapPolicy::ForwardFill, _, Some(last_candle)) => {
+				return self.next(last_candle, false)
+			}
+			(true, GapPolicy::Reset, Some(result), _) => {
+				if self.instance.reset(candle).is_err() {
+					return result.clone();
+				}
+
+				self.instance.next(candle)
+			}
+			_ => self.instance.next(candle),
+		};
+
+		self.last_candle = Some(candle);
+		self.last_result = Some(result.clone());
+		result
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	use super::{GapGuard, GapPolicy, IndicatorGapGuard};
+	use crate::core::{Candle, IndicatorInitializer, IndicatorInstance, Method};
+	use crate::helpers::{assert_eq_float, RandomCandles};
+	use crate::indicators::MACD;
+	use crate::methods::EMA;
+	use alloc::vec::Vec;
+
+	#[test]
+	fn test_gap_guard() {
+		let candles: Vec<Candle> = RandomCandles::new().take(100).collect();
+		let values: Vec<_> = candles.iter().map(|c| c.close).collect();
+		let is_gap = |i: usize| i % 17 == 5;
+
+		let mut skip = GapGuard::<EMA>::new((GapPolicy::Skip, 5), (values[0], false)).unwrap();
+		let mut fill =
+			GapGuard::<EMA>::new((GapPolicy::ForwardFill, 5), (values[0], false)).unwrap();
+		let mut reset = GapGuard::<EMA>::new((GapPolicy::Reset, 5), (values[0], false)).unwrap();
+
+		let mut ema_skip = EMA::new(5, values[0]).unwrap();
+		let mut ema_fill = EMA::new(5, values[0]).unwrap();
+		let mut ema_reset = EMA::new(5, values[0]).unwrap();
+		let mut last_skip = values[0];
+		let mut last_value = values[0];
+
+		for (i, &value) in values.iter().enumerate() {
+			let gap = i > 0 && is_gap(i);
+
+			if gap {
+				assert_eq_float(last_skip, skip.next((value, true)));
+				assert_eq_float(ema_fill.next(last_value), fill.next((value, true)));
+
+				ema_reset = EMA::new(5, value).unwrap();
+				assert_eq_float(ema_reset.next(value), reset.next((value, true)));
+			} else {
+				last_skip = ema_skip.next(value);
+				assert_eq_float(last_skip, skip.next((value, false)));
+				assert_eq_float(ema_fill.next(value), fill.next((value, false)));
+				assert_eq_float(ema_reset.next(value), reset.next((value, false)));
+				last_value = value;
+			}
+		}
+	}
+
+	#[test]
+	fn test_indicator_gap_guard() {
+		let candles: Vec<Candle> = RandomCandles::new().take(100).collect();
+		let macd = MACD::default();
+
+		for &policy in &[GapPolicy::Skip, GapPolicy::ForwardFill, GapPolicy::Reset] {
+			let mut guard = IndicatorGapGuard::new(macd.init(candles[0]).unwrap(), policy);
+			let mut expected = macd.init(candles[0]).unwrap();
+			let mut last = candles[0];
+			let mut last_result = None;
+
+			for (i, &candle) in candles.iter().enumerate() {
+				let gap = i > 0 && i % 13 == 7;
+				let result = guard.next(candle, gap);
+
+				let expected_result = match (gap, policy) {
+					(true, GapPolicy::Skip) => last_result.clone().unwrap(),
+					(true, GapPolicy::ForwardFill) => expected.next(last),
+					(true, GapPolicy::Reset) => {
+						expected = macd.init(candle).unwrap();
+						expected.next(candle)
+					}
+					(false, _) => {
+						last = candle;
+						expected.next(candle)
+					}
+				};
+
+				assert_eq!(result.values(), expected_result.values());
+				assert_eq!(result.signals(), expected_result.signals());
+				last_result = Some(result);
+			}
+
+			let state = guard.into_inner();
+			assert_eq!(
+				IndicatorInstance::<Candle>::config(&state).period1,
+				macd.period1
+			);
+		}
+	}
+}
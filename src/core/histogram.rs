@@ -0,0 +1,280 @@
+use super::ValueType;
+use alloc::collections::BTreeMap;
+use alloc::vec::Vec;
+
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
+
+#[cfg(not(feature = "std"))]
+use crate::core::Float as _;
+
+/// Histogram of weights accumulated into price bins of fixed size
+///
+/// Bin of the `value` covers range \[`k` * `bin_size`; (`k` + 1) * `bin_size`\), where `k` = floor(`value` / `bin_size`).
+/// Only non-empty bins are stored, so the histogram may cover any range of values.
+///
+/// Weights may be [removed](Histogram::remove) in the same way they were [added](Histogram::add),
+/// so the histogram can be used over a rolling window. Bin is dropped, when all of its weights are removed.
+///
+/// # Examples
+/// ```
+/// use yata::core::Histogram;
+///
+/// let mut h = Histogram::new(1.0);
+///
+/// h.add(10.2, 1.0);
+/// h.add(10.7, 3.0);
+/// h.add(12.5, 2.0);
+///
+/// assert_eq!(h.len(), 2);
+/// assert_eq!(h.total(), 6.0);
+/// assert_eq!(h.mode(), Some(10.5));
+///
+/// h.remove(10.7, 3.0);
+/// assert_eq!(h.mode(), Some(12.5));
+/// ```
+#[derive(Debug, Clone)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct Histogram {
+	bin_size: ValueType,
+	bins: BTreeMap<i64, (ValueType, usize)>,
+	total: ValueType,
+}
+
+impl Histogram {
+	/// Creates new empty `Histogram` with bins of size `bin_size`
+	#[must_use]
+	pub fn new(bin_size: ValueType) -> Self {
+		debug_assert!(bin_size > 0., "Histogram bin size must be positive");
+		Self {
+			bin_size,
+			bins: BTreeMap::new(),
+			total: 0.,
+		}
+	}
+
+	#[allow(clippy::cast_possible_truncation)]
+	fn key(&self, value: ValueType) -> i64 {
+		(value / self.bin_size).floor() as i64
+	}
+
+	#[allow(clippy::cast_precision_loss)]
+	fn bin_low(&self, key: i64) -> ValueType {
+		key as ValueType * self.bin_size
+	}
+
+	/// Adds `weight` into the bin of `value`
+	pub fn add(&mut self, value: ValueType, weight: ValueType) {
+		let bin = self.bins.entry(self.key(value)).or_insert((0., 0));
+		bin.0 += weight;
+		bin.1 += 1;
+		self.total += weight;
+	}
+
+	/// Removes `weight` previously [added](Histogram::add) into the bin of `value`
+	pub fn remove(&mut self, value: ValueType, weight: ValueType) {
+		let key = self.key(value);
+
+		if let Some(bin) = self.bins.get_mut(&key) {
+			bin.0 -= weight;
+			bin.1 -= 1;
+			self.total -= weight;
+
+			if bin.1 == 0 {
+				self.bins.remove(&key);
+			}
+		}
+
+		if self.bins.is_empty() {
+			self.total = 0.;
+		}
+	}
+
+	/// Removes all the bins
+	pub fn clear(&mut self) {
+		self.bins.clear();
+		self.total = 0.;
+	}
+
+	/// Returns size of the bins
+	#[must_use]
+	pub const fn bin_size(&self) -> ValueType {
+		self.bin_size
+	}
+
+	/// Returns count of non-empty bins
+	#[must_use]
+	pub fn len(&self) -> usize {
+		self.bins.len()
+	}
+
+	/// Checks if there are no bins
+	#[must_use]
+	pub fn is_empty(&self) -> bool {
+		self.bins.is_empty()
+	}
+
+	/// Returns sum of all the weights
+	#[must_use]
+	pub const fn total(&self) -> ValueType {
+		self.total
+	}
+
+	/// Returns an iterator over non-empty bins as pairs of bin lower bound and bin weight in ascending order
+	pub fn iter(&self) -> impl Iterator<Item = (ValueType, ValueType)> + '_ {
+		self.bins
+			.iter()
+			.map(move |(&key, &(weight, _))| (self.bin_low(key), weight))
+	}
+
+	/// Returns the middle of the heaviest bin
+	///
+	/// When there are several heaviest bins, then the lowest one is returned
+	#[must_use]
+	pub fn mode(&self) -> Option<ValueType> {
+		self.bins
+			.iter()
+			.fold(
+				None,
+				|max: Option<(i64, ValueType)>, (&key, &(weight, _))| match max {
+					Some((_, max_weight)) if max_weight >= weight => max,
+					_ => Some((key, weight)),
+				},
+			)
+			.map(|(key, _)| self.bin_size.mul_add(0.5, self.bin_low(key)))
+	}
+
+	/// Returns lower and upper bounds of the range of bins around the [mode](Histogram::mode),
+	/// which holds at least `fraction` of the total weight
+	///
+	/// Range is expanded from the heaviest bin to the heavier of the neighbour non-empty bins.
+	///
+	/// # Examples
+	/// ```
+	/// use yata::core::Histogram;
+	///
+	/// let mut h = Histogram::new(1.0);
+	///
+	/// h.add(1.0, 1.0);
+	/// h.add(2.0, 2.0);
+	/// h.add(3.0, 5.0);
+	/// h.add(4.0, 1.0);
+	/// h.add(5.0, 1.0);
+	///
+	/// assert_eq!(h.value_area(0.7), Some((2.0, 4.0)));
+	/// assert_eq!(h.value_area(0.0), Some((3.0, 4.0)));
+	/// assert_eq!(h.value_area(1.0), Some((1.0, 6.0)));
+	/// ```
+	#[must_use]
+	pub fn value_area(&self, fraction: ValueType) -> Option<(ValueType, ValueType)> {
+		let bins: Vec<(i64, ValueType)> = self
+			.bins
+			.iter()
+			.map(|(&key, &(weight, _))| (key, weight))
+			.collect();
+
+		let poc = bins
+			.iter()
+			.enumerate()
+			.fold(
+				None,
+				|max: Option<(usize, ValueType)>, (i, &(_, weight))| match max {
+					Some((_, max_weight)) if max_weight >= weight => max,
+					_ => Some((i, weight)),
+				},
+			)?
+			.0;
+
+		let target = self.total * fraction;
+		let (mut low, mut high) = (poc, poc);
+		let mut sum = bins[poc].1;
+
+		while sum < target && (low > 0 || high + 1 < bins.len()) {
+			let up = bins.get(high + 1).map(|&(_, weight)| weight);
+			let down = low.checked_sub(1).map(|i| bins[i].1);
+
+			match (up, down) {
+				(Some(up), Some(down)) if down > up => {
+					low -= 1;
+					sum += down;
+				}
+				(Some(up), _) => {
+					high += 1;
+					sum += up;
+				}
+				(None, Some(down)) => {
+					low -= 1;
+					sum += down;
+				}
+				(None, None) => break,
+			}
+		}
+
+		Some((
+			self.bin_low(bins[low].0),
+			self.bin_low(bins[high].0) + self.bin_size,
+		))
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	use super::Histogram;
+	use crate::core::ValueType;
+	use crate::helpers::{assert_eq_float, RandomCandles};
+	use alloc::vec::Vec;
+
+	#[test]
+	fn test_histogram_rolling() {
+		let data: Vec<ValueType> = RandomCandles::new().take(300).map(|c| c.close).collect();
+		let length = 20;
+
+		let mut h = Histogram::new(0.25);
+
+		data.iter().enumerate().for_each(|(i, &x)| {
+			h.add(x, 1.0);
+			if i >= length {
+				h.remove(data[i - length], 1.0);
+			}
+
+			let window = &data[(i + 1).saturating_sub(length)..=i];
+			let mut expected = Histogram::new(0.25);
+			for &x in window {
+				expected.add(x, 1.0);
+			}
+
+			assert_eq!(h.len(), expected.len());
+			assert_eq_float(expected.total(), h.total());
+			assert_eq!(h.mode(), expected.mode());
+			assert_eq!(h.value_area(0.7), expected.value_area(0.7));
+		});
+	}
+
+	#[test]
+	fn test_histogram_value_area() {
+		let data: Vec<ValueType> = RandomCandles::new().take(300).map(|c| c.close).collect();
+
+		let mut h = Histogram::new(0.1);
+		for &x in &data {
+			h.add(x, 1.0);
+		}
+
+		let (low, high) = h.value_area(0.7).unwrap();
+		let inside = data.iter().filter(|&&x| x >= low && x < high).count();
+
+		assert!(inside as ValueType >= data.len() as ValueType * 0.7);
+		assert!(low <= h.mode().unwrap() && h.mode().unwrap() < high);
+	}
+
+	#[test]
+	fn test_histogram_empty() {
+		let mut h = Histogram::new(1.0);
+		assert!(h.mode().is_none());
+		assert!(h.value_area(0.7).is_none());
+
+		h.add(1.0, 2.0);
+		h.remove(1.0, 2.0);
+		assert!(h.is_empty());
+		assert!(h.mode().is_none());
+	}
+}
@@ -0,0 +1,117 @@
+use super::IndicatorConfig;
+use crate::core::{Error, ValueType};
+use alloc::string::ToString;
+use core::fmt;
+
+/// Builder for the indicator **Configuration**
+///
+/// Parameters are [set](IndicatorConfig::set) by names one by one. The first parameter which fails to be set
+/// is remembered and returned by [`build`](ConfigBuilder::build), so the whole chain may be written without intermediate checks.
+///
+/// [`build`](ConfigBuilder::build) validates the **Configuration** and returns [`Error::WrongConfigParameter`] with the name
+/// of the first parameter which is out of its [range](crate::core::ParamDescriptor), so there is no need to wait for [`init`](crate::core::IndicatorInitializer::init)
+/// to discover problems.
+///
+/// # Examples
+///
+/// ```
+/// use yata::prelude::*;
+/// use yata::core::Error;
+/// use yata::indicators::MACD;
+///
+/// let macd = MACD::builder().set("period1", 9).set("period2", 21).build().unwrap();
+/// assert_eq!(macd.period1, 9);
+/// assert_eq!(macd.period2, 21);
+///
+/// let result = MACD::builder().set("period1", 30).build();
+/// assert!(matches!(result, Err(Error::WrongConfigParameter(name)) if name == "period1"));
+///
+/// let result = MACD::builder().set("unknown", 1).build();
+/// assert!(matches!(result, Err(Error::UnknownParameter(name)) if name == "unknown"));
+/// ```
+#[derive(Debug, Clone)]
+pub struct ConfigBuilder<C: IndicatorConfig> {
+	config: C,
+	error: Option<Error>,
+}
+
+impl<C: IndicatorConfig> ConfigBuilder<C> {
+	/// Creates a builder starting from the given **Configuration**
+	pub const fn new(config: C) -> Self {
+		Self {
+			config,
+			error: None,
+		}
+	}
+
+	/// Sets the parameter `name` to the `value`
+	///
+	/// If there is already an error, then does nothing.
+	#[must_use]
+	pub fn set(mut self, name: &str, value: impl fmt::Display) -> Self {
+		if self.error.is_none() {
+			self.error = self.config.set(name, value.to_string());
+		}
+
+		self
+	}
+
+	/// Validates the **Configuration** and returns it
+	pub fn build(self) -> Result<C, Error> {
+		if let Some(error) = self.error {
+			return Err(error);
+		}
+
+		if self.config.validate() {
+			return Ok(self.config);
+		}
+
+		let wrong_parameter = self.config.parameters().into_iter().find(|param| {
+			param
+				.value
+				.parse::<ValueType>()
+				.is_ok_and(|value| !param.contains(value))
+		});
+
+		match wrong_parameter {
+			Some(param) => Err(Error::WrongConfigParameter(param.name.to_string())),
+			None => Err(Error::WrongConfig),
+		}
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	use super::ConfigBuilder;
+	use crate::core::{Error, IndicatorConfig, Source};
+	use crate::indicators::{DynamicMomentumIndex, RelativeStrengthIndex};
+
+	#[test]
+	fn test_config_builder() {
+		let rsi = RelativeStrengthIndex::builder()
+			.set("period", 10)
+			.set("zone", 0.25)
+			.set("source", Source::Open)
+			.build()
+			.unwrap();
+
+		assert_eq!(rsi.period, 10);
+		assert!((rsi.zone - 0.25).abs() < 1e-10);
+		assert_eq!(rsi.source, Source::Open);
+
+		let result = ConfigBuilder::new(rsi).set("zone", 0.6).build();
+		assert!(matches!(result, Err(Error::WrongConfigParameter(name)) if name == "zone"));
+
+		let result = ConfigBuilder::new(rsi)
+			.set("period", "abc")
+			.set("zone", 0.3)
+			.build();
+		assert!(matches!(result, Err(Error::ParameterParse(name, _)) if name == "period"));
+
+		let result = DynamicMomentumIndex::builder()
+			.set("min_period", 20)
+			.set("max_period", 10)
+			.build();
+		assert!(matches!(result, Err(Error::WrongConfigParameter(name)) if name == "min_period"));
+	}
+}
@@ -1,5 +1,13 @@
-use super::{IndicatorInstance, IndicatorResult};
-use crate::core::{Error, OHLC};
+use super::{
+	BoxedIndicatorInstance, ConfigBuilder, IndicatorInstance, IndicatorResult, ParamDescriptor,
+	ParamKind, ParamValue,
+};
+use crate::core::{Error, PeriodType, OHLC};
+use alloc::boxed::Box;
+use alloc::string::String;
+use alloc::string::ToString;
+use alloc::vec::Vec;
+use core::fmt;
 
 /// Each indicator has it's own **Configuration** with parameters
 ///
@@ -11,12 +19,186 @@ pub trait IndicatorConfig: Clone {
 	/// Name of an indicator
 	const NAME: &'static str;
 
+	/// Names of the indicator raw values in the same order as they are returned in [`IndicatorResult`]
+	///
+	/// Default is empty, so the values may be accessed only by their indexes.
+	///
+	/// See [`IndicatorResult::value_by_name`]
+	const VALUE_NAMES: &'static [&'static str] = &[];
+
+	/// Names of the indicator signals in the same order as they are returned in [`IndicatorResult`]
+	///
+	/// Default is empty, so the signals may be accessed only by their indexes.
+	///
+	/// See [`IndicatorResult::signal_by_name`]
+	const SIGNAL_NAMES: &'static [&'static str] = &[];
+
 	/// Validates if **Configuration** is OK
 	fn validate(&self) -> bool;
 
 	/// Sets dynamically **Configuration** parameters
+	///
+	/// Returns [`Error::UnknownParameter`] or [`Error::ParameterParse`] without the indicator name.
+	/// [`IndicatorConfigDyn::set`] attaches the name to the returned errors, see [`Error::in_indicator`].
 	fn set(&mut self, name: &str, value: String) -> Option<Error>;
 
+	/// Returns descriptions of all the **Configuration** parameters, which may be [set](IndicatorConfig::set)
+	///
+	/// Default implementation returns an empty list, so [`get`](IndicatorConfig::get) and [`set_value`](IndicatorConfig::set_value)
+	/// know nothing about the parameters, while [`set`](IndicatorConfig::set) still works.
+	///
+	/// See [`ParamDescriptor`](crate::core::ParamDescriptor)
+	fn parameters(&self) -> Vec<ParamDescriptor> {
+		Vec::new()
+	}
+
+	/// Returns current typed value of the **Configuration** parameter `name`
+	///
+	/// Returns `None` if there is no parameter with such `name`.
+	/// The value may be passed back into [`set_value`](IndicatorConfig::set_value).
+	///
+	/// ```
+	/// use yata::prelude::*;
+	/// use yata::core::{ParamValue, Source};
+	/// use yata::indicators::MACD;
+	///
+	/// let mut macd = MACD::default();
+	/// macd.period1 = 9;
+	///
+	/// assert_eq!(macd.get("period1"), Some(ParamValue::Int(9)));
+	/// assert_eq!(macd.get("source"), Some(ParamValue::Source(Source::Close)));
+	/// assert_eq!(macd.get("period"), None);
+	/// ```
+	fn get(&self, name: &str) -> Option<ParamValue> {
+		self.parameters()
+			.into_iter()
+			.find(|param| param.name == name)
+			.and_then(|param| ParamValue::parse(param.kind, &param.value))
+	}
+
+	/// Sets dynamically **Configuration** parameter by its typed `value`
+	///
+	/// Unlike [`set`](IndicatorConfig::set), distinguishes errors:
+	/// * [`Error::UnknownParameter`] if there is no parameter with such `name`;
+	/// * [`Error::ParameterParse`] if the `value` does not fit the [kind](crate::core::ParamKind) of the parameter.
+	///
+	/// Numeric values are passed into [`set`](IndicatorConfig::set) by their shortest exact representation, so nothing is lost.
+	///
+	/// ```
+	/// use yata::prelude::*;
+	/// use yata::core::{Error, ParamValue, Source};
+	/// use yata::indicators::MACD;
+	///
+	/// let mut macd = MACD::default();
+	///
+	/// assert!(macd.set_value("period1", 9.into()).is_ok());
+	/// assert!(macd.set_value("source", Source::Open.into()).is_ok());
+	/// assert_eq!(macd.period1, 9);
+	/// assert_eq!(macd.source, Source::Open);
+	///
+	/// assert!(matches!(macd.set_value("period", 9.into()), Err(Error::UnknownParameter(_))));
+	/// assert!(matches!(macd.set_value("period1", ParamValue::Float(9.5)), Err(Error::ParameterParse(..))));
+	/// assert!(matches!(macd.set_value("period1", (-1).into()), Err(Error::ParameterParse(..))));
+	/// ```
+	fn set_value(&mut self, name: &str, value: ParamValue) -> Result<(), Error> {
+		let param = self
+			.parameters()
+			.into_iter()
+			.find(|param| param.name == name)
+			.ok_or_else(|| Error::UnknownParameter(name.to_string()))?;
+
+		if !value.fits(param.kind) {
+			return Err(Error::ParameterParse(name.to_string(), value.to_string()));
+		}
+
+		self.set(name, value.to_string()).map_or(Ok(()), Err)
+	}
+
+	/// Returns a [`ConfigBuilder`](crate::core::ConfigBuilder) starting from the default **Configuration**
+	#[must_use]
+	fn builder() -> ConfigBuilder<Self>
+	where
+		Self: Default,
+	{
+		ConfigBuilder::new(Self::default())
+	}
+
+	/// Creates a **Configuration** from the default one by [setting](IndicatorConfig::set) every `(name, value)` pair and then validating it
+	///
+	/// Any map of strings (f.e. `HashMap<String, String>`) may be used.
+	/// Returns the same errors as [`ConfigBuilder::build`](crate::core::ConfigBuilder::build).
+	///
+	/// ```
+	/// use std::collections::HashMap;
+	/// use yata::prelude::*;
+	/// use yata::indicators::MACD;
+	///
+	/// let mut params = HashMap::new();
+	/// params.insert("period1".to_string(), "9".to_string());
+	/// params.insert("period2".to_string(), "21".to_string());
+	///
+	/// let macd = MACD::from_map(params).unwrap();
+	/// assert_eq!(macd.period1, 9);
+	/// assert_eq!(macd.period2, 21);
+	/// ```
+	fn from_map<I, K, V>(params: I) -> Result<Self, Error>
+	where
+		Self: Default,
+		I: IntoIterator<Item = (K, V)>,
+		K: AsRef<str>,
+		V: fmt::Display,
+	{
+		params
+			.into_iter()
+			.fold(Self::builder(), |builder, (name, value)| {
+				builder.set(name.as_ref(), value)
+			})
+			.build()
+	}
+
+	/// Creates a **Configuration** from the flat JSON object of parameters
+	///
+	/// Values may be JSON strings, numbers or booleans. Parameters are applied the same way as in [`from_map`](IndicatorConfig::from_map).
+	/// Available only with `serde` feature.
+	///
+	/// ```
+	/// use yata::prelude::*;
+	/// use yata::core::Source;
+	/// use yata::indicators::MACD;
+	///
+	/// let macd = MACD::from_json(r#"{ "period1": 9, "period2": 21, "source": "open" }"#).unwrap();
+	/// assert_eq!(macd.period1, 9);
+	/// assert_eq!(macd.period2, 21);
+	/// assert_eq!(macd.source, Source::Open);
+	///
+	/// assert!(MACD::from_json(r#"{ "period1": }"#).is_err());
+	/// assert!(MACD::from_json(r#"{ "source": close }"#).is_err());
+	/// ```
+	#[cfg(feature = "serde")]
+	fn from_json(json: &str) -> Result<Self, Error>
+	where
+		Self: Default,
+	{
+		use serde_json::{Map, Value};
+
+		let object: Map<String, Value> =
+			serde_json::from_str(json).map_err(|e| Error::JsonParse(e.to_string()))?;
+
+		let params = object
+			.into_iter()
+			.map(|(name, value)| match value {
+				Value::String(value) => Ok((name, value)),
+				Value::Number(value) => Ok((name, value.to_string())),
+				Value::Bool(value) => Ok((name, value.to_string())),
+				_ => Err(Error::JsonParse(format!(
+					"parameter `{name}` must be a string, a number or a boolean"
+				))),
+			})
+			.collect::<Result<Vec<_>, _>>()?;
+
+		Self::from_map(params)
+	}
+
 	/// Should return `true` if indicator uses *volume* data
 	fn is_volume_based(&self) -> bool {
 		false
@@ -27,8 +209,75 @@ pub trait IndicatorConfig: Clone {
 		Self::NAME
 	}
 
+	/// Returns names of the indicator raw values
+	fn value_names(&self) -> &'static [&'static str] {
+		Self::VALUE_NAMES
+	}
+
+	/// Returns names of the indicator signals
+	fn signal_names(&self) -> &'static [&'static str] {
+		Self::SIGNAL_NAMES
+	}
+
 	/// Returns an [`IndicatorResult`](crate::core::IndicatorResult) size processing by the indicator `(count of raw value, count of signals)`
 	fn size(&self) -> (u8, u8);
+
+	/// Returns count of candles the **State** should process before its raw values become meaningful
+	///
+	/// Default implementation returns the greatest of the [`Period`](ParamKind::Period) parameters, which fits indicators over a single window.
+	/// Indicators, which chain several methods or look at the previous candles, override it with the whole length of the chain,
+	/// where recursive methods, like [`EMA`](crate::methods::EMA), are counted by their periods.
+	/// So with windowed methods the raw values of the **State** initialized by the last `min_periods` candles match the ones evaluated over the whole history.
+	///
+	/// See [`WarmUp`](crate::core::WarmUp).
+	///
+	/// ```
+	/// use yata::prelude::*;
+	/// use yata::indicators::{AverageDirectionalIndex, MACD};
+	///
+	/// let adx = AverageDirectionalIndex::default();
+	/// assert_eq!(adx.min_periods(), adx.period1 + adx.di_length + adx.adx_smoothing - 1);
+	///
+	/// let macd = MACD::default();
+	/// assert_eq!(macd.min_periods(), macd.period2 + macd.period3 - 1);
+	/// ```
+	fn min_periods(&self) -> PeriodType {
+		self.parameters()
+			.into_iter()
+			.filter(|param| param.kind == ParamKind::Period)
+			.filter_map(|param| param.value.parse().ok())
+			.max()
+			.unwrap_or(1)
+			.max(1)
+	}
+
+	/// Returns a one-line summary of the **Configuration** for logging
+	///
+	/// Contains the name of the indicator, values of all its parameters and its [`min_periods`](IndicatorConfig::min_periods).
+	///
+	/// ```
+	/// use yata::prelude::*;
+	/// use yata::indicators::Trix;
+	///
+	/// assert_eq!(
+	///     Trix::default().describe(),
+	///     "Trix(period1=18, period2=6, method2=ema, source=close), min_periods=53"
+	/// );
+	/// ```
+	fn describe(&self) -> String {
+		let parameters: Vec<String> = self
+			.parameters()
+			.iter()
+			.map(|param| format!("{}={}", param.name, param.value))
+			.collect();
+
+		format!(
+			"{}({}), min_periods={}",
+			self.name(),
+			parameters.join(", "),
+			self.min_periods()
+		)
+	}
 }
 
 /// To initialize an indicator's **State** indicator should implement `IndicatorInitializer`
@@ -39,6 +288,48 @@ pub trait IndicatorInitializer<T: OHLC> {
 	/// Initializes the **State** based on current **Configuration**
 	fn init(self, initial_value: T) -> Result<Self::Instance, Error>;
 
+	/// Initializes the **State** by the first candle of the `history` and then evaluates it over all the `history` candles
+	///
+	/// Returned **State** is already warmed up, so its very next result is meaningful.
+	///
+	/// Returns [`Error::NotReady`] if the `history` is shorter than [`min_periods`](IndicatorConfig::min_periods).
+	///
+	/// ```
+	/// use yata::prelude::*;
+	/// use yata::helpers::RandomCandles;
+	/// use yata::core::Error;
+	/// use yata::indicators::Trix;
+	///
+	/// let candles: Vec<_> = RandomCandles::new().take(80).collect();
+	/// let (history, rest) = candles.split_at(60);
+	///
+	/// let mut state = Trix::default().init_with_history(history).unwrap();
+	/// let mut replayed = Trix::default().init(history[0]).unwrap();
+	/// replayed.over(history);
+	///
+	/// for &candle in rest {
+	///     assert_eq!(state.next(candle).values(), replayed.next(candle).values());
+	/// }
+	///
+	/// assert!(matches!(Trix::default().init_with_history(&candles[..2]), Err(Error::NotReady(53))));
+	/// ```
+	fn init_with_history(self, history: &[T]) -> Result<Self::Instance, Error>
+	where
+		Self: IndicatorConfig + Sized,
+	{
+		let min_periods = self.min_periods();
+		if history.len() < min_periods as usize {
+			return Err(Error::NotReady(min_periods));
+		}
+
+		let mut state = self.init(history[0])?;
+		for &candle in history {
+			state.next(candle);
+		}
+
+		Ok(state)
+	}
+
 	/// Evaluates indicator config over sequence of OHLC and returns sequence of `IndicatorResult`s
 	/// ```
 	/// use yata::prelude::*;
@@ -61,12 +352,218 @@ pub trait IndicatorInitializer<T: OHLC> {
 		let mut state = self.init(over_slice[0])?;
 		Ok(state.over(over_slice))
 	}
+
+	/// Evaluates indicator config over `IntoIterator` of OHLC and returns sequence of `IndicatorResult`s
+	///
+	/// The **State** is initialized by the first candle and then evaluated over all the candles including the first one.
+	/// ```
+	/// use yata::prelude::*;
+	/// use yata::helpers::{RandomCandles};
+	/// use yata::indicators::Trix;
+	///
+	/// let trix = Trix::default();
+	/// let results = trix.over_iter(RandomCandles::new().take(10)).unwrap();
+	/// assert_eq!(results.len(), 10);
+	/// ```
+	fn over_iter<I>(self, candles: I) -> Result<Vec<IndicatorResult>, Error>
+	where
+		I: IntoIterator<Item = T>,
+		Self: Sized,
+	{
+		let mut candles = candles.into_iter().peekable();
+
+		let Some(&first) = candles.peek() else {
+			return Ok(Vec::new());
+		};
+
+		let mut state = self.init(first)?;
+		Ok(state.over_iter(candles))
+	}
 }
 
-// pub trait IndicatorConfigDyn<T: OHLC + 'static>: IndicatorConfig<T> {
-// 	fn validate(&self) -> bool;
+/// Dynamically dispatchable version of [`IndicatorConfig`] and [`IndicatorInitializer`]
+///
+/// It is implemented for every indicator **Configuration**, so indicators may be configured and initialized at runtime,
+/// f.e. by the [`create`](crate::indicators::create) function.
+///
+/// Because of the same method names it is not a part of the main [`prelude`](crate::prelude).
+/// Use [`prelude::dd`](crate::prelude::dd) to import it.
+///
+/// ```
+/// use yata::prelude::dd::*;
+/// use yata::helpers::RandomCandles;
+/// use yata::core::Candle;
+/// use yata::indicators::{Trix, MACD};
+///
+/// let mut configs: Vec<Box<dyn IndicatorConfigDyn<Candle>>> = vec![Box::new(Trix::default()), Box::new(MACD::default())];
+/// assert!(configs[1].set("period1", "10".to_string()).is_none());
+///
+/// let mut candles = RandomCandles::new();
+/// let first = candles.first();
+///
+/// for config in &configs {
+///     let mut state = config.init(first).unwrap();
+///     assert_eq!(state.next(first).size(), config.size());
+/// }
+/// ```
+pub trait IndicatorConfigDyn<T: OHLC>: fmt::Debug {
+	/// Initializes the **State** based on current **Configuration**
+	///
+	/// Returned errors carry the indicator name, see [`Error::in_indicator`].
+	fn init(&self, initial_value: T) -> Result<BoxedIndicatorInstance<T>, Error>;
+
+	/// Validates if **Configuration** is OK
+	fn validate(&self) -> bool;
+
+	/// Sets dynamically **Configuration** parameters
+	///
+	/// Returned errors carry the indicator name, see [`Error::in_indicator`].
+	fn set(&mut self, name: &str, value: String) -> Option<Error>;
 
-// 	fn set(&mut self, name: &str, value: String);
+	/// Returns descriptions of all the **Configuration** parameters
+	fn parameters(&self) -> Vec<ParamDescriptor>;
 
-// 	fn init(&self, initial_value: T) -> Box<dyn IndicatorInstanceDyn<T>>;
-// }
+	/// Returns current typed value of the **Configuration** parameter `name`
+	fn get(&self, name: &str) -> Option<ParamValue>;
+
+	/// Sets dynamically **Configuration** parameter by its typed `value`
+	///
+	/// Returned errors carry the indicator name, see [`Error::in_indicator`].
+	fn set_value(&mut self, name: &str, value: ParamValue) -> Result<(), Error>;
+
+	/// Should return `true` if indicator uses *volume* data
+	fn is_volume_based(&self) -> bool;
+
+	/// Returns a name of the indicator
+	fn name(&self) -> &'static str;
+
+	/// Returns names of the indicator raw values
+	fn value_names(&self) -> &'static [&'static str];
+
+	/// Returns names of the indicator signals
+	fn signal_names(&self) -> &'static [&'static str];
+
+	/// Returns an [`IndicatorResult`](crate::core::IndicatorResult) size processing by the indicator `(count of raw value, count of signals)`
+	fn size(&self) -> (u8, u8);
+
+	/// Returns count of candles the **State** should process before its results become meaningful
+	fn min_periods(&self) -> PeriodType;
+}
+
+impl<T, C> IndicatorConfigDyn<T> for C
+where
+	T: OHLC,
+	C: IndicatorConfig + IndicatorInitializer<T> + fmt::Debug,
+	C::Instance: fmt::Debug + Clone + Send + 'static,
+	<C::Instance as IndicatorInstance<T>>::Config: IndicatorInitializer<T, Instance = C::Instance>,
+{
+	fn init(&self, initial_value: T) -> Result<BoxedIndicatorInstance<T>, Error> {
+		let instance = IndicatorInitializer::init(self.clone(), initial_value)
+			.map_err(|error| error.in_indicator(C::NAME))?;
+		Ok(Box::new(instance))
+	}
+
+	fn validate(&self) -> bool {
+		IndicatorConfig::validate(self)
+	}
+
+	fn set(&mut self, name: &str, value: String) -> Option<Error> {
+		IndicatorConfig::set(self, name, value).map(|error| error.in_indicator(C::NAME))
+	}
+
+	fn parameters(&self) -> Vec<ParamDescriptor> {
+		IndicatorConfig::parameters(self)
+	}
+
+	fn get(&self, name: &str) -> Option<ParamValue> {
+		IndicatorConfig::get(self, name)
+	}
+
+	fn set_value(&mut self, name: &str, value: ParamValue) -> Result<(), Error> {
+		IndicatorConfig::set_value(self, name, value).map_err(|error| error.in_indicator(C::NAME))
+	}
+
+	fn is_volume_based(&self) -> bool {
+		IndicatorConfig::is_volume_based(self)
+	}
+
+	fn name(&self) -> &'static str {
+		IndicatorConfig::name(self)
+	}
+
+	fn value_names(&self) -> &'static [&'static str] {
+		IndicatorConfig::value_names(self)
+	}
+
+	fn signal_names(&self) -> &'static [&'static str] {
+		IndicatorConfig::signal_names(self)
+	}
+
+	fn size(&self) -> (u8, u8) {
+		IndicatorConfig::size(self)
+	}
+
+	fn min_periods(&self) -> PeriodType {
+		IndicatorConfig::min_periods(self)
+	}
+}
+
+#[cfg(all(test, feature = "serde"))]
+mod tests {
+	use super::IndicatorConfig;
+	use crate::core::{Error, Source};
+	use crate::indicators::MACD;
+
+	#[test]
+	fn test_from_json() {
+		let macd = MACD::from_json(
+			r#" { "period1": 9, "period2": 21, "source": "open", "method1": "ema" } "#,
+		)
+		.unwrap();
+
+		assert_eq!(macd.period1, 9);
+		assert_eq!(macd.period2, 21);
+		assert_eq!(macd.source, Source::Open);
+
+		assert!(MACD::from_json("{}").is_ok());
+	}
+
+	#[test]
+	fn test_from_json_non_bmp() {
+		// escaped surrogate pair is a valid JSON string, so it must reach the parameter itself
+		let error = MACD::from_json(r#"{ "source": "\ud83d\ude00" }"#).unwrap_err();
+
+		assert!(
+			matches!(&error, Error::ParameterParse(name, value) if name == "source" && value == "\u{1F600}"),
+			"{:?}",
+			error
+		);
+	}
+
+	#[test]
+	fn test_from_json_errors() {
+		let wrong = [
+			"",
+			"[]",
+			"{",
+			r#"{"source": close}"#,
+			r#"{"period1": 9"#,
+			r#"{"period1": 9,}"#,
+			r#"{"period1" 9}"#,
+			r#"{"period1": null}"#,
+			r#"{"period1": {"a": 1}}"#,
+			r#"{"period1": [9]}"#,
+			r#"{"source": "close}"#,
+			r#"{"source": "\ud83d"}"#,
+			r#"{"period1": 9} 2"#,
+		];
+
+		for s in &wrong {
+			assert!(
+				matches!(MACD::from_json(s), Err(Error::JsonParse(_))),
+				"{}",
+				s
+			);
+		}
+	}
+}
@@ -1,5 +1,9 @@
-use super::{IndicatorConfig, IndicatorResult};
-use crate::core::OHLC;
+use super::{IndicatorConfig, IndicatorInitializer, IndicatorResult, ParamDescriptor, ParamValue};
+use crate::core::{Error, PeriodType, ValueType, OHLC};
+use alloc::boxed::Box;
+use alloc::string::String;
+use alloc::vec::Vec;
+use core::fmt;
 
 /// Base trait for implementing indicators **State**
 pub trait IndicatorInstance<T: OHLC> {
@@ -17,6 +21,36 @@ pub trait IndicatorInstance<T: OHLC> {
 	where
 		Self: Sized;
 
+	/// Validates the `candle` and preceeds it like [`next`](IndicatorInstance::next)
+	///
+	/// Returns [`Error::InvalidCandles`] and keeps the **State** untouched if the candle
+	/// does not pass [`OHLC::validate`], e.g. has `NaN` or infinite values.
+	/// ```
+	/// use yata::prelude::*;
+	/// use yata::core::{Candle, Error, ValueType};
+	/// use yata::helpers::RandomCandles;
+	/// use yata::indicators::Trix;
+	///
+	/// let mut candles = RandomCandles::new();
+	/// let mut state = Trix::default().init(candles.first()).unwrap();
+	///
+	/// assert!(state.try_next(candles.next().unwrap()).is_ok());
+	///
+	/// let candle = Candle { close: ValueType::NAN, ..candles.next().unwrap() };
+	/// assert!(matches!(state.try_next(candle), Err(Error::InvalidCandles)));
+	/// ```
+	#[inline]
+	fn try_next(&mut self, candle: T) -> Result<IndicatorResult, Error>
+	where
+		Self: Sized,
+	{
+		if candle.validate() {
+			Ok(self.next(candle))
+		} else {
+			Err(Error::InvalidCandles)
+		}
+	}
+
 	/// Evaluates the **State** over the given sequence of candles and returns sequence of `IndicatorResult`s.
 	/// ```
 	/// use yata::prelude::*;
@@ -38,6 +72,102 @@ pub trait IndicatorInstance<T: OHLC> {
 		candles.iter().map(|&x| self.next(x)).collect()
 	}
 
+	/// Evaluates the **State** over the given `IntoIterator` of candles and returns sequence of `IndicatorResult`s.
+	///
+	/// The same as [`over`](IndicatorInstance::over), but candles may be produced lazily, without collecting them into a slice first.
+	/// ```
+	/// use yata::prelude::*;
+	/// use yata::helpers::{RandomCandles};
+	/// use yata::indicators::Trix;
+	///
+	/// let mut candles = RandomCandles::new();
+	/// let trix = Trix::default();
+	/// let mut state = trix.init(candles.first()).unwrap();
+	///
+	/// let results = state.over_iter(candles.take(10));
+	/// assert_eq!(results.len(), 10);
+	/// ```
+	#[inline]
+	fn over_iter<I>(&mut self, candles: I) -> Vec<IndicatorResult>
+	where
+		I: IntoIterator<Item = T>,
+		Self: Sized,
+	{
+		candles.into_iter().map(|x| self.next(x)).collect()
+	}
+
+	/// Returns [`IndicatorResult`] for the given `candle` without changing the **State**
+	///
+	/// The same as calling [`next`](IndicatorInstance::next) on a copy of the **State**, so it may be called repeatedly
+	/// over the still forming (incomplete) candle. When the candle is complete, call [`next`](IndicatorInstance::next).
+	/// To keep the revisions of the still forming candle in the **State**, wrap it into [`IndicatorUndo`](crate::core::IndicatorUndo)
+	/// and pass them to [`IndicatorUndo::update`](crate::core::IndicatorUndo::update).
+	/// ```
+	/// use yata::prelude::*;
+	/// use yata::helpers::{RandomCandles};
+	/// use yata::indicators::Trix;
+	///
+	/// let mut candles = RandomCandles::new();
+	/// let mut state = Trix::default().init(candles.first()).unwrap();
+	///
+	/// let candle = candles.next().unwrap();
+	/// let peeked = state.peek(candle);
+	///
+	/// assert_eq!(state.peek(candle).values(), peeked.values());
+	/// assert_eq!(state.next(candle).values(), peeked.values());
+	/// ```
+	#[inline]
+	fn peek(&self, candle: T) -> IndicatorResult
+	where
+		Self: Sized + Clone,
+	{
+		self.clone().next(candle)
+	}
+
+	/// Resets the **State** as if it was just [initialized](IndicatorInitializer::init) by its **Configuration** with the `candle`
+	///
+	/// Useful for restarting the indicator on session boundaries or data gaps.
+	///
+	/// Default implementation creates a new **State** by [`init`](IndicatorInitializer::init), so it reallocates all the inner windows.
+	/// Every indicator of the crate overrides it to [reset](crate::core::Method::reset) its inner methods in place.
+	/// ```
+	/// use yata::prelude::*;
+	/// use yata::helpers::{RandomCandles};
+	/// use yata::indicators::Trix;
+	///
+	/// let candles: Vec<_> = RandomCandles::new().take(20).collect();
+	/// let trix = Trix::default();
+	///
+	/// let mut state = trix.init(candles[0]).unwrap();
+	/// state.over(&candles[..10]);
+	///
+	/// state.reset(candles[10]).unwrap();
+	///
+	/// let results = state.over(&candles[10..]);
+	/// let expected = trix.over(&candles[10..]).unwrap();
+	/// assert!(results.iter().zip(&expected).all(|(a, b)| a.values() == b.values()));
+	/// ```
+	fn reset(&mut self, candle: T) -> Result<(), Error>
+	where
+		Self: Sized,
+		Self::Config: IndicatorInitializer<T, Instance = Self>,
+	{
+		*self = self.config().clone().init(candle)?;
+		Ok(())
+	}
+
+	/// Saves the part of the **State**, which is changed by the next call of [`next`](IndicatorInstance::next), onto the `stack`
+	///
+	/// The same as [`Method::save_undo`](crate::core::Method::save_undo), but for the indicator **State**.
+	/// Returns `false` if the indicator does not support it. Default implementation returns `false`,
+	/// so [`IndicatorUndo`](crate::core::IndicatorUndo) keeps a copy of the whole **State** instead.
+	fn save_undo(&self, _: &mut Vec<ValueType>) -> bool {
+		false
+	}
+
+	/// Reverts the last call of [`next`](IndicatorInstance::next) by the values, saved onto the `stack` by [`save_undo`](IndicatorInstance::save_undo) right before it
+	fn restore_undo(&mut self, _: &mut Vec<ValueType>) {}
+
 	/// Returns true if indicator is using volume data
 	fn is_volume_based(&self) -> bool
 	where
@@ -60,24 +190,186 @@ pub trait IndicatorInstance<T: OHLC> {
 	fn name(&self) -> &'static str {
 		self.config().name()
 	}
+
+	/// Returns names of the indicator raw values
+	///
+	/// See [`IndicatorResult::value_by_name`](crate::core::IndicatorResult::value_by_name)
+	fn value_names(&self) -> &'static [&'static str] {
+		self.config().value_names()
+	}
+
+	/// Returns names of the indicator signals
+	///
+	/// See [`IndicatorResult::signal_by_name`](crate::core::IndicatorResult::signal_by_name)
+	fn signal_names(&self) -> &'static [&'static str] {
+		self.config().signal_names()
+	}
+
+	/// Returns a one-line summary of the indicator **Configuration** for logging
+	///
+	/// See [`IndicatorConfig::describe`].
+	/// Use [`WarmUp::describe`](crate::core::WarmUp::describe) to also include the readiness of the **State**.
+	fn describe(&self) -> String {
+		self.config().describe()
+	}
+}
+
+/// Boxed [`IndicatorInstanceDyn`], which may be sent between threads
+pub type BoxedIndicatorInstance<T> = Box<dyn IndicatorInstanceDyn<T> + Send>;
+
+impl<T: OHLC> Clone for BoxedIndicatorInstance<T> {
+	fn clone(&self) -> Self {
+		(**self).clone_boxed()
+	}
 }
 
-// pub trait IndicatorInstanceDyn<T: OHLC>: Debug {
-// 	fn config(&self) -> &dyn IndicatorConfigDyn<T>;
+/// Dynamically dispatchable version of [`IndicatorInstance`]
+///
+/// It is implemented for every [`IndicatorInstance`], so different indicators may be stored together as `Box<dyn IndicatorInstanceDyn<T>>`.
+/// The **Configuration** type is erased, but its parameters are still available by [`parameters`](IndicatorInstanceDyn::parameters) and [`get`](IndicatorInstanceDyn::get).
+///
+/// Because of the same method names it is not a part of the main [`prelude`](crate::prelude).
+/// Use [`prelude::dd`](crate::prelude::dd) to import it.
+///
+/// ```
+/// use yata::prelude::dd::*;
+/// use yata::helpers::RandomCandles;
+/// use yata::core::{BoxedIndicatorInstance, Candle, ParamValue};
+/// use yata::indicators::{Trix, MACD};
+///
+/// let mut candles = RandomCandles::new();
+/// let first = candles.first();
+///
+/// let mut states: Vec<BoxedIndicatorInstance<Candle>> = vec![
+///     Trix::default().init(first).unwrap(),
+///     MACD::default().init(first).unwrap(),
+/// ];
+///
+/// let candle = candles.next().unwrap();
+/// for state in &mut states {
+///     let result = state.next(candle);
+///     assert_eq!(result.size(), state.size());
+///     assert_eq!(state.peek(candle).values(), state.clone().next(candle).values());
+/// }
+///
+/// let periods: Vec<_> = states.iter().map(|state| state.get("period1")).collect();
+/// assert_eq!(periods, [Some(ParamValue::Int(18)), Some(ParamValue::Int(12))]);
+///
+/// for state in &mut states {
+///     state.reset(first).unwrap();
+/// }
+/// ```
+pub trait IndicatorInstanceDyn<T: OHLC>: fmt::Debug {
+	/// Preceed given candle and returns [`IndicatorResult`](crate::core::IndicatorResult)
+	fn next(&mut self, candle: T) -> IndicatorResult;
+
+	/// Evaluates the **State** over the given sequence of candles and returns sequence of `IndicatorResult`s.
+	fn over(&mut self, candles: &[T]) -> Vec<IndicatorResult>;
+
+	/// Returns true if indicator is using volume data
+	fn is_volume_based(&self) -> bool;
+
+	/// Returns count of indicator's raw values and count of indicator's signals.
+	fn size(&self) -> (u8, u8);
+
+	/// Returns a name of the indicator
+	fn name(&self) -> &'static str;
+
+	/// Returns names of the indicator raw values
+	fn value_names(&self) -> &'static [&'static str];
+
+	/// Returns names of the indicator signals
+	fn signal_names(&self) -> &'static [&'static str];
+
+	/// Returns an [`IndicatorResult`] for the given `candle` without changing the **State**
+	///
+	/// See [`IndicatorInstance::peek`].
+	fn peek(&self, candle: T) -> IndicatorResult;
+
+	/// Resets the **State** as if it was just initialized by its **Configuration** with the `candle`
+	///
+	/// See [`IndicatorInstance::reset`].
+	fn reset(&mut self, candle: T) -> Result<(), Error>;
 
-// 	fn next(&mut self, candle: T) -> IndicatorResult;
+	/// Returns count of candles the **State** should process before its results become meaningful
+	///
+	/// See [`IndicatorConfig::min_periods`].
+	fn min_periods(&self) -> PeriodType;
 
-// 	fn name(&self) -> &str {
-// 		let parts = std::any::type_name::<Self>().split("::");
-// 		parts.last().unwrap_or_default()
-// 	}
+	/// Returns descriptions of all the **Configuration** parameters
+	fn parameters(&self) -> Vec<ParamDescriptor>;
 
-// 	fn is_volume_based(&self) -> bool { false }
+	/// Returns current typed value of the **Configuration** parameter `name`
+	fn get(&self, name: &str) -> Option<ParamValue>;
 
-// 	#[inline]
-// 	fn over(&mut self, candles: &Sequence<T>) -> Vec<IndicatorResult> {
-// 		candles.iter().map(|&x| self.next(x)).collect()
-// 	}
+	/// Clones the **State** into a new [`BoxedIndicatorInstance`]
+	fn clone_boxed(&self) -> BoxedIndicatorInstance<T>;
 
-// 	fn size(&self) -> (u8, u8);
-// }
+	/// Returns a one-line summary of the indicator **Configuration** for logging
+	///
+	/// See [`IndicatorInstance::describe`].
+	fn describe(&self) -> String;
+}
+
+impl<T, I> IndicatorInstanceDyn<T> for I
+where
+	T: OHLC,
+	I: IndicatorInstance<T> + fmt::Debug + Clone + Send + 'static,
+	I::Config: IndicatorInitializer<T, Instance = I>,
+{
+	fn next(&mut self, candle: T) -> IndicatorResult {
+		IndicatorInstance::next(self, candle)
+	}
+
+	fn over(&mut self, candles: &[T]) -> Vec<IndicatorResult> {
+		IndicatorInstance::over(self, candles)
+	}
+
+	fn is_volume_based(&self) -> bool {
+		IndicatorInstance::is_volume_based(self)
+	}
+
+	fn size(&self) -> (u8, u8) {
+		IndicatorInstance::size(self)
+	}
+
+	fn name(&self) -> &'static str {
+		IndicatorInstance::name(self)
+	}
+
+	fn value_names(&self) -> &'static [&'static str] {
+		IndicatorInstance::value_names(self)
+	}
+
+	fn signal_names(&self) -> &'static [&'static str] {
+		IndicatorInstance::signal_names(self)
+	}
+
+	fn peek(&self, candle: T) -> IndicatorResult {
+		IndicatorInstance::peek(self, candle)
+	}
+
+	fn reset(&mut self, candle: T) -> Result<(), Error> {
+		IndicatorInstance::reset(self, candle)
+	}
+
+	fn min_periods(&self) -> PeriodType {
+		self.config().min_periods()
+	}
+
+	fn parameters(&self) -> Vec<ParamDescriptor> {
+		self.config().parameters()
+	}
+
+	fn get(&self, name: &str) -> Option<ParamValue> {
+		self.config().get(name)
+	}
+
+	fn clone_boxed(&self) -> BoxedIndicatorInstance<T> {
+		Box::new(self.clone())
+	}
+
+	fn describe(&self) -> String {
+		self.config().describe()
+	}
+}
@@ -4,10 +4,16 @@
 //!
 //! Every indicator **State** should implement [`IndicatorInstance`].
 
+mod builder;
 mod config;
 mod instance;
+mod param;
 mod result;
+mod set;
 
+pub use builder::*;
 pub use config::*;
 pub use instance::*;
+pub use param::*;
 pub use result::*;
+pub use set::*;
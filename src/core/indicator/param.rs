@@ -0,0 +1,255 @@
+use crate::core::{Source, ValueType};
+use crate::helpers::RegularMethods;
+use alloc::string::{String, ToString};
+use core::fmt;
+use core::ops::Bound;
+
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
+
+/// Kind of an indicator **Configuration** parameter
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[cfg_attr(feature = "serde", serde(rename_all = "lowercase"))]
+pub enum ParamKind {
+	/// Period length or any other count of type [`PeriodType`](crate::core::PeriodType)
+	Period,
+
+	/// Any other integer value
+	Integer,
+
+	/// Floating point value of type [`ValueType`]
+	Float,
+
+	/// [`Source`](crate::core::Source) value
+	Source,
+
+	/// [`RegularMethods`](crate::helpers::RegularMethods) value
+	Method,
+
+	/// Boolean flag
+	Bool,
+
+	/// One of the indicator specific variants
+	Variant,
+}
+
+/// Description of an indicator **Configuration** parameter
+///
+/// Returned by [`IndicatorConfig::parameters`](crate::core::IndicatorConfig::parameters).
+///
+/// `value` and `default` are formatted the way they may be passed back into [`IndicatorConfig::set`](crate::core::IndicatorConfig::set).
+///
+/// # Examples
+///
+/// ```
+/// use core::ops::Bound;
+/// use yata::prelude::*;
+/// use yata::core::ParamKind;
+/// use yata::indicators::Trix;
+///
+/// let mut trix = Trix::default();
+/// trix.period1 = 20;
+///
+/// let params = trix.parameters();
+/// assert_eq!(params[0].name, "period1");
+/// assert_eq!(params[0].kind, ParamKind::Period);
+/// assert_eq!(params[0].value, "20");
+/// assert_eq!(params[0].default, "18");
+/// assert_eq!(params[0].min, Bound::Included(3.0));
+/// ```
+#[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "serde", derive(Serialize))]
+pub struct ParamDescriptor {
+	/// Name of the parameter
+	pub name: &'static str,
+	/// Kind of the parameter value
+	pub kind: ParamKind,
+	/// Current value of the parameter
+	pub value: String,
+	/// Default value of the parameter
+	pub default: String,
+	/// Lower bound of the numeric parameter
+	///
+	/// May depend on current values of the other parameters
+	pub min: Bound<ValueType>,
+	/// Upper bound of the numeric parameter
+	///
+	/// May depend on current values of the other parameters
+	pub max: Bound<ValueType>,
+}
+
+impl ParamDescriptor {
+	/// Creates a new unbounded parameter description
+	pub fn new(
+		name: &'static str,
+		kind: ParamKind,
+		value: impl fmt::Display,
+		default: impl fmt::Display,
+	) -> Self {
+		Self {
+			name,
+			kind,
+			value: value.to_string(),
+			default: default.to_string(),
+			min: Bound::Unbounded,
+			max: Bound::Unbounded,
+		}
+	}
+
+	/// Sets bounds of the numeric parameter
+	#[must_use]
+	pub const fn range(mut self, min: Bound<ValueType>, max: Bound<ValueType>) -> Self {
+		self.min = min;
+		self.max = max;
+		self
+	}
+
+	/// Checks if the numeric `value` is in the parameter's bounds
+	///
+	/// ```
+	/// use core::ops::Bound;
+	/// use yata::core::{ParamDescriptor, ParamKind};
+	///
+	/// let param = ParamDescriptor::new("zone", ParamKind::Float, 0.3, 0.3)
+	///     .range(Bound::Excluded(0.0), Bound::Included(0.5));
+	///
+	/// assert!(param.contains(0.5));
+	/// assert!(!param.contains(0.0));
+	/// ```
+	#[must_use]
+	pub fn contains(&self, value: ValueType) -> bool {
+		core::ops::RangeBounds::contains(&(self.min, self.max), &value)
+	}
+}
+
+/// Typed value of an indicator **Configuration** parameter
+///
+/// May be set by [`IndicatorConfig::set_value`](crate::core::IndicatorConfig::set_value).
+///
+/// # Examples
+///
+/// ```
+/// use yata::core::{ParamValue, Source};
+/// use yata::helpers::RegularMethods;
+///
+/// assert_eq!(ParamValue::from(14).to_string(), "14");
+/// assert_eq!(ParamValue::from(0.25).to_string(), "0.25");
+/// assert_eq!(ParamValue::from(Source::HL2).to_string(), "hl2");
+/// assert_eq!(ParamValue::from(RegularMethods::EMA).to_string(), "ema");
+/// ```
+#[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub enum ParamValue {
+	/// Integer value for [`Period`](ParamKind::Period) and [`Integer`](ParamKind::Integer) parameters
+	Int(i64),
+
+	/// Floating point value for [`Float`](ParamKind::Float) parameters
+	Float(ValueType),
+
+	/// [`Source`] value
+	Source(Source),
+
+	/// [`RegularMethods`] value
+	Method(RegularMethods),
+
+	/// Boolean value
+	Bool(bool),
+
+	/// Name of the indicator specific variant for [`Variant`](ParamKind::Variant) parameters
+	Variant(String),
+}
+
+impl ParamValue {
+	/// Parses the value of the parameter of the `kind` from its string representation
+	///
+	/// Returns `None` if the string can not be parsed into the `kind`.
+	///
+	/// ```
+	/// use yata::core::{ParamKind, ParamValue, Source};
+	///
+	/// assert_eq!(ParamValue::parse(ParamKind::Period, "14"), Some(ParamValue::Int(14)));
+	/// assert_eq!(ParamValue::parse(ParamKind::Source, "hl2"), Some(ParamValue::Source(Source::HL2)));
+	/// assert_eq!(ParamValue::parse(ParamKind::Bool, "yes"), None);
+	/// ```
+	#[must_use]
+	pub fn parse(kind: ParamKind, s: &str) -> Option<Self> {
+		match kind {
+			ParamKind::Period | ParamKind::Integer => s.parse().ok().map(Self::Int),
+			ParamKind::Float => s.parse().ok().map(Self::Float),
+			ParamKind::Source => s.parse().ok().map(Self::Source),
+			ParamKind::Method => s.parse().ok().map(Self::Method),
+			ParamKind::Bool => s.parse().ok().map(Self::Bool),
+			ParamKind::Variant => Some(Self::Variant(s.to_string())),
+		}
+	}
+
+	/// Checks if the value may be assigned to the parameter of the `kind`
+	///
+	/// [`Int`](ParamValue::Int) values may be also assigned to [`Float`](ParamKind::Float) parameters.
+	#[must_use]
+	pub const fn fits(&self, kind: ParamKind) -> bool {
+		matches!(
+			(self, kind),
+			(
+				Self::Int(_),
+				ParamKind::Period | ParamKind::Integer | ParamKind::Float
+			) | (Self::Float(_), ParamKind::Float)
+				| (Self::Source(_), ParamKind::Source)
+				| (Self::Method(_), ParamKind::Method)
+				| (Self::Bool(_), ParamKind::Bool)
+				| (Self::Variant(_), ParamKind::Variant)
+		)
+	}
+}
+
+impl fmt::Display for ParamValue {
+	fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+		match self {
+			Self::Int(value) => value.fmt(f),
+			Self::Float(value) => value.fmt(f),
+			Self::Source(value) => value.fmt(f),
+			Self::Method(value) => value.fmt(f),
+			Self::Bool(value) => value.fmt(f),
+			Self::Variant(value) => value.fmt(f),
+		}
+	}
+}
+
+macro_rules! impl_from_int {
+	($($t:ty),*) => {
+		$(
+			impl From<$t> for ParamValue {
+				fn from(value: $t) -> Self {
+					Self::Int(value.into())
+				}
+			}
+		)*
+	};
+}
+
+impl_from_int!(u8, u16, u32, i8, i16, i32, i64);
+
+impl From<ValueType> for ParamValue {
+	fn from(value: ValueType) -> Self {
+		Self::Float(value)
+	}
+}
+
+impl From<Source> for ParamValue {
+	fn from(value: Source) -> Self {
+		Self::Source(value)
+	}
+}
+
+impl From<RegularMethods> for ParamValue {
+	fn from(value: RegularMethods) -> Self {
+		Self::Method(value)
+	}
+}
+
+impl From<bool> for ParamValue {
+	fn from(value: bool) -> Self {
+		Self::Bool(value)
+	}
+}
@@ -1,116 +1,297 @@
 use crate::core::{Action, ValueType};
-use std::fmt;
+use alloc::vec::Vec;
+use core::convert::TryFrom;
+use core::fmt;
 
 #[cfg(feature = "serde")]
 use serde::{Deserialize, Serialize};
 
 /// Every `Indicator` proceed an input of [OHLC](crate::core::OHLC) or [OHLCV](crate::core::OHLCV) and returns an `IndicatorResult` which consist of some returned raw values and some calculated signals
 ///
-/// `Indicator` may return up to 4 signals and 4 raw values at each step
-#[derive(Clone, Copy)]
+/// `Indicator` may return any count of signals and raw values at each step.
+/// Up to [`SIZE`](IndicatorResult::SIZE) of them are stored inline without any allocation.
+#[derive(Clone)]
 #[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 pub struct IndicatorResult {
-	signals: [Action; IndicatorResult::SIZE],
-	values: [ValueType; IndicatorResult::SIZE],
-	length: (u8, u8),
+	signals: Storage<Action>,
+	values: Storage<ValueType>,
 }
 
 impl IndicatorResult {
-	/// Size of pre-allocated result array
+	/// Count of signals and count of raw values, which are stored inline
+	///
+	/// Results with more signals or raw values are stored on the heap.
 	/// For the most of cases it should not be used anywhere outside this crate
-	pub const SIZE: usize = 4;
+	pub const SIZE: usize = 5;
 
 	/// Returns a slice of signals of current indicator result
 	#[must_use]
 	pub fn signals(&self) -> &[Action] {
-		let len = self.length.1 as usize;
-		&self.signals[..len]
+		self.signals.as_slice()
 	}
 
 	/// Returns a slice of raw indicator values of current indicator result
 	#[must_use]
 	pub fn values(&self) -> &[ValueType] {
-		let len = self.length.0 as usize;
-		&self.values[..len]
+		self.values.as_slice()
 	}
 
 	/// Returns count of signals
+	///
+	/// Saturates at `u8::MAX`
 	#[must_use]
-	pub const fn signals_length(&self) -> u8 {
-		self.length.1
+	pub fn signals_length(&self) -> u8 {
+		u8::try_from(self.signals().len()).unwrap_or(u8::MAX)
 	}
 
 	/// Returns count of raw values
+	///
+	/// Saturates at `u8::MAX`
 	#[must_use]
-	pub const fn values_length(&self) -> u8 {
-		self.length.0
+	pub fn values_length(&self) -> u8 {
+		u8::try_from(self.values().len()).unwrap_or(u8::MAX)
 	}
 
 	/// Returns a tuple of count of raw values and count of signals
 	#[must_use]
-	pub const fn size(&self) -> (u8, u8) {
-		self.length
+	pub fn size(&self) -> (u8, u8) {
+		(self.values_length(), self.signals_length())
 	}
 
 	/// Returns a raw value at given index
 	#[inline]
 	#[must_use]
 	pub fn value(&self, index: usize) -> ValueType {
-		debug_assert!(index < self.length.0 as usize);
-		self.values[index]
+		self.values()[index]
 	}
 
 	/// Returns a signal at given index
 	#[inline]
 	#[must_use]
 	pub fn signal(&self, index: usize) -> Action {
-		debug_assert!(index < self.length.1 as usize);
-		self.signals[index]
+		self.signals()[index]
+	}
+
+	/// Returns a raw value by its name in the `names` list
+	///
+	/// `names` are usually taken from [`IndicatorConfig::VALUE_NAMES`](crate::core::IndicatorConfig::VALUE_NAMES)
+	/// or [`IndicatorInstance::value_names`](crate::core::IndicatorInstance::value_names).
+	/// Returns `None` if there is no such name or no value for it.
+	///
+	/// ```
+	/// use yata::prelude::*;
+	/// use yata::helpers::RandomCandles;
+	/// use yata::indicators::BollingerBands;
+	///
+	/// let mut candles = RandomCandles::new();
+	/// let mut bb = BollingerBands::default().init(candles.first()).unwrap();
+	///
+	/// let names = BollingerBands::VALUE_NAMES;
+	/// let result = bb.next(candles.next().unwrap());
+	/// let upper = result.value_by_name(names, "upper").unwrap();
+	/// let lower = result.value_by_name(names, "lower").unwrap();
+	///
+	/// assert!(upper >= lower);
+	/// assert_eq!(result.value_by_name(names, "unknown"), None);
+	/// ```
+	#[must_use]
+	pub fn value_by_name(&self, names: &[&str], name: &str) -> Option<ValueType> {
+		let index = names.iter().position(|&n| n == name)?;
+		self.values().get(index).copied()
+	}
+
+	/// Returns a signal by its name in the `names` list
+	///
+	/// `names` are usually taken from [`IndicatorConfig::SIGNAL_NAMES`](crate::core::IndicatorConfig::SIGNAL_NAMES)
+	/// or [`IndicatorInstance::signal_names`](crate::core::IndicatorInstance::signal_names).
+	/// Returns `None` if there is no such name or no signal for it.
+	#[must_use]
+	pub fn signal_by_name(&self, names: &[&str], name: &str) -> Option<Action> {
+		let index = names.iter().position(|&n| n == name)?;
+		self.signals().get(index).copied()
+	}
+
+	/// Returns a wrapper, which displays raw values and decoded signals by their names
+	///
+	/// Names are usually taken from [`IndicatorInstance::value_names`](crate::core::IndicatorInstance::value_names)
+	/// and [`IndicatorInstance::signal_names`](crate::core::IndicatorInstance::signal_names).
+	/// Items without a name are displayed by their index.
+	///
+	/// ```
+	/// use yata::core::{Action, IndicatorResult};
+	///
+	/// let result = IndicatorResult::new(&[1.5, -0.25], &[Action::BUY_ALL, Action::None]);
+	/// let named = result.named(&["macd", "signal"], &["cross"]);
+	///
+	/// assert_eq!(named.to_string(), "macd=1.5000, signal=-0.2500, cross=buy(1.00), signal1=none");
+	/// assert_eq!(format!("{:.2}", named), "macd=1.50, signal=-0.25, cross=buy(1.00), signal1=none");
+	/// ```
+	#[must_use]
+	pub const fn named<'a>(
+		&'a self,
+		value_names: &'a [&'a str],
+		signal_names: &'a [&'a str],
+	) -> NamedResult<'a> {
+		NamedResult {
+			result: self,
+			value_names,
+			signal_names,
+		}
 	}
 
 	/// Creates a new instance of `IndicatorResult` with provided *values* and *signals*
+	///
+	/// ```
+	/// use yata::core::{Action, IndicatorResult, ValueType};
+	///
+	/// let values: Vec<_> = (0..8).map(|i| i as ValueType).collect();
+	/// let result = IndicatorResult::new(&values, &[Action::BUY_ALL]);
+	///
+	/// assert_eq!(result.values(), &values[..]);
+	/// assert_eq!(result.size(), (8, 1));
+	/// ```
 	#[inline]
 	#[must_use]
 	pub fn new(values_slice: &[ValueType], signals_slice: &[Action]) -> Self {
-		let mut values = [0 as ValueType; Self::SIZE];
-		let mut signals = [Action::default(); Self::SIZE];
+		Self {
+			values: Storage::new(values_slice),
+			signals: Storage::new(signals_slice),
+		}
+	}
+}
 
-		let values_length = Self::SIZE.min(values_slice.len());
-		values[..values_length].copy_from_slice(&values_slice[..values_length]);
+impl fmt::Debug for IndicatorResult {
+	fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+		f.debug_struct("IndicatorResult")
+			.field("values", &self.values())
+			.field("signals", &self.signals())
+			.finish()
+	}
+}
 
-		let signals_length = Self::SIZE.min(signals_slice.len());
-		signals[..signals_length].copy_from_slice(&signals_slice[..signals_length]);
+/// Formats raw values with the precision of the formatter (`4` digits by default) and signals as ratios
+///
+/// ```
+/// use yata::core::{Action, IndicatorResult};
+///
+/// let result = IndicatorResult::new(&[1.5, -0.25], &[Action::BUY_ALL, Action::None]);
+///
+/// assert_eq!(result.to_string(), "values: [1.5000, -0.2500], signals: [+1.00, N]");
+/// assert_eq!(format!("{:.1}", result), "values: [1.5, -0.2], signals: [+1.00, N]");
+/// ```
+impl fmt::Display for IndicatorResult {
+	fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+		let precision = f.precision().unwrap_or(4);
 
-		#[allow(clippy::cast_possible_truncation)]
-		let length = (values_length as u8, signals_length as u8);
+		f.write_str("values: [")?;
+		for (i, value) in self.values().iter().enumerate() {
+			if i > 0 {
+				f.write_str(", ")?;
+			}
+			write!(f, "{value:.precision$}")?;
+		}
 
-		Self {
-			values,
-			signals,
-			length,
+		f.write_str("], signals: [")?;
+		for (i, signal) in self.signals().iter().enumerate() {
+			if i > 0 {
+				f.write_str(", ")?;
+			}
+			write!(f, "{signal}")?;
 		}
+
+		f.write_str("]")
 	}
 }
 
-impl fmt::Debug for IndicatorResult {
+/// [`IndicatorResult`] with the names of its raw values and signals, which implements [`Display`](fmt::Display)
+///
+/// Created by [`IndicatorResult::named`].
+#[derive(Debug, Clone, Copy)]
+pub struct NamedResult<'a> {
+	result: &'a IndicatorResult,
+	value_names: &'a [&'a str],
+	signal_names: &'a [&'a str],
+}
+
+impl fmt::Display for NamedResult<'_> {
 	fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
-		let values: Vec<String> = self
-			.values
-			.iter()
-			.take(self.length.0 as usize)
-			.map(|&x| format!("{:>7.4}", x))
-			.collect();
-		let signals: Vec<String> = self
-			.signals
-			.iter()
-			.take(self.length.1 as usize)
-			.map(std::string::ToString::to_string)
-			.collect();
-		write!(
-			f,
-			"S: [{:}], V: [{:}]",
-			signals.join(", "),
-			values.join(", ")
-		)
+		let precision = f.precision().unwrap_or(4);
+		let mut separator = "";
+
+		for (i, value) in self.result.values().iter().enumerate() {
+			f.write_str(separator)?;
+			write_name(f, self.value_names, "value", i)?;
+			write!(f, "={value:.precision$}")?;
+			separator = ", ";
+		}
+
+		for (i, signal) in self.result.signals().iter().enumerate() {
+			f.write_str(separator)?;
+			write_name(f, self.signal_names, "signal", i)?;
+
+			match signal.ratio() {
+				Some(ratio) if ratio > 0.0 => write!(f, "=buy({ratio:.2})")?,
+				Some(ratio) if ratio < 0.0 => write!(f, "=sell({:.2})", -ratio)?,
+				_ => f.write_str("=none")?,
+			}
+			separator = ", ";
+		}
+
+		Ok(())
+	}
+}
+
+fn write_name(
+	f: &mut fmt::Formatter<'_>,
+	names: &[&str],
+	fallback: &str,
+	index: usize,
+) -> fmt::Result {
+	match names.get(index) {
+		Some(name) => f.write_str(name),
+		None => write!(f, "{fallback}{index}"),
+	}
+}
+
+/// Small vector, which keeps up to [`IndicatorResult::SIZE`] items inline and spills the rest to the heap
+#[derive(Clone)]
+enum Storage<T: Copy + Default> {
+	Inline(u8, [T; IndicatorResult::SIZE]),
+	Heap(Vec<T>),
+}
+
+impl<T: Copy + Default> Storage<T> {
+	fn new(slice: &[T]) -> Self {
+		if slice.len() > IndicatorResult::SIZE {
+			return Self::Heap(slice.to_vec());
+		}
+
+		let mut items = [T::default(); IndicatorResult::SIZE];
+		items[..slice.len()].copy_from_slice(slice);
+
+		#[allow(clippy::cast_possible_truncation)]
+		Self::Inline(slice.len() as u8, items)
+	}
+
+	fn as_slice(&self) -> &[T] {
+		match self {
+			Self::Inline(length, items) => &items[..*length as usize],
+			Self::Heap(items) => items,
+		}
+	}
+}
+
+#[cfg(feature = "serde")]
+impl<T: Copy + Default + Serialize> Serialize for Storage<T> {
+	fn serialize<S: serde::Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+		self.as_slice().serialize(serializer)
+	}
+}
+
+#[cfg(feature = "serde")]
+impl<'de, T: Copy + Default + Deserialize<'de>> Deserialize<'de> for Storage<T> {
+	fn deserialize<D: serde::Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+		let items = Vec::<T>::deserialize(deserializer)?;
+		Ok(Self::new(&items))
 	}
 }
@@ -0,0 +1,206 @@
+use super::{BoxedIndicatorInstance, IndicatorInitializer, IndicatorInstance, IndicatorResult};
+use crate::core::OHLC;
+use alloc::boxed::Box;
+use alloc::collections::BTreeMap;
+use alloc::string::String;
+use alloc::vec::Vec;
+
+/// A set of indicator **States** keyed by name, which are evaluated together over every candle
+///
+/// With `parallel` feature enabled, indicators are evaluated in parallel on the [`rayon`](https://docs.rs/rayon) thread pool.
+/// It pays off mostly for the historical data ([`over`](IndicatorSet::over)) or for the big sets of indicators.
+///
+/// # Examples
+///
+/// ```
+/// use yata::prelude::*;
+/// use yata::core::IndicatorSet;
+/// use yata::helpers::RandomCandles;
+/// use yata::indicators::{Trix, MACD};
+///
+/// let candles: Vec<_> = RandomCandles::new().take(20).collect();
+///
+/// let mut set = IndicatorSet::new();
+/// set.insert("trix", Trix::default().init(candles[0]).unwrap());
+/// set.insert("macd", MACD::default().init(candles[0]).unwrap());
+///
+/// let results = set.next(candles[1]);
+/// assert_eq!(results.len(), 2);
+/// assert_eq!(results["macd"].size(), (2, 1));
+///
+/// let series = set.over(&candles[2..]);
+/// assert_eq!(series["trix"].len(), 18);
+/// ```
+#[derive(Debug)]
+pub struct IndicatorSet<T: OHLC> {
+	indicators: BTreeMap<String, BoxedIndicatorInstance<T>>,
+}
+
+impl<T> IndicatorSet<T>
+where
+	T: OHLC + Send + Sync,
+{
+	/// Creates an empty `IndicatorSet`
+	#[must_use]
+	pub const fn new() -> Self {
+		Self {
+			indicators: BTreeMap::new(),
+		}
+	}
+
+	/// Inserts an indicator **State** into the set with the `name` key
+	///
+	/// Returns previous **State** with the same `name` if there was any.
+	pub fn insert<I>(
+		&mut self,
+		name: impl Into<String>,
+		instance: I,
+	) -> Option<BoxedIndicatorInstance<T>>
+	where
+		I: IndicatorInstance<T> + core::fmt::Debug + Clone + Send + 'static,
+		I::Config: IndicatorInitializer<T, Instance = I>,
+	{
+		self.indicators.insert(name.into(), Box::new(instance))
+	}
+
+	/// Inserts an already boxed indicator **State** into the set with the `name` key
+	///
+	/// Useful for the indicators created at runtime by [`IndicatorConfigDyn`](crate::core::IndicatorConfigDyn).
+	/// Returns previous **State** with the same `name` if there was any.
+	pub fn insert_boxed(
+		&mut self,
+		name: impl Into<String>,
+		instance: BoxedIndicatorInstance<T>,
+	) -> Option<BoxedIndicatorInstance<T>> {
+		self.indicators.insert(name.into(), instance)
+	}
+
+	/// Removes an indicator **State** with the `name` key from the set and returns it
+	pub fn remove(&mut self, name: &str) -> Option<BoxedIndicatorInstance<T>> {
+		self.indicators.remove(name)
+	}
+
+	/// Returns a reference to the indicator **State** with the `name` key
+	#[must_use]
+	pub fn get(&self, name: &str) -> Option<&BoxedIndicatorInstance<T>> {
+		self.indicators.get(name)
+	}
+
+	/// Returns an iterator over the names of the indicators in the set
+	pub fn names(&self) -> impl Iterator<Item = &str> {
+		self.indicators.keys().map(String::as_str)
+	}
+
+	/// Returns count of indicators in the set
+	#[must_use]
+	pub fn len(&self) -> usize {
+		self.indicators.len()
+	}
+
+	/// Checks if there are no indicators in the set
+	#[must_use]
+	pub fn is_empty(&self) -> bool {
+		self.indicators.is_empty()
+	}
+
+	/// Evaluates every indicator over the `candle` and returns results keyed by the names of the indicators
+	pub fn next(&mut self, candle: T) -> BTreeMap<String, IndicatorResult> {
+		self.evaluate(|indicator| indicator.next(candle))
+	}
+
+	/// Evaluates every indicator over the `candles` and returns sequences of results keyed by the names of the indicators
+	pub fn over(&mut self, candles: &[T]) -> BTreeMap<String, Vec<IndicatorResult>> {
+		self.evaluate(|indicator| indicator.over(candles))
+	}
+
+	#[cfg(not(feature = "parallel"))]
+	fn evaluate<R, F>(&mut self, f: F) -> BTreeMap<String, R>
+	where
+		R: Send,
+		F: Fn(&mut BoxedIndicatorInstance<T>) -> R + Sync,
+	{
+		self.indicators
+			.iter_mut()
+			.map(|(name, indicator)| (name.clone(), f(indicator)))
+			.collect()
+	}
+
+	#[cfg(feature = "parallel")]
+	fn evaluate<R, F>(&mut self, f: F) -> BTreeMap<String, R>
+	where
+		R: Send,
+		F: Fn(&mut BoxedIndicatorInstance<T>) -> R + Sync,
+	{
+		use rayon::prelude::*;
+
+		self.indicators
+			.par_iter_mut()
+			.map(|(name, indicator)| (name.clone(), f(indicator)))
+			.collect()
+	}
+}
+
+impl<T> Default for IndicatorSet<T>
+where
+	T: OHLC + Send + Sync,
+{
+	fn default() -> Self {
+		Self::new()
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	use super::IndicatorSet;
+	use crate::core::{Candle, IndicatorConfig, IndicatorInitializer};
+	use crate::helpers::RandomCandles;
+	use crate::indicators::{AwesomeOscillator, Trix, MACD};
+	use alloc::string::ToString;
+	use alloc::vec::Vec;
+
+	#[test]
+	fn test_indicator_set() {
+		let candles: Vec<Candle> = RandomCandles::new().take(200).collect();
+
+		let mut set = IndicatorSet::new();
+		let mut macd2 = MACD::default();
+		macd2.set("period1", "3".to_string());
+
+		set.insert("trix", Trix::default().init(candles[0]).unwrap());
+		set.insert("macd", MACD::default().init(candles[0]).unwrap());
+		set.insert("macd2", macd2.init(candles[0]).unwrap());
+		set.insert("ao", AwesomeOscillator::default().init(candles[0]).unwrap());
+		assert_eq!(set.len(), 4);
+
+		let expected = [
+			("ao", AwesomeOscillator::default().over(&candles).unwrap()),
+			("macd", MACD::default().over(&candles).unwrap()),
+			("macd2", macd2.over(&candles).unwrap()),
+			("trix", Trix::default().over(&candles).unwrap()),
+		];
+
+		let mut results = set.over(&candles[..80]);
+		set.over(&candles[80..])
+			.into_iter()
+			.for_each(|(name, values)| results.get_mut(&name).unwrap().extend(values));
+
+		assert_eq!(results.len(), expected.len());
+		for (name, values) in &expected {
+			let values2 = &results[*name];
+			assert_eq!(values.len(), values2.len());
+
+			for (a, b) in values.iter().zip(values2) {
+				assert_eq!(a.values(), b.values());
+				assert_eq!(a.signals(), b.signals());
+			}
+		}
+
+		assert!(set.remove("macd2").is_some());
+		assert_eq!(set.names().collect::<Vec<_>>(), ["ao", "macd", "trix"]);
+
+		let candle = candles[0];
+		let results = set.next(candle);
+		assert_eq!(results.len(), 3);
+		assert_eq!(results["macd"].size(), set.get("macd").unwrap().size());
+	}
+}
@@ -1,5 +1,6 @@
-use super::{Error, Sequence};
-use std::fmt;
+use super::{Error, Map, PeriodType, Sequence, Then, ValueType, Zip};
+use alloc::vec::Vec;
+use core::fmt;
 
 /// Trait for creating methods for timeseries
 ///
@@ -46,8 +47,18 @@ use std::fmt;
 /// assert_eq!(s.as_slice(), &[1., 1.5, 2.5, 3.5, 4.5, 5.5, 6.5, 7.5, 8.5, 9.5]);
 /// ```
 ///
-/// # Be advised
-/// There is no `reset` method on the trait. If you need reset a state of the `Method` instance, you should just create a new one.
+/// ### Reset the state
+///
+/// ```
+/// use yata::methods::SMA;
+/// use yata::prelude::*;
+///
+/// let mut ma = SMA::new(3, 1.0).unwrap();
+/// ma.next(10.0);
+///
+/// ma.reset(2.0).unwrap();
+/// assert_eq!(ma.next(2.0), 2.0);
+/// ```
 pub trait Method: fmt::Debug {
 	/// Method parameters
 	type Params;
@@ -64,9 +75,97 @@ pub trait Method: fmt::Debug {
 	/// Generates next output value based on the given input `value`
 	fn next(&mut self, value: Self::Input) -> Self::Output;
 
+	/// Returns an output value for the given input `value` without changing the state of the method
+	///
+	/// The same as calling [`next`](Method::next) on a copy of the method, so it may be called repeatedly,
+	/// f.e. over the still forming bar. To keep the revisions of the still forming bar in the state,
+	/// wrap the method into [`Undo`](crate::core::Undo) and pass them to [`Undo::update`](crate::core::Undo::update).
+	///
+	/// ```
+	/// use yata::methods::EMA;
+	/// use yata::prelude::*;
+	///
+	/// let mut ema = EMA::new(3, 1.0).unwrap();
+	///
+	/// assert_eq!(ema.peek(3.0), 2.0);
+	/// assert_eq!(ema.peek(5.0), 3.0);
+	/// assert_eq!(ema.next(5.0), 3.0);
+	/// ```
+	#[inline]
+	fn peek(&self, value: Self::Input) -> Self::Output
+	where
+		Self: Clone,
+	{
+		self.clone().next(value)
+	}
+
+	/// Resets the state of the method as if it was just created with the same parameters and initial `value`
+	///
+	/// Unlike [`new`](Method::new), reuses already allocated buffers.
+	/// Returns an error in the same cases as [`new`](Method::new) does for the initial `value`.
+	///
+	/// Default implementation returns [`Error::Other`], because the method does not keep its parameters
+	/// to be created again. All the methods of the crate implement it.
+	fn reset(&mut self, _: Self::Input) -> Result<(), Error> {
+		Err(Error::Other(format!(
+			"{} does not support reset",
+			self.name()
+		)))
+	}
+
+	/// Saves the part of the state, which is changed by the next call of [`next`](Method::next), onto the `stack`
+	///
+	/// Together with [`restore_undo`](Method::restore_undo) it reverts the last call of [`next`](Method::next)
+	/// without copying the whole state: window-based methods save only the value, which is going to be pushed out of the window,
+	/// and the scalar accumulators.
+	///
+	/// Returns `false` if the method does not support it. Default implementation returns `false`,
+	/// so [`Undo`](crate::core::Undo) keeps a copy of the whole method instead.
+	///
+	/// ```
+	/// use yata::methods::SMA;
+	/// use yata::prelude::*;
+	///
+	/// let mut sma = SMA::new(2, 1.0).unwrap();
+	/// let mut stack = Vec::new();
+	///
+	/// assert!(sma.save_undo(&mut stack));
+	/// assert_eq!(sma.next(5.0), 3.0);
+	///
+	/// sma.restore_undo(&mut stack);
+	/// assert_eq!(sma.next(3.0), 2.0);
+	/// ```
+	fn save_undo(&self, _: &mut Vec<ValueType>) -> bool {
+		false
+	}
+
+	/// Reverts the last call of [`next`](Method::next) by the values, saved onto the `stack` by [`save_undo`](Method::save_undo) right before it
+	///
+	/// Values are popped from the `stack` in the reverse order.
+	fn restore_undo(&mut self, _: &mut Vec<ValueType>) {}
+
+	/// Returns count of input values the method should process before its output stops depending on the initial value
+	///
+	/// Methods over a window return length of the window. Default implementation returns `1`.
+	/// Recursive methods, like [`EMA`](crate::methods::EMA), never forget the initial value completely, so they also return `1`.
+	///
+	/// ```
+	/// use yata::methods::{Derivative, SMA};
+	/// use yata::prelude::*;
+	///
+	/// let sma = SMA::new(5, 1.0).unwrap();
+	/// assert_eq!(sma.min_periods(), 5);
+	///
+	/// let chain = SMA::new(5, 1.0).unwrap().then(SMA::new(3, 1.0).unwrap());
+	/// assert_eq!(chain.min_periods(), 7);
+	/// ```
+	fn min_periods(&self) -> PeriodType {
+		1
+	}
+
 	/// Returns a name of the method
 	fn name(&self) -> &str {
-		let parts = std::any::type_name::<Self>().split("::");
+		let parts = core::any::type_name::<Self>().split("::");
 		parts.last().unwrap_or_default()
 	}
 
@@ -75,7 +174,7 @@ pub trait Method: fmt::Debug {
 	where
 		Self: Sized,
 	{
-		(std::mem::size_of::<Self>(), std::mem::align_of::<Self>())
+		(core::mem::size_of::<Self>(), core::mem::align_of::<Self>())
 	}
 
 	/// Creates an `iterator` which produces values by the `Method` over given input data `Iterator`
@@ -87,7 +186,7 @@ pub trait Method: fmt::Debug {
 		MethodOverIterator::new(self, input)
 	}
 
-	/// Iterates the `Method` over the given `Iterator` and returns timeserie of output values
+	/// Iterates the `Method` over the given input values and returns timeserie of output values
 	///
 	/// # Guarantees
 	///
@@ -113,13 +212,81 @@ pub trait Method: fmt::Debug {
 	/// let result = ma.over(s.iter().copied());
 	/// assert_eq!(result.len(), s.len());
 	/// ```
+	///
+	/// Any [`IntoIterator`] may be used as an input:
+	/// ```
+	/// use yata::methods::SMA;
+	/// use yata::prelude::*;
+	///
+	/// let mut ma = SMA::new(2, 1.0).unwrap();
+	///
+	/// let result = ma.over(vec![1., 2., 3.]);
+	/// assert_eq!(result.as_slice(), &[1., 1.5, 2.5]);
+	/// ```
 	#[inline]
 	fn over<I>(&mut self, sequence: I) -> Sequence<Self::Output>
 	where
-		I: Iterator<Item = Self::Input>,
+		I: IntoIterator<Item = Self::Input>,
+		Self: Sized,
+	{
+		sequence.into_iter().map(|x| self.next(x)).collect()
+	}
+
+	/// Evaluates the `Method` over the given slice of input values and returns a vector of output values
+	///
+	/// The length of an output vector is always equal to the length of the input slice.
+	///
+	/// Default implementation just calls [`next`](Method::next) for every input value.
+	/// Methods may override it with an optimized implementation for the whole slice.
+	///
+	/// # Examples
+	///
+	/// ```
+	/// use yata::methods::SMA;
+	/// use yata::prelude::*;
+	///
+	/// let s = [1., 2., 3., 4., 5.];
+	/// let mut ma = SMA::new(2, s[0]).unwrap();
+	///
+	/// assert_eq!(ma.apply_slice(&s), vec![1., 1.5, 2.5, 3.5, 4.5]);
+	/// ```
+	#[inline]
+	fn apply_slice(&mut self, slice: &[Self::Input]) -> Vec<Self::Output> {
+		slice.iter().map(|&x| self.next(x)).collect()
+	}
+
+	/// Chains the method with the `next` one, so output of this method becomes an input of the `next` method
+	///
+	/// See [`Then`].
+	fn then<B>(self, next: B) -> Then<Self, B>
+	where
+		B: Method<Input = Self::Output>,
+		Self: Sized,
+	{
+		Then::from_parts(self, next)
+	}
+
+	/// Pairs the method with the `other` one over the same input, so the output is a tuple of both outputs
+	///
+	/// See [`Zip`].
+	fn zip<B>(self, other: B) -> Zip<Self, B>
+	where
+		B: Method<Input = Self::Input>,
+		Self: Sized,
+	{
+		Zip::from_parts(self, other)
+	}
+
+	/// Transforms every output value of the method by the function `f`
+	///
+	/// See [`Map`].
+	fn map<O, F>(self, f: F) -> Map<Self, F>
+	where
+		F: Fn(Self::Output) -> O,
+		O: Copy,
 		Self: Sized,
 	{
-		sequence.map(|x| self.next(x)).collect()
+		Map::from_parts(self, f)
 	}
 }
 
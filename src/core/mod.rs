@@ -3,22 +3,61 @@
 //! Some useful features and definitions
 
 mod action;
+mod adapters;
+mod anchor;
+mod bars;
+mod candle_math;
 mod candles;
+mod combinators;
+mod ensemble;
 mod errors;
+#[cfg(not(feature = "std"))]
+mod float;
+mod gap;
+mod histogram;
 mod indicator;
 mod method;
+mod nan;
 mod ohlcv;
+mod resampler;
 mod sequence;
+#[cfg(feature = "snapshot")]
+mod snapshot;
+mod ticks;
+mod undo;
+mod universe;
+mod warm_up;
 mod window;
 
 pub use action::Action;
+pub use adapters::{IndicatorIter, IteratorExt, MethodIter, ToCandle};
+pub use anchor::{Anchor, Anchored, EveryBars, ExternalAnchor, SessionAnchor, TimeBoundary};
+pub use bars::{BarConverter, RangeBars, TickBars, Trade, VolumeBars};
 pub use candles::*;
+pub use combinators::{Map, Then, Zip};
+pub use ensemble::{Ensemble, Vote};
 pub use errors::Error;
+#[cfg(not(feature = "std"))]
+pub(crate) use float::Float;
+pub use gap::{GapGuard, GapPolicy, IndicatorGapGuard};
+pub use histogram::Histogram;
 pub use indicator::*;
 pub use method::Method;
-pub use ohlcv::{OHLC, OHLCV};
-pub use sequence::Sequence;
-pub use window::Window;
+pub use nan::{NanGuard, NanPolicy};
+pub use ohlcv::{Timestamped, OHLC, OHLCV};
+pub use resampler::{ResampleBy, Resampler};
+#[cfg(feature = "decimal")]
+pub use rust_decimal::Decimal;
+pub use sequence::{Sequence, Series};
+#[cfg(feature = "snapshot")]
+pub(crate) use snapshot::snapshot_tags;
+#[cfg(feature = "snapshot")]
+pub use snapshot::{Snapshot, STATE_FORMAT_VERSION};
+pub use ticks::{TickScale, Ticks};
+pub use undo::{IndicatorUndo, Undo};
+pub use universe::{CrossSection, Universe};
+pub use warm_up::WarmUp;
+pub use window::{Window, WindowIterator};
 
 /// Main value type for calculations
 ///
@@ -30,11 +69,18 @@ pub use window::Window;
 ///
 /// ```toml
 /// [dependencies]
-/// yata = { value_type_f32 = true }
+/// yata = { version = "0.2", features = ["value_type_f32"] }
 /// ```
 ///
 /// Read more at [Features section](https://doc.rust-lang.org/cargo/reference/features.html#the-features-section)
 ///
+/// Be advised, `f32` keeps only ~7 significant digits, so rolling methods (like [`SMA`](crate::methods::SMA))
+/// lose relative precision on values close to zero, f.e. on the differences of close prices.
+///
+/// `ValueType` can not be a decimal type: methods rely on the floating point math (`sqrt`, `exp`, `ln`, `NaN` for missing values).
+/// For the long cumulative values without floating point drift enable `decimal` feature, which adds `rust_decimal::Decimal`
+/// methods to `methods` module (`DecimalSMA`, `DecimalADI` and `DecimalOBV`).
+///
 /// # See also
 ///
 /// [`PeriodType`]
@@ -57,7 +103,7 @@ pub type ValueType = f32;
 ///
 /// ```toml
 /// [dependencies]
-/// yata = { period_type_u16 = true }
+/// yata = { version = "0.2", features = ["period_type_u16"] }
 /// ```
 ///
 /// Read more at [Features section](https://doc.rust-lang.org/cargo/reference/features.html#the-features-section)
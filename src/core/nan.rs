@@ -0,0 +1,191 @@
+use super::{Error, Method, PeriodType, ValueType};
+use core::fmt;
+
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
+
+/// Policy of processing not finite (`NaN` or infinite) input values
+///
+/// Single `NaN` permanently poisons the state of recursive methods like [`EMA`](crate::methods::EMA) or [`RMA`](crate::methods::RMA).
+///
+/// See [`NanGuard`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub enum NanPolicy {
+	/// Not finite values are passed into the method as is
+	Propagate,
+
+	/// Not finite values are ignored and the last output is returned again
+	Skip,
+
+	/// Not finite values are replaced by the last finite input value
+	SubstituteLast,
+}
+
+/// Wraps a [`Method`] over [`ValueType`] and applies [`NanPolicy`] to not finite input values
+///
+/// Initial value must be finite, otherwise [`Error::InvalidCandles`] is returned.
+/// Count of not finite inputs is tracked with any policy, so the problems of the data feed may be detected.
+///
+/// # Examples
+///
+/// ```
+/// use yata::core::{NanGuard, NanPolicy, ValueType};
+/// use yata::methods::EMA;
+/// use yata::prelude::*;
+///
+/// let mut ema = NanGuard::<EMA>::new((NanPolicy::SubstituteLast, 3), 10.0).unwrap();
+///
+/// assert_eq!(ema.next(12.0), 11.0);
+/// assert_eq!(ema.next(ValueType::NAN), 11.5);
+/// assert_eq!(ema.next(12.0), 11.75);
+/// assert_eq!(ema.invalid_count(), 1);
+///
+/// assert!(NanGuard::<EMA>::new((NanPolicy::Skip, 3), ValueType::NAN).is_err());
+/// ```
+#[derive(Clone)]
+pub struct NanGuard<M: Method<Input = ValueType>> {
+	method: M,
+	policy: NanPolicy,
+	last_input: ValueType,
+	last_output: Option<M::Output>,
+	invalid_count: usize,
+}
+
+impl<M: Method<Input = ValueType>> NanGuard<M> {
+	/// Returns the applied [`NanPolicy`]
+	pub const fn policy(&self) -> NanPolicy {
+		self.policy
+	}
+
+	/// Returns a reference to the wrapped method
+	pub const fn method(&self) -> &M {
+		&self.method
+	}
+
+	/// Returns count of not finite input values since the method was created or reset
+	pub const fn invalid_count(&self) -> usize {
+		self.invalid_count
+	}
+}
+
+impl<M: Method<Input = ValueType>> fmt::Debug for NanGuard<M> {
+	fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+		f.debug_struct("NanGuard")
+			.field("method", &self.method)
+			.field("policy", &self.policy)
+			.field("invalid_count", &self.invalid_count)
+			.finish_non_exhaustive()
+	}
+}
+
+impl<M: Method<Input = ValueType>> Method for NanGuard<M> {
+	type Params = (NanPolicy, M::Params);
+	type Input = ValueType;
+	type Output = M::Output;
+
+	fn new((policy, params): Self::Params, value: Self::Input) -> Result<Self, Error> {
+		if !value.is_finite() {
+			return Err(Error::InvalidCandles);
+		}
+
+		Ok(Self {
+			method: M::new(params, value)?,
+			policy,
+			last_input: value,
+			last_output: None,
+			invalid_count: 0,
+		})
+	}
+
+	#[inline]
+	fn next(&mut self, value: Self::Input) -> Self::Output {
+		if value.is_finite() {
+			self.last_input = value;
+		} else {
+			self.invalid_count += 1;
+
+			match (self.policy, self.last_output) {
+				(NanPolicy::Propagate, _) => {}
+				(NanPolicy::Skip, Some(output)) => return output,
+				(NanPolicy::Skip | NanPolicy::SubstituteLast, _) => {
+					return self.next(self.last_input);
+				}
+			}
+		}
+
+		let output = self.method.next(value);
+		self.last_output = Some(output);
+		output
+	}
+
+	fn min_periods(&self) -> PeriodType {
+		self.method.min_periods()
+	}
+
+	fn reset(&mut self, value: Self::Input) -> Result<(), Error> {
+		if !value.is_finite() {
+			return Err(Error::InvalidCandles);
+		}
+
+		self.method.reset(value)?;
+		self.last_input = value;
+		self.last_output = None;
+		self.invalid_count = 0;
+		Ok(())
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	use super::{NanGuard, NanPolicy};
+	use crate::core::{Method, ValueType};
+	use crate::helpers::{assert_eq_float, RandomCandles};
+	use crate::methods::{EMA, RMA};
+	use alloc::vec::Vec;
+
+	#[test]
+	fn test_nan_guard() {
+		let values: Vec<ValueType> = RandomCandles::new().take(100).map(|c| c.close).collect();
+		let is_invalid = |i: usize| i % 11 == 3 || i % 23 == 4;
+		let invalid = [ValueType::NAN, ValueType::INFINITY, ValueType::NEG_INFINITY];
+
+		let mut skip = NanGuard::<RMA>::new((NanPolicy::Skip, 4), values[0]).unwrap();
+		let mut substitute =
+			NanGuard::<RMA>::new((NanPolicy::SubstituteLast, 4), values[0]).unwrap();
+		let mut propagate = NanGuard::<EMA>::new((NanPolicy::Propagate, 4), values[0]).unwrap();
+
+		let mut rma_skip = RMA::new(4, values[0]).unwrap();
+		let mut rma_substitute = RMA::new(4, values[0]).unwrap();
+		let mut last_output = values[0];
+		let mut last_value = values[0];
+		let mut count = 0;
+
+		for (i, &value) in values.iter().enumerate() {
+			if is_invalid(i) {
+				let value = invalid[count % invalid.len()];
+				count += 1;
+
+				assert_eq_float(last_output, skip.next(value));
+				assert_eq_float(rma_substitute.next(last_value), substitute.next(value));
+				assert!(!propagate.next(value).is_finite());
+			} else {
+				last_output = rma_skip.next(value);
+				last_value = value;
+
+				assert_eq_float(last_output, skip.next(value));
+				assert_eq_float(rma_substitute.next(value), substitute.next(value));
+				propagate.next(value);
+			}
+
+			assert_eq!(skip.invalid_count(), count);
+			assert_eq!(substitute.invalid_count(), count);
+		}
+
+		assert!(propagate.method().clone().next(1.0).is_nan());
+
+		substitute.reset(values[0]).unwrap();
+		assert_eq!(substitute.invalid_count(), 0);
+		assert!(substitute.reset(ValueType::NAN).is_err());
+	}
+}
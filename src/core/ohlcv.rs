@@ -1,5 +1,8 @@
-use super::{Sequence, Source, ValueType};
-use std::fmt::Debug;
+use super::{Sequence, Series, Source, ValueType};
+use core::fmt::Debug;
+
+#[cfg(not(feature = "std"))]
+use crate::core::Float as _;
 
 /// Basic trait for implementing [Open-High-Low-Close timeseries data](https://en.wikipedia.org/wiki/Candlestick_chart).
 ///
@@ -72,6 +75,51 @@ pub trait OHLC: Copy + Debug + Default {
 		(self.high() + self.low()) * 0.5
 	}
 
+	/// Calculates arithmetic average of `open`, `high`, `low` and `close` values of the candle
+	///
+	/// # Examples
+	///
+	/// ```
+	/// use yata::prelude::*;
+	/// use yata::core::Candle;
+	///
+	/// let candle = Candle {
+	///     open: 6.0,
+	///     high: 10.0,
+	///     low: 5.0,
+	///     close: 9.0,
+	///     ..Candle::default()
+	/// };
+	///
+	/// assert_eq!(candle.ohlc4(), 7.5);
+	/// ```
+	#[inline]
+	fn ohlc4(&self) -> ValueType {
+		(self.open() + self.high() + self.low() + self.close()) * 0.25
+	}
+
+	/// Calculates weighted close price of the candle: \(High + Low + 2 * Close\) / 4
+	///
+	/// # Examples
+	///
+	/// ```
+	/// use yata::prelude::*;
+	/// use yata::core::Candle;
+	///
+	/// let candle = Candle {
+	///     high: 10.0,
+	///     low: 5.0,
+	///     close: 9.0,
+	///     ..Candle::default()
+	/// };
+	///
+	/// assert_eq!(candle.wclose(), 8.25);
+	/// ```
+	#[inline]
+	fn wclose(&self) -> ValueType {
+		self.close().mul_add(2., self.high() + self.low()) * 0.25
+	}
+
 	/// CLV = \[\(close - low\) - \(high - close\)\] / \(high - low\)
 	///
 	/// # Examples
@@ -161,7 +209,10 @@ pub trait OHLC: Copy + Debug + Default {
 
 	/// Validates candle attributes
 	///
-	/// Returns `true` if validates OK
+	/// Returns `true` if validates OK: all the values are finite and positive,
+	/// *high* is not less than any other value and *low* is not more than any other value.
+	///
+	/// See also [`StrictCandle`](crate::core::StrictCandle).
 	///
 	/// # Examples
 	///
@@ -186,12 +237,26 @@ pub trait OHLC: Copy + Debug + Default {
 	///     ..Candle::default()
 	/// };
 	///
+	/// let candle3 = Candle {
+	///     open: 6.0, // cannot be less than low
+	///     high: 10.0,
+	///     low: 7.0,
+	///     close: 8.0,
+	///
+	///     ..Candle::default()
+	/// };
+	///
 	/// assert!(!OHLC::validate(&candle1));
 	/// assert!(!OHLC::validate(&candle2));
+	/// assert!(!OHLC::validate(&candle3));
 	/// ```
 	#[inline]
 	fn validate(&self) -> bool {
-		!(self.close() > self.high() || self.close() < self.low() || self.high() < self.low())
+		!(self.close() > self.high()
+			|| self.close() < self.low()
+			|| self.open() > self.high()
+			|| self.open() < self.low()
+			|| self.high() < self.low())
 			&& self.close() > 0.
 			&& self.open() > 0.
 			&& self.high() > 0.
@@ -218,6 +283,8 @@ pub trait OHLC: Copy + Debug + Default {
 	/// };
 	/// assert_eq!(OHLCV::source(&candle, Source::Low), 7.0);
 	/// assert_eq!(OHLCV::source(&candle, "close".to_string().parse().unwrap()), 10.0);
+	/// assert_eq!(OHLCV::source(&candle, "ohlc4".parse().unwrap()), 11.0);
+	/// assert_eq!(OHLCV::source(&candle, Source::WClose), 10.5);
 	/// ```
 	#[inline]
 	fn source(&self, source: Source) -> ValueType {
@@ -227,8 +294,10 @@ pub trait OHLC: Copy + Debug + Default {
 			Source::Low => self.low(),
 			Source::TP => self.tp(),
 			Source::HL2 => self.hl2(),
+			Source::OHLC4 => self.ohlc4(),
+			Source::WClose => self.wclose(),
 			Source::Open => self.open(),
-			Source::Volume|Source::VolumedPrice => ValueType::NAN,
+			Source::Volume | Source::VolumedPrice | Source::Custom => ValueType::NAN,
 		}
 	}
 }
@@ -253,7 +322,7 @@ pub trait OHLCV: OHLC {
 	fn volume(&self) -> ValueType;
 
 	/// Volumed price
-	/// 
+	///
 	/// Same as [`OHLC::tp()`] * [`OHLCV::volume()`]
 	fn volumed_price(&self) -> ValueType {
 		self.tp() * self.volume()
@@ -261,7 +330,7 @@ pub trait OHLCV: OHLC {
 
 	/// Validates candle attributes
 	///
-	/// See more at [`OHLC::validate()`].
+	/// Additionally to [`OHLC::validate()`] checks *volume* is finite and not negative.
 	#[inline]
 	fn validate(&self) -> bool {
 		OHLC::validate(self) && self.volume() >= 0. && self.volume().is_finite()
@@ -280,6 +349,16 @@ pub trait OHLCV: OHLC {
 	}
 }
 
+/// Extension trait for the timeseries data, which knows the time of its period
+///
+/// Time is represented as count of milliseconds since the Unix epoch (`1970-01-01T00:00:00Z`).
+///
+/// See [`TimedCandle`](crate::core::TimedCandle).
+pub trait Timestamped {
+	/// Should return a time of the beginning of the period in milliseconds since the Unix epoch
+	fn timestamp(&self) -> i64;
+}
+
 impl<T: OHLC> Sequence<T> {
 	/// Validates a whole sequence
 	///
@@ -289,6 +368,28 @@ impl<T: OHLC> Sequence<T> {
 	}
 }
 
+impl<T: OHLCV> Sequence<T> {
+	/// Returns [`Series`] of the candles [`Source`] values
+	///
+	/// # Examples
+	///
+	/// ```
+	/// use yata::core::{Candles, Source};
+	/// use yata::helpers::RandomCandles;
+	///
+	/// let candles: Candles<_> = RandomCandles::new().take(10).collect();
+	/// let closes = candles.source(Source::Close);
+	///
+	/// assert_eq!(closes[3], candles[3].close);
+	/// ```
+	#[must_use]
+	pub fn source(&self, source: Source) -> Series {
+		self.iter()
+			.map(|candle| OHLCV::source(candle, source))
+			.collect()
+	}
+}
+
 impl OHLC for (ValueType, ValueType, ValueType, ValueType) {
 	#[inline]
 	fn open(&self) -> ValueType {
@@ -0,0 +1,248 @@
+use super::{Error, TimedCandle, Timestamped, OHLCV};
+
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
+
+/// Rule of grouping lower timeframe candles into a higher timeframe candle
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub enum ResampleBy {
+	/// Every bar consists of the fixed count of candles
+	Count(usize),
+
+	/// Every bar covers the fixed duration in milliseconds, aligned to the Unix epoch
+	Duration(i64),
+}
+
+/// Aggregates lower timeframe candles into higher timeframe ones
+///
+/// *Open* of the bar is taken from the first candle, *close* from the last one,
+/// *high* and *low* are the extremes over all the candles and *volume* is the sum of all the volumes.
+///
+/// With [`ResampleBy::Count`] the bar is emitted as soon as the last candle of the bar is pushed,
+/// and its timestamp is taken from the first candle.
+///
+/// With [`ResampleBy::Duration`] the bar is emitted when the first candle of the next bar is pushed,
+/// because only then the bar is known to be complete. Timestamp of the bar is the beginning of its period.
+/// Candles must be pushed in the order of their timestamps.
+///
+/// Unfinished bar may be taken by [`current`](Resampler::current) or [`flush`](Resampler::flush).
+///
+/// # Examples
+///
+/// ```
+/// use yata::core::{Candle, Resampler, TimedCandle, ValueType};
+///
+/// const MINUTE: i64 = 60_000;
+///
+/// let mut resampler = Resampler::by_duration(5 * MINUTE).unwrap();
+///
+/// let bars: Vec<_> = (0..12)
+///     .filter_map(|i| {
+///         let price = i as ValueType;
+///         let candle = Candle::from((price, price + 1.0, price - 1.0, price + 0.5, 10.0));
+///         resampler.next(TimedCandle::new(i * MINUTE, candle))
+///     })
+///     .collect();
+///
+/// assert_eq!(bars.len(), 2);
+/// assert_eq!(bars[1].timestamp, 5 * MINUTE);
+/// assert_eq!(bars[1].open, 5.0);
+/// assert_eq!(bars[1].high, 10.0);
+/// assert_eq!(bars[1].low, 4.0);
+/// assert_eq!(bars[1].close, 9.5);
+/// assert_eq!(bars[1].volume, 50.0);
+///
+/// let last = resampler.flush().unwrap();
+/// assert_eq!(last.timestamp, 10 * MINUTE);
+/// assert_eq!(last.volume, 20.0);
+/// ```
+#[derive(Debug, Clone, Copy)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct Resampler {
+	by: ResampleBy,
+	bar: Option<TimedCandle>,
+	count: usize,
+}
+
+impl Resampler {
+	/// Creates a new `Resampler` with the grouping rule `by`
+	///
+	/// Returns an error if the count or the duration is not positive.
+	pub const fn new(by: ResampleBy) -> Result<Self, Error> {
+		let is_valid = match by {
+			ResampleBy::Count(count) => count > 0,
+			ResampleBy::Duration(duration) => duration > 0,
+		};
+
+		if !is_valid {
+			return Err(Error::WrongMethodParameters);
+		}
+
+		Ok(Self {
+			by,
+			bar: None,
+			count: 0,
+		})
+	}
+
+	/// Creates a new `Resampler`, which groups every `count` candles into a bar
+	pub const fn by_count(count: usize) -> Result<Self, Error> {
+		Self::new(ResampleBy::Count(count))
+	}
+
+	/// Creates a new `Resampler`, which groups candles into bars of `duration` milliseconds
+	pub const fn by_duration(duration: i64) -> Result<Self, Error> {
+		Self::new(ResampleBy::Duration(duration))
+	}
+
+	/// Returns the grouping rule
+	#[must_use]
+	pub const fn by(&self) -> ResampleBy {
+		self.by
+	}
+
+	/// Pushes the next lower timeframe `candle` and returns a completed bar if there is any
+	pub fn next<T: OHLCV + Timestamped>(&mut self, candle: T) -> Option<TimedCandle> {
+		match self.by {
+			ResampleBy::Count(count) => {
+				self.merge(candle, candle.timestamp());
+				self.count += 1;
+
+				if self.count == count {
+					self.flush()
+				} else {
+					None
+				}
+			}
+			ResampleBy::Duration(duration) => {
+				let timestamp = candle.timestamp().div_euclid(duration) * duration;
+
+				let completed = match self.bar {
+					Some(bar) if bar.timestamp != timestamp => self.flush(),
+					_ => None,
+				};
+
+				self.merge(candle, timestamp);
+				self.count += 1;
+
+				completed
+			}
+		}
+	}
+
+	/// Returns the unfinished bar if there is any
+	#[must_use]
+	pub const fn current(&self) -> Option<TimedCandle> {
+		self.bar
+	}
+
+	/// Returns the unfinished bar if there is any and starts a new one
+	pub fn flush(&mut self) -> Option<TimedCandle> {
+		self.count = 0;
+		self.bar.take()
+	}
+
+	fn merge<T: OHLCV>(&mut self, candle: T, timestamp: i64) {
+		let bar = self.bar.map_or_else(
+			|| TimedCandle::new(timestamp, candle),
+			|bar| TimedCandle {
+				high: bar.high.max(candle.high()),
+				low: bar.low.min(candle.low()),
+				close: candle.close(),
+				volume: bar.volume + candle.volume(),
+				..bar
+			},
+		);
+
+		self.bar = Some(bar);
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	use super::{ResampleBy, Resampler};
+	use crate::core::{Candle, TimedCandle, ValueType, OHLC, OHLCV};
+	use crate::helpers::{assert_eq_float, RandomCandles};
+	use alloc::vec::Vec;
+	use core::convert::TryFrom;
+
+	#[test]
+	fn test_resampler_by_count() {
+		let candles: Vec<Candle> = RandomCandles::new().take(100).collect();
+
+		for count in 1..15 {
+			let mut resampler = Resampler::by_count(count).unwrap();
+
+			let mut bars: Vec<_> = (0..)
+				.zip(&candles)
+				.filter_map(|(i, &candle)| resampler.next(TimedCandle::new(i, candle)))
+				.collect();
+			bars.extend(resampler.flush());
+
+			let chunks: Vec<_> = candles.chunks(count).collect();
+			assert_eq!(bars.len(), chunks.len());
+
+			let mut timestamp = 0;
+			for (bar, chunk) in bars.iter().zip(&chunks) {
+				let high = chunk
+					.iter()
+					.map(OHLC::high)
+					.fold(ValueType::MIN, ValueType::max);
+				let low = chunk
+					.iter()
+					.map(OHLC::low)
+					.fold(ValueType::MAX, ValueType::min);
+				let volume: ValueType = chunk.iter().map(OHLCV::volume).sum();
+
+				assert_eq!(bar.timestamp, timestamp);
+				assert_eq_float(chunk[0].open(), bar.open);
+				assert_eq_float(chunk[chunk.len() - 1].close(), bar.close);
+				assert_eq_float(high, bar.high);
+				assert_eq_float(low, bar.low);
+				assert_eq_float(volume, bar.volume);
+
+				timestamp += i64::try_from(chunk.len()).unwrap();
+			}
+
+			assert!(resampler.current().is_none());
+		}
+	}
+
+	#[test]
+	fn test_resampler_by_duration() {
+		let candles: Vec<Candle> = RandomCandles::new().take(100).collect();
+		let mut resampler = Resampler::by_duration(10).unwrap();
+		let mut previous = [Candle::default(); 2];
+
+		// two candles per every 10 ms, starting from negative time
+		for (i, &candle) in (0_i64..).zip(&candles) {
+			let timestamp = (i / 2) * 10 + (i % 2) * 5 - 200;
+			let bar = resampler.next(TimedCandle::new(timestamp, candle));
+
+			if i % 2 == 0 && i > 0 {
+				let bar = bar.unwrap();
+				let [first, second] = previous;
+
+				assert_eq!(bar.timestamp, timestamp - 10);
+				assert_eq_float(first.open(), bar.open);
+				assert_eq_float(second.close(), bar.close);
+				assert_eq_float(first.high().max(second.high()), bar.high);
+				assert_eq_float(first.low().min(second.low()), bar.low);
+				assert_eq_float(first.volume() + second.volume(), bar.volume);
+			} else {
+				assert!(bar.is_none());
+			}
+
+			assert_eq!(
+				resampler.current().unwrap().timestamp,
+				timestamp - timestamp.rem_euclid(10)
+			);
+
+			previous = [previous[1], candle];
+		}
+
+		assert!(Resampler::new(ResampleBy::Duration(0)).is_err());
+		assert!(Resampler::new(ResampleBy::Count(0)).is_err());
+	}
+}
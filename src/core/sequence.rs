@@ -1,11 +1,12 @@
 #![allow(clippy::use_self)]
 #[allow(unused_imports)]
 use super::Method;
+use super::ValueType;
+use alloc::vec::Vec;
+use core::iter::FromIterator;
+use core::ops::{Add, Bound, Deref, DerefMut, Div, Mul, RangeBounds, Sub};
 #[cfg(feature = "serde")]
 use serde::{Deserialize, Serialize};
-use std::iter::FromIterator;
-use std::ops::Deref;
-use std::ops::DerefMut;
 
 /// Wrapper for time series data vectors
 #[derive(Debug, Clone, PartialEq)]
@@ -65,8 +66,156 @@ impl<T: Copy> Sequence<T> {
 	) -> Sequence<O> {
 		self.iter().map(|&x| method.next(x)).collect()
 	}
+
+	/// Returns new `sequence` with the values shifted by `n` positions
+	///
+	/// Positive `n` moves values forward (lags them), negative `n` moves values backward.
+	/// Length of the `sequence` is preserved, so the freed positions are filled with `fill`.
+	///
+	/// # Examples
+	///
+	/// ```
+	/// use yata::core::Series;
+	///
+	/// let s = Series::from(vec![1., 2., 3., 4.]);
+	///
+	/// assert_eq!(s.shift(1, 0.).as_slice(), &[0., 1., 2., 3.]);
+	/// assert_eq!(s.shift(-2, 0.).as_slice(), &[3., 4., 0., 0.]);
+	/// ```
+	#[must_use]
+	pub fn shift(&self, n: isize, fill: T) -> Self {
+		let len = self.len();
+		let offset = n.unsigned_abs().min(len);
+
+		if n >= 0 {
+			core::iter::repeat_n(fill, offset)
+				.chain(self[..len - offset].iter().copied())
+				.collect()
+		} else {
+			self[offset..]
+				.iter()
+				.copied()
+				.chain(core::iter::repeat_n(fill, offset))
+				.collect()
+		}
+	}
+
+	/// Returns new `sequence`, which is a copy of the given `range` of values
+	///
+	/// # Panics
+	///
+	/// Panics if the `range` is out of bounds.
+	///
+	/// # Examples
+	///
+	/// ```
+	/// use yata::core::Series;
+	///
+	/// let s = Series::from(vec![1., 2., 3., 4.]);
+	///
+	/// assert_eq!(s.slice(1..3).as_slice(), &[2., 3.]);
+	/// assert_eq!(s.slice(2..).as_slice(), &[3., 4.]);
+	/// ```
+	#[must_use]
+	pub fn slice<R: RangeBounds<usize>>(&self, range: R) -> Self {
+		let bounds: (Bound<usize>, Bound<usize>) =
+			(range.start_bound().cloned(), range.end_bound().cloned());
+
+		Self(self.0[bounds].to_vec())
+	}
+
+	/// Combines values of two sequences element-wise by the function `f`
+	///
+	/// Length of the result is the length of the shortest `sequence`.
+	///
+	/// # Examples
+	///
+	/// ```
+	/// use yata::core::{Series, ValueType};
+	///
+	/// let a = Series::from(vec![1., 5., 3.]);
+	/// let b = Series::from(vec![4., 2., 6.]);
+	///
+	/// assert_eq!(a.zip_with(&b, ValueType::max).as_slice(), &[4., 5., 6.]);
+	/// ```
+	pub fn zip_with<U: Copy, O: Copy, F: FnMut(T, U) -> O>(
+		&self,
+		other: &Sequence<U>,
+		mut f: F,
+	) -> Sequence<O> {
+		self.iter()
+			.zip(other.iter())
+			.map(|(&a, &b)| f(a, b))
+			.collect()
+	}
+}
+
+/// Sequence of values, which supports element-wise arithmetic
+///
+/// Arithmetic between two series is applied to the pairs of values at the same positions,
+/// and the length of the result is the length of the shortest series.
+/// Arithmetic between a series and a [`ValueType`] is applied to every value of the series.
+///
+/// # Examples
+///
+/// ```
+/// use yata::core::{Series, ValueType};
+/// use yata::methods::SMA;
+/// use yata::prelude::*;
+///
+/// let s: Series = (1..=10).map(|x| x as ValueType).collect();
+///
+/// let ma = s.eval(&mut SMA::new(2, s[0]).unwrap());
+/// let change = &s - &s.shift(1, s[0]);
+/// let ratio = &change / &ma * 100.;
+///
+/// assert_eq!(ratio.len(), s.len());
+/// assert_eq!(ratio[1], 1. / 1.5 * 100.);
+/// ```
+pub type Series = Sequence<ValueType>;
+
+macro_rules! impl_series_op {
+	($trait:ident, $method:ident, $op:tt, $op_assign:tt) => {
+		impl $trait for &Series {
+			type Output = Series;
+
+			fn $method(self, other: Self) -> Self::Output {
+				self.zip_with(other, |a, b| a $op b)
+			}
+		}
+
+		impl $trait for Series {
+			type Output = Self;
+
+			fn $method(self, other: Self) -> Self::Output {
+				&self $op &other
+			}
+		}
+
+		impl $trait<ValueType> for &Series {
+			type Output = Series;
+
+			fn $method(self, other: ValueType) -> Self::Output {
+				self.iter().map(|&a| a $op other).collect()
+			}
+		}
+
+		impl $trait<ValueType> for Series {
+			type Output = Self;
+
+			fn $method(mut self, other: ValueType) -> Self::Output {
+				self.iter_mut().for_each(|a| *a $op_assign other);
+				self
+			}
+		}
+	};
 }
 
+impl_series_op!(Add, add, +, +=);
+impl_series_op!(Sub, sub, -, -=);
+impl_series_op!(Mul, mul, *, *=);
+impl_series_op!(Div, div, /, /=);
+
 impl<T: Copy> Deref for Sequence<T> {
 	type Target = Vec<T>;
 
@@ -0,0 +1,454 @@
+use super::{Action, Candle, Error, IndicatorResult, ParamValue, Source};
+use crate::helpers::{RegularMethod, RegularMethods};
+use alloc::string::String;
+use alloc::string::ToString;
+use alloc::vec::Vec;
+use serde::de::DeserializeOwned;
+use serde::{Deserialize, Serialize};
+
+/// Current version of the state snapshot format
+///
+/// Snapshots saved with another version are rejected by [`Snapshot::restore_state`].
+/// The version is bumped every time the state layout of any method or indicator changes incompatibly.
+pub const STATE_FORMAT_VERSION: u16 = 3;
+
+const FORMAT: &str = "yata-state";
+
+/// Versioned snapshot of the state
+///
+/// State is encoded in [CBOR](https://cbor.io), which is self-describing: structures are stored by their field names,
+/// so snapshots do not depend on the order of the fields and on the compiler.
+///
+/// Snapshot holds the [format version](STATE_FORMAT_VERSION) and the [`STATE_TAG`](Snapshot::STATE_TAG) of the type
+/// it was saved from, so it can be restored only into the same type by the same format version.
+///
+/// Implemented for all the [methods](crate::methods), [indicators](crate::indicators) configurations,
+/// the most of the indicator instances and the basic types like [`Candle`](crate::core::Candle)
+/// and [`IndicatorResult`](crate::core::IndicatorResult).
+/// Any other type, which supports [`serde`](https://serde.rs) serialization, may implement it by providing its own tag.
+///
+/// # Examples
+///
+/// ```
+/// use yata::core::Snapshot;
+/// use yata::methods::EMA;
+/// use yata::prelude::*;
+///
+/// let mut ema = EMA::new(5, 1.0).unwrap();
+/// ema.next(2.0);
+///
+/// let state = ema.save_state();
+///
+/// let mut restored = EMA::new(5, 0.0).unwrap();
+/// restored.restore_state(&state).unwrap();
+///
+/// assert_eq!(ema.next(3.0), restored.next(3.0));
+/// ```
+pub trait Snapshot: Serialize + DeserializeOwned {
+	/// Stable name of the type, which is stored in the snapshot
+	///
+	/// It must be unique and must never change, otherwise previously saved snapshots cannot be restored.
+	const STATE_TAG: &'static str;
+
+	/// Saves current state into a vector of bytes
+	///
+	/// # Panics
+	///
+	/// Panics if the [`Serialize`] implementation of the type returns an error.
+	/// Never happens for the methods and the indicator instances.
+	fn save_state(&self) -> Vec<u8> {
+		let envelope = Envelope {
+			format: FORMAT,
+			version: STATE_FORMAT_VERSION,
+			tag: Self::STATE_TAG,
+			state: self,
+		};
+
+		let mut buf = Vec::new();
+		ciborium::ser::into_writer(&envelope, &mut buf).expect("Unable to serialize the state");
+		buf
+	}
+
+	/// Restores the state from the bytes, previously returned by [`save_state`](Snapshot::save_state)
+	///
+	/// Returns [`Error::InvalidState`] if `state` was saved from another type, by another format version or is corrupted.
+	/// In this case current state is left unchanged.
+	fn restore_state(&mut self, state: &[u8]) -> Result<(), Error> {
+		let mut input = state;
+		let envelope: Envelope<String, ciborium::Value> = ciborium::de::from_reader(&mut input)
+			.map_err(|e| Error::InvalidState(e.to_string()))?;
+
+		if !input.is_empty() {
+			return Err(Error::InvalidState(
+				"Trailing bytes in the state".to_string(),
+			));
+		}
+
+		if envelope.format != FORMAT {
+			return Err(Error::InvalidState("Not a state snapshot".to_string()));
+		}
+
+		if envelope.version != STATE_FORMAT_VERSION {
+			return Err(Error::InvalidState(format!(
+				"Unsupported state format version {}, expected {}",
+				envelope.version, STATE_FORMAT_VERSION
+			)));
+		}
+
+		if envelope.tag != Self::STATE_TAG {
+			return Err(Error::InvalidState(format!(
+				"State of `{}` cannot be restored into `{}`",
+				envelope.tag,
+				Self::STATE_TAG
+			)));
+		}
+
+		*self = envelope
+			.state
+			.deserialized()
+			.map_err(|e| Error::InvalidState(e.to_string()))?;
+
+		Ok(())
+	}
+}
+
+#[derive(Serialize, Deserialize)]
+struct Envelope<T, S> {
+	format: T,
+	version: u16,
+	tag: T,
+	state: S,
+}
+
+/// Implements [`Snapshot`] for the listed types with the given tags
+macro_rules! snapshot_tags {
+	($($tag:literal => $ty:ty $(where $gen:ident: $bound:path)?),* $(,)?) => {
+		$(
+			impl$(<$gen: $bound>)? $crate::core::Snapshot for $ty
+			where
+				Self: serde::Serialize + serde::de::DeserializeOwned,
+			{
+				const STATE_TAG: &'static str = $tag;
+			}
+		)*
+	};
+}
+
+pub(crate) use snapshot_tags;
+
+snapshot_tags! {
+	"core::Action" => Action,
+	"core::Candle" => Candle,
+	"core::Source" => Source,
+	"core::IndicatorResult" => IndicatorResult,
+	"core::ParamValue" => ParamValue,
+	"core::Error" => Error,
+	"helpers::RegularMethods" => RegularMethods,
+	"helpers::RegularMethod" => RegularMethod,
+}
+
+#[cfg(test)]
+mod tests {
+	use super::{Envelope, Snapshot, FORMAT, STATE_FORMAT_VERSION};
+	use crate::core::{
+		Action, Candle, Error, IndicatorConfig, IndicatorInitializer, IndicatorInstance,
+		IndicatorResult, Method, ParamValue, Source, ValueType,
+	};
+	use crate::helpers::{assert_eq_float, method, RandomCandles, RegularMethods};
+	use crate::indicators::{
+		AnchoredVWAP, AverageDirectionalIndex, BollingerBands, ChaikinOscillator,
+		DynamicMomentumIndex, EldersForceIndex, KeltnerChannels, KnowSureThing,
+		RelativeStrengthIndex, RelativeVigorIndex, StochasticOscillator, SuperTrend, Trix,
+		VolumeProfile, MACD,
+	};
+	use crate::methods::{Conv, Divergence, EMA, SMM};
+	use core::fmt::Debug;
+
+	fn test_method_snapshot<M>(mut method: M, mut fresh: M, input: &[M::Input])
+	where
+		M: Method + Snapshot,
+		M::Output: Debug + PartialEq,
+	{
+		let (head, tail) = input.split_at(input.len() / 2);
+		for &x in head {
+			method.next(x);
+		}
+
+		fresh.restore_state(&method.save_state()).unwrap();
+
+		for &x in tail {
+			assert_eq!(method.next(x), fresh.next(x));
+		}
+	}
+
+	#[test]
+	fn test_snapshot_methods() {
+		let src: Vec<ValueType> = RandomCandles::new().take(300).map(|c| c.close).collect();
+		let pairs: Vec<_> = src.iter().map(|&x| (x, x.sin())).collect();
+
+		test_method_snapshot(
+			EMA::new(10, src[0]).unwrap(),
+			EMA::new(10, 0.).unwrap(),
+			&src,
+		);
+		test_method_snapshot(
+			SMM::new(15, src[0]).unwrap(),
+			SMM::new(15, 0.).unwrap(),
+			&src,
+		);
+		test_method_snapshot(
+			Conv::new(vec![1., 2., 3.], src[0]).unwrap(),
+			Conv::new(vec![1., 2., 3.], 0.).unwrap(),
+			&src,
+		);
+		test_method_snapshot(
+			Divergence::new((3, 2, 50, 0.), pairs[0]).unwrap(),
+			Divergence::new((3, 2, 50, 0.), (0., 0.)).unwrap(),
+			&pairs,
+		);
+	}
+
+	#[test]
+	fn test_snapshot_regular_methods() {
+		let src: Vec<ValueType> = RandomCandles::new().take(300).map(|c| c.close).collect();
+		let (head, tail) = src.split_at(150);
+
+		let methods = [
+			RegularMethods::SMA,
+			RegularMethods::WMA,
+			RegularMethods::HMA,
+			RegularMethods::RMA,
+			RegularMethods::EMA,
+			RegularMethods::DMA,
+			RegularMethods::DEMA,
+			RegularMethods::TMA,
+			RegularMethods::TEMA,
+			RegularMethods::WSMA,
+			RegularMethods::SMM,
+			RegularMethods::SWMA,
+			RegularMethods::LinReg,
+			RegularMethods::TRIMA,
+			RegularMethods::ZLEMA,
+			RegularMethods::T3,
+			RegularMethods::Past,
+			RegularMethods::Derivative,
+			RegularMethods::Integral,
+			RegularMethods::MeanAbsDev,
+			RegularMethods::MedianAbsDev,
+			RegularMethods::StDev,
+			RegularMethods::CCI,
+			RegularMethods::Momentum,
+			RegularMethods::RateOfChange,
+			RegularMethods::Highest,
+			RegularMethods::Lowest,
+			RegularMethods::HighestLowestDelta,
+			RegularMethods::Stochastic,
+			RegularMethods::PercentRank,
+		];
+
+		for &method_type in &methods {
+			let mut instance = method(method_type, 10, head[0]).unwrap();
+			for &x in head {
+				instance.next(x);
+			}
+
+			// the state is tagged by the concrete method, so it may be restored into any other one
+			let mut restored = method(RegularMethods::SMA, 3, 0.).unwrap();
+			restored.restore_state(&instance.save_state()).unwrap();
+
+			for &x in tail {
+				assert_eq!(instance.next(x), restored.next(x), "{method_type:?}");
+			}
+		}
+	}
+
+	fn test_indicator_snapshot<C>(cfg: C, candles: &[Candle])
+	where
+		C: IndicatorConfig + IndicatorInitializer<Candle> + Clone,
+		C::Instance: Snapshot,
+	{
+		let (head, tail) = candles.split_at(candles.len() / 2);
+
+		let mut instance = cfg.clone().init(head[0]).unwrap();
+		instance.over(head);
+
+		let mut restored = cfg.init(tail[0]).unwrap();
+		restored.restore_state(&instance.save_state()).unwrap();
+
+		for &candle in tail {
+			let (a, b) = (instance.next(candle), restored.next(candle));
+			assert_eq!(a.values(), b.values(), "{}", C::NAME);
+			assert_eq!(a.signals(), b.signals(), "{}", C::NAME);
+		}
+	}
+
+	#[test]
+	fn test_snapshot_indicators() {
+		let candles: Vec<_> = RandomCandles::new().take(300).collect();
+
+		test_indicator_snapshot(DynamicMomentumIndex::default(), &candles);
+		test_indicator_snapshot(
+			VolumeProfile {
+				period: 20,
+				..VolumeProfile::default()
+			},
+			&candles,
+		);
+
+		test_indicator_snapshot(
+			AnchoredVWAP {
+				sigma: 2.0,
+				..AnchoredVWAP::default()
+			},
+			&candles,
+		);
+		test_indicator_snapshot(AverageDirectionalIndex::default(), &candles);
+		test_indicator_snapshot(BollingerBands::default(), &candles);
+		test_indicator_snapshot(ChaikinOscillator::default(), &candles);
+		test_indicator_snapshot(EldersForceIndex::default(), &candles);
+		test_indicator_snapshot(KeltnerChannels::default(), &candles);
+		test_indicator_snapshot(MACD::default(), &candles);
+		test_indicator_snapshot(RelativeStrengthIndex::default(), &candles);
+		test_indicator_snapshot(RelativeVigorIndex::default(), &candles);
+		test_indicator_snapshot(SuperTrend::default(), &candles);
+		test_indicator_snapshot(Trix::default(), &candles);
+		test_indicator_snapshot(
+			StochasticOscillator {
+				method: RegularMethods::TEMA,
+				..StochasticOscillator::default()
+			},
+			&candles,
+		);
+		test_indicator_snapshot(
+			KnowSureThing {
+				method1: RegularMethods::WMA,
+				method2: RegularMethods::EMA,
+				..KnowSureThing::default()
+			},
+			&candles,
+		);
+	}
+
+	#[test]
+	fn test_snapshot_configs() {
+		let mut cfg = DynamicMomentumIndex::default();
+		cfg.set("period", "20".to_string());
+
+		let mut restored = DynamicMomentumIndex::default();
+		restored.restore_state(&cfg.save_state()).unwrap();
+		assert_eq!(restored.period, 20);
+
+		let mut restored = RegularMethods::EMA;
+		restored
+			.restore_state(&RegularMethods::TMA.save_state())
+			.unwrap();
+		assert_eq!(restored, RegularMethods::TMA);
+	}
+
+	#[test]
+	fn test_snapshot_outputs() {
+		let result = IndicatorResult::new(
+			&[1.0, -2.5, 3.0, 0.0, 4.0, 5.5],
+			&[Action::BUY_ALL, Action::None, Action::Sell(7)],
+		);
+		let mut restored = IndicatorResult::new(&[], &[]);
+		restored.restore_state(&result.save_state()).unwrap();
+		assert_eq!(restored.signals(), result.signals());
+		assert_eq!(restored.values(), result.values());
+
+		let params = [
+			ParamValue::Int(14),
+			ParamValue::Float(0.25),
+			ParamValue::Source(Source::HL2),
+			ParamValue::Variant("fast".into()),
+		];
+		for param in &params {
+			let mut restored = ParamValue::Int(0);
+			restored.restore_state(&param.save_state()).unwrap();
+			assert_eq!(&restored, param);
+		}
+
+		let error = Error::ParameterParse("period".into(), "x".into());
+		let mut restored = Error::WrongConfig;
+		restored.restore_state(&error.save_state()).unwrap();
+		assert!(
+			matches!(restored, Error::ParameterParse(name, value) if name == "period" && value == "x")
+		);
+	}
+
+	#[test]
+	fn test_snapshot_named_fields() {
+		let mut ema = EMA::new(10, 1.).unwrap();
+		ema.next(5.);
+
+		// fields are restored by their names, not by their order
+		let mut state = ciborium::Value::serialized(&ema).unwrap();
+		match &mut state {
+			ciborium::Value::Map(fields) => fields.reverse(),
+			other => panic!("State must be a map, got {:?}", other),
+		}
+
+		let envelope = Envelope {
+			format: FORMAT,
+			version: STATE_FORMAT_VERSION,
+			tag: "methods::EMA",
+			state,
+		};
+		let mut buf = Vec::new();
+		ciborium::ser::into_writer(&envelope, &mut buf).unwrap();
+
+		let mut restored = EMA::new(10, 0.).unwrap();
+		restored.restore_state(&buf).unwrap();
+		assert_eq!(ema.next(3.), restored.next(3.));
+	}
+
+	#[test]
+	fn test_snapshot_invalid() {
+		let ema = EMA::new(10, 1.).unwrap();
+		let state = ema.save_state();
+
+		let mut wrong_type = SMM::new(10, 1.).unwrap();
+		assert!(wrong_type.restore_state(&state).is_err());
+
+		let mut restored = EMA::new(10, 2.).unwrap();
+		assert!(restored.restore_state(&state[..state.len() - 1]).is_err());
+		assert!(restored
+			.restore_state(&[state.as_slice(), &[0]].concat())
+			.is_err());
+		assert!(restored.restore_state(&state[1..]).is_err());
+		assert!(restored.restore_state(&[]).is_err());
+
+		let encode = |format: &str, version: u16, tag: &str| {
+			let envelope = Envelope {
+				format,
+				version,
+				tag,
+				state: &ema,
+			};
+			let mut buf = Vec::new();
+			ciborium::ser::into_writer(&envelope, &mut buf).unwrap();
+			buf
+		};
+
+		assert!(restored
+			.restore_state(&encode(FORMAT, STATE_FORMAT_VERSION, "methods::EMA"))
+			.is_ok());
+		assert!(restored
+			.restore_state(&encode("other", STATE_FORMAT_VERSION, "methods::EMA"))
+			.is_err());
+		assert!(restored
+			.restore_state(&encode(FORMAT, STATE_FORMAT_VERSION + 1, "methods::EMA"))
+			.is_err());
+		assert!(restored
+			.restore_state(&encode(FORMAT, STATE_FORMAT_VERSION, "methods::SMM"))
+			.is_err());
+
+		let mut restored = EMA::new(10, 2.).unwrap();
+		assert!(restored
+			.restore_state(&encode(FORMAT, STATE_FORMAT_VERSION, "methods::EMA")[..10])
+			.is_err());
+
+		// failed restoring leaves the state unchanged
+		assert_eq_float(2., restored.next(2.));
+	}
+}
@@ -0,0 +1,82 @@
+use super::{Error, ValueType};
+
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
+
+#[cfg(not(feature = "std"))]
+use crate::core::Float as _;
+
+/// Fixed-point price type: an integer count of the smallest price steps (ticks)
+///
+/// Used by the fixed-point methods ([`FixedSMA`](crate::methods::FixedSMA), [`FixedEMA`](crate::methods::FixedEMA),
+/// [`FixedHighest`](crate::methods::FixedHighest), [`FixedLowest`](crate::methods::FixedLowest),
+/// [`FixedATR`](crate::methods::FixedATR)), which never touch floating point values.
+pub type Ticks = i64;
+
+/// Explicit scaling between [`ValueType`] prices and fixed-point [`Ticks`]
+///
+/// Conversions are needed only on the boundaries of the system: the fixed-point methods operate on [`Ticks`] directly.
+///
+/// # Examples
+///
+/// ```
+/// use yata::core::TickScale;
+///
+/// // prices with 2 decimal places
+/// let scale = TickScale::from_decimals(2).unwrap();
+///
+/// assert_eq!(scale.ticks_per_unit(), 100);
+/// assert_eq!(scale.to_ticks(12.34), 1234);
+/// assert_eq!(scale.to_ticks(-0.125), -13);
+/// assert!((scale.to_value(1234) - 12.34).abs() < 1e-5);
+///
+/// assert!(TickScale::new(0).is_err());
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct TickScale {
+	ticks_per_unit: Ticks,
+}
+
+impl TickScale {
+	/// Creates a scale with `ticks_per_unit` ticks in the unit of price
+	///
+	/// `ticks_per_unit` should be > 0
+	pub const fn new(ticks_per_unit: Ticks) -> Result<Self, Error> {
+		if ticks_per_unit > 0 {
+			Ok(Self { ticks_per_unit })
+		} else {
+			Err(Error::WrongMethodParameters)
+		}
+	}
+
+	/// Creates a scale for prices with `decimals` digits after the decimal point
+	pub fn from_decimals(decimals: u32) -> Result<Self, Error> {
+		let ticks_per_unit = Ticks::from(10_u8)
+			.checked_pow(decimals)
+			.ok_or(Error::WrongMethodParameters)?;
+
+		Self::new(ticks_per_unit)
+	}
+
+	/// Returns count of ticks in the unit of price
+	#[must_use]
+	pub const fn ticks_per_unit(self) -> Ticks {
+		self.ticks_per_unit
+	}
+
+	/// Converts the `value` into the nearest count of ticks
+	///
+	/// Halfway values are rounded away from zero. Values out of [`Ticks`] range are saturated.
+	#[must_use]
+	#[allow(clippy::cast_possible_truncation)]
+	pub fn to_ticks(self, value: ValueType) -> Ticks {
+		(value * self.ticks_per_unit as ValueType).round() as Ticks
+	}
+
+	/// Converts the `ticks` into the [`ValueType`] price
+	#[must_use]
+	pub fn to_value(self, ticks: Ticks) -> ValueType {
+		ticks as ValueType / self.ticks_per_unit as ValueType
+	}
+}
@@ -0,0 +1,444 @@
+use super::{
+	Error, IndicatorInitializer, IndicatorInstance, IndicatorResult, Method, PeriodType, ValueType,
+	OHLC,
+};
+use alloc::vec::Vec;
+use core::marker::PhantomData;
+use core::mem;
+
+/// Wraps a [`Method`] and allows to revert the last [`next`](Method::next) call by [`undo`](Undo::undo)
+///
+/// Useful when the data feed sends a correction for the last bar: the wrong value is reverted
+/// and then the corrected one is passed to [`next`](Method::next).
+///
+/// Before every call of [`next`](Method::next) the method saves the part of its state, which is going to be changed,
+/// by [`Method::save_undo`]: f.e. [`SMA`](crate::methods::SMA) saves just the value pushed out of its window and its accumulator.
+/// Methods, which do not support it, are kept as a copy instead, so every call costs an extra [`clone_from`](Clone::clone_from) of the method.
+/// Only the last call may be reverted.
+///
+/// Real-time feeds revise the still open bar many times before it closes. Every revision should be passed to [`update`](Undo::update):
+/// it replaces the previous revision in the state. The final value of the bar is passed to [`next`](Method::next), which replaces
+/// the last revision too, so the revisions do not affect any further values of recursive methods like [`EMA`](crate::methods::EMA).
+///
+/// # Examples
+///
+/// ```
+/// use yata::core::Undo;
+/// use yata::methods::SMA;
+/// use yata::prelude::*;
+///
+/// let mut sma = Undo::<SMA>::new(2, 1.0).unwrap();
+///
+/// assert_eq!(sma.next(3.0), 2.0);
+/// assert_eq!(sma.next(100.0), 51.5);
+///
+/// // correction of the last value
+/// assert!(sma.undo());
+/// assert_eq!(sma.next(5.0), 4.0);
+///
+/// assert!(sma.undo());
+/// assert!(!sma.undo());
+///
+/// // revisions of the still open bar
+/// assert_eq!(sma.update(7.0), 5.0);
+/// assert_eq!(sma.update(1.0), 2.0);
+/// // the bar closes
+/// assert_eq!(sma.next(5.0), 4.0);
+/// ```
+#[derive(Debug, Clone)]
+pub struct Undo<M: Method + Clone> {
+	method: M,
+	previous: M,
+	stack: Vec<ValueType>,
+	has_previous: bool,
+	is_saved: bool,
+	is_open: bool,
+}
+
+impl<M: Method + Clone> Undo<M> {
+	/// Returns a reference to the wrapped method
+	pub const fn method(&self) -> &M {
+		&self.method
+	}
+
+	/// Returns `true` if there is a call of [`next`](Method::next) to revert
+	pub const fn can_undo(&self) -> bool {
+		self.has_previous
+	}
+
+	/// Reverts the method to the state before the last call of [`next`](Method::next) or [`update`](Undo::update)
+	///
+	/// Returns `false` and does nothing if there is nothing to revert: the method was just created or reset,
+	/// or the last call was already reverted.
+	pub fn undo(&mut self) -> bool {
+		if !self.has_previous {
+			return false;
+		}
+
+		if self.is_saved {
+			self.method.restore_undo(&mut self.stack);
+		} else {
+			mem::swap(&mut self.method, &mut self.previous);
+		}
+
+		self.has_previous = false;
+		self.is_open = false;
+		true
+	}
+
+	/// Evaluates a revision of the still open bar `value`
+	///
+	/// Replaces the previous revision, if there is any. When the bar closes, pass its final value to [`next`](Method::next).
+	pub fn update(&mut self, value: M::Input) -> M::Output {
+		let output = self.next(value);
+		self.is_open = true;
+		output
+	}
+}
+
+impl<M: Method + Clone> Method for Undo<M> {
+	type Params = M::Params;
+	type Input = M::Input;
+	type Output = M::Output;
+
+	fn new(parameters: Self::Params, initial_value: Self::Input) -> Result<Self, Error> {
+		let method = M::new(parameters, initial_value)?;
+
+		Ok(Self {
+			previous: method.clone(),
+			method,
+			stack: Vec::new(),
+			has_previous: false,
+			is_saved: false,
+			is_open: false,
+		})
+	}
+
+	#[inline]
+	fn next(&mut self, value: Self::Input) -> Self::Output {
+		if self.is_open {
+			self.undo();
+		}
+
+		self.stack.clear();
+		self.is_saved = self.method.save_undo(&mut self.stack);
+
+		if !self.is_saved {
+			self.previous.clone_from(&self.method);
+		}
+
+		self.has_previous = true;
+		self.method.next(value)
+	}
+
+	fn min_periods(&self) -> PeriodType {
+		self.method.min_periods()
+	}
+
+	fn reset(&mut self, initial_value: Self::Input) -> Result<(), Error> {
+		self.method.reset(initial_value)?;
+		self.has_previous = false;
+		self.is_open = false;
+		Ok(())
+	}
+}
+
+/// Wraps an indicator **State** and allows to revert the last [`next`](IndicatorUndo::next) call by [`undo`](IndicatorUndo::undo)
+///
+/// Before every call of [`next`](IndicatorUndo::next) the **State** saves the part of it, which is going to be changed,
+/// by [`IndicatorInstance::save_undo`]. Indicators, which do not support it, are kept as a copy instead.
+/// Revisions of the still open candle are passed to [`update`](IndicatorUndo::update).
+/// See [`Undo`] for the details.
+///
+/// # Examples
+///
+/// ```
+/// use yata::core::IndicatorUndo;
+/// use yata::helpers::RandomCandles;
+/// use yata::indicators::Trix;
+/// use yata::prelude::*;
+///
+/// let candles: Vec<_> = RandomCandles::new().take(30).collect();
+/// let mut state = IndicatorUndo::new(Trix::default().init(candles[0]).unwrap());
+///
+/// for &candle in &candles[..20] {
+///     state.next(candle);
+/// }
+///
+/// // the last candle is corrected
+/// state.next(candles[29]);
+/// assert!(state.undo());
+/// let result = state.next(candles[20]);
+///
+/// let mut trix = Trix::default().init(candles[0]).unwrap();
+/// trix.over(&candles[..20]);
+/// assert_eq!(result.values(), trix.next(candles[20]).values());
+/// ```
+#[derive(Debug, Clone)]
+pub struct IndicatorUndo<I, T> {
+	instance: I,
+	previous: I,
+	stack: Vec<ValueType>,
+	has_previous: bool,
+	is_saved: bool,
+	is_open: bool,
+	phantom: PhantomData<T>,
+}
+
+impl<I, T> IndicatorUndo<I, T>
+where
+	T: OHLC,
+	I: IndicatorInstance<T> + Clone,
+	I::Config: IndicatorInitializer<T, Instance = I>,
+{
+	/// Wraps an indicator **State**
+	pub fn new(instance: I) -> Self {
+		Self {
+			previous: instance.clone(),
+			instance,
+			stack: Vec::new(),
+			has_previous: false,
+			is_saved: false,
+			is_open: false,
+			phantom: PhantomData,
+		}
+	}
+
+	/// Returns a reference to the wrapped indicator **State**
+	pub const fn instance(&self) -> &I {
+		&self.instance
+	}
+
+	/// Unwraps the indicator **State**
+	pub fn into_inner(self) -> I {
+		self.instance
+	}
+
+	/// Returns `true` if there is a call of [`next`](IndicatorUndo::next) to revert
+	pub const fn can_undo(&self) -> bool {
+		self.has_previous
+	}
+
+	/// Evaluates the next `candle`
+	///
+	/// Replaces the last revision of the candle, passed to [`update`](IndicatorUndo::update), if there is any.
+	pub fn next(&mut self, candle: T) -> IndicatorResult {
+		if self.is_open {
+			self.undo();
+		}
+
+		self.stack.clear();
+		self.is_saved = self.instance.save_undo(&mut self.stack);
+
+		if !self.is_saved {
+			self.previous.clone_from(&self.instance);
+		}
+
+		self.has_previous = true;
+		self.instance.next(candle)
+	}
+
+	/// Evaluates a revision of the still open `candle`
+	///
+	/// Replaces the previous revision, if there is any. When the candle closes, pass it to [`next`](IndicatorUndo::next).
+	pub fn update(&mut self, candle: T) -> IndicatorResult {
+		let result = self.next(candle);
+		self.is_open = true;
+		result
+	}
+
+	/// Reverts the **State** to the moment before the last call of [`next`](IndicatorUndo::next) or [`update`](IndicatorUndo::update)
+	///
+	/// Returns `false` and does nothing if there is nothing to revert.
+	pub fn undo(&mut self) -> bool {
+		if !self.has_previous {
+			return false;
+		}
+
+		if self.is_saved {
+			self.instance.restore_undo(&mut self.stack);
+		} else {
+			mem::swap(&mut self.instance, &mut self.previous);
+		}
+
+		self.has_previous = false;
+		self.is_open = false;
+		true
+	}
+
+	/// Resets the **State** by the `candle`, so there is nothing to revert
+	pub fn reset(&mut self, candle: T) -> Result<(), Error> {
+		self.instance.reset(candle)?;
+		self.has_previous = false;
+		self.is_open = false;
+		Ok(())
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	use super::{IndicatorUndo, Undo};
+	use crate::core::{Candle, IndicatorInitializer, IndicatorInstance, Method, ValueType};
+	use crate::helpers::{assert_eq_float, RandomCandles, RegularMethods};
+	use crate::indicators::{Trix, MACD};
+	use crate::methods::{
+		Cross, Momentum, RateOfChange, DEMA, DMA, EMA, RMA, SMA, SMM, TEMA, TMA, WMA, WSMA,
+	};
+	use alloc::vec::Vec;
+	use core::fmt::Debug;
+
+	#[test]
+	fn test_undo() {
+		let candles: Vec<Candle> = RandomCandles::new().take(100).collect();
+		let values: Vec<ValueType> = candles.iter().map(|c| c.close).collect();
+
+		let mut sma = Undo::<SMA>::new(5, values[0]).unwrap();
+		let mut ema = Undo::<EMA>::new(5, values[0]).unwrap();
+		let mut sma_expected = SMA::new(5, values[0]).unwrap();
+		let mut ema_expected = EMA::new(5, values[0]).unwrap();
+
+		assert!(!sma.undo());
+
+		for &value in &values {
+			sma.next(value * 2.0);
+			ema.next(-value);
+			assert!(sma.undo());
+			assert!(ema.undo());
+			assert!(!sma.can_undo());
+
+			assert_eq_float(sma_expected.next(value), sma.next(value));
+			assert_eq_float(ema_expected.next(value), ema.next(value));
+		}
+
+		sma.reset(values[0]).unwrap();
+		assert!(!sma.undo());
+
+		let mut state = IndicatorUndo::new(MACD::default().init(candles[0]).unwrap());
+		let mut expected = MACD::default().init(candles[0]).unwrap();
+
+		for (i, &candle) in candles.iter().enumerate() {
+			if i % 3 == 0 {
+				state.next(candles[99 - i]);
+				assert!(state.undo());
+			}
+
+			assert_eq!(state.next(candle).values(), expected.next(candle).values());
+		}
+
+		state.reset(candles[0]).unwrap();
+		assert!(!state.undo());
+	}
+
+	#[test]
+	fn test_update() {
+		let candles: Vec<Candle> = RandomCandles::new().take(100).collect();
+		let values: Vec<ValueType> = candles.iter().map(|c| c.close).collect();
+
+		let mut ema = Undo::<EMA>::new(5, values[0]).unwrap();
+		let mut smm = Undo::<SMM>::new(5, values[0]).unwrap();
+		let mut ema_expected = EMA::new(5, values[0]).unwrap();
+		let mut smm_expected = SMM::new(5, values[0]).unwrap();
+
+		for &value in &values {
+			ema.update(value * 2.0);
+			smm.update(value * 2.0);
+			assert_eq_float(ema_expected.peek(value), ema.update(value));
+			assert_eq_float(smm_expected.peek(value), smm.update(value));
+			assert_eq_float(ema_expected.next(value), ema.next(value));
+			assert_eq_float(smm_expected.next(value), smm.next(value));
+		}
+
+		// a revision is reverted like the closed bar
+		ema.update(values[0]);
+		assert!(ema.undo());
+		assert!(!ema.undo());
+		assert_eq_float(ema_expected.next(values[1]), ema.next(values[1]));
+
+		let mut state = IndicatorUndo::new(MACD::default().init(candles[0]).unwrap());
+		let mut expected = MACD::default().init(candles[0]).unwrap();
+
+		for (i, &candle) in candles.iter().enumerate() {
+			state.update(candles[99 - i]);
+			let revision = state.update(candle);
+			assert_eq!(revision.values(), expected.peek(candle).values());
+			assert_eq!(state.next(candle).values(), expected.next(candle).values());
+		}
+	}
+
+	fn test_undo_hook<M>(mut method: M, values: &[M::Input])
+	where
+		M: Method + Clone,
+		M::Output: PartialEq + Debug,
+	{
+		let mut stack = Vec::new();
+
+		for (&value, &wrong) in values.iter().zip(values.iter().rev()) {
+			let mut expected = method.clone();
+
+			assert!(method.save_undo(&mut stack));
+			method.next(wrong);
+			method.restore_undo(&mut stack);
+			assert!(stack.is_empty());
+
+			assert_eq!(method.next(value), expected.next(value));
+		}
+	}
+
+	#[test]
+	fn test_undo_hooks() {
+		let values: Vec<ValueType> = RandomCandles::new().take(100).map(|c| c.close).collect();
+		let pairs: Vec<_> = values.iter().map(|&x| (x, x.sin() + x)).collect();
+		let first = values[0];
+
+		test_undo_hook(SMA::new(7, first).unwrap(), &values);
+		test_undo_hook(WMA::new(7, first).unwrap(), &values);
+		test_undo_hook(EMA::new(7, first).unwrap(), &values);
+		test_undo_hook(DMA::new(7, first).unwrap(), &values);
+		test_undo_hook(DEMA::new(7, first).unwrap(), &values);
+		test_undo_hook(TMA::new(7, first).unwrap(), &values);
+		test_undo_hook(TEMA::new(7, first).unwrap(), &values);
+		test_undo_hook(RMA::new(7, first).unwrap(), &values);
+		test_undo_hook(WSMA::new(7, first).unwrap(), &values);
+		test_undo_hook(Momentum::new(7, first).unwrap(), &values);
+		test_undo_hook(RateOfChange::new(7, first).unwrap(), &values);
+		test_undo_hook(Cross::new((), pairs[0]).unwrap(), &pairs);
+		test_undo_hook(
+			SMA::new(7, first)
+				.unwrap()
+				.then(EMA::new(3, first).unwrap())
+				.zip(WMA::new(5, first).unwrap())
+				.map(|(a, b)| a - b),
+			&values,
+		);
+
+		// methods without the hook are kept as a copy
+		let mut smm = Undo::<SMM>::new(7, first).unwrap();
+		let mut expected = SMM::new(7, first).unwrap();
+		assert!(!SMM::new(7, first).unwrap().save_undo(&mut Vec::new()));
+
+		for &value in &values {
+			smm.next(value * 3.0);
+			assert!(smm.undo());
+			assert_eq_float(expected.next(value), smm.next(value));
+		}
+
+		let candles: Vec<Candle> = RandomCandles::new().take(100).collect();
+		let cfg = Trix {
+			method2: RegularMethods::SMA,
+			..Trix::default()
+		};
+		let mut state = IndicatorUndo::new(cfg.init(candles[0]).unwrap());
+		let mut expected = cfg.init(candles[0]).unwrap();
+		assert!(IndicatorInstance::<Candle>::save_undo(
+			&expected,
+			&mut Vec::new()
+		));
+
+		for &candle in &candles {
+			state.next(candles[99]);
+			assert!(state.undo());
+
+			assert_eq!(state.next(candle).values(), expected.next(candle).values());
+		}
+	}
+}
@@ -0,0 +1,331 @@
+use super::{Error, IndicatorConfig, IndicatorInitializer, IndicatorInstance, IndicatorResult};
+use super::{ValueType, OHLC};
+use alloc::collections::BTreeMap;
+use alloc::string::String;
+use alloc::vec::Vec;
+use core::cmp::Ordering;
+
+/// Runs the same indicator **Configuration** over many symbols in lockstep
+///
+/// Every call of [`next`](Universe::next) evaluates a single bar of every symbol and returns a [`CrossSection`]
+/// of the results, which allows to compare symbols with each other: rank them, find percentiles or select top-N.
+///
+/// A symbol joins the universe on its first candle: the **State** is initialized by this candle.
+/// Symbols which have no candle at some bar are not evaluated and are absent in the [`CrossSection`] of this bar.
+///
+/// # Examples
+///
+/// ```
+/// use yata::core::Universe;
+/// use yata::helpers::RandomCandles;
+/// use yata::indicators::RelativeStrengthIndex;
+///
+/// let btc: Vec<_> = RandomCandles::new().take(50).collect();
+/// let eth: Vec<_> = RandomCandles::new().skip(50).take(50).collect();
+/// let ltc: Vec<_> = RandomCandles::new().skip(100).take(50).collect();
+///
+/// let mut universe = Universe::new(RelativeStrengthIndex::default()).unwrap();
+///
+/// for i in 0..50 {
+///     let section = universe.next([("BTC", btc[i]), ("ETH", eth[i]), ("LTC", ltc[i])]).unwrap();
+///
+///     // two strongest symbols by the RSI value
+///     let top = section.top(2, 0);
+///     assert_eq!(top.len(), 2);
+///     assert!(section.value(top[0], 0) >= section.value(top[1], 0));
+///     assert!(section.percentile(top[0], 0) >= section.percentile(top[1], 0));
+/// }
+///
+/// assert_eq!(universe.symbols().collect::<Vec<_>>(), ["BTC", "ETH", "LTC"]);
+/// ```
+#[derive(Debug, Clone)]
+pub struct Universe<C, T>
+where
+	T: OHLC,
+	C: IndicatorConfig + IndicatorInitializer<T>,
+{
+	config: C,
+	states: BTreeMap<String, C::Instance>,
+}
+
+impl<C, T> Universe<C, T>
+where
+	T: OHLC,
+	C: IndicatorConfig + IndicatorInitializer<T>,
+{
+	/// Creates an empty universe with the indicator **Configuration**
+	///
+	/// Returns [`Error::WrongConfig`] if the `config` is not valid.
+	pub fn new(config: C) -> Result<Self, Error> {
+		if !config.validate() {
+			return Err(Error::WrongConfig.in_indicator(C::NAME));
+		}
+
+		Ok(Self {
+			config,
+			states: BTreeMap::new(),
+		})
+	}
+
+	/// Returns a reference to the indicator **Configuration**
+	pub const fn config(&self) -> &C {
+		&self.config
+	}
+
+	/// Returns an iterator over the symbols of the universe in alphabetical order
+	pub fn symbols(&self) -> impl Iterator<Item = &str> {
+		self.states.keys().map(String::as_str)
+	}
+
+	/// Returns a reference to the indicator **State** of the `symbol`
+	#[must_use]
+	pub fn get(&self, symbol: &str) -> Option<&C::Instance> {
+		self.states.get(symbol)
+	}
+
+	/// Removes the `symbol` from the universe and returns its indicator **State**
+	pub fn remove(&mut self, symbol: &str) -> Option<C::Instance> {
+		self.states.remove(symbol)
+	}
+
+	/// Returns count of symbols in the universe
+	#[must_use]
+	pub fn len(&self) -> usize {
+		self.states.len()
+	}
+
+	/// Checks if there are no symbols in the universe
+	#[must_use]
+	pub fn is_empty(&self) -> bool {
+		self.states.is_empty()
+	}
+
+	/// Evaluates the next bar: a candle for every symbol
+	///
+	/// Unknown symbols join the universe. Returns an error if the **State** of a new symbol can not be initialized.
+	/// In this case the symbols evaluated before the error are already updated.
+	pub fn next<I, S>(&mut self, candles: I) -> Result<CrossSection, Error>
+	where
+		I: IntoIterator<Item = (S, T)>,
+		S: Into<String>,
+	{
+		let mut results = BTreeMap::new();
+
+		for (symbol, candle) in candles {
+			let symbol = symbol.into();
+
+			let result = if let Some(state) = self.states.get_mut(&symbol) {
+				state.next(candle)
+			} else {
+				let mut state = self.config.clone().init(candle)?;
+				let result = state.next(candle);
+				self.states.insert(symbol.clone(), state);
+				result
+			};
+
+			results.insert(symbol, result);
+		}
+
+		Ok(CrossSection { results })
+	}
+}
+
+/// Results of the indicator for many symbols at the same bar
+///
+/// Produced by [`Universe::next`]. Symbols are compared by the raw value at `index` of their [`IndicatorResult`].
+/// Symbols with `NaN` or missing value are ignored by the cross-sectional operations.
+#[derive(Debug, Clone, Default)]
+pub struct CrossSection {
+	results: BTreeMap<String, IndicatorResult>,
+}
+
+impl CrossSection {
+	/// Returns the indicator result of the `symbol`
+	#[must_use]
+	pub fn get(&self, symbol: &str) -> Option<&IndicatorResult> {
+		self.results.get(symbol)
+	}
+
+	/// Returns the indicator results keyed by the symbols
+	#[must_use]
+	pub const fn results(&self) -> &BTreeMap<String, IndicatorResult> {
+		&self.results
+	}
+
+	/// Returns count of symbols in the cross section
+	#[must_use]
+	pub fn len(&self) -> usize {
+		self.results.len()
+	}
+
+	/// Checks if there are no symbols in the cross section
+	#[must_use]
+	pub fn is_empty(&self) -> bool {
+		self.results.is_empty()
+	}
+
+	/// Returns the raw value at `index` of the `symbol`
+	#[must_use]
+	pub fn value(&self, symbol: &str, index: usize) -> Option<ValueType> {
+		self.results
+			.get(symbol)
+			.and_then(|result| result.values().get(index).copied())
+	}
+
+	/// Returns symbols with their raw values at `index` sorted from the highest value to the lowest
+	///
+	/// Symbols with equal values keep alphabetical order.
+	#[must_use]
+	pub fn rank(&self, index: usize) -> Vec<(&str, ValueType)> {
+		let mut ranked: Vec<_> = self.values(index).collect();
+		ranked.sort_by(|a, b| b.1.partial_cmp(&a.1).unwrap_or(Ordering::Equal));
+
+		ranked
+	}
+
+	/// Returns the fraction of the other symbols, which raw values at `index` are strictly less than the value of the `symbol`
+	///
+	/// Output value is always in range \[0.0; 1.0\], like in [`PercentRank`](crate::methods::PercentRank).
+	/// Returns `None` if the `symbol` has no value or there are no other symbols to compare with.
+	#[must_use]
+	pub fn percentile(&self, symbol: &str, index: usize) -> Option<ValueType> {
+		let value = self.value(symbol, index).filter(|v| !v.is_nan())?;
+
+		let (less, others) = self
+			.values(index)
+			.filter(|&(s, _)| s != symbol)
+			.fold((0_usize, 0_usize), |(less, others), (_, v)| {
+				(less + usize::from(v < value), others + 1)
+			});
+
+		if others == 0 {
+			None
+		} else {
+			Some(less as ValueType / others as ValueType)
+		}
+	}
+
+	/// Returns up to `n` symbols with the highest raw values at `index`, starting from the highest one
+	#[must_use]
+	pub fn top(&self, n: usize, index: usize) -> Vec<&str> {
+		self.rank(index)
+			.into_iter()
+			.take(n)
+			.map(|(symbol, _)| symbol)
+			.collect()
+	}
+
+	/// Returns up to `n` symbols with the lowest raw values at `index`, starting from the lowest one
+	#[must_use]
+	pub fn bottom(&self, n: usize, index: usize) -> Vec<&str> {
+		self.rank(index)
+			.into_iter()
+			.rev()
+			.take(n)
+			.map(|(symbol, _)| symbol)
+			.collect()
+	}
+
+	fn values(&self, index: usize) -> impl Iterator<Item = (&str, ValueType)> {
+		self.results.iter().filter_map(move |(symbol, result)| {
+			result
+				.values()
+				.get(index)
+				.copied()
+				.filter(|v| !v.is_nan())
+				.map(|v| (symbol.as_str(), v))
+		})
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	use super::{CrossSection, Universe};
+	use crate::core::{
+		Action, Candle, IndicatorInitializer, IndicatorInstance, IndicatorResult, ValueType,
+	};
+	use crate::helpers::{assert_eq_float, RandomCandles};
+	use crate::indicators::{Trix, MACD};
+	use alloc::collections::BTreeMap;
+	use alloc::string::ToString;
+	use alloc::vec::Vec;
+
+	#[test]
+	fn test_universe() {
+		let candles: Vec<Candle> = RandomCandles::new().take(300).collect();
+		let (a, rest) = candles.split_at(100);
+		let (b, c) = rest.split_at(100);
+
+		let mut universe = Universe::new(Trix::default()).unwrap();
+		let mut expected_a = Trix::default().init(a[0]).unwrap();
+		let mut expected_c = Trix::default().init(c[50]).unwrap();
+
+		for i in 0..100 {
+			let mut bar = vec![("a", a[i]), ("b", b[i])];
+			if i >= 50 {
+				bar.push(("c", c[i]));
+			}
+
+			let section = universe.next(bar).unwrap();
+			assert_eq!(section.len(), if i >= 50 { 3 } else { 2 });
+
+			let value = expected_a.next(a[i]).value(0);
+			assert_eq_float(value, section.value("a", 0).unwrap());
+
+			if i >= 50 {
+				let value = expected_c.next(c[i]).value(0);
+				assert_eq_float(value, section.value("c", 0).unwrap());
+			}
+
+			let rank = section.rank(0);
+			assert!(rank.windows(2).all(|w| w[0].1 >= w[1].1));
+			assert_eq!(section.top(1, 0)[0], rank[0].0);
+			assert_eq!(section.bottom(1, 0)[0], rank[rank.len() - 1].0);
+		}
+
+		assert_eq!(universe.len(), 3);
+		assert!(universe.remove("b").is_some());
+		assert_eq!(universe.symbols().collect::<Vec<_>>(), ["a", "c"]);
+
+		let macd = MACD {
+			period1: 100,
+			..MACD::default()
+		};
+		assert!(Universe::<_, Candle>::new(macd).is_err());
+	}
+
+	#[test]
+	fn test_cross_section() {
+		let results: BTreeMap<_, _> = [
+			("a", 1.0),
+			("b", 3.0),
+			("c", ValueType::NAN),
+			("d", 2.0),
+			("e", 3.0),
+		]
+		.iter()
+		.map(|&(symbol, value)| {
+			(
+				symbol.to_string(),
+				IndicatorResult::new(&[value], &[Action::None]),
+			)
+		})
+		.collect();
+		let section = CrossSection { results };
+
+		assert_eq!(
+			section.rank(0),
+			[("b", 3.0), ("e", 3.0), ("d", 2.0), ("a", 1.0)]
+		);
+		assert_eq!(section.top(2, 0), ["b", "e"]);
+		assert_eq!(section.bottom(2, 0), ["a", "d"]);
+		assert_eq!(section.top(10, 0).len(), 4);
+		assert!(section.rank(1).is_empty());
+
+		assert_eq_float(0.0, section.percentile("a", 0).unwrap());
+		assert_eq_float(2.0 / 3.0, section.percentile("b", 0).unwrap());
+		assert_eq_float(1.0 / 3.0, section.percentile("d", 0).unwrap());
+		assert!(section.percentile("c", 0).is_none());
+		assert!(section.percentile("x", 0).is_none());
+	}
+}
@@ -0,0 +1,172 @@
+use super::{
+	Error, IndicatorConfig, IndicatorInitializer, IndicatorInstance, IndicatorResult, PeriodType,
+	OHLC,
+};
+use alloc::string::String;
+use core::marker::PhantomData;
+
+/// Wraps an indicator **State** and tracks if it has already processed enough candles to be warmed up
+///
+/// The **State** is ready after [`min_periods`](IndicatorConfig::min_periods) candles.
+/// Results before it may be suppressed by [`next_ready`](WarmUp::next_ready).
+///
+/// # Examples
+///
+/// ```
+/// use yata::core::WarmUp;
+/// use yata::helpers::RandomCandles;
+/// use yata::indicators::MACD;
+/// use yata::prelude::*;
+///
+/// let candles: Vec<_> = RandomCandles::new().take(50).collect();
+/// let macd = MACD::default();
+/// let mut state = WarmUp::new(macd.init(candles[0]).unwrap());
+///
+/// let results: Vec<_> = candles.iter().map(|&candle| state.next_ready(candle)).collect();
+///
+/// let warm_up = macd.min_periods() as usize;
+/// assert!(results[..warm_up - 1].iter().all(Option::is_none));
+/// assert!(results[warm_up - 1..].iter().all(Option::is_some));
+/// ```
+#[derive(Debug, Clone)]
+pub struct WarmUp<I, T> {
+	instance: I,
+	count: PeriodType,
+	min_periods: PeriodType,
+	phantom: PhantomData<T>,
+}
+
+impl<I, T> WarmUp<I, T>
+where
+	T: OHLC,
+	I: IndicatorInstance<T>,
+	I::Config: IndicatorInitializer<T, Instance = I>,
+{
+	/// Wraps an indicator **State**, which has not processed any candles yet
+	pub fn new(instance: I) -> Self {
+		let min_periods = instance.config().min_periods();
+
+		Self {
+			instance,
+			count: 0,
+			min_periods,
+			phantom: PhantomData,
+		}
+	}
+
+	/// Returns `true` if the **State** has processed at least [`min_periods`](IndicatorConfig::min_periods) candles
+	pub const fn is_ready(&self) -> bool {
+		self.count >= self.min_periods
+	}
+
+	/// Returns count of candles left to process until the **State** is ready
+	pub const fn remaining(&self) -> PeriodType {
+		self.min_periods.saturating_sub(self.count)
+	}
+
+	/// Returns a reference to the wrapped indicator **State**
+	pub const fn instance(&self) -> &I {
+		&self.instance
+	}
+
+	/// Unwraps the indicator **State**
+	pub fn into_inner(self) -> I {
+		self.instance
+	}
+
+	/// Evaluates the `candle` and returns the result even if the **State** is not ready yet
+	pub fn next(&mut self, candle: T) -> IndicatorResult {
+		self.count = self.count.saturating_add(1);
+		self.instance.next(candle)
+	}
+
+	/// Evaluates the `candle` and returns the result only if the **State** is ready
+	pub fn next_ready(&mut self, candle: T) -> Option<IndicatorResult> {
+		let result = self.next(candle);
+
+		if self.is_ready() {
+			Some(result)
+		} else {
+			None
+		}
+	}
+
+	/// Returns a one-line summary of the indicator **Configuration** and the readiness of the **State** for logging
+	///
+	/// See [`IndicatorInstance::describe`].
+	/// ```
+	/// use yata::core::WarmUp;
+	/// use yata::helpers::RandomCandles;
+	/// use yata::indicators::Trix;
+	/// use yata::prelude::*;
+	///
+	/// let mut candles = RandomCandles::new();
+	/// let mut state = WarmUp::new(Trix::default().init(candles.first()).unwrap());
+	/// state.next(candles.next().unwrap());
+	///
+	/// assert!(state.describe().ends_with("min_periods=53, warming up (52 candles left)"));
+	/// ```
+	pub fn describe(&self) -> String {
+		let description = self.instance.describe();
+
+		if self.is_ready() {
+			format!("{description}, ready")
+		} else {
+			format!(
+				"{description}, warming up ({} candles left)",
+				self.remaining()
+			)
+		}
+	}
+
+	/// Resets the **State** by the `candle`, so it has to warm up again
+	pub fn reset(&mut self, candle: T) -> Result<(), Error> {
+		self.instance.reset(candle)?;
+		self.count = 0;
+
+		Ok(())
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	use super::WarmUp;
+	use crate::core::{Candle, IndicatorConfig, IndicatorInitializer, IndicatorInstance};
+	use crate::helpers::RandomCandles;
+	use crate::indicators::{Trix, MACD};
+	use alloc::vec::Vec;
+
+	#[test]
+	fn test_warm_up() {
+		let candles: Vec<Candle> = RandomCandles::new().take(100).collect();
+
+		let macd = MACD {
+			period3: 5,
+			..MACD::default()
+		};
+		let mut state = WarmUp::new(macd.init(candles[0]).unwrap());
+		let mut raw = macd.init(candles[0]).unwrap();
+
+		assert_eq!(state.remaining(), macd.period2 + 4);
+
+		for (i, &candle) in candles.iter().enumerate() {
+			let expected = raw.next(candle);
+
+			match state.next_ready(candle) {
+				Some(result) => {
+					assert!(i + 1 >= macd.min_periods() as usize);
+					assert_eq!(result.values(), expected.values());
+				}
+				None => assert!(i + 1 < macd.min_periods() as usize),
+			}
+		}
+
+		assert!(state.is_ready());
+		state.reset(candles[0]).unwrap();
+		assert!(!state.is_ready());
+
+		let trix = Trix::default();
+		let state: WarmUp<_, Candle> = WarmUp::new(trix.init(candles[0]).unwrap());
+		assert_eq!(state.remaining(), trix.period1 * 3 - 1);
+	}
+}
@@ -1,7 +1,8 @@
 #![allow(unsafe_code)]
 use super::PeriodType;
-use std::mem;
-use std::vec;
+use alloc::vec;
+use alloc::vec::Vec;
+use core::mem;
 
 #[cfg(feature = "serde")]
 use serde::{Deserialize, Serialize};
@@ -90,6 +91,28 @@ where
 		}
 	}
 
+	/// Fills the `Window` with `value` in place, so it looks like just [created](Window::new) with the same `size`
+	///
+	/// # Examples
+	///
+	/// ```
+	/// use yata::core::Window;
+	///
+	/// let mut w = Window::new(3, 1);
+	///
+	/// w.push(2);
+	/// w.push(3);
+	/// w.fill(4);
+	///
+	/// let p: Vec<i32> = w.iter().collect();
+	/// assert_eq!(p, [4, 4, 4]);
+	/// assert_eq!(w.push(5), 4);
+	/// ```
+	pub fn fill(&mut self, value: T) {
+		self.buf.iter_mut().for_each(|x| *x = value);
+		self.index = 0;
+	}
+
 	/// Pushes the `value` into the `Window`.
 	///
 	/// Returns an oldest pushed value.
@@ -116,6 +139,63 @@ where
 		old_value
 	}
 
+	/// Reverts the last [`push`](Window::push): puts back the `value` pushed out by it as the oldest one.
+	///
+	/// Returns the last pushed value.
+	///
+	/// # Examples
+	///
+	/// ```
+	/// use yata::core::Window;
+	///
+	/// let mut w = Window::new(3, 1);
+	///
+	/// w.push(2);
+	/// w.push(3);
+	/// let oldest = w.push(4);
+	///
+	/// assert_eq!(w.undo_push(oldest), 4);
+	///
+	/// let p: Vec<i32> = w.iter().collect();
+	/// assert_eq!(p, [1, 2, 3]);
+	/// ```
+	#[inline]
+	pub fn undo_push(&mut self, value: T) -> T {
+		debug_assert!(!self.is_empty(), "Trying to use an empty window");
+
+		self.index = if self.index == 0 {
+			self.s_1
+		} else {
+			self.index - 1
+		};
+
+		mem::replace(&mut self.buf[self.index as usize], value)
+	}
+
+	/// Pushes all the values from the `slice` into the `Window` in order.
+	///
+	/// Values which would be pushed out anyway are skipped.
+	///
+	/// # Examples
+	///
+	/// ```
+	/// use yata::core::Window;
+	///
+	/// let mut w = Window::new(3, 1);
+	///
+	/// w.push_slice(&[2, 3, 4, 5]);
+	///
+	/// let p: Vec<i32> = w.iter().collect();
+	/// assert_eq!(p, [3, 4, 5]);
+	/// ```
+	pub fn push_slice(&mut self, slice: &[T]) {
+		let skip = slice.len().saturating_sub(self.size as usize);
+
+		for &value in &slice[skip..] {
+			self.push(value);
+		}
+	}
+
 	/// Returns an iterator over the `Window`'s values (by copy) (from the oldest to the newest).
 	///
 	/// # Examples
@@ -185,6 +265,121 @@ where
 		*unsafe { self.buf.get_unchecked(index as usize) }
 	}
 
+	/// Returns an oldest value. The same as [`first`](Window::first)
+	///
+	/// # Examples
+	///
+	/// ```
+	/// use yata::core::Window;
+	///
+	/// let mut w = Window::new(3, 1);
+	///
+	/// w.push(2);
+	/// w.push(3);
+	/// w.push(4);
+	///
+	/// assert_eq!(w.oldest(), 2);
+	/// assert_eq!(w.newest(), 4);
+	/// ```
+	#[inline]
+	#[must_use]
+	pub fn oldest(&self) -> T {
+		self.first()
+	}
+
+	/// Returns a last pushed value. The same as [`last`](Window::last)
+	#[inline]
+	#[must_use]
+	pub fn newest(&self) -> T {
+		self.last()
+	}
+
+	/// Returns a value by its `index` in chronological order (`0` is the oldest one)
+	/// or `None` if the `index` is out of range
+	///
+	/// # Examples
+	///
+	/// ```
+	/// use yata::core::Window;
+	///
+	/// let mut w = Window::new(3, 1);
+	///
+	/// w.push(2);
+	///
+	/// assert_eq!(w.get(2), Some(2));
+	/// assert_eq!(w.get(3), None);
+	/// ```
+	#[must_use]
+	pub fn get(&self, index: PeriodType) -> Option<T> {
+		(index < self.size).then(|| self[index])
+	}
+
+	/// Returns a pair of slices, which contain the values in chronological order
+	///
+	/// The first slice contains the oldest values. The second slice is empty if the values are stored contiguously.
+	///
+	/// # Examples
+	///
+	/// ```
+	/// use yata::core::Window;
+	///
+	/// let mut w = Window::new(3, 1);
+	///
+	/// w.push(2);
+	///
+	/// assert_eq!(w.as_slices(), (&[1, 1][..], &[2][..]));
+	/// ```
+	#[must_use]
+	pub fn as_slices(&self) -> (&[T], &[T]) {
+		let (newest, oldest) = self.buf.split_at(self.index as usize);
+		(oldest, newest)
+	}
+
+	/// Returns all the values in chronological order as a single slice if they are stored contiguously, or `None` otherwise
+	///
+	/// See also [`make_contiguous`](Window::make_contiguous).
+	///
+	/// # Examples
+	///
+	/// ```
+	/// use yata::core::Window;
+	///
+	/// let mut w = Window::new(2, 1);
+	///
+	/// w.push(2);
+	/// assert_eq!(w.contiguous(), None);
+	///
+	/// w.push(3);
+	/// assert_eq!(w.contiguous(), Some(&[2, 3][..]));
+	/// ```
+	#[must_use]
+	pub fn contiguous(&self) -> Option<&[T]> {
+		(self.index == 0).then_some(self.buf.as_slice())
+	}
+
+	/// Rearranges the internal buffer, so the values are stored contiguously, and returns them in chronological order
+	///
+	/// Takes `O(len)` time, so it should not be called on every [`push`](Window::push).
+	///
+	/// # Examples
+	///
+	/// ```
+	/// use yata::core::Window;
+	///
+	/// let mut w = Window::new(3, 1);
+	///
+	/// w.push(2);
+	/// assert_eq!(w.make_contiguous(), &[1, 1, 2]);
+	///
+	/// assert_eq!(w.contiguous(), Some(&[1, 1, 2][..]));
+	/// assert_eq!(w.push(3), 1);
+	/// ```
+	pub fn make_contiguous(&mut self) -> &[T] {
+		self.buf.rotate_left(self.index as usize);
+		self.index = 0;
+		self.buf.as_slice()
+	}
+
 	/// Checks if `Window` is empty (`length` == 0). Returns `true` if `Window` is empty or false otherwise.
 	#[must_use]
 	pub fn is_empty(&self) -> bool {
@@ -198,6 +393,9 @@ where
 	}
 
 	/// Casts `Window` as a slice of `T`
+	///
+	/// Values are in the order of the internal buffer, which is not chronological in general.
+	/// Use [`as_slices`](Window::as_slices) or [`contiguous`](Window::contiguous) to get them in chronological order.
 	#[must_use]
 	pub fn as_slice(&self) -> &[T] {
 		self.buf.as_slice()
@@ -219,7 +417,7 @@ where
 	}
 }
 
-impl<T> std::ops::Index<PeriodType> for Window<T>
+impl<T> core::ops::Index<PeriodType> for Window<T>
 where
 	T: Copy,
 {
@@ -263,6 +461,9 @@ where
 // 	}
 // }
 
+/// Iterator over the values of the [`Window`] in chronological order
+///
+/// Created by [`Window::iter`].
 #[derive(Debug)]
 pub struct WindowIterator<'a, T>
 where
@@ -277,6 +478,8 @@ impl<'a, T> WindowIterator<'a, T>
 where
 	T: Copy,
 {
+	/// Creates an iterator over the `window`
+	#[must_use]
 	pub fn new(window: &'a Window<T>) -> Self {
 		Self {
 			window,
@@ -325,7 +528,7 @@ where
 }
 
 impl<'a, T> ExactSizeIterator for WindowIterator<'a, T> where T: Copy {}
-impl<'a, T> std::iter::FusedIterator for WindowIterator<'a, T> where T: Copy {}
+impl<'a, T> core::iter::FusedIterator for WindowIterator<'a, T> where T: Copy {}
 
 #[cfg(test)]
 mod tests {
@@ -404,6 +607,37 @@ mod tests {
 		}
 	}
 
+	#[test]
+	fn test_slices() {
+		let data: Vec<_> = RandomCandles::new().take(300).collect();
+
+		for length in 1..255 {
+			let mut w = Window::new(length, data[0]);
+
+			for &c in &data {
+				w.push(c);
+
+				let expected: Vec<_> = w.iter().collect();
+				let (oldest, newest) = w.as_slices();
+				let joined: Vec<_> = oldest.iter().chain(newest).copied().collect();
+				assert_eq!(joined, expected);
+
+				if let Some(slice) = w.contiguous() {
+					assert_eq!(slice, expected.as_slice());
+				}
+
+				assert_eq!(w.get(length - 1), Some(c));
+				assert_eq!(w.get(length), None);
+			}
+
+			let expected: Vec<_> = w.iter().collect();
+			assert_eq!(w.make_contiguous(), expected.as_slice());
+			assert_eq!(w.contiguous(), Some(expected.as_slice()));
+			assert_eq!(w.push(data[0]), expected[0]);
+			assert_eq!(w.newest(), data[0]);
+		}
+	}
+
 	#[test]
 	fn test_index() {
 		let data: Vec<_> = RandomCandles::new().take(300).collect();
@@ -0,0 +1,654 @@
+//! Reading and writing candles in CSV format
+//!
+//! Available only with the `csv` feature.
+
+use crate::core::{Error, TimedCandle, ValueType};
+use std::fs::File;
+use std::io::{BufRead, BufReader, BufWriter, Lines, Write};
+use std::path::Path;
+
+/// Field of the [`TimedCandle`]
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Field {
+	/// Time of the candle, see [`TimeFormat`]
+	Timestamp,
+
+	/// *Open* value
+	Open,
+
+	/// *High* value
+	High,
+
+	/// *Low* value
+	Low,
+
+	/// *Close* value
+	Close,
+
+	/// *Volume* value
+	Volume,
+}
+
+impl Field {
+	const ALL: [Self; 6] = [
+		Self::Timestamp,
+		Self::Open,
+		Self::High,
+		Self::Low,
+		Self::Close,
+		Self::Volume,
+	];
+
+	/// Returns default column name of the field
+	#[must_use]
+	pub const fn name(self) -> &'static str {
+		match self {
+			Self::Timestamp => "timestamp",
+			Self::Open => "open",
+			Self::High => "high",
+			Self::Low => "low",
+			Self::Close => "close",
+			Self::Volume => "volume",
+		}
+	}
+
+	/// Returns `true` if the file may have no such column
+	///
+	/// Missing timestamp is read as `0` and missing volume is read as `NaN`.
+	const fn is_optional(self) -> bool {
+		matches!(self, Self::Timestamp | Self::Volume)
+	}
+}
+
+/// Column of the CSV file
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Column {
+	/// Column at the index, starting from `0`
+	Index(usize),
+
+	/// Column with the name in the header. Names are compared case-insensitively
+	Name(String),
+}
+
+impl From<usize> for Column {
+	fn from(index: usize) -> Self {
+		Self::Index(index)
+	}
+}
+
+impl From<&str> for Column {
+	fn from(name: &str) -> Self {
+		Self::Name(name.into())
+	}
+}
+
+impl From<String> for Column {
+	fn from(name: String) -> Self {
+		Self::Name(name)
+	}
+}
+
+/// Format of the timestamp column
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum TimeFormat {
+	/// Integer count of milliseconds since the Unix epoch
+	Milliseconds,
+
+	/// Integer count of seconds since the Unix epoch
+	Seconds,
+
+	/// UTC date and time like `2021-03-14 15:09:26`, `2021-03-14T15:09:26.535Z` or just date `2021-03-14`
+	DateTime,
+}
+
+/// Configuration of the CSV candles format
+///
+/// By default the file has a header and columns are found by the names
+/// `timestamp`, `open`, `high`, `low`, `close` and `volume`. Timestamps are in milliseconds.
+///
+/// # Examples
+///
+/// ```
+/// use yata::core::TimedCandle;
+/// use yata::helpers::csv::{Csv, Field, TimeFormat};
+///
+/// let data = "Date;Open;High;Low;Close\n2021-03-14;3.0;5.0;2.0;4.0\n2021-03-15;4.0;6.0;3.5;5.5\n";
+///
+/// let csv = Csv::new()
+///     .delimiter(';')
+///     .column(Field::Timestamp, "date")
+///     .time_format(TimeFormat::DateTime);
+///
+/// let candles: Vec<TimedCandle> = csv.reader(data.as_bytes()).unwrap().collect::<Result<_, _>>().unwrap();
+///
+/// assert_eq!(candles.len(), 2);
+/// assert_eq!(candles[1].timestamp, 1_615_766_400_000);
+/// assert_eq!(candles[0].close, 4.0);
+/// assert!(candles[0].volume.is_nan());
+///
+/// let mut output = Vec::new();
+/// csv.write(&mut output, candles).unwrap();
+/// assert!(String::from_utf8(output).unwrap().starts_with("date;open;high;low;close;volume\n2021-03-14 00:00:00;3;"));
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Csv {
+	delimiter: char,
+	header: bool,
+	time_format: TimeFormat,
+	columns: [Option<Column>; 6],
+}
+
+impl Csv {
+	/// Creates the default CSV format
+	#[must_use]
+	pub fn new() -> Self {
+		Self {
+			delimiter: ',',
+			header: true,
+			time_format: TimeFormat::Milliseconds,
+			columns: Field::ALL.map(|field| Some(Column::from(field.name()))),
+		}
+	}
+
+	/// Sets delimiter of the values. Default is `,`
+	#[must_use]
+	pub const fn delimiter(mut self, delimiter: char) -> Self {
+		self.delimiter = delimiter;
+		self
+	}
+
+	/// Sets if the file has a header line. Default is `true`
+	///
+	/// Columns of the file without a header may be set only by [`Column::Index`].
+	#[must_use]
+	pub const fn header(mut self, header: bool) -> Self {
+		self.header = header;
+		self
+	}
+
+	/// Sets format of the timestamp column. Default is [`TimeFormat::Milliseconds`]
+	#[must_use]
+	pub const fn time_format(mut self, time_format: TimeFormat) -> Self {
+		self.time_format = time_format;
+		self
+	}
+
+	/// Sets the `column` of the `field`
+	#[must_use]
+	pub fn column<C: Into<Column>>(mut self, field: Field, column: C) -> Self {
+		self.columns[field as usize] = Some(column.into());
+		self
+	}
+
+	/// Removes the `field` column, so the field is neither read nor written
+	///
+	/// Only optional [`Field::Timestamp`] and [`Field::Volume`] may be skipped.
+	#[must_use]
+	pub fn skip(mut self, field: Field) -> Self {
+		self.columns[field as usize] = None;
+		self
+	}
+
+	/// Creates an iterator of the candles read from the `reader`
+	///
+	/// The header, if any, is read immediately, so missing columns are reported here.
+	pub fn reader<R: BufRead>(&self, reader: R) -> Result<CsvReader<R>, Error> {
+		let mut lines = reader.lines();
+		let mut line = 0;
+
+		let header = if self.header {
+			line += 1;
+			match lines.next() {
+				Some(header) => split(&header.map_err(io)?, self.delimiter),
+				None => Vec::new(),
+			}
+		} else {
+			Vec::new()
+		};
+
+		let mut indexes = [None; 6];
+
+		for (field, column) in Field::ALL.iter().zip(&self.columns) {
+			let index = match column {
+				Some(Column::Index(index)) => Some(*index),
+				Some(Column::Name(name)) => header
+					.iter()
+					.position(|column| column.eq_ignore_ascii_case(name)),
+				None => None,
+			};
+
+			if index.is_none() && !field.is_optional() {
+				return Err(Error::CsvParse(format!(
+					"missing column `{}`",
+					field.name()
+				)));
+			}
+
+			indexes[*field as usize] = index;
+		}
+
+		Ok(CsvReader {
+			lines,
+			line,
+			delimiter: self.delimiter,
+			time_format: self.time_format,
+			indexes,
+		})
+	}
+
+	/// Reads all the candles from the file at the `path`
+	///
+	/// Candles may be of any type, which may be created from [`TimedCandle`], f.e. [`Candle`](crate::core::Candle).
+	pub fn read_file<T: From<TimedCandle>, P: AsRef<Path>>(
+		&self,
+		path: P,
+	) -> Result<Vec<T>, Error> {
+		let file = File::open(path).map_err(io)?;
+
+		self.reader(BufReader::new(file))?
+			.map(|candle| candle.map(T::from))
+			.collect()
+	}
+
+	/// Writes the `candles` into the `writer`
+	///
+	/// Columns are written in the order of [`Field`] variants. Header uses names of the columns set by [`Column::Name`]
+	/// or default names otherwise.
+	pub fn write<W, I>(&self, mut writer: W, candles: I) -> Result<(), Error>
+	where
+		W: Write,
+		I: IntoIterator,
+		I::Item: Into<TimedCandle>,
+	{
+		let fields: Vec<(Field, &str)> = Field::ALL
+			.iter()
+			.zip(&self.columns)
+			.filter_map(|(&field, column)| match column {
+				Some(Column::Name(name)) => Some((field, name.as_str())),
+				Some(Column::Index(_)) => Some((field, field.name())),
+				None => None,
+			})
+			.collect();
+
+		let delimiter = self.delimiter.to_string();
+
+		if self.header {
+			let names: Vec<&str> = fields.iter().map(|&(_, name)| name).collect();
+			writeln!(writer, "{}", names.join(&delimiter)).map_err(io)?;
+		}
+
+		for candle in candles {
+			let candle = candle.into();
+
+			let values: Vec<String> = fields
+				.iter()
+				.map(|&(field, _)| match field {
+					Field::Timestamp => format_timestamp(candle.timestamp, self.time_format),
+					Field::Open => candle.open.to_string(),
+					Field::High => candle.high.to_string(),
+					Field::Low => candle.low.to_string(),
+					Field::Close => candle.close.to_string(),
+					Field::Volume => candle.volume.to_string(),
+				})
+				.collect();
+
+			writeln!(writer, "{}", values.join(&delimiter)).map_err(io)?;
+		}
+
+		writer.flush().map_err(io)
+	}
+
+	/// Writes the `candles` into the file at the `path`. The file is created or truncated
+	pub fn write_file<P, I>(&self, path: P, candles: I) -> Result<(), Error>
+	where
+		P: AsRef<Path>,
+		I: IntoIterator,
+		I::Item: Into<TimedCandle>,
+	{
+		let file = File::create(path).map_err(io)?;
+		self.write(BufWriter::new(file), candles)
+	}
+}
+
+impl Default for Csv {
+	fn default() -> Self {
+		Self::new()
+	}
+}
+
+/// Iterator of the candles read from CSV data
+///
+/// Created by [`Csv::reader`]. Empty lines are skipped.
+#[derive(Debug)]
+pub struct CsvReader<R> {
+	lines: Lines<R>,
+	line: usize,
+	delimiter: char,
+	time_format: TimeFormat,
+	indexes: [Option<usize>; 6],
+}
+
+impl<R> CsvReader<R> {
+	fn parse(&self, line: &str) -> Result<TimedCandle, Error> {
+		let values = split(line, self.delimiter);
+
+		let value = |field: Field| -> Result<Option<&str>, Error> {
+			self.indexes[field as usize]
+				.map(|index| {
+					values.get(index).map(String::as_str).ok_or_else(|| {
+						Error::CsvParse(format!(
+							"line {}: missing column `{}`",
+							self.line,
+							field.name()
+						))
+					})
+				})
+				.transpose()
+		};
+
+		let number = |field: Field| -> Result<ValueType, Error> {
+			value(field)?.map_or(Ok(ValueType::NAN), |value| {
+				value.trim().parse().map_err(|_| {
+					Error::CsvParse(format!(
+						"line {}: invalid {} value `{}`",
+						self.line,
+						field.name(),
+						value
+					))
+				})
+			})
+		};
+
+		let timestamp = value(Field::Timestamp)?.map_or(Ok(0), |value| {
+			parse_timestamp(value.trim(), self.time_format).ok_or_else(|| {
+				Error::CsvParse(format!("line {}: invalid timestamp `{}`", self.line, value))
+			})
+		})?;
+
+		Ok(TimedCandle {
+			timestamp,
+			open: number(Field::Open)?,
+			high: number(Field::High)?,
+			low: number(Field::Low)?,
+			close: number(Field::Close)?,
+			volume: number(Field::Volume)?,
+		})
+	}
+}
+
+impl<R: BufRead> Iterator for CsvReader<R> {
+	type Item = Result<TimedCandle, Error>;
+
+	fn next(&mut self) -> Option<Self::Item> {
+		loop {
+			self.line += 1;
+
+			match self.lines.next()? {
+				Err(e) => return Some(Err(io(e))),
+				Ok(line) if line.trim().is_empty() => {}
+				Ok(line) => return Some(self.parse(&line)),
+			}
+		}
+	}
+}
+
+#[allow(clippy::needless_pass_by_value)]
+fn io(e: std::io::Error) -> Error {
+	Error::Other(e.to_string())
+}
+
+/// Splits the line into the values. Values may be quoted by `"`, quotes inside are escaped by doubling
+fn split(line: &str, delimiter: char) -> Vec<String> {
+	let mut values = Vec::new();
+	let mut value = String::new();
+	let mut quoted = false;
+	let mut chars = line.chars().peekable();
+
+	while let Some(c) = chars.next() {
+		match c {
+			'"' if quoted && chars.peek() == Some(&'"') => {
+				chars.next();
+				value.push('"');
+			}
+			'"' => quoted = !quoted,
+			c if c == delimiter && !quoted => values.push(core::mem::take(&mut value)),
+			c => value.push(c),
+		}
+	}
+
+	values.push(value);
+	values
+}
+
+const MS_PER_DAY: i64 = 86_400_000;
+
+/// Returns count of days since the Unix epoch of the date in the proleptic Gregorian calendar
+const fn days_from_civil(year: i64, month: i64, day: i64) -> i64 {
+	let year = if month <= 2 { year - 1 } else { year };
+	let era = year.div_euclid(400);
+	let year_of_era = year - era * 400;
+	let day_of_year = (153 * ((month + 9) % 12) + 2) / 5 + day - 1;
+	let day_of_era = year_of_era * 365 + year_of_era / 4 - year_of_era / 100 + day_of_year;
+
+	era * 146_097 + day_of_era - 719_468
+}
+
+/// Returns `(year, month, day)` of the date at the count of `days` since the Unix epoch
+const fn civil_from_days(days: i64) -> (i64, i64, i64) {
+	let days = days + 719_468;
+	let era = days.div_euclid(146_097);
+	let day_of_era = days - era * 146_097;
+	let year_of_era =
+		(day_of_era - day_of_era / 1460 + day_of_era / 36524 - day_of_era / 146_096) / 365;
+	let day_of_year = day_of_era - (365 * year_of_era + year_of_era / 4 - year_of_era / 100);
+	let mp = (5 * day_of_year + 2) / 153;
+	let day = day_of_year - (153 * mp + 2) / 5 + 1;
+	let month = if mp < 10 { mp + 3 } else { mp - 9 };
+	let year = year_of_era + era * 400 + if month <= 2 { 1 } else { 0 };
+
+	(year, month, day)
+}
+
+fn parse_timestamp(s: &str, format: TimeFormat) -> Option<i64> {
+	match format {
+		TimeFormat::Milliseconds => s.parse().ok(),
+		TimeFormat::Seconds => s.parse::<i64>().ok()?.checked_mul(1000),
+		TimeFormat::DateTime => parse_date_time(s),
+	}
+}
+
+fn parse_date_time(s: &str) -> Option<i64> {
+	let s = s.strip_suffix('Z').unwrap_or(s);
+	let (date, time) = s.split_at(s.find([' ', 'T']).unwrap_or(s.len()));
+
+	let mut date = date.splitn(3, '-').map(str::parse::<i64>);
+	let year = date.next()?.ok()?;
+	let month = date.next()?.ok()?;
+	let day = date.next()?.ok()?;
+
+	if !(1..=12).contains(&month) || !(1..=31).contains(&day) {
+		return None;
+	}
+
+	let (time, fraction) = time
+		.get(1..)
+		.map_or(("", ""), |time| time.split_once('.').unwrap_or((time, "")));
+
+	let mut time = time
+		.split(':')
+		.filter(|s| !s.is_empty())
+		.map(str::parse::<i64>);
+	let hours = time.next().unwrap_or(Ok(0)).ok()?;
+	let minutes = time.next().unwrap_or(Ok(0)).ok()?;
+	let seconds = time.next().unwrap_or(Ok(0)).ok()?;
+
+	if time.next().is_some() || hours > 23 || minutes > 59 || seconds > 60 {
+		return None;
+	}
+
+	let millis = fraction
+		.chars()
+		.chain("000".chars())
+		.take(3)
+		.try_fold(0, |millis, c| {
+			Some(millis * 10 + i64::from(c.to_digit(10)?))
+		})?;
+
+	Some(
+		days_from_civil(year, month, day) * MS_PER_DAY
+			+ ((hours * 60 + minutes) * 60 + seconds) * 1000
+			+ millis,
+	)
+}
+
+fn format_timestamp(timestamp: i64, format: TimeFormat) -> String {
+	match format {
+		TimeFormat::Milliseconds => timestamp.to_string(),
+		TimeFormat::Seconds => timestamp.div_euclid(1000).to_string(),
+		TimeFormat::DateTime => {
+			let (year, month, day) = civil_from_days(timestamp.div_euclid(MS_PER_DAY));
+			let millis = timestamp.rem_euclid(MS_PER_DAY);
+			let seconds = millis / 1000;
+
+			let date_time = format!(
+				"{:04}-{:02}-{:02} {:02}:{:02}:{:02}",
+				year,
+				month,
+				day,
+				seconds / 3600,
+				seconds / 60 % 60,
+				seconds % 60
+			);
+
+			match millis % 1000 {
+				0 => date_time,
+				millis => format!("{date_time}.{millis:03}"),
+			}
+		}
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	use super::{format_timestamp, parse_timestamp, Csv, Field, TimeFormat};
+	use crate::core::{Candle, TimedCandle};
+	use crate::helpers::{assert_eq_float, RandomCandles};
+
+	#[test]
+	fn test_csv_round_trip() {
+		let candles: Vec<TimedCandle> = RandomCandles::new()
+			.take(100)
+			.zip((0_i64..).map(|i| 1_600_000_000_000 + i * 60_000))
+			.map(|(candle, timestamp)| TimedCandle::new(timestamp, candle))
+			.collect();
+
+		for &format in &[
+			TimeFormat::Milliseconds,
+			TimeFormat::Seconds,
+			TimeFormat::DateTime,
+		] {
+			let csv = Csv::new().delimiter('\t').time_format(format);
+
+			let mut data = Vec::new();
+			csv.write(&mut data, candles.iter().copied()).unwrap();
+
+			let read: Vec<TimedCandle> = csv
+				.reader(data.as_slice())
+				.unwrap()
+				.collect::<Result<_, _>>()
+				.unwrap();
+
+			assert_eq!(read.len(), candles.len());
+
+			for (original, read) in candles.iter().zip(&read) {
+				assert_eq!(original.timestamp, read.timestamp);
+				assert_eq_float(original.open, read.open);
+				assert_eq_float(original.high, read.high);
+				assert_eq_float(original.low, read.low);
+				assert_eq_float(original.close, read.close);
+				assert_eq_float(original.volume, read.volume);
+			}
+		}
+	}
+
+	#[test]
+	fn test_csv_columns() {
+		let data = "\"close\",x,\"low, min\",high,open\n4,a,2,5,3\n\n6,b,1,7,2\n";
+
+		let csv = Csv::new()
+			.column(Field::Low, "LOW, MIN")
+			.skip(Field::Timestamp)
+			.skip(Field::Volume);
+		let candles: Vec<Candle> = csv
+			.reader(data.as_bytes())
+			.unwrap()
+			.map(|candle| candle.map(Candle::from))
+			.collect::<Result<_, _>>()
+			.unwrap();
+
+		assert_eq!(candles.len(), 2);
+		assert_eq_float(3.0, candles[0].open);
+		assert_eq_float(1.0, candles[1].low);
+		assert!(candles[0].volume.is_nan());
+
+		let no_header = Csv::new()
+			.header(false)
+			.column(Field::Open, 4)
+			.column(Field::High, 3)
+			.column(Field::Low, 2)
+			.column(Field::Close, 0)
+			.skip(Field::Timestamp);
+		let rows = &data[data.find('\n').unwrap() + 1..];
+		let mut reader = no_header.reader(rows.as_bytes()).unwrap();
+		assert!(reader.next().unwrap().is_ok());
+		assert!(reader.next().unwrap().is_ok());
+		assert!(reader.next().is_none());
+
+		assert!(Csv::new().reader(&b"open,high,low\n"[..]).is_err());
+		assert!(Csv::new()
+			.reader(&b"open,high,low,close\n1,2,a,1\n"[..])
+			.unwrap()
+			.next()
+			.unwrap()
+			.is_err());
+	}
+
+	#[test]
+	fn test_csv_timestamps() {
+		let cases = [
+			("1970-01-01", 0),
+			("2021-03-14 15:09:26", 1_615_734_566_000),
+			("2021-03-14T15:09:26.5Z", 1_615_734_566_500),
+			("2000-02-29T23:59", 951_868_740_000),
+			("1969-12-31 23:59:59.999", -1),
+		];
+
+		for &(s, timestamp) in &cases {
+			assert_eq!(parse_timestamp(s, TimeFormat::DateTime), Some(timestamp));
+
+			let formatted = format_timestamp(timestamp, TimeFormat::DateTime);
+			assert_eq!(
+				parse_timestamp(&formatted, TimeFormat::DateTime),
+				Some(timestamp)
+			);
+		}
+
+		assert_eq!(
+			format_timestamp(1_615_734_566_500, TimeFormat::DateTime),
+			"2021-03-14 15:09:26.500"
+		);
+		assert_eq!(
+			parse_timestamp("1615734566", TimeFormat::Seconds),
+			Some(1_615_734_566_000)
+		);
+
+		for s in &[
+			"2021-13-01",
+			"2021-03-14 25:00",
+			"2021-03-14 12:00:00+03:00",
+			"today",
+		] {
+			assert_eq!(parse_timestamp(s, TimeFormat::DateTime), None);
+		}
+	}
+}
@@ -0,0 +1,219 @@
+use super::Rng;
+use crate::core::{Candle, ValueType};
+
+#[cfg(not(feature = "std"))]
+use crate::core::Float as _;
+
+/// Market regime of the [`MarketCandles`] generator
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Regime {
+	/// Price drifts up
+	Uptrend,
+
+	/// Price drifts down
+	Downtrend,
+
+	/// Price oscillates around the level it had when the regime started
+	Choppy,
+}
+
+/// Random candles iterator with realistic market structure for testing purposes
+///
+/// Unlike [`RandomCandles`](super::RandomCandles), models:
+/// * stochastic switches between trending and choppy [regimes](Regime);
+/// * occasional price gaps between the *close* of the previous candle and the *open* of the next one;
+/// * volume correlated with the candle range, so wide candles and gaps come with high volume.
+///
+/// Sequence is deterministic for the same `seed`.
+///
+/// # Examples
+///
+/// ```
+/// use yata::core::OHLC;
+/// use yata::helpers::MarketCandles;
+///
+/// let candles: Vec<_> = MarketCandles::new(42).gap_probability(0.05).take(500).collect();
+///
+/// assert!(candles.iter().all(OHLC::validate));
+/// assert_eq!(candles, MarketCandles::new(42).gap_probability(0.05).take(500).collect::<Vec<_>>());
+/// ```
+#[derive(Debug, Clone)]
+pub struct MarketCandles {
+	rng: Rng,
+	price: ValueType,
+	level: ValueType,
+	regime: Regime,
+	volatility: ValueType,
+	gap_probability: ValueType,
+	switch_probability: ValueType,
+}
+
+impl MarketCandles {
+	const DEFAULT_PRICE: ValueType = 100.0;
+	const DEFAULT_VOLUME: ValueType = 1000.0;
+
+	/// Creates a new generator with the `seed`
+	///
+	/// Default volatility is `0.01` (1% per candle), gap probability is `0.01` and regime switch probability is `0.02`.
+	#[must_use]
+	pub const fn new(seed: u32) -> Self {
+		Self {
+			rng: Rng::new(seed),
+			price: Self::DEFAULT_PRICE,
+			level: Self::DEFAULT_PRICE,
+			regime: Regime::Choppy,
+			volatility: 0.01,
+			gap_probability: 0.01,
+			switch_probability: 0.02,
+		}
+	}
+
+	/// Sets relative deviation of the price change of every candle
+	#[must_use]
+	pub const fn volatility(mut self, volatility: ValueType) -> Self {
+		self.volatility = volatility;
+		self
+	}
+
+	/// Sets probability of the price gap before every candle
+	#[must_use]
+	pub const fn gap_probability(mut self, probability: ValueType) -> Self {
+		self.gap_probability = probability;
+		self
+	}
+
+	/// Sets probability of the regime switch before every candle
+	#[must_use]
+	pub const fn switch_probability(mut self, probability: ValueType) -> Self {
+		self.switch_probability = probability;
+		self
+	}
+
+	/// Returns the regime of the last generated candle
+	#[must_use]
+	pub const fn regime(&self) -> Regime {
+		self.regime
+	}
+
+	const fn switch(&mut self) {
+		self.regime = match self.rng.below(3) {
+			0 => Regime::Uptrend,
+			1 => Regime::Downtrend,
+			_ => Regime::Choppy,
+		};
+
+		self.level = self.price;
+	}
+}
+
+impl Default for MarketCandles {
+	fn default() -> Self {
+		Self::new(0)
+	}
+}
+
+impl Iterator for MarketCandles {
+	type Item = Candle;
+
+	fn next(&mut self) -> Option<Self::Item> {
+		if self.rng.chance(self.switch_probability) {
+			self.switch();
+		}
+
+		let volatility = self.volatility;
+		let is_gap = self.rng.chance(self.gap_probability);
+
+		let open = if is_gap {
+			let size = volatility * self.rng.uniform().mul_add(2.0, 3.0);
+			let direction: ValueType = if self.rng.chance(0.5) { 1.0 } else { -1.0 };
+			self.price * direction.mul_add(size, 1.0)
+		} else {
+			self.price
+		};
+
+		let drift = match self.regime {
+			Regime::Uptrend => 0.5 * volatility,
+			Regime::Downtrend => -0.5 * volatility,
+			Regime::Choppy => (self.level / open - 1.0) * 0.2,
+		};
+
+		let change = self.rng.normal().mul_add(volatility, drift).max(-0.5);
+		let close = open * (1.0 + change);
+
+		let high = open.max(close) * self.rng.normal().abs().mul_add(volatility / 2.0, 1.0);
+		let low = open.min(close) / self.rng.normal().abs().mul_add(volatility / 2.0, 1.0);
+
+		let range = (high - low) / open / volatility;
+		let gap_factor = if is_gap { 2.0 } else { 1.0 };
+		let volume = Self::DEFAULT_VOLUME
+			* gap_factor
+			* (1.0 + range)
+			* self.rng.normal().mul_add(0.2, 1.0).max(0.1);
+
+		self.price = close;
+
+		Some(Candle {
+			open,
+			high,
+			low,
+			close,
+			volume,
+		})
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	use super::{MarketCandles, Regime};
+	use crate::core::{ValueType, OHLC};
+	use alloc::vec::Vec;
+
+	#[test]
+	fn test_market_candles() {
+		let mut generator = MarketCandles::new(7).gap_probability(0.05);
+		let mut candles = Vec::new();
+		let mut regimes = Vec::new();
+
+		for _ in 0..5000 {
+			candles.push(generator.next().unwrap());
+			regimes.push(generator.regime());
+		}
+
+		assert!(candles.iter().all(OHLC::validate));
+		assert!(candles.iter().all(|c| c.volume > 0.0));
+
+		for regime in &[Regime::Uptrend, Regime::Downtrend, Regime::Choppy] {
+			assert!(regimes.contains(regime));
+		}
+
+		let gaps = candles
+			.windows(2)
+			.filter(|w| (w[1].open - w[0].close).abs() > 0.0)
+			.count();
+		assert!(gaps > 100 && gaps < 500, "{}", gaps);
+
+		// volume is correlated with the range of the candle
+		let mut by_range: Vec<_> = candles
+			.iter()
+			.map(|c| ((c.high - c.low) / c.open, c.volume))
+			.collect();
+		by_range.sort_by(|a, b| a.0.total_cmp(&b.0));
+		let (narrow, wide) = by_range.split_at(by_range.len() / 2);
+		let mean = |v: &[(ValueType, ValueType)]| {
+			v.iter().map(|x| x.1).sum::<ValueType>() / v.iter().map(|_| 1.0).sum::<ValueType>()
+		};
+		assert!(mean(wide) > mean(narrow));
+
+		// trending regimes move the price in their direction
+		let moves = |regime: Regime| {
+			candles
+				.iter()
+				.zip(&regimes)
+				.filter(|&(_, &r)| r == regime)
+				.map(|(c, _)| c.close / c.open - 1.0)
+				.sum::<ValueType>()
+		};
+		assert!(moves(Regime::Uptrend) > 0.0);
+		assert!(moves(Regime::Downtrend) < 0.0);
+	}
+}
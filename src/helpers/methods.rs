@@ -1,15 +1,19 @@
 #[cfg(feature = "serde")]
-use serde::{Deserialize, Serialize};
+use serde::{ser::Error as _, Deserialize, Deserializer, Serialize, Serializer};
 
 use crate::core::{Error, Method, PeriodType, ValueType};
 use crate::methods::{
 	Derivative, Highest, HighestLowestDelta, Integral, LinReg, Lowest, MeanAbsDev, MedianAbsDev,
-	Momentum, Past, RateOfChange, StDev, CCI, DEMA, DMA, EMA, HMA, RMA, SMA, SMM, SWMA, TEMA, TMA,
-	TRIMA, WMA, WSMA,
+	Momentum, Past, PercentRank, RateOfChange, StDev, Stochastic, CCI, DEMA, DMA, EMA, HMA, RMA,
+	SMA, SMM, SWMA, T3, TEMA, TMA, TRIMA, VWMA, WMA, WSMA, ZLEMA,
 };
+use alloc::boxed::Box;
+use alloc::string::String;
 
-use std::convert::TryFrom;
-use std::str::FromStr;
+use core::any::Any;
+use core::convert::TryFrom;
+use core::fmt;
+use core::str::FromStr;
 /// A shortcut for dynamically (runtime) generated regular methods
 ///
 /// Regular method is a method which has parameters of single [`PeriodType`], input is single [`ValueType`] and output is single [`ValueType`].
@@ -20,8 +24,121 @@ use std::str::FromStr;
 ///
 /// [`ValueType`]: crate::core::ValueType
 /// [`PeriodType`]: crate::core::PeriodType
-pub type RegularMethod =
-	Box<dyn Method<Params = PeriodType, Input = ValueType, Output = ValueType>>;
+pub type RegularMethod = Box<dyn DynRegularMethod>;
+
+/// Object-safe regular [`Method`], which may be cloned behind the [`RegularMethod`] pointer
+///
+/// Implemented for every regular [`Method`], which implements [`Clone`] and [`Send`].
+pub trait DynRegularMethod:
+	Method<Params = PeriodType, Input = ValueType, Output = ValueType> + Send
+{
+	/// Clones the method into a new [`RegularMethod`]
+	fn clone_boxed(&self) -> RegularMethod;
+
+	/// Returns the method as [`Any`], so it may be downcast to its concrete type
+	fn as_any(&self) -> &dyn Any;
+}
+
+impl<M> DynRegularMethod for M
+where
+	M: Method<Params = PeriodType, Input = ValueType, Output = ValueType> + Clone + Send + 'static,
+{
+	fn clone_boxed(&self) -> RegularMethod {
+		Box::new(self.clone())
+	}
+
+	fn as_any(&self) -> &dyn Any {
+		self
+	}
+}
+
+impl Clone for RegularMethod {
+	fn clone(&self) -> Self {
+		(**self).clone_boxed()
+	}
+}
+
+/// Implements `serde` traits for [`RegularMethod`] as an enum of the concrete methods, which are returned by [`method`]
+///
+/// The state is tagged by the name of the concrete method, so it may be restored without knowing the method in advance.
+#[cfg(feature = "serde")]
+macro_rules! serde_regular_method {
+	($($method:ident $(<$param:ty>)?),* $(,)?) => {
+		#[derive(Serialize)]
+		#[allow(clippy::upper_case_acronyms)]
+		enum RegularMethodRef<'a> {
+			$($method(&'a $method $(<$param>)?),)*
+		}
+
+		#[derive(Deserialize)]
+		#[allow(clippy::upper_case_acronyms)]
+		enum RegularMethodState {
+			$($method($method $(<$param>)?),)*
+		}
+
+		/// Serialized as an enum of the concrete methods, which are returned by [`method`]
+		///
+		/// Returns an error for any other [`DynRegularMethod`].
+		impl Serialize for RegularMethod {
+			fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+				let any = (**self).as_any();
+
+				$(
+					if let Some(method) = any.downcast_ref::<$method $(<$param>)?>() {
+						return RegularMethodRef::$method(method).serialize(serializer);
+					}
+				)*
+
+				Err(S::Error::custom(format!(
+					"regular method `{}` cannot be serialized",
+					self.name()
+				)))
+			}
+		}
+
+		impl<'de> Deserialize<'de> for RegularMethod {
+			fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+				Ok(match RegularMethodState::deserialize(deserializer)? {
+					$(RegularMethodState::$method(method) => Box::new(method),)*
+				})
+			}
+		}
+	};
+}
+
+#[cfg(feature = "serde")]
+serde_regular_method!(
+	SMA,
+	WMA,
+	HMA,
+	RMA,
+	EMA,
+	DMA,
+	DEMA,
+	TMA,
+	TEMA,
+	WSMA,
+	SMM,
+	SWMA,
+	LinReg,
+	TRIMA,
+	ZLEMA,
+	T3,
+	Past<ValueType>,
+	Derivative,
+	Integral,
+	MeanAbsDev,
+	MedianAbsDev,
+	StDev,
+	CCI,
+	Momentum,
+	RateOfChange,
+	Highest,
+	Lowest,
+	HighestLowestDelta,
+	Stochastic,
+	PercentRank,
+);
 
 /// Regular methods dictionary
 #[derive(Debug, Clone, Copy, PartialEq)]
@@ -67,6 +184,17 @@ pub enum RegularMethods {
 	/// [Triangular Moving Average](crate::methods::TRIMA)
 	TRIMA,
 
+	/// [Zero Lag Exponential Moving Average](crate::methods::ZLEMA)
+	ZLEMA,
+
+	/// [Tillson T3 Moving Average](crate::methods::T3)
+	T3,
+
+	/// [Volume Weighted Moving Average](crate::methods::VWMA)
+	///
+	/// It is volume-based, so it is available only through [`volume_method`]
+	VWMA,
+
 	/// [Linear regression](crate::methods::LinReg)
 	#[cfg_attr(feature = "serde", serde(rename = "lin_reg"))]
 	LinReg,
@@ -122,6 +250,13 @@ pub enum RegularMethods {
 	/// [HighestLowestDelta](crate::methods::HighestLowestDelta)
 	#[cfg_attr(feature = "serde", serde(rename = "highest_lowest_delta"))]
 	HighestLowestDelta,
+
+	/// [Stochastic](crate::methods::Stochastic)
+	Stochastic,
+
+	/// [Percent Rank](crate::methods::PercentRank)
+	#[cfg_attr(feature = "serde", serde(rename = "percent_rank"))]
+	PercentRank,
 }
 
 impl FromStr for RegularMethods {
@@ -142,6 +277,9 @@ impl FromStr for RegularMethods {
 			"smm" => Ok(Self::SMM),
 			"swma" => Ok(Self::SWMA),
 			"trima" => Ok(Self::TRIMA),
+			"zlema" => Ok(Self::ZLEMA),
+			"t3" => Ok(Self::T3),
+			"vwma" => Ok(Self::VWMA),
 			"lin_reg" | "linreg" => Ok(Self::LinReg),
 
 			"past" | "move" => Ok(Self::Past),
@@ -156,12 +294,54 @@ impl FromStr for RegularMethods {
 			"highest" => Ok(Self::Highest),
 			"lowest" => Ok(Self::Lowest),
 			"highest_lowest_delta" => Ok(Self::HighestLowestDelta),
+			"stochastic" => Ok(Self::Stochastic),
+			"percent_rank" | "percentrank" => Ok(Self::PercentRank),
 
 			_ => Err(format!("Unknown regular method name {}", s)),
 		}
 	}
 }
 
+impl fmt::Display for RegularMethods {
+	fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+		let name = match self {
+			Self::SMA => "sma",
+			Self::WMA => "wma",
+			Self::HMA => "hma",
+			Self::RMA => "rma",
+			Self::EMA => "ema",
+			Self::DMA => "dma",
+			Self::DEMA => "dema",
+			Self::TMA => "tma",
+			Self::TEMA => "tema",
+			Self::WSMA => "wsma",
+			Self::SMM => "smm",
+			Self::SWMA => "swma",
+			Self::TRIMA => "trima",
+			Self::ZLEMA => "zlema",
+			Self::T3 => "t3",
+			Self::VWMA => "vwma",
+			Self::LinReg => "lin_reg",
+			Self::Past | Self::Move => "past",
+			Self::Derivative => "derivative",
+			Self::Integral => "integral",
+			Self::MeanAbsDev => "mean_abs_dev",
+			Self::MedianAbsDev => "median_abs_dev",
+			Self::StDev => "st_dev",
+			Self::CCI => "cci",
+			Self::Momentum | Self::Change => "momentum",
+			Self::RateOfChange | Self::ROC => "rate_of_change",
+			Self::Highest => "highest",
+			Self::Lowest => "lowest",
+			Self::HighestLowestDelta => "highest_lowest_delta",
+			Self::Stochastic => "stochastic",
+			Self::PercentRank => "percent_rank",
+		};
+
+		f.write_str(name)
+	}
+}
+
 impl TryFrom<&str> for RegularMethods {
 	type Error = String;
 
@@ -196,6 +376,8 @@ impl TryFrom<String> for RegularMethods {
 /// * `swma` - [symmetrically weighted moving average](SWMA)
 /// * `lin_reg` - [linear regression moving average](LinReg)
 /// * `trima` - [triangular moving average](TRIMA)
+/// * `zlema` - [zero lag exponential moving average](ZLEMA)
+/// * `t3` - [Tillson T3 moving average](T3)
 /// * `past`, `move` - [moves timeseries forward](Past)
 /// * `derivative` - [derivative](Derivative)
 /// * `mean_abs_dev` - [mead absolute deviation](MeanAbsDev)
@@ -205,6 +387,10 @@ impl TryFrom<String> for RegularMethods {
 /// * `momentum`, `change` - [absolute change of values](Momentum)
 /// * `rate_of_change` - [relative change of values](RateOfChange)
 /// * [`highest`](Highest), [`lowest`](Lowest), [`highest_lowest_delta`](HighestLowestDelta)
+/// * `stochastic` - [raw stochastic %K value](Stochastic)
+/// * `percent_rank` - [percent rank of the value](PercentRank)
+///
+/// Returns [`Error::WrongMethodParameters`] for the volume-based `vwma`. Use [`volume_method`] instead.
 ///
 /// # Examples
 ///
@@ -273,6 +459,9 @@ pub fn method(
 		RegularMethods::SWMA => Ok(Box::new(SWMA::new(length, initial_value)?)),
 		RegularMethods::LinReg => Ok(Box::new(LinReg::new(length, initial_value)?)),
 		RegularMethods::TRIMA => Ok(Box::new(TRIMA::new(length, initial_value)?)),
+		RegularMethods::ZLEMA => Ok(Box::new(ZLEMA::new(length, initial_value)?)),
+		RegularMethods::T3 => Ok(Box::new(T3::new(length, initial_value)?)),
+		RegularMethods::VWMA => Err(Error::WrongMethodParameters),
 
 		RegularMethods::Past | RegularMethods::Move => {
 			Ok(Box::new(Past::new(length, initial_value)?))
@@ -294,5 +483,93 @@ pub fn method(
 		RegularMethods::HighestLowestDelta => {
 			Ok(Box::new(HighestLowestDelta::new(length, initial_value)?))
 		}
+		RegularMethods::Stochastic => Ok(Box::new(Stochastic::new(length, initial_value)?)),
+		RegularMethods::PercentRank => Ok(Box::new(PercentRank::new(length, initial_value)?)),
 	}
 }
+
+/// Volume-aware version of the [`RegularMethod`]
+///
+/// Input is (`value`: [`ValueType`], `volume`: [`ValueType`]).
+/// For [`RegularMethods::VWMA`] it is a [volume weighted moving average](VWMA),
+/// for any other method the `volume` is ignored and the [regular method](method) is applied to the `value`.
+///
+/// So volume-based indicators may offer VWMA smoothing along with all the regular methods.
+///
+/// # Examples
+///
+/// ```
+/// use yata::prelude::*;
+/// use yata::helpers::{volume_method, RegularMethods, VolumeMethod};
+/// use yata::methods::{SMA, VWMA};
+///
+/// let mut vwma = volume_method(RegularMethods::VWMA, 3, (1.0, 10.0)).unwrap();
+/// let mut vwma2 = VWMA::new(3, (1.0, 10.0)).unwrap();
+/// assert_eq!(vwma.next((2.0, 30.0)), vwma2.next((2.0, 30.0)));
+///
+/// let mut sma = VolumeMethod::new((RegularMethods::SMA, 3), (1.0, 10.0)).unwrap();
+/// let mut sma2 = SMA::new(3, 1.0).unwrap();
+/// assert_eq!(sma.next((2.0, 30.0)), sma2.next(2.0));
+/// ```
+#[derive(Debug, Clone)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct VolumeMethod {
+	inner: VolumeMethodInner,
+}
+
+#[derive(Debug, Clone)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+enum VolumeMethodInner {
+	Weighted(VWMA),
+	Regular(RegularMethod),
+}
+
+impl Method for VolumeMethod {
+	type Params = (RegularMethods, PeriodType);
+	type Input = (ValueType, ValueType);
+	type Output = ValueType;
+
+	fn new((method_type, length): Self::Params, value: Self::Input) -> Result<Self, Error> {
+		let inner = match method_type {
+			RegularMethods::VWMA => VolumeMethodInner::Weighted(VWMA::new(length, value)?),
+			method_type => VolumeMethodInner::Regular(method(method_type, length, value.0)?),
+		};
+
+		Ok(Self { inner })
+	}
+
+	#[inline]
+	fn next(&mut self, value: Self::Input) -> Self::Output {
+		match &mut self.inner {
+			VolumeMethodInner::Weighted(vwma) => vwma.next(value),
+			VolumeMethodInner::Regular(method) => method.next(value.0),
+		}
+	}
+
+	fn min_periods(&self) -> PeriodType {
+		match &self.inner {
+			VolumeMethodInner::Weighted(vwma) => vwma.min_periods(),
+			VolumeMethodInner::Regular(method) => method.min_periods(),
+		}
+	}
+
+	fn reset(&mut self, value: Self::Input) -> Result<(), Error> {
+		match &mut self.inner {
+			VolumeMethodInner::Weighted(vwma) => vwma.reset(value),
+			VolumeMethodInner::Regular(method) => method.reset(value.0),
+		}
+	}
+}
+
+/// Returns a [`VolumeMethod`] for timeseries of (`value`, `volume`) by given `method` and window `length`
+///
+/// Besides all the [regular methods](method) it supports [`RegularMethods::VWMA`].
+///
+/// See [`VolumeMethod`] for the details.
+pub fn volume_method(
+	method: RegularMethods,
+	length: PeriodType,
+	initial_value: (ValueType, ValueType),
+) -> Result<VolumeMethod, Error> {
+	VolumeMethod::new((method, length), initial_value)
+}
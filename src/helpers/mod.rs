@@ -2,10 +2,22 @@
 //! Additional helping primitives
 //!
 
+#[cfg(feature = "csv")]
+pub mod csv;
 mod methods;
+#[cfg(not(feature = "std"))]
+use crate::core::Float as _;
 use crate::core::{Candle, ValueType};
 pub use methods::*;
 
+mod market;
+pub use market::{MarketCandles, Regime};
+
+mod rng;
+pub(crate) use rng::Rng;
+
+pub(crate) mod simd;
+
 /// sign is like [f64.signum](https://doc.rust-lang.org/std/primitive.f64.html#method.signum)
 /// except when value == 0.0, then sign returns 0.0
 ///
@@ -67,6 +79,53 @@ pub fn signi(value: ValueType) -> i8 {
 	(value > 0.) as i8 - (value < 0.) as i8
 }
 
+/// Count of independent accumulators used by [dot]
+const DOT_LANES: usize = 8;
+
+/// Calculates dot product of two slices of type [`ValueType`]
+///
+/// Values are processed by chunks of 8 independent accumulators, so the compiler is able to use SIMD instructions for it on the supporting targets.
+/// The tail, which does not fill the whole chunk, is processed by the regular scalar code.
+///
+/// Plain multiplication and addition are used instead of [`mul_add`](f64::mul_add), because the last one is not vectorized on the targets without FMA.
+///
+/// If the slices have different lengths, then the longer one is truncated.
+///
+/// # Examples
+///
+/// ```
+/// use yata::helpers::dot;
+///
+/// assert_eq!(dot(&[1.0, 2.0, 3.0], &[4.0, 5.0, 6.0]), 32.0);
+/// assert_eq!(dot(&[1.0, 2.0, 3.0], &[4.0]), 4.0);
+/// ```
+#[must_use]
+#[allow(clippy::suboptimal_flops)]
+pub fn dot(a: &[ValueType], b: &[ValueType]) -> ValueType {
+	let length = a.len().min(b.len());
+	let (a, b) = (&a[..length], &b[..length]);
+
+	let a_chunks = a.chunks_exact(DOT_LANES);
+	let b_chunks = b.chunks_exact(DOT_LANES);
+	let tail = a_chunks
+		.remainder()
+		.iter()
+		.zip(b_chunks.remainder())
+		.fold(0., |sum, (&x, &y)| sum + x * y);
+
+	let lanes = a_chunks
+		.zip(b_chunks)
+		.fold([0.; DOT_LANES], |mut lanes, (x, y)| {
+			for i in 0..DOT_LANES {
+				lanes[i] += x[i] * y[i];
+			}
+			lanes
+		});
+
+	lanes.iter().sum::<ValueType>() + tail
+}
+
+/// Asserts that two values are equal up to the relative precision of [`ValueType`]
 #[cfg(test)]
 pub fn assert_eq_float(original: ValueType, calculated: ValueType) {
 	const SIGMA: ValueType = if cfg!(feature = "value_type_f32") {
@@ -76,18 +135,26 @@ pub fn assert_eq_float(original: ValueType, calculated: ValueType) {
 	};
 	let diff = original - calculated;
 
+	// `f32` keeps only ~7 significant digits, so values close to zero are compared by the absolute difference
+	let scale = if cfg!(feature = "value_type_f32") {
+		original.abs().max(1.0)
+	} else {
+		original.abs()
+	};
+
 	if original != 0. {
 		assert!(
-			(diff / original).abs() <= SIGMA,
+			(diff / scale).abs() <= SIGMA,
 			"orignial={}, calculated={}, diff={}, relative diff={}",
 			original,
 			calculated,
 			diff,
-			(diff / original).abs(),
+			(diff / scale).abs(),
 		);
 	}
 }
 
+/// Asserts that two values are not equal up to the relative precision of [`ValueType`]
 #[cfg(test)]
 pub fn assert_neq_float(value1: ValueType, value2: ValueType) {
 	const SIGMA: ValueType = if cfg!(feature = "value_type_f32") {
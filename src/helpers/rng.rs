@@ -0,0 +1,53 @@
+use crate::core::ValueType;
+
+/// Simple deterministic xorshift generator, so anything random in the crate is reproducible by its seed
+#[derive(Debug, Clone, Copy)]
+pub struct Rng(u32);
+
+impl Rng {
+	const DEFAULT_SEED: u32 = 0x9E37_79B9;
+
+	/// Creates a new generator. Zero `seed` is replaced by the default one, because xorshift never leaves zero state
+	pub const fn new(seed: u32) -> Self {
+		if seed == 0 {
+			Self(Self::DEFAULT_SEED)
+		} else {
+			Self(seed)
+		}
+	}
+
+	pub const fn next(&mut self) -> u32 {
+		self.0 ^= self.0 << 13;
+		self.0 ^= self.0 >> 17;
+		self.0 ^= self.0 << 5;
+		self.0
+	}
+
+	/// Returns random index in range \[`0`; `len`)
+	pub const fn below(&mut self, len: usize) -> usize {
+		self.next() as usize % len
+	}
+
+	/// Returns random value in range \[`0.0`; `1.0`)
+	pub fn uniform(&mut self) -> ValueType {
+		let [a, b, ..] = self.next().to_le_bytes();
+		ValueType::from(u16::from_le_bytes([a, b])) / 65536.0
+	}
+
+	/// Returns `true` with the `probability`
+	pub fn chance(&mut self, probability: ValueType) -> bool {
+		self.uniform() < probability
+	}
+
+	/// Returns approximately normally distributed value with zero mean and unit deviation
+	pub fn normal(&mut self) -> ValueType {
+		// Irwin-Hall distribution of 12 uniform values has variance 1
+		(0..12).map(|_| self.uniform()).sum::<ValueType>() - 6.0
+	}
+}
+
+impl Default for Rng {
+	fn default() -> Self {
+		Self::new(Self::DEFAULT_SEED)
+	}
+}
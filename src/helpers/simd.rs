@@ -0,0 +1,257 @@
+#![allow(unsafe_code)]
+//! Vectorized kernels for the batch evaluation of the methods
+//!
+//! With `simd` feature enabled on `x86_64` the kernels are evaluated by SSE2 instructions, which are always available there.
+//! Otherwise (and for the tails, which do not fill the whole vector) the scalar code is used.
+//! Kernels give exactly the same results in both cases, because every output is evaluated by the same operations in the same order.
+
+use crate::core::ValueType;
+
+#[cfg(not(feature = "std"))]
+use crate::core::Float as _;
+
+#[cfg(all(feature = "simd", target_arch = "x86_64"))]
+mod arch {
+	use crate::core::ValueType;
+
+	#[cfg(feature = "value_type_f32")]
+	use core::arch::x86_64::{
+		__m128 as Vector, _mm_add_ps as add, _mm_andnot_ps as and_not, _mm_loadu_ps as load,
+		_mm_mul_ps as mul, _mm_set1_ps as splat, _mm_sqrt_ps as sqrt, _mm_storeu_ps as store,
+		_mm_sub_ps as sub,
+	};
+	#[cfg(not(feature = "value_type_f32"))]
+	use core::arch::x86_64::{
+		__m128d as Vector, _mm_add_pd as add, _mm_andnot_pd as and_not, _mm_loadu_pd as load,
+		_mm_mul_pd as mul, _mm_set1_pd as splat, _mm_sqrt_pd as sqrt, _mm_storeu_pd as store,
+		_mm_sub_pd as sub,
+	};
+	use core::mem::size_of;
+
+	pub const LANES: usize = size_of::<Vector>() / size_of::<ValueType>();
+
+	/// Evaluates `out[i] = (a[i] - b[i]) * k` over the whole vectors and returns count of the evaluated values
+	pub fn sub_mul(a: &[ValueType], b: &[ValueType], k: ValueType, out: &mut [ValueType]) -> usize {
+		let length = out.len() / LANES * LANES;
+		assert!(a.len() >= length && b.len() >= length);
+
+		// SAFETY: every pointer is in bounds of its slice, because `i + LANES <= length`
+		unsafe {
+			let k = splat(k);
+			for i in (0..length).step_by(LANES) {
+				let x = sub(load(a.as_ptr().add(i)), load(b.as_ptr().add(i)));
+				store(out.as_mut_ptr().add(i), mul(x, k));
+			}
+		}
+
+		length
+	}
+
+	/// Evaluates `x[i] *= k` over the whole vectors and returns count of the evaluated values
+	pub fn mul_assign(x: &mut [ValueType], k: ValueType) -> usize {
+		let length = x.len() / LANES * LANES;
+
+		// SAFETY: every pointer is in bounds of the slice, because `i + LANES <= length`
+		unsafe {
+			let k = splat(k);
+			for i in (0..length).step_by(LANES) {
+				let ptr = x.as_mut_ptr().add(i);
+				store(ptr, mul(load(ptr), k));
+			}
+		}
+
+		length
+	}
+
+	/// Evaluates `x[i] = (|x[i]| * k).sqrt()` over the whole vectors and returns count of the evaluated values
+	pub fn abs_mul_sqrt(x: &mut [ValueType], k: ValueType) -> usize {
+		let length = x.len() / LANES * LANES;
+
+		// SAFETY: every pointer is in bounds of the slice, because `i + LANES <= length`
+		unsafe {
+			let (k, sign) = (splat(k), splat(-0.));
+			for i in (0..length).step_by(LANES) {
+				let ptr = x.as_mut_ptr().add(i);
+				store(ptr, sqrt(mul(and_not(sign, load(ptr)), k)));
+			}
+		}
+
+		length
+	}
+
+	/// Evaluates convolution of `x` with `weights` over the whole vectors of `out` and returns count of the evaluated values
+	///
+	/// Every vector holds `LANES` consecutive outputs, so the products of every output are summed in the order of the weights.
+	pub fn conv(
+		x: &[ValueType],
+		weights: &[ValueType],
+		k: ValueType,
+		out: &mut [ValueType],
+	) -> usize {
+		let length = out.len() / LANES * LANES;
+		let Some((&first, weights)) = weights.split_first() else {
+			return 0;
+		};
+		assert!(x.len() >= length + weights.len());
+
+		// SAFETY: every pointer is in bounds of its slice, because `i + j + 1 + LANES <= length + weights.len() <= x.len()`
+		unsafe {
+			let k = splat(k);
+			for i in (0..length).step_by(LANES) {
+				let ptr = x.as_ptr().add(i);
+				let sum = weights
+					.iter()
+					.enumerate()
+					.fold(mul(load(ptr), splat(first)), |sum, (j, &weight)| {
+						add(sum, mul(load(ptr.add(j + 1)), splat(weight)))
+					});
+
+				store(out.as_mut_ptr().add(i), mul(sum, k));
+			}
+		}
+
+		length
+	}
+}
+
+/// Evaluates `out[i] = (a[i] - b[i]) * k`
+///
+/// Extra values of the longer slices are ignored.
+pub fn sub_mul(a: &[ValueType], b: &[ValueType], k: ValueType, out: &mut [ValueType]) {
+	let length = a.len().min(b.len()).min(out.len());
+	let (a, b, out) = (&a[..length], &b[..length], &mut out[..length]);
+
+	#[cfg(all(feature = "simd", target_arch = "x86_64"))]
+	let start = arch::sub_mul(a, b, k, out);
+	#[cfg(not(all(feature = "simd", target_arch = "x86_64")))]
+	let start = 0;
+
+	out[start..]
+		.iter_mut()
+		.zip(a[start..].iter().zip(&b[start..]))
+		.for_each(|(out, (&a, &b))| *out = (a - b) * k);
+}
+
+/// Evaluates `x[i] *= k`
+pub fn mul_assign(x: &mut [ValueType], k: ValueType) {
+	#[cfg(all(feature = "simd", target_arch = "x86_64"))]
+	let start = arch::mul_assign(x, k);
+	#[cfg(not(all(feature = "simd", target_arch = "x86_64")))]
+	let start = 0;
+
+	x[start..].iter_mut().for_each(|x| *x *= k);
+}
+
+/// Evaluates `x[i] = (|x[i]| * k).sqrt()`
+pub fn abs_mul_sqrt(x: &mut [ValueType], k: ValueType) {
+	#[cfg(all(feature = "simd", target_arch = "x86_64"))]
+	let start = arch::abs_mul_sqrt(x, k);
+	#[cfg(not(all(feature = "simd", target_arch = "x86_64")))]
+	let start = 0;
+
+	x[start..]
+		.iter_mut()
+		.for_each(|x| *x = (x.abs() * k).sqrt());
+}
+
+/// Evaluates convolution `out[i] = sum(x[i + j] * weights[j]) * k`
+///
+/// `x` should hold at least `out.len() + weights.len() - 1` values, otherwise extra values of `out` are left unchanged.
+/// Products are summed in the order of the `weights`, so the results are exactly the same as of the sequential evaluation.
+pub fn conv(x: &[ValueType], weights: &[ValueType], k: ValueType, out: &mut [ValueType]) {
+	if weights.is_empty() {
+		return;
+	}
+
+	let length = out.len().min((x.len() + 1).saturating_sub(weights.len()));
+	let out = &mut out[..length];
+
+	#[cfg(all(feature = "simd", target_arch = "x86_64"))]
+	let start = arch::conv(x, weights, k, out);
+	#[cfg(not(all(feature = "simd", target_arch = "x86_64")))]
+	let start = 0;
+
+	out[start..]
+		.iter_mut()
+		.zip(x[start..].windows(weights.len()))
+		.for_each(|(out, values)| {
+			*out = values
+				.iter()
+				.zip(weights)
+				.map(|(&x, &weight)| x * weight)
+				.sum::<ValueType>()
+				* k;
+		});
+}
+
+#[cfg(test)]
+#[allow(clippy::suboptimal_flops)]
+mod tests {
+	use super::{abs_mul_sqrt, conv, mul_assign, sub_mul};
+	use crate::core::ValueType;
+	use crate::helpers::RandomCandles;
+	use alloc::vec::Vec;
+
+	#[test]
+	fn test_simd_element_wise() {
+		let src: Vec<ValueType> = RandomCandles::new().take(100).map(|c| c.close).collect();
+
+		for length in 0..20 {
+			let (a, b) = (&src[..length], &src[50..50 + length]);
+
+			let mut out = vec![0.; length];
+			sub_mul(a, b, 0.3, &mut out);
+
+			let mut scaled = a.to_vec();
+			mul_assign(&mut scaled, 0.3);
+
+			for i in 0..length {
+				assert_eq!(out[i].to_bits(), ((a[i] - b[i]) * 0.3).to_bits());
+				assert_eq!(scaled[i].to_bits(), (a[i] * 0.3).to_bits());
+			}
+		}
+	}
+
+	#[test]
+	fn test_simd_abs_mul_sqrt() {
+		let src: Vec<ValueType> = RandomCandles::new().take(100).map(|c| c.close).collect();
+
+		for length in 0..20 {
+			let x: Vec<ValueType> = src[..length].iter().map(|&x| x - 1.0).collect();
+
+			let mut out = x.clone();
+			abs_mul_sqrt(&mut out, 0.3);
+
+			for i in 0..length {
+				assert_eq!(out[i].to_bits(), (x[i].abs() * 0.3).sqrt().to_bits());
+			}
+		}
+	}
+
+	#[test]
+	fn test_simd_conv() {
+		let src: Vec<ValueType> = RandomCandles::new().take(100).map(|c| c.close).collect();
+
+		for weights_length in 1..12 {
+			let weights: Vec<ValueType> = (0..weights_length)
+				.map(|i| (i as ValueType).sin() + 1.5)
+				.collect();
+
+			for length in 0..20 {
+				let x = &src[..length + weights_length - 1];
+
+				let mut out = vec![0.; length];
+				conv(x, &weights, 0.7, &mut out);
+
+				for (i, &y) in out.iter().enumerate() {
+					let sum = x[i..i + weights_length]
+						.iter()
+						.zip(&weights)
+						.map(|(&x, &weight)| x * weight)
+						.sum::<ValueType>();
+					assert_eq!(y.to_bits(), (sum * 0.7).to_bits());
+				}
+			}
+		}
+	}
+}
@@ -0,0 +1,174 @@
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
+
+use crate::core::{Error, Method, PeriodType, ValueType, OHLCV};
+use crate::core::{IndicatorConfig, IndicatorInitializer, IndicatorInstance, IndicatorResult};
+use crate::core::{ParamDescriptor, ParamKind};
+use crate::helpers::{method, RegularMethod, RegularMethods};
+use crate::methods::{Cross, ADI};
+use alloc::string::String;
+use alloc::string::ToString;
+use alloc::vec::Vec;
+use core::ops::Bound::{Excluded, Included};
+
+/// Accumulation/Distribution line
+///
+/// ## Links
+///
+/// * <https://en.wikipedia.org/wiki/Accumulation/distribution_index>
+///
+/// # 2 values
+///
+/// * `A/D` value \(*volume* multiplied by [`CLV`](crate::core::OHLC::clv), accumulated over the `window`\)
+/// * `signal line` value
+///
+/// Range in \(-inf; +inf\)
+///
+/// # 1 signal
+///
+/// When `A/D` crosses `signal line` upwards, then returns full buy signal.
+/// When `A/D` crosses `signal line` downwards, then returns full sell signal.
+/// Otherwise no signal
+#[derive(Debug, Clone, Copy)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct AccumulationDistribution {
+	/// [`ADI`] window size. Default is 0 (windowless, the classic A/D line)
+	///
+	/// Range in \[0; [`PeriodType::MAX`](crate::core::PeriodType)\]
+	pub window: PeriodType,
+	/// Signal line MA period length. Default is `21`.
+	///
+	/// Range in \[1; [`PeriodType::MAX`](crate::core::PeriodType)\)
+	pub period: PeriodType,
+	/// Signal line MA method. Default is [`EMA`](crate::methods::EMA).
+	pub method: RegularMethods,
+}
+
+impl IndicatorConfig for AccumulationDistribution {
+	const NAME: &'static str = "AccumulationDistribution";
+	const VALUE_NAMES: &'static [&'static str] = &["ad", "signal_line"];
+	const SIGNAL_NAMES: &'static [&'static str] = &["signal"];
+
+	fn validate(&self) -> bool {
+		self.period > 0 && self.period < PeriodType::MAX
+	}
+
+	fn set(&mut self, name: &str, value: String) -> Option<Error> {
+		match name {
+			"window" => match value.parse() {
+				Err(_) => return Some(Error::ParameterParse(name.to_string(), value.to_string())),
+				Ok(value) => self.window = value,
+			},
+			"period" => match value.parse() {
+				Err(_) => return Some(Error::ParameterParse(name.to_string(), value.to_string())),
+				Ok(value) => self.period = value,
+			},
+			"method" => match value.parse() {
+				Err(_) => return Some(Error::ParameterParse(name.to_string(), value.to_string())),
+				Ok(value) => self.method = value,
+			},
+
+			_ => {
+				return Some(Error::UnknownParameter(name.to_string()));
+			}
+		};
+
+		None
+	}
+
+	fn parameters(&self) -> Vec<ParamDescriptor> {
+		let default = Self::default();
+
+		vec![
+			ParamDescriptor::new("window", ParamKind::Period, self.window, default.window)
+				.range(Included(0.), Included(PeriodType::MAX as ValueType)),
+			ParamDescriptor::new("period", ParamKind::Period, self.period, default.period)
+				.range(Included(1.), Excluded(PeriodType::MAX as ValueType)),
+			ParamDescriptor::new("method", ParamKind::Method, self.method, default.method),
+		]
+	}
+
+	fn is_volume_based(&self) -> bool {
+		true
+	}
+
+	fn size(&self) -> (u8, u8) {
+		(2, 1)
+	}
+
+	fn min_periods(&self) -> PeriodType {
+		self.period.saturating_add(self.window.saturating_sub(1))
+	}
+}
+
+impl<T: OHLCV> IndicatorInitializer<T> for AccumulationDistribution {
+	type Instance = AccumulationDistributionInstance<T>;
+
+	fn init(self, candle: T) -> Result<Self::Instance, Error>
+	where
+		Self: Sized,
+	{
+		if !self.validate() {
+			return Err(Error::WrongConfig);
+		}
+
+		let cfg = self;
+		let adi = ADI::new(cfg.window, candle)?;
+		let value = adi.get_value();
+
+		Ok(Self::Instance {
+			ma: method(cfg.method, cfg.period, value)?,
+			cross: Cross::new((), (value, value))?,
+			adi,
+			cfg,
+		})
+	}
+}
+
+impl Default for AccumulationDistribution {
+	fn default() -> Self {
+		Self {
+			window: 0,
+			period: 21,
+			method: RegularMethods::EMA,
+		}
+	}
+}
+
+/// Just an alias for [`AccumulationDistribution`]
+pub type ADL = AccumulationDistribution;
+
+/// Accumulation/Distribution line state structure
+#[derive(Debug, Clone)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct AccumulationDistributionInstance<T: OHLCV> {
+	cfg: AccumulationDistribution,
+
+	adi: ADI<T>,
+	ma: RegularMethod,
+	cross: Cross,
+}
+
+impl<T: OHLCV> IndicatorInstance<T> for AccumulationDistributionInstance<T> {
+	type Config = AccumulationDistribution;
+
+	fn config(&self) -> &Self::Config {
+		&self.cfg
+	}
+
+	fn next(&mut self, candle: T) -> IndicatorResult {
+		let ad = self.adi.next(candle);
+		let sigline = self.ma.next(ad);
+		let signal = self.cross.next((ad, sigline));
+
+		IndicatorResult::new(&[ad, sigline], &[signal])
+	}
+
+	fn reset(&mut self, candle: T) -> Result<(), Error> {
+		self.adi.reset(candle)?;
+		let value = self.adi.get_value();
+
+		self.ma.reset(value)?;
+		self.cross.reset((value, value))
+	}
+}
@@ -0,0 +1,294 @@
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
+
+use crate::core::{Action, Error, Method, PeriodType, Source, ValueType, OHLC};
+use crate::core::{IndicatorConfig, IndicatorInitializer, IndicatorInstance, IndicatorResult};
+use crate::core::{ParamDescriptor, ParamKind};
+use crate::helpers::{method, RegularMethod, RegularMethods};
+use crate::methods::Past;
+use alloc::string::String;
+use alloc::string::ToString;
+use alloc::vec::Vec;
+use core::ops::Bound::{Excluded, Included};
+
+/// Williams Alligator
+///
+/// ## Links
+///
+/// * <https://www.investopedia.com/articles/trading/072115/exploring-williams-alligator-indicator.asp>
+///
+/// # 3 values
+///
+/// * `jaw` value
+/// * `teeth` value
+/// * `lips` value
+///
+/// Every line is a moving average of `source` values shifted forward by its own offset.
+///
+/// Range of values is the same as the range of the `source` values.
+///
+/// # 1 signal
+///
+/// When lines become ordered as `lips` > `teeth` > `jaw`, then returns full buy signal.
+/// When lines become ordered as `lips` < `teeth` < `jaw`, then returns full sell signal.
+/// Otherwise no signal
+#[derive(Debug, Clone, Copy)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct Alligator {
+	/// Jaw MA period length. Default is `13`.
+	///
+	/// Range in \[1; [`PeriodType::MAX`](crate::core::PeriodType)\)
+	pub jaw_period: PeriodType,
+	/// Jaw line offset. Default is `8`.
+	///
+	/// Range in \[1; [`PeriodType::MAX`](crate::core::PeriodType)\)
+	pub jaw_offset: PeriodType,
+	/// Teeth MA period length. Default is `8`.
+	///
+	/// Range in \[1; [`PeriodType::MAX`](crate::core::PeriodType)\)
+	pub teeth_period: PeriodType,
+	/// Teeth line offset. Default is `5`.
+	///
+	/// Range in \[1; [`PeriodType::MAX`](crate::core::PeriodType)\)
+	pub teeth_offset: PeriodType,
+	/// Lips MA period length. Default is `5`.
+	///
+	/// Range in \[1; [`PeriodType::MAX`](crate::core::PeriodType)\)
+	pub lips_period: PeriodType,
+	/// Lips line offset. Default is `3`.
+	///
+	/// Range in \[1; [`PeriodType::MAX`](crate::core::PeriodType)\)
+	pub lips_offset: PeriodType,
+	/// MA method. Default is [`RMA`](crate::methods::RMA) \(a.k.a. `SMMA`\).
+	pub method: RegularMethods,
+	/// Source type of values. Default is [`HL2`](crate::core::Source#variant.HL2)
+	pub source: Source,
+}
+
+impl IndicatorConfig for Alligator {
+	const NAME: &'static str = "Alligator";
+	const VALUE_NAMES: &'static [&'static str] = &["jaw", "teeth", "lips"];
+	const SIGNAL_NAMES: &'static [&'static str] = &["signal"];
+
+	fn validate(&self) -> bool {
+		let valid = |p: PeriodType| p > 0 && p < PeriodType::MAX;
+
+		valid(self.jaw_period)
+			&& valid(self.jaw_offset)
+			&& valid(self.teeth_period)
+			&& valid(self.teeth_offset)
+			&& valid(self.lips_period)
+			&& valid(self.lips_offset)
+	}
+
+	fn set(&mut self, name: &str, value: String) -> Option<Error> {
+		match name {
+			"jaw_period" => match value.parse() {
+				Err(_) => return Some(Error::ParameterParse(name.to_string(), value.to_string())),
+				Ok(value) => self.jaw_period = value,
+			},
+			"jaw_offset" => match value.parse() {
+				Err(_) => return Some(Error::ParameterParse(name.to_string(), value.to_string())),
+				Ok(value) => self.jaw_offset = value,
+			},
+			"teeth_period" => match value.parse() {
+				Err(_) => return Some(Error::ParameterParse(name.to_string(), value.to_string())),
+				Ok(value) => self.teeth_period = value,
+			},
+			"teeth_offset" => match value.parse() {
+				Err(_) => return Some(Error::ParameterParse(name.to_string(), value.to_string())),
+				Ok(value) => self.teeth_offset = value,
+			},
+			"lips_period" => match value.parse() {
+				Err(_) => return Some(Error::ParameterParse(name.to_string(), value.to_string())),
+				Ok(value) => self.lips_period = value,
+			},
+			"lips_offset" => match value.parse() {
+				Err(_) => return Some(Error::ParameterParse(name.to_string(), value.to_string())),
+				Ok(value) => self.lips_offset = value,
+			},
+			"method" => match value.parse() {
+				Err(_) => return Some(Error::ParameterParse(name.to_string(), value.to_string())),
+				Ok(value) => self.method = value,
+			},
+			"source" => match value.parse() {
+				Err(_) => return Some(Error::ParameterParse(name.to_string(), value.to_string())),
+				Ok(value) => self.source = value,
+			},
+
+			_ => {
+				return Some(Error::UnknownParameter(name.to_string()));
+			}
+		};
+
+		None
+	}
+
+	fn parameters(&self) -> Vec<ParamDescriptor> {
+		let default = Self::default();
+
+		vec![
+			ParamDescriptor::new(
+				"jaw_period",
+				ParamKind::Period,
+				self.jaw_period,
+				default.jaw_period,
+			)
+			.range(Included(1.), Excluded(PeriodType::MAX as ValueType)),
+			ParamDescriptor::new(
+				"jaw_offset",
+				ParamKind::Period,
+				self.jaw_offset,
+				default.jaw_offset,
+			)
+			.range(Included(1.), Excluded(PeriodType::MAX as ValueType)),
+			ParamDescriptor::new(
+				"teeth_period",
+				ParamKind::Period,
+				self.teeth_period,
+				default.teeth_period,
+			)
+			.range(Included(1.), Excluded(PeriodType::MAX as ValueType)),
+			ParamDescriptor::new(
+				"teeth_offset",
+				ParamKind::Period,
+				self.teeth_offset,
+				default.teeth_offset,
+			)
+			.range(Included(1.), Excluded(PeriodType::MAX as ValueType)),
+			ParamDescriptor::new(
+				"lips_period",
+				ParamKind::Period,
+				self.lips_period,
+				default.lips_period,
+			)
+			.range(Included(1.), Excluded(PeriodType::MAX as ValueType)),
+			ParamDescriptor::new(
+				"lips_offset",
+				ParamKind::Period,
+				self.lips_offset,
+				default.lips_offset,
+			)
+			.range(Included(1.), Excluded(PeriodType::MAX as ValueType)),
+			ParamDescriptor::new("method", ParamKind::Method, self.method, default.method),
+			ParamDescriptor::new("source", ParamKind::Source, self.source, default.source),
+		]
+	}
+
+	fn size(&self) -> (u8, u8) {
+		(3, 1)
+	}
+
+	fn min_periods(&self) -> PeriodType {
+		(self.jaw_period.saturating_add(self.jaw_offset))
+			.max(self.teeth_period.saturating_add(self.teeth_offset))
+			.max(self.lips_period.saturating_add(self.lips_offset))
+	}
+}
+
+impl<T: OHLC> IndicatorInitializer<T> for Alligator {
+	type Instance = AlligatorInstance;
+
+	fn init(self, candle: T) -> Result<Self::Instance, Error>
+	where
+		Self: Sized,
+	{
+		if !self.validate() {
+			return Err(Error::WrongConfig);
+		}
+
+		let cfg = self;
+		let src = candle.source(cfg.source);
+
+		Ok(Self::Instance {
+			jaw_ma: method(cfg.method, cfg.jaw_period, src)?,
+			jaw: Past::new(cfg.jaw_offset, src)?,
+			teeth_ma: method(cfg.method, cfg.teeth_period, src)?,
+			teeth: Past::new(cfg.teeth_offset, src)?,
+			lips_ma: method(cfg.method, cfg.lips_period, src)?,
+			lips: Past::new(cfg.lips_offset, src)?,
+			order: 0,
+			cfg,
+		})
+	}
+}
+
+impl Default for Alligator {
+	fn default() -> Self {
+		Self {
+			jaw_period: 13,
+			jaw_offset: 8,
+			teeth_period: 8,
+			teeth_offset: 5,
+			lips_period: 5,
+			lips_offset: 3,
+			method: RegularMethods::RMA,
+			source: Source::HL2,
+		}
+	}
+}
+
+/// Williams Alligator state structure
+#[derive(Debug, Clone)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct AlligatorInstance {
+	cfg: Alligator,
+
+	jaw_ma: RegularMethod,
+	jaw: Past<ValueType>,
+	teeth_ma: RegularMethod,
+	teeth: Past<ValueType>,
+	lips_ma: RegularMethod,
+	lips: Past<ValueType>,
+	order: i8,
+}
+
+impl AlligatorInstance {
+	/// Calculates next `jaw`, `teeth` and `lips` values over the `src` value
+	pub(crate) fn lines(&mut self, src: ValueType) -> (ValueType, ValueType, ValueType) {
+		let jaw = self.jaw.next(self.jaw_ma.next(src));
+		let teeth = self.teeth.next(self.teeth_ma.next(src));
+		let lips = self.lips.next(self.lips_ma.next(src));
+
+		(jaw, teeth, lips)
+	}
+}
+
+impl<T: OHLC> IndicatorInstance<T> for AlligatorInstance {
+	type Config = Alligator;
+
+	fn config(&self) -> &Self::Config {
+		&self.cfg
+	}
+
+	fn next(&mut self, candle: T) -> IndicatorResult {
+		let (jaw, teeth, lips) = self.lines(candle.source(self.cfg.source));
+
+		let order = if lips > teeth && teeth > jaw {
+			1
+		} else if lips < teeth && teeth < jaw {
+			-1
+		} else {
+			0
+		};
+
+		let signal = (order != self.order) as i8 * order;
+		self.order = order;
+
+		IndicatorResult::new(&[jaw, teeth, lips], &[Action::from(signal)])
+	}
+
+	fn reset(&mut self, candle: T) -> Result<(), Error> {
+		let src = candle.source(self.cfg.source);
+
+		self.jaw_ma.reset(src)?;
+		self.jaw.reset(src)?;
+		self.teeth_ma.reset(src)?;
+		self.teeth.reset(src)?;
+		self.lips_ma.reset(src)?;
+		self.lips.reset(src)?;
+		self.order = 0;
+
+		Ok(())
+	}
+}
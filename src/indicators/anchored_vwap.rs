@@ -0,0 +1,321 @@
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
+
+use crate::core::{Anchor, Error, ExternalAnchor, Method, Source, ValueType, OHLCV};
+use crate::core::{IndicatorConfig, IndicatorInitializer, IndicatorInstance, IndicatorResult};
+use crate::core::{ParamDescriptor, ParamKind};
+use crate::methods::Cross;
+use alloc::string::String;
+use alloc::string::ToString;
+use alloc::sync::Arc;
+use alloc::vec::Vec;
+use core::fmt;
+use core::ops::Bound::{Included, Unbounded};
+
+#[cfg(not(feature = "std"))]
+use crate::core::Float as _;
+
+/// Anchored Volume Weighted Average Price
+///
+/// Accumulation of `VWAP` starts from the first candle and restarts every time the [`ExternalAnchor`] fires.
+/// Anchor may be fired explicitly by calling [`AnchoredVWAPInstance::anchor`] or by the user-supplied condition
+/// set by [`AnchoredVWAPInstance::set_anchor_condition`].
+///
+/// ## Links
+///
+/// * <https://www.tradingview.com/support/solutions/43000652199-anchored-vwap/>
+///
+/// # 3 values
+///
+/// * `VWAP` value
+/// * `upper band` value
+/// * `lower band` value
+///
+/// Range of values is the same as the range of the `source` values.
+///
+/// Bands are placed at `sigma` volume weighted standard deviations from `VWAP`.
+///
+/// # 1 signal
+///
+/// When *close* price crosses `VWAP` upwards, then returns full buy signal.
+/// When *close* price crosses `VWAP` downwards, then returns full sell signal.
+/// Otherwise no signal
+///
+/// # Examples
+///
+/// ```
+/// use yata::prelude::*;
+/// use yata::core::Candle;
+/// use yata::indicators::AnchoredVWAP;
+///
+/// let candle = Candle { open: 10.0, high: 10.0, low: 10.0, close: 10.0, volume: 1.0 };
+/// let mut vwap = AnchoredVWAP::default().init(candle).unwrap();
+///
+/// vwap.next(candle);
+/// vwap.next(Candle { open: 20.0, high: 20.0, low: 20.0, close: 20.0, volume: 1.0 });
+///
+/// // restart accumulation from the next candle
+/// vwap.anchor();
+///
+/// let result = vwap.next(Candle { open: 30.0, high: 30.0, low: 30.0, close: 30.0, volume: 1.0 });
+/// assert_eq!(result.value(0), 30.0);
+/// ```
+#[derive(Debug, Clone, Copy)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct AnchoredVWAP {
+	/// Bands size in standard deviations. Default is `0.0` (no bands).
+	///
+	/// Range in \[0.0; +inf\)
+	pub sigma: ValueType,
+	/// Price source type of values. Default is [`TP`](crate::core::Source#variant.TP)
+	pub source: Source,
+}
+
+impl IndicatorConfig for AnchoredVWAP {
+	const NAME: &'static str = "AnchoredVWAP";
+	const VALUE_NAMES: &'static [&'static str] = &["vwap", "upper", "lower"];
+	const SIGNAL_NAMES: &'static [&'static str] = &["signal"];
+
+	fn validate(&self) -> bool {
+		self.sigma >= 0.
+	}
+
+	fn set(&mut self, name: &str, value: String) -> Option<Error> {
+		match name {
+			"sigma" => match value.parse() {
+				Err(_) => return Some(Error::ParameterParse(name.to_string(), value.to_string())),
+				Ok(value) => self.sigma = value,
+			},
+			"source" => match value.parse() {
+				Err(_) => return Some(Error::ParameterParse(name.to_string(), value.to_string())),
+				Ok(value) => self.source = value,
+			},
+
+			_ => {
+				return Some(Error::UnknownParameter(name.to_string()));
+			}
+		};
+
+		None
+	}
+
+	fn parameters(&self) -> Vec<ParamDescriptor> {
+		let default = Self::default();
+
+		vec![
+			ParamDescriptor::new("sigma", ParamKind::Float, self.sigma, default.sigma)
+				.range(Included(0.0), Unbounded),
+			ParamDescriptor::new("source", ParamKind::Source, self.source, default.source),
+		]
+	}
+
+	fn is_volume_based(&self) -> bool {
+		true
+	}
+
+	fn size(&self) -> (u8, u8) {
+		(3, 1)
+	}
+}
+
+impl<T: OHLCV> IndicatorInitializer<T> for AnchoredVWAP {
+	type Instance = AnchoredVWAPInstance<T>;
+
+	fn init(self, candle: T) -> Result<Self::Instance, Error>
+	where
+		Self: Sized,
+	{
+		if !self.validate() {
+			return Err(Error::WrongConfig);
+		}
+
+		let cfg = self;
+		let src = OHLCV::source(&candle, cfg.source);
+
+		Ok(Self::Instance {
+			mean: 0.,
+			m2: 0.,
+			vol_sum: 0.,
+			anchor: ExternalAnchor::default(),
+			condition: None,
+			cross: Cross::new((), (candle.close(), src))?,
+			cfg,
+		})
+	}
+}
+
+impl Default for AnchoredVWAP {
+	fn default() -> Self {
+		Self {
+			sigma: 0.0,
+			source: Source::TP,
+		}
+	}
+}
+
+/// User-supplied anchor condition for [`AnchoredVWAPInstance`]
+///
+/// Condition is shared between the clones of the instance.
+pub type AnchorCondition<T> = Arc<dyn Fn(&T) -> bool + Send + Sync>;
+
+/// Anchored Volume Weighted Average Price state structure
+///
+/// Anchor condition is not a part of the state snapshot, so it should be set again after the state is restored.
+#[derive(Clone)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct AnchoredVWAPInstance<T: OHLCV> {
+	cfg: AnchoredVWAP,
+
+	mean: ValueType,
+	m2: ValueType,
+	vol_sum: ValueType,
+	anchor: ExternalAnchor,
+	#[cfg_attr(feature = "serde", serde(skip))]
+	condition: Option<AnchorCondition<T>>,
+	cross: Cross,
+}
+
+impl<T: OHLCV> AnchoredVWAPInstance<T> {
+	/// Fires the anchor: accumulation restarts from the next candle
+	pub fn anchor(&mut self) {
+		self.anchor.fire();
+	}
+
+	/// Sets the anchor `condition`, which is checked on every candle.
+	///
+	/// When `condition` returns `true`, accumulation restarts from this candle.
+	pub fn set_anchor_condition<F>(&mut self, condition: F)
+	where
+		F: Fn(&T) -> bool + Send + Sync + 'static,
+	{
+		self.condition = Some(Arc::new(condition));
+	}
+
+	/// Removes the anchor condition
+	pub fn clear_anchor_condition(&mut self) {
+		self.condition = None;
+	}
+}
+
+impl<T: OHLCV> fmt::Debug for AnchoredVWAPInstance<T> {
+	fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+		f.debug_struct("AnchoredVWAPInstance")
+			.field("cfg", &self.cfg)
+			.field("mean", &self.mean)
+			.field("m2", &self.m2)
+			.field("vol_sum", &self.vol_sum)
+			.field("anchor", &self.anchor)
+			.field("condition", &self.condition.is_some())
+			.field("cross", &self.cross)
+			.finish()
+	}
+}
+
+impl<T: OHLCV> IndicatorInstance<T> for AnchoredVWAPInstance<T> {
+	type Config = AnchoredVWAP;
+
+	fn config(&self) -> &Self::Config {
+		&self.cfg
+	}
+
+	fn next(&mut self, candle: T) -> IndicatorResult {
+		if matches!(&self.condition, Some(f) if f(&candle)) {
+			self.anchor.fire();
+		}
+
+		if self.anchor.fires(None) {
+			self.mean = 0.;
+			self.m2 = 0.;
+			self.vol_sum = 0.;
+		}
+
+		let src = OHLCV::source(&candle, self.cfg.source);
+		let volume = candle.volume();
+
+		// weighted Welford's update keeps the variance stable for large prices
+		self.vol_sum += volume;
+
+		let (vwap, st_dev) = if self.vol_sum > 0. {
+			let delta = src - self.mean;
+			self.mean += delta * volume / self.vol_sum;
+			self.m2 = (volume * delta).mul_add(src - self.mean, self.m2);
+
+			(self.mean, (self.m2 / self.vol_sum).max(0.).sqrt())
+		} else {
+			(src, 0.)
+		};
+
+		let upper = st_dev.mul_add(self.cfg.sigma, vwap);
+		let lower = st_dev.mul_add(-self.cfg.sigma, vwap);
+
+		let signal = self.cross.next((candle.close(), vwap));
+
+		IndicatorResult::new(&[vwap, upper, lower], &[signal])
+	}
+
+	/// Resets the **State** keeping the anchor condition
+	fn reset(&mut self, candle: T) -> Result<(), Error> {
+		let src = OHLCV::source(&candle, self.cfg.source);
+
+		self.mean = 0.;
+		self.m2 = 0.;
+		self.vol_sum = 0.;
+		self.anchor.reset();
+		self.cross.reset((candle.close(), src))
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	use super::AnchoredVWAP;
+	use crate::core::{Candle, IndicatorInitializer, IndicatorInstance, ValueType, OHLC};
+	use crate::helpers::{assert_eq_float, RandomCandles};
+	use alloc::vec::Vec;
+
+	#[test]
+	fn test_anchored_vwap_bands() {
+		let candles: Vec<Candle> = RandomCandles::new()
+			.take(100)
+			.map(|x| Candle {
+				volume: x.volume.abs(),
+				..x
+			})
+			.collect();
+
+		let cfg = AnchoredVWAP {
+			sigma: 2.0,
+			..AnchoredVWAP::default()
+		};
+		let mut state = cfg.init(candles[0]).unwrap();
+
+		for (i, &candle) in candles.iter().enumerate() {
+			let result = state.next(candle);
+
+			let (sum, vol_sum) = candles[..=i].iter().fold((0., 0.), |(s, v), x| {
+				(x.tp().mul_add(x.volume, s), v + x.volume)
+			});
+			let vwap = sum / vol_sum;
+			let variance = candles[..=i]
+				.iter()
+				.map(|x| (x.tp() - vwap).powi(2) * x.volume)
+				.sum::<ValueType>()
+				/ vol_sum;
+
+			assert_eq_float(vwap, result.value(0));
+			assert_eq_float(variance.sqrt().mul_add(2.0, vwap), result.value(1));
+		}
+	}
+
+	#[test]
+	fn test_anchored_vwap_reset_keeps_condition() {
+		let candles: Vec<Candle> = RandomCandles::new().take(30).collect();
+
+		let mut state = AnchoredVWAP::default().init(candles[0]).unwrap();
+		state.set_anchor_condition(|_| true);
+		state.reset(candles[0]).unwrap();
+
+		for &candle in &candles {
+			assert_eq_float(candle.tp(), state.next(candle).value(0));
+		}
+	}
+}
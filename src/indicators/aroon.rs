@@ -3,8 +3,13 @@ use serde::{Deserialize, Serialize};
 
 use crate::core::{Error, Method, PeriodType, ValueType, OHLC};
 use crate::core::{IndicatorConfig, IndicatorInitializer, IndicatorInstance, IndicatorResult};
+use crate::core::{ParamDescriptor, ParamKind};
 use crate::methods::{Cross, HighestIndex, LowestIndex};
-use std::marker::PhantomData;
+use alloc::string::String;
+use alloc::string::ToString;
+use alloc::vec::Vec;
+use core::marker::PhantomData;
+use core::ops::Bound::{Excluded, Included};
 
 // https://www.fidelity.com/learning-center/trading-investing/technical-analysis/technical-indicator-guide/aroon-indicator
 // Aroon-Up = [(Period Specified – Periods Since the Highest High within Period Specified) / Period Specified]
@@ -56,6 +61,8 @@ pub struct Aroon {
 
 impl IndicatorConfig for Aroon {
 	const NAME: &'static str = "Aroon";
+	const VALUE_NAMES: &'static [&'static str] = &["aroon_up", "aroon_down"];
+	const SIGNAL_NAMES: &'static [&'static str] = &["cross", "extreme", "zone"];
 
 	fn validate(&self) -> bool {
 		self.signal_zone >= 0.0
@@ -82,13 +89,36 @@ impl IndicatorConfig for Aroon {
 			},
 
 			_ => {
-				return Some(Error::ParameterParse(name.to_string(), value));
+				return Some(Error::UnknownParameter(name.to_string()));
 			}
 		};
 
 		None
 	}
 
+	fn parameters(&self) -> Vec<ParamDescriptor> {
+		let default = Self::default();
+
+		vec![
+			ParamDescriptor::new("period", ParamKind::Period, self.period, default.period)
+				.range(Included(2.), Excluded(PeriodType::MAX as ValueType)),
+			ParamDescriptor::new(
+				"signal_zone",
+				ParamKind::Float,
+				self.signal_zone,
+				default.signal_zone,
+			)
+			.range(Included(0.0), Included(1.0)),
+			ParamDescriptor::new(
+				"over_zone_period",
+				ParamKind::Period,
+				self.over_zone_period,
+				default.over_zone_period,
+			)
+			.range(Included(1.), Excluded(PeriodType::MAX as ValueType)),
+		]
+	}
+
 	fn size(&self) -> (u8, u8) {
 		(2, 3)
 	}
@@ -130,7 +160,8 @@ impl Default for Aroon {
 }
 
 /// Aroon state structure
-#[derive(Debug)]
+#[derive(Debug, Clone)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 pub struct AroonInstance<T: OHLC> {
 	cfg: Aroon,
 	lowest_index: LowestIndex,
@@ -177,4 +208,14 @@ impl<T: OHLC> IndicatorInstance<T> for AroonInstance<T> {
 			&[trend_signal, edge_signal.into(), trend_value.into()],
 		)
 	}
+
+	fn reset(&mut self, candle: T) -> Result<(), Error> {
+		self.lowest_index.reset(candle.low())?;
+		self.highest_index.reset(candle.high())?;
+		self.cross = Cross::default();
+		self.uptrend = 0;
+		self.downtrend = 0;
+
+		Ok(())
+	}
 }
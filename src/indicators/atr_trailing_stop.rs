@@ -0,0 +1,214 @@
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
+
+use crate::core::{Action, Error, PeriodType, Source, ValueType, OHLC};
+use crate::core::{IndicatorConfig, IndicatorInitializer, IndicatorInstance, IndicatorResult};
+use crate::core::{ParamDescriptor, ParamKind};
+use crate::helpers::{method, RegularMethod, RegularMethods};
+use alloc::string::String;
+use alloc::string::ToString;
+use alloc::vec::Vec;
+use core::ops::Bound::{Excluded, Included, Unbounded};
+
+#[cfg(not(feature = "std"))]
+use crate::core::Float as _;
+
+/// ATR Trailing Stop \(volatility stop\)
+///
+/// In long state the stop trails below `source` value at `multiplier` ATRs and may only move up.
+/// In short state the stop trails above `source` value at `multiplier` ATRs and may only move down.
+/// When *close* price crosses the stop, the state flips and the stop jumps to the other side of the price.
+///
+/// # 2 values
+///
+/// * `stop` level value
+///
+/// Range of values is the same as the range of the `source` values.
+///
+/// * `state`
+///
+/// `1.0` for long state, `-1.0` for short state.
+///
+/// # 1 signal
+///
+/// When state flips to long, then returns full buy signal.
+/// When state flips to short, then returns full sell signal.
+/// Otherwise no signal
+#[derive(Debug, Clone, Copy)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct ATRTrailingStop {
+	/// ATR period length. Default is `14`.
+	///
+	/// Range in \[1; [`PeriodType::MAX`](crate::core::PeriodType)\)
+	pub period: PeriodType,
+	/// ATR multiplier. Default is `3.0`.
+	///
+	/// Range in \(0; +inf\)
+	pub multiplier: ValueType,
+	/// ATR smoothing method. Default is [`RMA`](crate::methods::RMA).
+	pub method: RegularMethods,
+	/// Source type of values for the stop calculation. Default is [`Close`](crate::core::Source#variant.Close)
+	pub source: Source,
+}
+
+impl IndicatorConfig for ATRTrailingStop {
+	const NAME: &'static str = "ATRTrailingStop";
+	const VALUE_NAMES: &'static [&'static str] = &["stop", "state"];
+	const SIGNAL_NAMES: &'static [&'static str] = &["signal"];
+
+	fn validate(&self) -> bool {
+		self.period > 0 && self.period < PeriodType::MAX && self.multiplier > 0.
+	}
+
+	fn set(&mut self, name: &str, value: String) -> Option<Error> {
+		match name {
+			"period" => match value.parse() {
+				Err(_) => return Some(Error::ParameterParse(name.to_string(), value.to_string())),
+				Ok(value) => self.period = value,
+			},
+			"multiplier" => match value.parse() {
+				Err(_) => return Some(Error::ParameterParse(name.to_string(), value.to_string())),
+				Ok(value) => self.multiplier = value,
+			},
+			"method" => match value.parse() {
+				Err(_) => return Some(Error::ParameterParse(name.to_string(), value.to_string())),
+				Ok(value) => self.method = value,
+			},
+			"source" => match value.parse() {
+				Err(_) => return Some(Error::ParameterParse(name.to_string(), value.to_string())),
+				Ok(value) => self.source = value,
+			},
+
+			_ => {
+				return Some(Error::UnknownParameter(name.to_string()));
+			}
+		};
+
+		None
+	}
+
+	fn parameters(&self) -> Vec<ParamDescriptor> {
+		let default = Self::default();
+
+		vec![
+			ParamDescriptor::new("period", ParamKind::Period, self.period, default.period)
+				.range(Included(1.), Excluded(PeriodType::MAX as ValueType)),
+			ParamDescriptor::new(
+				"multiplier",
+				ParamKind::Float,
+				self.multiplier,
+				default.multiplier,
+			)
+			.range(Excluded(0.), Unbounded),
+			ParamDescriptor::new("method", ParamKind::Method, self.method, default.method),
+			ParamDescriptor::new("source", ParamKind::Source, self.source, default.source),
+		]
+	}
+
+	fn size(&self) -> (u8, u8) {
+		(2, 1)
+	}
+
+	fn min_periods(&self) -> PeriodType {
+		self.period.saturating_add(1)
+	}
+}
+
+impl<T: OHLC> IndicatorInitializer<T> for ATRTrailingStop {
+	type Instance = ATRTrailingStopInstance<T>;
+
+	fn init(self, candle: T) -> Result<Self::Instance, Error>
+	where
+		Self: Sized,
+	{
+		if !self.validate() {
+			return Err(Error::WrongConfig);
+		}
+
+		let cfg = self;
+		let src = candle.source(cfg.source);
+		let tr = candle.tr(&candle);
+
+		Ok(Self::Instance {
+			atr: method(cfg.method, cfg.period, tr)?,
+			prev_candle: candle,
+			stop: tr.mul_add(-cfg.multiplier, src),
+			state: 1,
+			cfg,
+		})
+	}
+}
+
+impl Default for ATRTrailingStop {
+	fn default() -> Self {
+		Self {
+			period: 14,
+			multiplier: 3.0,
+			method: RegularMethods::RMA,
+			source: Source::Close,
+		}
+	}
+}
+
+/// ATR Trailing Stop state structure
+#[derive(Debug, Clone)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct ATRTrailingStopInstance<T: OHLC> {
+	cfg: ATRTrailingStop,
+
+	atr: RegularMethod,
+	prev_candle: T,
+	stop: ValueType,
+	state: i8,
+}
+
+impl<T: OHLC> IndicatorInstance<T> for ATRTrailingStopInstance<T> {
+	type Config = ATRTrailingStop;
+
+	fn config(&self) -> &Self::Config {
+		&self.cfg
+	}
+
+	fn next(&mut self, candle: T) -> IndicatorResult {
+		let src = candle.source(self.cfg.source);
+		let atr = self.atr.next(candle.tr(&self.prev_candle));
+		self.prev_candle = candle;
+
+		let close = candle.close();
+		let offset = atr * self.cfg.multiplier;
+		let prev_state = self.state;
+
+		if self.state > 0 {
+			if close < self.stop {
+				self.state = -1;
+				self.stop = src + offset;
+			} else {
+				self.stop = self.stop.max(src - offset);
+			}
+		} else if close > self.stop {
+			self.state = 1;
+			self.stop = src - offset;
+		} else {
+			self.stop = self.stop.min(src + offset);
+		}
+
+		let signal = (self.state != prev_state) as i8 * self.state;
+
+		IndicatorResult::new(
+			&[self.stop, self.state as ValueType],
+			&[Action::from(signal)],
+		)
+	}
+
+	fn reset(&mut self, candle: T) -> Result<(), Error> {
+		let src = candle.source(self.cfg.source);
+		let tr = candle.tr(&candle);
+
+		self.atr.reset(tr)?;
+		self.prev_candle = candle;
+		self.stop = tr.mul_add(-self.cfg.multiplier, src);
+		self.state = 1;
+
+		Ok(())
+	}
+}
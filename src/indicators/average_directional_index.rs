@@ -1,9 +1,15 @@
 #[cfg(feature = "serde")]
 use serde::{Deserialize, Serialize};
 
-use crate::core::{Error, PeriodType, ValueType, Window, OHLC};
+use crate::core::{Action, Error, Method, PeriodType, ValueType, Window, OHLC};
 use crate::core::{IndicatorConfig, IndicatorInitializer, IndicatorInstance, IndicatorResult};
+use crate::core::{ParamDescriptor, ParamKind};
 use crate::helpers::{method, RegularMethod, RegularMethods};
+use crate::methods::Cross;
+use alloc::string::String;
+use alloc::string::ToString;
+use alloc::vec::Vec;
+use core::ops::Bound::{Excluded, Included};
 
 /// Average Directional Index
 ///
@@ -27,10 +33,11 @@ use crate::helpers::{method, RegularMethod, RegularMethods};
 ///
 /// Range in \[0.0; 1.0\]
 ///
-/// # 2 signals
+/// # 3 signals
 ///
 /// * `BUY_ALL` when `ADX` over `zone` and `+DI` > `-DI`, `SELL_ALL` when `ADX` over `zone` and `-DI` > `+DI`. Otherwise - no signal.
 /// * Digital signal by difference between `+DI` and `-DI`
+/// * `BUY_ALL` when `+DI` crosses `-DI` upwards and `ADX` is over `zone`, `SELL_ALL` when `+DI` crosses `-DI` downwards and `ADX` is over `zone`. Otherwise - no signal.
 ///
 #[derive(Debug, Clone, Copy)]
 #[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
@@ -61,6 +68,8 @@ pub struct AverageDirectionalIndex {
 
 impl IndicatorConfig for AverageDirectionalIndex {
 	const NAME: &'static str = "AverageDirectionalIndex";
+	const VALUE_NAMES: &'static [&'static str] = &["adx", "plus_di", "minus_di"];
+	const SIGNAL_NAMES: &'static [&'static str] = &["zone", "direction", "cross"];
 
 	fn validate(&self) -> bool {
 		self.di_length >= 1
@@ -104,15 +113,58 @@ impl IndicatorConfig for AverageDirectionalIndex {
 			},
 
 			_ => {
-				return Some(Error::ParameterParse(name.to_string(), value));
+				return Some(Error::UnknownParameter(name.to_string()));
 			}
 		};
 
 		None
 	}
 
+	fn parameters(&self) -> Vec<ParamDescriptor> {
+		let default = Self::default();
+
+		vec![
+			ParamDescriptor::new("method1", ParamKind::Method, self.method1, default.method1),
+			ParamDescriptor::new(
+				"di_length",
+				ParamKind::Period,
+				self.di_length,
+				default.di_length,
+			)
+			.range(
+				Excluded(self.period1 as ValueType),
+				Excluded(PeriodType::MAX as ValueType),
+			),
+			ParamDescriptor::new("method2", ParamKind::Method, self.method2, default.method2),
+			ParamDescriptor::new(
+				"adx_smoothing",
+				ParamKind::Period,
+				self.adx_smoothing,
+				default.adx_smoothing,
+			)
+			.range(
+				Excluded(self.period1 as ValueType),
+				Excluded(PeriodType::MAX as ValueType),
+			),
+			ParamDescriptor::new("period1", ParamKind::Period, self.period1, default.period1)
+				.range(
+					Included(1.),
+					Excluded(self.di_length.min(self.adx_smoothing) as ValueType),
+				),
+			ParamDescriptor::new("zone", ParamKind::Float, self.zone, default.zone)
+				.range(Included(0.0), Included(1.0)),
+		]
+	}
+
 	fn size(&self) -> (u8, u8) {
-		(3, 2)
+		(3, 3)
+	}
+
+	fn min_periods(&self) -> PeriodType {
+		self.period1
+			.saturating_add(self.di_length)
+			.saturating_add(self.adx_smoothing)
+			.saturating_sub(1)
 	}
 }
 
@@ -135,6 +187,7 @@ impl<T: OHLC> IndicatorInitializer<T> for AverageDirectionalIndex {
 			plus_di: method(cfg.method1, cfg.di_length, 0.0)?,
 			minus_di: method(cfg.method1, cfg.di_length, 0.0)?,
 			ma2: method(cfg.method2, cfg.adx_smoothing, 0.0)?,
+			cross: Cross::new((), (0.0, 0.0))?,
 			cfg,
 		})
 	}
@@ -153,7 +206,8 @@ impl Default for AverageDirectionalIndex {
 	}
 }
 
-#[derive(Debug)]
+#[derive(Debug, Clone)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 pub struct AverageDirectionalIndexInstance<T: OHLC> {
 	cfg: AverageDirectionalIndex,
 
@@ -162,6 +216,7 @@ pub struct AverageDirectionalIndexInstance<T: OHLC> {
 	plus_di: RegularMethod,
 	minus_di: RegularMethod,
 	ma2: RegularMethod,
+	cross: Cross,
 }
 
 impl<T: OHLC> AverageDirectionalIndexInstance<T> {
@@ -209,8 +264,24 @@ impl<T: OHLC> IndicatorInstance<T> for AverageDirectionalIndexInstance<T> {
 		let signal1 = (adx > self.cfg.zone) as i8 * ((plus > minus) as i8 - (plus < minus) as i8);
 		let signal2 = plus - minus;
 
+		let cross = self.cross.next((plus, minus));
+		let signal3 = if adx > self.cfg.zone {
+			cross
+		} else {
+			Action::None
+		};
+
 		let values = [adx, plus, minus];
 
-		IndicatorResult::new(&values, &[signal1.into(), signal2.into()])
+		IndicatorResult::new(&values, &[signal1.into(), signal2.into(), signal3])
+	}
+
+	fn reset(&mut self, candle: T) -> Result<(), Error> {
+		self.window.fill(candle);
+		self.tr_ma.reset(candle.tr(&candle))?;
+		self.plus_di.reset(0.0)?;
+		self.minus_di.reset(0.0)?;
+		self.ma2.reset(0.0)?;
+		self.cross.reset((0.0, 0.0))
 	}
 }
@@ -1,10 +1,15 @@
 #[cfg(feature = "serde")]
 use serde::{Deserialize, Serialize};
 
-use crate::core::{Error, Method, PeriodType, Source, OHLC};
+use crate::core::{Error, Method, PeriodType, Source, ValueType, OHLC};
 use crate::core::{IndicatorConfig, IndicatorInitializer, IndicatorInstance, IndicatorResult};
+use crate::core::{ParamDescriptor, ParamKind};
 use crate::helpers::{method, RegularMethod, RegularMethods};
 use crate::methods::{Cross, ReverseSignal};
+use alloc::string::String;
+use alloc::string::ToString;
+use alloc::vec::Vec;
+use core::ops::Bound::{Excluded, Included};
 
 /// Awesome Oscillator
 ///
@@ -55,6 +60,8 @@ pub struct AwesomeOscillator {
 
 impl IndicatorConfig for AwesomeOscillator {
 	const NAME: &'static str = "AwesomeOscillator";
+	const VALUE_NAMES: &'static [&'static str] = &["value"];
+	const SIGNAL_NAMES: &'static [&'static str] = &["twin_peaks", "zero_cross"];
 
 	fn validate(&self) -> bool {
 		self.period1 > 2
@@ -96,15 +103,50 @@ impl IndicatorConfig for AwesomeOscillator {
 				Err(_) => return Some(Error::ParameterParse(name.to_string(), value.to_string())),
 				Ok(value) => self.right = value,
 			},
+			"conseq_peaks" => match value.parse() {
+				Err(_) => return Some(Error::ParameterParse(name.to_string(), value.to_string())),
+				Ok(value) => self.conseq_peaks = value,
+			},
 
 			_ => {
-				return Some(Error::ParameterParse(name.to_string(), value));
+				return Some(Error::UnknownParameter(name.to_string()));
 			}
 		};
 
 		None
 	}
 
+	fn parameters(&self) -> Vec<ParamDescriptor> {
+		let default = Self::default();
+
+		vec![
+			ParamDescriptor::new("period1", ParamKind::Period, self.period1, default.period1)
+				.range(
+					Excluded(self.period2 as ValueType),
+					Excluded(PeriodType::MAX as ValueType),
+				),
+			ParamDescriptor::new("period2", ParamKind::Period, self.period2, default.period2)
+				.range(Excluded(1.), Excluded(self.period1 as ValueType)),
+			ParamDescriptor::new("method", ParamKind::Method, self.method, default.method),
+			ParamDescriptor::new("source", ParamKind::Source, self.source, default.source),
+			ParamDescriptor::new("left", ParamKind::Period, self.left, default.left).range(
+				Included(1.),
+				Excluded((PeriodType::MAX - self.right) as ValueType),
+			),
+			ParamDescriptor::new("right", ParamKind::Period, self.right, default.right).range(
+				Included(1.),
+				Excluded((PeriodType::MAX - self.left) as ValueType),
+			),
+			ParamDescriptor::new(
+				"conseq_peaks",
+				ParamKind::Integer,
+				self.conseq_peaks,
+				default.conseq_peaks,
+			)
+			.range(Included(1.), Included(PeriodType::MAX as ValueType)),
+		]
+	}
+
 	fn size(&self) -> (u8, u8) {
 		(1, 2)
 	}
@@ -150,7 +192,8 @@ impl Default for AwesomeOscillator {
 	}
 }
 
-#[derive(Debug)]
+#[derive(Debug, Clone)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 pub struct AwesomeOscillatorInstance {
 	cfg: AwesomeOscillator,
 
@@ -195,4 +238,17 @@ impl<T: OHLC> IndicatorInstance<T> for AwesomeOscillatorInstance {
 
 		IndicatorResult::new(&values, &signals)
 	}
+
+	fn reset(&mut self, candle: T) -> Result<(), Error> {
+		let src = candle.source(self.cfg.source);
+
+		self.ma1.reset(src)?;
+		self.ma2.reset(src)?;
+		self.cross_over = Cross::default();
+		self.reverse.reset(0.0)?;
+		self.low_peaks = 0;
+		self.high_peaks = 0;
+
+		Ok(())
+	}
 }
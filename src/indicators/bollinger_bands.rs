@@ -3,7 +3,15 @@ use serde::{Deserialize, Serialize};
 
 use crate::core::{Action, Error, Method, PeriodType, Source, ValueType, OHLC};
 use crate::core::{IndicatorConfig, IndicatorInitializer, IndicatorInstance, IndicatorResult};
-use crate::methods::{StDev, SMA};
+use crate::core::{ParamDescriptor, ParamKind};
+use crate::methods::{Lowest, StDev, SMA};
+use alloc::string::String;
+use alloc::string::ToString;
+use alloc::vec::Vec;
+use core::ops::Bound::{Excluded, Included, Unbounded};
+
+#[cfg(not(feature = "std"))]
+use crate::core::Float as _;
 
 /// Bollinget Bands
 ///
@@ -11,17 +19,36 @@ use crate::methods::{StDev, SMA};
 ///
 /// * <https://en.wikipedia.org/wiki/Bollinger_Bands>
 ///
-/// # 3 values
+/// # 5 values
 ///
 /// * `upper bound` \(range of values is the same as range of the `source` values\)
-/// * `source` value
+/// * `middle` line value \(range of values is the same as range of the `source` values\)
 /// * `lower bound` \(range of values is the same as range of the `source` values\)
+/// * `%B` value
+///
+/// Relative position of the `source` value between `lower bound` \(`0.0`\) and `upper bound` \(`1.0`\).
+///
+/// Range in \(-inf; +inf\)
+///
+/// * `bandwidth` value
+///
+/// Distance between `upper bound` and `lower bound` relative to the `middle` line.
+///
+/// Range in \[0.0; +inf\)
+///
+/// # 2 signals
 ///
-/// # 1 digital signal
+/// * Position signal
 ///
 /// When `source` value goes above the `upper bound`, then returns full buy signal.
 /// When `source` value goes under the `lower bound`, then returns full sell signal.
 /// Otherwise returns signal according to relative position of the `source` value based on `upper bound` and `lower bound` values.
+///
+/// * Squeeze signal
+///
+/// When `bandwidth` hits its lowest value over the last `squeeze_period` candles,
+/// then returns full buy signal if `source` value is above the `middle` line or full sell signal otherwise.
+/// Otherwise no signal
 #[derive(Debug, Clone, Copy)]
 #[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 pub struct BollingerBands {
@@ -33,15 +60,26 @@ pub struct BollingerBands {
 	///
 	/// Range in \(0.0; +inf\)
 	pub sigma: ValueType,
+	/// Bandwidth lowest value lookup period length for squeeze signal. Default is `125`.
+	///
+	/// Range in \[1; [`PeriodType::MAX`](crate::core::PeriodType)\)
+	pub squeeze_period: PeriodType,
 	/// Source type of values. Default is [`Close`](crate::core::Source#variant.Close)
 	pub source: Source,
 }
 
 impl IndicatorConfig for BollingerBands {
 	const NAME: &'static str = "BollingerBands";
+	const VALUE_NAMES: &'static [&'static str] =
+		&["upper", "middle", "lower", "percent_b", "bandwidth"];
+	const SIGNAL_NAMES: &'static [&'static str] = &["position", "squeeze"];
 
 	fn validate(&self) -> bool {
-		self.sigma > 0.0 && self.avg_size > 2 && self.avg_size < PeriodType::MAX
+		self.sigma > 0.0
+			&& self.avg_size > 2
+			&& self.avg_size < PeriodType::MAX
+			&& self.squeeze_period > 0
+			&& self.squeeze_period < PeriodType::MAX
 	}
 
 	fn set(&mut self, name: &str, value: String) -> Option<Error> {
@@ -54,21 +92,53 @@ impl IndicatorConfig for BollingerBands {
 				Err(_) => return Some(Error::ParameterParse(name.to_string(), value.to_string())),
 				Ok(value) => self.sigma = value,
 			},
+			"squeeze_period" => match value.parse() {
+				Err(_) => return Some(Error::ParameterParse(name.to_string(), value.to_string())),
+				Ok(value) => self.squeeze_period = value,
+			},
 			"source" => match value.parse() {
 				Err(_) => return Some(Error::ParameterParse(name.to_string(), value.to_string())),
 				Ok(value) => self.source = value,
 			},
 
 			_ => {
-				return Some(Error::ParameterParse(name.to_string(), value));
+				return Some(Error::UnknownParameter(name.to_string()));
 			}
 		};
 
 		None
 	}
 
+	fn parameters(&self) -> Vec<ParamDescriptor> {
+		let default = Self::default();
+
+		vec![
+			ParamDescriptor::new(
+				"avg_size",
+				ParamKind::Period,
+				self.avg_size,
+				default.avg_size,
+			)
+			.range(Included(3.), Excluded(PeriodType::MAX as ValueType)),
+			ParamDescriptor::new("sigma", ParamKind::Float, self.sigma, default.sigma)
+				.range(Excluded(0.0), Unbounded),
+			ParamDescriptor::new(
+				"squeeze_period",
+				ParamKind::Period,
+				self.squeeze_period,
+				default.squeeze_period,
+			)
+			.range(Included(1.), Excluded(PeriodType::MAX as ValueType)),
+			ParamDescriptor::new("source", ParamKind::Source, self.source, default.source),
+		]
+	}
+
 	fn size(&self) -> (u8, u8) {
-		(3, 1)
+		(5, 2)
+	}
+
+	fn min_periods(&self) -> PeriodType {
+		self.avg_size
 	}
 }
 
@@ -88,6 +158,7 @@ impl<T: OHLC> IndicatorInitializer<T> for BollingerBands {
 		Ok(Self::Instance {
 			ma: SMA::new(cfg.avg_size, src)?,
 			st_dev: StDev::new(cfg.avg_size, src)?,
+			lowest: Lowest::new(cfg.squeeze_period, 0.)?,
 			cfg,
 		})
 	}
@@ -98,17 +169,20 @@ impl Default for BollingerBands {
 		Self {
 			avg_size: 20,
 			sigma: 2.0,
+			squeeze_period: 125,
 			source: Source::Close,
 		}
 	}
 }
 
-#[derive(Debug)]
+#[derive(Debug, Clone)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 pub struct BollingerBandsInstance {
 	cfg: BollingerBands,
 
 	ma: SMA,
 	st_dev: StDev,
+	lowest: Lowest,
 }
 
 impl<T: OHLC> IndicatorInstance<T> for BollingerBandsInstance {
@@ -127,16 +201,38 @@ impl<T: OHLC> IndicatorInstance<T> for BollingerBandsInstance {
 		let upper = sq_error.mul_add(self.cfg.sigma, middle);
 		let lower = middle - sq_error * self.cfg.sigma;
 
-		let values = [upper, middle, lower];
-
 		let range = upper - lower;
-		let relative = if range == 0.0 {
+		let percent_b = if range > 0.0 {
 			(source - lower) / range
 		} else {
+			0.5
+		};
+		let bandwidth = if middle == 0.0 {
 			0.0
+		} else {
+			range / middle.abs()
 		};
 
-		let signals = [Action::from(relative * 2.0 - 1.0)];
+		let values = [upper, middle, lower, percent_b, bandwidth];
+
+		let squeeze = range > 0.0 && bandwidth <= self.lowest.next(bandwidth);
+		let squeeze_signal = if !squeeze {
+			Action::None
+		} else if source > middle {
+			Action::BUY_ALL
+		} else {
+			Action::SELL_ALL
+		};
+
+		let signals = [Action::from(percent_b * 2.0 - 1.0), squeeze_signal];
 		IndicatorResult::new(&values, &signals)
 	}
+
+	fn reset(&mut self, candle: T) -> Result<(), Error> {
+		let src = T::source(&candle, self.cfg.source);
+
+		self.ma.reset(src)?;
+		self.st_dev.reset(src)?;
+		self.lowest.reset(0.)
+	}
 }
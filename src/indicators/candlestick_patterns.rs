@@ -0,0 +1,336 @@
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
+
+use crate::core::{Action, Error, Method, PeriodType, ValueType, Window, OHLC};
+use crate::core::{IndicatorConfig, IndicatorInitializer, IndicatorInstance, IndicatorResult};
+use crate::core::{ParamDescriptor, ParamKind};
+use crate::methods::Change;
+use alloc::string::String;
+use alloc::string::ToString;
+use alloc::vec::Vec;
+use core::ops::Bound::{Excluded, Included};
+
+/// Candlestick patterns recognition
+///
+/// Recognizes classic candlestick patterns over the last 3 candles.
+/// Every pattern may be switched off in config, then its signal is always empty.
+///
+/// Direction of the single candle patterns depends on the preceding trend,
+/// which is the sign of the *close* price change over `trend_period` candles before the current one.
+///
+/// ## Links
+///
+/// * <https://en.wikipedia.org/wiki/Candlestick_pattern>
+///
+/// # 5 signals
+///
+/// * Doji
+///
+/// Candle body is not bigger than `doji_ratio` of the candle range.
+/// Returns full sell signal after uptrend and full buy signal after downtrend.
+/// Otherwise no signal
+///
+/// * Hammer / hanging man
+///
+/// Lower shadow is at least two times longer than the body, upper shadow is not longer than the body.
+/// Returns full buy signal after downtrend \(hammer\) and full sell signal after uptrend \(hanging man\).
+/// Otherwise no signal
+///
+/// * Engulfing
+///
+/// Candle body engulfs the opposite colored body of the previous candle.
+/// Returns full buy signal for the bullish engulfing and full sell signal for the bearish engulfing.
+/// Otherwise no signal
+///
+/// * Harami
+///
+/// Candle body is inside the opposite colored body of the previous candle.
+/// Returns full buy signal for the bullish harami and full sell signal for the bearish harami.
+/// Otherwise no signal
+///
+/// * Morning star / evening star
+///
+/// Long candle, then the candle with the body not bigger than `star_ratio` of the first candle body,
+/// then the opposite colored candle closing beyond the middle of the first candle body.
+/// Returns full buy signal for the morning star and full sell signal for the evening star.
+/// Otherwise no signal
+#[derive(Debug, Clone, Copy)]
+#[allow(clippy::struct_excessive_bools)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct CandlestickPatterns {
+	/// Recognize doji. Default is `true`.
+	pub doji: bool,
+	/// Recognize hammer and hanging man. Default is `true`.
+	pub hammer: bool,
+	/// Recognize engulfing. Default is `true`.
+	pub engulfing: bool,
+	/// Recognize harami. Default is `true`.
+	pub harami: bool,
+	/// Recognize morning star and evening star. Default is `true`.
+	pub star: bool,
+	/// Maximum body size of doji relative to the candle range. Default is `0.1`.
+	///
+	/// Range in \(0.0; 1.0\)
+	pub doji_ratio: ValueType,
+	/// Maximum body size of the star candle relative to the first candle body. Default is `0.3`.
+	///
+	/// Range in \(0.0; 1.0\)
+	pub star_ratio: ValueType,
+	/// Preceding trend period length. Default is `10`.
+	///
+	/// Range in \[1; [`PeriodType::MAX`](crate::core::PeriodType)\)
+	pub trend_period: PeriodType,
+}
+
+impl IndicatorConfig for CandlestickPatterns {
+	const NAME: &'static str = "CandlestickPatterns";
+	const SIGNAL_NAMES: &'static [&'static str] =
+		&["doji", "hammer", "engulfing", "harami", "star"];
+
+	fn validate(&self) -> bool {
+		self.doji_ratio > 0.
+			&& self.doji_ratio < 1.
+			&& self.star_ratio > 0.
+			&& self.star_ratio < 1.
+			&& self.trend_period > 0
+			&& self.trend_period < PeriodType::MAX
+	}
+
+	fn set(&mut self, name: &str, value: String) -> Option<Error> {
+		match name {
+			"doji" => match value.parse() {
+				Err(_) => return Some(Error::ParameterParse(name.to_string(), value.to_string())),
+				Ok(value) => self.doji = value,
+			},
+			"hammer" => match value.parse() {
+				Err(_) => return Some(Error::ParameterParse(name.to_string(), value.to_string())),
+				Ok(value) => self.hammer = value,
+			},
+			"engulfing" => match value.parse() {
+				Err(_) => return Some(Error::ParameterParse(name.to_string(), value.to_string())),
+				Ok(value) => self.engulfing = value,
+			},
+			"harami" => match value.parse() {
+				Err(_) => return Some(Error::ParameterParse(name.to_string(), value.to_string())),
+				Ok(value) => self.harami = value,
+			},
+			"star" => match value.parse() {
+				Err(_) => return Some(Error::ParameterParse(name.to_string(), value.to_string())),
+				Ok(value) => self.star = value,
+			},
+			"doji_ratio" => match value.parse() {
+				Err(_) => return Some(Error::ParameterParse(name.to_string(), value.to_string())),
+				Ok(value) => self.doji_ratio = value,
+			},
+			"star_ratio" => match value.parse() {
+				Err(_) => return Some(Error::ParameterParse(name.to_string(), value.to_string())),
+				Ok(value) => self.star_ratio = value,
+			},
+			"trend_period" => match value.parse() {
+				Err(_) => return Some(Error::ParameterParse(name.to_string(), value.to_string())),
+				Ok(value) => self.trend_period = value,
+			},
+
+			_ => {
+				return Some(Error::UnknownParameter(name.to_string()));
+			}
+		};
+
+		None
+	}
+
+	fn parameters(&self) -> Vec<ParamDescriptor> {
+		let default = Self::default();
+
+		vec![
+			ParamDescriptor::new("doji", ParamKind::Bool, self.doji, default.doji),
+			ParamDescriptor::new("hammer", ParamKind::Bool, self.hammer, default.hammer),
+			ParamDescriptor::new(
+				"engulfing",
+				ParamKind::Bool,
+				self.engulfing,
+				default.engulfing,
+			),
+			ParamDescriptor::new("harami", ParamKind::Bool, self.harami, default.harami),
+			ParamDescriptor::new("star", ParamKind::Bool, self.star, default.star),
+			ParamDescriptor::new(
+				"doji_ratio",
+				ParamKind::Float,
+				self.doji_ratio,
+				default.doji_ratio,
+			)
+			.range(Excluded(0.0), Excluded(1.0)),
+			ParamDescriptor::new(
+				"star_ratio",
+				ParamKind::Float,
+				self.star_ratio,
+				default.star_ratio,
+			)
+			.range(Excluded(0.0), Excluded(1.0)),
+			ParamDescriptor::new(
+				"trend_period",
+				ParamKind::Period,
+				self.trend_period,
+				default.trend_period,
+			)
+			.range(Included(1.), Excluded(PeriodType::MAX as ValueType)),
+		]
+	}
+
+	fn size(&self) -> (u8, u8) {
+		(0, 5)
+	}
+}
+
+impl<T: OHLC> IndicatorInitializer<T> for CandlestickPatterns {
+	type Instance = CandlestickPatternsInstance<T>;
+
+	fn init(self, candle: T) -> Result<Self::Instance, Error>
+	where
+		Self: Sized,
+	{
+		if !self.validate() {
+			return Err(Error::WrongConfig);
+		}
+
+		let cfg = self;
+		Ok(Self::Instance {
+			window: Window::new(2, candle),
+			change: Change::new(cfg.trend_period, candle.close())?,
+			trend: 0,
+			cfg,
+		})
+	}
+}
+
+impl Default for CandlestickPatterns {
+	fn default() -> Self {
+		Self {
+			doji: true,
+			hammer: true,
+			engulfing: true,
+			harami: true,
+			star: true,
+			doji_ratio: 0.1,
+			star_ratio: 0.3,
+			trend_period: 10,
+		}
+	}
+}
+
+/// Candlestick patterns recognition state structure
+#[derive(Debug, Clone)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct CandlestickPatternsInstance<T: OHLC> {
+	cfg: CandlestickPatterns,
+
+	window: Window<T>,
+	change: Change,
+	trend: i8,
+}
+
+/// Returns candle color: `1` for bullish, `-1` for bearish and `0` for neutral candle
+fn color<T: OHLC>(candle: &T) -> i8 {
+	(candle.close() > candle.open()) as i8 - (candle.close() < candle.open()) as i8
+}
+
+fn body<T: OHLC>(candle: &T) -> ValueType {
+	(candle.close() - candle.open()).abs()
+}
+
+fn body_top<T: OHLC>(candle: &T) -> ValueType {
+	candle.open().max(candle.close())
+}
+
+fn body_bottom<T: OHLC>(candle: &T) -> ValueType {
+	candle.open().min(candle.close())
+}
+
+impl<T: OHLC> CandlestickPatternsInstance<T> {
+	fn doji(&self, candle: &T) -> i8 {
+		let range = candle.high() - candle.low();
+
+		(range > 0. && body(candle) <= self.cfg.doji_ratio * range) as i8 * -self.trend
+	}
+
+	fn hammer(&self, candle: &T) -> i8 {
+		let body = body(candle);
+		let lower_shadow = body_bottom(candle) - candle.low();
+		let upper_shadow = candle.high() - body_top(candle);
+
+		(lower_shadow > 0. && lower_shadow >= 2. * body && upper_shadow <= body) as i8 * -self.trend
+	}
+
+	fn engulfing(prev: &T, candle: &T) -> i8 {
+		let direction = color(candle);
+		let is_engulfing = direction == -color(prev)
+			&& body_top(candle) >= body_top(prev)
+			&& body_bottom(candle) <= body_bottom(prev)
+			&& body(candle) > body(prev);
+
+		is_engulfing as i8 * direction
+	}
+
+	fn harami(prev: &T, candle: &T) -> i8 {
+		let direction = color(candle);
+		let is_harami = direction == -color(prev)
+			&& body_top(candle) <= body_top(prev)
+			&& body_bottom(candle) >= body_bottom(prev)
+			&& body(candle) < body(prev);
+
+		is_harami as i8 * direction
+	}
+
+	fn star(&self, first: &T, second: &T, candle: &T) -> i8 {
+		let direction = color(candle);
+		let middle = (first.open() + first.close()) * 0.5;
+		let is_star = direction == -color(first)
+			&& body(second) <= self.cfg.star_ratio * body(first)
+			&& (candle.close() - middle) * ValueType::from(direction) > 0.;
+
+		is_star as i8 * direction
+	}
+}
+
+impl<T: OHLC> IndicatorInstance<T> for CandlestickPatternsInstance<T> {
+	type Config = CandlestickPatterns;
+
+	fn config(&self) -> &Self::Config {
+		&self.cfg
+	}
+
+	fn next(&mut self, candle: T) -> IndicatorResult {
+		let first = self.window[0];
+		let prev = self.window[1];
+
+		let signal = |enabled: bool, value: i8| {
+			if enabled {
+				Action::from(value)
+			} else {
+				Action::None
+			}
+		};
+
+		let signals = [
+			signal(self.cfg.doji, self.doji(&candle)),
+			signal(self.cfg.hammer, self.hammer(&candle)),
+			signal(self.cfg.engulfing, Self::engulfing(&prev, &candle)),
+			signal(self.cfg.harami, Self::harami(&prev, &candle)),
+			signal(self.cfg.star, self.star(&first, &prev, &candle)),
+		];
+
+		self.window.push(candle);
+		let change = self.change.next(candle.close());
+		self.trend = (change > 0.) as i8 - (change < 0.) as i8;
+
+		IndicatorResult::new(&[], &signals)
+	}
+
+	fn reset(&mut self, candle: T) -> Result<(), Error> {
+		self.window.fill(candle);
+		self.change.reset(candle.close())?;
+		self.trend = 0;
+
+		Ok(())
+	}
+}
@@ -0,0 +1,186 @@
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
+
+use crate::core::{Error, Method, PeriodType, Source, ValueType, Window, OHLC};
+use crate::core::{IndicatorConfig, IndicatorInitializer, IndicatorInstance, IndicatorResult};
+use crate::core::{ParamDescriptor, ParamKind};
+use crate::methods::Cross;
+use alloc::string::String;
+use alloc::string::ToString;
+use alloc::vec::Vec;
+use core::ops::Bound::{Excluded, Included};
+
+#[cfg(not(feature = "std"))]
+use crate::core::Float as _;
+
+/// Ehlers Center of Gravity oscillator
+///
+/// `CG` = −Σ\(`i` + 1\)·`price[i]` / Σ`price[i]` + \(`period` + 1\) / 2, where `i` = 0 is the most recent value.
+///
+/// The constant term centers oscillator around zero.
+///
+/// # 2 values
+///
+/// * `CG` value
+/// * `trigger` value \(`CG` delayed by 1 candle\)
+///
+/// Range in \(-`period` / 2; `period` / 2\) for positive `source` values
+///
+/// # 1 signal
+///
+/// When `CG` crosses `trigger` upwards, then returns full buy signal.
+/// When `CG` crosses `trigger` downwards, then returns full sell signal.
+/// Otherwise no signal
+#[derive(Debug, Clone, Copy)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct CenterOfGravity {
+	/// Window length. Default is `10`.
+	///
+	/// Range in \[2; [`PeriodType::MAX`](crate::core::PeriodType)\)
+	pub period: PeriodType,
+	/// Source type of values. Default is [`HL2`](crate::core::Source#variant.HL2)
+	pub source: Source,
+}
+
+impl IndicatorConfig for CenterOfGravity {
+	const NAME: &'static str = "CenterOfGravity";
+	const VALUE_NAMES: &'static [&'static str] = &["cg", "trigger"];
+	const SIGNAL_NAMES: &'static [&'static str] = &["signal"];
+
+	fn validate(&self) -> bool {
+		self.period > 1 && self.period < PeriodType::MAX
+	}
+
+	fn set(&mut self, name: &str, value: String) -> Option<Error> {
+		match name {
+			"period" => match value.parse() {
+				Err(_) => return Some(Error::ParameterParse(name.to_string(), value.to_string())),
+				Ok(value) => self.period = value,
+			},
+			"source" => match value.parse() {
+				Err(_) => return Some(Error::ParameterParse(name.to_string(), value.to_string())),
+				Ok(value) => self.source = value,
+			},
+
+			_ => {
+				return Some(Error::UnknownParameter(name.to_string()));
+			}
+		};
+
+		None
+	}
+
+	fn parameters(&self) -> Vec<ParamDescriptor> {
+		let default = Self::default();
+
+		vec![
+			ParamDescriptor::new("period", ParamKind::Period, self.period, default.period)
+				.range(Included(2.), Excluded(PeriodType::MAX as ValueType)),
+			ParamDescriptor::new("source", ParamKind::Source, self.source, default.source),
+		]
+	}
+
+	fn size(&self) -> (u8, u8) {
+		(2, 1)
+	}
+
+	fn min_periods(&self) -> PeriodType {
+		self.period.saturating_add(1)
+	}
+}
+
+impl<T: OHLC> IndicatorInitializer<T> for CenterOfGravity {
+	type Instance = CenterOfGravityInstance;
+
+	fn init(self, candle: T) -> Result<Self::Instance, Error>
+	where
+		Self: Sized,
+	{
+		if !self.validate() {
+			return Err(Error::WrongConfig);
+		}
+
+		let cfg = self;
+		let src = candle.source(cfg.source);
+		let length = cfg.period as ValueType;
+
+		Ok(Self::Instance {
+			length,
+			center: (length + 1.) * 0.5,
+			sum: src * length,
+			weighted_sum: src * length * (length + 1.) * 0.5,
+			window: Window::new(cfg.period, src),
+			trigger: 0.,
+			cross: Cross::new((), (0., 0.))?,
+			cfg,
+		})
+	}
+}
+
+impl Default for CenterOfGravity {
+	fn default() -> Self {
+		Self {
+			period: 10,
+			source: Source::HL2,
+		}
+	}
+}
+
+/// Just an alias for [`CenterOfGravity`]
+pub type CG = CenterOfGravity;
+
+/// Ehlers Center of Gravity oscillator state structure
+#[derive(Debug, Clone)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct CenterOfGravityInstance {
+	cfg: CenterOfGravity,
+
+	length: ValueType,
+	center: ValueType,
+	sum: ValueType,
+	weighted_sum: ValueType,
+	window: Window<ValueType>,
+	trigger: ValueType,
+	cross: Cross,
+}
+
+impl<T: OHLC> IndicatorInstance<T> for CenterOfGravityInstance {
+	type Config = CenterOfGravity;
+
+	fn config(&self) -> &Self::Config {
+		&self.cfg
+	}
+
+	fn next(&mut self, candle: T) -> IndicatorResult {
+		let src = candle.source(self.cfg.source);
+		let past_src = self.window.push(src);
+
+		// every value which stays in the window gets its weight increased by 1
+		self.weighted_sum += past_src.mul_add(-(self.length + 1.), self.sum) + src;
+		self.sum += src - past_src;
+
+		let cg = if self.sum == 0. {
+			0.
+		} else {
+			self.center - self.weighted_sum / self.sum
+		};
+
+		let trigger = self.trigger;
+		self.trigger = cg;
+
+		let signal = self.cross.next((cg, trigger));
+
+		IndicatorResult::new(&[cg, trigger], &[signal])
+	}
+
+	fn reset(&mut self, candle: T) -> Result<(), Error> {
+		let src = candle.source(self.cfg.source);
+		let length = self.length;
+
+		self.sum = src * length;
+		self.weighted_sum = src * length * (length + 1.) * 0.5;
+		self.window.fill(src);
+		self.trigger = 0.;
+		self.cross.reset((0., 0.))
+	}
+}
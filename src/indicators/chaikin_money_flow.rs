@@ -3,7 +3,12 @@ use serde::{Deserialize, Serialize};
 
 use crate::core::{Error, Method, PeriodType, ValueType, Window, OHLCV};
 use crate::core::{IndicatorConfig, IndicatorInitializer, IndicatorInstance, IndicatorResult};
+use crate::core::{ParamDescriptor, ParamKind};
 use crate::methods::{Cross, ADI};
+use alloc::string::String;
+use alloc::string::ToString;
+use alloc::vec::Vec;
+use core::ops::Bound::{Excluded, Included};
 
 /// Chaikin Money Flow
 ///
@@ -33,6 +38,8 @@ pub struct ChaikinMoneyFlow {
 
 impl IndicatorConfig for ChaikinMoneyFlow {
 	const NAME: &'static str = "ChaikinMoneyFlow";
+	const VALUE_NAMES: &'static [&'static str] = &["value"];
+	const SIGNAL_NAMES: &'static [&'static str] = &["signal"];
 
 	fn validate(&self) -> bool {
 		self.size > 1 && self.size < PeriodType::MAX
@@ -45,13 +52,22 @@ impl IndicatorConfig for ChaikinMoneyFlow {
 				Ok(value) => self.size = value,
 			},
 			_ => {
-				return Some(Error::ParameterParse(name.to_string(), value));
+				return Some(Error::UnknownParameter(name.to_string()));
 			}
 		};
 
 		None
 	}
 
+	fn parameters(&self) -> Vec<ParamDescriptor> {
+		let default = Self::default();
+
+		vec![
+			ParamDescriptor::new("size", ParamKind::Period, self.size, default.size)
+				.range(Included(2.), Excluded(PeriodType::MAX as ValueType)),
+		]
+	}
+
 	fn is_volume_based(&self) -> bool {
 		true
 	}
@@ -92,7 +108,8 @@ impl Default for ChaikinMoneyFlow {
 	}
 }
 
-#[derive(Debug)]
+#[derive(Debug, Clone)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 pub struct ChaikinMoneyFlowInstance<T: OHLCV> {
 	cfg: ChaikinMoneyFlow,
 
@@ -117,4 +134,13 @@ impl<T: OHLCV> IndicatorInstance<T> for ChaikinMoneyFlowInstance<T> {
 
 		IndicatorResult::new(&[value], &[signal])
 	}
+
+	fn reset(&mut self, candle: T) -> Result<(), Error> {
+		self.adi.reset(candle)?;
+		self.vol_sum = candle.volume() * self.cfg.size as ValueType;
+		self.window.fill(candle.volume());
+		self.cross_over = Cross::default();
+
+		Ok(())
+	}
 }
@@ -1,10 +1,15 @@
 #[cfg(feature = "serde")]
 use serde::{Deserialize, Serialize};
 
-use crate::core::{Error, Method, PeriodType, OHLCV};
+use crate::core::{Error, Method, PeriodType, ValueType, OHLCV};
 use crate::core::{IndicatorConfig, IndicatorInitializer, IndicatorInstance, IndicatorResult};
+use crate::core::{ParamDescriptor, ParamKind};
 use crate::helpers::{method, RegularMethod, RegularMethods};
 use crate::methods::{Cross, ADI};
+use alloc::string::String;
+use alloc::string::ToString;
+use alloc::vec::Vec;
+use core::ops::Bound::{Excluded, Included};
 
 /// Chaikin Oscillator
 ///
@@ -44,6 +49,8 @@ pub struct ChaikinOscillator {
 
 impl IndicatorConfig for ChaikinOscillator {
 	const NAME: &'static str = "ChaikinOscillator";
+	const VALUE_NAMES: &'static [&'static str] = &["value"];
+	const SIGNAL_NAMES: &'static [&'static str] = &["signal"];
 
 	fn validate(&self) -> bool {
 		self.period1 > 0 && self.period1 < self.period2 && self.period2 < PeriodType::MAX
@@ -63,15 +70,36 @@ impl IndicatorConfig for ChaikinOscillator {
 				Err(_) => return Some(Error::ParameterParse(name.to_string(), value.to_string())),
 				Ok(value) => self.method = value,
 			},
+			"window" => match value.parse() {
+				Err(_) => return Some(Error::ParameterParse(name.to_string(), value.to_string())),
+				Ok(value) => self.window = value,
+			},
 
 			_ => {
-				return Some(Error::ParameterParse(name.to_string(), value));
+				return Some(Error::UnknownParameter(name.to_string()));
 			}
 		};
 
 		None
 	}
 
+	fn parameters(&self) -> Vec<ParamDescriptor> {
+		let default = Self::default();
+
+		vec![
+			ParamDescriptor::new("period1", ParamKind::Period, self.period1, default.period1)
+				.range(Included(1.), Excluded(self.period2 as ValueType)),
+			ParamDescriptor::new("period2", ParamKind::Period, self.period2, default.period2)
+				.range(
+					Excluded(self.period1 as ValueType),
+					Excluded(PeriodType::MAX as ValueType),
+				),
+			ParamDescriptor::new("method", ParamKind::Method, self.method, default.method),
+			ParamDescriptor::new("window", ParamKind::Period, self.window, default.window)
+				.range(Included(0.), Included(PeriodType::MAX as ValueType)),
+		]
+	}
+
 	fn is_volume_based(&self) -> bool {
 		true
 	}
@@ -79,6 +107,12 @@ impl IndicatorConfig for ChaikinOscillator {
 	fn size(&self) -> (u8, u8) {
 		(1, 1)
 	}
+
+	fn min_periods(&self) -> PeriodType {
+		self.period1
+			.max(self.period2)
+			.saturating_add(self.window.saturating_sub(1))
+	}
 }
 
 impl<T: OHLCV> IndicatorInitializer<T> for ChaikinOscillator {
@@ -116,7 +150,8 @@ impl Default for ChaikinOscillator {
 	}
 }
 
-#[derive(Debug)]
+#[derive(Debug, Clone)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 pub struct ChaikinOscillatorInstance<T: OHLCV> {
 	cfg: ChaikinOscillator,
 
@@ -145,4 +180,13 @@ impl<T: OHLCV> IndicatorInstance<T> for ChaikinOscillatorInstance<T> {
 
 		IndicatorResult::new(&[value], &[signal])
 	}
+
+	fn reset(&mut self, candle: T) -> Result<(), Error> {
+		self.adi.reset(candle)?;
+		self.ma1.reset(self.adi.get_value())?;
+		self.ma2.reset(self.adi.get_value())?;
+		self.cross_over = Cross::default();
+
+		Ok(())
+	}
 }
@@ -4,8 +4,16 @@ use serde::{Deserialize, Serialize};
 
 use crate::core::{Action, Error, Method, PeriodType, Source, ValueType, OHLC};
 use crate::core::{IndicatorConfig, IndicatorInitializer, IndicatorInstance, IndicatorResult};
+use crate::core::{ParamDescriptor, ParamKind};
 use crate::helpers::{method, signi, RegularMethod, RegularMethods};
 use crate::methods::{CrossAbove, Highest, Lowest};
+use alloc::string::String;
+use alloc::string::ToString;
+use alloc::vec::Vec;
+use core::ops::Bound::{Included, Unbounded};
+
+#[cfg(not(feature = "std"))]
+use crate::core::Float as _;
 
 /// Chande Kroll Stop
 ///
@@ -54,6 +62,8 @@ pub struct ChandeKrollStop {
 
 impl IndicatorConfig for ChandeKrollStop {
 	const NAME: &'static str = "ChandeKrollStop";
+	const VALUE_NAMES: &'static [&'static str] = &["stop_long", "source", "stop_short"];
+	const SIGNAL_NAMES: &'static [&'static str] = &["position", "cross"];
 
 	fn validate(&self) -> bool {
 		self.x >= 0.0 && self.p > 0 && self.q > 0
@@ -77,18 +87,41 @@ impl IndicatorConfig for ChandeKrollStop {
 				Err(_) => return Some(Error::ParameterParse(name.to_string(), value.to_string())),
 				Ok(value) => self.source = value,
 			},
+			"method" => match value.parse() {
+				Err(_) => return Some(Error::ParameterParse(name.to_string(), value.to_string())),
+				Ok(value) => self.method = value,
+			},
 
 			_ => {
-				return Some(Error::ParameterParse(name.to_string(), value));
+				return Some(Error::UnknownParameter(name.to_string()));
 			}
 		};
 
 		None
 	}
 
+	fn parameters(&self) -> Vec<ParamDescriptor> {
+		let default = Self::default();
+
+		vec![
+			ParamDescriptor::new("p", ParamKind::Period, self.p, default.p)
+				.range(Included(1.), Included(PeriodType::MAX as ValueType)),
+			ParamDescriptor::new("method", ParamKind::Method, self.method, default.method),
+			ParamDescriptor::new("x", ParamKind::Float, self.x, default.x)
+				.range(Included(0.), Unbounded),
+			ParamDescriptor::new("q", ParamKind::Period, self.q, default.q)
+				.range(Included(1.), Included(PeriodType::MAX as ValueType)),
+			ParamDescriptor::new("source", ParamKind::Source, self.source, default.source),
+		]
+	}
+
 	fn size(&self) -> (u8, u8) {
 		(3, 2)
 	}
+
+	fn min_periods(&self) -> PeriodType {
+		self.p.saturating_add(self.q)
+	}
 }
 
 impl<T: OHLC> IndicatorInitializer<T> for ChandeKrollStop {
@@ -139,7 +172,8 @@ impl Default for ChandeKrollStop {
 }
 
 /// Chande Kroll Stop state structure
-#[derive(Debug)]
+#[derive(Debug, Clone)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 pub struct ChandeKrollStopInstance<T: OHLC> {
 	cfg: ChandeKrollStop,
 
@@ -192,4 +226,26 @@ impl<T: OHLC> IndicatorInstance<T> for ChandeKrollStopInstance<T> {
 			&[Action::from(value), Action::from(s2)],
 		)
 	}
+
+	fn reset(&mut self, candle: T) -> Result<(), Error> {
+		let cfg = self.cfg;
+		let tr = candle.high() - candle.low();
+		// evaluated exactly the same way as in `init`
+		#[allow(clippy::suboptimal_flops)]
+		let stop_short = candle.high() - cfg.x * tr;
+		let stop_long = cfg.x.mul_add(tr, candle.low());
+
+		self.ma.reset(candle.tr(&candle))?;
+
+		self.highest1.reset(candle.high())?;
+		self.lowest1.reset(candle.low())?;
+
+		self.highest2.reset(stop_short)?;
+		self.lowest2.reset(stop_long)?;
+
+		self.prev_candle = candle;
+		self.prev_stop_short = stop_short;
+		self.prev_stop_long = stop_long;
+		self.cross_above.reset((stop_long, stop_short))
+	}
 }
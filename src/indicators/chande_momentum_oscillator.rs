@@ -3,7 +3,12 @@ use serde::{Deserialize, Serialize};
 
 use crate::core::{Error, Method, PeriodType, Source, ValueType, Window, OHLC};
 use crate::core::{IndicatorConfig, IndicatorInitializer, IndicatorInstance, IndicatorResult};
+use crate::core::{ParamDescriptor, ParamKind};
 use crate::methods::{Change, CrossAbove, CrossUnder};
+use alloc::string::String;
+use alloc::string::ToString;
+use alloc::vec::Vec;
+use core::ops::Bound::Included;
 
 /// Chande Momentum Oscillator
 ///
@@ -39,6 +44,8 @@ pub struct ChandeMomentumOscillator {
 
 impl IndicatorConfig for ChandeMomentumOscillator {
 	const NAME: &'static str = "ChandeMomentumOscillator";
+	const VALUE_NAMES: &'static [&'static str] = &["value"];
+	const SIGNAL_NAMES: &'static [&'static str] = &["signal"];
 
 	fn validate(&self) -> bool {
 		self.zone >= 0. && self.zone <= 1.0 && self.period > 1
@@ -60,16 +67,32 @@ impl IndicatorConfig for ChandeMomentumOscillator {
 			},
 
 			_ => {
-				return Some(Error::ParameterParse(name.to_string(), value));
+				return Some(Error::UnknownParameter(name.to_string()));
 			}
 		};
 
 		None
 	}
 
+	fn parameters(&self) -> Vec<ParamDescriptor> {
+		let default = Self::default();
+
+		vec![
+			ParamDescriptor::new("period", ParamKind::Period, self.period, default.period)
+				.range(Included(2.), Included(PeriodType::MAX as ValueType)),
+			ParamDescriptor::new("zone", ParamKind::Float, self.zone, default.zone)
+				.range(Included(0.0), Included(1.0)),
+			ParamDescriptor::new("source", ParamKind::Source, self.source, default.source),
+		]
+	}
+
 	fn size(&self) -> (u8, u8) {
 		(1, 1)
 	}
+
+	fn min_periods(&self) -> PeriodType {
+		self.period.saturating_add(1)
+	}
 }
 
 impl<T: OHLC> IndicatorInitializer<T> for ChandeMomentumOscillator {
@@ -107,7 +130,8 @@ impl Default for ChandeMomentumOscillator {
 	}
 }
 
-#[derive(Debug)]
+#[derive(Debug, Clone)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 pub struct ChandeMomentumOscillatorInstance {
 	cfg: ChandeMomentumOscillator,
 
@@ -157,4 +181,15 @@ impl<T: OHLC> IndicatorInstance<T> for ChandeMomentumOscillatorInstance {
 
 		IndicatorResult::new(&[value], &[signal])
 	}
+
+	fn reset(&mut self, candle: T) -> Result<(), Error> {
+		self.pos_sum = 0.;
+		self.neg_sum = 0.;
+		self.change.reset(candle.source(self.cfg.source))?;
+		self.window.fill(0.);
+		self.cross_under = CrossUnder::default();
+		self.cross_above = CrossAbove::default();
+
+		Ok(())
+	}
 }
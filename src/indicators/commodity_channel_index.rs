@@ -3,7 +3,12 @@ use serde::{Deserialize, Serialize};
 
 use crate::core::{Action, Error, Method, PeriodType, Source, ValueType, OHLC};
 use crate::core::{IndicatorConfig, IndicatorInitializer, IndicatorInstance, IndicatorResult};
+use crate::core::{ParamDescriptor, ParamKind};
 use crate::methods::CCI;
+use alloc::string::String;
+use alloc::string::ToString;
+use alloc::vec::Vec;
+use core::ops::Bound::{Excluded, Included, Unbounded};
 
 const SCALE: ValueType = 1.0 / 1.5;
 /// Commodity Channel Index
@@ -42,6 +47,8 @@ pub struct CommodityChannelIndex {
 
 impl IndicatorConfig for CommodityChannelIndex {
 	const NAME: &'static str = "CommodityChannelIndex";
+	const VALUE_NAMES: &'static [&'static str] = &["value"];
+	const SIGNAL_NAMES: &'static [&'static str] = &["signal"];
 
 	fn validate(&self) -> bool {
 		self.zone >= 0.0 && self.period > 1 && self.period < PeriodType::MAX
@@ -63,13 +70,25 @@ impl IndicatorConfig for CommodityChannelIndex {
 			},
 
 			_ => {
-				return Some(Error::ParameterParse(name.to_string(), value));
+				return Some(Error::UnknownParameter(name.to_string()));
 			}
 		};
 
 		None
 	}
 
+	fn parameters(&self) -> Vec<ParamDescriptor> {
+		let default = Self::default();
+
+		vec![
+			ParamDescriptor::new("period", ParamKind::Period, self.period, default.period)
+				.range(Included(2.), Excluded(PeriodType::MAX as ValueType)),
+			ParamDescriptor::new("zone", ParamKind::Float, self.zone, default.zone)
+				.range(Included(0.0), Unbounded),
+			ParamDescriptor::new("source", ParamKind::Source, self.source, default.source),
+		]
+	}
+
 	fn size(&self) -> (u8, u8) {
 		(1, 1)
 	}
@@ -109,7 +128,8 @@ impl Default for CommodityChannelIndex {
 	}
 }
 
-#[derive(Debug)]
+#[derive(Debug, Clone)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 pub struct CommodityChannelIndexInstance {
 	cfg: CommodityChannelIndex,
 
@@ -155,4 +175,10 @@ impl<T: OHLC> IndicatorInstance<T> for CommodityChannelIndexInstance {
 
 		IndicatorResult::new(&[cci], &[Action::from(signal)])
 	}
+
+	fn reset(&mut self, candle: T) -> Result<(), Error> {
+		self.last_cci = 0.;
+		self.last_signal = 0;
+		self.cci.reset(candle.source(self.cfg.source))
+	}
 }
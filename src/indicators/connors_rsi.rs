@@ -0,0 +1,282 @@
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
+
+use crate::core::{Error, Method, PeriodType, Source, ValueType, OHLC};
+use crate::core::{IndicatorConfig, IndicatorInitializer, IndicatorInstance, IndicatorResult};
+use crate::core::{ParamDescriptor, ParamKind};
+use crate::methods::{Change, CrossAbove, CrossUnder, PercentRank, RateOfChange, RMA};
+use alloc::string::String;
+use alloc::string::ToString;
+use alloc::vec::Vec;
+use core::ops::Bound::{Excluded, Included};
+
+/// Connors RSI
+///
+/// Average of three components:
+///
+/// * short `RSI` of the `source` values;
+/// * `RSI` of the up/down streak length;
+/// * percent rank of the one-period rate of change.
+///
+/// ## Links
+///
+/// * <https://www.tradingview.com/support/solutions/43000502017-connors-rsi-crsi/>
+///
+/// # 1 value
+///
+/// * `CRSI` value
+///
+/// Range in \[0.0; 1.0\]
+///
+/// # 1 signal
+///
+/// When `CRSI` value goes below `zone`, then returns full buy signal.
+/// When `CRSI` value goes above `1.0 - zone`, then returns full sell signal.
+/// Otherwise no signal
+#[derive(Debug, Clone, Copy)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct ConnorsRSI {
+	/// Price `RSI` period length. Default is `3`.
+	///
+	/// Range in \[2; [`PeriodType::MAX`](crate::core::PeriodType)\)
+	pub rsi_period: PeriodType,
+	/// Streak `RSI` period length. Default is `2`.
+	///
+	/// Range in \[2; [`PeriodType::MAX`](crate::core::PeriodType)\)
+	pub streak_period: PeriodType,
+	/// Percent rank period length. Default is `100`.
+	///
+	/// Range in \[1; [`PeriodType::MAX`](crate::core::PeriodType)\)
+	pub rank_period: PeriodType,
+	/// Signal zone size. Default is `0.1`.
+	///
+	/// Range in \(0.0; 0.5\]
+	pub zone: ValueType,
+	/// Source type of values. Default is [`Close`](crate::core::Source#variant.Close)
+	pub source: Source,
+}
+
+impl IndicatorConfig for ConnorsRSI {
+	const NAME: &'static str = "ConnorsRSI";
+	const VALUE_NAMES: &'static [&'static str] = &["value"];
+	const SIGNAL_NAMES: &'static [&'static str] = &["signal"];
+
+	fn validate(&self) -> bool {
+		self.rsi_period > 1
+			&& self.rsi_period < PeriodType::MAX
+			&& self.streak_period > 1
+			&& self.streak_period < PeriodType::MAX
+			&& self.rank_period > 0
+			&& self.rank_period < PeriodType::MAX
+			&& self.zone > 0.
+			&& self.zone <= 0.5
+	}
+
+	fn set(&mut self, name: &str, value: String) -> Option<Error> {
+		match name {
+			"rsi_period" => match value.parse() {
+				Err(_) => return Some(Error::ParameterParse(name.to_string(), value.to_string())),
+				Ok(value) => self.rsi_period = value,
+			},
+			"streak_period" => match value.parse() {
+				Err(_) => return Some(Error::ParameterParse(name.to_string(), value.to_string())),
+				Ok(value) => self.streak_period = value,
+			},
+			"rank_period" => match value.parse() {
+				Err(_) => return Some(Error::ParameterParse(name.to_string(), value.to_string())),
+				Ok(value) => self.rank_period = value,
+			},
+			"zone" => match value.parse() {
+				Err(_) => return Some(Error::ParameterParse(name.to_string(), value.to_string())),
+				Ok(value) => self.zone = value,
+			},
+			"source" => match value.parse() {
+				Err(_) => return Some(Error::ParameterParse(name.to_string(), value.to_string())),
+				Ok(value) => self.source = value,
+			},
+
+			_ => {
+				return Some(Error::UnknownParameter(name.to_string()));
+			}
+		};
+
+		None
+	}
+
+	fn parameters(&self) -> Vec<ParamDescriptor> {
+		let default = Self::default();
+
+		vec![
+			ParamDescriptor::new(
+				"rsi_period",
+				ParamKind::Period,
+				self.rsi_period,
+				default.rsi_period,
+			)
+			.range(Included(2.), Excluded(PeriodType::MAX as ValueType)),
+			ParamDescriptor::new(
+				"streak_period",
+				ParamKind::Period,
+				self.streak_period,
+				default.streak_period,
+			)
+			.range(Included(2.), Excluded(PeriodType::MAX as ValueType)),
+			ParamDescriptor::new(
+				"rank_period",
+				ParamKind::Period,
+				self.rank_period,
+				default.rank_period,
+			)
+			.range(Included(1.), Excluded(PeriodType::MAX as ValueType)),
+			ParamDescriptor::new("zone", ParamKind::Float, self.zone, default.zone)
+				.range(Excluded(0.0), Included(0.5)),
+			ParamDescriptor::new("source", ParamKind::Source, self.source, default.source),
+		]
+	}
+
+	fn size(&self) -> (u8, u8) {
+		(1, 1)
+	}
+
+	fn min_periods(&self) -> PeriodType {
+		self.rsi_period
+			.saturating_add(1)
+			.max(self.streak_period.saturating_add(2))
+			.max(self.rank_period.saturating_add(1))
+	}
+}
+
+impl<T: OHLC> IndicatorInitializer<T> for ConnorsRSI {
+	type Instance = ConnorsRSIInstance;
+
+	fn init(self, candle: T) -> Result<Self::Instance, Error>
+	where
+		Self: Sized,
+	{
+		if !self.validate() {
+			return Err(Error::WrongConfig);
+		}
+
+		let cfg = self;
+		let src = candle.source(cfg.source);
+
+		Ok(Self::Instance {
+			change: Change::new(1, src)?,
+			posma: RMA::new(cfg.rsi_period, 0.)?,
+			negma: RMA::new(cfg.rsi_period, 0.)?,
+			streak: 0.,
+			streak_change: Change::new(1, 0.)?,
+			streak_posma: RMA::new(cfg.streak_period, 0.)?,
+			streak_negma: RMA::new(cfg.streak_period, 0.)?,
+			roc: RateOfChange::new(1, src)?,
+			rank: PercentRank::new(cfg.rank_period, 0.)?,
+			cross_above: CrossAbove::default(),
+			cross_under: CrossUnder::default(),
+			cfg,
+		})
+	}
+}
+
+impl Default for ConnorsRSI {
+	fn default() -> Self {
+		Self {
+			rsi_period: 3,
+			streak_period: 2,
+			rank_period: 100,
+			zone: 0.1,
+			source: Source::Close,
+		}
+	}
+}
+
+/// Just an alias for [`ConnorsRSI`]
+pub type CRSI = ConnorsRSI;
+
+/// Connors RSI state structure
+#[derive(Debug, Clone)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct ConnorsRSIInstance {
+	cfg: ConnorsRSI,
+
+	change: Change,
+	posma: RMA,
+	negma: RMA,
+	streak: ValueType,
+	streak_change: Change,
+	streak_posma: RMA,
+	streak_negma: RMA,
+	roc: RateOfChange,
+	rank: PercentRank,
+	cross_above: CrossAbove,
+	cross_under: CrossUnder,
+}
+
+#[inline]
+fn rsi(change: ValueType, posma: &mut RMA, negma: &mut RMA) -> ValueType {
+	let pos = posma.next(change.max(0.));
+	let neg = -negma.next(change.min(0.));
+
+	if pos != 0. || neg != 0. {
+		pos / (pos + neg)
+	} else {
+		0.
+	}
+}
+
+impl<T: OHLC> IndicatorInstance<T> for ConnorsRSIInstance {
+	type Config = ConnorsRSI;
+
+	fn config(&self) -> &Self::Config {
+		&self.cfg
+	}
+
+	fn next(&mut self, candle: T) -> IndicatorResult {
+		let src = candle.source(self.cfg.source);
+
+		let change = self.change.next(src);
+		let price_rsi = rsi(change, &mut self.posma, &mut self.negma);
+
+		self.streak = if change > 0. {
+			self.streak.max(0.) + 1.
+		} else if change < 0. {
+			self.streak.min(0.) - 1.
+		} else {
+			0.
+		};
+
+		let streak_change = self.streak_change.next(self.streak);
+		let streak_rsi = rsi(
+			streak_change,
+			&mut self.streak_posma,
+			&mut self.streak_negma,
+		);
+
+		let rank = self.rank.next(self.roc.next(src));
+
+		let value = (price_rsi + streak_rsi + rank) / 3.;
+
+		let oversold = self.cross_under.next((value, self.cfg.zone));
+		let overbought = self.cross_above.next((value, 1. - self.cfg.zone));
+		let signal = oversold - overbought;
+
+		IndicatorResult::new(&[value], &[signal])
+	}
+
+	fn reset(&mut self, candle: T) -> Result<(), Error> {
+		let src = candle.source(self.cfg.source);
+
+		self.change.reset(src)?;
+		self.posma.reset(0.)?;
+		self.negma.reset(0.)?;
+		self.streak = 0.;
+		self.streak_change.reset(0.)?;
+		self.streak_posma.reset(0.)?;
+		self.streak_negma.reset(0.)?;
+		self.roc.reset(src)?;
+		self.rank.reset(0.)?;
+		self.cross_above = CrossAbove::default();
+		self.cross_under = CrossUnder::default();
+
+		Ok(())
+	}
+}
@@ -1,10 +1,15 @@
 #[cfg(feature = "serde")]
 use serde::{Deserialize, Serialize};
 
-use crate::core::{Error, Method, PeriodType, Source, OHLC};
+use crate::core::{Error, Method, PeriodType, Source, ValueType, OHLC};
 use crate::core::{IndicatorConfig, IndicatorInitializer, IndicatorInstance, IndicatorResult};
+use crate::core::{ParamDescriptor, ParamKind};
 use crate::helpers::{method, RegularMethod, RegularMethods};
 use crate::methods::{Cross, RateOfChange, ReverseSignal};
+use alloc::string::String;
+use alloc::string::ToString;
+use alloc::vec::Vec;
+use core::ops::Bound::{Excluded, Included};
 
 /// Coppock curve
 ///
@@ -67,6 +72,8 @@ pub struct CoppockCurve {
 
 impl IndicatorConfig for CoppockCurve {
 	const NAME: &'static str = "CoppockCurve";
+	const VALUE_NAMES: &'static [&'static str] = &["value", "signal_line"];
+	const SIGNAL_NAMES: &'static [&'static str] = &["zero_cross", "reverse", "signal_line_cross"];
 
 	fn validate(&self) -> bool {
 		self.period1 > 1
@@ -120,19 +127,66 @@ impl IndicatorConfig for CoppockCurve {
 				Err(_) => return Some(Error::ParameterParse(name.to_string(), value.to_string())),
 				Ok(value) => self.method2 = value,
 			},
-			// "zone"		=> self.zone = value.parse().unwrap(),
-			// "source"	=> self.source = value.parse().unwrap(),
 			_ => {
-				return Some(Error::ParameterParse(name.to_string(), value));
+				return Some(Error::UnknownParameter(name.to_string()));
 			}
 		};
 
 		None
 	}
 
+	fn parameters(&self) -> Vec<ParamDescriptor> {
+		let default = Self::default();
+
+		vec![
+			ParamDescriptor::new("period1", ParamKind::Period, self.period1, default.period1)
+				.range(Included(2.), Excluded(PeriodType::MAX as ValueType)),
+			ParamDescriptor::new("period2", ParamKind::Period, self.period2, default.period2)
+				.range(
+					Excluded(self.period3 as ValueType),
+					Excluded(PeriodType::MAX as ValueType),
+				),
+			ParamDescriptor::new("period3", ParamKind::Period, self.period3, default.period3)
+				.range(Included(1.), Excluded(self.period2 as ValueType)),
+			ParamDescriptor::new("s2_left", ParamKind::Period, self.s2_left, default.s2_left)
+				.range(
+					Included(1.),
+					Excluded((PeriodType::MAX - self.s2_right) as ValueType),
+				),
+			ParamDescriptor::new(
+				"s2_right",
+				ParamKind::Period,
+				self.s2_right,
+				default.s2_right,
+			)
+			.range(
+				Included(1.),
+				Excluded((PeriodType::MAX - self.s2_left) as ValueType),
+			),
+			ParamDescriptor::new(
+				"s3_period",
+				ParamKind::Period,
+				self.s3_period,
+				default.s3_period,
+			)
+			.range(Included(2.), Excluded(PeriodType::MAX as ValueType)),
+			ParamDescriptor::new("source", ParamKind::Source, self.source, default.source),
+			ParamDescriptor::new("method1", ParamKind::Method, self.method1, default.method1),
+			ParamDescriptor::new("method2", ParamKind::Method, self.method2, default.method2),
+		]
+	}
+
 	fn size(&self) -> (u8, u8) {
 		(2, 3)
 	}
+
+	fn min_periods(&self) -> PeriodType {
+		self.period2
+			.max(self.period3)
+			.saturating_add(self.period1)
+			.saturating_add(self.s3_period)
+			.saturating_sub(1)
+	}
 }
 
 impl<T: OHLC> IndicatorInitializer<T> for CoppockCurve {
@@ -178,7 +232,8 @@ impl Default for CoppockCurve {
 	}
 }
 
-#[derive(Debug)]
+#[derive(Debug, Clone)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 pub struct CoppockCurveInstance {
 	cfg: CoppockCurve,
 
@@ -211,4 +266,18 @@ impl<T: OHLC> IndicatorInstance<T> for CoppockCurveInstance {
 
 		IndicatorResult::new(&[value1, value2], &[signal1, signal2, signal3])
 	}
+
+	fn reset(&mut self, candle: T) -> Result<(), Error> {
+		let src = candle.source(self.cfg.source);
+
+		self.roc1.reset(src)?;
+		self.roc2.reset(src)?;
+		self.ma1.reset(0.)?;
+		self.ma2.reset(0.)?;
+		self.cross_over1 = Cross::default();
+		self.pivot.reset(0.)?;
+		self.cross_over2 = Cross::default();
+
+		Ok(())
+	}
 }
@@ -3,7 +3,12 @@ use serde::{Deserialize, Serialize};
 
 use crate::core::{Error, PeriodType, Source, ValueType, Window, OHLC};
 use crate::core::{IndicatorConfig, IndicatorInitializer, IndicatorInstance, IndicatorResult};
+use crate::core::{ParamDescriptor, ParamKind};
 use crate::helpers::{method, RegularMethod, RegularMethods};
+use alloc::string::String;
+use alloc::string::ToString;
+use alloc::vec::Vec;
+use core::ops::Bound::{Excluded, Included};
 
 // The Formula for the Detrended Price Oscillator (DPO) is
 // DPO=Price from X2+1 periods ago−X period SMA
@@ -40,6 +45,7 @@ pub struct DetrendedPriceOscillator {
 
 impl IndicatorConfig for DetrendedPriceOscillator {
 	const NAME: &'static str = "DetrendedPriceOscillator";
+	const VALUE_NAMES: &'static [&'static str] = &["value"];
 
 	fn validate(&self) -> bool {
 		self.period > 1 && self.period < PeriodType::MAX
@@ -61,13 +67,24 @@ impl IndicatorConfig for DetrendedPriceOscillator {
 			},
 
 			_ => {
-				return Some(Error::ParameterParse(name.to_string(), value));
+				return Some(Error::UnknownParameter(name.to_string()));
 			}
 		};
 
 		None
 	}
 
+	fn parameters(&self) -> Vec<ParamDescriptor> {
+		let default = Self::default();
+
+		vec![
+			ParamDescriptor::new("period", ParamKind::Period, self.period, default.period)
+				.range(Included(2.), Excluded(PeriodType::MAX as ValueType)),
+			ParamDescriptor::new("method", ParamKind::Method, self.method, default.method),
+			ParamDescriptor::new("source", ParamKind::Source, self.source, default.source),
+		]
+	}
+
 	fn size(&self) -> (u8, u8) {
 		(1, 0)
 	}
@@ -104,7 +121,8 @@ impl Default for DetrendedPriceOscillator {
 	}
 }
 
-#[derive(Debug)]
+#[derive(Debug, Clone)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 pub struct DetrendedPriceOscillatorInstance {
 	cfg: DetrendedPriceOscillator,
 
@@ -129,4 +147,13 @@ impl<T: OHLC> IndicatorInstance<T> for DetrendedPriceOscillatorInstance {
 
 		IndicatorResult::new(&[dpo], &[])
 	}
+
+	fn reset(&mut self, candle: T) -> Result<(), Error> {
+		let src = candle.source(self.cfg.source);
+
+		self.sma.reset(src)?;
+		self.window.fill(src);
+
+		Ok(())
+	}
 }
@@ -0,0 +1,157 @@
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
+
+use crate::core::{Error, Method, PeriodType, ValueType, OHLC};
+use crate::core::{IndicatorConfig, IndicatorInitializer, IndicatorInstance, IndicatorResult};
+use crate::core::{ParamDescriptor, ParamKind};
+use crate::methods::{CrossAbove, CrossUnder, Highest, Lowest};
+use alloc::string::String;
+use alloc::string::ToString;
+use alloc::vec::Vec;
+use core::ops::Bound::{Excluded, Included};
+
+/// Donchian Channels
+///
+/// ## Links
+///
+/// * <https://en.wikipedia.org/wiki/Donchian_channel>
+///
+/// # 3 values
+///
+/// * `upper` band value \(highest *high* over the last `period` candles\)
+/// * `middle` line value
+/// * `lower` band value \(lowest *low* over the last `period` candles\)
+///
+/// Range of values is the same as the range of the price values.
+///
+/// # 1 signal
+///
+/// Breakouts are calculated relative to the previous candle's channel.
+///
+/// When *close* price crosses `upper` band upwards, then returns full buy signal.
+/// When *close* price crosses `lower` band downwards, then returns full sell signal.
+/// Otherwise no signal
+#[derive(Debug, Clone, Copy)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct DonchianChannels {
+	/// Channel period length. Default is `20`.
+	///
+	/// Range in \[1; [`PeriodType::MAX`](crate::core::PeriodType)\)
+	pub period: PeriodType,
+}
+
+impl IndicatorConfig for DonchianChannels {
+	const NAME: &'static str = "DonchianChannels";
+	const VALUE_NAMES: &'static [&'static str] = &["upper", "middle", "lower"];
+	const SIGNAL_NAMES: &'static [&'static str] = &["signal"];
+
+	fn validate(&self) -> bool {
+		self.period > 0 && self.period < PeriodType::MAX
+	}
+
+	fn set(&mut self, name: &str, value: String) -> Option<Error> {
+		match name {
+			"period" => match value.parse() {
+				Err(_) => return Some(Error::ParameterParse(name.to_string(), value.to_string())),
+				Ok(value) => self.period = value,
+			},
+
+			_ => {
+				return Some(Error::UnknownParameter(name.to_string()));
+			}
+		};
+
+		None
+	}
+
+	fn parameters(&self) -> Vec<ParamDescriptor> {
+		let default = Self::default();
+
+		vec![
+			ParamDescriptor::new("period", ParamKind::Period, self.period, default.period)
+				.range(Included(1.), Excluded(PeriodType::MAX as ValueType)),
+		]
+	}
+
+	fn size(&self) -> (u8, u8) {
+		(3, 1)
+	}
+}
+
+impl<T: OHLC> IndicatorInitializer<T> for DonchianChannels {
+	type Instance = DonchianChannelsInstance;
+
+	fn init(self, candle: T) -> Result<Self::Instance, Error>
+	where
+		Self: Sized,
+	{
+		if !self.validate() {
+			return Err(Error::WrongConfig);
+		}
+
+		let cfg = self;
+		Ok(Self::Instance {
+			highest: Highest::new(cfg.period, candle.high())?,
+			lowest: Lowest::new(cfg.period, candle.low())?,
+			prev_upper: candle.high(),
+			prev_lower: candle.low(),
+			cross_above: CrossAbove::new((), (candle.close(), candle.high()))?,
+			cross_under: CrossUnder::new((), (candle.close(), candle.low()))?,
+			cfg,
+		})
+	}
+}
+
+impl Default for DonchianChannels {
+	fn default() -> Self {
+		Self { period: 20 }
+	}
+}
+
+/// Donchian Channels state structure
+#[derive(Debug, Clone)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct DonchianChannelsInstance {
+	cfg: DonchianChannels,
+
+	highest: Highest,
+	lowest: Lowest,
+	prev_upper: ValueType,
+	prev_lower: ValueType,
+	cross_above: CrossAbove,
+	cross_under: CrossUnder,
+}
+
+impl<T: OHLC> IndicatorInstance<T> for DonchianChannelsInstance {
+	type Config = DonchianChannels;
+
+	fn config(&self) -> &Self::Config {
+		&self.cfg
+	}
+
+	fn next(&mut self, candle: T) -> IndicatorResult {
+		let close = candle.close();
+
+		// current candle is always inside its own channel, so breakouts are checked against the previous channel
+		let signal = self.cross_above.next((close, self.prev_upper))
+			- self.cross_under.next((close, self.prev_lower));
+
+		let upper = self.highest.next(candle.high());
+		let lower = self.lowest.next(candle.low());
+		let middle = (upper + lower) * 0.5;
+
+		self.prev_upper = upper;
+		self.prev_lower = lower;
+
+		IndicatorResult::new(&[upper, middle, lower], &[signal])
+	}
+
+	fn reset(&mut self, candle: T) -> Result<(), Error> {
+		self.highest.reset(candle.high())?;
+		self.lowest.reset(candle.low())?;
+		self.prev_upper = candle.high();
+		self.prev_lower = candle.low();
+		self.cross_above.reset((candle.close(), candle.high()))?;
+		self.cross_under.reset((candle.close(), candle.low()))
+	}
+}
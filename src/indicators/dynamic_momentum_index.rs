@@ -0,0 +1,273 @@
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
+
+use crate::core::{Error, Method, PeriodType, Source, ValueType, OHLC};
+use crate::core::{IndicatorConfig, IndicatorInitializer, IndicatorInstance, IndicatorResult};
+use crate::core::{ParamDescriptor, ParamKind};
+use crate::methods::{CrossAbove, CrossUnder, StDev, VariableRSI, SMA};
+use alloc::string::String;
+use alloc::string::ToString;
+use alloc::vec::Vec;
+use core::ops::Bound::{Excluded, Included};
+
+/// Dynamic Momentum Index
+///
+/// [`RelativeStrengthIndex`](crate::indicators::RelativeStrengthIndex) with the volatility-adaptive period.
+///
+/// Volatility index is the ratio of the current standard deviation of `source` values and its average.
+/// Current period is `period` divided by volatility index and clamped into \[`min_period`; `max_period`\],
+/// so the period shortens when volatility grows and lengthens when volatility falls.
+///
+/// # 2 values
+///
+/// * `DMI` value
+///
+/// Range in \[0.0; 1.0\]
+///
+/// * current period value
+///
+/// Range in \[`min_period`; `max_period`\]
+///
+/// # 1 signal
+///
+/// When `DMI` value goes below `zone`, then returns full buy signal.
+/// When `DMI` value goes above `1.0 - zone`, then returns full sell signal.
+/// Otherwise no signal
+#[derive(Debug, Clone, Copy)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct DynamicMomentumIndex {
+	/// Base period length. Default is `14`.
+	///
+	/// Range in \[1; [`PeriodType::MAX`](crate::core::PeriodType)\)
+	pub period: PeriodType,
+	/// Standard deviation period length. Default is `5`.
+	///
+	/// Range in \[2; [`PeriodType::MAX`](crate::core::PeriodType)\)
+	pub st_dev_period: PeriodType,
+	/// Standard deviation averaging period length. Default is `10`.
+	///
+	/// Range in \[1; [`PeriodType::MAX`](crate::core::PeriodType)\)
+	pub avg_period: PeriodType,
+	/// Minimum period length. Default is `5`.
+	///
+	/// Range in \[1; `max_period`\]
+	pub min_period: PeriodType,
+	/// Maximum period length. Default is `30`.
+	///
+	/// Range in \[`min_period`; [`PeriodType::MAX`](crate::core::PeriodType)\)
+	pub max_period: PeriodType,
+	/// Signal zone size. Default is `0.3`.
+	///
+	/// Range in \(0.0; 0.5\]
+	pub zone: ValueType,
+	/// Source type of values. Default is [`Close`](crate::core::Source#variant.Close)
+	pub source: Source,
+}
+
+impl IndicatorConfig for DynamicMomentumIndex {
+	const NAME: &'static str = "DynamicMomentumIndex";
+	const VALUE_NAMES: &'static [&'static str] = &["value", "period"];
+	const SIGNAL_NAMES: &'static [&'static str] = &["signal"];
+
+	fn validate(&self) -> bool {
+		self.period > 0
+			&& self.period < PeriodType::MAX
+			&& self.st_dev_period > 1
+			&& self.st_dev_period < PeriodType::MAX
+			&& self.avg_period > 0
+			&& self.avg_period < PeriodType::MAX
+			&& self.min_period > 0
+			&& self.min_period <= self.max_period
+			&& self.max_period < PeriodType::MAX
+			&& self.zone > 0.
+			&& self.zone <= 0.5
+	}
+
+	fn set(&mut self, name: &str, value: String) -> Option<Error> {
+		match name {
+			"period" => match value.parse() {
+				Err(_) => return Some(Error::ParameterParse(name.to_string(), value.to_string())),
+				Ok(value) => self.period = value,
+			},
+			"st_dev_period" => match value.parse() {
+				Err(_) => return Some(Error::ParameterParse(name.to_string(), value.to_string())),
+				Ok(value) => self.st_dev_period = value,
+			},
+			"avg_period" => match value.parse() {
+				Err(_) => return Some(Error::ParameterParse(name.to_string(), value.to_string())),
+				Ok(value) => self.avg_period = value,
+			},
+			"min_period" => match value.parse() {
+				Err(_) => return Some(Error::ParameterParse(name.to_string(), value.to_string())),
+				Ok(value) => self.min_period = value,
+			},
+			"max_period" => match value.parse() {
+				Err(_) => return Some(Error::ParameterParse(name.to_string(), value.to_string())),
+				Ok(value) => self.max_period = value,
+			},
+			"zone" => match value.parse() {
+				Err(_) => return Some(Error::ParameterParse(name.to_string(), value.to_string())),
+				Ok(value) => self.zone = value,
+			},
+			"source" => match value.parse() {
+				Err(_) => return Some(Error::ParameterParse(name.to_string(), value.to_string())),
+				Ok(value) => self.source = value,
+			},
+
+			_ => {
+				return Some(Error::UnknownParameter(name.to_string()));
+			}
+		};
+
+		None
+	}
+
+	fn parameters(&self) -> Vec<ParamDescriptor> {
+		let default = Self::default();
+
+		vec![
+			ParamDescriptor::new("period", ParamKind::Period, self.period, default.period)
+				.range(Included(1.), Excluded(PeriodType::MAX as ValueType)),
+			ParamDescriptor::new(
+				"st_dev_period",
+				ParamKind::Period,
+				self.st_dev_period,
+				default.st_dev_period,
+			)
+			.range(Included(2.), Excluded(PeriodType::MAX as ValueType)),
+			ParamDescriptor::new(
+				"avg_period",
+				ParamKind::Period,
+				self.avg_period,
+				default.avg_period,
+			)
+			.range(Included(1.), Excluded(PeriodType::MAX as ValueType)),
+			ParamDescriptor::new(
+				"min_period",
+				ParamKind::Period,
+				self.min_period,
+				default.min_period,
+			)
+			.range(Included(1.), Included(self.max_period as ValueType)),
+			ParamDescriptor::new(
+				"max_period",
+				ParamKind::Period,
+				self.max_period,
+				default.max_period,
+			)
+			.range(
+				Included(self.min_period as ValueType),
+				Excluded(PeriodType::MAX as ValueType),
+			),
+			ParamDescriptor::new("zone", ParamKind::Float, self.zone, default.zone)
+				.range(Excluded(0.0), Included(0.5)),
+			ParamDescriptor::new("source", ParamKind::Source, self.source, default.source),
+		]
+	}
+
+	fn size(&self) -> (u8, u8) {
+		(2, 1)
+	}
+
+	fn min_periods(&self) -> PeriodType {
+		self.st_dev_period
+			.saturating_add(self.avg_period)
+			.saturating_sub(1)
+			.max(self.max_period.saturating_add(1))
+	}
+}
+
+impl<T: OHLC> IndicatorInitializer<T> for DynamicMomentumIndex {
+	type Instance = DynamicMomentumIndexInstance;
+
+	fn init(self, candle: T) -> Result<Self::Instance, Error>
+	where
+		Self: Sized,
+	{
+		if !self.validate() {
+			return Err(Error::WrongConfig);
+		}
+
+		let cfg = self;
+		let src = candle.source(cfg.source);
+
+		Ok(Self::Instance {
+			st_dev: StDev::new(cfg.st_dev_period, src)?,
+			avg: SMA::new(cfg.avg_period, 0.)?,
+			rsi: VariableRSI::new(cfg.max_period, (src, cfg.period))?,
+			cross_under: CrossUnder::new((), (0.5, cfg.zone))?,
+			cross_above: CrossAbove::new((), (0.5, 1. - cfg.zone))?,
+			cfg,
+		})
+	}
+}
+
+impl Default for DynamicMomentumIndex {
+	fn default() -> Self {
+		Self {
+			period: 14,
+			st_dev_period: 5,
+			avg_period: 10,
+			min_period: 5,
+			max_period: 30,
+			zone: 0.3,
+			source: Source::Close,
+		}
+	}
+}
+
+/// Dynamic Momentum Index state structure
+#[derive(Debug, Clone)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct DynamicMomentumIndexInstance {
+	cfg: DynamicMomentumIndex,
+
+	st_dev: StDev,
+	avg: SMA,
+	rsi: VariableRSI,
+	cross_under: CrossUnder,
+	cross_above: CrossAbove,
+}
+
+impl<T: OHLC> IndicatorInstance<T> for DynamicMomentumIndexInstance {
+	type Config = DynamicMomentumIndex;
+
+	fn config(&self) -> &Self::Config {
+		&self.cfg
+	}
+
+	#[allow(clippy::cast_possible_truncation, clippy::cast_sign_loss)]
+	fn next(&mut self, candle: T) -> IndicatorResult {
+		let src = candle.source(self.cfg.source);
+
+		let st_dev = self.st_dev.next(src);
+		let avg = self.avg.next(st_dev);
+
+		let period = if st_dev > 0. {
+			(self.cfg.period as ValueType * avg / st_dev).clamp(
+				self.cfg.min_period as ValueType,
+				self.cfg.max_period as ValueType,
+			) as PeriodType
+		} else {
+			self.cfg.max_period
+		};
+
+		let value = self.rsi.next((src, period));
+
+		let signal = self.cross_under.next((value, self.cfg.zone))
+			- self.cross_above.next((value, 1. - self.cfg.zone));
+
+		IndicatorResult::new(&[value, period as ValueType], &[signal])
+	}
+
+	fn reset(&mut self, candle: T) -> Result<(), Error> {
+		let cfg = self.cfg;
+		let src = candle.source(cfg.source);
+
+		self.st_dev.reset(src)?;
+		self.avg.reset(0.)?;
+		self.rsi.reset((src, cfg.period))?;
+		self.cross_under.reset((0.5, cfg.zone))?;
+		self.cross_above.reset((0.5, 1. - cfg.zone))
+	}
+}
@@ -1,10 +1,15 @@
 #[cfg(feature = "serde")]
 use serde::{Deserialize, Serialize};
 
-use crate::core::{Error, Method, PeriodType, Window, OHLCV};
+use crate::core::{Error, Method, PeriodType, ValueType, Window, OHLCV};
 use crate::core::{IndicatorConfig, IndicatorInitializer, IndicatorInstance, IndicatorResult};
+use crate::core::{ParamDescriptor, ParamKind};
 use crate::helpers::{method, RegularMethod, RegularMethods};
 use crate::methods::Cross;
+use alloc::string::String;
+use alloc::string::ToString;
+use alloc::vec::Vec;
+use core::ops::Bound::{Excluded, Included};
 
 /// Ease Of Movement
 ///
@@ -41,6 +46,8 @@ pub struct EaseOfMovement {
 
 impl IndicatorConfig for EaseOfMovement {
 	const NAME: &'static str = "EaseOfMovement";
+	const VALUE_NAMES: &'static [&'static str] = &["value"];
+	const SIGNAL_NAMES: &'static [&'static str] = &["zero_cross"];
 
 	fn validate(&self) -> bool {
 		self.period1 > 1 && self.period1 < PeriodType::MAX && self.period2 >= 1
@@ -62,13 +69,25 @@ impl IndicatorConfig for EaseOfMovement {
 			},
 
 			_ => {
-				return Some(Error::ParameterParse(name.to_string(), value));
+				return Some(Error::UnknownParameter(name.to_string()));
 			}
 		};
 
 		None
 	}
 
+	fn parameters(&self) -> Vec<ParamDescriptor> {
+		let default = Self::default();
+
+		vec![
+			ParamDescriptor::new("period1", ParamKind::Period, self.period1, default.period1)
+				.range(Included(2.), Excluded(PeriodType::MAX as ValueType)),
+			ParamDescriptor::new("period2", ParamKind::Period, self.period2, default.period2)
+				.range(Included(1.), Included(PeriodType::MAX as ValueType)),
+			ParamDescriptor::new("method", ParamKind::Method, self.method, default.method),
+		]
+	}
+
 	fn is_volume_based(&self) -> bool {
 		true
 	}
@@ -76,6 +95,10 @@ impl IndicatorConfig for EaseOfMovement {
 	fn size(&self) -> (u8, u8) {
 		(1, 1)
 	}
+
+	fn min_periods(&self) -> PeriodType {
+		self.period1.saturating_add(self.period2)
+	}
 }
 
 impl<T: OHLCV> IndicatorInitializer<T> for EaseOfMovement {
@@ -110,7 +133,8 @@ impl Default for EaseOfMovement {
 	}
 }
 
-#[derive(Debug)]
+#[derive(Debug, Clone)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 pub struct EaseOfMovementInstance<T: OHLCV> {
 	cfg: EaseOfMovement,
 
@@ -150,4 +174,10 @@ impl<T: OHLCV> IndicatorInstance<T> for EaseOfMovementInstance<T> {
 
 		IndicatorResult::new(&[value], &[signal])
 	}
+
+	fn reset(&mut self, candle: T) -> Result<(), Error> {
+		self.m1.reset(0.)?;
+		self.w.fill(candle);
+		self.cross.reset((0.0, 0.0))
+	}
 }
@@ -0,0 +1,249 @@
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
+
+use crate::core::{Action, Error, Method, PeriodType, Source, ValueType, OHLC};
+use crate::core::{IndicatorConfig, IndicatorInitializer, IndicatorInstance, IndicatorResult};
+use crate::core::{ParamDescriptor, ParamKind};
+use crate::methods::EMA;
+use alloc::string::String;
+use alloc::string::ToString;
+use alloc::vec::Vec;
+use core::ops::Bound::{Excluded, Included};
+
+/// Elder Impulse System
+///
+/// Combines the slope of `EMA` and the slope of [`MACD`](crate::indicators::MACD) histogram:
+///
+/// * green bar, when both `EMA` and `MACD` histogram rise
+/// * red bar, when both `EMA` and `MACD` histogram fall
+/// * blue bar otherwise
+///
+/// ## Links
+///
+/// * <https://school.stockcharts.com/doku.php?id=chart_analysis:elder_impulse_system>
+///
+/// # 3 values
+///
+/// * `impulse` state
+///
+/// `1.0` for green bar, `-1.0` for red bar, `0.0` for blue bar.
+///
+/// * `EMA` value
+///
+/// Range of values is the same as the range of the `source` values.
+///
+/// * `MACD histogram` value
+///
+/// Range in \(-inf; +inf\)
+///
+/// # 1 signal
+///
+/// When `impulse` state changes to green, then returns full buy signal.
+/// When `impulse` state changes to red, then returns full sell signal.
+/// Otherwise no signal
+#[derive(Debug, Clone, Copy)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct ElderImpulse {
+	/// `EMA` period length. Default is `13`.
+	///
+	/// Range in \[1; [`PeriodType::MAX`](crate::core::PeriodType)\)
+	pub period: PeriodType,
+	/// `MACD` fast MA period length. Default is `12`.
+	///
+	/// Range in \[1; `period2`\)
+	pub period1: PeriodType,
+	/// `MACD` slow MA period length. Default is `26`.
+	///
+	/// Range in \(`period1`; [`PeriodType::MAX`](crate::core::PeriodType)\)
+	pub period2: PeriodType,
+	/// `MACD` signal line MA period length. Default is `9`.
+	///
+	/// Range in \[1; [`PeriodType::MAX`](crate::core::PeriodType)\)
+	pub period3: PeriodType,
+	/// Source type of values. Default is [`Close`](crate::core::Source#variant.Close)
+	pub source: Source,
+}
+
+impl IndicatorConfig for ElderImpulse {
+	const NAME: &'static str = "ElderImpulse";
+	const VALUE_NAMES: &'static [&'static str] = &["impulse", "ema", "histogram"];
+	const SIGNAL_NAMES: &'static [&'static str] = &["signal"];
+
+	fn validate(&self) -> bool {
+		self.period > 0
+			&& self.period < PeriodType::MAX
+			&& self.period1 > 0
+			&& self.period1 < self.period2
+			&& self.period2 < PeriodType::MAX
+			&& self.period3 > 0
+			&& self.period3 < PeriodType::MAX
+	}
+
+	fn set(&mut self, name: &str, value: String) -> Option<Error> {
+		match name {
+			"period" => match value.parse() {
+				Err(_) => return Some(Error::ParameterParse(name.to_string(), value.to_string())),
+				Ok(value) => self.period = value,
+			},
+			"period1" => match value.parse() {
+				Err(_) => return Some(Error::ParameterParse(name.to_string(), value.to_string())),
+				Ok(value) => self.period1 = value,
+			},
+			"period2" => match value.parse() {
+				Err(_) => return Some(Error::ParameterParse(name.to_string(), value.to_string())),
+				Ok(value) => self.period2 = value,
+			},
+			"period3" => match value.parse() {
+				Err(_) => return Some(Error::ParameterParse(name.to_string(), value.to_string())),
+				Ok(value) => self.period3 = value,
+			},
+			"source" => match value.parse() {
+				Err(_) => return Some(Error::ParameterParse(name.to_string(), value.to_string())),
+				Ok(value) => self.source = value,
+			},
+
+			_ => {
+				return Some(Error::UnknownParameter(name.to_string()));
+			}
+		};
+
+		None
+	}
+
+	fn parameters(&self) -> Vec<ParamDescriptor> {
+		let default = Self::default();
+
+		vec![
+			ParamDescriptor::new("period", ParamKind::Period, self.period, default.period)
+				.range(Included(1.), Excluded(PeriodType::MAX as ValueType)),
+			ParamDescriptor::new("period1", ParamKind::Period, self.period1, default.period1)
+				.range(Included(1.), Excluded(self.period2 as ValueType)),
+			ParamDescriptor::new("period2", ParamKind::Period, self.period2, default.period2)
+				.range(
+					Excluded(self.period1 as ValueType),
+					Excluded(PeriodType::MAX as ValueType),
+				),
+			ParamDescriptor::new("period3", ParamKind::Period, self.period3, default.period3)
+				.range(Included(1.), Excluded(PeriodType::MAX as ValueType)),
+			ParamDescriptor::new("source", ParamKind::Source, self.source, default.source),
+		]
+	}
+
+	fn size(&self) -> (u8, u8) {
+		(3, 1)
+	}
+
+	fn min_periods(&self) -> PeriodType {
+		self.period1
+			.max(self.period2)
+			.saturating_add(self.period3)
+			.saturating_sub(1)
+			.max(self.period)
+			.saturating_add(1)
+	}
+}
+
+impl<T: OHLC> IndicatorInitializer<T> for ElderImpulse {
+	type Instance = ElderImpulseInstance;
+
+	fn init(self, candle: T) -> Result<Self::Instance, Error>
+	where
+		Self: Sized,
+	{
+		if !self.validate() {
+			return Err(Error::WrongConfig);
+		}
+
+		let cfg = self;
+		let src = candle.source(cfg.source);
+
+		Ok(Self::Instance {
+			ema: EMA::new(cfg.period, src)?,
+			ma1: EMA::new(cfg.period1, src)?,
+			ma2: EMA::new(cfg.period2, src)?,
+			ma3: EMA::new(cfg.period3, 0.)?,
+			prev_ema: src,
+			prev_histogram: 0.,
+			impulse: 0,
+			cfg,
+		})
+	}
+}
+
+impl Default for ElderImpulse {
+	fn default() -> Self {
+		Self {
+			period: 13,
+			period1: 12,
+			period2: 26,
+			period3: 9,
+			source: Source::Close,
+		}
+	}
+}
+
+/// Elder Impulse System state structure
+#[derive(Debug, Clone, Copy)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct ElderImpulseInstance {
+	cfg: ElderImpulse,
+
+	ema: EMA,
+	ma1: EMA,
+	ma2: EMA,
+	ma3: EMA,
+	prev_ema: ValueType,
+	prev_histogram: ValueType,
+	impulse: i8,
+}
+
+impl<T: OHLC> IndicatorInstance<T> for ElderImpulseInstance {
+	type Config = ElderImpulse;
+
+	fn config(&self) -> &Self::Config {
+		&self.cfg
+	}
+
+	fn next(&mut self, candle: T) -> IndicatorResult {
+		let src = candle.source(self.cfg.source);
+
+		let ema = self.ema.next(src);
+		let macd = self.ma1.next(src) - self.ma2.next(src);
+		let histogram = macd - self.ma3.next(macd);
+
+		let ema_slope = (ema > self.prev_ema) as i8 - (ema < self.prev_ema) as i8;
+		let histogram_slope =
+			(histogram > self.prev_histogram) as i8 - (histogram < self.prev_histogram) as i8;
+
+		self.prev_ema = ema;
+		self.prev_histogram = histogram;
+
+		let impulse = if ema_slope == histogram_slope {
+			ema_slope
+		} else {
+			0
+		};
+
+		let signal = (impulse != self.impulse) as i8 * impulse;
+		self.impulse = impulse;
+
+		IndicatorResult::new(
+			&[impulse as ValueType, ema, histogram],
+			&[Action::from(signal)],
+		)
+	}
+
+	fn reset(&mut self, candle: T) -> Result<(), Error> {
+		let src = candle.source(self.cfg.source);
+
+		self.ema.reset(src)?;
+		self.ma1.reset(src)?;
+		self.ma2.reset(src)?;
+		self.ma3.reset(0.)?;
+		self.prev_ema = src;
+		self.prev_histogram = 0.;
+		self.impulse = 0;
+
+		Ok(())
+	}
+}
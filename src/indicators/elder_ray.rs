@@ -0,0 +1,176 @@
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
+
+use crate::core::{Action, Error, PeriodType, Source, ValueType, OHLC};
+use crate::core::{IndicatorConfig, IndicatorInitializer, IndicatorInstance, IndicatorResult};
+use crate::core::{ParamDescriptor, ParamKind};
+use crate::helpers::{method, RegularMethod, RegularMethods};
+use alloc::string::String;
+use alloc::string::ToString;
+use alloc::vec::Vec;
+use core::ops::Bound::{Excluded, Included};
+
+/// Elder Ray \(Bull/Bear Power\)
+///
+/// ## Links
+///
+/// * <https://www.investopedia.com/terms/e/elderray.asp>
+///
+/// # 2 values
+///
+/// * `bull power` value \(*high* − `MA`\)
+/// * `bear power` value \(*low* − `MA`\)
+///
+/// Range in \(-inf; +inf\)
+///
+/// # 1 signal
+///
+/// When `MA` is rising and `bear power` is negative but rising, then returns full buy signal.
+/// When `MA` is falling and `bull power` is positive but falling, then returns full sell signal.
+/// Otherwise no signal
+#[derive(Debug, Clone, Copy)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct ElderRay {
+	/// MA period length. Default is `13`.
+	///
+	/// Range in \[1; [`PeriodType::MAX`](crate::core::PeriodType)\)
+	pub period: PeriodType,
+	/// MA method. Default is [`EMA`](crate::methods::EMA).
+	pub method: RegularMethods,
+	/// Source type of values for MA. Default is [`Close`](crate::core::Source#variant.Close)
+	pub source: Source,
+}
+
+impl IndicatorConfig for ElderRay {
+	const NAME: &'static str = "ElderRay";
+	const VALUE_NAMES: &'static [&'static str] = &["bull_power", "bear_power"];
+	const SIGNAL_NAMES: &'static [&'static str] = &["signal"];
+
+	fn validate(&self) -> bool {
+		self.period > 0 && self.period < PeriodType::MAX
+	}
+
+	fn set(&mut self, name: &str, value: String) -> Option<Error> {
+		match name {
+			"period" => match value.parse() {
+				Err(_) => return Some(Error::ParameterParse(name.to_string(), value.to_string())),
+				Ok(value) => self.period = value,
+			},
+			"method" => match value.parse() {
+				Err(_) => return Some(Error::ParameterParse(name.to_string(), value.to_string())),
+				Ok(value) => self.method = value,
+			},
+			"source" => match value.parse() {
+				Err(_) => return Some(Error::ParameterParse(name.to_string(), value.to_string())),
+				Ok(value) => self.source = value,
+			},
+
+			_ => {
+				return Some(Error::UnknownParameter(name.to_string()));
+			}
+		};
+
+		None
+	}
+
+	fn parameters(&self) -> Vec<ParamDescriptor> {
+		let default = Self::default();
+
+		vec![
+			ParamDescriptor::new("period", ParamKind::Period, self.period, default.period)
+				.range(Included(1.), Excluded(PeriodType::MAX as ValueType)),
+			ParamDescriptor::new("method", ParamKind::Method, self.method, default.method),
+			ParamDescriptor::new("source", ParamKind::Source, self.source, default.source),
+		]
+	}
+
+	fn size(&self) -> (u8, u8) {
+		(2, 1)
+	}
+}
+
+impl<T: OHLC> IndicatorInitializer<T> for ElderRay {
+	type Instance = ElderRayInstance;
+
+	fn init(self, candle: T) -> Result<Self::Instance, Error>
+	where
+		Self: Sized,
+	{
+		if !self.validate() {
+			return Err(Error::WrongConfig);
+		}
+
+		let cfg = self;
+		let src = candle.source(cfg.source);
+
+		Ok(Self::Instance {
+			ma: method(cfg.method, cfg.period, src)?,
+			prev_ma: src,
+			prev_bull: candle.high() - src,
+			prev_bear: candle.low() - src,
+			cfg,
+		})
+	}
+}
+
+impl Default for ElderRay {
+	fn default() -> Self {
+		Self {
+			period: 13,
+			method: RegularMethods::EMA,
+			source: Source::Close,
+		}
+	}
+}
+
+/// Elder Ray state structure
+#[derive(Debug, Clone)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct ElderRayInstance {
+	cfg: ElderRay,
+
+	ma: RegularMethod,
+	prev_ma: ValueType,
+	prev_bull: ValueType,
+	prev_bear: ValueType,
+}
+
+impl<T: OHLC> IndicatorInstance<T> for ElderRayInstance {
+	type Config = ElderRay;
+
+	fn config(&self) -> &Self::Config {
+		&self.cfg
+	}
+
+	fn next(&mut self, candle: T) -> IndicatorResult {
+		let ma = self.ma.next(candle.source(self.cfg.source));
+
+		let bull = candle.high() - ma;
+		let bear = candle.low() - ma;
+
+		let signal = if ma > self.prev_ma && bear < 0. && bear > self.prev_bear {
+			Action::BUY_ALL
+		} else if ma < self.prev_ma && bull > 0. && bull < self.prev_bull {
+			Action::SELL_ALL
+		} else {
+			Action::None
+		};
+
+		self.prev_ma = ma;
+		self.prev_bull = bull;
+		self.prev_bear = bear;
+
+		IndicatorResult::new(&[bull, bear], &[signal])
+	}
+
+	fn reset(&mut self, candle: T) -> Result<(), Error> {
+		let src = candle.source(self.cfg.source);
+
+		self.ma.reset(src)?;
+		self.prev_ma = src;
+		self.prev_bull = candle.high() - src;
+		self.prev_bear = candle.low() - src;
+
+		Ok(())
+	}
+}
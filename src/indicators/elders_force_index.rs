@@ -3,8 +3,13 @@ use serde::{Deserialize, Serialize};
 
 use crate::core::{Error, Method, PeriodType, Source, ValueType, Window, OHLC, OHLCV};
 use crate::core::{IndicatorConfig, IndicatorInitializer, IndicatorInstance, IndicatorResult};
+use crate::core::{ParamDescriptor, ParamKind};
 use crate::helpers::{method, RegularMethod, RegularMethods};
 use crate::methods::Cross;
+use alloc::string::String;
+use alloc::string::ToString;
+use alloc::vec::Vec;
+use core::ops::Bound::{Excluded, Included};
 
 /// Elders Force Index
 ///
@@ -41,6 +46,8 @@ pub struct EldersForceIndex {
 
 impl IndicatorConfig for EldersForceIndex {
 	const NAME: &'static str = "EldersForceIndex";
+	const VALUE_NAMES: &'static [&'static str] = &["value"];
+	const SIGNAL_NAMES: &'static [&'static str] = &["zero_cross"];
 
 	fn validate(&self) -> bool {
 		self.period1 > 1 && self.period2 >= 1
@@ -66,13 +73,26 @@ impl IndicatorConfig for EldersForceIndex {
 			},
 
 			_ => {
-				return Some(Error::ParameterParse(name.to_string(), value));
+				return Some(Error::UnknownParameter(name.to_string()));
 			}
 		};
 
 		None
 	}
 
+	fn parameters(&self) -> Vec<ParamDescriptor> {
+		let default = Self::default();
+
+		vec![
+			ParamDescriptor::new("period1", ParamKind::Period, self.period1, default.period1)
+				.range(Included(2.), Excluded(PeriodType::MAX as ValueType)),
+			ParamDescriptor::new("period2", ParamKind::Period, self.period2, default.period2)
+				.range(Included(1.), Excluded(PeriodType::MAX as ValueType)),
+			ParamDescriptor::new("method", ParamKind::Method, self.method, default.method),
+			ParamDescriptor::new("source", ParamKind::Source, self.source, default.source),
+		]
+	}
+
 	fn is_volume_based(&self) -> bool {
 		true
 	}
@@ -80,6 +100,10 @@ impl IndicatorConfig for EldersForceIndex {
 	fn size(&self) -> (u8, u8) {
 		(1, 1)
 	}
+
+	fn min_periods(&self) -> PeriodType {
+		self.period1.saturating_add(self.period2)
+	}
 }
 
 impl<T: OHLCV> IndicatorInitializer<T> for EldersForceIndex {
@@ -115,7 +139,8 @@ impl Default for EldersForceIndex {
 	}
 }
 
-#[derive(Debug)]
+#[derive(Debug, Clone)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 pub struct EldersForceIndexInstance<T: OHLCV> {
 	cfg: EldersForceIndex,
 
@@ -145,4 +170,13 @@ impl<T: OHLCV> IndicatorInstance<T> for EldersForceIndexInstance<T> {
 
 		IndicatorResult::new(&[value], &[signal])
 	}
+
+	fn reset(&mut self, candle: T) -> Result<(), Error> {
+		self.ma.reset(0.)?;
+		self.window.fill(candle);
+		self.vol_sum = candle.volume() * self.cfg.period2 as ValueType;
+		self.cross_over = Cross::default();
+
+		Ok(())
+	}
 }
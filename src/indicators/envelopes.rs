@@ -3,7 +3,12 @@ use serde::{Deserialize, Serialize};
 
 use crate::core::{Action, Error, PeriodType, Source, ValueType, OHLC};
 use crate::core::{IndicatorConfig, IndicatorInitializer, IndicatorInstance, IndicatorResult};
+use crate::core::{ParamDescriptor, ParamKind};
 use crate::helpers::{method, RegularMethod, RegularMethods};
+use alloc::string::String;
+use alloc::string::ToString;
+use alloc::vec::Vec;
+use core::ops::Bound::{Excluded, Included, Unbounded};
 
 /// Envelopes
 ///
@@ -43,6 +48,8 @@ pub struct Envelopes {
 
 impl IndicatorConfig for Envelopes {
 	const NAME: &'static str = "Envelopes";
+	const VALUE_NAMES: &'static [&'static str] = &["upper", "lower", "source2"];
+	const SIGNAL_NAMES: &'static [&'static str] = &["signal"];
 
 	fn validate(&self) -> bool {
 		self.k > 0.0 && self.period > 1
@@ -72,13 +79,27 @@ impl IndicatorConfig for Envelopes {
 			},
 
 			_ => {
-				return Some(Error::ParameterParse(name.to_string(), value));
+				return Some(Error::UnknownParameter(name.to_string()));
 			}
 		};
 
 		None
 	}
 
+	fn parameters(&self) -> Vec<ParamDescriptor> {
+		let default = Self::default();
+
+		vec![
+			ParamDescriptor::new("period", ParamKind::Period, self.period, default.period)
+				.range(Included(2.), Excluded(PeriodType::MAX as ValueType)),
+			ParamDescriptor::new("k", ParamKind::Float, self.k, default.k)
+				.range(Excluded(0.), Unbounded),
+			ParamDescriptor::new("method", ParamKind::Method, self.method, default.method),
+			ParamDescriptor::new("source", ParamKind::Source, self.source, default.source),
+			ParamDescriptor::new("source2", ParamKind::Source, self.source2, default.source2),
+		]
+	}
+
 	fn size(&self) -> (u8, u8) {
 		(3, 1)
 	}
@@ -118,7 +139,8 @@ impl Default for Envelopes {
 	}
 }
 
-#[derive(Debug)]
+#[derive(Debug, Clone)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 pub struct EnvelopesInstance {
 	cfg: Envelopes,
 
@@ -153,4 +175,8 @@ impl<T: OHLC> IndicatorInstance<T> for EnvelopesInstance {
 
 		IndicatorResult::new(&[value1, value2, src2], &[Action::from(signal)])
 	}
+
+	fn reset(&mut self, candle: T) -> Result<(), Error> {
+		self.ma.reset(candle.source(self.cfg.source))
+	}
 }
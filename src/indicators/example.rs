@@ -5,8 +5,15 @@
 //! The idea is to find signals where price of timeseries crosses this config's `price` for the last `period` frames.
 
 // Some core structures and traits
-use crate::core::{Action, Error, IndicatorResult, PeriodType, Source, ValueType};
+#[cfg(feature = "serde")]
+use crate::core::Source;
+use crate::core::{Action, Error, IndicatorResult, PeriodType, ValueType};
+use crate::core::{ParamDescriptor, ParamKind};
 use crate::prelude::*;
+use alloc::string::String;
+use alloc::string::ToString;
+use alloc::vec::Vec;
+use core::ops::Bound::{Excluded, Unbounded};
 
 // Cross method for searching crossover between price and our value
 use crate::methods::Cross;
@@ -28,6 +35,7 @@ use serde::{Deserialize, Serialize};
 pub struct Example {
 	price: ValueType,
 	period: PeriodType,
+	#[cfg(feature = "serde")]
 	source: Source,
 }
 
@@ -35,6 +43,10 @@ pub struct Example {
 impl IndicatorConfig for Example {
 	const NAME: &'static str = "Example";
 
+	/// Names of the raw values and the signals in the same order as they are returned in `IndicatorResult`
+	const VALUE_NAMES: &'static [&'static str] = &["close"];
+	const SIGNAL_NAMES: &'static [&'static str] = &["signal", "other_signal"];
+
 	/// Validates config values to be consistent
 	fn validate(&self) -> bool {
 		self.price > 0.0
@@ -49,13 +61,23 @@ impl IndicatorConfig for Example {
 			},
 
 			_ => {
-				return Some(Error::ParameterParse(name.to_string(), value));
+				return Some(Error::UnknownParameter(name.to_string()));
 			}
 		};
 
 		None
 	}
 
+	/// Describes every parameter, which may be set by `set` method
+	fn parameters(&self) -> Vec<ParamDescriptor> {
+		let default = Self::default();
+
+		vec![
+			ParamDescriptor::new("price", ParamKind::Float, self.price, default.price)
+				.range(Excluded(0.0), Unbounded),
+		]
+	}
+
 	/// Our indicator will return single raw value and two signals
 	fn size(&self) -> (u8, u8) {
 		(1, 2)
@@ -90,6 +112,7 @@ impl Default for Example {
 		Self {
 			price: 2.0,
 			period: 3,
+			#[cfg(feature = "serde")]
 			source: Source::Close,
 		}
 	}
@@ -101,6 +124,7 @@ impl Default for Example {
 ///
 /// See source code for the full example
 #[derive(Debug, Clone, Copy)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 pub struct ExampleInstance {
 	cfg: Example,
 
@@ -144,4 +168,12 @@ impl<T: OHLC> IndicatorInstance<T> for ExampleInstance {
 
 		IndicatorResult::new(&[candle.close()], &[signal, some_other_signal])
 	}
+
+	fn reset(&mut self, _candle: T) -> Result<(), Error> {
+		self.cross = Cross::default();
+		self.last_signal = Action::None;
+		self.last_signal_position = 0;
+
+		Ok(())
+	}
 }
@@ -3,8 +3,16 @@ use serde::{Deserialize, Serialize};
 
 use crate::core::{Error, Method, PeriodType, Source, ValueType, OHLC};
 use crate::core::{IndicatorConfig, IndicatorInitializer, IndicatorInstance, IndicatorResult};
+use crate::core::{ParamDescriptor, ParamKind};
 use crate::helpers::{method, RegularMethod, RegularMethods};
-use crate::methods::{Cross, Highest, Lowest};
+use crate::methods::{Cross, Stochastic};
+use alloc::string::String;
+use alloc::string::ToString;
+use alloc::vec::Vec;
+use core::ops::Bound::{Excluded, Included, Unbounded};
+
+#[cfg(not(feature = "std"))]
+use crate::core::Float as _;
 
 // https://www.investopedia.com/terms/f/fisher-transform.asp
 // FT = 1/2 * ln((1+x)/(1-x)) = arctanh(x)
@@ -50,6 +58,8 @@ pub struct FisherTransform {
 
 impl IndicatorConfig for FisherTransform {
 	const NAME: &'static str = "FisherTransform";
+	const VALUE_NAMES: &'static [&'static str] = &["value", "signal_line"];
+	const SIGNAL_NAMES: &'static [&'static str] = &["zero_cross", "signal_line_cross"];
 
 	fn validate(&self) -> bool {
 		self.period1 > 1 && self.period2 > 1 && self.zone > 0.
@@ -79,16 +89,35 @@ impl IndicatorConfig for FisherTransform {
 			},
 
 			_ => {
-				return Some(Error::ParameterParse(name.to_string(), value));
+				return Some(Error::UnknownParameter(name.to_string()));
 			}
 		};
 
 		None
 	}
 
+	fn parameters(&self) -> Vec<ParamDescriptor> {
+		let default = Self::default();
+
+		vec![
+			ParamDescriptor::new("period1", ParamKind::Period, self.period1, default.period1)
+				.range(Included(2.), Excluded(PeriodType::MAX as ValueType)),
+			ParamDescriptor::new("period2", ParamKind::Period, self.period2, default.period2)
+				.range(Included(2.), Excluded(PeriodType::MAX as ValueType)),
+			ParamDescriptor::new("zone", ParamKind::Float, self.zone, default.zone)
+				.range(Excluded(0.), Unbounded),
+			ParamDescriptor::new("method", ParamKind::Method, self.method, default.method),
+			ParamDescriptor::new("source", ParamKind::Source, self.source, default.source),
+		]
+	}
+
 	fn size(&self) -> (u8, u8) {
 		(2, 2)
 	}
+
+	fn min_periods(&self) -> PeriodType {
+		self.period1.saturating_add(self.period2).saturating_sub(1)
+	}
 }
 
 impl<T: OHLC> IndicatorInitializer<T> for FisherTransform {
@@ -107,8 +136,7 @@ impl<T: OHLC> IndicatorInitializer<T> for FisherTransform {
 
 		Ok(Self::Instance {
 			ma1: method(cfg.method, cfg.period2, 0.)?,
-			highest: Highest::new(cfg.period1, src)?,
-			lowest: Lowest::new(cfg.period1, src)?,
+			stochastic: Stochastic::new(cfg.period1, src)?,
 			cross: Cross::default(),
 			cross_ma: Cross::default(),
 			prev_value: 0.,
@@ -130,13 +158,13 @@ impl Default for FisherTransform {
 	}
 }
 
-#[derive(Debug)]
+#[derive(Debug, Clone)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 pub struct FisherTransformInstance {
 	cfg: FisherTransform,
 
 	ma1: RegularMethod,
-	highest: Highest,
-	lowest: Lowest,
+	stochastic: Stochastic,
 	cross: Cross,
 	cross_ma: Cross,
 	prev_value: ValueType,
@@ -160,19 +188,19 @@ impl<T: OHLC> IndicatorInstance<T> for FisherTransformInstance {
 	fn next(&mut self, candle: T) -> IndicatorResult {
 		let src = candle.source(self.cfg.source);
 
-		// first we need to find MAX and MIN values for last `period1` prices
-		let highest = self.highest.next(src);
-		let lowest = self.lowest.next(src);
-
-		// we need to check division by zero, so we can really just check if `h` is equal to `l` without using any kind of round error checks
-		let fisher_transform = if highest.to_bits() == lowest.to_bits() {
-			0.
-		} else {
-			// converting `SRC` into a value in range [-1; 1]
-			let x = bound_value((src - lowest) / (highest - lowest) * 2. - 1.);
-			// calculating fisher transform value
-			x.atanh()
-		};
+		// first we need to find position of `SRC` between MAX and MIN values for last `period1` prices
+		let k = self.stochastic.next(src);
+
+		// when MAX is equal to MIN, there is no range to transform
+		let fisher_transform =
+			if self.stochastic.get_highest().to_bits() == self.stochastic.get_lowest().to_bits() {
+				0.
+			} else {
+				// converting `SRC` into a value in range [-1; 1]
+				let x = bound_value(k.mul_add(2., -1.));
+				// calculating fisher transform value
+				x.atanh()
+			};
 
 		let cumulative = self.prev_value.mul_add(0.5, fisher_transform);
 
@@ -210,4 +238,15 @@ impl<T: OHLC> IndicatorInstance<T> for FisherTransformInstance {
 
 		IndicatorResult::new(&[cumulative, signal_line], &[s1.into(), s2.into()])
 	}
+
+	fn reset(&mut self, candle: T) -> Result<(), Error> {
+		self.ma1.reset(0.)?;
+		self.stochastic.reset(candle.source(self.cfg.source))?;
+		self.cross = Cross::default();
+		self.cross_ma = Cross::default();
+		self.prev_value = 0.;
+		self.last_reverse = 0;
+
+		Ok(())
+	}
 }
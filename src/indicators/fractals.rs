@@ -0,0 +1,179 @@
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
+
+use crate::core::{Error, Method, PeriodType, ValueType, Window, OHLC};
+use crate::core::{IndicatorConfig, IndicatorInitializer, IndicatorInstance, IndicatorResult};
+use crate::core::{ParamDescriptor, ParamKind};
+use crate::methods::{CrossAbove, CrossUnder};
+use alloc::string::String;
+use alloc::string::ToString;
+use alloc::vec::Vec;
+use core::ops::Bound::{Excluded, Included};
+
+/// Williams Fractals
+///
+/// Up fractal is a candle whose *high* is strictly greater than *highs* of `period` candles on each side.
+/// Down fractal is a candle whose *low* is strictly lower than *lows* of `period` candles on each side.
+///
+/// Fractal is confirmed only after `period` candles to the right of it are closed.
+///
+/// ## Links
+///
+/// * <https://www.investopedia.com/terms/f/fractal.asp>
+///
+/// # 2 values
+///
+/// * `up fractal` value \(*high* of the latest confirmed up fractal\)
+/// * `down fractal` value \(*low* of the latest confirmed down fractal\)
+///
+/// Range of values is the same as the range of the price values.
+///
+/// # 1 signal
+///
+/// When *close* price crosses `up fractal` upwards, then returns full buy signal.
+/// When *close* price crosses `down fractal` downwards, then returns full sell signal.
+/// Otherwise no signal
+#[derive(Debug, Clone, Copy)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct Fractals {
+	/// Count of candles on each side of the fractal. Default is `2`.
+	///
+	/// Range in \[1; ([`PeriodType::MAX`](crate::core::PeriodType) - 1) / 2\)
+	pub period: PeriodType,
+}
+
+impl IndicatorConfig for Fractals {
+	const NAME: &'static str = "Fractals";
+	const VALUE_NAMES: &'static [&'static str] = &["up", "down"];
+	const SIGNAL_NAMES: &'static [&'static str] = &["signal"];
+
+	fn validate(&self) -> bool {
+		self.period > 0 && self.period < (PeriodType::MAX - 1) / 2
+	}
+
+	fn set(&mut self, name: &str, value: String) -> Option<Error> {
+		match name {
+			"period" => match value.parse() {
+				Err(_) => return Some(Error::ParameterParse(name.to_string(), value.to_string())),
+				Ok(value) => self.period = value,
+			},
+
+			_ => {
+				return Some(Error::UnknownParameter(name.to_string()));
+			}
+		};
+
+		None
+	}
+
+	fn parameters(&self) -> Vec<ParamDescriptor> {
+		let default = Self::default();
+
+		vec![
+			ParamDescriptor::new("period", ParamKind::Period, self.period, default.period).range(
+				Included(1.),
+				Excluded(((PeriodType::MAX - 1) / 2) as ValueType),
+			),
+		]
+	}
+
+	fn size(&self) -> (u8, u8) {
+		(2, 1)
+	}
+
+	fn min_periods(&self) -> PeriodType {
+		self.period.saturating_mul(2).saturating_add(1)
+	}
+}
+
+impl<T: OHLC> IndicatorInitializer<T> for Fractals {
+	type Instance = FractalsInstance;
+
+	fn init(self, candle: T) -> Result<Self::Instance, Error>
+	where
+		Self: Sized,
+	{
+		if !self.validate() {
+			return Err(Error::WrongConfig);
+		}
+
+		let cfg = self;
+		let (high, low) = (candle.high(), candle.low());
+
+		Ok(Self::Instance {
+			window: Window::new(cfg.period * 2 + 1, (high, low)),
+			up: high,
+			down: low,
+			cross_above: CrossAbove::new((), (candle.close(), high))?,
+			cross_under: CrossUnder::new((), (candle.close(), low))?,
+			cfg,
+		})
+	}
+}
+
+impl Default for Fractals {
+	fn default() -> Self {
+		Self { period: 2 }
+	}
+}
+
+/// Williams Fractals state structure
+#[derive(Debug, Clone)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct FractalsInstance {
+	cfg: Fractals,
+
+	window: Window<(ValueType, ValueType)>,
+	up: ValueType,
+	down: ValueType,
+	cross_above: CrossAbove,
+	cross_under: CrossUnder,
+}
+
+impl<T: OHLC> IndicatorInstance<T> for FractalsInstance {
+	type Config = Fractals;
+
+	fn config(&self) -> &Self::Config {
+		&self.cfg
+	}
+
+	fn next(&mut self, candle: T) -> IndicatorResult {
+		self.window.push((candle.high(), candle.low()));
+
+		let middle = self.cfg.period as usize;
+		let (high, low) = self.window.iter().nth(middle).unwrap_or_default();
+
+		let (is_up, is_down) = self
+			.window
+			.iter()
+			.enumerate()
+			.filter(|&(i, _)| i != middle)
+			.fold((true, true), |(is_up, is_down), (_, (h, l))| {
+				(is_up && h < high, is_down && l > low)
+			});
+
+		if is_up {
+			self.up = high;
+		}
+
+		if is_down {
+			self.down = low;
+		}
+
+		let close = candle.close();
+		let signal =
+			self.cross_above.next((close, self.up)) - self.cross_under.next((close, self.down));
+
+		IndicatorResult::new(&[self.up, self.down], &[signal])
+	}
+
+	fn reset(&mut self, candle: T) -> Result<(), Error> {
+		let (high, low) = (candle.high(), candle.low());
+
+		self.window.fill((high, low));
+		self.up = high;
+		self.down = low;
+		self.cross_above.reset((candle.close(), high))?;
+		self.cross_under.reset((candle.close(), low))
+	}
+}
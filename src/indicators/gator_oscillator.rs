@@ -0,0 +1,310 @@
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
+
+use super::{Alligator, AlligatorInstance};
+use crate::core::{Action, Error, PeriodType, Source, ValueType, OHLC};
+use crate::core::{IndicatorConfig, IndicatorInitializer, IndicatorInstance, IndicatorResult};
+use crate::core::{ParamDescriptor, ParamKind};
+use crate::helpers::RegularMethods;
+use alloc::string::String;
+use alloc::string::ToString;
+use alloc::vec::Vec;
+use core::ops::Bound::{Excluded, Included};
+
+/// Gator Oscillator
+///
+/// Based on the [`Alligator`] lines.
+///
+/// ## Links
+///
+/// * <https://www.investopedia.com/terms/g/gator-oscillator.asp>
+///
+/// # 2 values
+///
+/// * `upper` histogram value \(absolute distance between `jaw` and `teeth`\)
+///
+/// Range in \[0.0; +inf\)
+///
+/// * `lower` histogram value \(negative absolute distance between `teeth` and `lips`\)
+///
+/// Range in \(-inf; 0.0\]
+///
+/// # 2 signals
+///
+/// * Expanding phase signal
+///
+/// When both histograms start expanding \(the alligator is *eating*\), then returns full buy signal if `lips` are above `jaw`
+/// or full sell signal if `lips` are below `jaw`.
+/// Otherwise no signal
+///
+/// * Contracting phase signal
+///
+/// When both histograms start contracting \(the alligator is *sated*\) after an expanding phase,
+/// then returns full signal opposite to the trend: sell if `lips` are above `jaw`, buy if `lips` are below `jaw`.
+/// Otherwise no signal
+#[derive(Debug, Clone, Copy)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct GatorOscillator {
+	/// Jaw MA period length. Default is `13`.
+	///
+	/// Range in \[1; [`PeriodType::MAX`](crate::core::PeriodType)\)
+	pub jaw_period: PeriodType,
+	/// Jaw line offset. Default is `8`.
+	///
+	/// Range in \[1; [`PeriodType::MAX`](crate::core::PeriodType)\)
+	pub jaw_offset: PeriodType,
+	/// Teeth MA period length. Default is `8`.
+	///
+	/// Range in \[1; [`PeriodType::MAX`](crate::core::PeriodType)\)
+	pub teeth_period: PeriodType,
+	/// Teeth line offset. Default is `5`.
+	///
+	/// Range in \[1; [`PeriodType::MAX`](crate::core::PeriodType)\)
+	pub teeth_offset: PeriodType,
+	/// Lips MA period length. Default is `5`.
+	///
+	/// Range in \[1; [`PeriodType::MAX`](crate::core::PeriodType)\)
+	pub lips_period: PeriodType,
+	/// Lips line offset. Default is `3`.
+	///
+	/// Range in \[1; [`PeriodType::MAX`](crate::core::PeriodType)\)
+	pub lips_offset: PeriodType,
+	/// MA method. Default is [`RMA`](crate::methods::RMA) \(a.k.a. `SMMA`\).
+	pub method: RegularMethods,
+	/// Source type of values. Default is [`HL2`](crate::core::Source#variant.HL2)
+	pub source: Source,
+}
+
+impl GatorOscillator {
+	const fn alligator(self) -> Alligator {
+		Alligator {
+			jaw_period: self.jaw_period,
+			jaw_offset: self.jaw_offset,
+			teeth_period: self.teeth_period,
+			teeth_offset: self.teeth_offset,
+			lips_period: self.lips_period,
+			lips_offset: self.lips_offset,
+			method: self.method,
+			source: self.source,
+		}
+	}
+}
+
+impl IndicatorConfig for GatorOscillator {
+	const NAME: &'static str = "GatorOscillator";
+	const VALUE_NAMES: &'static [&'static str] = &["upper", "lower"];
+	const SIGNAL_NAMES: &'static [&'static str] = &["expanding", "contracting"];
+
+	fn validate(&self) -> bool {
+		self.alligator().validate()
+	}
+
+	fn set(&mut self, name: &str, value: String) -> Option<Error> {
+		match name {
+			"jaw_period" => match value.parse() {
+				Err(_) => return Some(Error::ParameterParse(name.to_string(), value.to_string())),
+				Ok(value) => self.jaw_period = value,
+			},
+			"jaw_offset" => match value.parse() {
+				Err(_) => return Some(Error::ParameterParse(name.to_string(), value.to_string())),
+				Ok(value) => self.jaw_offset = value,
+			},
+			"teeth_period" => match value.parse() {
+				Err(_) => return Some(Error::ParameterParse(name.to_string(), value.to_string())),
+				Ok(value) => self.teeth_period = value,
+			},
+			"teeth_offset" => match value.parse() {
+				Err(_) => return Some(Error::ParameterParse(name.to_string(), value.to_string())),
+				Ok(value) => self.teeth_offset = value,
+			},
+			"lips_period" => match value.parse() {
+				Err(_) => return Some(Error::ParameterParse(name.to_string(), value.to_string())),
+				Ok(value) => self.lips_period = value,
+			},
+			"lips_offset" => match value.parse() {
+				Err(_) => return Some(Error::ParameterParse(name.to_string(), value.to_string())),
+				Ok(value) => self.lips_offset = value,
+			},
+			"method" => match value.parse() {
+				Err(_) => return Some(Error::ParameterParse(name.to_string(), value.to_string())),
+				Ok(value) => self.method = value,
+			},
+			"source" => match value.parse() {
+				Err(_) => return Some(Error::ParameterParse(name.to_string(), value.to_string())),
+				Ok(value) => self.source = value,
+			},
+
+			_ => {
+				return Some(Error::UnknownParameter(name.to_string()));
+			}
+		};
+
+		None
+	}
+
+	fn parameters(&self) -> Vec<ParamDescriptor> {
+		let default = Self::default();
+
+		vec![
+			ParamDescriptor::new(
+				"jaw_period",
+				ParamKind::Period,
+				self.jaw_period,
+				default.jaw_period,
+			)
+			.range(Included(1.), Excluded(PeriodType::MAX as ValueType)),
+			ParamDescriptor::new(
+				"jaw_offset",
+				ParamKind::Period,
+				self.jaw_offset,
+				default.jaw_offset,
+			)
+			.range(Included(1.), Excluded(PeriodType::MAX as ValueType)),
+			ParamDescriptor::new(
+				"teeth_period",
+				ParamKind::Period,
+				self.teeth_period,
+				default.teeth_period,
+			)
+			.range(Included(1.), Excluded(PeriodType::MAX as ValueType)),
+			ParamDescriptor::new(
+				"teeth_offset",
+				ParamKind::Period,
+				self.teeth_offset,
+				default.teeth_offset,
+			)
+			.range(Included(1.), Excluded(PeriodType::MAX as ValueType)),
+			ParamDescriptor::new(
+				"lips_period",
+				ParamKind::Period,
+				self.lips_period,
+				default.lips_period,
+			)
+			.range(Included(1.), Excluded(PeriodType::MAX as ValueType)),
+			ParamDescriptor::new(
+				"lips_offset",
+				ParamKind::Period,
+				self.lips_offset,
+				default.lips_offset,
+			)
+			.range(Included(1.), Excluded(PeriodType::MAX as ValueType)),
+			ParamDescriptor::new("method", ParamKind::Method, self.method, default.method),
+			ParamDescriptor::new("source", ParamKind::Source, self.source, default.source),
+		]
+	}
+
+	fn size(&self) -> (u8, u8) {
+		(2, 2)
+	}
+
+	fn min_periods(&self) -> PeriodType {
+		(self.jaw_period.saturating_add(self.jaw_offset))
+			.max(self.teeth_period.saturating_add(self.teeth_offset))
+			.max(self.lips_period.saturating_add(self.lips_offset))
+	}
+}
+
+impl<T: OHLC> IndicatorInitializer<T> for GatorOscillator {
+	type Instance = GatorOscillatorInstance;
+
+	fn init(self, candle: T) -> Result<Self::Instance, Error>
+	where
+		Self: Sized,
+	{
+		if !self.validate() {
+			return Err(Error::WrongConfig);
+		}
+
+		let cfg = self;
+
+		Ok(Self::Instance {
+			alligator: cfg.alligator().init(candle)?,
+			prev_upper: 0.,
+			prev_lower: 0.,
+			phase: 0,
+			cfg,
+		})
+	}
+}
+
+impl Default for GatorOscillator {
+	fn default() -> Self {
+		let alligator = Alligator::default();
+
+		Self {
+			jaw_period: alligator.jaw_period,
+			jaw_offset: alligator.jaw_offset,
+			teeth_period: alligator.teeth_period,
+			teeth_offset: alligator.teeth_offset,
+			lips_period: alligator.lips_period,
+			lips_offset: alligator.lips_offset,
+			method: alligator.method,
+			source: alligator.source,
+		}
+	}
+}
+
+/// Gator Oscillator state structure
+#[derive(Debug, Clone)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct GatorOscillatorInstance {
+	cfg: GatorOscillator,
+
+	alligator: AlligatorInstance,
+	prev_upper: ValueType,
+	prev_lower: ValueType,
+	phase: i8,
+}
+
+impl<T: OHLC> IndicatorInstance<T> for GatorOscillatorInstance {
+	type Config = GatorOscillator;
+
+	fn config(&self) -> &Self::Config {
+		&self.cfg
+	}
+
+	fn next(&mut self, candle: T) -> IndicatorResult {
+		let (jaw, teeth, lips) = self.alligator.lines(candle.source(self.cfg.source));
+
+		let upper = (jaw - teeth).abs();
+		let lower = -(teeth - lips).abs();
+
+		// `1` for expanding phase, `-1` for contracting phase
+		let phase = if upper > self.prev_upper && lower < self.prev_lower {
+			1
+		} else if upper < self.prev_upper && lower > self.prev_lower {
+			-1
+		} else {
+			self.phase
+		};
+
+		let trend = if lips > jaw {
+			1
+		} else if lips < jaw {
+			-1
+		} else {
+			0
+		};
+
+		let expanding = (phase == 1 && self.phase != 1) as i8 * trend;
+		let contracting = (phase == -1 && self.phase == 1) as i8 * -trend;
+
+		self.prev_upper = upper;
+		self.prev_lower = lower;
+		self.phase = phase;
+
+		IndicatorResult::new(
+			&[upper, lower],
+			&[Action::from(expanding), Action::from(contracting)],
+		)
+	}
+
+	fn reset(&mut self, candle: T) -> Result<(), Error> {
+		self.alligator.reset(candle)?;
+		self.prev_upper = 0.;
+		self.prev_lower = 0.;
+		self.phase = 0;
+
+		Ok(())
+	}
+}
@@ -0,0 +1,223 @@
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
+
+use crate::core::{Action, Error, PeriodType, ValueType, OHLC};
+use crate::core::{IndicatorConfig, IndicatorInitializer, IndicatorInstance, IndicatorResult};
+use crate::core::{ParamDescriptor, ParamKind};
+use crate::helpers::{method, RegularMethod, RegularMethods};
+use alloc::string::String;
+use alloc::string::ToString;
+use alloc::vec::Vec;
+use core::ops::Bound::{Excluded, Included};
+
+/// Heikin-Ashi trend
+///
+/// Incoming candles are converted to Heikin-Ashi candles:
+///
+/// * `HA close` = (*open* + *high* + *low* + *close*) / 4
+/// * `HA open` = (previous `HA open` + previous `HA close`) / 2
+///
+/// When `period` is greater than `1`, then *open*, *high*, *low* and *close* prices are smoothed by `method` before conversion.
+///
+/// Heikin-Ashi candle is bullish when `HA close` is above `HA open` and bearish when `HA close` is below `HA open`.
+/// Candle with `HA close` equal to `HA open` keeps the previous direction.
+///
+/// ## Links
+///
+/// * <https://en.wikipedia.org/wiki/Candlestick_chart#Heikin-Ashi_candlesticks>
+///
+/// # 4 values
+///
+/// * `HA open` value
+/// * `HA close` value
+///
+/// Range of values is the same as the range of the price values.
+///
+/// * `trend` direction
+///
+/// `1.0` for bullish candles, `-1.0` for bearish candles, `0.0` until the first colored candle.
+///
+/// * `count` of consecutive candles of the current direction
+///
+/// Range in \[0.0; +inf\)
+///
+/// # 1 signal
+///
+/// When Heikin-Ashi candle color flips to bullish, then returns full buy signal.
+/// When Heikin-Ashi candle color flips to bearish, then returns full sell signal.
+/// Otherwise no signal
+#[derive(Debug, Clone, Copy)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct HeikinAshi {
+	/// Prices smoothing period length. Default is `1` (no smoothing).
+	///
+	/// Range in \[1; [`PeriodType::MAX`](crate::core::PeriodType)\)
+	pub period: PeriodType,
+	/// Prices smoothing method. Default is [`EMA`](crate::methods::EMA).
+	pub method: RegularMethods,
+}
+
+impl IndicatorConfig for HeikinAshi {
+	const NAME: &'static str = "HeikinAshi";
+	const VALUE_NAMES: &'static [&'static str] = &["open", "close", "trend", "count"];
+	const SIGNAL_NAMES: &'static [&'static str] = &["signal"];
+
+	fn validate(&self) -> bool {
+		self.period > 0 && self.period < PeriodType::MAX
+	}
+
+	fn set(&mut self, name: &str, value: String) -> Option<Error> {
+		match name {
+			"period" => match value.parse() {
+				Err(_) => return Some(Error::ParameterParse(name.to_string(), value.to_string())),
+				Ok(value) => self.period = value,
+			},
+			"method" => match value.parse() {
+				Err(_) => return Some(Error::ParameterParse(name.to_string(), value.to_string())),
+				Ok(value) => self.method = value,
+			},
+
+			_ => {
+				return Some(Error::UnknownParameter(name.to_string()));
+			}
+		};
+
+		None
+	}
+
+	fn parameters(&self) -> Vec<ParamDescriptor> {
+		let default = Self::default();
+
+		vec![
+			ParamDescriptor::new("period", ParamKind::Period, self.period, default.period)
+				.range(Included(1.), Excluded(PeriodType::MAX as ValueType)),
+			ParamDescriptor::new("method", ParamKind::Method, self.method, default.method),
+		]
+	}
+
+	fn size(&self) -> (u8, u8) {
+		(4, 1)
+	}
+}
+
+impl<T: OHLC> IndicatorInitializer<T> for HeikinAshi {
+	type Instance = HeikinAshiInstance;
+
+	fn init(self, candle: T) -> Result<Self::Instance, Error>
+	where
+		Self: Sized,
+	{
+		if !self.validate() {
+			return Err(Error::WrongConfig);
+		}
+
+		let cfg = self;
+		let smoothing = if cfg.period > 1 {
+			Some([
+				method(cfg.method, cfg.period, candle.open())?,
+				method(cfg.method, cfg.period, candle.high())?,
+				method(cfg.method, cfg.period, candle.low())?,
+				method(cfg.method, cfg.period, candle.close())?,
+			])
+		} else {
+			None
+		};
+
+		Ok(Self::Instance {
+			smoothing,
+			ha_open: (candle.open() + candle.close()) * 0.5,
+			ha_close: (candle.open() + candle.high() + candle.low() + candle.close()) * 0.25,
+			trend: 0,
+			count: 0,
+			cfg,
+		})
+	}
+}
+
+impl Default for HeikinAshi {
+	fn default() -> Self {
+		Self {
+			period: 1,
+			method: RegularMethods::EMA,
+		}
+	}
+}
+
+/// Heikin-Ashi trend state structure
+#[derive(Debug, Clone)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct HeikinAshiInstance {
+	cfg: HeikinAshi,
+
+	smoothing: Option<[RegularMethod; 4]>,
+	ha_open: ValueType,
+	ha_close: ValueType,
+	trend: i8,
+	count: usize,
+}
+
+impl<T: OHLC> IndicatorInstance<T> for HeikinAshiInstance {
+	type Config = HeikinAshi;
+
+	fn config(&self) -> &Self::Config {
+		&self.cfg
+	}
+
+	fn next(&mut self, candle: T) -> IndicatorResult {
+		let (open, high, low, close) = match &mut self.smoothing {
+			Some([ma_open, ma_high, ma_low, ma_close]) => (
+				ma_open.next(candle.open()),
+				ma_high.next(candle.high()),
+				ma_low.next(candle.low()),
+				ma_close.next(candle.close()),
+			),
+			None => (candle.open(), candle.high(), candle.low(), candle.close()),
+		};
+
+		self.ha_open = (self.ha_open + self.ha_close) * 0.5;
+		self.ha_close = (open + high + low + close) * 0.25;
+
+		let trend = match (self.ha_close > self.ha_open, self.ha_close < self.ha_open) {
+			(true, _) => 1,
+			(_, true) => -1,
+			_ => self.trend,
+		};
+
+		let signal = if trend == self.trend { 0 } else { trend };
+
+		if trend == 0 {
+			self.count = 0;
+		} else if trend == self.trend {
+			self.count += 1;
+		} else {
+			self.count = 1;
+		}
+		self.trend = trend;
+
+		IndicatorResult::new(
+			&[
+				self.ha_open,
+				self.ha_close,
+				trend as ValueType,
+				self.count as ValueType,
+			],
+			&[Action::from(signal)],
+		)
+	}
+
+	fn reset(&mut self, candle: T) -> Result<(), Error> {
+		if let Some([open, high, low, close]) = &mut self.smoothing {
+			open.reset(candle.open())?;
+			high.reset(candle.high())?;
+			low.reset(candle.low())?;
+			close.reset(candle.close())?;
+		}
+
+		self.ha_open = (candle.open() + candle.close()) * 0.5;
+		self.ha_close = (candle.open() + candle.high() + candle.low() + candle.close()) * 0.25;
+		self.trend = 0;
+		self.count = 0;
+
+		Ok(())
+	}
+}
@@ -1,9 +1,17 @@
 #[cfg(feature = "serde")]
 use serde::{Deserialize, Serialize};
 
-use crate::core::{Error, Method, PeriodType, Source, OHLC};
+use crate::core::{Error, Method, PeriodType, Source, ValueType, OHLC};
 use crate::core::{IndicatorConfig, IndicatorInitializer, IndicatorInstance, IndicatorResult};
+use crate::core::{ParamDescriptor, ParamKind};
 use crate::methods::{ReverseSignal, HMA};
+use alloc::string::String;
+use alloc::string::ToString;
+use alloc::vec::Vec;
+use core::ops::Bound::Included;
+
+#[cfg(not(feature = "std"))]
+use crate::core::Float as _;
 
 #[derive(Debug, Clone, Copy)]
 #[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
@@ -16,6 +24,8 @@ pub struct HullMovingAverage {
 
 impl IndicatorConfig for HullMovingAverage {
 	const NAME: &'static str = "HullMovingAverage";
+	const VALUE_NAMES: &'static [&'static str] = &["value"];
+	const SIGNAL_NAMES: &'static [&'static str] = &["reverse"];
 
 	fn validate(&self) -> bool {
 		self.period > 2 && self.left >= 1 && self.right >= 1
@@ -41,16 +51,38 @@ impl IndicatorConfig for HullMovingAverage {
 			},
 
 			_ => {
-				return Some(Error::ParameterParse(name.to_string(), value));
+				return Some(Error::UnknownParameter(name.to_string()));
 			}
 		};
 
 		None
 	}
 
+	fn parameters(&self) -> Vec<ParamDescriptor> {
+		let default = Self::default();
+
+		vec![
+			ParamDescriptor::new("period", ParamKind::Period, self.period, default.period)
+				.range(Included(3.), Included(PeriodType::MAX as ValueType)),
+			ParamDescriptor::new("left", ParamKind::Period, self.left, default.left)
+				.range(Included(1.), Included(PeriodType::MAX as ValueType)),
+			ParamDescriptor::new("right", ParamKind::Period, self.right, default.right)
+				.range(Included(1.), Included(PeriodType::MAX as ValueType)),
+			ParamDescriptor::new("source", ParamKind::Source, self.source, default.source),
+		]
+	}
+
 	fn size(&self) -> (u8, u8) {
 		(1, 1)
 	}
+
+	fn min_periods(&self) -> PeriodType {
+		// the same lengths as in `HMA`
+		#[allow(clippy::cast_possible_truncation, clippy::cast_sign_loss)]
+		let smooth_period = (self.period as ValueType).sqrt() as PeriodType;
+
+		self.period.saturating_add(smooth_period).saturating_sub(1)
+	}
 }
 
 impl<T: OHLC> IndicatorInitializer<T> for HullMovingAverage {
@@ -86,7 +118,8 @@ impl Default for HullMovingAverage {
 	}
 }
 
-#[derive(Debug)]
+#[derive(Debug, Clone)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 pub struct HullMovingAverageInstance {
 	cfg: HullMovingAverage,
 
@@ -107,4 +140,11 @@ impl<T: OHLC> IndicatorInstance<T> for HullMovingAverageInstance {
 
 		IndicatorResult::new(&[value], &[signal])
 	}
+
+	fn reset(&mut self, candle: T) -> Result<(), Error> {
+		let src = candle.source(self.cfg.source);
+
+		self.hma.reset(src)?;
+		self.pivot.reset(src)
+	}
 }
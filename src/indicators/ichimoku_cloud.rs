@@ -3,7 +3,12 @@ use serde::{Deserialize, Serialize};
 
 use crate::core::{Action, Error, Method, PeriodType, Source, ValueType, Window, OHLC};
 use crate::core::{IndicatorConfig, IndicatorInitializer, IndicatorInstance, IndicatorResult};
+use crate::core::{ParamDescriptor, ParamKind};
 use crate::methods::{Cross, Highest, Lowest};
+use alloc::string::String;
+use alloc::string::ToString;
+use alloc::vec::Vec;
+use core::ops::Bound::{Excluded, Included};
 
 #[derive(Debug, Clone, Copy)]
 #[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
@@ -17,6 +22,9 @@ pub struct IchimokuCloud {
 
 impl IndicatorConfig for IchimokuCloud {
 	const NAME: &'static str = "IchimokuCloud";
+	const VALUE_NAMES: &'static [&'static str] =
+		&["tenkan_sen", "kijun_sen", "senkou_span_a", "senkou_span_b"];
+	const SIGNAL_NAMES: &'static [&'static str] = &["tenkan_sen_cross", "kijun_sen_cross"];
 
 	fn validate(&self) -> bool {
 		self.l1 < self.l2 && self.l2 < self.l3
@@ -46,16 +54,40 @@ impl IndicatorConfig for IchimokuCloud {
 			},
 
 			_ => {
-				return Some(Error::ParameterParse(name.to_string(), value));
+				return Some(Error::UnknownParameter(name.to_string()));
 			}
 		};
 
 		None
 	}
 
+	fn parameters(&self) -> Vec<ParamDescriptor> {
+		let default = Self::default();
+
+		vec![
+			ParamDescriptor::new("l1", ParamKind::Period, self.l1, default.l1)
+				.range(Included(1.), Excluded(self.l2 as ValueType)),
+			ParamDescriptor::new("l2", ParamKind::Period, self.l2, default.l2).range(
+				Excluded(self.l1 as ValueType),
+				Excluded(self.l3 as ValueType),
+			),
+			ParamDescriptor::new("l3", ParamKind::Period, self.l3, default.l3).range(
+				Excluded(self.l2 as ValueType),
+				Included(PeriodType::MAX as ValueType),
+			),
+			ParamDescriptor::new("m", ParamKind::Period, self.m, default.m)
+				.range(Included(1.), Included(PeriodType::MAX as ValueType)),
+			ParamDescriptor::new("source", ParamKind::Source, self.source, default.source),
+		]
+	}
+
 	fn size(&self) -> (u8, u8) {
 		(4, 2)
 	}
+
+	fn min_periods(&self) -> PeriodType {
+		self.l1.max(self.l2).max(self.l3).saturating_add(self.m)
+	}
 }
 
 impl<T: OHLC> IndicatorInitializer<T> for IchimokuCloud {
@@ -97,7 +129,8 @@ impl Default for IchimokuCloud {
 	}
 }
 
-#[derive(Debug)]
+#[derive(Debug, Clone)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 pub struct IchimokuCloudInstance {
 	cfg: IchimokuCloud,
 
@@ -172,4 +205,19 @@ impl<T: OHLC> IndicatorInstance<T> for IchimokuCloudInstance {
 			&[Action::from(s1), Action::from(s2)],
 		)
 	}
+
+	fn reset(&mut self, candle: T) -> Result<(), Error> {
+		self.highest1.reset(candle.high())?;
+		self.highest2.reset(candle.high())?;
+		self.highest3.reset(candle.high())?;
+		self.lowest1.reset(candle.low())?;
+		self.lowest2.reset(candle.low())?;
+		self.lowest3.reset(candle.low())?;
+		self.window1.fill(candle.hl2());
+		self.window2.fill(candle.hl2());
+		self.cross1 = Cross::default();
+		self.cross2 = Cross::default();
+
+		Ok(())
+	}
 }
@@ -3,7 +3,15 @@ use serde::{Deserialize, Serialize};
 
 use crate::core::{Action, Error, Method, PeriodType, Source, ValueType, OHLC};
 use crate::core::{IndicatorConfig, IndicatorInitializer, IndicatorInstance, IndicatorResult};
+use crate::core::{ParamDescriptor, ParamKind};
 use crate::methods::{Change, Cross, LinearVolatility, StDev};
+use alloc::string::String;
+use alloc::string::ToString;
+use alloc::vec::Vec;
+use core::ops::Bound::{Excluded, Included, Unbounded};
+
+#[cfg(not(feature = "std"))]
+use crate::core::Float as _;
 
 // https://ru.wikipedia.org/wiki/%D0%90%D0%B4%D0%B0%D0%BF%D1%82%D0%B8%D0%B2%D0%BD%D0%B0%D1%8F_%D1%81%D0%BA%D0%BE%D0%BB%D1%8C%D0%B7%D1%8F%D1%89%D0%B0%D1%8F_%D1%81%D1%80%D0%B5%D0%B4%D0%BD%D1%8F%D1%8F_%D0%9A%D0%B0%D1%83%D1%84%D0%BC%D0%B0%D0%BD%D0%B0
 #[derive(Debug, Clone, Copy)]
@@ -20,6 +28,8 @@ pub struct Kaufman {
 
 impl IndicatorConfig for Kaufman {
 	const NAME: &'static str = "Kaufman";
+	const VALUE_NAMES: &'static [&'static str] = &["value"];
+	const SIGNAL_NAMES: &'static [&'static str] = &["signal"];
 
 	fn validate(&self) -> bool {
 		self.period3 > self.period2
@@ -60,16 +70,52 @@ impl IndicatorConfig for Kaufman {
 			},
 
 			_ => {
-				return Some(Error::ParameterParse(name.to_string(), value));
+				return Some(Error::UnknownParameter(name.to_string()));
 			}
 		};
 
 		None
 	}
 
+	fn parameters(&self) -> Vec<ParamDescriptor> {
+		let default = Self::default();
+
+		vec![
+			ParamDescriptor::new("period1", ParamKind::Period, self.period1, default.period1)
+				.range(Included(1.), Included(PeriodType::MAX as ValueType)),
+			ParamDescriptor::new("period2", ParamKind::Period, self.period2, default.period2)
+				.range(Included(1.), Excluded(self.period3 as ValueType)),
+			ParamDescriptor::new("period3", ParamKind::Period, self.period3, default.period3)
+				.range(
+					Excluded(self.period2 as ValueType),
+					Included(PeriodType::MAX as ValueType),
+				),
+			ParamDescriptor::new(
+				"filter_period",
+				ParamKind::Period,
+				self.filter_period,
+				default.filter_period,
+			)
+			.range(Included(0.), Included(PeriodType::MAX as ValueType)),
+			ParamDescriptor::new(
+				"square_smooth",
+				ParamKind::Bool,
+				self.square_smooth,
+				default.square_smooth,
+			),
+			ParamDescriptor::new("k", ParamKind::Float, self.k, default.k)
+				.range(Excluded(0.), Unbounded),
+			ParamDescriptor::new("source", ParamKind::Source, self.source, default.source),
+		]
+	}
+
 	fn size(&self) -> (u8, u8) {
 		(1, 1)
 	}
+
+	fn min_periods(&self) -> PeriodType {
+		self.period1.saturating_add(self.period3)
+	}
 }
 
 impl<T: OHLC> IndicatorInitializer<T> for Kaufman {
@@ -113,7 +159,8 @@ impl Default for Kaufman {
 		}
 	}
 }
-#[derive(Debug)]
+#[derive(Debug, Clone)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 pub struct KaufmanInstance {
 	cfg: Kaufman,
 
@@ -179,4 +226,18 @@ impl<T: OHLC> IndicatorInstance<T> for KaufmanInstance {
 
 		IndicatorResult::new(&[value], &[signal])
 	}
+
+	fn reset(&mut self, candle: T) -> Result<(), Error> {
+		let src = candle.source(self.cfg.source);
+
+		self.volatility.reset(src)?;
+		self.change.reset(src)?;
+		self.st_dev.reset(src)?;
+		self.cross = Cross::default();
+		self.last_signal = Action::None;
+		self.last_signal_value = src;
+		self.prev_value = src;
+
+		Ok(())
+	}
 }
@@ -3,8 +3,16 @@ use serde::{Deserialize, Serialize};
 
 use crate::core::{Error, Method, PeriodType, Source, ValueType, OHLC};
 use crate::core::{IndicatorConfig, IndicatorInitializer, IndicatorInstance, IndicatorResult};
+use crate::core::{ParamDescriptor, ParamKind};
 use crate::helpers::{method, RegularMethod, RegularMethods};
-use crate::methods::{CrossAbove, CrossUnder, SMA};
+use crate::methods::{CrossAbove, CrossUnder};
+use alloc::string::String;
+use alloc::string::ToString;
+use alloc::vec::Vec;
+use core::ops::Bound::{Excluded, Included, Unbounded};
+
+#[cfg(not(feature = "std"))]
+use crate::core::Float as _;
 
 // https://en.wikipedia.org/wiki/Keltner_channel
 #[derive(Debug, Clone, Copy)]
@@ -14,10 +22,18 @@ pub struct KeltnerChannels {
 	pub method: RegularMethods,
 	pub sigma: ValueType,
 	pub source: Source,
+	/// Use true range instead of plain `high - low` range for bands size. Default is `false`.
+	pub true_range: bool,
+	/// Range smoothing method. Default is [`SMA`](crate::methods::SMA).
+	///
+	/// Use [`RMA`](crate::methods::RMA) together with `true_range` to get the classic `ATR` bands.
+	pub atr_method: RegularMethods,
 }
 
 impl IndicatorConfig for KeltnerChannels {
 	const NAME: &'static str = "KeltnerChannels";
+	const VALUE_NAMES: &'static [&'static str] = &["source", "upper", "lower"];
+	const SIGNAL_NAMES: &'static [&'static str] = &["signal"];
 
 	fn validate(&self) -> bool {
 		self.period > 1 && self.sigma > 1e-4
@@ -41,18 +57,56 @@ impl IndicatorConfig for KeltnerChannels {
 				Err(_) => return Some(Error::ParameterParse(name.to_string(), value.to_string())),
 				Ok(value) => self.source = value,
 			},
+			"true_range" => match value.parse() {
+				Err(_) => return Some(Error::ParameterParse(name.to_string(), value.to_string())),
+				Ok(value) => self.true_range = value,
+			},
+			"atr_method" => match value.parse() {
+				Err(_) => return Some(Error::ParameterParse(name.to_string(), value.to_string())),
+				Ok(value) => self.atr_method = value,
+			},
 
 			_ => {
-				return Some(Error::ParameterParse(name.to_string(), value));
+				return Some(Error::UnknownParameter(name.to_string()));
 			}
 		};
 
 		None
 	}
 
+	fn parameters(&self) -> Vec<ParamDescriptor> {
+		let default = Self::default();
+
+		vec![
+			ParamDescriptor::new("period", ParamKind::Period, self.period, default.period)
+				.range(Included(2.), Included(PeriodType::MAX as ValueType)),
+			ParamDescriptor::new("method", ParamKind::Method, self.method, default.method),
+			ParamDescriptor::new("sigma", ParamKind::Float, self.sigma, default.sigma)
+				.range(Excluded(1e-4), Unbounded),
+			ParamDescriptor::new("source", ParamKind::Source, self.source, default.source),
+			ParamDescriptor::new(
+				"true_range",
+				ParamKind::Bool,
+				self.true_range,
+				default.true_range,
+			),
+			ParamDescriptor::new(
+				"atr_method",
+				ParamKind::Method,
+				self.atr_method,
+				default.atr_method,
+			),
+		]
+	}
+
 	fn size(&self) -> (u8, u8) {
 		(3, 1)
 	}
+
+	fn min_periods(&self) -> PeriodType {
+		self.period
+			.saturating_add(PeriodType::from(self.true_range))
+	}
 }
 
 impl<T: OHLC> IndicatorInitializer<T> for KeltnerChannels {
@@ -71,7 +125,7 @@ impl<T: OHLC> IndicatorInitializer<T> for KeltnerChannels {
 		Ok(Self::Instance {
 			prev_candle: candle,
 			ma: method(cfg.method, cfg.period, src)?,
-			sma: SMA::new(cfg.period, candle.high() - candle.low())?,
+			atr: method(cfg.atr_method, cfg.period, candle.high() - candle.low())?,
 			cross_above: CrossAbove::default(),
 			cross_under: CrossUnder::default(),
 			cfg,
@@ -86,17 +140,20 @@ impl Default for KeltnerChannels {
 			sigma: 1.0,
 			source: Source::Close,
 			method: RegularMethods::EMA,
+			true_range: false,
+			atr_method: RegularMethods::SMA,
 		}
 	}
 }
 
-#[derive(Debug)]
+#[derive(Debug, Clone)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 pub struct KeltnerChannelsInstance<T: OHLC> {
 	cfg: KeltnerChannels,
 
 	prev_candle: T,
 	ma: RegularMethod,
-	sma: SMA,
+	atr: RegularMethod,
 	cross_above: CrossAbove,
 	cross_under: CrossUnder,
 }
@@ -110,9 +167,15 @@ impl<T: OHLC> IndicatorInstance<T> for KeltnerChannelsInstance<T> {
 
 	fn next(&mut self, candle: T) -> IndicatorResult {
 		let source = candle.source(self.cfg.source);
-		let tr = candle.tr(&self.prev_candle);
+		let range = if self.cfg.true_range {
+			candle.tr(&self.prev_candle)
+		} else {
+			candle.high() - candle.low()
+		};
+		self.prev_candle = candle;
+
 		let ma: ValueType = self.ma.next(source);
-		let atr = self.sma.next(tr);
+		let atr = self.atr.next(range);
 
 		let upper = atr.mul_add(self.cfg.sigma, ma);
 		let lower = ma - atr * self.cfg.sigma;
@@ -122,4 +185,16 @@ impl<T: OHLC> IndicatorInstance<T> for KeltnerChannelsInstance<T> {
 
 		IndicatorResult::new(&[source, upper, lower], &[signal])
 	}
+
+	fn reset(&mut self, candle: T) -> Result<(), Error> {
+		let src = candle.source(self.cfg.source);
+
+		self.prev_candle = candle;
+		self.ma.reset(src)?;
+		self.atr.reset(candle.high() - candle.low())?;
+		self.cross_above = CrossAbove::default();
+		self.cross_under = CrossUnder::default();
+
+		Ok(())
+	}
 }
@@ -3,8 +3,13 @@ use serde::{Deserialize, Serialize};
 
 use crate::core::{Error, Method, PeriodType, ValueType, OHLCV};
 use crate::core::{IndicatorConfig, IndicatorInitializer, IndicatorInstance, IndicatorResult};
+use crate::core::{ParamDescriptor, ParamKind};
 use crate::helpers::{method, sign, RegularMethod, RegularMethods};
 use crate::methods::Cross;
+use alloc::string::String;
+use alloc::string::ToString;
+use alloc::vec::Vec;
+use core::ops::Bound::{Excluded, Included};
 
 #[derive(Debug, Clone, Copy)]
 #[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
@@ -18,6 +23,8 @@ pub struct KlingerVolumeOscillator {
 
 impl IndicatorConfig for KlingerVolumeOscillator {
 	const NAME: &'static str = "KlingerVolumeOscillator";
+	const VALUE_NAMES: &'static [&'static str] = &["value", "signal_line"];
+	const SIGNAL_NAMES: &'static [&'static str] = &["zero_cross", "signal_line_cross"];
 
 	fn validate(&self) -> bool {
 		self.period1 < self.period2
@@ -47,13 +54,31 @@ impl IndicatorConfig for KlingerVolumeOscillator {
 			},
 
 			_ => {
-				return Some(Error::ParameterParse(name.to_string(), value));
+				return Some(Error::UnknownParameter(name.to_string()));
 			}
 		};
 
 		None
 	}
 
+	fn parameters(&self) -> Vec<ParamDescriptor> {
+		let default = Self::default();
+
+		vec![
+			ParamDescriptor::new("period1", ParamKind::Period, self.period1, default.period1)
+				.range(Included(1.), Excluded(self.period2 as ValueType)),
+			ParamDescriptor::new("period2", ParamKind::Period, self.period2, default.period2)
+				.range(
+					Excluded(self.period1 as ValueType),
+					Included(PeriodType::MAX as ValueType),
+				),
+			ParamDescriptor::new("period3", ParamKind::Period, self.period3, default.period3)
+				.range(Included(1.), Included(PeriodType::MAX as ValueType)),
+			ParamDescriptor::new("method1", ParamKind::Method, self.method1, default.method1),
+			ParamDescriptor::new("method2", ParamKind::Method, self.method2, default.method2),
+		]
+	}
+
 	fn is_volume_based(&self) -> bool {
 		true
 	}
@@ -61,6 +86,10 @@ impl IndicatorConfig for KlingerVolumeOscillator {
 	fn size(&self) -> (u8, u8) {
 		(2, 2)
 	}
+
+	fn min_periods(&self) -> PeriodType {
+		self.period1.max(self.period2).saturating_add(self.period3)
+	}
 }
 
 impl<T: OHLCV> IndicatorInitializer<T> for KlingerVolumeOscillator {
@@ -99,7 +128,8 @@ impl Default for KlingerVolumeOscillator {
 	}
 }
 
-#[derive(Debug)]
+#[derive(Debug, Clone)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 pub struct KlingerVolumeOscillatorInstance {
 	cfg: KlingerVolumeOscillator,
 
@@ -145,4 +175,15 @@ impl<T: OHLCV> IndicatorInstance<T> for KlingerVolumeOscillatorInstance {
 
 		IndicatorResult::new(&[ko, ma3], &[s1, s2])
 	}
+
+	fn reset(&mut self, candle: T) -> Result<(), Error> {
+		self.ma1.reset(0.)?;
+		self.ma2.reset(0.)?;
+		self.ma3.reset(0.)?;
+		self.cross1 = Cross::default();
+		self.cross2 = Cross::default();
+		self.last_tp = candle.tp();
+
+		Ok(())
+	}
 }
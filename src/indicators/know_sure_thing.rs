@@ -3,8 +3,16 @@ use serde::{Deserialize, Serialize};
 
 use crate::core::{Error, Method, PeriodType, ValueType, OHLC};
 use crate::core::{IndicatorConfig, IndicatorInitializer, IndicatorInstance, IndicatorResult};
+use crate::core::{ParamDescriptor, ParamKind};
 use crate::helpers::{method, RegularMethod, RegularMethods};
 use crate::methods::{Cross, RateOfChange};
+use alloc::string::String;
+use alloc::string::ToString;
+use alloc::vec::Vec;
+use core::ops::Bound::{Excluded, Included};
+
+#[cfg(not(feature = "std"))]
+use crate::core::Float as _;
 
 // https://en.wikipedia.org/wiki/KST_oscillator
 #[derive(Debug, Clone, Copy)]
@@ -26,6 +34,8 @@ pub struct KnowSureThing {
 
 impl IndicatorConfig for KnowSureThing {
 	const NAME: &'static str = "KnowSureThing";
+	const VALUE_NAMES: &'static [&'static str] = &["value", "signal_line"];
+	const SIGNAL_NAMES: &'static [&'static str] = &["signal_line_cross"];
 
 	fn validate(&self) -> bool {
 		self.period1 < self.period2 && self.period2 < self.period3 && self.period3 < self.period4
@@ -79,16 +89,61 @@ impl IndicatorConfig for KnowSureThing {
 			},
 
 			_ => {
-				return Some(Error::ParameterParse(name.to_string(), value));
+				return Some(Error::UnknownParameter(name.to_string()));
 			}
 		};
 
 		None
 	}
 
+	fn parameters(&self) -> Vec<ParamDescriptor> {
+		let default = Self::default();
+
+		vec![
+			ParamDescriptor::new("period1", ParamKind::Period, self.period1, default.period1)
+				.range(Included(1.), Excluded(self.period2 as ValueType)),
+			ParamDescriptor::new("period2", ParamKind::Period, self.period2, default.period2)
+				.range(
+					Excluded(self.period1 as ValueType),
+					Excluded(self.period3 as ValueType),
+				),
+			ParamDescriptor::new("period3", ParamKind::Period, self.period3, default.period3)
+				.range(
+					Excluded(self.period2 as ValueType),
+					Excluded(self.period4 as ValueType),
+				),
+			ParamDescriptor::new("period4", ParamKind::Period, self.period4, default.period4)
+				.range(
+					Excluded(self.period3 as ValueType),
+					Included(PeriodType::MAX as ValueType),
+				),
+			ParamDescriptor::new("sma1", ParamKind::Period, self.sma1, default.sma1)
+				.range(Included(1.), Included(PeriodType::MAX as ValueType)),
+			ParamDescriptor::new("sma2", ParamKind::Period, self.sma2, default.sma2)
+				.range(Included(1.), Included(PeriodType::MAX as ValueType)),
+			ParamDescriptor::new("sma3", ParamKind::Period, self.sma3, default.sma3)
+				.range(Included(1.), Included(PeriodType::MAX as ValueType)),
+			ParamDescriptor::new("sma4", ParamKind::Period, self.sma4, default.sma4)
+				.range(Included(1.), Included(PeriodType::MAX as ValueType)),
+			ParamDescriptor::new("method1", ParamKind::Method, self.method1, default.method1),
+			ParamDescriptor::new("sma5", ParamKind::Period, self.sma5, default.sma5)
+				.range(Included(1.), Included(PeriodType::MAX as ValueType)),
+			ParamDescriptor::new("method2", ParamKind::Method, self.method2, default.method2),
+		]
+	}
+
 	fn size(&self) -> (u8, u8) {
 		(2, 1)
 	}
+
+	fn min_periods(&self) -> PeriodType {
+		(self.period1.saturating_add(self.sma1))
+			.max(self.period2.saturating_add(self.sma2))
+			.max(self.period3.saturating_add(self.sma3))
+			.max(self.period4.saturating_add(self.sma4))
+			.saturating_add(self.sma5)
+			.saturating_sub(1)
+	}
 }
 
 impl<T: OHLC> IndicatorInitializer<T> for KnowSureThing {
@@ -138,7 +193,8 @@ impl Default for KnowSureThing {
 	}
 }
 
-#[derive(Debug)]
+#[derive(Debug, Clone)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 pub struct KnowSureThingInstance {
 	cfg: KnowSureThing,
 
@@ -181,4 +237,21 @@ impl<T: OHLC> IndicatorInstance<T> for KnowSureThingInstance {
 
 		IndicatorResult::new(&[kst, sl], &[signal])
 	}
+
+	fn reset(&mut self, candle: T) -> Result<(), Error> {
+		let close = candle.close();
+
+		self.roc1v.reset(close)?;
+		self.roc2v.reset(close)?;
+		self.roc3v.reset(close)?;
+		self.roc4v.reset(close)?;
+		self.ma1.reset(0.)?;
+		self.ma2.reset(0.)?;
+		self.ma3.reset(0.)?;
+		self.ma4.reset(0.)?;
+		self.ma5.reset(0.)?;
+		self.cross = Cross::default();
+
+		Ok(())
+	}
 }
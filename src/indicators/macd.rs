@@ -3,10 +3,15 @@
 #[cfg(feature = "serde")]
 use serde::{Deserialize, Serialize};
 
-use crate::core::{Candle, Error, Method, PeriodType, Source, OHLC};
+use crate::core::{Candle, Error, Method, PeriodType, Source, ValueType, OHLC};
 use crate::core::{IndicatorConfig, IndicatorInitializer, IndicatorInstance, IndicatorResult};
+use crate::core::{ParamDescriptor, ParamKind};
 use crate::helpers::{method, RegularMethod, RegularMethods};
 use crate::methods::Cross;
+use alloc::string::String;
+use alloc::string::ToString;
+use alloc::vec::Vec;
+use core::ops::Bound::{Excluded, Included};
 
 // https://en.wikipedia.org/wiki/MACD
 #[derive(Debug, Clone, Copy)]
@@ -23,6 +28,8 @@ pub struct MACD {
 
 impl IndicatorConfig for MACD {
 	const NAME: &'static str = "MACD";
+	const VALUE_NAMES: &'static [&'static str] = &["macd", "signal_line"];
+	const SIGNAL_NAMES: &'static [&'static str] = &["signal_line_cross"];
 
 	fn validate(&self) -> bool {
 		self.period1 < self.period2
@@ -59,16 +66,43 @@ impl IndicatorConfig for MACD {
 				Ok(value) => self.source = value,
 			},
 			_ => {
-				return Some(Error::ParameterParse(name.to_string(), value));
+				return Some(Error::UnknownParameter(name.to_string()));
 			}
 		};
 
 		None
 	}
 
+	fn parameters(&self) -> Vec<ParamDescriptor> {
+		let default = Self::default();
+
+		vec![
+			ParamDescriptor::new("period1", ParamKind::Period, self.period1, default.period1)
+				.range(Included(1.), Excluded(self.period2 as ValueType)),
+			ParamDescriptor::new("period2", ParamKind::Period, self.period2, default.period2)
+				.range(
+					Excluded(self.period1 as ValueType),
+					Included(PeriodType::MAX as ValueType),
+				),
+			ParamDescriptor::new("period3", ParamKind::Period, self.period3, default.period3)
+				.range(Included(1.), Included(PeriodType::MAX as ValueType)),
+			ParamDescriptor::new("method1", ParamKind::Method, self.method1, default.method1),
+			ParamDescriptor::new("method2", ParamKind::Method, self.method2, default.method2),
+			ParamDescriptor::new("method3", ParamKind::Method, self.method3, default.method3),
+			ParamDescriptor::new("source", ParamKind::Source, self.source, default.source),
+		]
+	}
+
 	fn size(&self) -> (u8, u8) {
 		(2, 1)
 	}
+
+	fn min_periods(&self) -> PeriodType {
+		self.period1
+			.max(self.period2)
+			.saturating_add(self.period3)
+			.saturating_sub(1)
+	}
 }
 
 impl<T: OHLC> IndicatorInitializer<T> for MACD {
@@ -108,7 +142,8 @@ impl Default for MACD {
 	}
 }
 
-#[derive(Debug)]
+#[derive(Debug, Clone)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 pub struct MACDInstance {
 	cfg: MACD,
 
@@ -148,4 +183,27 @@ impl<T: OHLC> IndicatorInstance<T> for MACDInstance {
 
 		IndicatorResult::new(&[macd, sigline], &[signal])
 	}
+
+	fn reset(&mut self, candle: T) -> Result<(), Error> {
+		let src = candle.source(self.cfg.source);
+
+		self.ma1.reset(src)?;
+		self.ma2.reset(src)?;
+		self.ma3.reset(src)?;
+		self.cross.reset((0.0, 0.0))
+	}
+
+	fn save_undo(&self, stack: &mut Vec<ValueType>) -> bool {
+		self.ma1.save_undo(stack)
+			&& self.ma2.save_undo(stack)
+			&& self.ma3.save_undo(stack)
+			&& self.cross.save_undo(stack)
+	}
+
+	fn restore_undo(&mut self, stack: &mut Vec<ValueType>) {
+		self.cross.restore_undo(stack);
+		self.ma3.restore_undo(stack);
+		self.ma2.restore_undo(stack);
+		self.ma1.restore_undo(stack);
+	}
 }
@@ -0,0 +1,276 @@
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
+
+use crate::core::{Error, Method, Source, ValueType, Window, OHLC};
+use crate::core::{IndicatorConfig, IndicatorInitializer, IndicatorInstance, IndicatorResult};
+use crate::core::{ParamDescriptor, ParamKind};
+use crate::methods::Cross;
+use alloc::string::String;
+use alloc::string::ToString;
+use alloc::vec::Vec;
+use core::ops::Bound::{Excluded, Included};
+
+#[cfg(not(feature = "std"))]
+use crate::core::Float as _;
+
+/// Ehlers MESA Adaptive Moving Average \(`MAMA`\) with Following Adaptive Moving Average \(`FAMA`\)
+///
+/// Smoothing factor adapts to the rate of change of the phase, which is measured by Hilbert transform discriminator.
+///
+/// ## Links
+///
+/// * <https://www.mesasoftware.com/papers/MAMA.pdf>
+///
+/// # 2 values
+///
+/// * `MAMA` value
+/// * `FAMA` value
+///
+/// Range of values is the same as the range of the `source` values.
+///
+/// # 1 signal
+///
+/// When `MAMA` crosses `FAMA` upwards, then returns full buy signal.
+/// When `MAMA` crosses `FAMA` downwards, then returns full sell signal.
+/// Otherwise no signal
+#[derive(Debug, Clone, Copy)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct MESAAdaptiveMovingAverage {
+	/// Upper limit of the smoothing factor. Default is `0.5`.
+	///
+	/// Range in \[`slow_limit`; 1.0\]
+	pub fast_limit: ValueType,
+	/// Lower limit of the smoothing factor. Default is `0.05`.
+	///
+	/// Range in \(0.0; `fast_limit`\]
+	pub slow_limit: ValueType,
+	/// Source type of values. Default is [`HL2`](crate::core::Source#variant.HL2)
+	pub source: Source,
+}
+
+impl IndicatorConfig for MESAAdaptiveMovingAverage {
+	const NAME: &'static str = "MESAAdaptiveMovingAverage";
+	const VALUE_NAMES: &'static [&'static str] = &["mama", "fama"];
+	const SIGNAL_NAMES: &'static [&'static str] = &["signal"];
+
+	fn validate(&self) -> bool {
+		self.slow_limit > 0. && self.slow_limit <= self.fast_limit && self.fast_limit <= 1.
+	}
+
+	fn set(&mut self, name: &str, value: String) -> Option<Error> {
+		match name {
+			"fast_limit" => match value.parse() {
+				Err(_) => return Some(Error::ParameterParse(name.to_string(), value.to_string())),
+				Ok(value) => self.fast_limit = value,
+			},
+			"slow_limit" => match value.parse() {
+				Err(_) => return Some(Error::ParameterParse(name.to_string(), value.to_string())),
+				Ok(value) => self.slow_limit = value,
+			},
+			"source" => match value.parse() {
+				Err(_) => return Some(Error::ParameterParse(name.to_string(), value.to_string())),
+				Ok(value) => self.source = value,
+			},
+
+			_ => {
+				return Some(Error::UnknownParameter(name.to_string()));
+			}
+		};
+
+		None
+	}
+
+	fn parameters(&self) -> Vec<ParamDescriptor> {
+		let default = Self::default();
+
+		vec![
+			ParamDescriptor::new(
+				"fast_limit",
+				ParamKind::Float,
+				self.fast_limit,
+				default.fast_limit,
+			)
+			.range(Included(self.slow_limit), Included(1.0)),
+			ParamDescriptor::new(
+				"slow_limit",
+				ParamKind::Float,
+				self.slow_limit,
+				default.slow_limit,
+			)
+			.range(Excluded(0.0), Included(self.fast_limit)),
+			ParamDescriptor::new("source", ParamKind::Source, self.source, default.source),
+		]
+	}
+
+	fn size(&self) -> (u8, u8) {
+		(2, 1)
+	}
+}
+
+impl<T: OHLC> IndicatorInitializer<T> for MESAAdaptiveMovingAverage {
+	type Instance = MESAAdaptiveMovingAverageInstance;
+
+	fn init(self, candle: T) -> Result<Self::Instance, Error>
+	where
+		Self: Sized,
+	{
+		if !self.validate() {
+			return Err(Error::WrongConfig);
+		}
+
+		let cfg = self;
+		let src = candle.source(cfg.source);
+
+		Ok(Self::Instance {
+			price: Window::new(4, src),
+			smooth: Window::new(7, src),
+			detrender: Window::new(7, 0.),
+			i1: Window::new(7, 0.),
+			q1: Window::new(7, 0.),
+			i2: 0.,
+			q2: 0.,
+			re: 0.,
+			im: 0.,
+			period: 0.,
+			phase: 0.,
+			mama: src,
+			fama: src,
+			cross: Cross::new((), (src, src))?,
+			cfg,
+		})
+	}
+}
+
+impl Default for MESAAdaptiveMovingAverage {
+	fn default() -> Self {
+		Self {
+			fast_limit: 0.5,
+			slow_limit: 0.05,
+			source: Source::HL2,
+		}
+	}
+}
+
+/// Just an alias for [`MESAAdaptiveMovingAverage`]
+pub type MAMA = MESAAdaptiveMovingAverage;
+
+/// Ehlers MESA Adaptive Moving Average state structure
+#[derive(Debug, Clone)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct MESAAdaptiveMovingAverageInstance {
+	cfg: MESAAdaptiveMovingAverage,
+
+	price: Window<ValueType>,
+	smooth: Window<ValueType>,
+	detrender: Window<ValueType>,
+	i1: Window<ValueType>,
+	q1: Window<ValueType>,
+	i2: ValueType,
+	q2: ValueType,
+	re: ValueType,
+	im: ValueType,
+	period: ValueType,
+	phase: ValueType,
+	mama: ValueType,
+	fama: ValueType,
+	cross: Cross,
+}
+
+/// Hilbert transform over the last 7 values of the `window`
+#[inline]
+#[allow(clippy::suboptimal_flops)]
+fn hilbert(window: &Window<ValueType>) -> ValueType {
+	// `window[6]` is the most recent value
+	0.0962 * (window[6] - window[0]) + 0.5769 * (window[4] - window[2])
+}
+
+impl<T: OHLC> IndicatorInstance<T> for MESAAdaptiveMovingAverageInstance {
+	type Config = MESAAdaptiveMovingAverage;
+
+	fn config(&self) -> &Self::Config {
+		&self.cfg
+	}
+
+	#[allow(clippy::similar_names, clippy::suboptimal_flops)]
+	fn next(&mut self, candle: T) -> IndicatorResult {
+		let src = candle.source(self.cfg.source);
+
+		self.price.push(src);
+		let p = &self.price;
+		let smooth = (4. * p[3] + 3. * p[2] + 2. * p[1] + p[0]) * 0.1;
+		self.smooth.push(smooth);
+
+		let k = 0.075 * self.period + 0.54;
+
+		// InPhase and Quadrature components
+		self.detrender.push(hilbert(&self.smooth) * k);
+		let i1 = self.detrender[3];
+		self.i1.push(i1);
+		let q1 = hilbert(&self.detrender) * k;
+		self.q1.push(q1);
+
+		// advance the phase of I1 and Q1 by 90 degrees
+		let ji = hilbert(&self.i1) * k;
+		let jq = hilbert(&self.q1) * k;
+
+		// phasor addition for 3 bar averaging
+		let i2 = 0.2 * (i1 - jq) + 0.8 * self.i2;
+		let q2 = 0.2 * (q1 + ji) + 0.8 * self.q2;
+
+		// homodyne discriminator
+		let re = 0.2 * (i2 * self.i2 + q2 * self.q2) + 0.8 * self.re;
+		let im = 0.2 * (i2 * self.q2 - q2 * self.i2) + 0.8 * self.im;
+		self.i2 = i2;
+		self.q2 = q2;
+		self.re = re;
+		self.im = im;
+
+		let prev_period = self.period;
+		let mut period = if im != 0. && re != 0. {
+			360. / (im / re).atan().to_degrees()
+		} else {
+			prev_period
+		};
+		period = period
+			.min(1.5 * prev_period)
+			.max(0.67 * prev_period)
+			.clamp(6., 50.);
+		self.period = 0.2 * period + 0.8 * prev_period;
+
+		let phase = if i1 == 0. {
+			self.phase
+		} else {
+			(q1 / i1).atan().to_degrees()
+		};
+		let delta_phase = (self.phase - phase).max(1.);
+		self.phase = phase;
+
+		let alpha = (self.cfg.fast_limit / delta_phase).max(self.cfg.slow_limit);
+
+		self.mama = alpha * src + (1. - alpha) * self.mama;
+		self.fama = 0.5 * alpha * self.mama + (1. - 0.5 * alpha) * self.fama;
+
+		let signal = self.cross.next((self.mama, self.fama));
+
+		IndicatorResult::new(&[self.mama, self.fama], &[signal])
+	}
+
+	fn reset(&mut self, candle: T) -> Result<(), Error> {
+		let src = candle.source(self.cfg.source);
+
+		self.price.fill(src);
+		self.smooth.fill(src);
+		self.detrender.fill(0.);
+		self.i1.fill(0.);
+		self.q1.fill(0.);
+		self.i2 = 0.;
+		self.q2 = 0.;
+		self.re = 0.;
+		self.im = 0.;
+		self.period = 0.;
+		self.phase = 0.;
+		self.mama = src;
+		self.fama = src;
+		self.cross.reset((src, src))
+	}
+}
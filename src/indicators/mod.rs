@@ -1,11 +1,26 @@
 #![allow(missing_docs)]
 pub mod example;
 
+mod registry;
+pub use registry::{create, DynIndicator, IndicatorColumns, INDICATORS};
+
 // // ---------------------------------------------
 
+mod accumulation_distribution;
+pub use accumulation_distribution::{AccumulationDistribution, ADL};
+
+mod alligator;
+pub use alligator::{Alligator, AlligatorInstance};
+
+mod anchored_vwap;
+pub use anchored_vwap::{AnchorCondition, AnchoredVWAP, AnchoredVWAPInstance};
+
 mod aroon;
 pub use aroon::Aroon;
 
+mod atr_trailing_stop;
+pub use atr_trailing_stop::ATRTrailingStop;
+
 mod average_directional_index;
 pub use average_directional_index::AverageDirectionalIndex;
 
@@ -15,6 +30,12 @@ pub use awesome_oscillator::AwesomeOscillator;
 mod bollinger_bands;
 pub use bollinger_bands::BollingerBands;
 
+mod candlestick_patterns;
+pub use candlestick_patterns::CandlestickPatterns;
+
+mod center_of_gravity;
+pub use center_of_gravity::{CenterOfGravity, CG};
+
 mod chaikin_money_flow;
 pub use chaikin_money_flow::ChaikinMoneyFlow;
 
@@ -30,15 +51,30 @@ pub use chande_momentum_oscillator::ChandeMomentumOscillator;
 mod commodity_channel_index;
 pub use commodity_channel_index::CommodityChannelIndex;
 
+mod connors_rsi;
+pub use connors_rsi::{ConnorsRSI, CRSI};
+
 mod coppock_curve;
 pub use coppock_curve::CoppockCurve;
 
 mod detrended_price_oscillator;
 pub use detrended_price_oscillator::DetrendedPriceOscillator;
 
+mod donchian_channels;
+pub use donchian_channels::DonchianChannels;
+
+mod dynamic_momentum_index;
+pub use dynamic_momentum_index::DynamicMomentumIndex;
+
 mod ease_of_movement;
 pub use ease_of_movement::EaseOfMovement;
 
+mod elder_impulse;
+pub use elder_impulse::ElderImpulse;
+
+mod elder_ray;
+pub use elder_ray::ElderRay;
+
 mod elders_force_index;
 pub use elders_force_index::EldersForceIndex;
 
@@ -52,6 +88,15 @@ pub use tv_fisher_transform::TVFisherTransformInstance;
 mod fisher_transform;
 pub use fisher_transform::FisherTransform;
 
+mod fractals;
+pub use fractals::Fractals;
+
+mod gator_oscillator;
+pub use gator_oscillator::GatorOscillator;
+
+mod heikin_ashi;
+pub use heikin_ashi::HeikinAshi;
+
 mod hull_moving_average;
 pub use hull_moving_average::HullMovingAverage;
 
@@ -73,6 +118,9 @@ pub use know_sure_thing::KnowSureThing;
 mod macd;
 pub use macd::{MovingAverageConvergenceDivergence, MACD};
 
+mod mesa_adaptive_moving_average;
+pub use mesa_adaptive_moving_average::{MESAAdaptiveMovingAverage, MAMA};
+
 mod momentum_index;
 pub use momentum_index::MomentumIndex;
 
@@ -82,24 +130,54 @@ pub use money_flow_index::MoneyFlowIndex;
 mod parabolic_sar;
 pub use parabolic_sar::{ParabolicSAR, ParabolicStopAndReverse};
 
+mod percentage_volume_oscillator;
+pub use percentage_volume_oscillator::{PercentageVolumeOscillator, PVO};
+
+mod pivot_points;
+pub use pivot_points::{PivotLevels, PivotPoints, PivotPointsInstance, PivotPointsVariant};
+
 mod pivot_reversal_strategy;
 pub use pivot_reversal_strategy::PivotReversalStrategy;
 
 mod price_channel_strategy;
 pub use price_channel_strategy::PriceChannelStrategy;
 
+mod price_volume_trend;
+pub use price_volume_trend::{PriceVolumeTrend, PVT};
+
+mod range_action_verification_index;
+pub use range_action_verification_index::{RangeActionVerificationIndex, RAVI};
+
 mod relative_strength_index;
 pub use relative_strength_index::{RelativeStrengthIndex, RSI};
 
 mod relative_vigor_index;
 pub use relative_vigor_index::RelativeVigorIndex;
 
+mod relative_volatility_index;
+pub use relative_volatility_index::RelativeVolatilityIndex;
+
+mod relative_volume;
+pub use relative_volume::{RelativeVolume, RVOL};
+
 mod smi_ergodic_indicator;
 pub use smi_ergodic_indicator::SMIErgodicIndicator;
 
+mod squeeze_momentum;
+pub use squeeze_momentum::SqueezeMomentum;
+
+mod standard_error_bands;
+pub use standard_error_bands::StandardErrorBands;
+
 mod stochastic_oscillator;
 pub use stochastic_oscillator::StochasticOscillator;
 
+mod super_trend;
+pub use super_trend::SuperTrend;
+
+mod support_resistance;
+pub use support_resistance::SupportResistance;
+
 mod trix;
 pub use trix::Trix;
 
@@ -109,5 +187,166 @@ pub use true_strength_index::TrueStrengthIndex;
 mod vidya;
 pub use vidya::Vidya;
 
+mod volume_index;
+pub use volume_index::VolumeIndex;
+
+mod volume_profile;
+pub use volume_profile::VolumeProfile;
+
+mod volume_weighted_average_price;
+pub use volume_weighted_average_price::{VolumeWeightedAveragePrice, VWAP};
+
+mod volume_weighted_macd;
+pub use volume_weighted_macd::{VolumeWeightedMACD, VWMACD};
+
+mod vortex;
+pub use vortex::Vortex;
+
 mod woodies_cci;
 pub use woodies_cci::WoodiesCCI;
+
+mod zero_lag_macd;
+pub use zero_lag_macd::ZeroLagMACD;
+
+#[cfg(feature = "snapshot")]
+use crate::core::{snapshot_tags, OHLC, OHLCV};
+
+#[cfg(feature = "snapshot")]
+snapshot_tags! {
+	"indicators::AccumulationDistribution" => AccumulationDistribution,
+	"indicators::AccumulationDistributionInstance" => accumulation_distribution::AccumulationDistributionInstance<T> where T: OHLCV,
+	"indicators::Alligator" => Alligator,
+	"indicators::AlligatorInstance" => AlligatorInstance,
+	"indicators::AnchoredVWAP" => AnchoredVWAP,
+	"indicators::AnchoredVWAPInstance" => AnchoredVWAPInstance<T> where T: OHLCV,
+	"indicators::Aroon" => Aroon,
+	"indicators::AroonInstance" => aroon::AroonInstance<T> where T: OHLC,
+	"indicators::ATRTrailingStop" => ATRTrailingStop,
+	"indicators::ATRTrailingStopInstance" => atr_trailing_stop::ATRTrailingStopInstance<T> where T: OHLC,
+	"indicators::AverageDirectionalIndex" => AverageDirectionalIndex,
+	"indicators::AverageDirectionalIndexInstance" => average_directional_index::AverageDirectionalIndexInstance<T> where T: OHLC,
+	"indicators::AwesomeOscillator" => AwesomeOscillator,
+	"indicators::AwesomeOscillatorInstance" => awesome_oscillator::AwesomeOscillatorInstance,
+	"indicators::BollingerBands" => BollingerBands,
+	"indicators::BollingerBandsInstance" => bollinger_bands::BollingerBandsInstance,
+	"indicators::CandlestickPatterns" => CandlestickPatterns,
+	"indicators::CandlestickPatternsInstance" => candlestick_patterns::CandlestickPatternsInstance<T> where T: OHLC,
+	"indicators::CenterOfGravity" => CenterOfGravity,
+	"indicators::CenterOfGravityInstance" => center_of_gravity::CenterOfGravityInstance,
+	"indicators::ChaikinMoneyFlow" => ChaikinMoneyFlow,
+	"indicators::ChaikinMoneyFlowInstance" => chaikin_money_flow::ChaikinMoneyFlowInstance<T> where T: OHLCV,
+	"indicators::ChaikinOscillator" => ChaikinOscillator,
+	"indicators::ChaikinOscillatorInstance" => chaikin_oscillator::ChaikinOscillatorInstance<T> where T: OHLCV,
+	"indicators::ChandeKrollStop" => ChandeKrollStop,
+	"indicators::ChandeKrollStopInstance" => chande_kroll_stop::ChandeKrollStopInstance<T> where T: OHLC,
+	"indicators::ChandeMomentumOscillator" => ChandeMomentumOscillator,
+	"indicators::ChandeMomentumOscillatorInstance" => chande_momentum_oscillator::ChandeMomentumOscillatorInstance,
+	"indicators::CommodityChannelIndex" => CommodityChannelIndex,
+	"indicators::CommodityChannelIndexInstance" => commodity_channel_index::CommodityChannelIndexInstance,
+	"indicators::ConnorsRSI" => ConnorsRSI,
+	"indicators::ConnorsRSIInstance" => connors_rsi::ConnorsRSIInstance,
+	"indicators::CoppockCurve" => CoppockCurve,
+	"indicators::CoppockCurveInstance" => coppock_curve::CoppockCurveInstance,
+	"indicators::DetrendedPriceOscillator" => DetrendedPriceOscillator,
+	"indicators::DetrendedPriceOscillatorInstance" => detrended_price_oscillator::DetrendedPriceOscillatorInstance,
+	"indicators::DonchianChannels" => DonchianChannels,
+	"indicators::DonchianChannelsInstance" => donchian_channels::DonchianChannelsInstance,
+	"indicators::DynamicMomentumIndex" => DynamicMomentumIndex,
+	"indicators::DynamicMomentumIndexInstance" => dynamic_momentum_index::DynamicMomentumIndexInstance,
+	"indicators::EaseOfMovement" => EaseOfMovement,
+	"indicators::EaseOfMovementInstance" => ease_of_movement::EaseOfMovementInstance<T> where T: OHLCV,
+	"indicators::ElderImpulse" => ElderImpulse,
+	"indicators::ElderImpulseInstance" => elder_impulse::ElderImpulseInstance,
+	"indicators::ElderRay" => ElderRay,
+	"indicators::ElderRayInstance" => elder_ray::ElderRayInstance,
+	"indicators::EldersForceIndex" => EldersForceIndex,
+	"indicators::EldersForceIndexInstance" => elders_force_index::EldersForceIndexInstance<T> where T: OHLCV,
+	"indicators::Envelopes" => Envelopes,
+	"indicators::EnvelopesInstance" => envelopes::EnvelopesInstance,
+	"indicators::Example" => example::Example,
+	"indicators::ExampleInstance" => example::ExampleInstance,
+	"indicators::FisherTransform" => FisherTransform,
+	"indicators::FisherTransformInstance" => fisher_transform::FisherTransformInstance,
+	"indicators::Fractals" => Fractals,
+	"indicators::FractalsInstance" => fractals::FractalsInstance,
+	"indicators::GatorOscillator" => GatorOscillator,
+	"indicators::GatorOscillatorInstance" => gator_oscillator::GatorOscillatorInstance,
+	"indicators::HeikinAshi" => HeikinAshi,
+	"indicators::HeikinAshiInstance" => heikin_ashi::HeikinAshiInstance,
+	"indicators::HullMovingAverage" => HullMovingAverage,
+	"indicators::HullMovingAverageInstance" => hull_moving_average::HullMovingAverageInstance,
+	"indicators::IchimokuCloud" => IchimokuCloud,
+	"indicators::IchimokuCloudInstance" => ichimoku_cloud::IchimokuCloudInstance,
+	"indicators::Kaufman" => Kaufman,
+	"indicators::KaufmanInstance" => kaufman::KaufmanInstance,
+	"indicators::KeltnerChannels" => KeltnerChannels,
+	"indicators::KeltnerChannelsInstance" => keltner_channels::KeltnerChannelsInstance<T> where T: OHLC,
+	"indicators::KlingerVolumeOscillator" => KlingerVolumeOscillator,
+	"indicators::KlingerVolumeOscillatorInstance" => klinger_volume_oscillator::KlingerVolumeOscillatorInstance,
+	"indicators::KnowSureThing" => KnowSureThing,
+	"indicators::KnowSureThingInstance" => know_sure_thing::KnowSureThingInstance,
+	"indicators::MACD" => MACD,
+	"indicators::MACDInstance" => macd::MACDInstance,
+	"indicators::MESAAdaptiveMovingAverage" => MESAAdaptiveMovingAverage,
+	"indicators::MESAAdaptiveMovingAverageInstance" => mesa_adaptive_moving_average::MESAAdaptiveMovingAverageInstance,
+	"indicators::MomentumIndex" => MomentumIndex,
+	"indicators::MomentumIndexInstance" => momentum_index::MomentumIndexInstance,
+	"indicators::MoneyFlowIndex" => MoneyFlowIndex,
+	"indicators::MoneyFlowIndexInstance" => money_flow_index::MoneyFlowIndexInstance<T> where T: OHLCV,
+	"indicators::ParabolicSAR" => ParabolicSAR,
+	"indicators::ParabolicSARInstance" => parabolic_sar::ParabolicSARInstance<T> where T: OHLC,
+	"indicators::PercentageVolumeOscillator" => PercentageVolumeOscillator,
+	"indicators::PercentageVolumeOscillatorInstance" => percentage_volume_oscillator::PercentageVolumeOscillatorInstance,
+	"indicators::PivotPoints" => PivotPoints,
+	"indicators::PivotPointsInstance" => PivotPointsInstance,
+	"indicators::PivotReversalStrategy" => PivotReversalStrategy,
+	"indicators::PivotReversalStrategyInstance" => pivot_reversal_strategy::PivotReversalStrategyInstance<T> where T: OHLC,
+	"indicators::PriceChannelStrategy" => PriceChannelStrategy,
+	"indicators::PriceChannelStrategyInstance" => price_channel_strategy::PriceChannelStrategyInstance,
+	"indicators::PriceVolumeTrend" => PriceVolumeTrend,
+	"indicators::PriceVolumeTrendInstance" => price_volume_trend::PriceVolumeTrendInstance,
+	"indicators::RangeActionVerificationIndex" => RangeActionVerificationIndex,
+	"indicators::RangeActionVerificationIndexInstance" => range_action_verification_index::RangeActionVerificationIndexInstance,
+	"indicators::RelativeStrengthIndex" => RelativeStrengthIndex,
+	"indicators::RelativeStrengthIndexInstance" => relative_strength_index::RelativeStrengthIndexInstance,
+	"indicators::RelativeVigorIndex" => RelativeVigorIndex,
+	"indicators::RelativeVigorIndexInstance" => relative_vigor_index::RelativeVigorIndexInstance,
+	"indicators::RelativeVolatilityIndex" => RelativeVolatilityIndex,
+	"indicators::RelativeVolatilityIndexInstance" => relative_volatility_index::RelativeVolatilityIndexInstance,
+	"indicators::RelativeVolume" => RelativeVolume,
+	"indicators::RelativeVolumeInstance" => relative_volume::RelativeVolumeInstance,
+	"indicators::SMIErgodicIndicator" => SMIErgodicIndicator,
+	"indicators::SMIErgodicIndicatorInstance" => smi_ergodic_indicator::SMIErgodicIndicatorInstance,
+	"indicators::SqueezeMomentum" => SqueezeMomentum,
+	"indicators::SqueezeMomentumInstance" => squeeze_momentum::SqueezeMomentumInstance<T> where T: OHLC,
+	"indicators::StandardErrorBands" => StandardErrorBands,
+	"indicators::StandardErrorBandsInstance" => standard_error_bands::StandardErrorBandsInstance,
+	"indicators::StochasticOscillator" => StochasticOscillator,
+	"indicators::StochasticOscillatorInstance" => stochastic_oscillator::StochasticOscillatorInstance,
+	"indicators::SuperTrend" => SuperTrend,
+	"indicators::SuperTrendInstance" => super_trend::SuperTrendInstance<T> where T: OHLC,
+	"indicators::SupportResistance" => SupportResistance,
+	"indicators::SupportResistanceInstance" => support_resistance::SupportResistanceInstance,
+	"indicators::Trix" => Trix,
+	"indicators::TRIXInstance" => trix::TRIXInstance,
+	"indicators::TrueStrengthIndex" => TrueStrengthIndex,
+	"indicators::TrueStrengthIndexInstance" => true_strength_index::TrueStrengthIndexInstance,
+	"indicators::TVFisherTransform" => TVFisherTransform,
+	"indicators::TVFisherTransformInstance" => TVFisherTransformInstance,
+	"indicators::Vidya" => Vidya,
+	"indicators::VidyaInstance" => vidya::VidyaInstance,
+	"indicators::VolumeIndex" => VolumeIndex,
+	"indicators::VolumeIndexInstance" => volume_index::VolumeIndexInstance,
+	"indicators::VolumeProfile" => VolumeProfile,
+	"indicators::VolumeProfileInstance" => volume_profile::VolumeProfileInstance,
+	"indicators::VolumeWeightedAveragePrice" => VolumeWeightedAveragePrice,
+	"indicators::VolumeWeightedAveragePriceInstance" => volume_weighted_average_price::VolumeWeightedAveragePriceInstance,
+	"indicators::VolumeWeightedMACD" => VolumeWeightedMACD,
+	"indicators::VolumeWeightedMACDInstance" => volume_weighted_macd::VolumeWeightedMACDInstance,
+	"indicators::Vortex" => Vortex,
+	"indicators::VortexInstance" => vortex::VortexInstance<T> where T: OHLC,
+	"indicators::WoodiesCCI" => WoodiesCCI,
+	"indicators::WoodiesCCIInstance" => woodies_cci::WoodiesCCIInstance,
+	"indicators::ZeroLagMACD" => ZeroLagMACD,
+	"indicators::ZeroLagMACDInstance" => zero_lag_macd::ZeroLagMACDInstance,
+}
@@ -1,9 +1,14 @@
 #[cfg(feature = "serde")]
 use serde::{Deserialize, Serialize};
 
-use crate::core::{Action, Error, Method, PeriodType, Source, OHLC};
+use crate::core::{Action, Error, Method, PeriodType, Source, ValueType, OHLC};
 use crate::core::{IndicatorConfig, IndicatorInitializer, IndicatorInstance, IndicatorResult};
+use crate::core::{ParamDescriptor, ParamKind};
 use crate::methods::Momentum;
+use alloc::string::String;
+use alloc::string::ToString;
+use alloc::vec::Vec;
+use core::ops::Bound::{Excluded, Included};
 
 #[derive(Debug, Clone, Copy)]
 #[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
@@ -15,6 +20,8 @@ pub struct MomentumIndex {
 
 impl IndicatorConfig for MomentumIndex {
 	const NAME: &'static str = "MomentumIndex";
+	const VALUE_NAMES: &'static [&'static str] = &["value", "momentum"];
+	const SIGNAL_NAMES: &'static [&'static str] = &["signal"];
 
 	fn validate(&self) -> bool {
 		self.period2 > 0 && self.period1 > self.period2
@@ -36,16 +43,35 @@ impl IndicatorConfig for MomentumIndex {
 			},
 
 			_ => {
-				return Some(Error::ParameterParse(name.to_string(), value));
+				return Some(Error::UnknownParameter(name.to_string()));
 			}
 		};
 
 		None
 	}
 
+	fn parameters(&self) -> Vec<ParamDescriptor> {
+		let default = Self::default();
+
+		vec![
+			ParamDescriptor::new("period1", ParamKind::Period, self.period1, default.period1)
+				.range(
+					Excluded(self.period2 as ValueType),
+					Included(PeriodType::MAX as ValueType),
+				),
+			ParamDescriptor::new("period2", ParamKind::Period, self.period2, default.period2)
+				.range(Included(1.), Excluded(self.period1 as ValueType)),
+			ParamDescriptor::new("source", ParamKind::Source, self.source, default.source),
+		]
+	}
+
 	fn size(&self) -> (u8, u8) {
 		(2, 1)
 	}
+
+	fn min_periods(&self) -> PeriodType {
+		self.period1.max(self.period2).saturating_add(1)
+	}
 }
 
 impl<T: OHLC> IndicatorInitializer<T> for MomentumIndex {
@@ -80,7 +106,8 @@ impl Default for MomentumIndex {
 	}
 }
 
-#[derive(Debug)]
+#[derive(Debug, Clone)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 pub struct MomentumIndexInstance {
 	cfg: MomentumIndex,
 
@@ -114,4 +141,11 @@ impl<T: OHLC> IndicatorInstance<T> for MomentumIndexInstance {
 
 		IndicatorResult::new(&[v, s], &[Action::from(signal)])
 	}
+
+	fn reset(&mut self, candle: T) -> Result<(), Error> {
+		let src = candle.source(self.cfg.source);
+
+		self.momentum1.reset(src)?;
+		self.momentum2.reset(src)
+	}
 }
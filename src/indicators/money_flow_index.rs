@@ -3,7 +3,12 @@ use serde::{Deserialize, Serialize};
 
 use crate::core::{Error, Method, PeriodType, ValueType, Window, OHLCV};
 use crate::core::{IndicatorConfig, IndicatorInitializer, IndicatorInstance, IndicatorResult};
+use crate::core::{ParamDescriptor, ParamKind};
 use crate::methods::{CrossAbove, CrossUnder};
+use alloc::string::String;
+use alloc::string::ToString;
+use alloc::vec::Vec;
+use core::ops::Bound::Included;
 
 #[derive(Debug, Clone, Copy)]
 #[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
@@ -14,6 +19,8 @@ pub struct MoneyFlowIndex {
 
 impl IndicatorConfig for MoneyFlowIndex {
 	const NAME: &'static str = "MoneyFlowIndex";
+	const VALUE_NAMES: &'static [&'static str] = &["upper", "value", "lower"];
+	const SIGNAL_NAMES: &'static [&'static str] = &["signal"];
 
 	fn validate(&self) -> bool {
 		self.zone >= 0. && self.zone <= 0.5
@@ -31,13 +38,24 @@ impl IndicatorConfig for MoneyFlowIndex {
 			},
 
 			_ => {
-				return Some(Error::ParameterParse(name.to_string(), value));
+				return Some(Error::UnknownParameter(name.to_string()));
 			}
 		};
 
 		None
 	}
 
+	fn parameters(&self) -> Vec<ParamDescriptor> {
+		let default = Self::default();
+
+		vec![
+			ParamDescriptor::new("period", ParamKind::Period, self.period, default.period)
+				.range(Included(1.), Included(PeriodType::MAX as ValueType)),
+			ParamDescriptor::new("zone", ParamKind::Float, self.zone, default.zone)
+				.range(Included(0.), Included(0.5)),
+		]
+	}
+
 	fn is_volume_based(&self) -> bool {
 		true
 	}
@@ -45,6 +63,10 @@ impl IndicatorConfig for MoneyFlowIndex {
 	fn size(&self) -> (u8, u8) {
 		(3, 1)
 	}
+
+	fn min_periods(&self) -> PeriodType {
+		self.period.saturating_add(1)
+	}
 }
 
 impl<T: OHLCV> IndicatorInitializer<T> for MoneyFlowIndex {
@@ -81,7 +103,8 @@ impl Default for MoneyFlowIndex {
 	}
 }
 
-#[derive(Debug)]
+#[derive(Debug, Clone)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 pub struct MoneyFlowIndexInstance<T: OHLCV> {
 	cfg: MoneyFlowIndex,
 
@@ -150,4 +173,16 @@ impl<T: OHLCV> IndicatorInstance<T> for MoneyFlowIndexInstance<T> {
 
 		IndicatorResult::new(&[upper, value, lower], &[signal])
 	}
+
+	fn reset(&mut self, candle: T) -> Result<(), Error> {
+		self.window.fill(candle);
+		self.prev_candle = candle;
+		self.last_prev_candle = candle;
+		self.pmf = 0.;
+		self.nmf = 0.;
+		self.cross_under = CrossUnder::default();
+		self.cross_above = CrossAbove::default();
+
+		Ok(())
+	}
 }
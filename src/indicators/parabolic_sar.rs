@@ -3,7 +3,15 @@ use serde::{Deserialize, Serialize};
 
 use crate::core::{Action, Error, ValueType, OHLC};
 use crate::core::{IndicatorConfig, IndicatorInitializer, IndicatorInstance, IndicatorResult};
-use std::cmp::Ordering;
+use crate::core::{ParamDescriptor, ParamKind};
+use alloc::string::String;
+use alloc::string::ToString;
+use alloc::vec::Vec;
+use core::cmp::Ordering;
+use core::ops::Bound::{Excluded, Unbounded};
+
+#[cfg(not(feature = "std"))]
+use crate::core::Float as _;
 
 // https://en.wikipedia.org/wiki/Parabolic_SAR
 #[derive(Debug, Clone, Copy)]
@@ -15,6 +23,8 @@ pub struct ParabolicSAR {
 
 impl IndicatorConfig for ParabolicSAR {
 	const NAME: &'static str = "ParabolicSAR";
+	const VALUE_NAMES: &'static [&'static str] = &["sar", "trend"];
+	const SIGNAL_NAMES: &'static [&'static str] = &["reverse"];
 
 	fn validate(&self) -> bool {
 		self.af_step < self.af_max
@@ -32,13 +42,24 @@ impl IndicatorConfig for ParabolicSAR {
 			},
 
 			_ => {
-				return Some(Error::ParameterParse(name.to_string(), value));
+				return Some(Error::UnknownParameter(name.to_string()));
 			}
 		};
 
 		None
 	}
 
+	fn parameters(&self) -> Vec<ParamDescriptor> {
+		let default = Self::default();
+
+		vec![
+			ParamDescriptor::new("af_step", ParamKind::Float, self.af_step, default.af_step)
+				.range(Unbounded, Excluded(self.af_max)),
+			ParamDescriptor::new("af_max", ParamKind::Float, self.af_max, default.af_max)
+				.range(Excluded(self.af_step), Unbounded),
+		]
+	}
+
 	fn size(&self) -> (u8, u8) {
 		(2, 1)
 	}
@@ -78,7 +99,8 @@ impl Default for ParabolicSAR {
 	}
 }
 
-#[derive(Debug)]
+#[derive(Debug, Clone)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 pub struct ParabolicSARInstance<T: OHLC> {
 	cfg: ParabolicSAR,
 
@@ -165,4 +187,16 @@ impl<T: OHLC> IndicatorInstance<T> for ParabolicSARInstance<T> {
 
 		IndicatorResult::new(&[sar, trend as ValueType], &[Action::from(signal)])
 	}
+
+	fn reset(&mut self, candle: T) -> Result<(), Error> {
+		self.trend = 1;
+		self.trend_inc = 1;
+		self.low = candle.low();
+		self.high = candle.high();
+		self.sar = candle.low();
+		self.prev_candle = candle;
+		self.prev_trend = 0;
+
+		Ok(())
+	}
 }
@@ -0,0 +1,231 @@
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
+
+use crate::core::{Error, Method, PeriodType, ValueType, OHLCV};
+use crate::core::{IndicatorConfig, IndicatorInitializer, IndicatorInstance, IndicatorResult};
+use crate::core::{ParamDescriptor, ParamKind};
+use crate::helpers::{method, RegularMethod, RegularMethods};
+use crate::methods::Cross;
+use alloc::string::String;
+use alloc::string::ToString;
+use alloc::vec::Vec;
+use core::ops::Bound::{Excluded, Included};
+
+/// Percentage Volume Oscillator
+///
+/// The same construction as Percentage Price Oscillator, but applied to *volume*.
+///
+/// ## Links
+///
+/// * <https://school.stockcharts.com/doku.php?id=technical_indicators:percentage_volume_oscillator_pvo>
+///
+/// # 3 values
+///
+/// * `PVO` value \(relative difference between fast and slow *volume* moving averages\)
+///
+/// Range in \[-1.0; +inf\)
+///
+/// * `signal line` value
+///
+/// Range in \[-1.0; +inf\)
+///
+/// * `histogram` value \(`PVO` − `signal line`\)
+///
+/// Range in \(-inf; +inf\)
+///
+/// Values are relative, so `0.1` means *volume* fast MA is 10% higher than slow MA.
+///
+/// # 1 signal
+///
+/// When `PVO` crosses `signal line` upwards \(volume surge\), then returns full buy signal.
+/// When `PVO` crosses `signal line` downwards, then returns full sell signal.
+/// Otherwise no signal
+#[derive(Debug, Clone, Copy)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct PercentageVolumeOscillator {
+	/// Fast MA period length. Default is `12`.
+	///
+	/// Range in \[1; `period2`\)
+	pub period1: PeriodType,
+	/// Slow MA period length. Default is `26`.
+	///
+	/// Range in \(`period1`; [`PeriodType::MAX`](crate::core::PeriodType)\)
+	pub period2: PeriodType,
+	/// Signal line MA period length. Default is `9`.
+	///
+	/// Range in \[1; [`PeriodType::MAX`](crate::core::PeriodType)\)
+	pub period3: PeriodType,
+	/// Fast MA method. Default is [`EMA`](crate::methods::EMA).
+	pub method1: RegularMethods,
+	/// Slow MA method. Default is [`EMA`](crate::methods::EMA).
+	pub method2: RegularMethods,
+	/// Signal line MA method. Default is [`EMA`](crate::methods::EMA).
+	pub method3: RegularMethods,
+}
+
+impl IndicatorConfig for PercentageVolumeOscillator {
+	const NAME: &'static str = "PercentageVolumeOscillator";
+	const VALUE_NAMES: &'static [&'static str] = &["pvo", "signal_line", "histogram"];
+	const SIGNAL_NAMES: &'static [&'static str] = &["signal"];
+
+	fn validate(&self) -> bool {
+		self.period1 > 0
+			&& self.period1 < self.period2
+			&& self.period2 < PeriodType::MAX
+			&& self.period3 > 0
+			&& self.period3 < PeriodType::MAX
+	}
+
+	fn set(&mut self, name: &str, value: String) -> Option<Error> {
+		match name {
+			"period1" => match value.parse() {
+				Err(_) => return Some(Error::ParameterParse(name.to_string(), value.to_string())),
+				Ok(value) => self.period1 = value,
+			},
+			"period2" => match value.parse() {
+				Err(_) => return Some(Error::ParameterParse(name.to_string(), value.to_string())),
+				Ok(value) => self.period2 = value,
+			},
+			"period3" => match value.parse() {
+				Err(_) => return Some(Error::ParameterParse(name.to_string(), value.to_string())),
+				Ok(value) => self.period3 = value,
+			},
+			"method1" => match value.parse() {
+				Err(_) => return Some(Error::ParameterParse(name.to_string(), value.to_string())),
+				Ok(value) => self.method1 = value,
+			},
+			"method2" => match value.parse() {
+				Err(_) => return Some(Error::ParameterParse(name.to_string(), value.to_string())),
+				Ok(value) => self.method2 = value,
+			},
+			"method3" => match value.parse() {
+				Err(_) => return Some(Error::ParameterParse(name.to_string(), value.to_string())),
+				Ok(value) => self.method3 = value,
+			},
+
+			_ => {
+				return Some(Error::UnknownParameter(name.to_string()));
+			}
+		};
+
+		None
+	}
+
+	fn parameters(&self) -> Vec<ParamDescriptor> {
+		let default = Self::default();
+
+		vec![
+			ParamDescriptor::new("period1", ParamKind::Period, self.period1, default.period1)
+				.range(Included(1.), Excluded(self.period2 as ValueType)),
+			ParamDescriptor::new("period2", ParamKind::Period, self.period2, default.period2)
+				.range(
+					Excluded(self.period1 as ValueType),
+					Excluded(PeriodType::MAX as ValueType),
+				),
+			ParamDescriptor::new("period3", ParamKind::Period, self.period3, default.period3)
+				.range(Included(1.), Excluded(PeriodType::MAX as ValueType)),
+			ParamDescriptor::new("method1", ParamKind::Method, self.method1, default.method1),
+			ParamDescriptor::new("method2", ParamKind::Method, self.method2, default.method2),
+			ParamDescriptor::new("method3", ParamKind::Method, self.method3, default.method3),
+		]
+	}
+
+	fn is_volume_based(&self) -> bool {
+		true
+	}
+
+	fn size(&self) -> (u8, u8) {
+		(3, 1)
+	}
+
+	fn min_periods(&self) -> PeriodType {
+		self.period1
+			.max(self.period2)
+			.saturating_add(self.period3)
+			.saturating_sub(1)
+	}
+}
+
+impl<T: OHLCV> IndicatorInitializer<T> for PercentageVolumeOscillator {
+	type Instance = PercentageVolumeOscillatorInstance;
+
+	fn init(self, candle: T) -> Result<Self::Instance, Error>
+	where
+		Self: Sized,
+	{
+		if !self.validate() {
+			return Err(Error::WrongConfig);
+		}
+
+		let cfg = self;
+		let volume = candle.volume();
+
+		Ok(Self::Instance {
+			ma1: method(cfg.method1, cfg.period1, volume)?,
+			ma2: method(cfg.method2, cfg.period2, volume)?,
+			ma3: method(cfg.method3, cfg.period3, 0.)?,
+			cross: Cross::new((), (0., 0.))?,
+			cfg,
+		})
+	}
+}
+
+impl Default for PercentageVolumeOscillator {
+	fn default() -> Self {
+		Self {
+			period1: 12,
+			period2: 26,
+			period3: 9,
+			method1: RegularMethods::EMA,
+			method2: RegularMethods::EMA,
+			method3: RegularMethods::EMA,
+		}
+	}
+}
+
+/// Just an alias for [`PercentageVolumeOscillator`]
+pub type PVO = PercentageVolumeOscillator;
+
+/// Percentage Volume Oscillator state structure
+#[derive(Debug, Clone)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct PercentageVolumeOscillatorInstance {
+	cfg: PercentageVolumeOscillator,
+
+	ma1: RegularMethod,
+	ma2: RegularMethod,
+	ma3: RegularMethod,
+	cross: Cross,
+}
+
+impl<T: OHLCV> IndicatorInstance<T> for PercentageVolumeOscillatorInstance {
+	type Config = PercentageVolumeOscillator;
+
+	fn config(&self) -> &Self::Config {
+		&self.cfg
+	}
+
+	fn next(&mut self, candle: T) -> IndicatorResult {
+		let volume = candle.volume();
+
+		let fast = self.ma1.next(volume);
+		let slow = self.ma2.next(volume);
+
+		let pvo = if slow > 0. { (fast - slow) / slow } else { 0. };
+		let sigline = self.ma3.next(pvo);
+		let histogram = pvo - sigline;
+
+		let signal = self.cross.next((pvo, sigline));
+
+		IndicatorResult::new(&[pvo, sigline, histogram], &[signal])
+	}
+
+	fn reset(&mut self, candle: T) -> Result<(), Error> {
+		let volume = candle.volume();
+
+		self.ma1.reset(volume)?;
+		self.ma2.reset(volume)?;
+		self.ma3.reset(0.)?;
+		self.cross.reset((0., 0.))
+	}
+}
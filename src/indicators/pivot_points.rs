@@ -0,0 +1,349 @@
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
+
+use crate::core::{Action, Anchor, Error, EveryBars, ExternalAnchor, PeriodType, ValueType, OHLC};
+use crate::core::{IndicatorConfig, IndicatorInitializer, IndicatorInstance, IndicatorResult};
+use crate::core::{ParamDescriptor, ParamKind};
+use alloc::string::String;
+use alloc::string::ToString;
+use alloc::vec::Vec;
+use core::fmt;
+use core::ops::Bound::{Excluded, Included};
+use core::str::FromStr;
+
+#[cfg(not(feature = "std"))]
+use crate::core::Float as _;
+
+/// Formula used by [`PivotPoints`] to calculate levels
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[cfg_attr(feature = "serde", serde(rename_all = "lowercase"))]
+pub enum PivotPointsVariant {
+	/// Classic \(floor\) pivot points
+	Classic,
+
+	/// Fibonacci pivot points
+	Fibonacci,
+
+	/// Camarilla pivot points
+	Camarilla,
+}
+
+impl FromStr for PivotPointsVariant {
+	type Err = String;
+
+	fn from_str(s: &str) -> Result<Self, Self::Err> {
+		match s.to_ascii_lowercase().trim() {
+			"classic" | "floor" => Ok(Self::Classic),
+			"fibonacci" | "fib" => Ok(Self::Fibonacci),
+			"camarilla" => Ok(Self::Camarilla),
+
+			_ => Err(format!("Unknown pivot points variant {}", s)),
+		}
+	}
+}
+
+impl fmt::Display for PivotPointsVariant {
+	fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+		let name = match self {
+			Self::Classic => "classic",
+			Self::Fibonacci => "fibonacci",
+			Self::Camarilla => "camarilla",
+		};
+
+		f.write_str(name)
+	}
+}
+
+/// Pivot points levels
+#[derive(Debug, Clone, Copy, PartialEq)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct PivotLevels {
+	/// Pivot point
+	pub pivot: ValueType,
+	/// Resistance levels `R1`, `R2`, `R3`
+	pub resistance: [ValueType; 3],
+	/// Support levels `S1`, `S2`, `S3`
+	pub support: [ValueType; 3],
+}
+
+impl PivotLevels {
+	/// Calculates levels over *high*, *low* and *close* prices of the previous higher-timeframe bar
+	#[must_use]
+	pub fn new(
+		variant: PivotPointsVariant,
+		high: ValueType,
+		low: ValueType,
+		close: ValueType,
+	) -> Self {
+		let pivot = (high + low + close) / 3.;
+		let range = high - low;
+
+		let (resistance, support) = match variant {
+			PivotPointsVariant::Classic => (
+				[
+					pivot.mul_add(2., -low),
+					pivot + range,
+					(pivot - low).mul_add(2., high),
+				],
+				[
+					pivot.mul_add(2., -high),
+					pivot - range,
+					(pivot - high).mul_add(2., low),
+				],
+			),
+			PivotPointsVariant::Fibonacci => (
+				[
+					range.mul_add(0.382, pivot),
+					range.mul_add(0.618, pivot),
+					pivot + range,
+				],
+				[
+					range.mul_add(-0.382, pivot),
+					range.mul_add(-0.618, pivot),
+					pivot - range,
+				],
+			),
+			PivotPointsVariant::Camarilla => (
+				[
+					range.mul_add(1.1 / 12., close),
+					range.mul_add(1.1 / 6., close),
+					range.mul_add(1.1 / 4., close),
+				],
+				[
+					range.mul_add(-1.1 / 12., close),
+					range.mul_add(-1.1 / 6., close),
+					range.mul_add(-1.1 / 4., close),
+				],
+			),
+		};
+
+		Self {
+			pivot,
+			resistance,
+			support,
+		}
+	}
+
+	fn iter(&self) -> impl Iterator<Item = ValueType> + '_ {
+		core::iter::once(self.pivot)
+			.chain(self.resistance.iter().copied())
+			.chain(self.support.iter().copied())
+	}
+}
+
+/// Pivot Points \(classic, Fibonacci, Camarilla\)
+///
+/// Levels are calculated over *high*, *low* and *close* prices of the previous higher-timeframe bar.
+/// Higher-timeframe bar rolls over every `period` candles or when the anchor is fired explicitly
+/// by calling [`PivotPointsInstance::anchor`], which starts the count of candles over. Until the first rollover levels are calculated over the first candle.
+///
+/// All the levels are available by calling [`PivotPointsInstance::levels`].
+///
+/// ## Links
+///
+/// * <https://en.wikipedia.org/wiki/Pivot_point_(technical_analysis)>
+///
+/// # 3 values
+///
+/// * `pivot` value
+/// * nearest `resistance` value \(the lowest level above *close* price\)
+/// * nearest `support` value \(the highest level below *close* price\)
+///
+/// When there is no level above \(below\) *close* price, then `R3` \(`S3`\) is returned.
+///
+/// Range of values is the same as the range of the price values.
+///
+/// # 1 signal
+///
+/// When *close* price crosses any level upwards, then returns full buy signal.
+/// When *close* price crosses any level downwards, then returns full sell signal.
+/// Otherwise no signal
+#[derive(Debug, Clone, Copy)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct PivotPoints {
+	/// Count of candles in the higher-timeframe bar. Default is `24`.
+	///
+	/// When `period` is `0`, then rollover happens only on explicit anchor.
+	///
+	/// Range in \[0; [`PeriodType::MAX`](crate::core::PeriodType)\)
+	pub period: PeriodType,
+	/// Levels formula. Default is [`Classic`](PivotPointsVariant::Classic).
+	pub variant: PivotPointsVariant,
+}
+
+impl IndicatorConfig for PivotPoints {
+	const NAME: &'static str = "PivotPoints";
+	const VALUE_NAMES: &'static [&'static str] = &["pivot", "resistance", "support"];
+	const SIGNAL_NAMES: &'static [&'static str] = &["signal"];
+
+	fn validate(&self) -> bool {
+		self.period < PeriodType::MAX
+	}
+
+	fn set(&mut self, name: &str, value: String) -> Option<Error> {
+		match name {
+			"period" => match value.parse() {
+				Err(_) => return Some(Error::ParameterParse(name.to_string(), value.to_string())),
+				Ok(value) => self.period = value,
+			},
+			"variant" => match value.parse() {
+				Err(_) => return Some(Error::ParameterParse(name.to_string(), value.to_string())),
+				Ok(value) => self.variant = value,
+			},
+
+			_ => {
+				return Some(Error::UnknownParameter(name.to_string()));
+			}
+		};
+
+		None
+	}
+
+	fn parameters(&self) -> Vec<ParamDescriptor> {
+		let default = Self::default();
+
+		vec![
+			ParamDescriptor::new("period", ParamKind::Period, self.period, default.period)
+				.range(Included(0.), Excluded(PeriodType::MAX as ValueType)),
+			ParamDescriptor::new("variant", ParamKind::Variant, self.variant, default.variant),
+		]
+	}
+
+	fn size(&self) -> (u8, u8) {
+		(3, 1)
+	}
+}
+
+impl<T: OHLC> IndicatorInitializer<T> for PivotPoints {
+	type Instance = PivotPointsInstance;
+
+	fn init(self, candle: T) -> Result<Self::Instance, Error>
+	where
+		Self: Sized,
+	{
+		if !self.validate() {
+			return Err(Error::WrongConfig);
+		}
+
+		let cfg = self;
+		let (high, low, close) = (candle.high(), candle.low(), candle.close());
+		let bars = match cfg.period {
+			0 => None,
+			period => Some(EveryBars::new(period as usize)?),
+		};
+
+		Ok(Self::Instance {
+			levels: PivotLevels::new(cfg.variant, high, low, close),
+			high,
+			low,
+			close,
+			anchor: (ExternalAnchor::default(), bars),
+			prev_close: close,
+			cfg,
+		})
+	}
+}
+
+impl Default for PivotPoints {
+	fn default() -> Self {
+		Self {
+			period: 24,
+			variant: PivotPointsVariant::Classic,
+		}
+	}
+}
+
+/// Pivot Points state structure
+#[derive(Debug, Clone, Copy)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct PivotPointsInstance {
+	cfg: PivotPoints,
+
+	levels: PivotLevels,
+	high: ValueType,
+	low: ValueType,
+	close: ValueType,
+	anchor: (ExternalAnchor, Option<EveryBars>),
+	prev_close: ValueType,
+}
+
+impl PivotPointsInstance {
+	/// Fires the anchor: new higher-timeframe bar starts from the next candle
+	pub fn anchor(&mut self) {
+		self.anchor.0.fire();
+	}
+
+	/// Returns current pivot points levels
+	#[must_use]
+	pub const fn levels(&self) -> &PivotLevels {
+		&self.levels
+	}
+}
+
+impl<T: OHLC> IndicatorInstance<T> for PivotPointsInstance {
+	type Config = PivotPoints;
+
+	fn config(&self) -> &Self::Config {
+		&self.cfg
+	}
+
+	fn next(&mut self, candle: T) -> IndicatorResult {
+		let (high, low, close) = (candle.high(), candle.low(), candle.close());
+
+		if self.anchor.fires(None) {
+			self.levels = PivotLevels::new(self.cfg.variant, self.high, self.low, self.close);
+			self.high = high;
+			self.low = low;
+		} else {
+			self.high = self.high.max(high);
+			self.low = self.low.min(low);
+		}
+
+		self.close = close;
+
+		let prev_close = self.prev_close;
+		self.prev_close = close;
+
+		let (crossed_up, crossed_down) =
+			self.levels
+				.iter()
+				.fold((false, false), |(up, down), level| {
+					(
+						up || (prev_close <= level && close > level),
+						down || (prev_close >= level && close < level),
+					)
+				});
+
+		let signal = crossed_up as i8 - crossed_down as i8;
+
+		let resistance = self
+			.levels
+			.iter()
+			.filter(|&level| level > close)
+			.fold(self.levels.resistance[2], ValueType::min);
+		let support = self
+			.levels
+			.iter()
+			.filter(|&level| level < close)
+			.fold(self.levels.support[2], ValueType::max);
+
+		IndicatorResult::new(
+			&[self.levels.pivot, resistance, support],
+			&[Action::from(signal)],
+		)
+	}
+
+	fn reset(&mut self, candle: T) -> Result<(), Error> {
+		let (high, low, close) = (candle.high(), candle.low(), candle.close());
+
+		self.levels = PivotLevels::new(self.cfg.variant, high, low, close);
+		self.high = high;
+		self.low = low;
+		self.close = close;
+		self.anchor.reset();
+		self.prev_close = close;
+
+		Ok(())
+	}
+}
@@ -3,7 +3,12 @@ use serde::{Deserialize, Serialize};
 
 use crate::core::{Action, Error, Method, PeriodType, ValueType, Window, OHLC};
 use crate::core::{IndicatorConfig, IndicatorInitializer, IndicatorInstance, IndicatorResult};
+use crate::core::{ParamDescriptor, ParamKind};
 use crate::methods::{ReverseHighSignal, ReverseLowSignal};
+use alloc::string::String;
+use alloc::string::ToString;
+use alloc::vec::Vec;
+use core::ops::Bound::Included;
 
 #[derive(Debug, Clone, Copy)]
 #[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
@@ -14,6 +19,8 @@ pub struct PivotReversalStrategy {
 
 impl IndicatorConfig for PivotReversalStrategy {
 	const NAME: &'static str = "PivotReversalStrategy";
+	const VALUE_NAMES: &'static [&'static str] = &["value"];
+	const SIGNAL_NAMES: &'static [&'static str] = &["signal"];
 
 	fn validate(&self) -> bool {
 		self.left >= 1 && self.right >= 1
@@ -31,16 +38,31 @@ impl IndicatorConfig for PivotReversalStrategy {
 			},
 
 			_ => {
-				return Some(Error::ParameterParse(name.to_string(), value));
+				return Some(Error::UnknownParameter(name.to_string()));
 			}
 		};
 
 		None
 	}
 
+	fn parameters(&self) -> Vec<ParamDescriptor> {
+		let default = Self::default();
+
+		vec![
+			ParamDescriptor::new("left", ParamKind::Period, self.left, default.left)
+				.range(Included(1.), Included(PeriodType::MAX as ValueType)),
+			ParamDescriptor::new("right", ParamKind::Period, self.right, default.right)
+				.range(Included(1.), Included(PeriodType::MAX as ValueType)),
+		]
+	}
+
 	fn size(&self) -> (u8, u8) {
 		(1, 1)
 	}
+
+	fn min_periods(&self) -> PeriodType {
+		self.left.saturating_add(self.right).saturating_add(1)
+	}
 }
 
 impl<T: OHLC> IndicatorInitializer<T> for PivotReversalStrategy {
@@ -72,7 +94,8 @@ impl Default for PivotReversalStrategy {
 	}
 }
 
-#[derive(Debug)]
+#[derive(Debug, Clone)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 pub struct PivotReversalStrategyInstance<T: OHLC> {
 	cfg: PivotReversalStrategy,
 
@@ -121,4 +144,14 @@ impl<T: OHLC> IndicatorInstance<T> for PivotReversalStrategyInstance<T> {
 
 		IndicatorResult::new(&[r as ValueType], &[Action::from(r)])
 	}
+
+	fn reset(&mut self, candle: T) -> Result<(), Error> {
+		self.ph.reset(candle.high())?;
+		self.pl.reset(candle.low())?;
+		self.window.fill(candle);
+		self.hprice = 0.;
+		self.lprice = 0.;
+
+		Ok(())
+	}
 }
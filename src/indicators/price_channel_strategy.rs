@@ -3,7 +3,15 @@ use serde::{Deserialize, Serialize};
 
 use crate::core::{Action, Error, Method, PeriodType, ValueType, OHLC};
 use crate::core::{IndicatorConfig, IndicatorInitializer, IndicatorInstance, IndicatorResult};
+use crate::core::{ParamDescriptor, ParamKind};
 use crate::methods::{Highest, Lowest};
+use alloc::string::String;
+use alloc::string::ToString;
+use alloc::vec::Vec;
+use core::ops::Bound::{Excluded, Included, Unbounded};
+
+#[cfg(not(feature = "std"))]
+use crate::core::Float as _;
 
 #[derive(Debug, Clone, Copy)]
 #[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
@@ -14,6 +22,8 @@ pub struct PriceChannelStrategy {
 
 impl IndicatorConfig for PriceChannelStrategy {
 	const NAME: &'static str = "PriceChannelStrategy";
+	const VALUE_NAMES: &'static [&'static str] = &["upper", "lower"];
+	const SIGNAL_NAMES: &'static [&'static str] = &["signal"];
 
 	fn validate(&self) -> bool {
 		self.period > 1 && self.sigma > 0.
@@ -31,13 +41,24 @@ impl IndicatorConfig for PriceChannelStrategy {
 			},
 
 			_ => {
-				return Some(Error::ParameterParse(name.to_string(), value));
+				return Some(Error::UnknownParameter(name.to_string()));
 			}
 		};
 
 		None
 	}
 
+	fn parameters(&self) -> Vec<ParamDescriptor> {
+		let default = Self::default();
+
+		vec![
+			ParamDescriptor::new("period", ParamKind::Period, self.period, default.period)
+				.range(Included(2.), Included(PeriodType::MAX as ValueType)),
+			ParamDescriptor::new("sigma", ParamKind::Float, self.sigma, default.sigma)
+				.range(Excluded(0.), Unbounded),
+		]
+	}
+
 	fn size(&self) -> (u8, u8) {
 		(2, 1)
 	}
@@ -71,7 +92,8 @@ impl Default for PriceChannelStrategy {
 	}
 }
 
-#[derive(Debug)]
+#[derive(Debug, Clone)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 pub struct PriceChannelStrategyInstance {
 	cfg: PriceChannelStrategy,
 
@@ -106,4 +128,9 @@ impl<T: OHLC> IndicatorInstance<T> for PriceChannelStrategyInstance {
 
 		IndicatorResult::new(&[upper, lower], &[Action::from(signal)])
 	}
+
+	fn reset(&mut self, candle: T) -> Result<(), Error> {
+		self.highest.reset(candle.high())?;
+		self.lowest.reset(candle.low())
+	}
 }
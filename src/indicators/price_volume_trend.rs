@@ -0,0 +1,176 @@
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
+
+use crate::core::{Error, Method, PeriodType, Source, ValueType, OHLCV};
+use crate::core::{IndicatorConfig, IndicatorInitializer, IndicatorInstance, IndicatorResult};
+use crate::core::{ParamDescriptor, ParamKind};
+use crate::helpers::{method, RegularMethod, RegularMethods};
+use crate::methods::Cross;
+use alloc::string::String;
+use alloc::string::ToString;
+use alloc::vec::Vec;
+use core::ops::Bound::{Excluded, Included};
+
+/// Price Volume Trend
+///
+/// ## Links
+///
+/// * <https://en.wikipedia.org/wiki/Volume%E2%80%93price_trend>
+///
+/// # 2 values
+///
+/// * `PVT` value \(cumulative *volume* scaled by relative `source` change\)
+/// * `signal line` value
+///
+/// Range in \(-inf; +inf\)
+///
+/// # 1 signal
+///
+/// When `PVT` crosses `signal line` upwards, then returns full buy signal.
+/// When `PVT` crosses `signal line` downwards, then returns full sell signal.
+/// Otherwise no signal
+#[derive(Debug, Clone, Copy)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct PriceVolumeTrend {
+	/// Signal line MA period length. Default is `21`.
+	///
+	/// Range in \[1; [`PeriodType::MAX`](crate::core::PeriodType)\)
+	pub period: PeriodType,
+	/// Signal line MA method. Default is [`EMA`](crate::methods::EMA).
+	pub method: RegularMethods,
+	/// Source type of values. Default is [`Close`](crate::core::Source#variant.Close)
+	pub source: Source,
+}
+
+impl IndicatorConfig for PriceVolumeTrend {
+	const NAME: &'static str = "PriceVolumeTrend";
+	const VALUE_NAMES: &'static [&'static str] = &["pvt", "signal_line"];
+	const SIGNAL_NAMES: &'static [&'static str] = &["signal"];
+
+	fn validate(&self) -> bool {
+		self.period > 0 && self.period < PeriodType::MAX
+	}
+
+	fn set(&mut self, name: &str, value: String) -> Option<Error> {
+		match name {
+			"period" => match value.parse() {
+				Err(_) => return Some(Error::ParameterParse(name.to_string(), value.to_string())),
+				Ok(value) => self.period = value,
+			},
+			"method" => match value.parse() {
+				Err(_) => return Some(Error::ParameterParse(name.to_string(), value.to_string())),
+				Ok(value) => self.method = value,
+			},
+			"source" => match value.parse() {
+				Err(_) => return Some(Error::ParameterParse(name.to_string(), value.to_string())),
+				Ok(value) => self.source = value,
+			},
+
+			_ => {
+				return Some(Error::UnknownParameter(name.to_string()));
+			}
+		};
+
+		None
+	}
+
+	fn parameters(&self) -> Vec<ParamDescriptor> {
+		let default = Self::default();
+
+		vec![
+			ParamDescriptor::new("period", ParamKind::Period, self.period, default.period)
+				.range(Included(1.), Excluded(PeriodType::MAX as ValueType)),
+			ParamDescriptor::new("method", ParamKind::Method, self.method, default.method),
+			ParamDescriptor::new("source", ParamKind::Source, self.source, default.source),
+		]
+	}
+
+	fn is_volume_based(&self) -> bool {
+		true
+	}
+
+	fn size(&self) -> (u8, u8) {
+		(2, 1)
+	}
+
+	fn min_periods(&self) -> PeriodType {
+		self.period.saturating_add(1)
+	}
+}
+
+impl<T: OHLCV> IndicatorInitializer<T> for PriceVolumeTrend {
+	type Instance = PriceVolumeTrendInstance;
+
+	fn init(self, candle: T) -> Result<Self::Instance, Error>
+	where
+		Self: Sized,
+	{
+		if !self.validate() {
+			return Err(Error::WrongConfig);
+		}
+
+		let cfg = self;
+
+		Ok(Self::Instance {
+			prev_src: OHLCV::source(&candle, cfg.source),
+			pvt: 0.,
+			ma: method(cfg.method, cfg.period, 0.)?,
+			cross: Cross::new((), (0., 0.))?,
+			cfg,
+		})
+	}
+}
+
+impl Default for PriceVolumeTrend {
+	fn default() -> Self {
+		Self {
+			period: 21,
+			method: RegularMethods::EMA,
+			source: Source::Close,
+		}
+	}
+}
+
+/// Just an alias for [`PriceVolumeTrend`]
+pub type PVT = PriceVolumeTrend;
+
+/// Price Volume Trend state structure
+#[derive(Debug, Clone)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct PriceVolumeTrendInstance {
+	cfg: PriceVolumeTrend,
+
+	prev_src: ValueType,
+	pvt: ValueType,
+	ma: RegularMethod,
+	cross: Cross,
+}
+
+impl<T: OHLCV> IndicatorInstance<T> for PriceVolumeTrendInstance {
+	type Config = PriceVolumeTrend;
+
+	fn config(&self) -> &Self::Config {
+		&self.cfg
+	}
+
+	fn next(&mut self, candle: T) -> IndicatorResult {
+		let src = OHLCV::source(&candle, self.cfg.source);
+
+		if self.prev_src != 0. {
+			self.pvt += candle.volume() * (src - self.prev_src) / self.prev_src;
+		}
+		self.prev_src = src;
+
+		let sigline = self.ma.next(self.pvt);
+		let signal = self.cross.next((self.pvt, sigline));
+
+		IndicatorResult::new(&[self.pvt, sigline], &[signal])
+	}
+
+	fn reset(&mut self, candle: T) -> Result<(), Error> {
+		self.prev_src = OHLCV::source(&candle, self.cfg.source);
+		self.pvt = 0.;
+		self.ma.reset(0.)?;
+		self.cross.reset((0., 0.))
+	}
+}
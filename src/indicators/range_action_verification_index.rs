@@ -0,0 +1,219 @@
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
+
+use crate::core::{Action, Error, PeriodType, Source, ValueType, OHLC};
+use crate::core::{IndicatorConfig, IndicatorInitializer, IndicatorInstance, IndicatorResult};
+use crate::core::{ParamDescriptor, ParamKind};
+use crate::helpers::{method, RegularMethod, RegularMethods};
+use alloc::string::String;
+use alloc::string::ToString;
+use alloc::vec::Vec;
+use core::ops::Bound::{Excluded, Included, Unbounded};
+
+/// Range Action Verification Index
+///
+/// `RAVI` = |`MA1` − `MA2`| / `MA2`, where `MA1` is a short moving average and `MA2` is a long moving average.
+///
+/// # 1 value
+///
+/// * `RAVI` value
+///
+/// Range in \[0.0; +inf\)
+///
+/// # 2 signals
+///
+/// * Trend-on signal
+///
+/// When `RAVI` value goes above `threshold`, then returns full buy signal if `MA1` is above `MA2`
+/// or full sell signal if `MA1` is below `MA2`.
+/// Otherwise no signal
+///
+/// * Trend-off signal
+///
+/// When `RAVI` value goes below `threshold`, then returns full signal opposite to the trend:
+/// sell if `MA1` is above `MA2`, buy if `MA1` is below `MA2`.
+/// Otherwise no signal
+#[derive(Debug, Clone, Copy)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct RangeActionVerificationIndex {
+	/// Short MA period length. Default is `7`.
+	///
+	/// Range in \[1; `period2`\)
+	pub period1: PeriodType,
+	/// Long MA period length. Default is `65`.
+	///
+	/// Range in \(`period1`; [`PeriodType::MAX`](crate::core::PeriodType)\)
+	pub period2: PeriodType,
+	/// MA method. Default is [`SMA`](crate::methods::SMA).
+	pub method: RegularMethods,
+	/// Trend threshold. Default is `0.03`.
+	///
+	/// Range in \(0.0; +inf\)
+	pub threshold: ValueType,
+	/// Source type of values. Default is [`Close`](crate::core::Source#variant.Close)
+	pub source: Source,
+}
+
+impl IndicatorConfig for RangeActionVerificationIndex {
+	const NAME: &'static str = "RangeActionVerificationIndex";
+	const VALUE_NAMES: &'static [&'static str] = &["value"];
+	const SIGNAL_NAMES: &'static [&'static str] = &["trend_on", "trend_off"];
+
+	fn validate(&self) -> bool {
+		self.period1 > 0
+			&& self.period1 < self.period2
+			&& self.period2 < PeriodType::MAX
+			&& self.threshold > 0.
+	}
+
+	fn set(&mut self, name: &str, value: String) -> Option<Error> {
+		match name {
+			"period1" => match value.parse() {
+				Err(_) => return Some(Error::ParameterParse(name.to_string(), value.to_string())),
+				Ok(value) => self.period1 = value,
+			},
+			"period2" => match value.parse() {
+				Err(_) => return Some(Error::ParameterParse(name.to_string(), value.to_string())),
+				Ok(value) => self.period2 = value,
+			},
+			"method" => match value.parse() {
+				Err(_) => return Some(Error::ParameterParse(name.to_string(), value.to_string())),
+				Ok(value) => self.method = value,
+			},
+			"threshold" => match value.parse() {
+				Err(_) => return Some(Error::ParameterParse(name.to_string(), value.to_string())),
+				Ok(value) => self.threshold = value,
+			},
+			"source" => match value.parse() {
+				Err(_) => return Some(Error::ParameterParse(name.to_string(), value.to_string())),
+				Ok(value) => self.source = value,
+			},
+
+			_ => {
+				return Some(Error::UnknownParameter(name.to_string()));
+			}
+		};
+
+		None
+	}
+
+	fn parameters(&self) -> Vec<ParamDescriptor> {
+		let default = Self::default();
+
+		vec![
+			ParamDescriptor::new("period1", ParamKind::Period, self.period1, default.period1)
+				.range(Included(1.), Excluded(self.period2 as ValueType)),
+			ParamDescriptor::new("period2", ParamKind::Period, self.period2, default.period2)
+				.range(
+					Excluded(self.period1 as ValueType),
+					Excluded(PeriodType::MAX as ValueType),
+				),
+			ParamDescriptor::new("method", ParamKind::Method, self.method, default.method),
+			ParamDescriptor::new(
+				"threshold",
+				ParamKind::Float,
+				self.threshold,
+				default.threshold,
+			)
+			.range(Excluded(0.0), Unbounded),
+			ParamDescriptor::new("source", ParamKind::Source, self.source, default.source),
+		]
+	}
+
+	fn size(&self) -> (u8, u8) {
+		(1, 2)
+	}
+}
+
+impl<T: OHLC> IndicatorInitializer<T> for RangeActionVerificationIndex {
+	type Instance = RangeActionVerificationIndexInstance;
+
+	fn init(self, candle: T) -> Result<Self::Instance, Error>
+	where
+		Self: Sized,
+	{
+		if !self.validate() {
+			return Err(Error::WrongConfig);
+		}
+
+		let cfg = self;
+		let src = candle.source(cfg.source);
+
+		Ok(Self::Instance {
+			ma1: method(cfg.method, cfg.period1, src)?,
+			ma2: method(cfg.method, cfg.period2, src)?,
+			trend_on: false,
+			cfg,
+		})
+	}
+}
+
+impl Default for RangeActionVerificationIndex {
+	fn default() -> Self {
+		Self {
+			period1: 7,
+			period2: 65,
+			method: RegularMethods::SMA,
+			threshold: 0.03,
+			source: Source::Close,
+		}
+	}
+}
+
+/// Just an alias for [`RangeActionVerificationIndex`]
+pub type RAVI = RangeActionVerificationIndex;
+
+/// Range Action Verification Index state structure
+#[derive(Debug, Clone)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct RangeActionVerificationIndexInstance {
+	cfg: RangeActionVerificationIndex,
+
+	ma1: RegularMethod,
+	ma2: RegularMethod,
+	trend_on: bool,
+}
+
+impl<T: OHLC> IndicatorInstance<T> for RangeActionVerificationIndexInstance {
+	type Config = RangeActionVerificationIndex;
+
+	fn config(&self) -> &Self::Config {
+		&self.cfg
+	}
+
+	fn next(&mut self, candle: T) -> IndicatorResult {
+		let src = candle.source(self.cfg.source);
+
+		let ma1 = self.ma1.next(src);
+		let ma2 = self.ma2.next(src);
+
+		let ravi = if ma2 == 0. {
+			0.
+		} else {
+			((ma1 - ma2) / ma2).abs()
+		};
+
+		let trend = (ma1 > ma2) as i8 - (ma1 < ma2) as i8;
+		let trend_on = ravi > self.cfg.threshold;
+
+		let on_signal = (trend_on && !self.trend_on) as i8 * trend;
+		let off_signal = (!trend_on && self.trend_on) as i8 * -trend;
+
+		self.trend_on = trend_on;
+
+		IndicatorResult::new(
+			&[ravi],
+			&[Action::from(on_signal), Action::from(off_signal)],
+		)
+	}
+
+	fn reset(&mut self, candle: T) -> Result<(), Error> {
+		let src = candle.source(self.cfg.source);
+
+		self.ma1.reset(src)?;
+		self.ma2.reset(src)?;
+		self.trend_on = false;
+
+		Ok(())
+	}
+}
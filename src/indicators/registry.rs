@@ -0,0 +1,591 @@
+#[allow(clippy::wildcard_imports)]
+use super::*;
+use crate::core::{
+	BoxedIndicatorInstance, Candle, Error, IndicatorConfig, IndicatorConfigDyn, IndicatorResult,
+	ValueType, OHLCV,
+};
+use alloc::boxed::Box;
+use alloc::string::String;
+use alloc::string::ToString;
+use alloc::vec::Vec;
+use core::fmt::Write;
+
+macro_rules! registry {
+	($($config:ty),* $(,)?) => {
+		/// Names of all the indicators, which may be created by [`create`]
+		pub const INDICATORS: &[&str] = &[$(<$config as IndicatorConfig>::NAME),*];
+
+		/// Creates a default **Configuration** of the indicator by its [`NAME`](crate::core::IndicatorConfig::NAME)
+		///
+		/// Returns [`Error::UnknownIndicator`] if there is no indicator with such name.
+		/// See the whole list of names in [`INDICATORS`].
+		///
+		/// # Examples
+		///
+		/// ```
+		/// use yata::prelude::dd::*;
+		/// use yata::helpers::RandomCandles;
+		/// use yata::indicators::create;
+		///
+		/// let mut config = create("MACD").unwrap();
+		/// assert!(config.set("period1", "10".to_string()).is_none());
+		///
+		/// let mut candles = RandomCandles::new();
+		/// let mut state = config.init(candles.first()).unwrap();
+		/// let result = state.next(candles.next().unwrap());
+		/// assert_eq!(result.size(), (2, 1));
+		///
+		/// assert!(create::<Candle>("Unknown").is_err());
+		/// ```
+		pub fn create<T>(name: &str) -> Result<Box<dyn IndicatorConfigDyn<T>>, Error>
+		where
+			T: OHLCV + Send + 'static,
+		{
+			match name {
+				$(<$config as IndicatorConfig>::NAME => Ok(Box::new(<$config>::default())),)*
+				_ => Err(Error::UnknownIndicator(name.to_string())),
+			}
+		}
+	};
+}
+
+registry!(
+	AccumulationDistribution,
+	Alligator,
+	AnchoredVWAP,
+	Aroon,
+	ATRTrailingStop,
+	AverageDirectionalIndex,
+	AwesomeOscillator,
+	BollingerBands,
+	CandlestickPatterns,
+	CenterOfGravity,
+	ChaikinMoneyFlow,
+	ChaikinOscillator,
+	ChandeKrollStop,
+	ChandeMomentumOscillator,
+	CommodityChannelIndex,
+	ConnorsRSI,
+	CoppockCurve,
+	DetrendedPriceOscillator,
+	DonchianChannels,
+	DynamicMomentumIndex,
+	EaseOfMovement,
+	ElderImpulse,
+	ElderRay,
+	EldersForceIndex,
+	Envelopes,
+	FisherTransform,
+	Fractals,
+	GatorOscillator,
+	HeikinAshi,
+	HullMovingAverage,
+	IchimokuCloud,
+	Kaufman,
+	KeltnerChannels,
+	KlingerVolumeOscillator,
+	KnowSureThing,
+	MACD,
+	MESAAdaptiveMovingAverage,
+	MomentumIndex,
+	MoneyFlowIndex,
+	ParabolicSAR,
+	PercentageVolumeOscillator,
+	PivotPoints,
+	PivotReversalStrategy,
+	PriceChannelStrategy,
+	PriceVolumeTrend,
+	RangeActionVerificationIndex,
+	RelativeStrengthIndex,
+	RelativeVigorIndex,
+	RelativeVolatilityIndex,
+	RelativeVolume,
+	SMIErgodicIndicator,
+	SqueezeMomentum,
+	StandardErrorBands,
+	StochasticOscillator,
+	SuperTrend,
+	SupportResistance,
+	TrueStrengthIndex,
+	Trix,
+	TVFisherTransform,
+	Vidya,
+	VolumeIndex,
+	VolumeProfile,
+	VolumeWeightedAveragePrice,
+	VolumeWeightedMACD,
+	Vortex,
+	WoodiesCCI,
+	ZeroLagMACD,
+);
+
+/// Indicator created by its name, with a plain interface for bindings to other languages
+///
+/// Consists only of strings, numbers and [`Candle`]s, so it is easy to wrap f.e. by `wasm-bindgen`
+/// for browser-based charting tools. Results are returned as JSON objects of the named raw values
+/// and signals [ratios](crate::core::Action::ratio), where `NaN` and no signal are `null`.
+///
+/// **State** is initialized lazily by the first candle and is dropped every time the **Configuration** changes.
+///
+/// # Examples
+///
+/// ```
+/// use yata::helpers::RandomCandles;
+/// use yata::indicators::DynIndicator;
+///
+/// let mut macd = DynIndicator::new("MACD").unwrap();
+/// macd.set("period1", "10").unwrap();
+/// assert!(macd.set("period", "10").is_err());
+///
+/// for candle in RandomCandles::new().take(10) {
+///     let json = macd.next_json(candle).unwrap();
+///     assert!(json.starts_with(r#"{"values":{"macd":"#));
+/// }
+/// ```
+#[derive(Debug)]
+pub struct DynIndicator {
+	config: Box<dyn IndicatorConfigDyn<Candle>>,
+	state: Option<BoxedIndicatorInstance<Candle>>,
+}
+
+impl DynIndicator {
+	/// Creates the default **Configuration** of the indicator by its name
+	///
+	/// See the whole list of names in [`INDICATORS`].
+	pub fn new(name: &str) -> Result<Self, Error> {
+		Ok(Self {
+			config: create(name)?,
+			state: None,
+		})
+	}
+
+	/// Returns the name of the indicator
+	#[must_use]
+	pub fn name(&self) -> &'static str {
+		self.config.name()
+	}
+
+	/// Returns the **Configuration** of the indicator
+	#[must_use]
+	pub fn config(&self) -> &dyn IndicatorConfigDyn<Candle> {
+		self.config.as_ref()
+	}
+
+	/// Sets the **Configuration** parameter `name` by its string `value` and drops current **State**
+	pub fn set(&mut self, name: &str, value: &str) -> Result<(), Error> {
+		if let Some(error) = self.config.set(name, value.into()) {
+			return Err(error);
+		}
+
+		self.state = None;
+		Ok(())
+	}
+
+	/// Drops current **State**, so it is initialized again by the next candle
+	pub fn reset(&mut self) {
+		self.state = None;
+	}
+
+	/// Evaluates the indicator over the `candle`
+	///
+	/// Returns an error if the **Configuration** is not valid.
+	pub fn next(&mut self, candle: Candle) -> Result<IndicatorResult, Error> {
+		let state = match &mut self.state {
+			Some(state) => state,
+			state => state.insert(self.config.init(candle)?),
+		};
+
+		Ok(state.next(candle))
+	}
+
+	/// Evaluates the indicator over the whole history given by the columns of the candles values
+	///
+	/// Returns [`Error::InvalidCandles`] if the columns have different lengths.
+	///
+	/// ```
+	/// use yata::indicators::DynIndicator;
+	///
+	/// let close = [1.0, 2.0, 3.0, 2.5, 2.0, 1.5];
+	/// let volume = [10.0; 6];
+	///
+	/// let mut rsi = DynIndicator::new("RelativeStrengthIndex").unwrap();
+	/// let columns = rsi.over_columns(&close, &close, &close, &close, &volume).unwrap();
+	///
+	/// assert_eq!(columns.values[0].1.len(), close.len());
+	/// assert!(rsi.over_columns(&close, &close, &close, &close, &volume[1..]).is_err());
+	/// ```
+	pub fn over_columns(
+		&mut self,
+		open: &[ValueType],
+		high: &[ValueType],
+		low: &[ValueType],
+		close: &[ValueType],
+		volume: &[ValueType],
+	) -> Result<IndicatorColumns, Error> {
+		let len = open.len();
+
+		if [high.len(), low.len(), close.len(), volume.len()]
+			.iter()
+			.any(|&l| l != len)
+		{
+			return Err(Error::InvalidCandles);
+		}
+
+		let mut columns = IndicatorColumns {
+			values: self
+				.config
+				.value_names()
+				.iter()
+				.map(|&name| (name, Vec::with_capacity(len)))
+				.collect(),
+			signals: self
+				.config
+				.signal_names()
+				.iter()
+				.map(|&name| (name, Vec::with_capacity(len)))
+				.collect(),
+		};
+
+		for i in 0..len {
+			let result = self.next((open[i], high[i], low[i], close[i], volume[i]).into())?;
+
+			for ((_, column), &value) in columns.values.iter_mut().zip(result.values()) {
+				column.push(value);
+			}
+
+			for ((_, column), signal) in columns.signals.iter_mut().zip(result.signals()) {
+				column.push(signal.ratio().unwrap_or(ValueType::NAN));
+			}
+		}
+
+		Ok(columns)
+	}
+
+	/// Evaluates the indicator over the `candle` and returns the result as a JSON object
+	///
+	/// F.e. `{"values":{"macd":0.1,"sigline":0.05},"signals":{"macd/sigline cross":null}}`.
+	pub fn next_json(&mut self, candle: Candle) -> Result<String, Error> {
+		let result = self.next(candle)?;
+
+		let values = self.config.value_names().iter().zip(result.values());
+		let signals = self
+			.config
+			.signal_names()
+			.iter()
+			.zip(result.signals().iter().map(|signal| signal.ratio()));
+
+		let mut json = String::from(r#"{"values":{"#);
+		write_pairs(&mut json, values.map(|(name, &value)| (*name, Some(value))));
+		json.push_str(r#"},"signals":{"#);
+		write_pairs(&mut json, signals.map(|(name, ratio)| (*name, ratio)));
+		json.push_str("}}");
+
+		Ok(json)
+	}
+}
+
+/// Columnar results of [`DynIndicator::over_columns`]
+///
+/// Every column has the name from [`value_names`](crate::core::IndicatorConfig::value_names)
+/// or [`signal_names`](crate::core::IndicatorConfig::signal_names) and a value for every candle.
+/// Signals are represented by their [ratios](crate::core::Action::ratio), where no signal is `NaN`,
+/// so every column may be passed as is into a numeric array or a dataframe.
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct IndicatorColumns {
+	/// Columns of the raw values
+	pub values: Vec<(&'static str, Vec<ValueType>)>,
+
+	/// Columns of the signals
+	pub signals: Vec<(&'static str, Vec<ValueType>)>,
+}
+
+fn write_pairs<'a, I>(json: &mut String, pairs: I)
+where
+	I: Iterator<Item = (&'a str, Option<ValueType>)>,
+{
+	for (i, (name, value)) in pairs.enumerate() {
+		if i > 0 {
+			json.push(',');
+		}
+
+		json.push('"');
+		for c in name.chars() {
+			if c == '"' || c == '\\' {
+				json.push('\\');
+			}
+			json.push(c);
+		}
+		json.push_str("\":");
+
+		match value {
+			Some(value) if value.is_finite() => {
+				let _ = write!(json, "{value}");
+			}
+			_ => json.push_str("null"),
+		}
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	use super::{create, DynIndicator, INDICATORS};
+	use crate::core::{
+		Candle, Error, IndicatorInitializer, IndicatorInstance, ParamKind, ValueType,
+	};
+	use crate::helpers::assert_eq_float;
+	use crate::helpers::RandomCandles;
+	use crate::indicators::Trix;
+	use alloc::string::ToString;
+	use alloc::vec::Vec;
+
+	#[test]
+	fn test_registry() {
+		let candles: Vec<Candle> = RandomCandles::new().take(100).collect();
+
+		for &name in INDICATORS {
+			let config = create::<Candle>(name).unwrap();
+			assert_eq!(config.name(), name);
+			assert!(config.validate());
+
+			let mut state = config.init(candles[0]).unwrap();
+			let results = state.over(&candles);
+			assert_eq!(results.len(), candles.len());
+			assert!(results.iter().all(|r| r.size() == config.size()));
+
+			let (values, signals) = config.size();
+			assert_eq!(config.value_names().len(), values as usize, "{}", name);
+			assert_eq!(config.signal_names().len(), signals as usize, "{}", name);
+		}
+
+		assert!(create::<Candle>("Unknown").is_err());
+	}
+
+	#[test]
+	fn test_registry_reset() {
+		let candles: Vec<Candle> = RandomCandles::new().take(200).collect();
+		let (history, tail) = candles.split_at(100);
+
+		for &name in INDICATORS {
+			let config = create::<Candle>(name).unwrap();
+
+			let mut state = config.init(history[0]).unwrap();
+			state.over(history);
+			state.reset(tail[0]).unwrap();
+
+			let mut fresh = config.init(tail[0]).unwrap();
+
+			for &candle in tail {
+				let (result, expected) = (state.next(candle), fresh.next(candle));
+
+				// compared bitwise, so `NaN` values must match too
+				for (a, b) in result.values().iter().zip(expected.values()) {
+					assert_eq!(a.to_bits(), b.to_bits(), "{}", name);
+				}
+				assert_eq!(result.signals(), expected.signals(), "{}", name);
+			}
+		}
+	}
+
+	#[test]
+	fn test_registry_parameters() {
+		for &name in INDICATORS {
+			let mut config = create::<Candle>(name).unwrap();
+
+			for param in config.parameters() {
+				assert_eq!(param.value, param.default, "{}.{}", name, param.name);
+
+				if let Ok(value) = param.value.parse::<ValueType>() {
+					assert!(param.contains(value), "{}.{}", name, param.name);
+				}
+
+				assert!(
+					config.set(param.name, param.value.clone()).is_none(),
+					"{}.{}",
+					name,
+					param.name
+				);
+
+				let value = config.get(param.name).unwrap();
+				assert!(value.fits(param.kind), "{}.{}", name, param.name);
+				assert!(
+					config.set_value(param.name, value).is_ok(),
+					"{}.{}",
+					name,
+					param.name
+				);
+
+				if param.kind == ParamKind::Period {
+					let error = config.set(param.name, "abc".to_string());
+					assert!(
+						matches!(
+							error.as_ref().map(Error::kind),
+							Some(Error::ParameterParse(..))
+						),
+						"{}.{}",
+						name,
+						param.name
+					);
+				}
+			}
+
+			let error = config.set("unknown", "1".to_string());
+			assert!(
+				matches!(
+					error.as_ref().map(Error::kind),
+					Some(Error::UnknownParameter(_))
+				),
+				"{}",
+				name
+			);
+
+			assert_eq!(
+				config.parameters(),
+				create::<Candle>(name).unwrap().parameters()
+			);
+		}
+	}
+
+	#[test]
+	fn test_registry_min_periods() {
+		// these indicators never forget the initial candle: they are recursive, cumulative or keep the levels found long ago
+		const UNBOUNDED: &[&str] = &[
+			"AccumulationDistribution",
+			"AnchoredVWAP",
+			"ATRTrailingStop",
+			"ChaikinOscillator",
+			"ConnorsRSI",
+			"ElderImpulse",
+			"FisherTransform",
+			"Fractals",
+			"HeikinAshi",
+			"Kaufman",
+			"MESAAdaptiveMovingAverage",
+			"ParabolicSAR",
+			"PivotPoints",
+			"PivotReversalStrategy",
+			"PriceVolumeTrend",
+			"SMIErgodicIndicator",
+			"SuperTrend",
+			"SupportResistance",
+			"TrueStrengthIndex",
+			"Trix",
+			"TVFisherTransform",
+			"Vidya",
+			"VolumeIndex",
+			"VolumeProfile",
+			"VolumeWeightedAveragePrice",
+			"ZeroLagMACD",
+		];
+
+		let sigma = ValueType::EPSILON.sqrt();
+		let candles: Vec<Candle> = RandomCandles::new().take(400).collect();
+		let (history, tail) = candles.split_at(200);
+
+		for &name in INDICATORS.iter().filter(|name| !UNBOUNDED.contains(name)) {
+			let mut config = create::<Candle>(name).unwrap();
+
+			// recursive methods never forget the initial value, so they are replaced by the windowed one
+			for param in config.parameters() {
+				if param.kind == ParamKind::Method {
+					assert!(
+						config.set(param.name, "sma".to_string()).is_none(),
+						"{}.{}",
+						name,
+						param.name
+					);
+				}
+			}
+
+			let warm_up = &history[history.len() - config.min_periods() as usize..];
+
+			let mut state = config.init(history[0]).unwrap();
+			let mut warmed = config.init(warm_up[0]).unwrap();
+
+			let results = state.over(history).into_iter().last().into_iter();
+			let warmed_results = warmed.over(warm_up).into_iter().last().into_iter();
+
+			let results = results.chain(tail.iter().map(|&candle| state.next(candle)));
+			let warmed_results =
+				warmed_results.chain(tail.iter().map(|&candle| warmed.next(candle)));
+
+			for (result, warmed_result) in results.zip(warmed_results) {
+				for (&a, &b) in result.values().iter().zip(warmed_result.values()) {
+					// running sums of the windows collect different rounding errors
+					assert!(
+						(a.is_nan() && b.is_nan()) || (a - b).abs() <= sigma * a.abs().max(1.),
+						"{}: {} != {}",
+						name,
+						a,
+						b
+					);
+				}
+			}
+		}
+	}
+
+	#[test]
+	fn test_dyn_indicator() {
+		let candles: Vec<Candle> = RandomCandles::new().take(100).collect();
+		let mut trix = DynIndicator::new("Trix").unwrap();
+		trix.set("period1", "12").unwrap();
+		assert!(trix.set("period1", "x").is_err());
+
+		let mut state = Trix {
+			period1: 12,
+			..Trix::default()
+		}
+		.init(candles[0])
+		.unwrap();
+
+		for &candle in &candles {
+			let expected = state.next(candle);
+			let result = trix.next(candle).unwrap();
+			assert_eq!(result.signals(), expected.signals());
+			for (&a, &b) in expected.values().iter().zip(result.values()) {
+				assert_eq_float(a, b);
+			}
+		}
+
+		trix.set("period1", "1").unwrap();
+		assert!(trix.next(candles[0]).is_err());
+
+		let mut macd = DynIndicator::new("MACD").unwrap();
+		let json = macd.next_json(candles[0]).unwrap();
+		let names = macd.config().value_names();
+		assert!(json.contains(&format!(r#""{}":"#, names[0])));
+		assert!(json.ends_with("}}"));
+
+		let mut trix = DynIndicator::new("Trix").unwrap();
+		let columns: Vec<Vec<ValueType>> = (0..5)
+			.map(|i| {
+				candles
+					.iter()
+					.map(|c| [c.open, c.high, c.low, c.close, c.volume][i])
+					.collect()
+			})
+			.collect();
+		let result = trix
+			.over_columns(
+				&columns[0],
+				&columns[1],
+				&columns[2],
+				&columns[3],
+				&columns[4],
+			)
+			.unwrap();
+		let mut state = Trix::default().init(candles[0]).unwrap();
+
+		for (i, &candle) in candles.iter().enumerate() {
+			let expected = state.next(candle);
+			assert_eq_float(expected.value(0), result.values[0].1[i]);
+
+			let ratio = result.signals[0].1[i];
+			match expected.signal(0).ratio() {
+				Some(signal) => assert_eq_float(signal, ratio),
+				None => assert!(ratio.is_nan()),
+			}
+		}
+
+		assert!(DynIndicator::new("Unknown").is_err());
+	}
+}
@@ -3,8 +3,13 @@ use serde::{Deserialize, Serialize};
 
 use crate::core::{Error, Method, PeriodType, Source, ValueType, OHLC};
 use crate::core::{IndicatorConfig, IndicatorInitializer, IndicatorInstance, IndicatorResult};
+use crate::core::{ParamDescriptor, ParamKind};
 use crate::helpers::{method, RegularMethod, RegularMethods};
 use crate::methods::{Change, CrossAbove, CrossUnder};
+use alloc::string::String;
+use alloc::string::ToString;
+use alloc::vec::Vec;
+use core::ops::Bound::{Excluded, Included};
 
 #[derive(Debug, Clone, Copy)]
 #[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
@@ -17,6 +22,8 @@ pub struct RelativeStrengthIndex {
 
 impl IndicatorConfig for RelativeStrengthIndex {
 	const NAME: &'static str = "RelativeStrengthIndex";
+	const VALUE_NAMES: &'static [&'static str] = &["value"];
+	const SIGNAL_NAMES: &'static [&'static str] = &["signal"];
 
 	fn validate(&self) -> bool {
 		self.period > 2 && self.zone > 0. && self.zone <= 0.5
@@ -42,16 +49,33 @@ impl IndicatorConfig for RelativeStrengthIndex {
 			},
 
 			_ => {
-				return Some(Error::ParameterParse(name.to_string(), value));
+				return Some(Error::UnknownParameter(name.to_string()));
 			}
 		};
 
 		None
 	}
 
+	fn parameters(&self) -> Vec<ParamDescriptor> {
+		let default = Self::default();
+
+		vec![
+			ParamDescriptor::new("period", ParamKind::Period, self.period, default.period)
+				.range(Included(3.), Included(PeriodType::MAX as ValueType)),
+			ParamDescriptor::new("zone", ParamKind::Float, self.zone, default.zone)
+				.range(Excluded(0.), Included(0.5)),
+			ParamDescriptor::new("source", ParamKind::Source, self.source, default.source),
+			ParamDescriptor::new("method", ParamKind::Method, self.method, default.method),
+		]
+	}
+
 	fn size(&self) -> (u8, u8) {
 		(1, 1)
 	}
+
+	fn min_periods(&self) -> PeriodType {
+		self.period.saturating_add(1)
+	}
 }
 
 impl<T: OHLC> IndicatorInitializer<T> for RelativeStrengthIndex {
@@ -90,7 +114,8 @@ impl Default for RelativeStrengthIndex {
 	}
 }
 
-#[derive(Debug)]
+#[derive(Debug, Clone)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 pub struct RelativeStrengthIndexInstance {
 	cfg: RelativeStrengthIndex,
 
@@ -132,4 +157,14 @@ impl<T: OHLC> IndicatorInstance<T> for RelativeStrengthIndexInstance {
 
 		IndicatorResult::new(&[value], &[signal])
 	}
+
+	fn reset(&mut self, candle: T) -> Result<(), Error> {
+		self.change.reset(candle.source(self.cfg.source))?;
+		self.posma.reset(0.)?;
+		self.negma.reset(0.)?;
+		self.cross_above = CrossAbove::default();
+		self.cross_under = CrossUnder::default();
+
+		Ok(())
+	}
 }
@@ -3,8 +3,13 @@ use serde::{Deserialize, Serialize};
 
 use crate::core::{Action, Error, Method, PeriodType, ValueType, OHLC};
 use crate::core::{IndicatorConfig, IndicatorInitializer, IndicatorInstance, IndicatorResult};
+use crate::core::{ParamDescriptor, ParamKind};
 use crate::helpers::{method, RegularMethod, RegularMethods};
 use crate::methods::{Cross, SMA, SWMA};
+use alloc::string::String;
+use alloc::string::ToString;
+use alloc::vec::Vec;
+use core::ops::Bound::Included;
 
 #[derive(Debug, Clone, Copy)]
 #[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
@@ -18,6 +23,8 @@ pub struct RelativeVigorIndex {
 
 impl IndicatorConfig for RelativeVigorIndex {
 	const NAME: &'static str = "RelativeVigorIndex";
+	const VALUE_NAMES: &'static [&'static str] = &["value", "signal_line"];
+	const SIGNAL_NAMES: &'static [&'static str] = &["signal_line_cross", "zone"];
 
 	fn validate(&self) -> bool {
 		self.period1 >= 2 && self.zone >= 0. && self.zone <= 1. && self.period3 > 1
@@ -47,16 +54,39 @@ impl IndicatorConfig for RelativeVigorIndex {
 			},
 
 			_ => {
-				return Some(Error::ParameterParse(name.to_string(), value));
+				return Some(Error::UnknownParameter(name.to_string()));
 			}
 		};
 
 		None
 	}
 
+	fn parameters(&self) -> Vec<ParamDescriptor> {
+		let default = Self::default();
+
+		vec![
+			ParamDescriptor::new("period1", ParamKind::Period, self.period1, default.period1)
+				.range(Included(2.), Included(PeriodType::MAX as ValueType)),
+			ParamDescriptor::new("period2", ParamKind::Period, self.period2, default.period2)
+				.range(Included(1.), Included(PeriodType::MAX as ValueType)),
+			ParamDescriptor::new("period3", ParamKind::Period, self.period3, default.period3)
+				.range(Included(2.), Included(PeriodType::MAX as ValueType)),
+			ParamDescriptor::new("method", ParamKind::Method, self.method, default.method),
+			ParamDescriptor::new("zone", ParamKind::Float, self.zone, default.zone)
+				.range(Included(0.), Included(1.)),
+		]
+	}
+
 	fn size(&self) -> (u8, u8) {
 		(2, 2)
 	}
+
+	fn min_periods(&self) -> PeriodType {
+		self.period1
+			.saturating_add(self.period2)
+			.saturating_add(self.period3)
+			.saturating_sub(1)
+	}
 }
 
 impl<T: OHLC> IndicatorInitializer<T> for RelativeVigorIndex {
@@ -100,7 +130,8 @@ impl Default for RelativeVigorIndex {
 	}
 }
 
-#[derive(Debug)]
+#[derive(Debug, Clone)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 pub struct RelativeVigorIndexInstance {
 	cfg: RelativeVigorIndex,
 
@@ -156,4 +187,20 @@ impl<T: OHLC> IndicatorInstance<T> for RelativeVigorIndexInstance {
 
 		IndicatorResult::new(&[rvi, sig], &[s1, Action::from(s2)])
 	}
+
+	fn reset(&mut self, candle: T) -> Result<(), Error> {
+		let d_close = candle.close() - candle.open();
+		let d_hl = candle.high() - candle.low();
+		let rvi = if d_hl == 0. { 0. } else { d_close / d_hl };
+
+		self.prev_close = candle.open();
+		self.swma1.reset(d_close)?;
+		self.sma1.reset(d_close)?;
+		self.swma2.reset(d_hl)?;
+		self.sma2.reset(d_hl)?;
+		self.ma.reset(rvi)?;
+		self.cross = Cross::default();
+
+		Ok(())
+	}
 }
@@ -0,0 +1,229 @@
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
+
+use crate::core::{Error, Method, PeriodType, Source, ValueType, OHLC};
+use crate::core::{IndicatorConfig, IndicatorInitializer, IndicatorInstance, IndicatorResult};
+use crate::core::{ParamDescriptor, ParamKind};
+use crate::helpers::{method, RegularMethod, RegularMethods};
+use crate::methods::{Change, CrossAbove, CrossUnder, StDev};
+use alloc::string::String;
+use alloc::string::ToString;
+use alloc::vec::Vec;
+use core::ops::Bound::{Excluded, Included};
+
+/// Relative Volatility Index
+///
+/// The same construction as [`RelativeStrengthIndex`](crate::indicators::RelativeStrengthIndex),
+/// but standard deviation of `source` values is accumulated instead of absolute price change.
+///
+/// # 1 value
+///
+/// * `RVI` value
+///
+/// Range in \[0.0; 1.0\]
+///
+/// # 1 signal
+///
+/// When `RVI` value goes below `zone`, then returns full buy signal.
+/// When `RVI` value goes above `1.0 - zone`, then returns full sell signal.
+/// Otherwise no signal
+#[derive(Debug, Clone, Copy)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct RelativeVolatilityIndex {
+	/// Standard deviation period length. Default is `10`.
+	///
+	/// Range in \[2; [`PeriodType::MAX`](crate::core::PeriodType)\)
+	pub st_dev_period: PeriodType,
+	/// Smoothing period length. Default is `14`.
+	///
+	/// Range in \[1; [`PeriodType::MAX`](crate::core::PeriodType)\)
+	pub period: PeriodType,
+	/// Smoothing method. Default is [`EMA`](crate::methods::EMA).
+	pub method: RegularMethods,
+	/// Signal zone size. Default is `0.3`.
+	///
+	/// Range in \(0.0; 0.5\]
+	pub zone: ValueType,
+	/// Source type of values. Default is [`Close`](crate::core::Source#variant.Close)
+	pub source: Source,
+}
+
+impl IndicatorConfig for RelativeVolatilityIndex {
+	const NAME: &'static str = "RelativeVolatilityIndex";
+	const VALUE_NAMES: &'static [&'static str] = &["value"];
+	const SIGNAL_NAMES: &'static [&'static str] = &["signal"];
+
+	fn validate(&self) -> bool {
+		self.st_dev_period > 1
+			&& self.st_dev_period < PeriodType::MAX
+			&& self.period > 0
+			&& self.period < PeriodType::MAX
+			&& self.zone > 0.
+			&& self.zone <= 0.5
+	}
+
+	fn set(&mut self, name: &str, value: String) -> Option<Error> {
+		match name {
+			"st_dev_period" => match value.parse() {
+				Err(_) => return Some(Error::ParameterParse(name.to_string(), value.to_string())),
+				Ok(value) => self.st_dev_period = value,
+			},
+			"period" => match value.parse() {
+				Err(_) => return Some(Error::ParameterParse(name.to_string(), value.to_string())),
+				Ok(value) => self.period = value,
+			},
+			"method" => match value.parse() {
+				Err(_) => return Some(Error::ParameterParse(name.to_string(), value.to_string())),
+				Ok(value) => self.method = value,
+			},
+			"zone" => match value.parse() {
+				Err(_) => return Some(Error::ParameterParse(name.to_string(), value.to_string())),
+				Ok(value) => self.zone = value,
+			},
+			"source" => match value.parse() {
+				Err(_) => return Some(Error::ParameterParse(name.to_string(), value.to_string())),
+				Ok(value) => self.source = value,
+			},
+
+			_ => {
+				return Some(Error::UnknownParameter(name.to_string()));
+			}
+		};
+
+		None
+	}
+
+	fn parameters(&self) -> Vec<ParamDescriptor> {
+		let default = Self::default();
+
+		vec![
+			ParamDescriptor::new(
+				"st_dev_period",
+				ParamKind::Period,
+				self.st_dev_period,
+				default.st_dev_period,
+			)
+			.range(Included(2.), Excluded(PeriodType::MAX as ValueType)),
+			ParamDescriptor::new("period", ParamKind::Period, self.period, default.period)
+				.range(Included(1.), Excluded(PeriodType::MAX as ValueType)),
+			ParamDescriptor::new("method", ParamKind::Method, self.method, default.method),
+			ParamDescriptor::new("zone", ParamKind::Float, self.zone, default.zone)
+				.range(Excluded(0.0), Included(0.5)),
+			ParamDescriptor::new("source", ParamKind::Source, self.source, default.source),
+		]
+	}
+
+	fn size(&self) -> (u8, u8) {
+		(1, 1)
+	}
+
+	fn min_periods(&self) -> PeriodType {
+		self.st_dev_period
+			.max(2)
+			.saturating_add(self.period)
+			.saturating_sub(1)
+	}
+}
+
+impl<T: OHLC> IndicatorInitializer<T> for RelativeVolatilityIndex {
+	type Instance = RelativeVolatilityIndexInstance;
+
+	fn init(self, candle: T) -> Result<Self::Instance, Error>
+	where
+		Self: Sized,
+	{
+		if !self.validate() {
+			return Err(Error::WrongConfig);
+		}
+
+		let cfg = self;
+		let src = candle.source(cfg.source);
+
+		Ok(Self::Instance {
+			change: Change::new(1, src)?,
+			st_dev: StDev::new(cfg.st_dev_period, src)?,
+			posma: method(cfg.method, cfg.period, 0.)?,
+			negma: method(cfg.method, cfg.period, 0.)?,
+			cross_above: CrossAbove::default(),
+			cross_under: CrossUnder::default(),
+			cfg,
+		})
+	}
+}
+
+impl Default for RelativeVolatilityIndex {
+	fn default() -> Self {
+		Self {
+			st_dev_period: 10,
+			period: 14,
+			method: RegularMethods::EMA,
+			zone: 0.3,
+			source: Source::Close,
+		}
+	}
+}
+
+/// Relative Volatility Index state structure
+#[derive(Debug, Clone)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct RelativeVolatilityIndexInstance {
+	cfg: RelativeVolatilityIndex,
+
+	change: Change,
+	st_dev: StDev,
+	posma: RegularMethod,
+	negma: RegularMethod,
+	cross_above: CrossAbove,
+	cross_under: CrossUnder,
+}
+
+impl<T: OHLC> IndicatorInstance<T> for RelativeVolatilityIndexInstance {
+	type Config = RelativeVolatilityIndex;
+
+	fn config(&self) -> &Self::Config {
+		&self.cfg
+	}
+
+	fn next(&mut self, candle: T) -> IndicatorResult {
+		let src = candle.source(self.cfg.source);
+
+		let change = self.change.next(src);
+		let st_dev = self.st_dev.next(src);
+
+		let (up, down) = if change > 0. {
+			(st_dev, 0.)
+		} else if change < 0. {
+			(0., st_dev)
+		} else {
+			(0., 0.)
+		};
+
+		let pos = self.posma.next(up);
+		let neg = self.negma.next(down);
+
+		let value = if pos + neg > 0. {
+			pos / (pos + neg)
+		} else {
+			0.
+		};
+
+		let oversold = self.cross_under.next((value, self.cfg.zone));
+		let overbought = self.cross_above.next((value, 1. - self.cfg.zone));
+		let signal = oversold - overbought;
+
+		IndicatorResult::new(&[value], &[signal])
+	}
+
+	fn reset(&mut self, candle: T) -> Result<(), Error> {
+		let src = candle.source(self.cfg.source);
+
+		self.change.reset(src)?;
+		self.st_dev.reset(src)?;
+		self.posma.reset(0.)?;
+		self.negma.reset(0.)?;
+		self.cross_above = CrossAbove::default();
+		self.cross_under = CrossUnder::default();
+
+		Ok(())
+	}
+}
@@ -0,0 +1,217 @@
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
+
+use crate::core::{Action, Error, Method, PeriodType, ValueType, OHLCV};
+use crate::core::{IndicatorConfig, IndicatorInitializer, IndicatorInstance, IndicatorResult};
+use crate::core::{ParamDescriptor, ParamKind};
+use crate::methods::SMA;
+use alloc::string::String;
+use alloc::string::ToString;
+use alloc::vec::Vec;
+use core::ops::Bound::{Excluded, Included, Unbounded};
+
+/// Relative Volume
+///
+/// `RVOL` = current *volume* / average *volume* over the previous `period` candles.
+///
+/// Candles do not carry timestamps, so time-of-day adjustment is made by `session` length:
+/// when `session` is greater than `0`, every candle is compared only with the candles at the same
+/// position in the previous `period` sessions.
+///
+/// # 2 values
+///
+/// * `RVOL` value
+///
+/// Range in \[0.0; +inf\)
+///
+/// * average *volume* value
+///
+/// Range in \[0.0; +inf\)
+///
+/// # 1 signal
+///
+/// When `RVOL` goes above `threshold` on a bullish candle \(*close* > *open*\), then returns full buy signal.
+/// When `RVOL` goes above `threshold` on a bearish candle \(*close* < *open*\), then returns full sell signal.
+/// Otherwise no signal
+#[derive(Debug, Clone, Copy)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct RelativeVolume {
+	/// Averaging period length. Default is `20`.
+	///
+	/// Range in \[1; [`PeriodType::MAX`](crate::core::PeriodType)\)
+	pub period: PeriodType,
+	/// Count of candles in the session. Default is `0` \(no time-of-day adjustment\).
+	///
+	/// Range in \[0; [`PeriodType::MAX`](crate::core::PeriodType)\)
+	pub session: PeriodType,
+	/// Volume surge threshold. Default is `2.0`.
+	///
+	/// Range in \(0.0; +inf\)
+	pub threshold: ValueType,
+}
+
+impl IndicatorConfig for RelativeVolume {
+	const NAME: &'static str = "RelativeVolume";
+	const VALUE_NAMES: &'static [&'static str] = &["value", "average_volume"];
+	const SIGNAL_NAMES: &'static [&'static str] = &["signal"];
+
+	fn validate(&self) -> bool {
+		self.period > 0
+			&& self.period < PeriodType::MAX
+			&& self.session < PeriodType::MAX
+			&& self.threshold > 0.
+	}
+
+	fn set(&mut self, name: &str, value: String) -> Option<Error> {
+		match name {
+			"period" => match value.parse() {
+				Err(_) => return Some(Error::ParameterParse(name.to_string(), value.to_string())),
+				Ok(value) => self.period = value,
+			},
+			"session" => match value.parse() {
+				Err(_) => return Some(Error::ParameterParse(name.to_string(), value.to_string())),
+				Ok(value) => self.session = value,
+			},
+			"threshold" => match value.parse() {
+				Err(_) => return Some(Error::ParameterParse(name.to_string(), value.to_string())),
+				Ok(value) => self.threshold = value,
+			},
+
+			_ => {
+				return Some(Error::UnknownParameter(name.to_string()));
+			}
+		};
+
+		None
+	}
+
+	fn parameters(&self) -> Vec<ParamDescriptor> {
+		let default = Self::default();
+
+		vec![
+			ParamDescriptor::new("period", ParamKind::Period, self.period, default.period)
+				.range(Included(1.), Excluded(PeriodType::MAX as ValueType)),
+			ParamDescriptor::new("session", ParamKind::Period, self.session, default.session)
+				.range(Included(0.), Excluded(PeriodType::MAX as ValueType)),
+			ParamDescriptor::new(
+				"threshold",
+				ParamKind::Float,
+				self.threshold,
+				default.threshold,
+			)
+			.range(Excluded(0.0), Unbounded),
+		]
+	}
+
+	fn is_volume_based(&self) -> bool {
+		true
+	}
+
+	fn size(&self) -> (u8, u8) {
+		(2, 1)
+	}
+
+	fn min_periods(&self) -> PeriodType {
+		self.period
+			.saturating_mul(self.session.max(1))
+			.saturating_add(1)
+	}
+}
+
+impl<T: OHLCV> IndicatorInitializer<T> for RelativeVolume {
+	type Instance = RelativeVolumeInstance;
+
+	fn init(self, candle: T) -> Result<Self::Instance, Error>
+	where
+		Self: Sized,
+	{
+		if !self.validate() {
+			return Err(Error::WrongConfig);
+		}
+
+		let cfg = self;
+		let volume = candle.volume();
+
+		let slots = (0..cfg.session.max(1))
+			.map(|_| SMA::new(cfg.period, volume).map(|sma| (sma, volume)))
+			.collect::<Result<_, _>>()?;
+
+		Ok(Self::Instance {
+			slots,
+			index: 0,
+			surge: false,
+			cfg,
+		})
+	}
+}
+
+impl Default for RelativeVolume {
+	fn default() -> Self {
+		Self {
+			period: 20,
+			session: 0,
+			threshold: 2.0,
+		}
+	}
+}
+
+/// Just an alias for [`RelativeVolume`]
+pub type RVOL = RelativeVolume;
+
+/// Relative Volume state structure
+#[derive(Debug, Clone)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct RelativeVolumeInstance {
+	cfg: RelativeVolume,
+
+	/// average over the previous candles and its current value for every position in the session
+	slots: Vec<(SMA, ValueType)>,
+	index: usize,
+	surge: bool,
+}
+
+impl<T: OHLCV> IndicatorInstance<T> for RelativeVolumeInstance {
+	type Config = RelativeVolume;
+
+	fn config(&self) -> &Self::Config {
+		&self.cfg
+	}
+
+	fn next(&mut self, candle: T) -> IndicatorResult {
+		let volume = candle.volume();
+
+		let (sma, average) = &mut self.slots[self.index];
+		let prev_average = *average;
+		*average = sma.next(volume);
+
+		self.index = (self.index + 1) % self.slots.len();
+
+		let rvol = if prev_average > 0. {
+			volume / prev_average
+		} else {
+			0.
+		};
+
+		let surge = rvol > self.cfg.threshold;
+		let direction =
+			(candle.close() > candle.open()) as i8 - (candle.close() < candle.open()) as i8;
+		let signal = (surge && !self.surge) as i8 * direction;
+		self.surge = surge;
+
+		IndicatorResult::new(&[rvol, prev_average], &[Action::from(signal)])
+	}
+
+	fn reset(&mut self, candle: T) -> Result<(), Error> {
+		let volume = candle.volume();
+
+		for (sma, value) in &mut self.slots {
+			sma.reset(volume)?;
+			*value = volume;
+		}
+
+		self.index = 0;
+		self.surge = false;
+
+		Ok(())
+	}
+}
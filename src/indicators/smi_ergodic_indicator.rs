@@ -3,8 +3,13 @@ use serde::{Deserialize, Serialize};
 
 use crate::core::{Error, Method, PeriodType, Source, ValueType, OHLC};
 use crate::core::{IndicatorConfig, IndicatorInitializer, IndicatorInstance, IndicatorResult};
+use crate::core::{ParamDescriptor, ParamKind};
 use crate::helpers::{method, RegularMethod, RegularMethods};
 use crate::methods::{Change, Cross, EMA};
+use alloc::string::String;
+use alloc::string::ToString;
+use alloc::vec::Vec;
+use core::ops::Bound::Included;
 
 #[derive(Debug, Clone, Copy)]
 #[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
@@ -18,6 +23,8 @@ pub struct SMIErgodicIndicator {
 
 impl IndicatorConfig for SMIErgodicIndicator {
 	const NAME: &'static str = "SMIErgodicIndicator";
+	const VALUE_NAMES: &'static [&'static str] = &["value", "signal_line"];
+	const SIGNAL_NAMES: &'static [&'static str] = &["signal_line_cross"];
 
 	fn validate(&self) -> bool {
 		self.period1 > 1 && self.period2 > 1 && self.period3 > 1
@@ -47,16 +54,38 @@ impl IndicatorConfig for SMIErgodicIndicator {
 			},
 
 			_ => {
-				return Some(Error::ParameterParse(name.to_string(), value));
+				return Some(Error::UnknownParameter(name.to_string()));
 			}
 		};
 
 		None
 	}
 
+	fn parameters(&self) -> Vec<ParamDescriptor> {
+		let default = Self::default();
+
+		vec![
+			ParamDescriptor::new("period1", ParamKind::Period, self.period1, default.period1)
+				.range(Included(2.), Included(PeriodType::MAX as ValueType)),
+			ParamDescriptor::new("period2", ParamKind::Period, self.period2, default.period2)
+				.range(Included(2.), Included(PeriodType::MAX as ValueType)),
+			ParamDescriptor::new("period3", ParamKind::Period, self.period3, default.period3)
+				.range(Included(2.), Included(PeriodType::MAX as ValueType)),
+			ParamDescriptor::new("method", ParamKind::Method, self.method, default.method),
+			ParamDescriptor::new("source", ParamKind::Source, self.source, default.source),
+		]
+	}
+
 	fn size(&self) -> (u8, u8) {
 		(2, 1)
 	}
+
+	fn min_periods(&self) -> PeriodType {
+		self.period1
+			.saturating_add(self.period2)
+			.saturating_add(self.period3)
+			.saturating_sub(1)
+	}
 }
 
 impl<T: OHLC> IndicatorInitializer<T> for SMIErgodicIndicator {
@@ -98,7 +127,8 @@ impl Default for SMIErgodicIndicator {
 	}
 }
 
-#[derive(Debug)]
+#[derive(Debug, Clone)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 pub struct SMIErgodicIndicatorInstance {
 	cfg: SMIErgodicIndicator,
 
@@ -137,4 +167,16 @@ impl<T: OHLC> IndicatorInstance<T> for SMIErgodicIndicatorInstance {
 
 		IndicatorResult::new(&[smi, sig], &[signal])
 	}
+
+	fn reset(&mut self, candle: T) -> Result<(), Error> {
+		self.change.reset(candle.source(self.cfg.source))?;
+		self.ema11.reset(0.)?;
+		self.ema12.reset(0.)?;
+		self.ema21.reset(0.)?;
+		self.ema22.reset(0.)?;
+		self.ma.reset(0.)?;
+		self.cross = Cross::default();
+
+		Ok(())
+	}
 }
@@ -0,0 +1,236 @@
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
+
+use crate::core::{Action, Error, Method, PeriodType, Source, ValueType, OHLC};
+use crate::core::{IndicatorConfig, IndicatorInitializer, IndicatorInstance, IndicatorResult};
+use crate::core::{ParamDescriptor, ParamKind};
+use crate::methods::{Highest, LinReg, Lowest, StDev, SMA};
+use alloc::string::String;
+use alloc::string::ToString;
+use alloc::vec::Vec;
+use core::ops::Bound::{Excluded, Included, Unbounded};
+
+#[cfg(not(feature = "std"))]
+use crate::core::Float as _;
+
+/// Squeeze Momentum \(TTM Squeeze\)
+///
+/// Squeeze is on when Bollinger Bands are inside Keltner Channels.
+///
+/// ## Links
+///
+/// * <https://school.stockcharts.com/doku.php?id=technical_indicators:ttm_squeeze>
+///
+/// # 2 values
+///
+/// * `momentum` histogram value
+///
+/// Linear regression of the distance between `source` value and the midline of Donchian Channel and `SMA` average.
+///
+/// Range in \(-inf; +inf\)
+///
+/// * `squeeze` state
+///
+/// `1.0` when squeeze is on, `0.0` when squeeze is off.
+///
+/// # 1 signal
+///
+/// When squeeze is released and `momentum` is positive, then returns full buy signal.
+/// When squeeze is released and `momentum` is negative, then returns full sell signal.
+/// Otherwise no signal
+#[derive(Debug, Clone, Copy)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct SqueezeMomentum {
+	/// Bands and momentum period length. Default is `20`.
+	///
+	/// Range in \[2; [`PeriodType::MAX`](crate::core::PeriodType)\)
+	pub period: PeriodType,
+	/// Bollinger Bands size in standard deviations. Default is `2.0`.
+	///
+	/// Range in \(0.0; +inf\)
+	pub bb_sigma: ValueType,
+	/// Keltner Channels size in average true ranges. Default is `1.5`.
+	///
+	/// Range in \(0.0; +inf\)
+	pub kc_sigma: ValueType,
+	/// Source type of values. Default is [`Close`](crate::core::Source#variant.Close)
+	pub source: Source,
+}
+
+impl IndicatorConfig for SqueezeMomentum {
+	const NAME: &'static str = "SqueezeMomentum";
+	const VALUE_NAMES: &'static [&'static str] = &["momentum", "squeeze"];
+	const SIGNAL_NAMES: &'static [&'static str] = &["signal"];
+
+	fn validate(&self) -> bool {
+		self.period > 1 && self.period < PeriodType::MAX && self.bb_sigma > 0. && self.kc_sigma > 0.
+	}
+
+	fn set(&mut self, name: &str, value: String) -> Option<Error> {
+		match name {
+			"period" => match value.parse() {
+				Err(_) => return Some(Error::ParameterParse(name.to_string(), value.to_string())),
+				Ok(value) => self.period = value,
+			},
+			"bb_sigma" => match value.parse() {
+				Err(_) => return Some(Error::ParameterParse(name.to_string(), value.to_string())),
+				Ok(value) => self.bb_sigma = value,
+			},
+			"kc_sigma" => match value.parse() {
+				Err(_) => return Some(Error::ParameterParse(name.to_string(), value.to_string())),
+				Ok(value) => self.kc_sigma = value,
+			},
+			"source" => match value.parse() {
+				Err(_) => return Some(Error::ParameterParse(name.to_string(), value.to_string())),
+				Ok(value) => self.source = value,
+			},
+
+			_ => {
+				return Some(Error::UnknownParameter(name.to_string()));
+			}
+		};
+
+		None
+	}
+
+	fn parameters(&self) -> Vec<ParamDescriptor> {
+		let default = Self::default();
+
+		vec![
+			ParamDescriptor::new("period", ParamKind::Period, self.period, default.period)
+				.range(Included(2.), Excluded(PeriodType::MAX as ValueType)),
+			ParamDescriptor::new(
+				"bb_sigma",
+				ParamKind::Float,
+				self.bb_sigma,
+				default.bb_sigma,
+			)
+			.range(Excluded(0.0), Unbounded),
+			ParamDescriptor::new(
+				"kc_sigma",
+				ParamKind::Float,
+				self.kc_sigma,
+				default.kc_sigma,
+			)
+			.range(Excluded(0.0), Unbounded),
+			ParamDescriptor::new("source", ParamKind::Source, self.source, default.source),
+		]
+	}
+
+	fn size(&self) -> (u8, u8) {
+		(2, 1)
+	}
+
+	fn min_periods(&self) -> PeriodType {
+		self.period
+			.saturating_mul(2)
+			.saturating_sub(1)
+			.max(self.period.saturating_add(1))
+	}
+}
+
+impl<T: OHLC> IndicatorInitializer<T> for SqueezeMomentum {
+	type Instance = SqueezeMomentumInstance<T>;
+
+	fn init(self, candle: T) -> Result<Self::Instance, Error>
+	where
+		Self: Sized,
+	{
+		if !self.validate() {
+			return Err(Error::WrongConfig);
+		}
+
+		let cfg = self;
+		let src = candle.source(cfg.source);
+
+		Ok(Self::Instance {
+			ma: SMA::new(cfg.period, src)?,
+			st_dev: StDev::new(cfg.period, src)?,
+			atr: SMA::new(cfg.period, candle.tr(&candle))?,
+			highest: Highest::new(cfg.period, candle.high())?,
+			lowest: Lowest::new(cfg.period, candle.low())?,
+			lin_reg: LinReg::new(cfg.period, 0.)?,
+			prev_candle: candle,
+			squeeze: false,
+			cfg,
+		})
+	}
+}
+
+impl Default for SqueezeMomentum {
+	fn default() -> Self {
+		Self {
+			period: 20,
+			bb_sigma: 2.0,
+			kc_sigma: 1.5,
+			source: Source::Close,
+		}
+	}
+}
+
+/// Squeeze Momentum state structure
+#[derive(Debug, Clone)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct SqueezeMomentumInstance<T: OHLC> {
+	cfg: SqueezeMomentum,
+
+	ma: SMA,
+	st_dev: StDev,
+	atr: SMA,
+	highest: Highest,
+	lowest: Lowest,
+	lin_reg: LinReg,
+	prev_candle: T,
+	squeeze: bool,
+}
+
+impl<T: OHLC> IndicatorInstance<T> for SqueezeMomentumInstance<T> {
+	type Config = SqueezeMomentum;
+
+	fn config(&self) -> &Self::Config {
+		&self.cfg
+	}
+
+	fn next(&mut self, candle: T) -> IndicatorResult {
+		let src = candle.source(self.cfg.source);
+
+		let ma = self.ma.next(src);
+		let st_dev = self.st_dev.next(src);
+		let atr = self.atr.next(candle.tr(&self.prev_candle));
+		self.prev_candle = candle;
+
+		// bands are symmetric around the same `ma`, so it is enough to compare their widths
+		let squeeze = st_dev * self.cfg.bb_sigma < atr * self.cfg.kc_sigma;
+
+		let middle = (self.highest.next(candle.high()) + self.lowest.next(candle.low())) * 0.5;
+		let momentum = self.lin_reg.next((middle + ma).mul_add(-0.5, src));
+
+		let signal = if self.squeeze && !squeeze {
+			(momentum > 0.) as i8 - (momentum < 0.) as i8
+		} else {
+			0
+		};
+
+		self.squeeze = squeeze;
+
+		IndicatorResult::new(
+			&[momentum, squeeze as i8 as ValueType],
+			&[Action::from(signal)],
+		)
+	}
+
+	fn reset(&mut self, candle: T) -> Result<(), Error> {
+		let src = candle.source(self.cfg.source);
+
+		self.ma.reset(src)?;
+		self.st_dev.reset(src)?;
+		self.atr.reset(candle.tr(&candle))?;
+		self.highest.reset(candle.high())?;
+		self.lowest.reset(candle.low())?;
+		self.lin_reg.reset(0.)?;
+		self.prev_candle = candle;
+		self.squeeze = false;
+
+		Ok(())
+	}
+}
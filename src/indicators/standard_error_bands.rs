@@ -0,0 +1,246 @@
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
+
+use crate::core::{Error, Method, PeriodType, Source, ValueType, Window, OHLC};
+use crate::core::{IndicatorConfig, IndicatorInitializer, IndicatorInstance, IndicatorResult};
+use crate::core::{ParamDescriptor, ParamKind};
+use crate::helpers::{method, RegularMethod, RegularMethods};
+use crate::methods::{CrossAbove, CrossUnder};
+use alloc::string::String;
+use alloc::string::ToString;
+use alloc::vec::Vec;
+use core::ops::Bound::{Excluded, Included, Unbounded};
+
+#[cfg(not(feature = "std"))]
+use crate::core::Float as _;
+
+/// Standard Error Bands
+///
+/// Linear regression endpoint of the last `period` `source` values is used as the middle line,
+/// and bands are placed at `sigma` standard errors of the regression around it.
+/// Both regression endpoint and standard error are smoothed by `method` over `smooth_period` values,
+/// so the bands react to the trend noise rather than to the plain price dispersion, as [`BollingerBands`](crate::indicators::BollingerBands) do.
+///
+/// # 3 values
+///
+/// * `upper` band value
+/// * `middle` line value \(smoothed regression endpoint\)
+/// * `lower` band value
+///
+/// Range of values is the same as the range of the `source` values.
+///
+/// # 1 signal
+///
+/// When `source` value crosses `lower` band downwards, then returns full buy signal.
+/// When `source` value crosses `upper` band upwards, then returns full sell signal.
+/// Otherwise no signal
+#[derive(Debug, Clone, Copy)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct StandardErrorBands {
+	/// Linear regression period length. Default is `21`.
+	///
+	/// Range in \[3; [`PeriodType::MAX`](crate::core::PeriodType)\)
+	pub period: PeriodType,
+	/// Smoothing period length. Default is `3`.
+	///
+	/// Range in \[1; [`PeriodType::MAX`](crate::core::PeriodType)\)
+	pub smooth_period: PeriodType,
+	/// Smoothing method. Default is [`SMA`](crate::methods::SMA).
+	pub method: RegularMethods,
+	/// Bands size in standard errors. Default is `2.0`.
+	///
+	/// Range in \(0.0; +inf\)
+	pub sigma: ValueType,
+	/// Source type of values. Default is [`Close`](crate::core::Source#variant.Close)
+	pub source: Source,
+}
+
+impl IndicatorConfig for StandardErrorBands {
+	const NAME: &'static str = "StandardErrorBands";
+	const VALUE_NAMES: &'static [&'static str] = &["upper", "middle", "lower"];
+	const SIGNAL_NAMES: &'static [&'static str] = &["signal"];
+
+	fn validate(&self) -> bool {
+		self.period > 2
+			&& self.period < PeriodType::MAX
+			&& self.smooth_period > 0
+			&& self.smooth_period < PeriodType::MAX
+			&& self.sigma > 0.
+	}
+
+	fn set(&mut self, name: &str, value: String) -> Option<Error> {
+		match name {
+			"period" => match value.parse() {
+				Err(_) => return Some(Error::ParameterParse(name.to_string(), value.to_string())),
+				Ok(value) => self.period = value,
+			},
+			"smooth_period" => match value.parse() {
+				Err(_) => return Some(Error::ParameterParse(name.to_string(), value.to_string())),
+				Ok(value) => self.smooth_period = value,
+			},
+			"method" => match value.parse() {
+				Err(_) => return Some(Error::ParameterParse(name.to_string(), value.to_string())),
+				Ok(value) => self.method = value,
+			},
+			"sigma" => match value.parse() {
+				Err(_) => return Some(Error::ParameterParse(name.to_string(), value.to_string())),
+				Ok(value) => self.sigma = value,
+			},
+			"source" => match value.parse() {
+				Err(_) => return Some(Error::ParameterParse(name.to_string(), value.to_string())),
+				Ok(value) => self.source = value,
+			},
+
+			_ => {
+				return Some(Error::UnknownParameter(name.to_string()));
+			}
+		};
+
+		None
+	}
+
+	fn parameters(&self) -> Vec<ParamDescriptor> {
+		let default = Self::default();
+
+		vec![
+			ParamDescriptor::new("period", ParamKind::Period, self.period, default.period)
+				.range(Included(3.), Excluded(PeriodType::MAX as ValueType)),
+			ParamDescriptor::new(
+				"smooth_period",
+				ParamKind::Period,
+				self.smooth_period,
+				default.smooth_period,
+			)
+			.range(Included(1.), Excluded(PeriodType::MAX as ValueType)),
+			ParamDescriptor::new("method", ParamKind::Method, self.method, default.method),
+			ParamDescriptor::new("sigma", ParamKind::Float, self.sigma, default.sigma)
+				.range(Excluded(0.0), Unbounded),
+			ParamDescriptor::new("source", ParamKind::Source, self.source, default.source),
+		]
+	}
+
+	fn size(&self) -> (u8, u8) {
+		(3, 1)
+	}
+
+	fn min_periods(&self) -> PeriodType {
+		self.period
+			.saturating_add(self.smooth_period)
+			.saturating_sub(1)
+	}
+}
+
+impl<T: OHLC> IndicatorInitializer<T> for StandardErrorBands {
+	type Instance = StandardErrorBandsInstance;
+
+	fn init(self, candle: T) -> Result<Self::Instance, Error>
+	where
+		Self: Sized,
+	{
+		if !self.validate() {
+			return Err(Error::WrongConfig);
+		}
+
+		let cfg = self;
+		let src = candle.source(cfg.source);
+
+		let n = cfg.period as ValueType;
+		// x runs from 0 for the oldest value to n-1 for the newest one
+		let s_x = n * (n - 1.) * 0.5;
+		let s_x2 = s_x * n.mul_add(2., -1.) / 3.;
+
+		Ok(Self::Instance {
+			window: Window::new(cfg.period, src),
+			n,
+			s_x,
+			divider: n.mul_add(s_x2, -s_x * s_x).recip(),
+			s_y: src * n,
+			s_y2: src * src * n,
+			s_xy: src * s_x,
+			ma_middle: method(cfg.method, cfg.smooth_period, src)?,
+			ma_error: method(cfg.method, cfg.smooth_period, 0.)?,
+			cross_above: CrossAbove::new((), (src, src))?,
+			cross_under: CrossUnder::new((), (src, src))?,
+			cfg,
+		})
+	}
+}
+
+impl Default for StandardErrorBands {
+	fn default() -> Self {
+		Self {
+			period: 21,
+			smooth_period: 3,
+			method: RegularMethods::SMA,
+			sigma: 2.0,
+			source: Source::Close,
+		}
+	}
+}
+
+/// Standard Error Bands state structure
+#[derive(Debug, Clone)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct StandardErrorBandsInstance {
+	cfg: StandardErrorBands,
+
+	window: Window<ValueType>,
+	n: ValueType,
+	s_x: ValueType,
+	divider: ValueType,
+	s_y: ValueType,
+	s_y2: ValueType,
+	s_xy: ValueType,
+	ma_middle: RegularMethod,
+	ma_error: RegularMethod,
+	cross_above: CrossAbove,
+	cross_under: CrossUnder,
+}
+
+impl<T: OHLC> IndicatorInstance<T> for StandardErrorBandsInstance {
+	type Config = StandardErrorBands;
+
+	fn config(&self) -> &Self::Config {
+		&self.cfg
+	}
+
+	fn next(&mut self, candle: T) -> IndicatorResult {
+		let src = candle.source(self.cfg.source);
+		let past_src = self.window.push(src);
+
+		// every value left in the window moves one step back along x
+		self.s_xy += (self.n - 1.).mul_add(src, past_src - self.s_y);
+		self.s_y += src - past_src;
+		self.s_y2 += src.mul_add(src, -past_src * past_src);
+
+		let slope = self.n.mul_add(self.s_xy, -self.s_x * self.s_y) * self.divider;
+		let intercept = slope.mul_add(-self.s_x, self.s_y) / self.n;
+		let endpoint = slope.mul_add(self.n - 1., intercept);
+
+		let sq_residuals = slope.mul_add(-self.s_xy, intercept.mul_add(-self.s_y, self.s_y2));
+		let st_error = (sq_residuals.max(0.) / (self.n - 2.)).sqrt();
+
+		let middle = self.ma_middle.next(endpoint);
+		let band = self.ma_error.next(st_error) * self.cfg.sigma;
+
+		let upper = middle + band;
+		let lower = middle - band;
+
+		let signal = self.cross_under.next((src, lower)) - self.cross_above.next((src, upper));
+
+		IndicatorResult::new(&[upper, middle, lower], &[signal])
+	}
+
+	fn reset(&mut self, candle: T) -> Result<(), Error> {
+		let src = candle.source(self.cfg.source);
+
+		self.window.fill(src);
+		self.s_y = src * self.n;
+		self.s_y2 = src * src * self.n;
+		self.s_xy = src * self.s_x;
+		self.ma_middle.reset(src)?;
+		self.ma_error.reset(0.)?;
+		self.cross_above.reset((src, src))?;
+		self.cross_under.reset((src, src))
+	}
+}
@@ -3,8 +3,13 @@ use serde::{Deserialize, Serialize};
 
 use crate::core::{Error, Method, PeriodType, ValueType, OHLC};
 use crate::core::{IndicatorConfig, IndicatorInitializer, IndicatorInstance, IndicatorResult};
+use crate::core::{ParamDescriptor, ParamKind};
 use crate::helpers::{method, RegularMethod, RegularMethods};
 use crate::methods::{Cross, CrossAbove, CrossUnder, Highest, Lowest};
+use alloc::string::String;
+use alloc::string::ToString;
+use alloc::vec::Vec;
+use core::ops::Bound::Included;
 
 #[derive(Debug, Clone, Copy)]
 #[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
@@ -18,6 +23,8 @@ pub struct StochasticOscillator {
 
 impl IndicatorConfig for StochasticOscillator {
 	const NAME: &'static str = "StochasticOscillator";
+	const VALUE_NAMES: &'static [&'static str] = &["k", "d"];
+	const SIGNAL_NAMES: &'static [&'static str] = &["k_zone", "d_zone", "cross"];
 
 	fn validate(&self) -> bool {
 		self.period > 1
@@ -47,16 +54,48 @@ impl IndicatorConfig for StochasticOscillator {
 			},
 
 			_ => {
-				return Some(Error::ParameterParse(name.to_string(), value));
+				return Some(Error::UnknownParameter(name.to_string()));
 			}
 		};
 
 		None
 	}
 
+	fn parameters(&self) -> Vec<ParamDescriptor> {
+		let default = Self::default();
+
+		vec![
+			ParamDescriptor::new("period", ParamKind::Period, self.period, default.period)
+				.range(Included(2.), Included(PeriodType::MAX as ValueType)),
+			ParamDescriptor::new(
+				"smooth_k",
+				ParamKind::Period,
+				self.smooth_k,
+				default.smooth_k,
+			)
+			.range(Included(1.), Included(PeriodType::MAX as ValueType)),
+			ParamDescriptor::new(
+				"smooth_d",
+				ParamKind::Period,
+				self.smooth_d,
+				default.smooth_d,
+			)
+			.range(Included(1.), Included(PeriodType::MAX as ValueType)),
+			ParamDescriptor::new("zone", ParamKind::Float, self.zone, default.zone),
+			ParamDescriptor::new("method", ParamKind::Method, self.method, default.method),
+		]
+	}
+
 	fn size(&self) -> (u8, u8) {
 		(2, 3)
 	}
+
+	fn min_periods(&self) -> PeriodType {
+		self.period
+			.saturating_add(self.smooth_k)
+			.saturating_add(self.smooth_d)
+			.saturating_sub(2)
+	}
 }
 
 impl<T: OHLC> IndicatorInitializer<T> for StochasticOscillator {
@@ -107,7 +146,8 @@ impl Default for StochasticOscillator {
 	}
 }
 
-#[derive(Debug)]
+#[derive(Debug, Clone)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 pub struct StochasticOscillatorInstance {
 	cfg: StochasticOscillator,
 
@@ -157,4 +197,25 @@ impl<T: OHLC> IndicatorInstance<T> for StochasticOscillatorInstance {
 
 		IndicatorResult::new(&[f1, f2], &[s1, s2, s3])
 	}
+
+	fn reset(&mut self, candle: T) -> Result<(), Error> {
+		#[allow(clippy::float_cmp)]
+		let k_rows = if candle.high() == candle.low() {
+			0.
+		} else {
+			(candle.close() - candle.low()) / (candle.high() - candle.low())
+		};
+
+		self.highest.reset(candle.high())?;
+		self.lowest.reset(candle.low())?;
+		self.ma1.reset(k_rows)?;
+		self.ma2.reset(k_rows)?;
+		self.cross_over = Cross::default();
+		self.cross_above1 = CrossAbove::default();
+		self.cross_under1 = CrossUnder::default();
+		self.cross_above2 = CrossAbove::default();
+		self.cross_under2 = CrossUnder::default();
+
+		Ok(())
+	}
 }
@@ -0,0 +1,281 @@
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
+
+use crate::core::{Action, Error, PeriodType, Source, ValueType, OHLC};
+use crate::core::{IndicatorConfig, IndicatorInitializer, IndicatorInstance, IndicatorResult};
+use crate::core::{ParamDescriptor, ParamKind};
+use crate::helpers::{method, RegularMethod, RegularMethods};
+use alloc::string::String;
+use alloc::string::ToString;
+use alloc::vec::Vec;
+use core::ops::Bound::{Excluded, Included, Unbounded};
+
+#[cfg(not(feature = "std"))]
+use crate::core::Float as _;
+
+/// `SuperTrend`
+///
+/// ## Links
+///
+/// * <https://www.tradingview.com/support/solutions/43000634738-supertrend/>
+///
+/// # 2 values
+///
+/// * `trailing line` value \(lower band on uptrend, upper band on downtrend\)
+///
+/// Range of values is the same as the range of the `source` values.
+///
+/// * `trend` direction
+///
+/// `1.0` for uptrend, `-1.0` for downtrend.
+///
+/// # 1 signal
+///
+/// When *close* price goes above upper band and trend flips up, then returns full buy signal.
+/// When *close* price goes below lower band and trend flips down, then returns full sell signal.
+/// Otherwise no signal
+#[derive(Debug, Clone, Copy)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct SuperTrend {
+	/// ATR period length. Default is `10`.
+	///
+	/// Range in \[1; [`PeriodType::MAX`](crate::core::PeriodType)\)
+	pub period: PeriodType,
+	/// ATR multiplier. Default is `3.0`.
+	///
+	/// Range in \(0; +inf\)
+	pub multiplier: ValueType,
+	/// ATR smoothing method. Default is [`RMA`](crate::methods::RMA).
+	pub method: RegularMethods,
+	/// Source type of values for bands calculation. Default is [`HL2`](crate::core::Source#variant.HL2)
+	pub source: Source,
+}
+
+impl IndicatorConfig for SuperTrend {
+	const NAME: &'static str = "SuperTrend";
+	const VALUE_NAMES: &'static [&'static str] = &["line", "trend"];
+	const SIGNAL_NAMES: &'static [&'static str] = &["signal"];
+
+	fn validate(&self) -> bool {
+		self.period > 0 && self.period < PeriodType::MAX && self.multiplier > 0.
+	}
+
+	fn set(&mut self, name: &str, value: String) -> Option<Error> {
+		match name {
+			"period" => match value.parse() {
+				Err(_) => return Some(Error::ParameterParse(name.to_string(), value.to_string())),
+				Ok(value) => self.period = value,
+			},
+			"multiplier" => match value.parse() {
+				Err(_) => return Some(Error::ParameterParse(name.to_string(), value.to_string())),
+				Ok(value) => self.multiplier = value,
+			},
+			"method" => match value.parse() {
+				Err(_) => return Some(Error::ParameterParse(name.to_string(), value.to_string())),
+				Ok(value) => self.method = value,
+			},
+			"source" => match value.parse() {
+				Err(_) => return Some(Error::ParameterParse(name.to_string(), value.to_string())),
+				Ok(value) => self.source = value,
+			},
+
+			_ => {
+				return Some(Error::UnknownParameter(name.to_string()));
+			}
+		};
+
+		None
+	}
+
+	fn parameters(&self) -> Vec<ParamDescriptor> {
+		let default = Self::default();
+
+		vec![
+			ParamDescriptor::new("period", ParamKind::Period, self.period, default.period)
+				.range(Included(1.), Excluded(PeriodType::MAX as ValueType)),
+			ParamDescriptor::new(
+				"multiplier",
+				ParamKind::Float,
+				self.multiplier,
+				default.multiplier,
+			)
+			.range(Excluded(0.), Unbounded),
+			ParamDescriptor::new("method", ParamKind::Method, self.method, default.method),
+			ParamDescriptor::new("source", ParamKind::Source, self.source, default.source),
+		]
+	}
+
+	fn size(&self) -> (u8, u8) {
+		(2, 1)
+	}
+
+	fn min_periods(&self) -> PeriodType {
+		self.period.saturating_add(1)
+	}
+}
+
+impl<T: OHLC> IndicatorInitializer<T> for SuperTrend {
+	type Instance = SuperTrendInstance<T>;
+
+	fn init(self, candle: T) -> Result<Self::Instance, Error>
+	where
+		Self: Sized,
+	{
+		if !self.validate() {
+			return Err(Error::WrongConfig);
+		}
+
+		let cfg = self;
+		let src = candle.source(cfg.source);
+		let tr = candle.tr(&candle);
+
+		Ok(Self::Instance {
+			atr: method(cfg.method, cfg.period, tr)?,
+			prev_candle: candle,
+			upper: tr.mul_add(cfg.multiplier, src),
+			lower: tr.mul_add(-cfg.multiplier, src),
+			trend: 1,
+			cfg,
+		})
+	}
+}
+
+impl Default for SuperTrend {
+	fn default() -> Self {
+		Self {
+			period: 10,
+			multiplier: 3.0,
+			method: RegularMethods::RMA,
+			source: Source::HL2,
+		}
+	}
+}
+
+/// `SuperTrend` state structure
+#[derive(Debug, Clone)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct SuperTrendInstance<T: OHLC> {
+	cfg: SuperTrend,
+
+	atr: RegularMethod,
+	prev_candle: T,
+	upper: ValueType,
+	lower: ValueType,
+	trend: i8,
+}
+
+impl<T: OHLC> IndicatorInstance<T> for SuperTrendInstance<T> {
+	type Config = SuperTrend;
+
+	fn config(&self) -> &Self::Config {
+		&self.cfg
+	}
+
+	fn next(&mut self, candle: T) -> IndicatorResult {
+		let src = candle.source(self.cfg.source);
+		let atr = self.atr.next(candle.tr(&self.prev_candle));
+		let prev_close = self.prev_candle.close();
+		self.prev_candle = candle;
+
+		let basic_upper = atr.mul_add(self.cfg.multiplier, src);
+		let basic_lower = atr.mul_add(-self.cfg.multiplier, src);
+
+		// bands may only tighten while price stays inside them
+		let upper = if basic_upper < self.upper || prev_close > self.upper {
+			basic_upper
+		} else {
+			self.upper
+		};
+
+		let lower = if basic_lower > self.lower || prev_close < self.lower {
+			basic_lower
+		} else {
+			self.lower
+		};
+
+		let close = candle.close();
+		let prev_trend = self.trend;
+
+		if self.trend < 0 && close > upper {
+			self.trend = 1;
+		} else if self.trend > 0 && close < lower {
+			self.trend = -1;
+		}
+
+		self.upper = upper;
+		self.lower = lower;
+
+		let line = if self.trend > 0 { lower } else { upper };
+		let signal = (self.trend != prev_trend) as i8 * self.trend;
+
+		IndicatorResult::new(&[line, self.trend as ValueType], &[Action::from(signal)])
+	}
+
+	fn reset(&mut self, candle: T) -> Result<(), Error> {
+		let src = candle.source(self.cfg.source);
+		let tr = candle.tr(&candle);
+
+		self.atr.reset(tr)?;
+		self.prev_candle = candle;
+		self.upper = tr.mul_add(self.cfg.multiplier, src);
+		self.lower = tr.mul_add(-self.cfg.multiplier, src);
+		self.trend = 1;
+
+		Ok(())
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	use super::SuperTrend;
+	use crate::core::{Action, Candle, IndicatorConfig, IndicatorInitializer, IndicatorInstance};
+	use crate::helpers::{assert_eq_float, RegularMethods};
+	use alloc::vec::Vec;
+
+	#[test]
+	fn test_super_trend_reference() {
+		let candles: Vec<Candle> = [
+			(10.0, 10.3, 10.0, 10.2),
+			(10.2, 11.0, 10.1, 10.9),
+			(10.9, 12.2, 10.8, 11.9),
+			(11.9, 13.0, 11.6, 12.8),
+			(12.8, 13.2, 11.9, 12.2),
+			(12.2, 13.5, 12.1, 13.1),
+			(13.1, 13.2, 12.7, 12.8),
+			(12.8, 12.9, 11.7, 11.9),
+		]
+		.iter()
+		.map(|&(open, high, low, close)| Candle::from((open, high, low, close, 1.0)))
+		.collect();
+
+		// evaluated by the TradingView `ta.supertrend` algorithm, skipping the first bar
+		let expected = [
+			(10.325, 1.0, Action::None),
+			(11.15, 1.0, Action::None),
+			(11.95, 1.0, Action::None),
+			(12.875, -1.0, Action::SELL_ALL),
+			(12.45, 1.0, Action::BUY_ALL),
+			(13.075, -1.0, Action::SELL_ALL),
+			(12.6, -1.0, Action::None),
+		];
+
+		let config = SuperTrend {
+			period: 1,
+			multiplier: 0.25,
+			method: RegularMethods::RMA,
+			..SuperTrend::default()
+		};
+		assert!(config.validate());
+
+		let mut state = config.init(candles[0]).unwrap();
+		state.next(candles[0]);
+
+		for (candle, &(line, trend, signal)) in candles[1..].iter().zip(&expected) {
+			let result = state.next(*candle);
+
+			assert_eq_float(line, result.values()[0]);
+			assert_eq_float(trend, result.values()[1]);
+			assert_eq!(signal, result.signals()[0]);
+		}
+	}
+}
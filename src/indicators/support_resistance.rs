@@ -0,0 +1,303 @@
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
+
+use crate::core::{Action, Error, PeriodType, ValueType, Window, OHLC};
+use crate::core::{IndicatorConfig, IndicatorInitializer, IndicatorInstance, IndicatorResult};
+use crate::core::{ParamDescriptor, ParamKind};
+use alloc::string::String;
+use alloc::string::ToString;
+use alloc::vec::Vec;
+use core::cmp::Ordering;
+use core::ops::Bound::{Excluded, Included};
+
+/// Support and resistance levels detector
+///
+/// Swing pivot high is a candle whose *high* is strictly greater than *highs* of `strength` candles on each side.
+/// Swing pivot low is a candle whose *low* is strictly lower than *lows* of `strength` candles on each side.
+/// Pivot is confirmed only after `strength` candles to the right of it are closed.
+///
+/// Every confirmed pivot is merged into the nearest tracked level, if it is closer than `tolerance` \(relative to the level price\).
+/// Merged level price is the average of all of its pivots prices.
+/// Otherwise pivot starts a new level. When there are more than `max_levels` levels, then the level with the least pivots count is dropped
+/// \(the oldest one among equal\).
+///
+/// # 2 values
+///
+/// * `support` value \(the nearest level at or below *close* price\)
+/// * `resistance` value \(the nearest level above *close* price\)
+///
+/// When there is no such level, then *close* price is returned instead.
+///
+/// Range of values is the same as the range of the price values.
+///
+/// # 2 signals
+///
+/// * Approach signal
+///
+/// When *close* price is above `support` by no more than `tolerance`, then returns full buy signal.
+/// When *close* price is below `resistance` by no more than `tolerance`, then returns full sell signal.
+/// Otherwise no signal
+///
+/// * Breakout signal
+///
+/// When *close* price crosses any level upwards, then returns full buy signal.
+/// When *close* price crosses any level downwards, then returns full sell signal.
+/// Otherwise no signal
+#[derive(Debug, Clone, Copy)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct SupportResistance {
+	/// Count of candles on each side of the swing pivot. Default is `5`.
+	///
+	/// Range in \[1; ([`PeriodType::MAX`](crate::core::PeriodType) - 1) / 2\)
+	pub strength: PeriodType,
+	/// Relative distance for pivots merging and level approach. Default is `0.005`.
+	///
+	/// Range in \(0.0; 1.0\)
+	pub tolerance: ValueType,
+	/// Maximum count of tracked levels. Default is `8`.
+	///
+	/// Range in \[1; [`PeriodType::MAX`](crate::core::PeriodType)\]
+	pub max_levels: PeriodType,
+}
+
+impl IndicatorConfig for SupportResistance {
+	const NAME: &'static str = "SupportResistance";
+	const VALUE_NAMES: &'static [&'static str] = &["support", "resistance"];
+	const SIGNAL_NAMES: &'static [&'static str] = &["approach", "breakout"];
+
+	fn validate(&self) -> bool {
+		self.strength > 0
+			&& self.strength < (PeriodType::MAX - 1) / 2
+			&& self.tolerance > 0.
+			&& self.tolerance < 1.
+			&& self.max_levels > 0
+	}
+
+	fn set(&mut self, name: &str, value: String) -> Option<Error> {
+		match name {
+			"strength" => match value.parse() {
+				Err(_) => return Some(Error::ParameterParse(name.to_string(), value.to_string())),
+				Ok(value) => self.strength = value,
+			},
+			"tolerance" => match value.parse() {
+				Err(_) => return Some(Error::ParameterParse(name.to_string(), value.to_string())),
+				Ok(value) => self.tolerance = value,
+			},
+			"max_levels" => match value.parse() {
+				Err(_) => return Some(Error::ParameterParse(name.to_string(), value.to_string())),
+				Ok(value) => self.max_levels = value,
+			},
+
+			_ => {
+				return Some(Error::UnknownParameter(name.to_string()));
+			}
+		};
+
+		None
+	}
+
+	fn parameters(&self) -> Vec<ParamDescriptor> {
+		let default = Self::default();
+
+		vec![
+			ParamDescriptor::new(
+				"strength",
+				ParamKind::Period,
+				self.strength,
+				default.strength,
+			)
+			.range(
+				Included(1.),
+				Excluded(((PeriodType::MAX - 1) / 2) as ValueType),
+			),
+			ParamDescriptor::new(
+				"tolerance",
+				ParamKind::Float,
+				self.tolerance,
+				default.tolerance,
+			)
+			.range(Excluded(0.0), Excluded(1.0)),
+			ParamDescriptor::new(
+				"max_levels",
+				ParamKind::Period,
+				self.max_levels,
+				default.max_levels,
+			)
+			.range(Included(1.), Included(PeriodType::MAX as ValueType)),
+		]
+	}
+
+	fn size(&self) -> (u8, u8) {
+		(2, 2)
+	}
+
+	fn min_periods(&self) -> PeriodType {
+		self.strength.saturating_mul(2).saturating_add(1)
+	}
+}
+
+impl<T: OHLC> IndicatorInitializer<T> for SupportResistance {
+	type Instance = SupportResistanceInstance;
+
+	fn init(self, candle: T) -> Result<Self::Instance, Error>
+	where
+		Self: Sized,
+	{
+		if !self.validate() {
+			return Err(Error::WrongConfig);
+		}
+
+		let cfg = self;
+
+		Ok(Self::Instance {
+			window: Window::new(cfg.strength * 2 + 1, (candle.high(), candle.low())),
+			levels: Vec::with_capacity(cfg.max_levels as usize + 1),
+			prev_close: candle.close(),
+			cfg,
+		})
+	}
+}
+
+impl Default for SupportResistance {
+	fn default() -> Self {
+		Self {
+			strength: 5,
+			tolerance: 0.005,
+			max_levels: 8,
+		}
+	}
+}
+
+/// Support and resistance levels detector state structure
+#[derive(Debug, Clone)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct SupportResistanceInstance {
+	cfg: SupportResistance,
+
+	window: Window<(ValueType, ValueType)>,
+	levels: Vec<(ValueType, usize)>,
+	prev_close: ValueType,
+}
+
+impl SupportResistanceInstance {
+	/// Returns currently tracked levels as pairs of level price and count of merged pivots
+	#[must_use]
+	pub fn levels(&self) -> &[(ValueType, usize)] {
+		&self.levels
+	}
+
+	fn add_pivot(&mut self, price: ValueType) {
+		let tolerance = self.cfg.tolerance;
+		let nearest = self
+			.levels
+			.iter_mut()
+			.filter(|(level, _)| (price - *level).abs() <= level.abs() * tolerance)
+			.min_by(|(a, _), (b, _)| {
+				(price - a)
+					.abs()
+					.partial_cmp(&(price - b).abs())
+					.unwrap_or(Ordering::Equal)
+			});
+
+		if let Some((level, count)) = nearest {
+			*count += 1;
+			*level += (price - *level) / *count as ValueType;
+			return;
+		}
+
+		self.levels.push((price, 1));
+
+		if self.levels.len() > self.cfg.max_levels as usize {
+			let weakest = self
+				.levels
+				.iter()
+				.enumerate()
+				.min_by_key(|&(_, &(_, count))| count)
+				.map(|(i, _)| i)
+				.unwrap_or_default();
+
+			self.levels.remove(weakest);
+		}
+	}
+}
+
+impl<T: OHLC> IndicatorInstance<T> for SupportResistanceInstance {
+	type Config = SupportResistance;
+
+	fn config(&self) -> &Self::Config {
+		&self.cfg
+	}
+
+	fn next(&mut self, candle: T) -> IndicatorResult {
+		self.window.push((candle.high(), candle.low()));
+
+		let middle = self.cfg.strength as usize;
+		let (high, low) = self.window.iter().nth(middle).unwrap_or_default();
+
+		let (is_high, is_low) = self
+			.window
+			.iter()
+			.enumerate()
+			.filter(|&(i, _)| i != middle)
+			.fold((true, true), |(is_high, is_low), (_, (h, l))| {
+				(is_high && h < high, is_low && l > low)
+			});
+
+		if is_high {
+			self.add_pivot(high);
+		}
+
+		if is_low {
+			self.add_pivot(low);
+		}
+
+		let close = candle.close();
+		let prev_close = self.prev_close;
+		self.prev_close = close;
+
+		let support = self
+			.levels
+			.iter()
+			.map(|&(level, _)| level)
+			.filter(|&level| level <= close)
+			.fold(None, |s: Option<ValueType>, level| {
+				Some(s.map_or(level, |s| s.max(level)))
+			});
+		let resistance = self
+			.levels
+			.iter()
+			.map(|&(level, _)| level)
+			.filter(|&level| level > close)
+			.fold(None, |r: Option<ValueType>, level| {
+				Some(r.map_or(level, |r| r.min(level)))
+			});
+
+		let near = |level: Option<ValueType>| matches!(level, Some(level) if (close - level).abs() <= level.abs() * self.cfg.tolerance);
+
+		let approach = near(support) as i8 - near(resistance) as i8;
+
+		let (up, down) = self
+			.levels
+			.iter()
+			.fold((false, false), |(up, down), &(level, _)| {
+				(
+					up || (prev_close <= level && close > level),
+					down || (prev_close >= level && close < level),
+				)
+			});
+		let breakout = up as i8 - down as i8;
+
+		IndicatorResult::new(
+			&[support.unwrap_or(close), resistance.unwrap_or(close)],
+			&[Action::from(approach), Action::from(breakout)],
+		)
+	}
+
+	fn reset(&mut self, candle: T) -> Result<(), Error> {
+		self.window.fill((candle.high(), candle.low()));
+		self.levels.clear();
+		self.prev_close = candle.close();
+
+		Ok(())
+	}
+}
@@ -2,8 +2,13 @@ use crate::core::{
 	Error, IndicatorConfig, IndicatorInitializer, IndicatorInstance, IndicatorResult, Method,
 	PeriodType, Source, ValueType, OHLC,
 };
+use crate::core::{ParamDescriptor, ParamKind};
 use crate::helpers::{method, RegularMethod, RegularMethods};
 use crate::methods::{Change, Cross, TMA};
+use alloc::string::String;
+use alloc::string::ToString;
+use alloc::vec::Vec;
+use core::ops::Bound::Included;
 
 #[cfg(feature = "serde")]
 use serde::{Deserialize, Serialize};
@@ -21,6 +26,8 @@ pub struct Trix {
 
 impl IndicatorConfig for Trix {
 	const NAME: &'static str = "Trix";
+	const VALUE_NAMES: &'static [&'static str] = &["value"];
+	const SIGNAL_NAMES: &'static [&'static str] = &["direction", "signal_line_cross", "zero_cross"];
 
 	fn validate(&self) -> bool {
 		self.period1 > 2 && self.period2 > 1
@@ -40,17 +47,38 @@ impl IndicatorConfig for Trix {
 				Err(_) => return Some(Error::ParameterParse(name.to_string(), value.to_string())),
 				Ok(value) => self.source = value,
 			},
+			"method2" => match value.parse() {
+				Err(_) => return Some(Error::ParameterParse(name.to_string(), value.to_string())),
+				Ok(value) => self.method2 = value,
+			},
 			_ => {
-				return Some(Error::ParameterParse(name.to_string(), value));
+				return Some(Error::UnknownParameter(name.to_string()));
 			}
 		};
 
 		None
 	}
 
+	fn parameters(&self) -> Vec<ParamDescriptor> {
+		let default = Self::default();
+
+		vec![
+			ParamDescriptor::new("period1", ParamKind::Period, self.period1, default.period1)
+				.range(Included(3.), Included(PeriodType::MAX as ValueType)),
+			ParamDescriptor::new("period2", ParamKind::Period, self.period2, default.period2)
+				.range(Included(2.), Included(PeriodType::MAX as ValueType)),
+			ParamDescriptor::new("method2", ParamKind::Method, self.method2, default.method2),
+			ParamDescriptor::new("source", ParamKind::Source, self.source, default.source),
+		]
+	}
+
 	fn size(&self) -> (u8, u8) {
 		(1, 3)
 	}
+
+	fn min_periods(&self) -> PeriodType {
+		self.period1.saturating_mul(3).saturating_sub(1)
+	}
 }
 
 impl<T: OHLC> IndicatorInitializer<T> for Trix {
@@ -89,7 +117,8 @@ impl Default for Trix {
 }
 
 // https://en.wikipedia.org/wiki/Trix_(technical_analysis)
-#[derive(Debug)]
+#[derive(Debug, Clone)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 pub struct TRIXInstance {
 	// <T: OHLC> {
 	cfg: Trix,
@@ -139,4 +168,33 @@ impl<T: OHLC> IndicatorInstance<T> for TRIXInstance {
 
 		IndicatorResult::new(&[value], &[signal1.into(), signal2, signal3])
 	}
+
+	fn reset(&mut self, candle: T) -> Result<(), Error> {
+		let src = candle.source(self.cfg.source);
+
+		self.tma.reset(src)?;
+		self.sig.reset(src)?;
+		self.change.reset(src)?;
+		self.cross1.reset((src, src))?;
+		self.cross2.reset((src, src))?;
+		self.prev_value = 0.0;
+
+		Ok(())
+	}
+
+	fn save_undo(&self, stack: &mut Vec<ValueType>) -> bool {
+		self.tma.save_undo(stack)
+			&& self.change.save_undo(stack)
+			&& self.sig.save_undo(stack)
+			&& self.cross1.save_undo(stack)
+			&& self.cross2.save_undo(stack)
+	}
+
+	fn restore_undo(&mut self, stack: &mut Vec<ValueType>) {
+		self.cross2.restore_undo(stack);
+		self.cross1.restore_undo(stack);
+		self.sig.restore_undo(stack);
+		self.change.restore_undo(stack);
+		self.tma.restore_undo(stack);
+	}
 }
@@ -3,7 +3,12 @@ use serde::{Deserialize, Serialize};
 
 use crate::core::{Error, Method, PeriodType, Source, ValueType, OHLC};
 use crate::core::{IndicatorConfig, IndicatorInitializer, IndicatorInstance, IndicatorResult};
+use crate::core::{ParamDescriptor, ParamKind};
 use crate::methods::{Change, Cross, CrossAbove, CrossUnder, EMA};
+use alloc::string::String;
+use alloc::string::ToString;
+use alloc::vec::Vec;
+use core::ops::Bound::Included;
 
 // https://en.wikipedia.org/wiki/Trix_(technical_analysis)
 #[derive(Debug, Clone, Copy)]
@@ -18,6 +23,8 @@ pub struct TrueStrengthIndex {
 
 impl IndicatorConfig for TrueStrengthIndex {
 	const NAME: &'static str = "TrueStrengthIndex";
+	const VALUE_NAMES: &'static [&'static str] = &["value", "signal_line"];
+	const SIGNAL_NAMES: &'static [&'static str] = &["zone", "zero_cross", "signal_line_cross"];
 
 	fn validate(&self) -> bool {
 		self.period1 > 2 && self.zone >= 0. && self.zone <= 1.
@@ -47,16 +54,39 @@ impl IndicatorConfig for TrueStrengthIndex {
 			},
 
 			_ => {
-				return Some(Error::ParameterParse(name.to_string(), value));
+				return Some(Error::UnknownParameter(name.to_string()));
 			}
 		};
 
 		None
 	}
 
+	fn parameters(&self) -> Vec<ParamDescriptor> {
+		let default = Self::default();
+
+		vec![
+			ParamDescriptor::new("period1", ParamKind::Period, self.period1, default.period1)
+				.range(Included(3.), Included(PeriodType::MAX as ValueType)),
+			ParamDescriptor::new("period2", ParamKind::Period, self.period2, default.period2)
+				.range(Included(1.), Included(PeriodType::MAX as ValueType)),
+			ParamDescriptor::new("period3", ParamKind::Period, self.period3, default.period3)
+				.range(Included(1.), Included(PeriodType::MAX as ValueType)),
+			ParamDescriptor::new("zone", ParamKind::Float, self.zone, default.zone)
+				.range(Included(0.), Included(1.)),
+			ParamDescriptor::new("source", ParamKind::Source, self.source, default.source),
+		]
+	}
+
 	fn size(&self) -> (u8, u8) {
 		(2, 3)
 	}
+
+	fn min_periods(&self) -> PeriodType {
+		self.period1
+			.saturating_add(self.period2)
+			.saturating_add(self.period3)
+			.saturating_sub(1)
+	}
 }
 
 impl<T: OHLC> IndicatorInitializer<T> for TrueStrengthIndex {
@@ -101,7 +131,8 @@ impl Default for TrueStrengthIndex {
 	}
 }
 
-#[derive(Debug)]
+#[derive(Debug, Clone)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 pub struct TrueStrengthIndexInstance {
 	cfg: TrueStrengthIndex,
 
@@ -144,4 +175,19 @@ impl<T: OHLC> IndicatorInstance<T> for TrueStrengthIndexInstance {
 
 		IndicatorResult::new(&[value, sig], &[s1, s2, s3])
 	}
+
+	fn reset(&mut self, candle: T) -> Result<(), Error> {
+		self.change.reset(candle.source(self.cfg.source))?;
+		self.ema11.reset(0.)?;
+		self.ema12.reset(0.)?;
+		self.ema21.reset(0.)?;
+		self.ema22.reset(0.)?;
+		self.ema.reset(0.)?;
+		self.cross_under = CrossUnder::default();
+		self.cross_above = CrossAbove::default();
+		self.cross_over1 = Cross::default();
+		self.cross_over2 = Cross::default();
+
+		Ok(())
+	}
 }
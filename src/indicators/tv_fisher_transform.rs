@@ -4,8 +4,16 @@ use serde::{Deserialize, Serialize};
 
 use crate::core::{Action, Error, Method, PeriodType, Source, ValueType, OHLC};
 use crate::core::{IndicatorConfig, IndicatorInitializer, IndicatorInstance, IndicatorResult};
+use crate::core::{ParamDescriptor, ParamKind};
 use crate::helpers::{method, RegularMethod, RegularMethods};
 use crate::methods::{Cross, Highest, Lowest};
+use alloc::string::String;
+use alloc::string::ToString;
+use alloc::vec::Vec;
+use core::ops::Bound::Included;
+
+#[cfg(not(feature = "std"))]
+use crate::core::Float as _;
 
 // https://www.investopedia.com/terms/f/fisher-transform.asp
 // FT = 1/2 * ln((1+x)/(1-x)) = arctanh(x)
@@ -24,6 +32,8 @@ pub struct TVFisherTransform {
 
 impl IndicatorConfig for TVFisherTransform {
 	const NAME: &'static str = "TVFisherTransform";
+	const VALUE_NAMES: &'static [&'static str] = &["value", "previous"];
+	const SIGNAL_NAMES: &'static [&'static str] = &["zero_cross", "direction"];
 
 	fn validate(&self) -> bool {
 		self.period1 >= 3 && self.delta >= 1 && self.period2 >= 1
@@ -57,16 +67,36 @@ impl IndicatorConfig for TVFisherTransform {
 			},
 
 			_ => {
-				return Some(Error::ParameterParse(name.to_string(), value));
+				return Some(Error::UnknownParameter(name.to_string()));
 			}
 		};
 
 		None
 	}
 
+	fn parameters(&self) -> Vec<ParamDescriptor> {
+		let default = Self::default();
+
+		vec![
+			ParamDescriptor::new("period1", ParamKind::Period, self.period1, default.period1)
+				.range(Included(3.), Included(PeriodType::MAX as ValueType)),
+			ParamDescriptor::new("period2", ParamKind::Period, self.period2, default.period2)
+				.range(Included(1.), Included(PeriodType::MAX as ValueType)),
+			ParamDescriptor::new("zone", ParamKind::Float, self.zone, default.zone),
+			ParamDescriptor::new("delta", ParamKind::Period, self.delta, default.delta)
+				.range(Included(1.), Included(PeriodType::MAX as ValueType)),
+			ParamDescriptor::new("method", ParamKind::Method, self.method, default.method),
+			ParamDescriptor::new("source", ParamKind::Source, self.source, default.source),
+		]
+	}
+
 	fn size(&self) -> (u8, u8) {
 		(2, 2)
 	}
+
+	fn min_periods(&self) -> PeriodType {
+		self.period1.saturating_add(1)
+	}
 }
 
 impl<T: OHLC> IndicatorInitializer<T> for TVFisherTransform {
@@ -110,7 +140,8 @@ impl Default for TVFisherTransform {
 	}
 }
 
-#[derive(Debug)]
+#[derive(Debug, Clone)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 pub struct TVFisherTransformInstance {
 	cfg: TVFisherTransform,
 
@@ -201,4 +232,19 @@ impl<T: OHLC> IndicatorInstance<T> for TVFisherTransformInstance {
 			&[Action::from(s1), Action::from(s2)],
 		)
 	}
+
+	fn reset(&mut self, candle: T) -> Result<(), Error> {
+		let src = candle.source(self.cfg.source);
+
+		self.ma1.reset(0.)?;
+		self.highest.reset(src)?;
+		self.lowest.reset(src)?;
+		self.cross_over = Cross::default();
+		self.extreme = 0;
+		self.prev_value = 0.;
+		self.prev_fish = 0.;
+		self.prev_state = false;
+
+		Ok(())
+	}
 }
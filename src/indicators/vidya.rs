@@ -3,7 +3,15 @@ use serde::{Deserialize, Serialize};
 
 use crate::core::{Action, Error, Method, PeriodType, Source, ValueType, Window, OHLC};
 use crate::core::{IndicatorConfig, IndicatorInitializer, IndicatorInstance, IndicatorResult};
+use crate::core::{ParamDescriptor, ParamKind};
 use crate::methods::Change;
+use alloc::string::String;
+use alloc::string::ToString;
+use alloc::vec::Vec;
+use core::ops::Bound::Included;
+
+#[cfg(not(feature = "std"))]
+use crate::core::Float as _;
 
 #[derive(Debug, Clone, Copy)]
 #[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
@@ -15,6 +23,8 @@ pub struct Vidya {
 
 impl IndicatorConfig for Vidya {
 	const NAME: &'static str = "Vidya";
+	const VALUE_NAMES: &'static [&'static str] = &["value"];
+	const SIGNAL_NAMES: &'static [&'static str] = &["signal"];
 
 	fn validate(&self) -> bool {
 		self.period > 1 && self.zone >= 0. && self.zone <= 5.
@@ -36,16 +46,32 @@ impl IndicatorConfig for Vidya {
 			},
 
 			_ => {
-				return Some(Error::ParameterParse(name.to_string(), value));
+				return Some(Error::UnknownParameter(name.to_string()));
 			}
 		};
 
 		None
 	}
 
+	fn parameters(&self) -> Vec<ParamDescriptor> {
+		let default = Self::default();
+
+		vec![
+			ParamDescriptor::new("period", ParamKind::Period, self.period, default.period)
+				.range(Included(2.), Included(PeriodType::MAX as ValueType)),
+			ParamDescriptor::new("zone", ParamKind::Float, self.zone, default.zone)
+				.range(Included(0.), Included(5.)),
+			ParamDescriptor::new("source", ParamKind::Source, self.source, default.source),
+		]
+	}
+
 	fn size(&self) -> (u8, u8) {
 		(1, 1)
 	}
+
+	fn min_periods(&self) -> PeriodType {
+		self.period.saturating_mul(2)
+	}
 }
 
 impl<T: OHLC> IndicatorInitializer<T> for Vidya {
@@ -86,7 +112,8 @@ impl Default for Vidya {
 	}
 }
 
-#[derive(Debug)]
+#[derive(Debug, Clone)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 pub struct VidyaInstance {
 	cfg: Vidya,
 
@@ -167,4 +194,18 @@ impl<T: OHLC> IndicatorInstance<T> for VidyaInstance {
 
 		IndicatorResult::new(&[value], &[Action::from(signal)])
 	}
+
+	fn reset(&mut self, candle: T) -> Result<(), Error> {
+		let src = candle.source(self.cfg.source);
+
+		self.up_sum = 0.;
+		self.dn_sum = 0.;
+		self.last_value = src;
+		self.last_result = src;
+		self.window.fill(0.);
+		self.change.reset(src)?;
+		self.last_signal = 0;
+
+		Ok(())
+	}
 }
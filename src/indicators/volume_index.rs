@@ -0,0 +1,219 @@
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
+
+use crate::core::{Error, Method, PeriodType, Source, ValueType, OHLCV};
+use crate::core::{IndicatorConfig, IndicatorInitializer, IndicatorInstance, IndicatorResult};
+use crate::core::{ParamDescriptor, ParamKind};
+use crate::helpers::{method, RegularMethod, RegularMethods};
+use crate::methods::Cross;
+use alloc::string::String;
+use alloc::string::ToString;
+use alloc::vec::Vec;
+use core::ops::Bound::{Excluded, Included};
+
+/// Negative and Positive Volume Indices
+///
+/// `NVI` accumulates relative `source` changes only on candles where *volume* decreases.
+/// `PVI` accumulates relative `source` changes only on candles where *volume* increases.
+/// Both indices start from `1000.0`.
+///
+/// ## Links
+///
+/// * <https://en.wikipedia.org/wiki/Negative_volume_index>
+///
+/// # 4 values
+///
+/// * `NVI` value
+/// * `NVI signal line` value
+/// * `PVI` value
+/// * `PVI signal line` value
+///
+/// Range in \(0.0; +inf\)
+///
+/// # 2 signals
+///
+/// * `NVI` signal
+///
+/// When `NVI` crosses its signal line upwards, then returns full buy signal.
+/// When `NVI` crosses its signal line downwards, then returns full sell signal.
+/// Otherwise no signal
+///
+/// * `PVI` signal
+///
+/// When `PVI` crosses its signal line upwards, then returns full buy signal.
+/// When `PVI` crosses its signal line downwards, then returns full sell signal.
+/// Otherwise no signal
+#[derive(Debug, Clone, Copy)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct VolumeIndex {
+	/// Signal lines MA period length. Default is `254`.
+	///
+	/// Classic period is `255`, but it does not fit into default `u8` [`PeriodType`](crate::core::PeriodType).
+	///
+	/// Range in \[1; [`PeriodType::MAX`](crate::core::PeriodType)\)
+	pub period: PeriodType,
+	/// Signal lines MA method. Default is [`EMA`](crate::methods::EMA).
+	pub method: RegularMethods,
+	/// Source type of values. Default is [`Close`](crate::core::Source#variant.Close)
+	pub source: Source,
+}
+
+impl IndicatorConfig for VolumeIndex {
+	const NAME: &'static str = "VolumeIndex";
+	const VALUE_NAMES: &'static [&'static str] =
+		&["nvi", "nvi_signal_line", "pvi", "pvi_signal_line"];
+	const SIGNAL_NAMES: &'static [&'static str] = &["nvi", "pvi"];
+
+	fn validate(&self) -> bool {
+		self.period > 0 && self.period < PeriodType::MAX
+	}
+
+	fn set(&mut self, name: &str, value: String) -> Option<Error> {
+		match name {
+			"period" => match value.parse() {
+				Err(_) => return Some(Error::ParameterParse(name.to_string(), value.to_string())),
+				Ok(value) => self.period = value,
+			},
+			"method" => match value.parse() {
+				Err(_) => return Some(Error::ParameterParse(name.to_string(), value.to_string())),
+				Ok(value) => self.method = value,
+			},
+			"source" => match value.parse() {
+				Err(_) => return Some(Error::ParameterParse(name.to_string(), value.to_string())),
+				Ok(value) => self.source = value,
+			},
+
+			_ => {
+				return Some(Error::UnknownParameter(name.to_string()));
+			}
+		};
+
+		None
+	}
+
+	fn parameters(&self) -> Vec<ParamDescriptor> {
+		let default = Self::default();
+
+		vec![
+			ParamDescriptor::new("period", ParamKind::Period, self.period, default.period)
+				.range(Included(1.), Excluded(PeriodType::MAX as ValueType)),
+			ParamDescriptor::new("method", ParamKind::Method, self.method, default.method),
+			ParamDescriptor::new("source", ParamKind::Source, self.source, default.source),
+		]
+	}
+
+	fn is_volume_based(&self) -> bool {
+		true
+	}
+
+	fn size(&self) -> (u8, u8) {
+		(4, 2)
+	}
+
+	fn min_periods(&self) -> PeriodType {
+		self.period.saturating_add(1)
+	}
+}
+
+impl<T: OHLCV> IndicatorInitializer<T> for VolumeIndex {
+	type Instance = VolumeIndexInstance;
+
+	fn init(self, candle: T) -> Result<Self::Instance, Error>
+	where
+		Self: Sized,
+	{
+		if !self.validate() {
+			return Err(Error::WrongConfig);
+		}
+
+		let cfg = self;
+
+		Ok(Self::Instance {
+			prev_src: OHLCV::source(&candle, cfg.source),
+			prev_volume: candle.volume(),
+			nvi: INITIAL_INDEX,
+			pvi: INITIAL_INDEX,
+			nvi_ma: method(cfg.method, cfg.period, INITIAL_INDEX)?,
+			pvi_ma: method(cfg.method, cfg.period, INITIAL_INDEX)?,
+			nvi_cross: Cross::new((), (INITIAL_INDEX, INITIAL_INDEX))?,
+			pvi_cross: Cross::new((), (INITIAL_INDEX, INITIAL_INDEX))?,
+			cfg,
+		})
+	}
+}
+
+impl Default for VolumeIndex {
+	fn default() -> Self {
+		Self {
+			period: 254,
+			method: RegularMethods::EMA,
+			source: Source::Close,
+		}
+	}
+}
+
+const INITIAL_INDEX: ValueType = 1000.;
+
+/// Negative and Positive Volume Indices state structure
+#[derive(Debug, Clone)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct VolumeIndexInstance {
+	cfg: VolumeIndex,
+
+	prev_src: ValueType,
+	prev_volume: ValueType,
+	nvi: ValueType,
+	pvi: ValueType,
+	nvi_ma: RegularMethod,
+	pvi_ma: RegularMethod,
+	nvi_cross: Cross,
+	pvi_cross: Cross,
+}
+
+impl<T: OHLCV> IndicatorInstance<T> for VolumeIndexInstance {
+	type Config = VolumeIndex;
+
+	fn config(&self) -> &Self::Config {
+		&self.cfg
+	}
+
+	fn next(&mut self, candle: T) -> IndicatorResult {
+		let src = OHLCV::source(&candle, self.cfg.source);
+		let volume = candle.volume();
+
+		if self.prev_src != 0. {
+			let change = (src - self.prev_src) / self.prev_src;
+
+			if volume < self.prev_volume {
+				self.nvi += self.nvi * change;
+			} else if volume > self.prev_volume {
+				self.pvi += self.pvi * change;
+			}
+		}
+
+		self.prev_src = src;
+		self.prev_volume = volume;
+
+		let nvi_sigline = self.nvi_ma.next(self.nvi);
+		let pvi_sigline = self.pvi_ma.next(self.pvi);
+
+		let nvi_signal = self.nvi_cross.next((self.nvi, nvi_sigline));
+		let pvi_signal = self.pvi_cross.next((self.pvi, pvi_sigline));
+
+		IndicatorResult::new(
+			&[self.nvi, nvi_sigline, self.pvi, pvi_sigline],
+			&[nvi_signal, pvi_signal],
+		)
+	}
+
+	fn reset(&mut self, candle: T) -> Result<(), Error> {
+		self.prev_src = OHLCV::source(&candle, self.cfg.source);
+		self.prev_volume = candle.volume();
+		self.nvi = INITIAL_INDEX;
+		self.pvi = INITIAL_INDEX;
+		self.nvi_ma.reset(INITIAL_INDEX)?;
+		self.pvi_ma.reset(INITIAL_INDEX)?;
+		self.nvi_cross.reset((INITIAL_INDEX, INITIAL_INDEX))?;
+		self.pvi_cross.reset((INITIAL_INDEX, INITIAL_INDEX))
+	}
+}
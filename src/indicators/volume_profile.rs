@@ -0,0 +1,269 @@
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
+
+use crate::core::{Anchor, Error, ExternalAnchor, Histogram, Method, PeriodType, Source};
+use crate::core::{IndicatorConfig, IndicatorInitializer, IndicatorInstance, IndicatorResult};
+use crate::core::{ParamDescriptor, ParamKind};
+use crate::core::{ValueType, Window, OHLCV};
+use crate::methods::{CrossAbove, CrossUnder};
+use alloc::string::String;
+use alloc::string::ToString;
+use alloc::vec::Vec;
+use core::ops::Bound::{Excluded, Included, Unbounded};
+
+/// Volume Profile
+///
+/// Volume of every candle is accumulated into the [`Histogram`] bin of its `source` price.
+///
+/// When `period` is `0`, volume is accumulated from the first candle and restarts every time
+/// the instance is anchored by its `anchor` method. Otherwise volume is accumulated over the last `period` candles.
+///
+/// # 3 values
+///
+/// * `POC` \(point of control\) value
+///
+/// Middle of the price bin with the highest volume.
+///
+/// * `VAH` \(value area high\) value
+/// * `VAL` \(value area low\) value
+///
+/// Bounds of the price range around `POC`, which holds `value_area` part of the accumulated volume.
+///
+/// Range of values is the same as the range of the `source` values.
+///
+/// # 1 signal
+///
+/// Breakouts are calculated relative to the previous candle's value area.
+///
+/// When *close* price crosses `VAH` upwards, then returns full buy signal.
+/// When *close* price crosses `VAL` downwards, then returns full sell signal.
+/// Otherwise no signal
+#[derive(Debug, Clone, Copy)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct VolumeProfile {
+	/// Rolling window length. Default is `0`.
+	///
+	/// When `period` is `0`, then volume is accumulated until the next anchor (unbounded window).
+	///
+	/// Range in \[0; [`PeriodType::MAX`](crate::core::PeriodType)\)
+	pub period: PeriodType,
+	/// Price bin size. Default is `0.1`.
+	///
+	/// Should be chosen according to the price scale of the instrument.
+	///
+	/// Range in \(0.0; +inf\)
+	pub bin_size: ValueType,
+	/// Part of the volume inside value area. Default is `0.7`.
+	///
+	/// Range in \(0.0; 1.0\]
+	pub value_area: ValueType,
+	/// Price source type of values. Default is [`TP`](crate::core::Source#variant.TP)
+	pub source: Source,
+}
+
+impl IndicatorConfig for VolumeProfile {
+	const NAME: &'static str = "VolumeProfile";
+	const VALUE_NAMES: &'static [&'static str] = &["poc", "vah", "val"];
+	const SIGNAL_NAMES: &'static [&'static str] = &["signal"];
+
+	fn validate(&self) -> bool {
+		self.period < PeriodType::MAX
+			&& self.bin_size > 0.
+			&& self.value_area > 0.
+			&& self.value_area <= 1.
+	}
+
+	fn set(&mut self, name: &str, value: String) -> Option<Error> {
+		match name {
+			"period" => match value.parse() {
+				Err(_) => return Some(Error::ParameterParse(name.to_string(), value.to_string())),
+				Ok(value) => self.period = value,
+			},
+			"bin_size" => match value.parse() {
+				Err(_) => return Some(Error::ParameterParse(name.to_string(), value.to_string())),
+				Ok(value) => self.bin_size = value,
+			},
+			"value_area" => match value.parse() {
+				Err(_) => return Some(Error::ParameterParse(name.to_string(), value.to_string())),
+				Ok(value) => self.value_area = value,
+			},
+			"source" => match value.parse() {
+				Err(_) => return Some(Error::ParameterParse(name.to_string(), value.to_string())),
+				Ok(value) => self.source = value,
+			},
+
+			_ => {
+				return Some(Error::UnknownParameter(name.to_string()));
+			}
+		};
+
+		None
+	}
+
+	fn parameters(&self) -> Vec<ParamDescriptor> {
+		let default = Self::default();
+
+		vec![
+			ParamDescriptor::new("period", ParamKind::Period, self.period, default.period)
+				.range(Included(0.), Excluded(PeriodType::MAX as ValueType)),
+			ParamDescriptor::new(
+				"bin_size",
+				ParamKind::Float,
+				self.bin_size,
+				default.bin_size,
+			)
+			.range(Excluded(0.0), Unbounded),
+			ParamDescriptor::new(
+				"value_area",
+				ParamKind::Float,
+				self.value_area,
+				default.value_area,
+			)
+			.range(Excluded(0.0), Included(1.0)),
+			ParamDescriptor::new("source", ParamKind::Source, self.source, default.source),
+		]
+	}
+
+	fn is_volume_based(&self) -> bool {
+		true
+	}
+
+	fn size(&self) -> (u8, u8) {
+		(3, 1)
+	}
+}
+
+impl<T: OHLCV> IndicatorInitializer<T> for VolumeProfile {
+	type Instance = VolumeProfileInstance;
+
+	fn init(self, candle: T) -> Result<Self::Instance, Error>
+	where
+		Self: Sized,
+	{
+		if !self.validate() {
+			return Err(Error::WrongConfig);
+		}
+
+		let cfg = self;
+		let src = OHLCV::source(&candle, cfg.source);
+		let volume = candle.volume();
+
+		let mut histogram = Histogram::new(cfg.bin_size);
+
+		// in anchored mode there is no window to prefill, so accumulation starts from scratch
+		let window = if cfg.period == 0 {
+			Window::empty()
+		} else {
+			(0..cfg.period).for_each(|_| histogram.add(src, volume));
+			Window::new(cfg.period, (src, volume))
+		};
+
+		Ok(Self::Instance {
+			histogram,
+			window,
+			anchor: ExternalAnchor::default(),
+			prev_vah: src,
+			prev_val: src,
+			cross_above: CrossAbove::new((), (candle.close(), src))?,
+			cross_under: CrossUnder::new((), (candle.close(), src))?,
+			cfg,
+		})
+	}
+}
+
+impl Default for VolumeProfile {
+	fn default() -> Self {
+		Self {
+			period: 0,
+			bin_size: 0.1,
+			value_area: 0.7,
+			source: Source::TP,
+		}
+	}
+}
+
+/// Volume Profile state structure
+#[derive(Debug, Clone)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct VolumeProfileInstance {
+	cfg: VolumeProfile,
+
+	histogram: Histogram,
+	window: Window<(ValueType, ValueType)>,
+	anchor: ExternalAnchor,
+	prev_vah: ValueType,
+	prev_val: ValueType,
+	cross_above: CrossAbove,
+	cross_under: CrossUnder,
+}
+
+impl VolumeProfileInstance {
+	/// Fires the anchor: accumulation restarts from the next candle
+	///
+	/// Has no effect when `period` is not `0`
+	pub fn anchor(&mut self) {
+		if self.cfg.period == 0 {
+			self.anchor.fire();
+		}
+	}
+
+	/// Returns accumulated volume histogram
+	#[must_use]
+	pub const fn histogram(&self) -> &Histogram {
+		&self.histogram
+	}
+}
+
+impl<T: OHLCV> IndicatorInstance<T> for VolumeProfileInstance {
+	type Config = VolumeProfile;
+
+	fn config(&self) -> &Self::Config {
+		&self.cfg
+	}
+
+	fn next(&mut self, candle: T) -> IndicatorResult {
+		if self.anchor.fires(None) {
+			self.histogram.clear();
+		}
+
+		let src = OHLCV::source(&candle, self.cfg.source);
+		let volume = candle.volume();
+
+		self.histogram.add(src, volume);
+
+		if !self.window.is_empty() {
+			let (past_src, past_volume) = self.window.push((src, volume));
+			self.histogram.remove(past_src, past_volume);
+		}
+
+		let poc = self.histogram.mode().unwrap_or(src);
+		let (val, vah) = self
+			.histogram
+			.value_area(self.cfg.value_area)
+			.unwrap_or((src, src));
+
+		// value area already includes the current candle, so breakouts are checked against the previous one
+		let close = candle.close();
+		let signal = self.cross_above.next((close, self.prev_vah))
+			- self.cross_under.next((close, self.prev_val));
+
+		self.prev_vah = vah;
+		self.prev_val = val;
+
+		IndicatorResult::new(&[poc, vah, val], &[signal])
+	}
+
+	fn reset(&mut self, candle: T) -> Result<(), Error> {
+		let src = OHLCV::source(&candle, self.cfg.source);
+		let volume = candle.volume();
+
+		self.histogram.clear();
+		(0..self.cfg.period).for_each(|_| self.histogram.add(src, volume));
+		self.window.fill((src, volume));
+		self.anchor.reset();
+		self.prev_vah = src;
+		self.prev_val = src;
+		self.cross_above.reset((candle.close(), src))?;
+		self.cross_under.reset((candle.close(), src))
+	}
+}
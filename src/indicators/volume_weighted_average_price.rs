@@ -0,0 +1,190 @@
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
+
+use crate::core::{Error, Method, PeriodType, Source, ValueType, Window, OHLCV};
+use crate::core::{IndicatorConfig, IndicatorInitializer, IndicatorInstance, IndicatorResult};
+use crate::core::{ParamDescriptor, ParamKind};
+use crate::methods::Cross;
+use alloc::string::String;
+use alloc::string::ToString;
+use alloc::vec::Vec;
+use core::ops::Bound::{Excluded, Included};
+
+/// Volume Weighted Average Price
+///
+/// ## Links
+///
+/// * <https://en.wikipedia.org/wiki/Volume-weighted_average_price>
+///
+/// # 1 value
+///
+/// * `VWAP` value
+///
+/// Range of values is the same as the range of the `source` values.
+///
+/// # 1 signal
+///
+/// When *close* price crosses `VWAP` upwards, then returns full buy signal.
+/// When *close* price crosses `VWAP` downwards, then returns full sell signal.
+/// Otherwise no signal
+#[derive(Debug, Clone, Copy)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct VolumeWeightedAveragePrice {
+	/// Rolling window length. Default is `0`.
+	///
+	/// When `period` is `0`, then `VWAP` is accumulated over the whole session (unbounded window).
+	///
+	/// Range in \[0; [`PeriodType::MAX`](crate::core::PeriodType)\)
+	pub period: PeriodType,
+	/// Price source type of values. Default is [`TP`](crate::core::Source#variant.TP)
+	pub source: Source,
+}
+
+impl IndicatorConfig for VolumeWeightedAveragePrice {
+	const NAME: &'static str = "VolumeWeightedAveragePrice";
+	const VALUE_NAMES: &'static [&'static str] = &["value"];
+	const SIGNAL_NAMES: &'static [&'static str] = &["signal"];
+
+	fn validate(&self) -> bool {
+		self.period < PeriodType::MAX
+	}
+
+	fn set(&mut self, name: &str, value: String) -> Option<Error> {
+		match name {
+			"period" => match value.parse() {
+				Err(_) => return Some(Error::ParameterParse(name.to_string(), value.to_string())),
+				Ok(value) => self.period = value,
+			},
+			"source" => match value.parse() {
+				Err(_) => return Some(Error::ParameterParse(name.to_string(), value.to_string())),
+				Ok(value) => self.source = value,
+			},
+
+			_ => {
+				return Some(Error::UnknownParameter(name.to_string()));
+			}
+		};
+
+		None
+	}
+
+	fn parameters(&self) -> Vec<ParamDescriptor> {
+		let default = Self::default();
+
+		vec![
+			ParamDescriptor::new("period", ParamKind::Period, self.period, default.period)
+				.range(Included(0.), Excluded(PeriodType::MAX as ValueType)),
+			ParamDescriptor::new("source", ParamKind::Source, self.source, default.source),
+		]
+	}
+
+	fn is_volume_based(&self) -> bool {
+		true
+	}
+
+	fn size(&self) -> (u8, u8) {
+		(1, 1)
+	}
+}
+
+impl<T: OHLCV> IndicatorInitializer<T> for VolumeWeightedAveragePrice {
+	type Instance = VolumeWeightedAveragePriceInstance;
+
+	fn init(self, candle: T) -> Result<Self::Instance, Error>
+	where
+		Self: Sized,
+	{
+		if !self.validate() {
+			return Err(Error::WrongConfig);
+		}
+
+		let cfg = self;
+		let src = OHLCV::source(&candle, cfg.source);
+		let volume = candle.volume();
+
+		// in session mode there is no window to prefill, so accumulation starts from scratch
+		let (window, length) = if cfg.period == 0 {
+			(Window::empty(), 0.)
+		} else {
+			(
+				Window::new(cfg.period, (src, volume)),
+				cfg.period as ValueType,
+			)
+		};
+
+		Ok(Self::Instance {
+			sum: src * volume * length,
+			vol_sum: volume * length,
+			window,
+			cross: Cross::new((), (candle.close(), src))?,
+			cfg,
+		})
+	}
+}
+
+impl Default for VolumeWeightedAveragePrice {
+	fn default() -> Self {
+		Self {
+			period: 0,
+			source: Source::TP,
+		}
+	}
+}
+
+/// Just an alias for [`VolumeWeightedAveragePrice`]
+pub type VWAP = VolumeWeightedAveragePrice;
+
+/// Volume Weighted Average Price state structure
+#[derive(Debug, Clone)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct VolumeWeightedAveragePriceInstance {
+	cfg: VolumeWeightedAveragePrice,
+
+	sum: ValueType,
+	vol_sum: ValueType,
+	window: Window<(ValueType, ValueType)>,
+	cross: Cross,
+}
+
+impl<T: OHLCV> IndicatorInstance<T> for VolumeWeightedAveragePriceInstance {
+	type Config = VolumeWeightedAveragePrice;
+
+	fn config(&self) -> &Self::Config {
+		&self.cfg
+	}
+
+	fn next(&mut self, candle: T) -> IndicatorResult {
+		let src = OHLCV::source(&candle, self.cfg.source);
+		let volume = candle.volume();
+
+		self.sum += src * volume;
+		self.vol_sum += volume;
+
+		if !self.window.is_empty() {
+			let (past_src, past_volume) = self.window.push((src, volume));
+			self.sum -= past_src * past_volume;
+			self.vol_sum -= past_volume;
+		}
+
+		let vwap = if self.vol_sum > 0. {
+			self.sum / self.vol_sum
+		} else {
+			src
+		};
+
+		let signal = self.cross.next((candle.close(), vwap));
+
+		IndicatorResult::new(&[vwap], &[signal])
+	}
+
+	fn reset(&mut self, candle: T) -> Result<(), Error> {
+		let src = OHLCV::source(&candle, self.cfg.source);
+		let volume = candle.volume();
+		let length = self.cfg.period as ValueType;
+
+		self.sum = src * volume * length;
+		self.vol_sum = volume * length;
+		self.window.fill((src, volume));
+		self.cross.reset((candle.close(), src))
+	}
+}
@@ -0,0 +1,211 @@
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
+
+use crate::core::{Error, Method, PeriodType, Source, ValueType, OHLCV};
+use crate::core::{IndicatorConfig, IndicatorInitializer, IndicatorInstance, IndicatorResult};
+use crate::core::{ParamDescriptor, ParamKind};
+use crate::helpers::{method, RegularMethod, RegularMethods};
+use crate::methods::{Cross, VWMA};
+use alloc::string::String;
+use alloc::string::ToString;
+use alloc::vec::Vec;
+use core::ops::Bound::{Excluded, Included};
+
+/// Volume-Weighted Moving Average Convergence Divergence
+///
+/// The same as [`MACD`](crate::indicators::MACD), but fast and slow lines are
+/// [volume weighted moving averages](crate::methods::VWMA).
+///
+/// # 3 values
+///
+/// * `MACD` value
+/// * `signal line` value
+/// * `histogram` value \(`MACD` − `signal line`\)
+///
+/// Range in \(-inf; +inf\)
+///
+/// # 1 signal
+///
+/// When `MACD` crosses `signal line` upwards, then returns full buy signal.
+/// When `MACD` crosses `signal line` downwards, then returns full sell signal.
+/// Otherwise no signal
+#[derive(Debug, Clone, Copy)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct VolumeWeightedMACD {
+	/// Fast VWMA period length. Default is `12`.
+	///
+	/// Range in \[1; `period2`\)
+	pub period1: PeriodType,
+	/// Slow VWMA period length. Default is `26`.
+	///
+	/// Range in \(`period1`; [`PeriodType::MAX`](crate::core::PeriodType)\)
+	pub period2: PeriodType,
+	/// Signal line MA period length. Default is `9`.
+	///
+	/// Range in \[1; [`PeriodType::MAX`](crate::core::PeriodType)\)
+	pub period3: PeriodType,
+	/// Signal line MA method. Default is [`EMA`](crate::methods::EMA).
+	pub method3: RegularMethods,
+	/// Source type of values. Default is [`Close`](crate::core::Source#variant.Close)
+	pub source: Source,
+}
+
+impl IndicatorConfig for VolumeWeightedMACD {
+	const NAME: &'static str = "VolumeWeightedMACD";
+	const VALUE_NAMES: &'static [&'static str] = &["macd", "signal_line", "histogram"];
+	const SIGNAL_NAMES: &'static [&'static str] = &["signal"];
+
+	fn validate(&self) -> bool {
+		self.period1 > 0
+			&& self.period1 < self.period2
+			&& self.period2 < PeriodType::MAX
+			&& self.period3 > 0
+			&& self.period3 < PeriodType::MAX
+	}
+
+	fn set(&mut self, name: &str, value: String) -> Option<Error> {
+		match name {
+			"period1" => match value.parse() {
+				Err(_) => return Some(Error::ParameterParse(name.to_string(), value.to_string())),
+				Ok(value) => self.period1 = value,
+			},
+			"period2" => match value.parse() {
+				Err(_) => return Some(Error::ParameterParse(name.to_string(), value.to_string())),
+				Ok(value) => self.period2 = value,
+			},
+			"period3" => match value.parse() {
+				Err(_) => return Some(Error::ParameterParse(name.to_string(), value.to_string())),
+				Ok(value) => self.period3 = value,
+			},
+			"method3" => match value.parse() {
+				Err(_) => return Some(Error::ParameterParse(name.to_string(), value.to_string())),
+				Ok(value) => self.method3 = value,
+			},
+			"source" => match value.parse() {
+				Err(_) => return Some(Error::ParameterParse(name.to_string(), value.to_string())),
+				Ok(value) => self.source = value,
+			},
+
+			_ => {
+				return Some(Error::UnknownParameter(name.to_string()));
+			}
+		};
+
+		None
+	}
+
+	fn parameters(&self) -> Vec<ParamDescriptor> {
+		let default = Self::default();
+
+		vec![
+			ParamDescriptor::new("period1", ParamKind::Period, self.period1, default.period1)
+				.range(Included(1.), Excluded(self.period2 as ValueType)),
+			ParamDescriptor::new("period2", ParamKind::Period, self.period2, default.period2)
+				.range(
+					Excluded(self.period1 as ValueType),
+					Excluded(PeriodType::MAX as ValueType),
+				),
+			ParamDescriptor::new("period3", ParamKind::Period, self.period3, default.period3)
+				.range(Included(1.), Excluded(PeriodType::MAX as ValueType)),
+			ParamDescriptor::new("method3", ParamKind::Method, self.method3, default.method3),
+			ParamDescriptor::new("source", ParamKind::Source, self.source, default.source),
+		]
+	}
+
+	fn is_volume_based(&self) -> bool {
+		true
+	}
+
+	fn size(&self) -> (u8, u8) {
+		(3, 1)
+	}
+
+	fn min_periods(&self) -> PeriodType {
+		self.period1
+			.max(self.period2)
+			.saturating_add(self.period3)
+			.saturating_sub(1)
+	}
+}
+
+impl<T: OHLCV> IndicatorInitializer<T> for VolumeWeightedMACD {
+	type Instance = VolumeWeightedMACDInstance;
+
+	fn init(self, candle: T) -> Result<Self::Instance, Error>
+	where
+		Self: Sized,
+	{
+		if !self.validate() {
+			return Err(Error::WrongConfig);
+		}
+
+		let cfg = self;
+		let src = OHLCV::source(&candle, cfg.source);
+		let volume = candle.volume();
+
+		Ok(Self::Instance {
+			ma1: VWMA::new(cfg.period1, (src, volume))?,
+			ma2: VWMA::new(cfg.period2, (src, volume))?,
+			ma3: method(cfg.method3, cfg.period3, 0.)?,
+			cross: Cross::new((), (0., 0.))?,
+			cfg,
+		})
+	}
+}
+
+impl Default for VolumeWeightedMACD {
+	fn default() -> Self {
+		Self {
+			period1: 12,
+			period2: 26,
+			period3: 9,
+			method3: RegularMethods::EMA,
+			source: Source::Close,
+		}
+	}
+}
+
+/// Just an alias for [`VolumeWeightedMACD`]
+pub type VWMACD = VolumeWeightedMACD;
+
+/// Volume-Weighted MACD state structure
+#[derive(Debug, Clone)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct VolumeWeightedMACDInstance {
+	cfg: VolumeWeightedMACD,
+
+	ma1: VWMA,
+	ma2: VWMA,
+	ma3: RegularMethod,
+	cross: Cross,
+}
+
+impl<T: OHLCV> IndicatorInstance<T> for VolumeWeightedMACDInstance {
+	type Config = VolumeWeightedMACD;
+
+	fn config(&self) -> &Self::Config {
+		&self.cfg
+	}
+
+	fn next(&mut self, candle: T) -> IndicatorResult {
+		let value = (OHLCV::source(&candle, self.cfg.source), candle.volume());
+
+		let macd = self.ma1.next(value) - self.ma2.next(value);
+		let sigline = self.ma3.next(macd);
+		let histogram = macd - sigline;
+
+		let signal = self.cross.next((macd, sigline));
+
+		IndicatorResult::new(&[macd, sigline, histogram], &[signal])
+	}
+
+	fn reset(&mut self, candle: T) -> Result<(), Error> {
+		let src = OHLCV::source(&candle, self.cfg.source);
+		let volume = candle.volume();
+
+		self.ma1.reset((src, volume))?;
+		self.ma2.reset((src, volume))?;
+		self.ma3.reset(0.)?;
+		self.cross.reset((0., 0.))
+	}
+}
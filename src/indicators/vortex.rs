@@ -0,0 +1,163 @@
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
+
+use crate::core::{Error, Method, PeriodType, ValueType, OHLC};
+use crate::core::{IndicatorConfig, IndicatorInitializer, IndicatorInstance, IndicatorResult};
+use crate::core::{ParamDescriptor, ParamKind};
+use crate::methods::{Cross, Integral};
+use alloc::string::String;
+use alloc::string::ToString;
+use alloc::vec::Vec;
+use core::ops::Bound::{Excluded, Included};
+
+/// Vortex Indicator
+///
+/// ## Links
+///
+/// * <https://en.wikipedia.org/wiki/Vortex_indicator>
+///
+/// # 2 values
+///
+/// * `VI+` value
+/// * `VI-` value
+///
+/// Range in \[0.0; +inf\)
+///
+/// # 1 signal
+///
+/// When `VI+` crosses `VI-` upwards, then returns full buy signal.
+/// When `VI+` crosses `VI-` downwards, then returns full sell signal.
+/// Otherwise no signal
+#[derive(Debug, Clone, Copy)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct Vortex {
+	/// Sums period length. Default is `14`.
+	///
+	/// Range in \[2; [`PeriodType::MAX`](crate::core::PeriodType)\)
+	pub period: PeriodType,
+}
+
+impl IndicatorConfig for Vortex {
+	const NAME: &'static str = "Vortex";
+	const VALUE_NAMES: &'static [&'static str] = &["vi_plus", "vi_minus"];
+	const SIGNAL_NAMES: &'static [&'static str] = &["signal"];
+
+	fn validate(&self) -> bool {
+		self.period > 1 && self.period < PeriodType::MAX
+	}
+
+	fn set(&mut self, name: &str, value: String) -> Option<Error> {
+		match name {
+			"period" => match value.parse() {
+				Err(_) => return Some(Error::ParameterParse(name.to_string(), value.to_string())),
+				Ok(value) => self.period = value,
+			},
+
+			_ => {
+				return Some(Error::UnknownParameter(name.to_string()));
+			}
+		};
+
+		None
+	}
+
+	fn parameters(&self) -> Vec<ParamDescriptor> {
+		let default = Self::default();
+
+		vec![
+			ParamDescriptor::new("period", ParamKind::Period, self.period, default.period)
+				.range(Included(2.), Excluded(PeriodType::MAX as ValueType)),
+		]
+	}
+
+	fn size(&self) -> (u8, u8) {
+		(2, 1)
+	}
+
+	fn min_periods(&self) -> PeriodType {
+		self.period.saturating_add(1)
+	}
+}
+
+impl<T: OHLC> IndicatorInitializer<T> for Vortex {
+	type Instance = VortexInstance<T>;
+
+	fn init(self, candle: T) -> Result<Self::Instance, Error>
+	where
+		Self: Sized,
+	{
+		if !self.validate() {
+			return Err(Error::WrongConfig);
+		}
+
+		let cfg = self;
+		let tr = candle.tr(&candle);
+
+		Ok(Self::Instance {
+			vm_plus: Integral::new(cfg.period, tr)?,
+			vm_minus: Integral::new(cfg.period, tr)?,
+			tr_sum: Integral::new(cfg.period, tr)?,
+			prev_candle: candle,
+			cross: Cross::new((), (1.0, 1.0))?,
+			cfg,
+		})
+	}
+}
+
+impl Default for Vortex {
+	fn default() -> Self {
+		Self { period: 14 }
+	}
+}
+
+/// Vortex Indicator state structure
+#[derive(Debug, Clone)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct VortexInstance<T: OHLC> {
+	cfg: Vortex,
+
+	vm_plus: Integral,
+	vm_minus: Integral,
+	tr_sum: Integral,
+	prev_candle: T,
+	cross: Cross,
+}
+
+impl<T: OHLC> IndicatorInstance<T> for VortexInstance<T> {
+	type Config = Vortex;
+
+	fn config(&self) -> &Self::Config {
+		&self.cfg
+	}
+
+	fn next(&mut self, candle: T) -> IndicatorResult {
+		let plus_sum = self
+			.vm_plus
+			.next((candle.high() - self.prev_candle.low()).abs());
+		let minus_sum = self
+			.vm_minus
+			.next((candle.low() - self.prev_candle.high()).abs());
+		let tr_sum = self.tr_sum.next(candle.tr(&self.prev_candle));
+		self.prev_candle = candle;
+
+		let (vi_plus, vi_minus) = if tr_sum > 0. {
+			(plus_sum / tr_sum, minus_sum / tr_sum)
+		} else {
+			(0., 0.)
+		};
+
+		let signal = self.cross.next((vi_plus, vi_minus));
+
+		IndicatorResult::new(&[vi_plus, vi_minus], &[signal])
+	}
+
+	fn reset(&mut self, candle: T) -> Result<(), Error> {
+		let tr = candle.tr(&candle);
+
+		self.vm_plus.reset(tr)?;
+		self.vm_minus.reset(tr)?;
+		self.tr_sum.reset(tr)?;
+		self.prev_candle = candle;
+		self.cross.reset((1.0, 1.0))
+	}
+}
@@ -5,8 +5,13 @@ use super::commodity_channel_index::CommodityChannelIndexInstance;
 use super::CommodityChannelIndex;
 use crate::core::{Action, Error, Method, PeriodType, ValueType, Window, OHLC};
 use crate::core::{IndicatorConfig, IndicatorInitializer, IndicatorInstance, IndicatorResult};
+use crate::core::{ParamDescriptor, ParamKind};
 use crate::helpers::signi;
 use crate::methods::{Cross, CrossAbove, CrossUnder, SMA};
+use alloc::string::String;
+use alloc::string::ToString;
+use alloc::vec::Vec;
+use core::ops::Bound::{Excluded, Included};
 
 #[derive(Debug, Clone, Copy)]
 #[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
@@ -20,6 +25,8 @@ pub struct WoodiesCCI {
 
 impl IndicatorConfig for WoodiesCCI {
 	const NAME: &'static str = "WoodiesCCI";
+	const VALUE_NAMES: &'static [&'static str] = &["cci", "turbo_cci"];
+	const SIGNAL_NAMES: &'static [&'static str] = &["zero_cross", "trend", "zone"];
 
 	fn validate(&self) -> bool {
 		self.period1 > self.period2
@@ -39,7 +46,7 @@ impl IndicatorConfig for WoodiesCCI {
 				Err(_) => return Some(Error::ParameterParse(name.to_string(), value.to_string())),
 				Ok(value) => self.signal1_period = value,
 			},
-			"signal1_bars_count" => match value.parse() {
+			"signal2_bars_count" | "signal1_bars_count" => match value.parse() {
 				Err(_) => return Some(Error::ParameterParse(name.to_string(), value.to_string())),
 				Ok(value) => self.signal2_bars_count = value,
 			},
@@ -49,13 +56,47 @@ impl IndicatorConfig for WoodiesCCI {
 			},
 
 			_ => {
-				return Some(Error::ParameterParse(name.to_string(), value));
+				return Some(Error::UnknownParameter(name.to_string()));
 			}
 		};
 
 		None
 	}
 
+	fn parameters(&self) -> Vec<ParamDescriptor> {
+		let default = Self::default();
+
+		vec![
+			ParamDescriptor::new("period1", ParamKind::Period, self.period1, default.period1)
+				.range(
+					Excluded(self.period2 as ValueType),
+					Included(PeriodType::MAX as ValueType),
+				),
+			ParamDescriptor::new("period2", ParamKind::Period, self.period2, default.period2)
+				.range(Included(1.), Excluded(self.period1 as ValueType)),
+			ParamDescriptor::new(
+				"signal1_period",
+				ParamKind::Period,
+				self.signal1_period,
+				default.signal1_period,
+			)
+			.range(Included(1.), Included(PeriodType::MAX as ValueType)),
+			ParamDescriptor::new(
+				"signal2_bars_count",
+				ParamKind::Period,
+				self.signal2_bars_count,
+				default.signal2_bars_count,
+			)
+			.range(Included(1.), Included(PeriodType::MAX as ValueType)),
+			ParamDescriptor::new(
+				"signal3_zone",
+				ParamKind::Float,
+				self.signal3_zone,
+				default.signal3_zone,
+			),
+		]
+	}
+
 	fn size(&self) -> (u8, u8) {
 		(2, 3)
 	}
@@ -112,7 +153,8 @@ impl Default for WoodiesCCI {
 	}
 }
 
-#[derive(Debug)]
+#[derive(Debug, Clone)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 pub struct WoodiesCCIInstance {
 	cfg: WoodiesCCI,
 
@@ -156,8 +198,9 @@ impl<T: OHLC> IndicatorInstance<T> for WoodiesCCIInstance {
 		// } else {
 		// 	s2 = 0;
 		// }
-		let s2 = (self.s2_sum >= isize::from(self.cfg.signal2_bars_count)) as i8
-			- (self.s2_sum <= -isize::from(self.cfg.signal2_bars_count)) as i8;
+		#[allow(clippy::cast_possible_wrap)]
+		let bars_count = self.cfg.signal2_bars_count as isize;
+		let s2 = (self.s2_sum >= bars_count) as i8 - (self.s2_sum <= -bars_count) as i8;
 
 		// if s0.is_some() {
 		// 	self.s3_sum = 0.;
@@ -177,4 +220,20 @@ impl<T: OHLC> IndicatorInstance<T> for WoodiesCCIInstance {
 
 		IndicatorResult::new(&[cci1, cci2], &[s1, Action::from(s2), s3])
 	}
+
+	fn reset(&mut self, candle: T) -> Result<(), Error> {
+		self.cci1.reset(candle)?;
+		self.cci2.reset(candle)?;
+		self.sma.reset(0.)?;
+		self.cross1 = Cross::default();
+		self.cross2 = Cross::default();
+		self.s2_sum = 0;
+		self.s3_sum = 0.;
+		self.s3_count = 0;
+		self.window.fill(0);
+		self.cross_above = CrossAbove::default();
+		self.cross_under = CrossUnder::default();
+
+		Ok(())
+	}
 }
@@ -0,0 +1,194 @@
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
+
+use crate::core::{Error, Method, PeriodType, Source, ValueType, OHLC};
+use crate::core::{IndicatorConfig, IndicatorInitializer, IndicatorInstance, IndicatorResult};
+use crate::core::{ParamDescriptor, ParamKind};
+use crate::methods::{Cross, DEMA};
+use alloc::string::String;
+use alloc::string::ToString;
+use alloc::vec::Vec;
+use core::ops::Bound::{Excluded, Included};
+
+/// Zero-Lag Moving Average Convergence Divergence
+///
+/// The same as [`MACD`](crate::indicators::MACD), but every moving average is a zero-lag `EMA`
+/// \(`2·EMA − EMA(EMA)`, see [`DEMA`](crate::methods::DEMA)\).
+///
+/// # 3 values
+///
+/// * `MACD` value
+/// * `signal line` value
+/// * `histogram` value \(`MACD` − `signal line`\)
+///
+/// Range in \(-inf; +inf\)
+///
+/// # 1 signal
+///
+/// When `MACD` crosses `signal line` upwards, then returns full buy signal.
+/// When `MACD` crosses `signal line` downwards, then returns full sell signal.
+/// Otherwise no signal
+#[derive(Debug, Clone, Copy)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct ZeroLagMACD {
+	/// Fast MA period length. Default is `12`.
+	///
+	/// Range in \[1; `period2`\)
+	pub period1: PeriodType,
+	/// Slow MA period length. Default is `26`.
+	///
+	/// Range in \(`period1`; [`PeriodType::MAX`](crate::core::PeriodType)\)
+	pub period2: PeriodType,
+	/// Signal line MA period length. Default is `9`.
+	///
+	/// Range in \[1; [`PeriodType::MAX`](crate::core::PeriodType)\)
+	pub period3: PeriodType,
+	/// Source type of values. Default is [`Close`](crate::core::Source#variant.Close)
+	pub source: Source,
+}
+
+impl IndicatorConfig for ZeroLagMACD {
+	const NAME: &'static str = "ZeroLagMACD";
+	const VALUE_NAMES: &'static [&'static str] = &["macd", "signal_line", "histogram"];
+	const SIGNAL_NAMES: &'static [&'static str] = &["signal"];
+
+	fn validate(&self) -> bool {
+		self.period1 > 0
+			&& self.period1 < self.period2
+			&& self.period2 < PeriodType::MAX
+			&& self.period3 > 0
+			&& self.period3 < PeriodType::MAX
+	}
+
+	fn set(&mut self, name: &str, value: String) -> Option<Error> {
+		match name {
+			"period1" => match value.parse() {
+				Err(_) => return Some(Error::ParameterParse(name.to_string(), value.to_string())),
+				Ok(value) => self.period1 = value,
+			},
+			"period2" => match value.parse() {
+				Err(_) => return Some(Error::ParameterParse(name.to_string(), value.to_string())),
+				Ok(value) => self.period2 = value,
+			},
+			"period3" => match value.parse() {
+				Err(_) => return Some(Error::ParameterParse(name.to_string(), value.to_string())),
+				Ok(value) => self.period3 = value,
+			},
+			"source" => match value.parse() {
+				Err(_) => return Some(Error::ParameterParse(name.to_string(), value.to_string())),
+				Ok(value) => self.source = value,
+			},
+
+			_ => {
+				return Some(Error::UnknownParameter(name.to_string()));
+			}
+		};
+
+		None
+	}
+
+	fn parameters(&self) -> Vec<ParamDescriptor> {
+		let default = Self::default();
+
+		vec![
+			ParamDescriptor::new("period1", ParamKind::Period, self.period1, default.period1)
+				.range(Included(1.), Excluded(self.period2 as ValueType)),
+			ParamDescriptor::new("period2", ParamKind::Period, self.period2, default.period2)
+				.range(
+					Excluded(self.period1 as ValueType),
+					Excluded(PeriodType::MAX as ValueType),
+				),
+			ParamDescriptor::new("period3", ParamKind::Period, self.period3, default.period3)
+				.range(Included(1.), Excluded(PeriodType::MAX as ValueType)),
+			ParamDescriptor::new("source", ParamKind::Source, self.source, default.source),
+		]
+	}
+
+	fn size(&self) -> (u8, u8) {
+		(3, 1)
+	}
+
+	fn min_periods(&self) -> PeriodType {
+		self.period1
+			.max(self.period2)
+			.saturating_add(self.period3)
+			.saturating_mul(2)
+			.saturating_sub(3)
+	}
+}
+
+impl<T: OHLC> IndicatorInitializer<T> for ZeroLagMACD {
+	type Instance = ZeroLagMACDInstance;
+
+	fn init(self, candle: T) -> Result<Self::Instance, Error>
+	where
+		Self: Sized,
+	{
+		if !self.validate() {
+			return Err(Error::WrongConfig);
+		}
+
+		let cfg = self;
+		let src = candle.source(cfg.source);
+
+		Ok(Self::Instance {
+			ma1: DEMA::new(cfg.period1, src)?,
+			ma2: DEMA::new(cfg.period2, src)?,
+			ma3: DEMA::new(cfg.period3, 0.)?,
+			cross: Cross::new((), (0., 0.))?,
+			cfg,
+		})
+	}
+}
+
+impl Default for ZeroLagMACD {
+	fn default() -> Self {
+		Self {
+			period1: 12,
+			period2: 26,
+			period3: 9,
+			source: Source::Close,
+		}
+	}
+}
+
+/// Zero-Lag MACD state structure
+#[derive(Debug, Clone, Copy)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct ZeroLagMACDInstance {
+	cfg: ZeroLagMACD,
+
+	ma1: DEMA,
+	ma2: DEMA,
+	ma3: DEMA,
+	cross: Cross,
+}
+
+impl<T: OHLC> IndicatorInstance<T> for ZeroLagMACDInstance {
+	type Config = ZeroLagMACD;
+
+	fn config(&self) -> &Self::Config {
+		&self.cfg
+	}
+
+	fn next(&mut self, candle: T) -> IndicatorResult {
+		let src = candle.source(self.cfg.source);
+
+		let macd = self.ma1.next(src) - self.ma2.next(src);
+		let sigline = self.ma3.next(macd);
+		let histogram = macd - sigline;
+
+		let signal = self.cross.next((macd, sigline));
+
+		IndicatorResult::new(&[macd, sigline, histogram], &[signal])
+	}
+
+	fn reset(&mut self, candle: T) -> Result<(), Error> {
+		let src = candle.source(self.cfg.source);
+
+		self.ma1.reset(src)?;
+		self.ma2.reset(src)?;
+		self.ma3.reset(0.)?;
+		self.cross.reset((0., 0.))
+	}
+}
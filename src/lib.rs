@@ -15,6 +15,9 @@ See the License for the specific language governing permissions and
 limitations under the License.
 
 */
+#![cfg_attr(not(feature = "std"), no_std)]
+// Test harness links `std` anyway, so its inherent float methods shadow the ones of `Float` trait
+#![cfg_attr(all(test, not(feature = "std")), allow(unused_imports, dead_code))]
 #![warn(
 	missing_docs,
 	missing_debug_implementations,
@@ -137,15 +140,38 @@ limitations under the License.
 //!
 //! If you like this library and you want to say thanks, you can do it also by donating to bitcoin address _1P3gTnaTK9LKSYx2nETrKe2zjP4HMkdhvK_
 
+#[macro_use]
+extern crate alloc;
+
+#[cfg(not(any(feature = "std", feature = "libm")))]
+compile_error!("either `std` or `libm` feature must be enabled");
+
+pub mod backtest;
 pub mod core;
 pub mod helpers;
 pub mod indicators;
 pub mod methods;
+#[cfg(feature = "python")]
+pub mod python;
+pub mod stats;
+#[cfg(feature = "wasm")]
+pub mod wasm;
 
 /// Contains main traits you need to start using this library
 pub mod prelude {
 	pub use super::core::{
-		Candle, Error, IndicatorConfig, IndicatorInitializer, IndicatorInstance, Method, OHLC,
-		OHLCV,
+		Candle, Error, IndicatorConfig, IndicatorInitializer, IndicatorInstance, IteratorExt,
+		Method, OHLC, OHLCV,
 	};
+
+	/// Dynamically dispatchable traits
+	///
+	/// Contains the same items as the main `prelude`, except the indicator traits,
+	/// which are replaced by [`IndicatorConfigDyn`](crate::core::IndicatorConfigDyn) and [`IndicatorInstanceDyn`](crate::core::IndicatorInstanceDyn)
+	pub mod dd {
+		pub use crate::core::{
+			Candle, Error, IndicatorConfigDyn, IndicatorInstanceDyn, IteratorExt, Method, OHLC,
+			OHLCV,
+		};
+	}
 }
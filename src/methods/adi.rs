@@ -1,6 +1,6 @@
 use crate::core::Method;
 use crate::core::{Error, PeriodType, ValueType, Window, OHLCV};
-use std::marker::PhantomData;
+use core::marker::PhantomData;
 
 #[cfg(feature = "serde")]
 use serde::{Deserialize, Serialize};
@@ -22,13 +22,15 @@ use serde::{Deserialize, Serialize};
 ///
 /// Can be used by a shortcut [ADI]
 ///
-/// Used in indicators: [Chaikin Money Flow](crate::indicators::ChaikinMoneyFlow), [Chaikin Oscillator](crate::indicators::ChaikinOscillator)
+/// Used in indicators: [Accumulation/Distribution line](crate::indicators::AccumulationDistribution),
+/// [Chaikin Money Flow](crate::indicators::ChaikinMoneyFlow), [Chaikin Oscillator](crate::indicators::ChaikinOscillator)
 ///
 /// # Parameters
 ///
 /// Has a single parameter `length`: [`PeriodType`]
 ///
-/// When `length` == 0, ADI becomes windowless. That means full ADI value accumulation over time.
+/// When `length` == 0, ADI becomes windowless. That means full ADI value accumulation over time,
+/// which is the classic A/D line. It may also be created by [`ADI::cumulative`].
 ///
 /// When `length` > 0, ADI will be calculated over the last `length` values.
 ///
@@ -83,6 +85,31 @@ pub struct ADI<T: OHLCV> {
 }
 
 impl<T: OHLCV> ADI<T> {
+	/// Creates a windowless ADI, which accumulates values since the beginning of timeseries (the classic A/D line)
+	///
+	/// The same as `ADI::new(0, candle)`.
+	#[must_use]
+	pub fn cumulative() -> Self {
+		Self {
+			length: 0,
+			cmf_sum: 0.0,
+			window: Window::empty(),
+			phantom: PhantomData,
+		}
+	}
+
+	/// Returns the window size, or `0` if ADI is windowless
+	#[must_use]
+	pub const fn length(&self) -> PeriodType {
+		self.length
+	}
+
+	/// Returns `true` if ADI accumulates values since the beginning of timeseries
+	#[must_use]
+	pub const fn is_cumulative(&self) -> bool {
+		self.length == 0
+	}
+
 	/// Returns last calculated value
 	#[must_use]
 	pub fn get_value(&self) -> ValueType {
@@ -96,20 +123,17 @@ impl<T: OHLCV> Method for ADI<T> {
 	type Output = ValueType;
 
 	fn new(length: Self::Params, candle: Self::Input) -> Result<Self, Error> {
-		let mut cmf_sum = 0.0;
-		let window = if length > 0 {
-			let clvv = candle.clv() * candle.volume();
-			cmf_sum = clvv * length as ValueType;
-			Window::new(length, clvv)
-		} else {
-			Window::empty()
-		};
+		if length == 0 {
+			return Ok(Self::cumulative());
+		}
+
+		let clvv = candle.clv() * candle.volume();
 
 		Ok(Self {
 			length,
 
-			cmf_sum,
-			window,
+			cmf_sum: clvv * length as ValueType,
+			window: Window::new(length, clvv),
 			phantom: PhantomData::default(),
 		})
 	}
@@ -125,6 +149,19 @@ impl<T: OHLCV> Method for ADI<T> {
 
 		self.cmf_sum
 	}
+
+	fn reset(&mut self, candle: Self::Input) -> Result<(), Error> {
+		let clvv = candle.clv() * candle.volume();
+
+		if self.length > 0 {
+			self.cmf_sum = clvv * self.length as ValueType;
+			self.window.fill(clvv);
+		} else {
+			self.cmf_sum = 0.0;
+		}
+
+		Ok(())
+	}
 }
 
 #[cfg(test)]
@@ -184,9 +221,12 @@ mod tests {
 
 		let mut candles = RandomCandles::default();
 		let mut adi = ADI::new(0, candles.first()).unwrap();
+		let mut cumulative = ADI::cumulative();
+		assert!(cumulative.is_cumulative());
 
 		candles.take(100).fold(0., |s, candle| {
 			assert_eq_float(adi.next(candle), s + candle.clv() * candle.volume());
+			assert_eq_float(cumulative.next(candle), s + candle.clv() * candle.volume());
 			s + candle.clv() * candle.volume()
 		});
 	}
@@ -58,6 +58,10 @@ impl Method for CCI {
 			0.
 		}
 	}
+
+	fn reset(&mut self, value: Self::Input) -> Result<(), Error> {
+		self.0.reset(value)
+	}
 }
 
 #[cfg(test)]
@@ -65,6 +69,7 @@ mod tests {
 	use super::{Method, CCI as TestingMethod};
 	use crate::core::ValueType;
 	use crate::helpers::{assert_eq_float, RandomCandles};
+	use alloc::vec::Vec;
 
 	#[test]
 	fn test_cci_const() {
@@ -1,5 +1,7 @@
 use crate::core::Method;
 use crate::core::{Error, PeriodType, ValueType, Window};
+use crate::helpers::simd;
+use alloc::vec::Vec;
 
 #[cfg(feature = "serde")]
 use serde::{Deserialize, Serialize};
@@ -26,9 +28,15 @@ use serde::{Deserialize, Serialize};
 ///
 /// This method is relatively slow compare to the other methods.
 ///
+/// [`apply_slice`](crate::core::Method::apply_slice) evaluates the whole slice over the contiguous buffer instead of the ring one, so it is preferable for the batch computations.
+/// With `simd` feature it uses SIMD instructions on `x86_64`. Results are exactly the same as of [`next`](crate::core::Method::next).
+///
+/// Output is normalized by the sum of the `weights`. Use [`Conv::from_taps`] for filters,
+/// which must not be normalized (f.e. high-pass ones), or [`Conv::fir`] to design the filter.
+///
 /// # See also
 ///
-/// [`WMA`](crate::methods::WMA), [`SWMA`](crate::methods::SWMA)
+/// [`WMA`](crate::methods::WMA), [`SWMA`](crate::methods::SWMA), [`FirFilter`](crate::methods::FirFilter)
 ///
 /// [`ValueType`]: crate::core::ValueType
 /// [`PeriodType`]: crate::core::PeriodType
@@ -43,6 +51,29 @@ pub struct Conv {
 	initialized: bool,
 }
 
+impl Conv {
+	/// Creates a new `Conv` with filter `taps`, which are applied as is, without normalization by their sum
+	///
+	/// The last tap is applied to the newest value.
+	///
+	/// ```
+	/// use yata::methods::Conv;
+	/// use yata::prelude::*;
+	///
+	/// // first difference
+	/// let mut diff = Conv::from_taps(vec![-1.0, 1.0], 1.0).unwrap();
+	///
+	/// assert_eq!(diff.next(3.0), 2.0);
+	/// assert_eq!(diff.next(2.0), -1.0);
+	/// ```
+	pub fn from_taps(taps: Vec<ValueType>, value: ValueType) -> Result<Self, Error> {
+		let mut conv = Self::new(taps, value)?;
+		conv.wsum_invert = 1.0;
+
+		Ok(conv)
+	}
+}
+
 impl Method for Conv {
 	type Params = Vec<ValueType>;
 	type Input = ValueType;
@@ -78,6 +109,25 @@ impl Method for Conv {
 			.sum::<ValueType>()
 			* self.wsum_invert
 	}
+
+	fn apply_slice(&mut self, slice: &[Self::Input]) -> Vec<Self::Output> {
+		let buf: Vec<ValueType> = self.window.iter().chain(slice.iter().copied()).collect();
+
+		// every next window is shifted by one value, so the first window is the current one with the oldest value pushed out
+		let mut result = vec![0.; slice.len()];
+		simd::conv(&buf[1..], &self.weights, self.wsum_invert, &mut result);
+
+		self.window.push_slice(slice);
+
+		result
+	}
+
+	fn reset(&mut self, value: Self::Input) -> Result<(), Error> {
+		self.window.fill(value);
+		self.initialized = false;
+
+		Ok(())
+	}
 }
 
 #[cfg(test)]
@@ -86,6 +136,7 @@ mod tests {
 	use super::{Conv as TestingMethod, Method};
 	use crate::core::{PeriodType, ValueType};
 	use crate::helpers::{assert_eq_float, RandomCandles};
+	use alloc::vec::Vec;
 
 	fn get_weights(length: PeriodType) -> Vec<ValueType> {
 		(0..length)
@@ -149,4 +200,27 @@ mod tests {
 			});
 		});
 	}
+
+	#[test]
+	fn test_conv_apply_slice() {
+		let candles = RandomCandles::default();
+
+		let src: Vec<ValueType> = candles.take(300).map(|x| x.close).collect();
+
+		(1..255).for_each(|weights_count| {
+			let weights = get_weights(weights_count);
+			let mut ma = TestingMethod::new(weights.clone(), src[0]).unwrap();
+			let mut ma2 = TestingMethod::new(weights, src[0]).unwrap();
+
+			src.chunks(weights_count as usize * 3 % 41 + 1)
+				.for_each(|chunk| {
+					let values = ma2.apply_slice(chunk);
+					assert_eq!(values.len(), chunk.len());
+
+					chunk.iter().zip(values).for_each(|(&x, value2)| {
+						assert_eq!(ma.next(x).to_bits(), value2.to_bits());
+					});
+				});
+		});
+	}
 }
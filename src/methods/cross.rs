@@ -1,9 +1,13 @@
 use crate::core::Method;
 use crate::core::{Action, Error, ValueType};
+use alloc::vec::Vec;
 
 #[cfg(feature = "serde")]
 use serde::{Deserialize, Serialize};
 
+#[cfg(not(feature = "std"))]
+use crate::core::Float as _;
+
 /// Searches for two timeseries lines of type [`ValueType`] cross each other.
 ///
 /// If `value` crossed `base` upwards, then returns [`Action::BUY_ALL`](crate::core::Action::BUY_ALL)
@@ -84,6 +88,20 @@ impl Method for Cross {
 
 		((up as i8) - (down as i8)).into()
 	}
+
+	fn reset(&mut self, value: Self::Input) -> Result<(), Error> {
+		self.up.reset(value)?;
+		self.down.reset(value)
+	}
+
+	fn save_undo(&self, stack: &mut Vec<ValueType>) -> bool {
+		self.up.save_undo(stack) && self.down.save_undo(stack)
+	}
+
+	fn restore_undo(&mut self, stack: &mut Vec<ValueType>) {
+		self.down.restore_undo(stack);
+		self.up.restore_undo(stack);
+	}
 }
 
 /// Searches for `value` timeseries line crosses `base` line upwards
@@ -173,6 +191,24 @@ impl Method for CrossAbove {
 	fn next(&mut self, value: Self::Input) -> Self::Output {
 		Action::from(self.binary(value.0, value.1) as i8)
 	}
+
+	fn reset(&mut self, value: Self::Input) -> Result<(), Error> {
+		self.last_delta = value.0 - value.1;
+
+		Ok(())
+	}
+
+	fn save_undo(&self, stack: &mut Vec<ValueType>) -> bool {
+		stack.push(self.last_delta);
+
+		true
+	}
+
+	fn restore_undo(&mut self, stack: &mut Vec<ValueType>) {
+		if let Some(last_delta) = stack.pop() {
+			self.last_delta = last_delta;
+		}
+	}
 }
 
 /// Searches for `value` timeseries line crosses `base` line downwards
@@ -262,6 +298,146 @@ impl Method for CrossUnder {
 	fn next(&mut self, value: Self::Input) -> Self::Output {
 		Action::from(self.binary(value.0, value.1) as i8)
 	}
+
+	fn reset(&mut self, value: Self::Input) -> Result<(), Error> {
+		self.last_delta = value.0 - value.1;
+
+		Ok(())
+	}
+
+	fn save_undo(&self, stack: &mut Vec<ValueType>) -> bool {
+		stack.push(self.last_delta);
+
+		true
+	}
+
+	fn restore_undo(&mut self, stack: &mut Vec<ValueType>) {
+		if let Some(last_delta) = stack.pop() {
+			self.last_delta = last_delta;
+		}
+	}
+}
+
+/// Output of the [`CrossEvents`] method
+#[derive(Debug, Clone, Copy, PartialEq)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct CrossEvent {
+	/// Cross on the current step: the same as [`Cross`] returns
+	pub action: Action,
+
+	/// Count of steps since the last cross: `0` when the lines crossed on the current step,
+	/// `None` if they have not crossed yet
+	pub bars_since: Option<usize>,
+
+	/// Level of the last cross, linearly interpolated between the two steps around it,
+	/// `None` if the lines have not crossed yet
+	pub level: Option<ValueType>,
+}
+
+/// Searches for two timeseries lines of type [`ValueType`] cross each other and tracks the last cross
+///
+/// Works like [`Cross`], but also returns count of steps since the last cross and the level where the lines crossed.
+/// The level is found by linear interpolation of both lines between the step before the cross and the step of the cross,
+/// so it fits for placing stops right at the crossing price.
+///
+/// # Parameters
+///
+/// Has no parameters
+///
+/// # Input type
+///
+/// Input type is (`value`: [`ValueType`], `base`: [`ValueType`])
+///
+/// # Output type
+///
+/// Output type is [`CrossEvent`]
+///
+/// # Examples
+///
+/// ```
+/// use yata::prelude::*;
+/// use yata::core::Action;
+/// use yata::methods::CrossEvents;
+///
+/// let mut cross = CrossEvents::new((), (0.0, 2.0)).unwrap();
+///
+/// let event = cross.next((1.0, 2.0));
+/// assert_eq!(event.bars_since, None);
+///
+/// let event = cross.next((3.0, 1.0));
+/// assert_eq!(event.action, Action::BUY_ALL);
+/// assert_eq!(event.bars_since, Some(0));
+/// assert!((event.level.unwrap() - 5.0 / 3.0).abs() < 1e-6);
+///
+/// let event = cross.next((4.0, 1.0));
+/// assert_eq!(event.action, Action::None);
+/// assert_eq!(event.bars_since, Some(1));
+/// ```
+///
+/// # Performance
+///
+/// O(1)
+///
+/// # See also
+///
+/// [`Cross`], [`CrossAbove`], [`CrossUnder`]
+///
+/// [`ValueType`]: crate::core::ValueType
+#[derive(Debug, Clone, Copy)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct CrossEvents {
+	last_value: (ValueType, ValueType),
+	bars_since: Option<usize>,
+	level: Option<ValueType>,
+}
+
+impl Method for CrossEvents {
+	type Params = ();
+	type Input = (ValueType, ValueType);
+	type Output = CrossEvent;
+
+	fn new((): Self::Params, value: Self::Input) -> Result<Self, Error>
+	where
+		Self: Sized,
+	{
+		Ok(Self {
+			last_value: value,
+			bars_since: None,
+			level: None,
+		})
+	}
+
+	#[inline]
+	fn next(&mut self, value: Self::Input) -> Self::Output {
+		let (last_value, last_base) = self.last_value;
+		let last_delta = last_value - last_base;
+		let current_delta = value.0 - value.1;
+
+		let up = last_delta < 0. && current_delta >= 0.;
+		let down = last_delta > 0. && current_delta <= 0.;
+
+		if up || down {
+			let ratio = last_delta / (last_delta - current_delta);
+			self.level = Some(ratio.mul_add(value.0 - last_value, last_value));
+			self.bars_since = Some(0);
+		} else {
+			self.bars_since = self.bars_since.map(|bars| bars.saturating_add(1));
+		}
+
+		self.last_value = value;
+
+		CrossEvent {
+			action: (i8::from(up) - i8::from(down)).into(),
+			bars_since: self.bars_since,
+			level: self.level,
+		}
+	}
+
+	fn reset(&mut self, value: Self::Input) -> Result<(), Error> {
+		*self = Self::new((), value)?;
+
+		Ok(())
+	}
 }
 
 #[cfg(test)]
@@ -270,6 +446,7 @@ mod tests {
 	use crate::core::{Candle, Method, ValueType};
 	use crate::helpers::RandomCandles;
 	use crate::methods::tests::test_const;
+	use alloc::vec::Vec;
 
 	#[test]
 	fn test_cross_const() {
@@ -377,4 +554,46 @@ mod tests {
 			assert_eq!(value1, value2, "{}, {} at index {}", value2, value1, i);
 		});
 	}
+
+	#[test]
+	fn test_cross_events() {
+		use super::{Cross, CrossEvents as TestingMethod};
+		use crate::helpers::assert_eq_float;
+
+		let candles = RandomCandles::default();
+
+		let src: Vec<ValueType> = candles.take(100).map(|x| x.close).collect();
+		let base: Vec<ValueType> = src.iter().rev().copied().collect();
+
+		let mut cross = Cross::new((), (src[0], base[0])).unwrap();
+		let mut events = TestingMethod::new((), (src[0], base[0])).unwrap();
+		let mut last_cross = None;
+
+		for i in 0..src.len() {
+			let action = cross.next((src[i], base[i]));
+			let event = events.next((src[i], base[i]));
+
+			assert_eq!(event.action, action);
+
+			if action != crate::core::Action::None {
+				last_cross = Some(i);
+
+				let level = event.level.unwrap();
+				let (low, high) = if src[i - 1] < src[i] {
+					(src[i - 1], src[i])
+				} else {
+					(src[i], src[i - 1])
+				};
+				assert!(level >= low && level <= high);
+
+				// both lines pass through the crossing point
+				let ratio = (level - src[i - 1]) / (src[i] - src[i - 1]);
+				assert_eq_float(level, ratio.mul_add(base[i] - base[i - 1], base[i - 1]));
+			}
+
+			assert_eq!(event.bars_since, last_cross.map(|c| i - c));
+		}
+
+		assert!(last_cross.is_some());
+	}
 }
@@ -0,0 +1,455 @@
+use crate::core::Method;
+use crate::core::{Decimal, Error, PeriodType, Window};
+use core::cmp::Ordering;
+
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
+
+/// [Simple Moving Average](crate::methods::SMA) of specified `length` for timeseries of [`Decimal`] values
+///
+/// The sum is kept exactly, so there is no drift over time. Only the output is rounded by the division
+/// to 28 significant digits.
+///
+/// # Parameters
+///
+/// Has a single parameter `length`: [`PeriodType`]
+///
+/// `length` should be > 0
+///
+/// # Input type
+///
+/// Input type is [`Decimal`]
+///
+/// # Output type
+///
+/// Output type is [`Decimal`]
+///
+/// # Examples
+///
+/// ```
+/// use yata::prelude::*;
+/// use yata::core::Decimal;
+/// use yata::methods::DecimalSMA;
+///
+/// let mut sma = DecimalSMA::new(2, Decimal::new(10, 1)).unwrap(); // 1.0
+///
+/// assert_eq!(sma.next(Decimal::new(11, 1)), Decimal::new(105, 2)); // 1.05
+/// assert_eq!(sma.next(Decimal::new(12, 1)), Decimal::new(115, 2)); // 1.15
+/// ```
+///
+/// # Performance
+///
+/// O(1)
+///
+/// # See also
+///
+/// [`SMA`](crate::methods::SMA), [`FixedSMA`](crate::methods::FixedSMA)
+///
+/// [`Decimal`]: crate::core::Decimal
+/// [`PeriodType`]: crate::core::PeriodType
+#[derive(Debug, Clone)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct DecimalSMA {
+	divider: Decimal,
+	sum: Decimal,
+	window: Window<Decimal>,
+}
+
+impl DecimalSMA {
+	/// Returns the exact sum of the last `length` values
+	#[must_use]
+	pub const fn get_sum(&self) -> Decimal {
+		self.sum
+	}
+}
+
+impl Method for DecimalSMA {
+	type Params = PeriodType;
+	type Input = Decimal;
+	type Output = Decimal;
+
+	fn new(length: Self::Params, value: Self::Input) -> Result<Self, Error> {
+		match length {
+			0 => Err(Error::WrongMethodParameters),
+			length => Ok(Self {
+				divider: Decimal::from(length),
+				sum: value * Decimal::from(length),
+				window: Window::new(length, value),
+			}),
+		}
+	}
+
+	#[inline]
+	fn next(&mut self, value: Self::Input) -> Self::Output {
+		let prev_value = self.window.push(value);
+		self.sum += value - prev_value;
+
+		self.sum / self.divider
+	}
+
+	fn reset(&mut self, value: Self::Input) -> Result<(), Error> {
+		self.sum = value * self.divider;
+		self.window.fill(value);
+
+		Ok(())
+	}
+}
+
+/// [Accumulation Distribution Index](crate::methods::ADI) of specified `length` for candles of [`Decimal`] values
+///
+/// The accumulated value is kept exactly, so there is no drift even over the very long timeseries.
+/// Only the [`CLV`](crate::core::OHLC::clv) factor of every candle is rounded by the division to 28 significant digits.
+///
+/// # Parameters
+///
+/// Has a single parameter `length`: [`PeriodType`]
+///
+/// When `length` == 0, ADI becomes windowless, which is the classic A/D line.
+///
+/// When `length` > 0, ADI will be calculated over the last `length` values.
+///
+/// # Input type
+///
+/// Input type is (`high`, `low`, `close`, `volume`) of [`Decimal`]
+///
+/// `high` should be >= `low`
+///
+/// # Output type
+///
+/// Output type is [`Decimal`]
+///
+/// # Examples
+///
+/// ```
+/// use yata::prelude::*;
+/// use yata::core::Decimal;
+/// use yata::methods::DecimalADI;
+///
+/// let candle = |high, low, close| (Decimal::from(high), Decimal::from(low), Decimal::from(close), Decimal::from(10));
+/// let mut adi = DecimalADI::new(0, candle(2, 1, 1)).unwrap();
+///
+/// assert_eq!(adi.next(candle(4, 2, 4)), Decimal::from(10));
+/// assert_eq!(adi.next(candle(4, 2, 3)), Decimal::from(10));
+/// assert_eq!(adi.next(candle(4, 2, 2)), Decimal::from(0));
+///
+/// assert!(DecimalADI::new(0, candle(1, 2, 1)).is_err());
+/// ```
+///
+/// # Performance
+///
+/// O(1)
+///
+/// # See also
+///
+/// [`ADI`](crate::methods::ADI)
+///
+/// [`Decimal`]: crate::core::Decimal
+/// [`PeriodType`]: crate::core::PeriodType
+#[derive(Debug, Clone)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct DecimalADI {
+	length: PeriodType,
+	cmf_sum: Decimal,
+	window: Window<Decimal>,
+}
+
+impl DecimalADI {
+	/// Returns last calculated value
+	#[must_use]
+	pub const fn get_value(&self) -> Decimal {
+		self.cmf_sum
+	}
+
+	fn clvv((high, low, close, volume): (Decimal, Decimal, Decimal, Decimal)) -> Decimal {
+		if high == low {
+			Decimal::ZERO
+		} else {
+			((close - low) - (high - close)) / (high - low) * volume
+		}
+	}
+}
+
+impl Method for DecimalADI {
+	type Params = PeriodType;
+	type Input = (Decimal, Decimal, Decimal, Decimal);
+	type Output = Decimal;
+
+	fn new(length: Self::Params, candle: Self::Input) -> Result<Self, Error> {
+		let (high, low, ..) = candle;
+		if high < low {
+			return Err(Error::InvalidCandles);
+		}
+
+		if length == 0 {
+			return Ok(Self {
+				length,
+				cmf_sum: Decimal::ZERO,
+				window: Window::empty(),
+			});
+		}
+
+		let clvv = Self::clvv(candle);
+
+		Ok(Self {
+			length,
+			cmf_sum: clvv * Decimal::from(length),
+			window: Window::new(length, clvv),
+		})
+	}
+
+	#[inline]
+	fn next(&mut self, candle: Self::Input) -> Self::Output {
+		let clvv = Self::clvv(candle);
+		self.cmf_sum += clvv;
+
+		if self.length > 0 {
+			self.cmf_sum -= self.window.push(clvv);
+		}
+
+		self.cmf_sum
+	}
+
+	fn reset(&mut self, candle: Self::Input) -> Result<(), Error> {
+		let (high, low, ..) = candle;
+		if high < low {
+			return Err(Error::InvalidCandles);
+		}
+
+		if self.length > 0 {
+			let clvv = Self::clvv(candle);
+			self.cmf_sum = clvv * Decimal::from(self.length);
+			self.window.fill(clvv);
+		} else {
+			self.cmf_sum = Decimal::ZERO;
+		}
+
+		Ok(())
+	}
+}
+
+/// [On Balance Volume](https://en.wikipedia.org/wiki/On-balance_volume) of specified `length` for timeseries of [`Decimal`] values
+///
+/// Adds `volume` when `close` rises, subtracts it when `close` falls and keeps the value when `close` does not change.
+/// All the operations are exact, so there is no drift over time.
+///
+/// # Parameters
+///
+/// Has a single parameter `length`: [`PeriodType`]
+///
+/// When `length` == 0, OBV becomes windowless, which is the classic OBV line.
+///
+/// When `length` > 0, OBV will be calculated over the last `length` values.
+///
+/// # Input type
+///
+/// Input type is (`close`, `volume`) of [`Decimal`]
+///
+/// # Output type
+///
+/// Output type is [`Decimal`]
+///
+/// # Examples
+///
+/// ```
+/// use yata::prelude::*;
+/// use yata::core::Decimal;
+/// use yata::methods::DecimalOBV;
+///
+/// let mut obv = DecimalOBV::new(0, (Decimal::from(10), Decimal::from(5))).unwrap();
+///
+/// assert_eq!(obv.next((Decimal::from(11), Decimal::from(3))), Decimal::from(3));
+/// assert_eq!(obv.next((Decimal::from(11), Decimal::from(7))), Decimal::from(3));
+/// assert_eq!(obv.next((Decimal::from(9), Decimal::from(4))), Decimal::from(-1));
+/// ```
+///
+/// # Performance
+///
+/// O(1)
+///
+/// # See also
+///
+/// [`DecimalADI`]
+///
+/// [`Decimal`]: crate::core::Decimal
+/// [`PeriodType`]: crate::core::PeriodType
+#[derive(Debug, Clone)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct DecimalOBV {
+	length: PeriodType,
+	value: Decimal,
+	prev_close: Decimal,
+	window: Window<Decimal>,
+}
+
+impl DecimalOBV {
+	/// Returns last calculated value
+	#[must_use]
+	pub const fn get_value(&self) -> Decimal {
+		self.value
+	}
+}
+
+impl Method for DecimalOBV {
+	type Params = PeriodType;
+	type Input = (Decimal, Decimal);
+	type Output = Decimal;
+
+	fn new(length: Self::Params, (close, _): Self::Input) -> Result<Self, Error> {
+		let window = if length > 0 {
+			Window::new(length, Decimal::ZERO)
+		} else {
+			Window::empty()
+		};
+
+		Ok(Self {
+			length,
+			value: Decimal::ZERO,
+			prev_close: close,
+			window,
+		})
+	}
+
+	#[inline]
+	fn next(&mut self, (close, volume): Self::Input) -> Self::Output {
+		let delta = match close.cmp(&self.prev_close) {
+			Ordering::Greater => volume,
+			Ordering::Less => -volume,
+			Ordering::Equal => Decimal::ZERO,
+		};
+
+		self.prev_close = close;
+		self.value += delta;
+
+		if self.length > 0 {
+			self.value -= self.window.push(delta);
+		}
+
+		self.value
+	}
+
+	fn reset(&mut self, (close, _): Self::Input) -> Result<(), Error> {
+		self.value = Decimal::ZERO;
+		self.prev_close = close;
+		self.window.fill(Decimal::ZERO);
+
+		Ok(())
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	use super::{DecimalADI, DecimalOBV, DecimalSMA};
+	use crate::core::{Candle, Decimal, Method, ValueType, OHLCV};
+	use crate::helpers::{assert_eq_float, RandomCandles};
+	use crate::methods::tests::test_const;
+	use crate::methods::{ADI, SMA};
+	use rust_decimal::prelude::{FromPrimitive, ToPrimitive};
+
+	fn to_decimal(value: ValueType) -> Decimal {
+		Decimal::from_f64(f64::from(value)).unwrap().round_dp(6)
+	}
+
+	#[allow(trivial_numeric_casts, clippy::cast_possible_truncation)]
+	fn to_value(value: Decimal) -> ValueType {
+		value.to_f64().unwrap() as ValueType
+	}
+
+	#[test]
+	fn test_decimal_const() {
+		for i in 1..255 {
+			let input = Decimal::from(i) - Decimal::new(7, 3);
+			let candle = (input, input, input, input);
+
+			test_const(&mut DecimalSMA::new(i, input).unwrap(), input, input);
+			test_const(
+				&mut DecimalADI::new(i, candle).unwrap(),
+				candle,
+				Decimal::ZERO,
+			);
+			test_const(
+				&mut DecimalOBV::new(i, (input, input)).unwrap(),
+				(input, input),
+				Decimal::ZERO,
+			);
+		}
+	}
+
+	#[test]
+	fn test_decimal() {
+		let candles: Vec<Candle> = RandomCandles::new()
+			.take(300)
+			.map(|c| Candle {
+				open: to_value(to_decimal(c.open)),
+				high: to_value(to_decimal(c.high)),
+				low: to_value(to_decimal(c.low)),
+				close: to_value(to_decimal(c.close)),
+				volume: to_value(to_decimal(c.volume)),
+			})
+			.collect();
+		let decimals: Vec<(Decimal, Decimal, Decimal, Decimal)> = candles
+			.iter()
+			.map(|c| {
+				(
+					to_decimal(c.high),
+					to_decimal(c.low),
+					to_decimal(c.close),
+					to_decimal(c.volume),
+				)
+			})
+			.collect();
+
+		for length in [0, 1, 2, 3, 10, 50, 254] {
+			let mut adi = DecimalADI::new(length, decimals[0]).unwrap();
+			let mut obv = DecimalOBV::new(length, (decimals[0].2, decimals[0].3)).unwrap();
+			let mut adi_float = ADI::new(length, candles[0]).unwrap();
+			let mut sma = DecimalSMA::new(length.max(1), decimals[0].2).unwrap();
+			let mut sma_float = SMA::new(length.max(1), candles[0].close).unwrap();
+
+			let mut obv_float = 0.0;
+			let mut deltas = Vec::new();
+			let mut prev_close = candles[0].close;
+
+			for (&candle, &decimal) in candles.iter().zip(&decimals) {
+				assert_eq_float(adi_float.next(candle), to_value(adi.next(decimal)));
+				assert_eq_float(sma_float.next(candle.close), to_value(sma.next(decimal.2)));
+
+				let delta = if candle.close > prev_close {
+					candle.volume()
+				} else if candle.close < prev_close {
+					-candle.volume()
+				} else {
+					0.0
+				};
+				prev_close = candle.close;
+				deltas.push(delta);
+				obv_float += delta;
+				if length > 0 && deltas.len() > length as usize {
+					obv_float -= deltas[deltas.len() - 1 - length as usize];
+				}
+
+				assert_eq_float(obv_float, to_value(obv.next((decimal.2, decimal.3))));
+			}
+		}
+
+		assert!(DecimalSMA::new(0, Decimal::ONE).is_err());
+	}
+
+	#[test]
+	fn test_decimal_no_drift() {
+		let tenth = Decimal::new(1, 1);
+		let mut sma = DecimalSMA::new(3, Decimal::ZERO).unwrap();
+		let mut obv = DecimalOBV::new(0, (Decimal::ZERO, Decimal::ZERO)).unwrap();
+
+		for i in 1..=100_000 {
+			sma.next(tenth * Decimal::from(i % 7));
+			obv.next((Decimal::from(i), tenth));
+		}
+
+		for _ in 0..3 {
+			sma.next(tenth);
+		}
+		assert_eq!(sma.get_sum(), Decimal::new(3, 1));
+		assert_eq!(sma.next(tenth), tenth);
+		assert_eq!(obv.get_value(), Decimal::from(10_000));
+	}
+}
@@ -1,5 +1,7 @@
 use crate::core::Method;
 use crate::core::{Error, PeriodType, ValueType, Window};
+use crate::methods::EMA;
+use alloc::vec::Vec;
 
 #[cfg(feature = "serde")]
 use serde::{Deserialize, Serialize};
@@ -14,6 +16,9 @@ use serde::{Deserialize, Serialize};
 ///
 /// Default is 1
 ///
+/// Use [`Derivative::higher_order`] to evaluate the derivative of a higher `order` (f.e. acceleration)
+/// and to smooth the input by [`EMA`] before the differentiation.
+///
 /// # Input type
 ///
 /// Input type is [`ValueType`]
@@ -39,6 +44,18 @@ use serde::{Deserialize, Serialize};
 /// });
 /// ```
 ///
+/// ```
+/// use yata::prelude::*;
+/// use yata::core::ValueType;
+/// use yata::methods::Derivative;
+///
+/// // acceleration of the quadratic function is constant
+/// let mut acceleration = Derivative::higher_order(1, 2, None, 0.0).unwrap();
+///
+/// let values: Vec<_> = (1..10).map(|x| acceleration.next((x * x) as ValueType)).collect();
+/// assert!(values[1..].iter().all(|&x| x == 2.0));
+/// ```
+///
 /// # Performance
 ///
 /// O(1)
@@ -53,33 +70,88 @@ use serde::{Deserialize, Serialize};
 #[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 pub struct Derivative {
 	divider: ValueType,
-	window: Window<ValueType>,
+	windows: Vec<Window<ValueType>>,
+	smoothing: Option<EMA>,
 	initialized: bool,
 }
 
 /// Just an alias for Derivative
 pub type Differential = Derivative;
 
+impl Derivative {
+	/// Creates a new `Derivative` of the `order` over the window `length`
+	///
+	/// If `smoothing` length is set, the input is smoothed by [`EMA`] of this length first.
+	///
+	/// Every next order is evaluated over the previous one, like a chain of `order` derivatives,
+	/// but the inner derivatives are initialized by zero, so a constant input results in zero output from the very beginning.
+	///
+	/// `order` should be > 0.
+	pub fn higher_order(
+		length: PeriodType,
+		order: u8,
+		smoothing: Option<PeriodType>,
+		value: ValueType,
+	) -> Result<Self, Error> {
+		if length == 0 || order == 0 {
+			return Err(Error::WrongMethodParameters);
+		}
+
+		let smoothing = smoothing
+			.map(|smoothing| EMA::new(smoothing, value))
+			.transpose()?;
+
+		let windows = (0..order)
+			.map(|i| Window::new(length, if i == 0 { value } else { 0.0 }))
+			.collect();
+
+		Ok(Self {
+			divider: (length as ValueType).recip(),
+			windows,
+			smoothing,
+			initialized: false,
+		})
+	}
+
+	/// Returns the order of the derivative
+	#[must_use]
+	pub const fn order(&self) -> usize {
+		self.windows.len()
+	}
+}
+
 impl Method for Derivative {
 	type Params = PeriodType;
 	type Input = ValueType;
 	type Output = Self::Input;
 
 	fn new(length: Self::Params, value: Self::Input) -> Result<Self, Error> {
-		match length {
-			0 => Err(Error::WrongMethodParameters),
-			length => Ok(Self {
-				divider: (length as ValueType).recip(),
-				window: Window::new(length, value),
-				initialized: false,
-			}),
-		}
+		Self::higher_order(length, 1, None, value)
 	}
 
 	#[inline]
 	fn next(&mut self, value: Self::Input) -> Self::Output {
-		let prev_value = self.window.push(value);
-		(value - prev_value) * self.divider
+		let mut value = self.smoothing.as_mut().map_or(value, |ema| ema.next(value));
+
+		for window in &mut self.windows {
+			let prev_value = window.push(value);
+			value = (value - prev_value) * self.divider;
+		}
+
+		value
+	}
+
+	fn reset(&mut self, value: Self::Input) -> Result<(), Error> {
+		if let Some(ema) = &mut self.smoothing {
+			ema.reset(value)?;
+		}
+
+		for (i, window) in self.windows.iter_mut().enumerate() {
+			window.fill(if i == 0 { value } else { 0.0 });
+		}
+		self.initialized = false;
+
+		Ok(())
 	}
 }
 
@@ -90,6 +162,8 @@ mod tests {
 	use crate::core::{Candle, ValueType};
 	use crate::helpers::{assert_eq_float, RandomCandles};
 	use crate::methods::tests::test_const;
+	use crate::methods::EMA;
+	use alloc::vec::Vec;
 
 	#[test]
 	fn test_derivative_const() {
@@ -133,4 +207,56 @@ mod tests {
 			});
 		});
 	}
+
+	#[test]
+	fn test_derivative_higher_order() {
+		let candles = RandomCandles::default();
+
+		let src: Vec<ValueType> = candles.take(300).map(|x| x.close).collect();
+
+		for length in [1, 2, 5, 20] {
+			for order in 1..4 {
+				let mut method = TestingMethod::higher_order(length, order, None, src[0]).unwrap();
+				assert_eq!(method.order(), usize::from(order));
+
+				let mut chain: Vec<_> = (0..order)
+					.map(|i| TestingMethod::new(length, if i == 0 { src[0] } else { 0.0 }).unwrap())
+					.collect();
+
+				let mut smoothed =
+					TestingMethod::higher_order(length, order, Some(5), src[0]).unwrap();
+				let mut ema = EMA::new(5, src[0]).unwrap();
+				let mut smoothed_chain = chain.clone();
+
+				for (i, &x) in src.iter().enumerate() {
+					if i == 150 {
+						method.reset(x).unwrap();
+						smoothed.reset(x).unwrap();
+						ema.reset(x).unwrap();
+						for (j, (a, b)) in chain.iter_mut().zip(&mut smoothed_chain).enumerate() {
+							let value = if j == 0 { x } else { 0.0 };
+							a.reset(value).unwrap();
+							b.reset(value).unwrap();
+						}
+					}
+
+					let expected = chain.iter_mut().fold(x, |value, d| d.next(value));
+					assert_eq_float(expected, method.next(x));
+
+					let smoothed_x = ema.next(x);
+					let expected = smoothed_chain
+						.iter_mut()
+						.fold(smoothed_x, |value, d| d.next(value));
+					assert_eq_float(expected, smoothed.next(x));
+				}
+			}
+		}
+
+		let mut method = TestingMethod::higher_order(3, 3, Some(4), 5.0).unwrap();
+		assert!((0..10).all(|_| method.next(5.0) == 0.0));
+
+		assert!(TestingMethod::higher_order(0, 1, None, 1.0).is_err());
+		assert!(TestingMethod::higher_order(1, 0, None, 1.0).is_err());
+		assert!(TestingMethod::higher_order(1, 1, Some(0), 1.0).is_err());
+	}
 }
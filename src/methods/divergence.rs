@@ -0,0 +1,300 @@
+use crate::core::Method;
+use crate::core::{Action, Error, PeriodType, ValueType, Window};
+
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
+
+#[cfg(not(feature = "std"))]
+use crate::core::Float as _;
+
+/// Searches for divergences between price and oscillator timeseries by matching swing pivots of price
+///
+/// Swing pivot is a price value which is strictly greater \(pivot high\) or strictly lower \(pivot low\)
+/// than `left` values before it and `right` values after it. Pivot is confirmed only after `right` values are received.
+///
+/// Every newly confirmed pivot is matched with the previous pivot of the same kind, if it is not older than `lookback` values:
+///
+/// * regular bullish divergence: price makes lower low, oscillator makes higher low;
+/// * hidden bullish divergence: price makes higher low, oscillator makes lower low;
+/// * regular bearish divergence: price makes higher high, oscillator makes lower high;
+/// * hidden bearish divergence: price makes lower high, oscillator makes higher high.
+///
+/// Value `a` is considered higher than value `b` only when `a` > `b` + `tolerance`·|`b`|
+/// \(and lower only when `a` < `b` − `tolerance`·|`b`|\).
+///
+/// # Parameters
+///
+/// Has a tuple of 4 parameters (`left`: [`PeriodType`], `right`: [`PeriodType`], `lookback`: [`PeriodType`], `tolerance`: [`ValueType`])
+///
+/// `left` should be > 0, `right` should be > 0, `lookback` should be > 0 and `tolerance` should be >= 0.0
+///
+/// There is an additional restriction on parameters: `left`+`right`+1 should be < [`PeriodType::MAX`](crate::core::PeriodType).
+///
+/// # Input type
+///
+/// Input type is a tuple of (`price`: [`ValueType`], `oscillator`: [`ValueType`])
+///
+/// # Output type
+///
+/// Output type is a tuple of (`regular`: [`Action`], `hidden`: [`Action`])
+///
+/// Bullish divergence returns full buy signal, bearish divergence returns full sell signal.
+///
+/// # Examples
+///
+/// ```
+/// use yata::prelude::*;
+/// use yata::methods::Divergence;
+///
+/// let price = [5.0, 3.0, 5.0, 2.0, 5.0];
+/// let osc   = [5.0, 1.0, 5.0, 2.0, 5.0];
+///
+/// let mut divergence = Divergence::new((1, 1, 10, 0.0), (price[0], osc[0])).unwrap();
+/// let r: Vec<_> = price.iter().zip(osc.iter()).map(|(&p, &o)| divergence.next((p, o)).0.analog()).collect();
+///
+/// // regular bullish divergence is confirmed on the last value
+/// assert_eq!(r, [0, 0, 0, 0, 1]);
+/// ```
+///
+/// # Performance
+///
+/// O(`left`+`right`)
+///
+/// # See also
+///
+/// [`ReverseSignal`]
+///
+/// [`ValueType`]: crate::core::ValueType
+/// [`PeriodType`]: crate::core::PeriodType
+/// [`Action`]: crate::core::Action
+/// [`ReverseSignal`]: crate::methods::ReverseSignal
+#[derive(Debug, Clone)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct Divergence {
+	left: PeriodType,
+	lookback: usize,
+	tolerance: ValueType,
+	index: usize,
+	last_high: Option<(usize, ValueType, ValueType)>,
+	last_low: Option<(usize, ValueType, ValueType)>,
+	window: Window<(ValueType, ValueType)>,
+}
+
+impl Divergence {
+	fn higher(&self, a: ValueType, b: ValueType) -> bool {
+		a > b.abs().mul_add(self.tolerance, b)
+	}
+
+	fn lower(&self, a: ValueType, b: ValueType) -> bool {
+		a < b.abs().mul_add(-self.tolerance, b)
+	}
+
+	/// Matches new pivot with the `last` one and returns (`regular`, `hidden`) divergence flags
+	fn matches(
+		&self,
+		last: Option<(usize, ValueType, ValueType)>,
+		pivot: (usize, ValueType, ValueType),
+		is_high: bool,
+	) -> (bool, bool) {
+		let (index, price, osc) = pivot;
+
+		match last {
+			Some((last_index, last_price, last_osc)) if index - last_index <= self.lookback => {
+				let (price_up, price_down) = (
+					self.higher(price, last_price),
+					self.lower(price, last_price),
+				);
+				let (osc_up, osc_down) = (self.higher(osc, last_osc), self.lower(osc, last_osc));
+
+				if is_high {
+					(price_up && osc_down, price_down && osc_up)
+				} else {
+					(price_down && osc_up, price_up && osc_down)
+				}
+			}
+			_ => (false, false),
+		}
+	}
+}
+
+impl Method for Divergence {
+	type Params = (PeriodType, PeriodType, PeriodType, ValueType);
+	type Input = (ValueType, ValueType);
+	type Output = (Action, Action);
+
+	fn new(params: Self::Params, value: Self::Input) -> Result<Self, Error> {
+		let (left, right, lookback, tolerance) = params;
+
+		if left == 0
+			|| right == 0
+			|| lookback == 0
+			|| tolerance < 0.
+			|| left.saturating_add(right) >= PeriodType::MAX - 1
+		{
+			return Err(Error::WrongMethodParameters);
+		}
+
+		Ok(Self {
+			left,
+			lookback: lookback as usize,
+			tolerance,
+			index: 0,
+			last_high: None,
+			last_low: None,
+			window: Window::new(left + right + 1, value),
+		})
+	}
+
+	#[inline]
+	fn next(&mut self, value: Self::Input) -> Self::Output {
+		self.window.push(value);
+		self.index += 1;
+
+		if self.index < self.window.len() as usize {
+			return (Action::None, Action::None);
+		}
+
+		let (price, osc) = self.window[self.left];
+		let (is_high, is_low) = self
+			.window
+			.iter()
+			.enumerate()
+			.filter(|&(i, _)| i != self.left as usize)
+			.fold((true, true), |(is_high, is_low), (_, (p, _))| {
+				(is_high && p < price, is_low && p > price)
+			});
+
+		let pivot = (
+			self.index - self.window.len() as usize + self.left as usize,
+			price,
+			osc,
+		);
+
+		let (mut regular, mut hidden) = (0, 0);
+
+		if is_high {
+			let (r, h) = self.matches(self.last_high, pivot, true);
+			regular -= r as i8;
+			hidden -= h as i8;
+			self.last_high = Some(pivot);
+		}
+
+		if is_low {
+			let (r, h) = self.matches(self.last_low, pivot, false);
+			regular += r as i8;
+			hidden += h as i8;
+			self.last_low = Some(pivot);
+		}
+
+		(Action::from(regular), Action::from(hidden))
+	}
+
+	fn reset(&mut self, value: Self::Input) -> Result<(), Error> {
+		self.index = 0;
+		self.last_high = None;
+		self.last_low = None;
+		self.window.fill(value);
+
+		Ok(())
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	use super::{Divergence as TestingMethod, Method};
+	use crate::core::{PeriodType, ValueType};
+	use crate::helpers::RandomCandles;
+	use alloc::vec::Vec;
+
+	fn run(
+		params: (PeriodType, PeriodType, PeriodType, ValueType),
+		price: &[ValueType],
+		osc: &[ValueType],
+	) -> Vec<(i8, i8)> {
+		let mut method = TestingMethod::new(params, (price[0], osc[0])).unwrap();
+
+		price
+			.iter()
+			.zip(osc.iter())
+			.map(|(&p, &o)| {
+				let (regular, hidden) = method.next((p, o));
+				(regular.analog(), hidden.analog())
+			})
+			.collect()
+	}
+
+	#[test]
+	fn test_divergence_wrong_params() {
+		assert!(TestingMethod::new((0, 1, 10, 0.0), (1.0, 1.0)).is_err());
+		assert!(TestingMethod::new((1, 0, 10, 0.0), (1.0, 1.0)).is_err());
+		assert!(TestingMethod::new((1, 1, 0, 0.0), (1.0, 1.0)).is_err());
+		assert!(TestingMethod::new((1, 1, 10, -0.1), (1.0, 1.0)).is_err());
+	}
+
+	#[test]
+	fn test_divergence_const() {
+		let mut method = TestingMethod::new((2, 2, 20, 0.0), (1.0, 1.0)).unwrap();
+
+		for _ in 0..100 {
+			let (regular, hidden) = method.next((1.0, 1.0));
+			assert_eq!(regular.analog(), 0);
+			assert_eq!(hidden.analog(), 0);
+		}
+	}
+
+	#[test]
+	fn test_divergence_regular() {
+		let price = [5.0, 3.0, 5.0, 2.0, 5.0, 6.0, 5.0, 7.0, 5.0];
+		let osc = [5.0, 1.0, 5.0, 2.0, 5.0, 6.0, 5.0, 4.0, 5.0];
+
+		let r = run((1, 1, 10, 0.0), &price, &osc);
+
+		assert_eq!(r[4], (1, 0));
+		assert_eq!(r[8], (-1, 0));
+		assert_eq!(r.iter().filter(|&&x| x != (0, 0)).count(), 2);
+	}
+
+	#[test]
+	fn test_divergence_hidden() {
+		let price = [5.0, 2.0, 5.0, 3.0, 5.0, 7.0, 5.0, 6.0, 5.0];
+		let osc = [5.0, 2.0, 5.0, 1.0, 5.0, 6.0, 3.0, 7.0, 5.0];
+
+		let r = run((1, 1, 10, 0.0), &price, &osc);
+
+		assert_eq!(r[4], (0, 1));
+		assert_eq!(r[8], (0, -1));
+		assert_eq!(r.iter().filter(|&&x| x != (0, 0)).count(), 2);
+	}
+
+	#[test]
+	fn test_divergence_lookback() {
+		let price = [5.0, 3.0, 5.0, 5.5, 5.0, 2.0, 5.0];
+		let osc = [5.0, 1.0, 5.0, 5.0, 5.0, 2.0, 5.0];
+
+		// pivot lows are 4 values apart
+		assert_eq!(run((1, 1, 4, 0.0), &price, &osc)[6], (1, 0));
+		assert_eq!(run((1, 1, 3, 0.0), &price, &osc)[6], (0, 0));
+	}
+
+	#[test]
+	fn test_divergence_tolerance() {
+		let price = [5.0, 3.0, 5.0, 2.9, 5.0];
+		let osc = [5.0, 1.0, 5.0, 2.0, 5.0];
+
+		assert_eq!(run((1, 1, 10, 0.01), &price, &osc)[4], (1, 0));
+		assert_eq!(run((1, 1, 10, 0.05), &price, &osc)[4], (0, 0));
+	}
+
+	#[test]
+	fn test_divergence_random() {
+		let candles = RandomCandles::default();
+		let mut method = TestingMethod::new((2, 2, 30, 0.0), (1.0, 1.0)).unwrap();
+
+		// price and oscillator moving in the same direction never diverge
+		candles.take(1000).for_each(|x| {
+			let (regular, hidden) = method.next((x.close, x.close * 2.0));
+			assert_eq!(regular.analog(), 0);
+			assert_eq!(hidden.analog(), 0);
+		});
+	}
+}
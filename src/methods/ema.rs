@@ -1,9 +1,13 @@
 use crate::core::Method;
 use crate::core::{Error, PeriodType, ValueType};
+use alloc::vec::Vec;
 
 #[cfg(feature = "serde")]
 use serde::{Deserialize, Serialize};
 
+#[cfg(not(feature = "std"))]
+use crate::core::Float as _;
+
 /// [Exponential Moving Average](https://en.wikipedia.org/wiki/Moving_average#Exponential_moving_average) of specified `length` for timeseries of type [`ValueType`]
 ///
 /// # Parameters
@@ -73,6 +77,29 @@ impl Method for EMA {
 
 		self.value
 	}
+
+	#[inline]
+	fn peek(&self, value: Self::Input) -> Self::Output {
+		(value - self.value).mul_add(self.alpha, self.value)
+	}
+
+	fn reset(&mut self, value: Self::Input) -> Result<(), Error> {
+		self.value = value;
+
+		Ok(())
+	}
+
+	fn save_undo(&self, stack: &mut Vec<ValueType>) -> bool {
+		stack.push(self.value);
+
+		true
+	}
+
+	fn restore_undo(&mut self, stack: &mut Vec<ValueType>) {
+		if let Some(value) = stack.pop() {
+			self.value = value;
+		}
+	}
 }
 
 /// Simple shortcut for [EMA] over [EMA]
@@ -106,6 +133,25 @@ impl Method for DMA {
 	fn next(&mut self, value: Self::Input) -> Self::Output {
 		self.dma.next(self.ema.next(value))
 	}
+
+	#[inline]
+	fn peek(&self, value: Self::Input) -> Self::Output {
+		self.dma.peek(self.ema.peek(value))
+	}
+
+	fn reset(&mut self, value: Self::Input) -> Result<(), Error> {
+		self.ema.reset(value)?;
+		self.dma.reset(value)
+	}
+
+	fn save_undo(&self, stack: &mut Vec<ValueType>) -> bool {
+		self.ema.save_undo(stack) && self.dma.save_undo(stack)
+	}
+
+	fn restore_undo(&mut self, stack: &mut Vec<ValueType>) {
+		self.dma.restore_undo(stack);
+		self.ema.restore_undo(stack);
+	}
 }
 
 /// Simple shortcut for [EMA] over [EMA] over [EMA] (or [EMA] over [DMA], or [DMA] over [EMA])
@@ -139,6 +185,25 @@ impl Method for TMA {
 	fn next(&mut self, value: Self::Input) -> Self::Output {
 		self.tma.next(self.dma.next(value))
 	}
+
+	#[inline]
+	fn peek(&self, value: Self::Input) -> Self::Output {
+		self.tma.peek(self.dma.peek(value))
+	}
+
+	fn reset(&mut self, value: Self::Input) -> Result<(), Error> {
+		self.dma.reset(value)?;
+		self.tma.reset(value)
+	}
+
+	fn save_undo(&self, stack: &mut Vec<ValueType>) -> bool {
+		self.dma.save_undo(stack) && self.tma.save_undo(stack)
+	}
+
+	fn restore_undo(&mut self, stack: &mut Vec<ValueType>) {
+		self.tma.restore_undo(stack);
+		self.dma.restore_undo(stack);
+	}
 }
 
 /// [Double Exponential Moving Average](https://en.wikipedia.org/wiki/Double_exponential_moving_average) of specified `length` for timeseries of type [`ValueType`]
@@ -213,6 +278,28 @@ impl Method for DEMA {
 		// 2. * ema - dma
 		e_ma.mul_add(2., -d_ma)
 	}
+
+	#[inline]
+	fn peek(&self, value: Self::Input) -> Self::Output {
+		let e_ma = self.ema.peek(value);
+		let d_ma = self.dma.peek(e_ma);
+
+		e_ma.mul_add(2., -d_ma)
+	}
+
+	fn reset(&mut self, value: Self::Input) -> Result<(), Error> {
+		self.ema.reset(value)?;
+		self.dma.reset(value)
+	}
+
+	fn save_undo(&self, stack: &mut Vec<ValueType>) -> bool {
+		self.ema.save_undo(stack) && self.dma.save_undo(stack)
+	}
+
+	fn restore_undo(&mut self, stack: &mut Vec<ValueType>) {
+		self.dma.restore_undo(stack);
+		self.ema.restore_undo(stack);
+	}
 }
 
 /// [Triple Exponential Moving Average](https://en.wikipedia.org/wiki/Triple_exponential_moving_average) of specified `length` for timeseries of type [`ValueType`]
@@ -290,6 +377,31 @@ impl Method for TEMA {
 		// 3. * (ema - dma) + tma
 		(e_ma - d_ma).mul_add(3., t_ma)
 	}
+
+	#[inline]
+	fn peek(&self, value: Self::Input) -> Self::Output {
+		let e_ma = self.ema.peek(value);
+		let d_ma = self.dma.peek(e_ma);
+		let t_ma = self.tma.peek(d_ma);
+
+		(e_ma - d_ma).mul_add(3., t_ma)
+	}
+
+	fn reset(&mut self, value: Self::Input) -> Result<(), Error> {
+		self.ema.reset(value)?;
+		self.dma.reset(value)?;
+		self.tma.reset(value)
+	}
+
+	fn save_undo(&self, stack: &mut Vec<ValueType>) -> bool {
+		self.ema.save_undo(stack) && self.dma.save_undo(stack) && self.tma.save_undo(stack)
+	}
+
+	fn restore_undo(&mut self, stack: &mut Vec<ValueType>) {
+		self.tma.restore_undo(stack);
+		self.dma.restore_undo(stack);
+		self.ema.restore_undo(stack);
+	}
 }
 
 #[cfg(test)]
@@ -300,6 +412,7 @@ mod tests {
 	use crate::core::{Method, ValueType};
 	use crate::helpers::{assert_eq_float, RandomCandles};
 	use crate::methods::tests::test_const_float;
+	use alloc::vec::Vec;
 
 	#[test]
 	fn test_ema_const() {
@@ -0,0 +1,288 @@
+use super::Conv;
+use crate::core::{Error, PeriodType, ValueType};
+use alloc::vec::Vec;
+
+#[cfg(feature = "value_type_f32")]
+use core::f32::consts::PI;
+#[cfg(not(feature = "value_type_f32"))]
+use core::f64::consts::PI;
+
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
+
+#[cfg(not(feature = "std"))]
+use crate::core::Float as _;
+
+/// [Window function](https://en.wikipedia.org/wiki/Window_function) for the windowed-sinc filter design
+///
+/// Wider main lobe gives a smoother transition band, lower side lobes give a better stopband attenuation.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[cfg_attr(feature = "serde", serde(rename_all = "lowercase"))]
+pub enum FirWindow {
+	/// No windowing. The sharpest transition, but the worst stopband attenuation (~21 dB)
+	Rectangular,
+
+	/// Hann window (~44 dB)
+	Hann,
+
+	/// Hamming window (~53 dB). It is the default one
+	#[default]
+	Hamming,
+
+	/// Blackman window. The widest transition, but the best stopband attenuation (~74 dB)
+	Blackman,
+}
+
+impl FirWindow {
+	/// Returns symmetric window coefficients of the given `length`
+	#[must_use]
+	pub fn coefficients(self, length: PeriodType) -> Vec<ValueType> {
+		let m = (length.saturating_sub(1) as ValueType).max(1.0);
+
+		(0..length)
+			.map(|n| {
+				let phase = 2.0 * PI * n as ValueType / m;
+
+				match self {
+					Self::Rectangular => 1.0,
+					Self::Hann => phase.cos().mul_add(-0.5, 0.5),
+					Self::Hamming => phase.cos().mul_add(-0.46, 0.54),
+					Self::Blackman => (2.0 * phase)
+						.cos()
+						.mul_add(0.08, phase.cos().mul_add(-0.5, 0.42)),
+				}
+			})
+			.collect()
+	}
+}
+
+/// Frequency response of the windowed-sinc [FIR filter](https://en.wikipedia.org/wiki/Finite_impulse_response)
+///
+/// Cutoffs are set as periods of cycles in bars, so f.e. `LowPass(20.0)` suppresses cycles shorter than 20 bars.
+/// Every period must be greater than `2.0` (the Nyquist period).
+///
+/// Sharpness of the filter grows with the length of the filter, but so does its lag, which is `(length - 1) / 2` bars.
+///
+/// # Examples
+///
+/// ```
+/// use yata::core::ValueType;
+/// use yata::methods::{Conv, FirFilter, FirWindow};
+/// use yata::prelude::*;
+///
+/// let mut low_pass = Conv::fir(FirFilter::LowPass(10.0), 31, FirWindow::Blackman, 0.0).unwrap();
+///
+/// // cycle of 2 bars is suppressed
+/// let output = low_pass.over((0..100).map(|i| if i % 2 == 0 { 1.0 } else { -1.0 }));
+/// assert!(output.last().unwrap().abs() < 1e-3);
+///
+/// let taps = FirFilter::HighPass(10.0).taps(31, FirWindow::Blackman).unwrap();
+/// assert!(taps.iter().sum::<ValueType>().abs() < 1e-5);
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub enum FirFilter {
+	/// Passes cycles longer than the period
+	LowPass(ValueType),
+
+	/// Passes cycles shorter than the period
+	///
+	/// Requires an odd length of the filter.
+	HighPass(ValueType),
+
+	/// Passes cycles between the shorter and the longer periods
+	BandPass(ValueType, ValueType),
+}
+
+impl FirFilter {
+	/// Evaluates `length` filter taps with the `window`
+	///
+	/// Returns [`Error::WrongMethodParameters`] if `length` < 2, any period is not greater than `2.0`,
+	/// the band periods are not in increasing order or the high-pass filter length is even.
+	pub fn taps(self, length: PeriodType, window: FirWindow) -> Result<Vec<ValueType>, Error> {
+		let is_valid_period = |period: ValueType| period.is_finite() && period > 2.0;
+
+		let is_valid = length >= 2
+			&& match self {
+				Self::LowPass(period) => is_valid_period(period),
+				Self::HighPass(period) => is_valid_period(period) && length % 2 == 1,
+				Self::BandPass(short, long) => is_valid_period(short) && short < long,
+			};
+
+		if !is_valid {
+			return Err(Error::WrongMethodParameters);
+		}
+
+		let window = window.coefficients(length);
+		let taps = match self {
+			Self::LowPass(period) => low_pass(period, &window),
+			Self::HighPass(period) => {
+				let mut taps: Vec<ValueType> =
+					low_pass(period, &window).iter().map(|x| -x).collect();
+				taps[length as usize / 2] += 1.0;
+				taps
+			}
+			Self::BandPass(short, long) => low_pass(short, &window)
+				.iter()
+				.zip(low_pass(long, &window))
+				.map(|(a, b)| a - b)
+				.collect(),
+		};
+
+		Ok(taps)
+	}
+}
+
+/// Windowed-sinc low-pass taps with the unity gain at zero frequency
+fn low_pass(period: ValueType, window: &[ValueType]) -> Vec<ValueType> {
+	let frequency = period.recip();
+	let center = (window.len() - 1) as ValueType / 2.0;
+
+	let taps: Vec<ValueType> = window
+		.iter()
+		.enumerate()
+		.map(|(n, w)| {
+			let x = 2.0 * frequency * (n as ValueType - center);
+			let sinc = if x == 0.0 {
+				1.0
+			} else {
+				(PI * x).sin() / (PI * x)
+			};
+
+			sinc * w
+		})
+		.collect();
+
+	let sum: ValueType = taps.iter().sum();
+	taps.iter().map(|x| x / sum).collect()
+}
+
+impl Conv {
+	/// Creates a new `Conv` with the windowed-sinc FIR `filter` of `length` taps
+	///
+	/// See [`FirFilter`].
+	pub fn fir(
+		filter: FirFilter,
+		length: PeriodType,
+		window: FirWindow,
+		value: ValueType,
+	) -> Result<Self, Error> {
+		Self::from_taps(filter.taps(length, window)?, value)
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	use super::{FirFilter, FirWindow, PI};
+	use crate::core::{Method, ValueType};
+	use crate::helpers::assert_eq_float;
+	use crate::methods::Conv;
+
+	const WINDOWS: [FirWindow; 4] = [
+		FirWindow::Rectangular,
+		FirWindow::Hann,
+		FirWindow::Hamming,
+		FirWindow::Blackman,
+	];
+
+	/// Amplitude of the filter output over the sine wave of the `period` after the filter is filled
+	fn amplitude(filter: FirFilter, window: FirWindow, period: ValueType) -> ValueType {
+		let mut conv = Conv::fir(filter, 63, window, 0.0).unwrap();
+
+		(0..400)
+			.map(|i| conv.next((2.0 * PI * i as ValueType / period).sin()))
+			.skip(100)
+			.fold(0.0, |max: ValueType, x| max.max(x.abs()))
+	}
+
+	#[test]
+	fn test_fir_windows() {
+		let delta = if cfg!(feature = "value_type_f32") {
+			1e-6
+		} else {
+			1e-9
+		};
+
+		for window in WINDOWS {
+			let coefficients = window.coefficients(11);
+			assert_eq!(coefficients.len(), 11);
+			assert!(coefficients
+				.iter()
+				.all(|&x| (-delta..=1.0 + delta).contains(&x)));
+
+			for i in 0..5 {
+				assert_eq_float(coefficients[i], coefficients[10 - i]);
+			}
+			assert_eq_float(1.0, coefficients[5]);
+		}
+	}
+
+	#[test]
+	fn test_fir_filters() {
+		let delta = if cfg!(feature = "value_type_f32") {
+			1e-5
+		} else {
+			1e-9
+		};
+
+		for window in WINDOWS {
+			let low = FirFilter::LowPass(20.0);
+			let high = FirFilter::HighPass(20.0);
+			let band = FirFilter::BandPass(4.0, 8.0);
+
+			for filter in [low, high, band] {
+				let taps = filter.taps(63, window).unwrap();
+				for i in 0..31 {
+					assert_eq_float(taps[i], taps[62 - i]);
+				}
+			}
+
+			let tolerance = if window == FirWindow::Rectangular {
+				0.2
+			} else {
+				0.05
+			};
+
+			assert_eq_float(
+				1.0,
+				FirFilter::LowPass(20.0)
+					.taps(63, window)
+					.unwrap()
+					.iter()
+					.sum(),
+			);
+			assert!((amplitude(low, window, 60.0) - 1.0).abs() < tolerance);
+			assert!(amplitude(low, window, 5.0) < tolerance);
+
+			assert!(
+				FirFilter::HighPass(20.0)
+					.taps(63, window)
+					.unwrap()
+					.iter()
+					.sum::<ValueType>()
+					.abs() < delta
+			);
+			assert!(amplitude(high, window, 60.0) < tolerance);
+			assert!((amplitude(high, window, 5.0) - 1.0).abs() < tolerance);
+
+			assert!(amplitude(band, window, 100.0) < tolerance);
+			assert!((amplitude(band, window, 5.3) - 1.0).abs() < tolerance);
+			assert!(amplitude(band, window, 2.5) < tolerance);
+		}
+	}
+
+	#[test]
+	fn test_fir_parameters() {
+		let window = FirWindow::default();
+
+		assert!(FirFilter::LowPass(10.0).taps(1, window).is_err());
+		assert!(FirFilter::LowPass(10.0).taps(2, window).is_ok());
+		assert!(FirFilter::LowPass(2.0).taps(10, window).is_err());
+		assert!(FirFilter::LowPass(ValueType::NAN).taps(10, window).is_err());
+		assert!(FirFilter::HighPass(10.0).taps(10, window).is_err());
+		assert!(FirFilter::HighPass(10.0).taps(11, window).is_ok());
+		assert!(FirFilter::BandPass(20.0, 10.0).taps(11, window).is_err());
+		assert!(FirFilter::BandPass(10.0, 20.0).taps(10, window).is_ok());
+	}
+}
@@ -0,0 +1,560 @@
+use crate::core::Method;
+use crate::core::{Error, PeriodType, Ticks, Window};
+
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
+
+/// Count of fractional bits kept in the internal state of recursive fixed-point methods
+const FRACTION_BITS: u32 = 32;
+
+/// Divides `n` by positive `d`, rounding halfway values away from zero
+const fn div_round(n: i128, d: i128) -> i128 {
+	let (q, r) = (n / d, n % d);
+
+	if 2 * r.abs() >= d {
+		q + n.signum()
+	} else {
+		q
+	}
+}
+
+/// Narrows the averaged value back to [`Ticks`]
+///
+/// Averages never leave the range of their inputs, so nothing is truncated.
+#[allow(clippy::cast_possible_truncation)]
+const fn narrow(value: i128) -> Ticks {
+	value as Ticks
+}
+
+/// [Simple Moving Average](crate::methods::SMA) of specified `length` for timeseries of fixed-point [`Ticks`]
+///
+/// The sum is kept exactly, so there is no drift over time. The output is rounded to the nearest tick
+/// (halfway values are rounded away from zero).
+///
+/// # Parameters
+///
+/// Has a single parameter `length`: [`PeriodType`]
+///
+/// `length` should be > 0
+///
+/// # Input type
+///
+/// Input type is [`Ticks`]
+///
+/// # Output type
+///
+/// Output type is [`Ticks`]
+///
+/// # Examples
+///
+/// ```
+/// use yata::prelude::*;
+/// use yata::methods::FixedSMA;
+///
+/// let mut sma = FixedSMA::new(3, 100).unwrap();
+///
+/// assert_eq!(sma.next(101), 100);
+/// assert_eq!(sma.next(102), 101);
+/// assert_eq!(sma.next(104), 102);
+/// ```
+///
+/// # Performance
+///
+/// O(1)
+///
+/// # See also
+///
+/// [`SMA`](crate::methods::SMA), [`TickScale`](crate::core::TickScale)
+///
+/// [`Ticks`]: crate::core::Ticks
+/// [`PeriodType`]: crate::core::PeriodType
+#[derive(Debug, Clone)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct FixedSMA {
+	sum: i128,
+	window: Window<Ticks>,
+}
+
+impl FixedSMA {
+	/// Returns the exact sum of the last `length` values
+	#[must_use]
+	pub const fn sum(&self) -> i128 {
+		self.sum
+	}
+}
+
+impl Method for FixedSMA {
+	type Params = PeriodType;
+	type Input = Ticks;
+	type Output = Self::Input;
+
+	fn new(length: Self::Params, value: Self::Input) -> Result<Self, Error> {
+		match length {
+			0 => Err(Error::WrongMethodParameters),
+			length => Ok(Self {
+				sum: i128::from(value) * i128::from(length),
+				window: Window::new(length, value),
+			}),
+		}
+	}
+
+	#[inline]
+	fn next(&mut self, value: Self::Input) -> Self::Output {
+		let prev_value = self.window.push(value);
+		self.sum += i128::from(value) - i128::from(prev_value);
+
+		narrow(div_round(self.sum, i128::from(self.window.len())))
+	}
+
+	fn min_periods(&self) -> PeriodType {
+		self.window.len()
+	}
+
+	fn reset(&mut self, value: Self::Input) -> Result<(), Error> {
+		self.window.fill(value);
+		self.sum = i128::from(value) * i128::from(self.window.len());
+
+		Ok(())
+	}
+}
+
+/// [Exponential Moving Average](crate::methods::EMA) of specified `length` for timeseries of fixed-point [`Ticks`]
+///
+/// The internal state keeps 32 extra fractional bits, so rounding errors do not accumulate.
+/// The output is rounded to the nearest tick.
+///
+/// # Parameters
+///
+/// Has a single parameter `length`: [`PeriodType`]
+///
+/// `length` should be > 0
+///
+/// # Input type
+///
+/// Input type is [`Ticks`]
+///
+/// # Output type
+///
+/// Output type is [`Ticks`]
+///
+/// # Examples
+///
+/// ```
+/// use yata::prelude::*;
+/// use yata::methods::FixedEMA;
+///
+/// // alpha = 2 / (3 + 1) = 0.5
+/// let mut ema = FixedEMA::new(3, 100).unwrap();
+///
+/// assert_eq!(ema.next(110), 105);
+/// assert_eq!(ema.next(110), 108); // 107.5 is rounded up
+/// ```
+///
+/// # Performance
+///
+/// O(1)
+///
+/// # See also
+///
+/// [`EMA`](crate::methods::EMA), [`TickScale`](crate::core::TickScale)
+///
+/// [`Ticks`]: crate::core::Ticks
+/// [`PeriodType`]: crate::core::PeriodType
+#[derive(Debug, Clone, Copy)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct FixedEMA {
+	divider: i128,
+	value: i128,
+}
+
+impl Method for FixedEMA {
+	type Params = PeriodType;
+	type Input = Ticks;
+	type Output = Self::Input;
+
+	fn new(length: Self::Params, value: Self::Input) -> Result<Self, Error> {
+		match length {
+			0 => Err(Error::WrongMethodParameters),
+			length => Ok(Self {
+				divider: i128::from(length) + 1,
+				value: i128::from(value) << FRACTION_BITS,
+			}),
+		}
+	}
+
+	#[inline]
+	fn next(&mut self, value: Self::Input) -> Self::Output {
+		let delta = (i128::from(value) << FRACTION_BITS) - self.value;
+		self.value += div_round(delta * 2, self.divider);
+
+		narrow(div_round(self.value, 1 << FRACTION_BITS))
+	}
+
+	fn reset(&mut self, value: Self::Input) -> Result<(), Error> {
+		self.value = i128::from(value) << FRACTION_BITS;
+
+		Ok(())
+	}
+}
+
+/// Returns highest value over the last `length` values for timeseries of fixed-point [`Ticks`]
+///
+/// # Parameters
+///
+/// Has a single parameter `length`: [`PeriodType`]
+///
+/// `length` should be > 0
+///
+/// # Input type
+///
+/// Input type is [`Ticks`]
+///
+/// # Output type
+///
+/// Output type is [`Ticks`]
+///
+/// # Examples
+///
+/// ```
+/// use yata::prelude::*;
+/// use yata::methods::FixedHighest;
+///
+/// let values = [100, 200, 300, 200, 100, 50, 200, 300];
+/// let r      = [100, 200, 300, 300, 300, 200, 200, 300];
+///
+/// let mut highest = FixedHighest::new(3, values[0]).unwrap();
+///
+/// for (&value, &expected) in values.iter().zip(&r) {
+///     assert_eq!(highest.next(value), expected);
+/// }
+/// ```
+///
+/// # Performance
+///
+/// O(`length`)
+///
+/// # See also
+///
+/// [`Highest`](crate::methods::Highest), [`FixedLowest`]
+///
+/// [`Ticks`]: crate::core::Ticks
+/// [`PeriodType`]: crate::core::PeriodType
+#[derive(Debug, Clone)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct FixedHighest {
+	value: Ticks,
+	window: Window<Ticks>,
+}
+
+impl Method for FixedHighest {
+	type Params = PeriodType;
+	type Input = Ticks;
+	type Output = Self::Input;
+
+	fn new(length: Self::Params, value: Self::Input) -> Result<Self, Error> {
+		match length {
+			0 => Err(Error::WrongMethodParameters),
+			length => Ok(Self {
+				window: Window::new(length, value),
+				value,
+			}),
+		}
+	}
+
+	#[inline]
+	fn next(&mut self, value: Self::Input) -> Self::Output {
+		let left_value = self.window.push(value);
+
+		if value >= self.value {
+			self.value = value;
+		} else if left_value == self.value {
+			self.value = self.window.iter().fold(value, Ord::max);
+		}
+
+		self.value
+	}
+
+	fn min_periods(&self) -> PeriodType {
+		self.window.len()
+	}
+
+	fn reset(&mut self, value: Self::Input) -> Result<(), Error> {
+		self.window.fill(value);
+		self.value = value;
+
+		Ok(())
+	}
+}
+
+/// Returns lowest value over the last `length` values for timeseries of fixed-point [`Ticks`]
+///
+/// # Parameters
+///
+/// Has a single parameter `length`: [`PeriodType`]
+///
+/// `length` should be > 0
+///
+/// # Input type
+///
+/// Input type is [`Ticks`]
+///
+/// # Output type
+///
+/// Output type is [`Ticks`]
+///
+/// # Examples
+///
+/// ```
+/// use yata::prelude::*;
+/// use yata::methods::FixedLowest;
+///
+/// let values = [100, 200, 300, 200, 100, 50, 200, 300];
+/// let r      = [100, 100, 100, 200, 100, 50, 50, 50];
+///
+/// let mut lowest = FixedLowest::new(3, values[0]).unwrap();
+///
+/// for (&value, &expected) in values.iter().zip(&r) {
+///     assert_eq!(lowest.next(value), expected);
+/// }
+/// ```
+///
+/// # Performance
+///
+/// O(`length`)
+///
+/// # See also
+///
+/// [`Lowest`](crate::methods::Lowest), [`FixedHighest`]
+///
+/// [`Ticks`]: crate::core::Ticks
+/// [`PeriodType`]: crate::core::PeriodType
+#[derive(Debug, Clone)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct FixedLowest {
+	value: Ticks,
+	window: Window<Ticks>,
+}
+
+impl Method for FixedLowest {
+	type Params = PeriodType;
+	type Input = Ticks;
+	type Output = Self::Input;
+
+	fn new(length: Self::Params, value: Self::Input) -> Result<Self, Error> {
+		match length {
+			0 => Err(Error::WrongMethodParameters),
+			length => Ok(Self {
+				window: Window::new(length, value),
+				value,
+			}),
+		}
+	}
+
+	#[inline]
+	fn next(&mut self, value: Self::Input) -> Self::Output {
+		let left_value = self.window.push(value);
+
+		if value <= self.value {
+			self.value = value;
+		} else if left_value == self.value {
+			self.value = self.window.iter().fold(value, Ord::min);
+		}
+
+		self.value
+	}
+
+	fn min_periods(&self) -> PeriodType {
+		self.window.len()
+	}
+
+	fn reset(&mut self, value: Self::Input) -> Result<(), Error> {
+		self.window.fill(value);
+		self.value = value;
+
+		Ok(())
+	}
+}
+
+/// [Average True Range](https://en.wikipedia.org/wiki/Average_true_range) of specified `length` for fixed-point candles
+///
+/// True range is smoothed by the Wilder's moving average (see [`RMA`](crate::methods::RMA)).
+/// The internal state keeps 32 extra fractional bits, the output is rounded to the nearest tick.
+///
+/// # Parameters
+///
+/// Has a single parameter `length`: [`PeriodType`]
+///
+/// `length` should be > 0
+///
+/// # Input type
+///
+/// Input type is (`high`, `low`, `close`) of [`Ticks`]
+///
+/// `high` should be >= `low`
+///
+/// # Output type
+///
+/// Output type is [`Ticks`]
+///
+/// # Examples
+///
+/// ```
+/// use yata::prelude::*;
+/// use yata::methods::FixedATR;
+///
+/// let mut atr = FixedATR::new(2, (110, 100, 105)).unwrap();
+///
+/// // true range is 130 - 105 = 25 because of the gap
+/// assert_eq!(atr.next((130, 120, 125)), 18);
+/// assert_eq!(atr.next((125, 125, 125)), 9);
+///
+/// assert!(FixedATR::new(2, (100, 110, 105)).is_err());
+/// ```
+///
+/// # Performance
+///
+/// O(1)
+///
+/// # See also
+///
+/// [`OHLC::tr`](crate::core::OHLC::tr), [`TickScale`](crate::core::TickScale)
+///
+/// [`Ticks`]: crate::core::Ticks
+/// [`PeriodType`]: crate::core::PeriodType
+#[derive(Debug, Clone, Copy)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct FixedATR {
+	divider: i128,
+	value: i128,
+	prev_close: Ticks,
+}
+
+impl Method for FixedATR {
+	type Params = PeriodType;
+	type Input = (Ticks, Ticks, Ticks);
+	type Output = Ticks;
+
+	fn new(length: Self::Params, (high, low, close): Self::Input) -> Result<Self, Error> {
+		if high < low {
+			return Err(Error::InvalidCandles);
+		}
+
+		match length {
+			0 => Err(Error::WrongMethodParameters),
+			length => Ok(Self {
+				divider: i128::from(length),
+				value: (i128::from(high) - i128::from(low)) << FRACTION_BITS,
+				prev_close: close,
+			}),
+		}
+	}
+
+	#[inline]
+	fn next(&mut self, (high, low, close): Self::Input) -> Self::Output {
+		let prev_close = core::mem::replace(&mut self.prev_close, close);
+		let tr = i128::from(high.max(prev_close)) - i128::from(low.min(prev_close));
+
+		let delta = (tr << FRACTION_BITS) - self.value;
+		self.value += div_round(delta, self.divider);
+
+		narrow(div_round(self.value, 1 << FRACTION_BITS))
+	}
+
+	fn reset(&mut self, (high, low, close): Self::Input) -> Result<(), Error> {
+		if high < low {
+			return Err(Error::InvalidCandles);
+		}
+
+		self.value = (i128::from(high) - i128::from(low)) << FRACTION_BITS;
+		self.prev_close = close;
+
+		Ok(())
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	use super::{FixedATR, FixedEMA, FixedHighest, FixedLowest, FixedSMA};
+	use crate::core::{Method, TickScale, Ticks, ValueType, OHLC};
+	use crate::helpers::RandomCandles;
+	use crate::methods::tests::test_const;
+	use crate::methods::{Highest, Lowest, EMA, RMA, SMA};
+	use alloc::vec::Vec;
+
+	#[test]
+	fn test_fixed_const() {
+		for i in 1..255 {
+			let input = i as Ticks * 1000 - 7;
+
+			test_const(&mut FixedSMA::new(i, input).unwrap(), input, input);
+			test_const(&mut FixedEMA::new(i, input).unwrap(), input, input);
+			test_const(&mut FixedHighest::new(i, input).unwrap(), input, input);
+			test_const(&mut FixedLowest::new(i, input).unwrap(), input, input);
+			test_const(
+				&mut FixedATR::new(i, (input, input, input)).unwrap(),
+				(input, input, input),
+				0,
+			);
+		}
+	}
+
+	#[test]
+	fn test_fixed() {
+		let scale = TickScale::from_decimals(4).unwrap();
+		let unit = TickScale::new(1).unwrap();
+		let ticks: Vec<(Ticks, Ticks, Ticks)> = RandomCandles::new()
+			.take(300)
+			.map(|c| {
+				let (high, low, close) = (c.high(), c.low(), c.close());
+				(
+					scale.to_ticks(high),
+					scale.to_ticks(low),
+					scale.to_ticks(close),
+				)
+			})
+			.collect();
+
+		for length in [1, 2, 3, 10, 50, 254] {
+			let (high, low, close) = ticks[0];
+
+			let mut sma = FixedSMA::new(length, close).unwrap();
+			let mut ema = FixedEMA::new(length, close).unwrap();
+			let mut highest = FixedHighest::new(length, high).unwrap();
+			let mut lowest = FixedLowest::new(length, low).unwrap();
+			let mut atr = FixedATR::new(length, ticks[0]).unwrap();
+
+			let mut sma_float = SMA::new(length, close as ValueType).unwrap();
+			let mut ema_float = EMA::new(length, close as ValueType).unwrap();
+			let mut highest_float = Highest::new(length, high as ValueType).unwrap();
+			let mut lowest_float = Lowest::new(length, low as ValueType).unwrap();
+			let mut atr_float = RMA::new(length, (high - low) as ValueType).unwrap();
+
+			let mut prev_close = close;
+			for &(high, low, close) in &ticks {
+				let tr = high.max(prev_close) - low.min(prev_close);
+				prev_close = close;
+
+				let expected = unit.to_ticks(sma_float.next(close as ValueType));
+				assert!((sma.next(close) - expected).abs() <= 1);
+
+				let expected = unit.to_ticks(ema_float.next(close as ValueType));
+				assert!((ema.next(close) - expected).abs() <= 1);
+
+				let expected = unit.to_ticks(highest_float.next(high as ValueType));
+				assert_eq!(highest.next(high), expected);
+
+				let expected = unit.to_ticks(lowest_float.next(low as ValueType));
+				assert_eq!(lowest.next(low), expected);
+
+				let expected = unit.to_ticks(atr_float.next(tr as ValueType));
+				assert!((atr.next((high, low, close)) - expected).abs() <= 1);
+			}
+		}
+
+		assert!(FixedSMA::new(0, 1).is_err());
+		assert!(FixedEMA::new(0, 1).is_err());
+		assert!(FixedHighest::new(0, 1).is_err());
+		assert!(FixedLowest::new(0, 1).is_err());
+		assert!(FixedATR::new(0, (1, 1, 1)).is_err());
+	}
+}
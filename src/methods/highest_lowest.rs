@@ -116,6 +116,22 @@ impl Method for HighestLowestDelta {
 
 		self.highest - self.lowest
 	}
+
+	fn min_periods(&self) -> PeriodType {
+		self.window.len()
+	}
+
+	fn reset(&mut self, value: Self::Input) -> Result<(), Error> {
+		if !value.is_finite() {
+			return Err(Error::InvalidCandles);
+		}
+
+		self.window.fill(value);
+		self.highest = value;
+		self.lowest = value;
+
+		Ok(())
+	}
 }
 
 /// Returns highest value over the last `length` values for timeseries of type [`ValueType`]
@@ -209,6 +225,21 @@ impl Method for Highest {
 
 		self.value
 	}
+
+	fn min_periods(&self) -> PeriodType {
+		self.window.len()
+	}
+
+	fn reset(&mut self, value: Self::Input) -> Result<(), Error> {
+		if !value.is_finite() {
+			return Err(Error::InvalidCandles);
+		}
+
+		self.window.fill(value);
+		self.value = value;
+
+		Ok(())
+	}
 }
 
 /// Returns lowest value over the last `length` values for timeseries of type [`ValueType`]
@@ -302,6 +333,21 @@ impl Method for Lowest {
 
 		self.value
 	}
+
+	fn min_periods(&self) -> PeriodType {
+		self.window.len()
+	}
+
+	fn reset(&mut self, value: Self::Input) -> Result<(), Error> {
+		if !value.is_finite() {
+			return Err(Error::InvalidCandles);
+		}
+
+		self.window.fill(value);
+		self.value = value;
+
+		Ok(())
+	}
 }
 
 #[cfg(test)]
@@ -310,6 +356,7 @@ mod tests {
 	use crate::core::{Method, ValueType};
 	use crate::helpers::{assert_eq_float, RandomCandles};
 	use crate::methods::tests::test_const;
+	use alloc::vec::Vec;
 
 	#[test]
 	fn test_highest_const() {
@@ -114,6 +114,18 @@ impl Method for HighestIndex {
 
 		self.index
 	}
+
+	fn reset(&mut self, value: Self::Input) -> Result<(), Error> {
+		if !value.is_finite() {
+			return Err(Error::InvalidCandles);
+		}
+
+		self.window.fill(value);
+		self.index = 0;
+		self.value = value;
+
+		Ok(())
+	}
 }
 
 /// Returns lowest value index over the last `length` values for timeseries of type [`ValueType`]
@@ -226,6 +238,18 @@ impl Method for LowestIndex {
 
 		self.index
 	}
+
+	fn reset(&mut self, value: Self::Input) -> Result<(), Error> {
+		if !value.is_finite() {
+			return Err(Error::InvalidCandles);
+		}
+
+		self.window.fill(value);
+		self.index = 0;
+		self.value = value;
+
+		Ok(())
+	}
 }
 
 #[cfg(test)]
@@ -235,6 +259,7 @@ mod tests {
 	use crate::core::ValueType;
 	use crate::helpers::RandomCandles;
 	use crate::methods::tests::test_const;
+	use alloc::vec::Vec;
 
 	#[test]
 	fn test_highest_index_const() {
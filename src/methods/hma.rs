@@ -4,6 +4,9 @@ use crate::core::{Error, Method, PeriodType, ValueType};
 #[cfg(feature = "serde")]
 use serde::{Deserialize, Serialize};
 
+#[cfg(not(feature = "std"))]
+use crate::core::Float as _;
+
 /// [Hull Moving Average](https://www.tradingview.com/scripts/hullma/) for last `length` values for timeseries of type [`ValueType`]
 ///
 /// HMA = [`WMA`] from (2*[`WMA`] over `length`/2 − [`WMA`] over `length`) over sqrt(`length`))
@@ -83,6 +86,12 @@ impl Method for HMA {
 
 		self.wma3.next(w1.mul_add(2., -w2))
 	}
+
+	fn reset(&mut self, value: Self::Input) -> Result<(), Error> {
+		self.wma1.reset(value)?;
+		self.wma2.reset(value)?;
+		self.wma3.reset(value)
+	}
 }
 
 #[cfg(test)]
@@ -92,6 +101,7 @@ mod tests {
 	use crate::core::{PeriodType, ValueType};
 	use crate::helpers::{assert_eq_float, RandomCandles};
 	use crate::methods::tests::test_const_float;
+	use alloc::vec::Vec;
 
 	#[test]
 	fn test_hma_const() {
@@ -4,6 +4,9 @@ use crate::core::{Error, PeriodType, ValueType, Window};
 #[cfg(feature = "serde")]
 use serde::{Deserialize, Serialize};
 
+#[cfg(not(feature = "std"))]
+use crate::core::Float as _;
+
 /// Integrates (summarizes) [`ValueType`] values for the given window size `length`
 ///
 /// # Parameters
@@ -110,6 +113,13 @@ impl Method for Integral {
 
 		self.value
 	}
+
+	fn reset(&mut self, value: Self::Input) -> Result<(), Error> {
+		self.value = value * self.window.len() as ValueType;
+		self.window.fill(value);
+
+		Ok(())
+	}
 }
 
 impl Default for Integral {
@@ -118,12 +128,126 @@ impl Default for Integral {
 	}
 }
 
+/// Exponentially decaying integral (leaky accumulator) of [`ValueType`] values with the given `decay` factor
+///
+/// Every step the accumulated sum is multiplied by `decay` and the new value is added to it:
+/// `sum = sum * decay + value`.
+/// So old values fade away smoothly instead of falling off the window edge like in [`Integral`].
+///
+/// The sum is initialized as if the initial `value` was accumulating forever (`value / (1 - decay)`),
+/// so the constant input results in the constant output.
+///
+/// # Parameters
+///
+/// Has a single parameter `decay`: [`ValueType`]
+///
+/// `decay` should be in range \[`0.0`; `1.0`)
+///
+/// Use [`DecayingIntegral::with_half_life`] to set the count of bars, after which the value contribution is halved.
+///
+/// # Input type
+///
+/// Input type is [`ValueType`]
+///
+/// # Output type
+///
+/// Output type is [`ValueType`]
+///
+/// # Examples
+///
+/// ```
+/// use yata::prelude::*;
+/// use yata::methods::DecayingIntegral;
+///
+/// let mut integral = DecayingIntegral::new(0.5, 0.0).unwrap();
+///
+/// assert_eq!(integral.next(4.0), 4.0);
+/// assert_eq!(integral.next(0.0), 2.0);
+/// assert_eq!(integral.next(2.0), 3.0);
+///
+/// // the contribution of the value is halved every 10 bars
+/// let mut integral = DecayingIntegral::with_half_life(10.0, 0.0).unwrap();
+/// integral.next(1.0);
+///
+/// let values: Vec<_> = (0..10).map(|_| integral.next(0.0)).collect();
+/// assert!((values[9] - 0.5).abs() < 1e-5);
+/// ```
+///
+/// # Performance
+///
+/// O(1)
+///
+/// # See also
+///
+/// [`Integral`], [`EMA`](crate::methods::EMA)
+///
+/// [`ValueType`]: crate::core::ValueType
+#[derive(Debug, Clone, Copy)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct DecayingIntegral {
+	decay: ValueType,
+	value: ValueType,
+}
+
+/// Just an alias for `DecayingIntegral`
+pub type LeakyIntegral = DecayingIntegral;
+
+impl DecayingIntegral {
+	/// Creates a new `DecayingIntegral`, which halves the contribution of every value after `half_life` bars
+	///
+	/// `half_life` should be > 0.
+	pub fn with_half_life(half_life: ValueType, value: ValueType) -> Result<Self, Error> {
+		if half_life.is_nan() || half_life <= 0.0 {
+			return Err(Error::WrongMethodParameters);
+		}
+
+		// 0.5 ^ (1 / half_life)
+		Self::new((-half_life.recip()).exp2(), value)
+	}
+
+	/// Returns the decay factor
+	#[must_use]
+	pub const fn decay(&self) -> ValueType {
+		self.decay
+	}
+}
+
+impl Method for DecayingIntegral {
+	type Params = ValueType;
+	type Input = ValueType;
+	type Output = Self::Input;
+
+	fn new(decay: Self::Params, value: Self::Input) -> Result<Self, Error> {
+		if !(0.0..1.0).contains(&decay) {
+			return Err(Error::WrongMethodParameters);
+		}
+
+		Ok(Self {
+			decay,
+			value: value / (1.0 - decay),
+		})
+	}
+
+	#[inline]
+	fn next(&mut self, value: Self::Input) -> Self::Output {
+		self.value = self.value.mul_add(self.decay, value);
+		self.value
+	}
+
+	fn reset(&mut self, value: Self::Input) -> Result<(), Error> {
+		self.value = value / (1.0 - self.decay);
+
+		Ok(())
+	}
+}
+
 #[cfg(test)]
 mod tests {
-	use super::{Integral as TestingMethod, Method};
+	use super::{DecayingIntegral, Integral as TestingMethod, Method};
 	use crate::core::ValueType;
 	use crate::helpers::{assert_eq_float, RandomCandles};
-	use crate::methods::tests::test_const;
+	use crate::methods::tests::{test_const, test_const_float};
+	use alloc::vec::Vec;
 
 	#[test]
 	fn test_integral_const() {
@@ -199,4 +323,43 @@ mod tests {
 			});
 		});
 	}
+
+	#[test]
+	fn test_decaying_integral() {
+		let src: Vec<ValueType> = RandomCandles::default()
+			.take(300)
+			.map(|x| x.close)
+			.collect();
+
+		for decay in [0.0, 0.3, 0.9, 0.99] {
+			let mut method = DecayingIntegral::new(decay, src[0]).unwrap();
+			test_const_float(&mut method, src[0], src[0] / (1.0 - decay));
+
+			let mut method = DecayingIntegral::new(decay, src[0]).unwrap();
+			let initial = src[0] / (1.0 - decay);
+
+			src.iter().enumerate().for_each(|(i, &x)| {
+				let expected = src[..=i]
+					.iter()
+					.rev()
+					.enumerate()
+					.fold(initial * decay.powi(i as i32 + 1), |s, (j, &v)| {
+						s + v * decay.powi(j as i32)
+					});
+
+				assert_eq_float(expected, method.next(x));
+			});
+		}
+
+		for half_life in [0.5, 1.0, 7.0, 100.0] {
+			let method = DecayingIntegral::with_half_life(half_life, 1.0).unwrap();
+			assert_eq_float(0.5, method.decay().powf(half_life));
+		}
+
+		assert!(DecayingIntegral::new(1.0, 1.0).is_err());
+		assert!(DecayingIntegral::new(-0.1, 1.0).is_err());
+		assert!(DecayingIntegral::with_half_life(0.0, 1.0).is_err());
+		assert!(DecayingIntegral::with_half_life(ValueType::NAN, 1.0).is_err());
+		assert!(DecayingIntegral::with_half_life(ValueType::INFINITY, 1.0).is_err());
+	}
 }
@@ -4,6 +4,9 @@ use crate::core::{Error, PeriodType, ValueType, Window};
 #[cfg(feature = "serde")]
 use serde::{Deserialize, Serialize};
 
+#[cfg(not(feature = "std"))]
+use crate::core::Float as _;
+
 /// [Linear regression](https://en.wikipedia.org/wiki/Linear_regression) moving average for last `length` values of timeseries of type [`ValueType`]
 ///
 /// # Parameters
@@ -29,6 +32,7 @@ use serde::{Deserialize, Serialize};
 #[derive(Debug, Clone)]
 #[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 pub struct LinReg {
+	#[cfg(feature = "serde")]
 	length: PeriodType,
 	s_xy: ValueType,
 	s_y: ValueType,
@@ -60,6 +64,7 @@ impl Method for LinReg {
 
 				let s_x = -(s_x as ValueType);
 				Ok(Self {
+					#[cfg(feature = "serde")]
 					length,
 					float_length,
 					length_invert,
@@ -84,6 +89,18 @@ impl Method for LinReg {
 		let k = self.s_xy.mul_add(self.float_length, self.s_x * self.s_y) * self.divider;
 		self.s_x.mul_add(k, self.s_y) * self.length_invert
 	}
+
+	fn min_periods(&self) -> PeriodType {
+		self.window.len()
+	}
+
+	fn reset(&mut self, value: Self::Input) -> Result<(), Error> {
+		self.s_y = -value * self.float_length;
+		self.s_xy = value * self.s_x;
+		self.window.fill(value);
+
+		Ok(())
+	}
 }
 
 #[cfg(test)]
@@ -93,6 +110,7 @@ mod tests {
 	use crate::core::ValueType;
 	use crate::helpers::{assert_eq_float, RandomCandles};
 	use crate::methods::tests::test_const_float;
+	use alloc::vec::Vec;
 
 	#[test]
 	fn test_lin_reg_const() {
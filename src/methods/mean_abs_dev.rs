@@ -63,6 +63,10 @@ impl Method for MeanAbsDev {
 			.sum::<ValueType>()
 			* self.0.get_divider()
 	}
+
+	fn reset(&mut self, value: Self::Input) -> Result<(), Error> {
+		self.0.reset(value)
+	}
 }
 
 #[cfg(test)]
@@ -70,6 +74,7 @@ mod tests {
 	use super::{MeanAbsDev as TestingMethod, Method};
 	use crate::core::ValueType;
 	use crate::helpers::{assert_eq_float, RandomCandles};
+	use alloc::vec::Vec;
 
 	#[test]
 	fn test_mean_abs_dev_const() {
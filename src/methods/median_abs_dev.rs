@@ -69,6 +69,10 @@ impl Method for MedianAbsDev {
 			.sum::<ValueType>()
 			* self.divider
 	}
+
+	fn reset(&mut self, value: Self::Input) -> Result<(), Error> {
+		self.smm.reset(value)
+	}
 }
 
 #[cfg(test)]
@@ -76,7 +80,8 @@ mod tests {
 	use super::{MedianAbsDev as TestingMethod, Method};
 	use crate::core::ValueType;
 	use crate::helpers::{assert_eq_float, RandomCandles};
-	use std::cmp::Ordering;
+	use alloc::vec::Vec;
+	use core::cmp::Ordering;
 
 	#[test]
 	fn test_median_abs_dev_const() {
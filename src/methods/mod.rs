@@ -51,10 +51,18 @@ mod swma;
 pub use swma::*;
 mod conv;
 pub use conv::*;
+mod fir;
+pub use fir::*;
 mod vwma;
 pub use vwma::*;
+mod session_vwap;
+pub use session_vwap::*;
 mod trima;
 pub use trima::*;
+mod zlema;
+pub use zlema::*;
+mod t3;
+pub use t3::*;
 //
 mod derivative;
 pub use derivative::*;
@@ -79,6 +87,8 @@ mod cross;
 pub use cross::*;
 mod reverse;
 pub use reverse::*;
+mod signal_filters;
+pub use signal_filters::*;
 mod highest_lowest;
 pub use highest_lowest::*;
 mod adi;
@@ -87,12 +97,102 @@ pub use adi::*;
 pub use highest_lowest_index::*;
 mod past;
 pub use past::*;
+mod stochastic;
+pub use stochastic::*;
+mod percent_rank;
+pub use percent_rank::*;
+mod divergence;
+pub use divergence::*;
+mod variable_rsi;
+pub use variable_rsi::*;
+mod fixed_point;
+pub use fixed_point::*;
+#[cfg(feature = "decimal")]
+mod decimal;
+#[cfg(feature = "decimal")]
+pub use decimal::*;
+
+#[cfg(feature = "snapshot")]
+use crate::core::{snapshot_tags, ValueType, OHLC, OHLCV};
+
+#[cfg(feature = "snapshot")]
+snapshot_tags! {
+	"methods::ADI" => ADI<T> where T: OHLCV,
+	"methods::CCI" => CCI,
+	"methods::Conv" => Conv,
+	"methods::Cross" => Cross,
+	"methods::CrossAbove" => CrossAbove,
+	"methods::CrossUnder" => CrossUnder,
+	"methods::CrossEvents" => CrossEvents,
+	"methods::Derivative" => Derivative,
+	"methods::Divergence" => Divergence,
+	"methods::DEMA" => DEMA,
+	"methods::DMA" => DMA,
+	"methods::EMA" => EMA,
+	"methods::TEMA" => TEMA,
+	"methods::TMA" => TMA,
+	"methods::FirFilter" => FirFilter,
+	"methods::FixedATR" => FixedATR,
+	"methods::FixedEMA" => FixedEMA,
+	"methods::FixedHighest" => FixedHighest,
+	"methods::FixedLowest" => FixedLowest,
+	"methods::FixedSMA" => FixedSMA,
+	"methods::Highest" => Highest,
+	"methods::HighestLowestDelta" => HighestLowestDelta,
+	"methods::Lowest" => Lowest,
+	"methods::HighestIndex" => HighestIndex,
+	"methods::LowestIndex" => LowestIndex,
+	"methods::HMA" => HMA,
+	"methods::DecayingIntegral" => DecayingIntegral,
+	"methods::Integral" => Integral,
+	"methods::LinReg" => LinReg,
+	"methods::MeanAbsDev" => MeanAbsDev,
+	"methods::MedianAbsDev" => MedianAbsDev,
+	"methods::Momentum" => Momentum,
+	"methods::NormalizedMomentum" => NormalizedMomentum<T> where T: OHLC,
+	"methods::PercentRank" => PercentRank,
+	"methods::RateOfChange" => RateOfChange,
+	"methods::ReverseHighSignal" => ReverseHighSignal,
+	"methods::ReverseLowSignal" => ReverseLowSignal,
+	"methods::ReverseSignal" => ReverseSignal,
+	"methods::ReversePoints" => ReversePoints,
+	"methods::RMA" => RMA,
+	"methods::SessionVWAP" => SessionVWAP,
+	"methods::Agree" => Agree,
+	"methods::Confirm" => Confirm,
+	"methods::Cooldown" => Cooldown,
+	"methods::SMA" => SMA,
+	"methods::SMM" => SMM,
+	"methods::StDev" => StDev,
+	"methods::Stochastic" => Stochastic,
+	"methods::SWMA" => SWMA,
+	"methods::T3" => T3,
+	"methods::TRIMA" => TRIMA,
+	"methods::VariableRSI" => VariableRSI,
+	"methods::LinearVolatility" => LinearVolatility,
+	"methods::VWMA" => VWMA,
+	"methods::WMA" => WMA,
+	"methods::WSMA" => WSMA,
+	"methods::ZLEMA" => ZLEMA,
+	"methods::Past" => Past<ValueType>,
+}
+
+#[cfg(all(feature = "snapshot", feature = "decimal"))]
+snapshot_tags! {
+	"methods::DecimalADI" => DecimalADI,
+	"methods::DecimalOBV" => DecimalOBV,
+	"methods::DecimalSMA" => DecimalSMA,
+}
 
 #[cfg(test)]
 mod tests {
-	use crate::core::{Method, ValueType};
-	use crate::helpers::assert_eq_float;
-	use std::fmt::Debug;
+	use super::*;
+	#[cfg(feature = "decimal")]
+	use crate::core::Decimal;
+	use crate::core::{Candle, Method, PeriodType, ResampleBy, TickScale, Ticks, ValueType};
+	use crate::helpers::{assert_eq_float, method, RandomCandles, RegularMethods};
+	use alloc::vec::Vec;
+	use core::fmt::Debug;
 
 	pub(super) fn test_const<P, I: Copy, O: Copy + Debug + PartialEq>(
 		method: &mut dyn Method<Params = P, Input = I, Output = O>,
@@ -114,4 +214,168 @@ mod tests {
 			assert_eq_float(output, method.next(input));
 		}
 	}
+
+	fn test_reset<M, F>(create: F, input: &[M::Input])
+	where
+		M: Method,
+		M::Output: Debug + PartialEq,
+		F: Fn(M::Input) -> M,
+	{
+		let (head, tail) = input.split_at(input.len() / 2);
+
+		let mut method = create(head[0]);
+		head.iter().for_each(|&x| {
+			method.next(x);
+		});
+
+		method.reset(tail[0]).unwrap();
+		let mut fresh = create(tail[0]);
+
+		tail.iter()
+			.for_each(|&x| assert_eq!(method.next(x), fresh.next(x)));
+	}
+
+	#[test]
+	fn test_methods_reset() {
+		let candles: Vec<Candle> = RandomCandles::new().take(300).collect();
+		let src: Vec<ValueType> = candles.iter().map(|c| c.close).collect();
+		let pairs: Vec<(ValueType, ValueType)> =
+			candles.iter().map(|c| (c.close, c.volume)).collect();
+		let lengths: Vec<(ValueType, PeriodType)> = src.iter().map(|&x| (x, 7)).collect();
+		let scale = TickScale::from_decimals(3).unwrap();
+		let ticks: Vec<Ticks> = src.iter().map(|&x| scale.to_ticks(x)).collect();
+		let tick_candles: Vec<(Ticks, Ticks, Ticks)> =
+			ticks.iter().map(|&x| (x + 5, x - 5, x)).collect();
+		#[cfg(feature = "decimal")]
+		let (decimals, decimal_pairs, decimal_candles) = {
+			let decimals: Vec<Decimal> = ticks.iter().map(|&x| Decimal::new(x, 3)).collect();
+			let pairs: Vec<(Decimal, Decimal)> =
+				decimals.iter().map(|&x| (x, Decimal::ONE + x)).collect();
+			let candles: Vec<(Decimal, Decimal, Decimal, Decimal)> = decimals
+				.iter()
+				.map(|&x| (x + Decimal::ONE, x - Decimal::ONE, x, Decimal::TEN))
+				.collect();
+			(decimals, pairs, candles)
+		};
+
+		for length in [2, 3, 10, 33] {
+			test_reset(|x| SMA::new(length, x).unwrap(), &src);
+			test_reset(|x| WMA::new(length, x).unwrap(), &src);
+			test_reset(|x| EMA::new(length, x).unwrap(), &src);
+			test_reset(|x| DMA::new(length, x).unwrap(), &src);
+			test_reset(|x| TMA::new(length, x).unwrap(), &src);
+			test_reset(|x| DEMA::new(length, x).unwrap(), &src);
+			test_reset(|x| TEMA::new(length, x).unwrap(), &src);
+			test_reset(|x| WSMA::new(length, x).unwrap(), &src);
+			test_reset(|x| RMA::new(length, x).unwrap(), &src);
+			test_reset(|x| SMM::new(length, x).unwrap(), &src);
+			test_reset(|x| HMA::new(length, x).unwrap(), &src);
+			test_reset(|x| LinReg::new(length, x).unwrap(), &src);
+			test_reset(|x| SWMA::new(length, x).unwrap(), &src);
+			test_reset(|x| Conv::new(vec![1.0; length as usize], x).unwrap(), &src);
+			test_reset(|x| VWMA::new(length, x).unwrap(), &pairs);
+			test_reset(|x| TRIMA::new(length, x).unwrap(), &src);
+			test_reset(|x| ZLEMA::new(length, x).unwrap(), &src);
+			test_reset(|x| T3::new(length, x).unwrap(), &src);
+			test_reset(|x| Derivative::new(length, x).unwrap(), &src);
+			test_reset(|x| Integral::new(length, x).unwrap(), &src);
+			test_reset(|x| Momentum::new(length, x).unwrap(), &src);
+			test_reset(|x| RateOfChange::new(length, x).unwrap(), &src);
+			test_reset(|x| StDev::new(length, x).unwrap(), &src);
+			test_reset(|x| LinearVolatility::new(length, x).unwrap(), &src);
+			test_reset(|x| CCI::new(length, x).unwrap(), &src);
+			test_reset(|x| MeanAbsDev::new(length, x).unwrap(), &src);
+			test_reset(|x| MedianAbsDev::new(length, x).unwrap(), &src);
+			test_reset(|x| ReverseSignal::new(length, length, x).unwrap(), &src);
+			test_reset(|x| ReversePoints::new(length, length, x).unwrap(), &src);
+			test_reset(|x| Highest::new(length, x).unwrap(), &src);
+			test_reset(|x| Lowest::new(length, x).unwrap(), &src);
+			test_reset(|x| HighestLowestDelta::new(length, x).unwrap(), &src);
+			test_reset(|x| HighestIndex::new(length, x).unwrap(), &src);
+			test_reset(|x| LowestIndex::new(length, x).unwrap(), &src);
+			test_reset(|x| ADI::new(length, x).unwrap(), &candles);
+			test_reset(
+				|x| NormalizedMomentum::new((length, Normalization::ATR), x).unwrap(),
+				&candles,
+			);
+			test_reset(|x| Past::new(length, x).unwrap(), &src);
+			test_reset(|x| Stochastic::new(length, x).unwrap(), &src);
+			test_reset(|x| PercentRank::new(length, x).unwrap(), &src);
+			test_reset(
+				|x| Divergence::new((length, 2, 50, 0.0), x).unwrap(),
+				&pairs,
+			);
+			test_reset(|x| VariableRSI::new(length, x).unwrap(), &lengths);
+			test_reset(|x| FixedSMA::new(length, x).unwrap(), &ticks);
+			test_reset(|x| FixedEMA::new(length, x).unwrap(), &ticks);
+			test_reset(|x| FixedHighest::new(length, x).unwrap(), &ticks);
+			test_reset(|x| FixedLowest::new(length, x).unwrap(), &ticks);
+			test_reset(|x| FixedATR::new(length, x).unwrap(), &tick_candles);
+
+			#[cfg(feature = "decimal")]
+			{
+				test_reset(|x| DecimalSMA::new(length, x).unwrap(), &decimals);
+				test_reset(|x| DecimalADI::new(length, x).unwrap(), &decimal_candles);
+				test_reset(|x| DecimalOBV::new(length, x).unwrap(), &decimal_pairs);
+			}
+		}
+
+		test_reset(|x| ADI::new(0, x).unwrap(), &candles);
+		test_reset(|x| DecayingIntegral::new(0.9, x).unwrap(), &src);
+		test_reset(
+			|x| SessionVWAP::new(Some(ResampleBy::Count(10)), x).unwrap(),
+			&pairs,
+		);
+		test_reset(|x| Cross::new((), x).unwrap(), &pairs);
+		test_reset(|x| CrossAbove::new((), x).unwrap(), &pairs);
+		test_reset(|x| CrossUnder::new((), x).unwrap(), &pairs);
+		test_reset(|x| CrossEvents::new((), x).unwrap(), &pairs);
+	}
+
+	fn test_peek<M>(mut method: M, input: &[M::Input])
+	where
+		M: Method + Clone,
+		M::Output: Debug + PartialEq,
+	{
+		for &x in input {
+			let peeked = method.peek(x);
+
+			assert_eq!(method.peek(x), peeked);
+			assert_eq!(method.next(x), peeked);
+		}
+	}
+
+	#[test]
+	fn test_methods_peek() {
+		let candles: Vec<Candle> = RandomCandles::new().take(300).collect();
+		let src: Vec<ValueType> = candles.iter().map(|c| c.close).collect();
+		let pairs: Vec<(ValueType, ValueType)> =
+			candles.iter().map(|c| (c.close, c.volume)).collect();
+
+		for length in [1, 2, 10, 33] {
+			test_peek(EMA::new(length, src[0]).unwrap(), &src);
+			test_peek(DMA::new(length, src[0]).unwrap(), &src);
+			test_peek(TMA::new(length, src[0]).unwrap(), &src);
+			test_peek(DEMA::new(length, src[0]).unwrap(), &src);
+			test_peek(TEMA::new(length, src[0]).unwrap(), &src);
+			test_peek(RMA::new(length, src[0]).unwrap(), &src);
+			test_peek(WSMA::new(length, src[0]).unwrap(), &src);
+			test_peek(SMA::new(length, src[0]).unwrap(), &src);
+			test_peek(WMA::new(length, src[0]).unwrap(), &src);
+			test_peek(Momentum::new(length, src[0]).unwrap(), &src);
+			test_peek(RateOfChange::new(length, src[0]).unwrap(), &src);
+		}
+
+		test_peek(SMM::new(10, src[0]).unwrap(), &src);
+		test_peek(LinReg::new(10, src[0]).unwrap(), &src);
+		test_peek(Highest::new(10, src[0]).unwrap(), &src);
+		test_peek(ADI::new(10, candles[0]).unwrap(), &candles);
+		test_peek(Cross::new((), pairs[0]).unwrap(), &pairs);
+
+		let mut method = method(RegularMethods::HMA, 10, src[0]).unwrap();
+		for &x in &src {
+			let mut copy = method.clone();
+			assert_eq!(copy.next(x), method.next(x));
+		}
+	}
 }
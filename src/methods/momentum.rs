@@ -1,5 +1,7 @@
 use crate::core::Method;
-use crate::core::{Error, PeriodType, ValueType, Window};
+use crate::core::{Error, PeriodType, ValueType, Window, OHLC};
+use crate::methods::{StDev, RMA};
+use alloc::vec::Vec;
 
 #[cfg(feature = "serde")]
 use serde::{Deserialize, Serialize};
@@ -95,14 +97,168 @@ impl Method for Momentum {
 	fn next(&mut self, value: Self::Input) -> Self::Output {
 		value - self.window.push(value)
 	}
+
+	#[inline]
+	fn peek(&self, value: Self::Input) -> Self::Output {
+		value - self.window.first()
+	}
+
+	fn reset(&mut self, value: Self::Input) -> Result<(), Error> {
+		self.window.fill(value);
+		self.last_value = value;
+
+		Ok(())
+	}
+
+	fn save_undo(&self, stack: &mut Vec<ValueType>) -> bool {
+		stack.push(self.window.first());
+
+		true
+	}
+
+	fn restore_undo(&mut self, stack: &mut Vec<ValueType>) {
+		if let Some(oldest) = stack.pop() {
+			self.window.undo_push(oldest);
+		}
+	}
+}
+
+/// Volatility measure used by [`NormalizedMomentum`]
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub enum Normalization {
+	/// Average true range, smoothed by [`RMA`]
+	ATR,
+
+	/// Standard deviation of *close* prices, see [`StDev`]
+	StDev,
+}
+
+/// [`Momentum`] of *close* prices divided by the volatility over the same `length`
+///
+/// The output is a count of volatility units the price has moved by, so it is comparable across instruments
+/// with different price levels and volatilities, f.e. to rank them by the momentum.
+///
+/// When the volatility is zero (f.e. on a constant input), the output is `0.0`.
+///
+/// # Parameters
+///
+/// Has a tuple of 2 parameters (`length`: [`PeriodType`], `normalization`: [`Normalization`])
+///
+/// `length` should be > 1
+///
+/// # Input type
+///
+/// Input type is [`OHLC`]
+///
+/// # Output type
+///
+/// Output type is [`ValueType`]
+///
+/// # Examples
+///
+/// ```
+/// use yata::prelude::*;
+/// use yata::core::{Candle, ValueType};
+/// use yata::methods::{Normalization, NormalizedMomentum};
+///
+/// let candle = |close: ValueType| Candle::from((close, close + 1.0, close - 1.0, close));
+///
+/// let mut momentum = NormalizedMomentum::new((2, Normalization::ATR), candle(10.0)).unwrap();
+/// assert_eq!(momentum.next(candle(10.0)), 0.0);
+///
+/// // instrument with 10 times higher prices and ranges has the same normalized momentum
+/// let candle10 = |close: ValueType| Candle::from((close * 10.0, close * 10.0 + 10.0, close * 10.0 - 10.0, close * 10.0));
+/// let mut momentum10 = NormalizedMomentum::new((2, Normalization::ATR), candle10(10.0)).unwrap();
+///
+/// for close in [11.0, 13.0, 12.0] {
+///     assert!((momentum.next(candle(close)) - momentum10.next(candle10(close))).abs() < 1e-9);
+/// }
+/// ```
+///
+/// # Performance
+///
+/// O(1)
+///
+/// # See also
+///
+/// [`Momentum`], [`StDev`], [`OHLC::tr`]
+///
+/// [`ValueType`]: crate::core::ValueType
+/// [`PeriodType`]: crate::core::PeriodType
+/// [`OHLC`]: crate::core::OHLC
+/// [`OHLC::tr`]: crate::core::OHLC::tr
+#[derive(Debug, Clone)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct NormalizedMomentum<T: OHLC> {
+	normalization: Normalization,
+	momentum: Momentum,
+	st_dev: StDev,
+	atr: RMA,
+	prev_candle: T,
+}
+
+impl<T: OHLC> Method for NormalizedMomentum<T> {
+	type Params = (PeriodType, Normalization);
+	type Input = T;
+	type Output = ValueType;
+
+	fn new((length, normalization): Self::Params, candle: Self::Input) -> Result<Self, Error> {
+		let close = candle.close();
+
+		Ok(Self {
+			normalization,
+			momentum: Momentum::new(length, close)?,
+			st_dev: StDev::new(length, close)?,
+			atr: RMA::new(length, candle.high() - candle.low())?,
+			prev_candle: candle,
+		})
+	}
+
+	#[inline]
+	fn next(&mut self, candle: Self::Input) -> Self::Output {
+		let close = candle.close();
+		let momentum = self.momentum.next(close);
+		let st_dev = self.st_dev.next(close);
+		let atr = self.atr.next(candle.tr(&self.prev_candle));
+		self.prev_candle = candle;
+
+		let volatility = match self.normalization {
+			Normalization::ATR => atr,
+			Normalization::StDev => st_dev,
+		};
+
+		if volatility > 0.0 {
+			momentum / volatility
+		} else {
+			0.0
+		}
+	}
+
+	fn min_periods(&self) -> PeriodType {
+		self.st_dev.min_periods().saturating_add(1)
+	}
+
+	fn reset(&mut self, candle: Self::Input) -> Result<(), Error> {
+		let close = candle.close();
+
+		self.momentum.reset(close)?;
+		self.st_dev.reset(close)?;
+		self.atr.reset(candle.high() - candle.low())?;
+		self.prev_candle = candle;
+
+		Ok(())
+	}
 }
 
 #[cfg(test)]
 mod tests {
-	use super::{Method, Momentum as TestingMethod};
-	use crate::core::ValueType;
+	use super::{Method, Momentum as TestingMethod, Normalization, NormalizedMomentum};
+	use crate::core::{Candle, ValueType, OHLC};
 	use crate::helpers::{assert_eq_float, RandomCandles};
 	use crate::methods::tests::test_const;
+	use crate::methods::{StDev, RMA};
+	use alloc::vec::Vec;
 
 	#[test]
 	fn test_momentum_const() {
@@ -144,4 +300,43 @@ mod tests {
 			});
 		});
 	}
+
+	#[test]
+	fn test_normalized_momentum() {
+		let candles: Vec<Candle> = RandomCandles::default().take(300).collect();
+
+		for length in [2, 5, 20] {
+			let params = (length, Normalization::ATR);
+			let mut by_atr = NormalizedMomentum::new(params, candles[0]).unwrap();
+			let params = (length, Normalization::StDev);
+			let mut by_st_dev = NormalizedMomentum::new(params, candles[0]).unwrap();
+
+			let mut momentum = TestingMethod::new(length, candles[0].close).unwrap();
+			let mut st_dev = StDev::new(length, candles[0].close).unwrap();
+			let mut atr = RMA::new(length, candles[0].high - candles[0].low).unwrap();
+			let mut prev = candles[0];
+
+			for &candle in &candles {
+				let m = momentum.next(candle.close);
+				let s = st_dev.next(candle.close);
+				let a = atr.next(candle.tr(&prev));
+				prev = candle;
+
+				assert_eq_float(m / a, by_atr.next(candle));
+
+				let value = by_st_dev.next(candle);
+				if s > 0.0 {
+					assert_eq_float(m / s, value);
+				} else {
+					assert_eq!(value, 0.0);
+				}
+			}
+		}
+
+		let candle = candles[0];
+		let mut method = NormalizedMomentum::new((5, Normalization::StDev), candle).unwrap();
+		test_const(&mut method, candle, 0.0);
+
+		assert!(NormalizedMomentum::new((1, Normalization::ATR), candle).is_err());
+	}
 }
@@ -1,6 +1,6 @@
 use crate::core::Method;
 use crate::core::{Error, PeriodType, Window};
-use std::fmt;
+use core::fmt;
 
 #[cfg(feature = "serde")]
 use serde::{Deserialize, Serialize};
@@ -78,6 +78,12 @@ where
 	fn next(&mut self, value: T) -> T {
 		self.0.push(value)
 	}
+
+	fn reset(&mut self, value: Self::Input) -> Result<(), Error> {
+		self.0.fill(value);
+
+		Ok(())
+	}
 }
 
 #[cfg(test)]
@@ -86,6 +92,7 @@ mod tests {
 	use crate::core::ValueType;
 	use crate::helpers::{assert_eq_float, RandomCandles};
 	use crate::methods::tests::test_const;
+	use alloc::vec::Vec;
 
 	#[test]
 	fn test_past_const() {
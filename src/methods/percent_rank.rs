@@ -0,0 +1,134 @@
+use crate::core::Method;
+use crate::core::{Error, PeriodType, ValueType, Window};
+
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
+
+/// Percent rank of the current value among the previous `length` values for timeseries of type [`ValueType`]
+///
+/// Calculates the fraction of the previous `length` values which are strictly less than the current value.
+///
+/// # Parameters
+///
+/// Has a single parameter `length`: [`PeriodType`]
+///
+/// `length` should be > 0
+///
+/// # Input type
+///
+/// Input type is [`ValueType`]
+///
+/// # Output type
+///
+/// Output type is [`ValueType`]
+///
+/// Output value is always in range \[0.0; 1.0\]
+///
+/// # Examples
+///
+/// ```
+/// use yata::prelude::*;
+/// use yata::methods::PercentRank;
+///
+/// let values = [1.0, 2.0, 3.0, 2.0, 1.0, 0.5, 2.0, 3.0];
+/// let r      = [0.0, 1.0, 1.0, 1.0 / 3.0, 0.0, 0.0, 2.0 / 3.0, 1.0];
+///
+/// let mut rank = PercentRank::new(3, values[0]).unwrap();
+///
+/// (0..values.len()).for_each(|i| {
+///     let v = rank.next(values[i]);
+///     assert_eq!(v, r[i]);
+/// });
+/// ```
+///
+/// # Performance
+///
+/// O(`length`)
+///
+/// This method is relatively slow compare to the other methods.
+///
+/// # See also
+///
+/// [`Stochastic`]
+///
+/// [`ValueType`]: crate::core::ValueType
+/// [`PeriodType`]: crate::core::PeriodType
+/// [`Stochastic`]: crate::methods::Stochastic
+#[derive(Debug, Clone)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct PercentRank {
+	divider: ValueType,
+	window: Window<ValueType>,
+}
+
+impl Method for PercentRank {
+	type Params = PeriodType;
+	type Input = ValueType;
+	type Output = Self::Input;
+
+	fn new(length: Self::Params, value: Self::Input) -> Result<Self, Error> {
+		match length {
+			0 => Err(Error::WrongMethodParameters),
+			length => Ok(Self {
+				divider: (length as ValueType).recip(),
+				window: Window::new(length, value),
+			}),
+		}
+	}
+
+	#[inline]
+	fn next(&mut self, value: Self::Input) -> Self::Output {
+		let count = self.window.iter().filter(|&v| v < value).count();
+		self.window.push(value);
+
+		count as ValueType * self.divider
+	}
+
+	fn reset(&mut self, value: Self::Input) -> Result<(), Error> {
+		self.window.fill(value);
+
+		Ok(())
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	use super::{Method, PercentRank as TestingMethod};
+	use crate::core::ValueType;
+	use crate::helpers::{assert_eq_float, RandomCandles};
+	use crate::methods::tests::test_const;
+	use alloc::vec::Vec;
+
+	#[test]
+	fn test_percent_rank_const() {
+		for i in 1..255 {
+			let input = (i as ValueType + 56.0) / 16.3251;
+			let mut method = TestingMethod::new(i, input).unwrap();
+
+			test_const(&mut method, input, 0.0);
+		}
+	}
+
+	#[test]
+	fn test_percent_rank() {
+		let candles = RandomCandles::default();
+
+		let src: Vec<ValueType> = candles.take(300).map(|x| x.close).collect();
+
+		(1..255).for_each(|length| {
+			let mut ma = TestingMethod::new(length, src[0]).unwrap();
+			let length = length as usize;
+
+			src.iter().enumerate().for_each(|(i, &x)| {
+				let value1 = ma.next(x);
+				let count = (1..=length)
+					.filter(|&j| src[i.saturating_sub(j)] < x)
+					.count();
+				let value2 = count as ValueType / length as ValueType;
+
+				assert_eq_float(value2, value1);
+				assert!((0.0..=1.0).contains(&value1));
+			});
+		});
+	}
+}
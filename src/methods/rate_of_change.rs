@@ -1,5 +1,6 @@
 use crate::core::Method;
 use crate::core::{Error, PeriodType, ValueType, Window};
+use alloc::vec::Vec;
 
 #[cfg(feature = "serde")]
 use serde::{Deserialize, Serialize};
@@ -63,6 +64,31 @@ impl Method for RateOfChange {
 
 		(value - prev_value) / prev_value
 	}
+
+	#[inline]
+	fn peek(&self, value: Self::Input) -> Self::Output {
+		let prev_value = self.0.first();
+
+		(value - prev_value) / prev_value
+	}
+
+	fn reset(&mut self, value: Self::Input) -> Result<(), Error> {
+		self.0.fill(value);
+
+		Ok(())
+	}
+
+	fn save_undo(&self, stack: &mut Vec<ValueType>) -> bool {
+		stack.push(self.0.first());
+
+		true
+	}
+
+	fn restore_undo(&mut self, stack: &mut Vec<ValueType>) {
+		if let Some(oldest) = stack.pop() {
+			self.0.undo_push(oldest);
+		}
+	}
 }
 
 #[cfg(test)]
@@ -72,6 +98,7 @@ mod tests {
 	use crate::helpers::{assert_eq_float, RandomCandles};
 	use crate::methods::tests::test_const;
 	use crate::methods::{Derivative, Past};
+	use alloc::vec::Vec;
 
 	#[test]
 	fn test_rate_of_change_const() {
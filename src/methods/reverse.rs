@@ -89,6 +89,11 @@ impl Method for ReverseSignal {
 	fn next(&mut self, value: ValueType) -> Self::Output {
 		self.low.next(value) - self.high.next(value)
 	}
+
+	fn reset(&mut self, value: Self::Input) -> Result<(), Error> {
+		self.high.reset(value)?;
+		self.low.reset(value)
+	}
 }
 
 /// Searches for high Reverse points over last `left`+`right`+1 values of type [`ValueType`]
@@ -140,12 +145,13 @@ impl Method for ReverseSignal {
 #[derive(Debug, Clone)]
 #[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 pub struct ReverseHighSignal {
+	#[cfg(feature = "serde")]
 	left: PeriodType,
 	right: PeriodType,
 
 	max_value: ValueType,
-	max_index: PeriodType,
-	index: PeriodType,
+	max_index: usize,
+	index: usize,
 	window: Window<ValueType>,
 }
 
@@ -170,6 +176,7 @@ impl Method for ReverseHighSignal {
 		}
 
 		Ok(Self {
+			#[cfg(feature = "serde")]
 			left,
 			right,
 			max_value: value,
@@ -183,7 +190,7 @@ impl Method for ReverseHighSignal {
 	fn next(&mut self, value: Self::Input) -> Self::Output {
 		self.window.push(value);
 
-		let first_index = (self.index + 1).saturating_sub(self.window.len());
+		let first_index = (self.index + 1).saturating_sub(self.window.len() as usize);
 
 		if self.max_index < first_index {
 			let mut max_index = first_index;
@@ -206,9 +213,8 @@ impl Method for ReverseHighSignal {
 			self.max_index = self.index;
 		}
 
-		let s = if self.index >= self.right
-			&& self.max_index == self.index.saturating_sub(self.right)
-		{
+		let right = self.right as usize;
+		let s = if self.index >= right && self.max_index == self.index - right {
 			Action::BUY_ALL
 		} else {
 			Action::None
@@ -217,6 +223,15 @@ impl Method for ReverseHighSignal {
 		self.index += 1;
 		s
 	}
+
+	fn reset(&mut self, value: Self::Input) -> Result<(), Error> {
+		self.max_value = value;
+		self.max_index = 0;
+		self.index = 0;
+		self.window.fill(value);
+
+		Ok(())
+	}
 }
 
 /// Searches for low reverse points over last `left`+`right`+1 values of type [`ValueType`]
@@ -268,6 +283,7 @@ impl Method for ReverseHighSignal {
 #[derive(Debug, Clone)]
 #[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 pub struct ReverseLowSignal {
+	#[cfg(feature = "serde")]
 	left: PeriodType,
 	right: PeriodType,
 
@@ -275,8 +291,8 @@ pub struct ReverseLowSignal {
 	// before:	usize,
 	// after:	usize,
 	min_value: ValueType,
-	min_index: PeriodType,
-	index: PeriodType,
+	min_index: usize,
+	index: usize,
 	window: Window<ValueType>,
 }
 
@@ -301,6 +317,7 @@ impl Method for ReverseLowSignal {
 		}
 
 		Ok(Self {
+			#[cfg(feature = "serde")]
 			left,
 			right,
 			min_value: value,
@@ -314,7 +331,7 @@ impl Method for ReverseLowSignal {
 	fn next(&mut self, value: Self::Input) -> Self::Output {
 		self.window.push(value);
 
-		let first_index = (self.index + 1).saturating_sub(self.window.len());
+		let first_index = (self.index + 1).saturating_sub(self.window.len() as usize);
 
 		if self.min_index < first_index {
 			let mut min_index = first_index;
@@ -337,9 +354,8 @@ impl Method for ReverseLowSignal {
 			self.min_index = self.index;
 		}
 
-		let s = if self.index >= self.right
-			&& self.min_index == self.index.saturating_sub(self.right)
-		{
+		let right = self.right as usize;
+		let s = if self.index >= right && self.min_index == self.index - right {
 			Action::BUY_ALL
 		} else {
 			Action::None
@@ -348,12 +364,185 @@ impl Method for ReverseLowSignal {
 		self.index += 1;
 		s
 	}
+
+	fn reset(&mut self, value: Self::Input) -> Result<(), Error> {
+		self.min_value = value;
+		self.min_index = 0;
+		self.index = 0;
+		self.window.fill(value);
+
+		Ok(())
+	}
+}
+
+/// Price and age of a reverse point found by [`ReversePoints`]
+#[derive(Debug, Clone, Copy, PartialEq)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct ReverseLevel {
+	/// Value at the reverse point
+	pub value: ValueType,
+
+	/// Count of steps passed since the reverse point. It is at least `right`, because a reverse point is confirmed only after `right` more values
+	pub age: usize,
+}
+
+/// Output of the [`ReversePoints`] method
+#[derive(Debug, Clone, Copy, PartialEq)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct ReversePoint {
+	/// Reverse point confirmed on the current step: the same as [`ReverseSignal`] returns
+	pub action: Action,
+
+	/// Last confirmed high reverse point or `None` if there was no one yet
+	pub high: Option<ReverseLevel>,
+
+	/// Last confirmed low reverse point or `None` if there was no one yet
+	pub low: Option<ReverseLevel>,
+}
+
+/// Searches for reverse points over last `left`+`right`+1 values of type [`ValueType`] and tracks the last of them
+///
+/// Works like [`ReverseSignal`], but also returns value and age of the last high and the last low reverse points,
+/// so they may be used as the support and the resistance levels.
+///
+/// # Parameters
+///
+/// Has a tuple of 2 parameters (`left`: [`PeriodType`], `right`: [`PeriodType`])
+///
+/// `left` is a count of values before the reverse point and `right` is a count of values after it,
+/// which should be lower (for the high point) or higher (for the low point) than the reverse point value.
+///
+/// `left` should be > 0 and `right` should be > 0
+///
+/// There is an additional restriction on parameters: `left`+`right`+1 should be <= [`PeriodType::MAX`](crate::core::PeriodType).
+///
+/// # Input type
+///
+/// Input type is [`ValueType`]
+///
+/// # Output type
+///
+/// Output type is [`ReversePoint`]
+///
+/// # Examples
+///
+/// ```
+/// use yata::prelude::*;
+/// use yata::core::Action;
+/// use yata::methods::{ReverseLevel, ReversePoints};
+///
+/// let s = [1.0, 2.0, 5.0, 2.0, 1.0, 1.5, 2.0];
+///
+/// let mut pivot = ReversePoints::new(2, 1, s[0]).unwrap();
+/// let points: Vec<_> = s.iter().map(|&v| pivot.next(v)).collect();
+///
+/// assert_eq!(points[2].high, None);
+/// assert_eq!(points[3].action, Action::SELL_ALL);
+/// assert_eq!(points[3].high, Some(ReverseLevel { value: 5.0, age: 1 }));
+/// assert_eq!(points[6].high, Some(ReverseLevel { value: 5.0, age: 4 }));
+/// assert_eq!(points[5].low, Some(ReverseLevel { value: 1.0, age: 1 }));
+/// ```
+///
+/// # Performance
+///
+/// O(`left`+`right`)
+///
+/// # See also
+///
+/// [`ReverseSignal`], [`ReverseHighSignal`], [`ReverseLowSignal`]
+///
+/// [`ValueType`]: crate::core::ValueType
+/// [`PeriodType`]: crate::core::PeriodType
+#[derive(Debug, Clone)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct ReversePoints {
+	high_signal: ReverseHighSignal,
+	low_signal: ReverseLowSignal,
+	high: Option<ReverseLevel>,
+	low: Option<ReverseLevel>,
+}
+
+impl ReversePoints {
+	/// Constructs new instanceof `ReversePoints`
+	/// It's just an alias for `Method::new((left, right), value)` but without parentheses of `Input` tuple
+	pub fn new(left: PeriodType, right: PeriodType, value: ValueType) -> Result<Self, Error> {
+		Method::new((left, right), value)
+	}
+}
+
+fn next_level(
+	level: Option<ReverseLevel>,
+	signal: Action,
+	value: ValueType,
+	right: PeriodType,
+) -> Option<ReverseLevel> {
+	if signal == Action::None {
+		level.map(|level| ReverseLevel {
+			age: level.age.saturating_add(1),
+			..level
+		})
+	} else {
+		Some(ReverseLevel {
+			value,
+			age: right as usize,
+		})
+	}
+}
+
+impl Method for ReversePoints {
+	type Params = (PeriodType, PeriodType);
+	type Input = ValueType;
+	type Output = ReversePoint;
+
+	fn new(params: Self::Params, value: Self::Input) -> Result<Self, Error> {
+		Ok(Self {
+			high_signal: Method::new(params, value)?,
+			low_signal: Method::new(params, value)?,
+			high: None,
+			low: None,
+		})
+	}
+
+	#[inline]
+	fn next(&mut self, value: Self::Input) -> Self::Output {
+		let high = self.high_signal.next(value);
+		let low = self.low_signal.next(value);
+
+		self.high = next_level(
+			self.high,
+			high,
+			self.high_signal.max_value,
+			self.high_signal.right,
+		);
+		self.low = next_level(
+			self.low,
+			low,
+			self.low_signal.min_value,
+			self.low_signal.right,
+		);
+
+		ReversePoint {
+			action: low - high,
+			high: self.high,
+			low: self.low,
+		}
+	}
+
+	fn reset(&mut self, value: Self::Input) -> Result<(), Error> {
+		self.high_signal.reset(value)?;
+		self.low_signal.reset(value)?;
+		self.high = None;
+		self.low = None;
+
+		Ok(())
+	}
 }
 
 #[cfg(test)]
 mod tests {
 	use super::*;
 	use crate::methods::tests::test_const;
+	use alloc::vec::Vec;
 
 	#[test]
 	fn test_reverse_low_const() {
@@ -404,4 +593,40 @@ mod tests {
 		let r2: Vec<i8> = v.iter().map(|&x| pivot.next(x).analog()).collect();
 		assert_eq!(r, r2);
 	}
+
+	#[test]
+	fn test_reverse_points() {
+		use crate::helpers::{assert_eq_float, RandomCandles};
+
+		let src: Vec<ValueType> = RandomCandles::new().take(1000).map(|c| c.close).collect();
+
+		for (left, right) in [(1, 1), (2, 5), (7, 3), (20, 20)] {
+			let mut points = ReversePoints::new(left, right, src[0]).unwrap();
+			let mut signal = ReverseSignal::new(left, right, src[0]).unwrap();
+			let (mut high, mut low) = (None, None);
+
+			for (i, &x) in src.iter().enumerate() {
+				let point = points.next(x);
+				let action = signal.next(x);
+				assert_eq!(point.action, action);
+
+				let pivot = i.saturating_sub(right as usize);
+				if action == Action::SELL_ALL {
+					high = Some(pivot);
+				} else if action == Action::BUY_ALL {
+					low = Some(pivot);
+				}
+
+				for (level, index) in [(point.high, high), (point.low, low)] {
+					assert_eq!(level.map(|level| level.age), index.map(|index| i - index));
+
+					if let (Some(level), Some(index)) = (level, index) {
+						assert_eq_float(src[index], level.value);
+					}
+				}
+			}
+
+			assert!(high.is_some() && low.is_some());
+		}
+	}
 }
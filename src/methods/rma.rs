@@ -1,9 +1,13 @@
 use crate::core::Method;
 use crate::core::{Error, PeriodType, ValueType};
+use alloc::vec::Vec;
 
 #[cfg(feature = "serde")]
 use serde::{Deserialize, Serialize};
 
+#[cfg(not(feature = "std"))]
+use crate::core::Float as _;
+
 /// [Running Moving Average](https://en.wikipedia.org/wiki/Moving_average#Modified_moving_average) of specified `length` for timeseries of type [`ValueType`]
 ///
 /// # Parameters
@@ -87,6 +91,29 @@ impl Method for RMA {
 
 		value
 	}
+
+	#[inline]
+	fn peek(&self, value: Self::Input) -> Self::Output {
+		self.alpha.mul_add(value, self.alpha_rev * self.prev_value)
+	}
+
+	fn reset(&mut self, value: Self::Input) -> Result<(), Error> {
+		self.prev_value = value;
+
+		Ok(())
+	}
+
+	fn save_undo(&self, stack: &mut Vec<ValueType>) -> bool {
+		stack.push(self.prev_value);
+
+		true
+	}
+
+	fn restore_undo(&mut self, stack: &mut Vec<ValueType>) {
+		if let Some(prev_value) = stack.pop() {
+			self.prev_value = prev_value;
+		}
+	}
 }
 
 #[cfg(test)]
@@ -95,6 +122,7 @@ mod tests {
 	use super::{Method, RMA as TestingMethod};
 	use crate::core::ValueType;
 	use crate::helpers::{assert_eq_float, RandomCandles};
+	use alloc::vec::Vec;
 
 	#[test]
 	fn test_rma_const() {
@@ -0,0 +1,234 @@
+use crate::core::{Anchor, ExternalAnchor, Method, SessionAnchor};
+use crate::core::{Error, ResampleBy, ValueType};
+
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
+
+#[cfg(not(feature = "std"))]
+use crate::core::Float as _;
+
+/// Volume weighted average price accumulated since the beginning of the session for timeseries of type ([`ValueType`], [`ValueType`])
+/// which represents pair of values (`price`, `volume`)
+///
+/// The session restarts:
+///
+/// * every time the session rule fires (see below);
+/// * explicitly by calling [`anchor`](SessionVWAP::anchor): accumulation restarts from the next value.
+///
+/// Until there is any volume in the session, the output is the current `price`.
+///
+/// It is the underlying primitive for session and anchored `VWAP` indicators.
+///
+/// # Parameters
+///
+/// Has a single parameter `session`: [`Option`]<[`ResampleBy`]>
+///
+/// * `None` - the session restarts only explicitly;
+/// * [`ResampleBy::Count`] - the session restarts every `count` values;
+/// * [`ResampleBy::Duration`] - the session restarts when the timestamp passed to [`next_at`](SessionVWAP::next_at)
+///   rolls over to the next period of `duration` milliseconds, aligned to the Unix epoch. Plain [`next`](Method::next) does not track time.
+///
+/// Count and duration should be > 0.
+///
+/// The session rule is a [`SessionAnchor`] paired with an [`ExternalAnchor`] fired by [`anchor`](SessionVWAP::anchor),
+/// so after the explicit restart the count of values in the session starts over.
+///
+/// # Input type
+///
+/// Input type is ([`ValueType`], [`ValueType`])
+///
+/// # Output type
+///
+/// Output type is [`ValueType`]
+///
+/// # Examples
+///
+/// ```
+/// use yata::prelude::*;
+/// use yata::core::ResampleBy;
+/// use yata::methods::SessionVWAP;
+///
+/// let mut vwap = SessionVWAP::new(Some(ResampleBy::Count(3)), (10.0, 1.0)).unwrap();
+///
+/// assert_eq!(vwap.next((10.0, 1.0)), 10.0);
+/// assert_eq!(vwap.next((20.0, 3.0)), 17.5);
+/// assert_eq!(vwap.next((30.0, 0.0)), 17.5);
+///
+/// // new session
+/// assert_eq!(vwap.next((40.0, 1.0)), 40.0);
+///
+/// vwap.anchor();
+/// assert_eq!(vwap.next((50.0, 1.0)), 50.0);
+/// ```
+///
+/// ```
+/// use yata::prelude::*;
+/// use yata::core::ResampleBy;
+/// use yata::methods::SessionVWAP;
+///
+/// const HOUR: i64 = 3_600_000;
+/// const DAY: i64 = 24 * HOUR;
+///
+/// let mut vwap = SessionVWAP::new(Some(ResampleBy::Duration(DAY)), (10.0, 1.0)).unwrap();
+///
+/// assert_eq!(vwap.next_at(22 * HOUR, (10.0, 1.0)), 10.0);
+/// assert_eq!(vwap.next_at(23 * HOUR, (20.0, 1.0)), 15.0);
+///
+/// // the next day
+/// assert_eq!(vwap.next_at(DAY + HOUR, (30.0, 1.0)), 30.0);
+/// ```
+///
+/// # Performance
+///
+/// O(1)
+///
+/// # See also
+///
+/// [`VWMA`](crate::methods::VWMA), [`AnchoredVWAP`](crate::indicators::AnchoredVWAP), [`Anchored`](crate::core::Anchored)
+///
+/// [`ValueType`]: crate::core::ValueType
+#[derive(Debug, Clone, Copy)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct SessionVWAP {
+	anchor: (ExternalAnchor, Option<SessionAnchor>),
+	sum: ValueType,
+	vol_sum: ValueType,
+	count: usize,
+}
+
+impl SessionVWAP {
+	/// Restarts the session: accumulation restarts from the next value
+	pub const fn anchor(&mut self) {
+		self.anchor.0.fire();
+	}
+
+	/// Returns count of values accumulated in the current session
+	#[must_use]
+	pub const fn session_length(&self) -> usize {
+		self.count
+	}
+
+	/// Evaluates the `value` at the `timestamp` in milliseconds since the Unix epoch
+	///
+	/// With [`ResampleBy::Duration`] session rule the session restarts when the `timestamp` rolls over to the next period.
+	/// Otherwise the `timestamp` is ignored and it is the same as [`next`](Method::next).
+	/// Timestamps must be passed in the increasing order.
+	pub fn next_at(&mut self, timestamp: i64, value: (ValueType, ValueType)) -> ValueType {
+		self.step(Some(timestamp), value)
+	}
+
+	fn step(
+		&mut self,
+		timestamp: Option<i64>,
+		(price, volume): (ValueType, ValueType),
+	) -> ValueType {
+		if self.anchor.fires(timestamp) {
+			self.restart();
+		}
+
+		self.sum = price.mul_add(volume, self.sum);
+		self.vol_sum += volume;
+		self.count += 1;
+
+		if self.vol_sum > 0.0 {
+			self.sum / self.vol_sum
+		} else {
+			price
+		}
+	}
+
+	const fn restart(&mut self) {
+		self.sum = 0.0;
+		self.vol_sum = 0.0;
+		self.count = 0;
+	}
+}
+
+impl Method for SessionVWAP {
+	type Params = Option<ResampleBy>;
+	type Input = (ValueType, ValueType);
+	type Output = ValueType;
+
+	fn new(session: Self::Params, _: Self::Input) -> Result<Self, Error> {
+		let session = session.map(SessionAnchor::new).transpose()?;
+
+		Ok(Self {
+			anchor: (ExternalAnchor::default(), session),
+			sum: 0.0,
+			vol_sum: 0.0,
+			count: 0,
+		})
+	}
+
+	#[inline]
+	fn next(&mut self, value: Self::Input) -> Self::Output {
+		self.step(None, value)
+	}
+
+	fn reset(&mut self, _: Self::Input) -> Result<(), Error> {
+		self.restart();
+		self.anchor.reset();
+
+		Ok(())
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	use super::{Method, SessionVWAP as TestingMethod};
+	use crate::core::{ResampleBy, ValueType};
+	use crate::helpers::{assert_eq_float, RandomCandles};
+	use crate::methods::tests::test_const_float;
+	use alloc::vec::Vec;
+
+	#[test]
+	fn test_session_vwap_const() {
+		for session in [None, Some(ResampleBy::Count(1)), Some(ResampleBy::Count(7))] {
+			let input = (56.0 / 16.3251, 3.0);
+			let mut method = TestingMethod::new(session, input).unwrap();
+
+			test_const_float(&mut method, input, input.0);
+		}
+	}
+
+	#[test]
+	fn test_session_vwap() {
+		let src: Vec<(ValueType, ValueType)> = RandomCandles::default()
+			.take(300)
+			.map(|x| (x.close, x.volume.abs()))
+			.collect();
+
+		for count in [1, 2, 10, 33] {
+			let mut method = TestingMethod::new(Some(ResampleBy::Count(count)), src[0]).unwrap();
+			let mut manual = TestingMethod::new(None, src[0]).unwrap();
+			let mut timed =
+				TestingMethod::new(Some(ResampleBy::Duration(count as i64 * 10)), src[0]).unwrap();
+
+			src.iter().enumerate().for_each(|(i, &x)| {
+				let start = i - i % count;
+				let session = &src[start..=i];
+
+				let sum: ValueType = session.iter().map(|(p, v)| p * v).sum();
+				let vol_sum: ValueType = session.iter().map(|(_, v)| v).sum();
+
+				if i > 0 && i % count == 0 {
+					manual.anchor();
+				}
+
+				let expected = sum / vol_sum;
+				assert_eq_float(expected, method.next(x));
+				assert_eq_float(expected, manual.next(x));
+				assert_eq_float(expected, timed.next_at(i as i64 * 10 + 5, x));
+				assert_eq!(method.session_length(), i % count + 1);
+			});
+		}
+
+		let mut method = TestingMethod::new(None, (1.0, 0.0)).unwrap();
+		assert_eq!(method.next((2.0, 0.0)), 2.0);
+		assert_eq!(method.next((3.0, 1.0)), 3.0);
+		assert_eq!(method.next((5.0, 1.0)), 4.0);
+
+		assert!(TestingMethod::new(Some(ResampleBy::Count(0)), (1.0, 1.0)).is_err());
+		assert!(TestingMethod::new(Some(ResampleBy::Duration(0)), (1.0, 1.0)).is_err());
+	}
+}
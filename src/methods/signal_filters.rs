@@ -0,0 +1,386 @@
+use crate::core::Method;
+use crate::core::{Action, Error, PeriodType};
+
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
+
+/// Passes the signal only when it is confirmed by `period` consecutive signals of the same direction
+///
+/// The signal is passed once on the `period`-th consecutive signal, all the further signals of the same direction
+/// are suppressed until the series is broken by [`Action::None`] or by the signal of the opposite direction.
+///
+/// # Parameters
+///
+/// Has a single parameter `period`: [`PeriodType`]
+///
+/// `period` should be > 0. `period` = 1 passes only the first signal of every series.
+///
+/// # Input type
+///
+/// Input type is [`Action`]
+///
+/// # Output type
+///
+/// Output type is [`Action`]
+///
+/// # Examples
+///
+/// ```
+/// use yata::prelude::*;
+/// use yata::core::Action;
+/// use yata::methods::Confirm;
+///
+/// let s = [1, 1, 0, 1, 1, 1, 1, -1, -1];
+/// let r = [0, 1, 0, 0, 1, 0, 0,  0, -1];
+///
+/// let mut confirm = Confirm::new(2, Action::None).unwrap();
+/// let r2: Vec<i8> = s.iter().map(|&v| confirm.next(v.into()).analog()).collect();
+///
+/// assert_eq!(r2, r);
+/// ```
+///
+/// # Performance
+///
+/// O(1)
+///
+/// # See also
+///
+/// [`Cooldown`], [`Agree`]
+///
+/// [`PeriodType`]: crate::core::PeriodType
+/// [`Action`]: crate::core::Action
+#[derive(Debug, Clone, Copy)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct Confirm {
+	period: PeriodType,
+	count: PeriodType,
+	sign: i8,
+}
+
+impl Method for Confirm {
+	type Params = PeriodType;
+	type Input = Action;
+	type Output = Action;
+
+	fn new(period: Self::Params, _: Self::Input) -> Result<Self, Error>
+	where
+		Self: Sized,
+	{
+		match period {
+			0 => Err(Error::WrongMethodParameters),
+			period => Ok(Self {
+				period,
+				count: 0,
+				sign: 0,
+			}),
+		}
+	}
+
+	#[inline]
+	fn next(&mut self, value: Self::Input) -> Self::Output {
+		let sign = value.analog();
+
+		if sign == 0 || sign != self.sign {
+			self.count = 0;
+		}
+
+		self.sign = sign;
+
+		if sign == 0 || self.count == self.period {
+			return Action::None;
+		}
+
+		self.count += 1;
+
+		if self.count == self.period {
+			value
+		} else {
+			Action::None
+		}
+	}
+
+	fn reset(&mut self, _: Self::Input) -> Result<(), Error> {
+		self.count = 0;
+		self.sign = 0;
+
+		Ok(())
+	}
+}
+
+/// Suppresses all the signals for `period` values after every passed signal
+///
+/// # Parameters
+///
+/// Has a single parameter `period`: [`PeriodType`]
+///
+/// `period` = 0 passes all the signals.
+///
+/// # Input type
+///
+/// Input type is [`Action`]
+///
+/// # Output type
+///
+/// Output type is [`Action`]
+///
+/// # Examples
+///
+/// ```
+/// use yata::prelude::*;
+/// use yata::core::Action;
+/// use yata::methods::Cooldown;
+///
+/// let s = [1, -1, 1, 0, -1, 1, 0];
+/// let r = [1,  0, 0, 0, -1, 0, 0];
+///
+/// let mut cooldown = Cooldown::new(2, Action::None).unwrap();
+/// let r2: Vec<i8> = s.iter().map(|&v| cooldown.next(v.into()).analog()).collect();
+///
+/// assert_eq!(r2, r);
+/// ```
+///
+/// # Performance
+///
+/// O(1)
+///
+/// # See also
+///
+/// [`Confirm`], [`Agree`]
+///
+/// [`PeriodType`]: crate::core::PeriodType
+/// [`Action`]: crate::core::Action
+#[derive(Debug, Clone, Copy)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct Cooldown {
+	period: PeriodType,
+	left: PeriodType,
+}
+
+impl Method for Cooldown {
+	type Params = PeriodType;
+	type Input = Action;
+	type Output = Action;
+
+	fn new(period: Self::Params, _: Self::Input) -> Result<Self, Error>
+	where
+		Self: Sized,
+	{
+		Ok(Self { period, left: 0 })
+	}
+
+	#[inline]
+	fn next(&mut self, value: Self::Input) -> Self::Output {
+		if self.left > 0 {
+			self.left -= 1;
+			return Action::None;
+		}
+
+		if value.analog() != 0 {
+			self.left = self.period;
+		}
+
+		value
+	}
+
+	fn reset(&mut self, _: Self::Input) -> Result<(), Error> {
+		self.left = 0;
+
+		Ok(())
+	}
+}
+
+/// Passes the signal of the first stream only when it agrees with the signal of the second stream
+///
+/// The signals agree when they have the same direction. The signal of the second stream is taken into account
+/// for `period` values after it appears, so the streams may agree even if the signals do not match in time exactly.
+///
+/// # Parameters
+///
+/// Has a single parameter `period`: [`PeriodType`]
+///
+/// `period` = 0 requires both signals to appear at the same time.
+///
+/// # Input type
+///
+/// Input type is (`signal`: [`Action`], `filter`: [`Action`])
+///
+/// # Output type
+///
+/// Output type is [`Action`]
+///
+/// # Examples
+///
+/// ```
+/// use yata::prelude::*;
+/// use yata::core::Action;
+/// use yata::methods::Agree;
+///
+/// let s = [1, 0, 1, 0, 0,  1, -1];
+/// let f = [1, 0, 0, 0, 1, -1,  0];
+/// let r = [1, 0, 0, 0, 0,  0, -1];
+///
+/// let mut agree = Agree::new(1, (Action::None, Action::None)).unwrap();
+/// let r2: Vec<i8> = s
+///     .iter()
+///     .zip(&f)
+///     .map(|(&s, &f)| agree.next((s.into(), f.into())).analog())
+///     .collect();
+///
+/// assert_eq!(r2, r);
+/// ```
+///
+/// # Performance
+///
+/// O(1)
+///
+/// # See also
+///
+/// [`Confirm`], [`Cooldown`]
+///
+/// [`PeriodType`]: crate::core::PeriodType
+/// [`Action`]: crate::core::Action
+#[derive(Debug, Clone, Copy)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct Agree {
+	period: PeriodType,
+	sign: i8,
+	age: PeriodType,
+}
+
+impl Method for Agree {
+	type Params = PeriodType;
+	type Input = (Action, Action);
+	type Output = Action;
+
+	fn new(period: Self::Params, _: Self::Input) -> Result<Self, Error>
+	where
+		Self: Sized,
+	{
+		Ok(Self {
+			period,
+			sign: 0,
+			age: 0,
+		})
+	}
+
+	#[inline]
+	fn next(&mut self, (signal, filter): Self::Input) -> Self::Output {
+		match filter.analog() {
+			0 if self.age < self.period => self.age += 1,
+			0 => self.sign = 0,
+			sign => {
+				self.sign = sign;
+				self.age = 0;
+			}
+		}
+
+		if self.sign != 0 && signal.analog() == self.sign {
+			signal
+		} else {
+			Action::None
+		}
+	}
+
+	fn reset(&mut self, _: Self::Input) -> Result<(), Error> {
+		self.sign = 0;
+		self.age = 0;
+
+		Ok(())
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	use super::{Agree, Confirm, Cooldown};
+	use crate::core::{Action, Method};
+	use crate::helpers::RandomCandles;
+	use alloc::vec::Vec;
+
+	fn signals() -> Vec<Action> {
+		RandomCandles::new()
+			.take(300)
+			.enumerate()
+			.map(|(i, c)| match i % 7 {
+				0 => Action::None,
+				_ if c.close > c.open => Action::BUY_ALL,
+				_ => Action::SELL_ALL,
+			})
+			.collect()
+	}
+
+	#[test]
+	fn test_confirm() {
+		let signals = signals();
+
+		for period in 1..10 {
+			let mut confirm = Confirm::new(period, signals[0]).unwrap();
+
+			for (i, &signal) in signals.iter().enumerate() {
+				let output = confirm.next(signal);
+
+				let series = signals[..=i]
+					.iter()
+					.rev()
+					.take_while(|s| s.analog() != 0 && s.analog() == signal.analog())
+					.count();
+
+				if series == period as usize {
+					assert_eq!(output, signal);
+				} else {
+					assert_eq!(output, Action::None);
+				}
+			}
+		}
+
+		assert!(Confirm::new(0, Action::None).is_err());
+	}
+
+	#[test]
+	fn test_cooldown() {
+		let signals = signals();
+
+		for period in 0..10 {
+			let mut cooldown = Cooldown::new(period, signals[0]).unwrap();
+			let mut last = None;
+
+			for (i, &signal) in signals.iter().enumerate() {
+				let output = cooldown.next(signal);
+				let is_cooling = last.map_or(false, |last| i - last <= period as usize);
+
+				if is_cooling || signal.analog() == 0 {
+					assert_eq!(output.analog(), 0);
+				} else {
+					assert_eq!(output, signal);
+					last = Some(i);
+				}
+			}
+		}
+	}
+
+	#[test]
+	fn test_agree() {
+		let signals = signals();
+		let filters: Vec<_> = signals.iter().skip(3).copied().collect();
+
+		for period in 0..10 {
+			let mut agree = Agree::new(period, (signals[0], filters[0])).unwrap();
+
+			for (i, (&signal, &filter)) in signals.iter().zip(&filters).enumerate() {
+				let output = agree.next((signal, filter));
+
+				let recent = filters[..=i]
+					.iter()
+					.rev()
+					.take(period as usize + 1)
+					.find(|f| f.analog() != 0)
+					.map_or(0, |f| f.analog());
+
+				if signal.analog() != 0 && signal.analog() == recent {
+					assert_eq!(output, signal);
+				} else {
+					assert_eq!(output, Action::None);
+				}
+			}
+		}
+	}
+}
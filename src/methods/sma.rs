@@ -1,5 +1,7 @@
 use crate::core::Method;
 use crate::core::{Error, PeriodType, ValueType, Window};
+use crate::helpers::simd;
+use alloc::vec::Vec;
 
 #[cfg(feature = "serde")]
 use serde::{Deserialize, Serialize};
@@ -40,6 +42,9 @@ use serde::{Deserialize, Serialize};
 ///
 /// O(1)
 ///
+/// [`apply_slice`](crate::core::Method::apply_slice) evaluates the whole slice over the contiguous buffer instead of the ring one, so it is preferable for the batch computations.
+/// With `simd` feature it uses SIMD instructions on `x86_64`. Results are exactly the same as of [`next`](crate::core::Method::next).
+///
 /// [`ValueType`]: crate::core::ValueType
 /// [`PeriodType`]: crate::core::PeriodType
 
@@ -97,6 +102,57 @@ impl Method for SMA {
 
 		self.value
 	}
+
+	#[inline]
+	#[allow(clippy::suboptimal_flops)]
+	fn peek(&self, value: Self::Input) -> Self::Output {
+		// exactly the same expression as in `next`, so the results are equal
+		self.value + (value - self.window.first()) * self.divider
+	}
+
+	#[allow(clippy::suboptimal_flops)]
+	fn apply_slice(&mut self, slice: &[Self::Input]) -> Vec<Self::Output> {
+		let length = self.window.len() as usize;
+		let buf: Vec<ValueType> = self.window.iter().chain(slice.iter().copied()).collect();
+
+		// independent values are calculated in a separate vectorized pass
+		let mut result = vec![0.; slice.len()];
+		simd::sub_mul(&buf[length..], &buf, self.divider, &mut result);
+
+		for x in &mut result {
+			self.value += *x;
+			*x = self.value;
+		}
+
+		self.window.push_slice(slice);
+
+		result
+	}
+
+	fn min_periods(&self) -> PeriodType {
+		self.window.len()
+	}
+
+	fn reset(&mut self, value: Self::Input) -> Result<(), Error> {
+		self.value = value;
+		self.window.fill(value);
+
+		Ok(())
+	}
+
+	fn save_undo(&self, stack: &mut Vec<ValueType>) -> bool {
+		stack.push(self.window.first());
+		stack.push(self.value);
+
+		true
+	}
+
+	fn restore_undo(&mut self, stack: &mut Vec<ValueType>) {
+		if let (Some(value), Some(oldest)) = (stack.pop(), stack.pop()) {
+			self.value = value;
+			self.window.undo_push(oldest);
+		}
+	}
 }
 
 #[cfg(test)]
@@ -105,6 +161,7 @@ mod tests {
 	use crate::core::ValueType;
 	use crate::helpers::{assert_eq_float, RandomCandles};
 	use crate::methods::tests::test_const;
+	use alloc::vec::Vec;
 
 	#[allow(dead_code)]
 	const SIGMA: ValueType = 1e-5;
@@ -154,4 +211,26 @@ mod tests {
 			});
 		});
 	}
+
+	#[test]
+	fn test_sma_apply_slice() {
+		let candles = RandomCandles::default();
+
+		let src: Vec<ValueType> = candles.take(300).map(|x| x.close).collect();
+
+		(1..255).for_each(|sma_length| {
+			let mut sma = TestingMethod::new(sma_length, src[0]).unwrap();
+			let mut sma2 = TestingMethod::new(sma_length, src[0]).unwrap();
+
+			src.chunks(sma_length as usize * 3 % 41 + 1)
+				.for_each(|chunk| {
+					let values = sma2.apply_slice(chunk);
+					assert_eq!(values.len(), chunk.len());
+
+					chunk.iter().zip(values).for_each(|(&x, value2)| {
+						assert_eq!(sma.next(x).to_bits(), value2.to_bits());
+					});
+				});
+		});
+	}
 }
@@ -1,45 +1,71 @@
 use crate::core::Method;
 use crate::core::{Error, PeriodType, ValueType, Window};
-use std::cmp::Ordering;
+use alloc::vec::Vec;
 
 #[cfg(feature = "serde")]
 use serde::{Deserialize, Serialize};
 
-// find current value index
-fn find_index(value: ValueType, slice: &[ValueType], padding: usize) -> usize {
-	if slice.len() == 1 {
-		return padding;
-	}
+// Heap node: `(value, window's slot)`
+type HeapNode = (ValueType, PeriodType);
 
-	let half = slice.len() / 2;
+// Position of the window's slot inside one of the heaps: `(is in lower heap, index inside the heap)`
+type HeapPosition = (bool, PeriodType);
 
-	// It's not a mistake. We really need a bit-to-bit comparison of float values here
-	if value.to_bits() == slice[half].to_bits() {
-		padding + half
-	} else if value > slice[half] {
-		find_index(value, &slice[(half + 1)..], padding + half + 1)
+// `lower` heap is a max-heap, `upper` heap is a min-heap
+#[inline]
+fn is_above(in_lower: bool, a: ValueType, b: ValueType) -> bool {
+	if in_lower {
+		a > b
 	} else {
-		find_index(value, &slice[..half], padding)
+		a < b
 	}
 }
 
-// find new value insert index at
-fn find_insert_index(value: ValueType, slice: &[ValueType], padding: usize) -> usize {
-	if slice.is_empty() {
-		return padding;
+// restores heap property for the node at `index` which value has been changed
+// heaps indexes are always less than the window length, so they always fit into `PeriodType`
+#[allow(clippy::cast_possible_truncation)]
+fn sift(heap: &mut [HeapNode], positions: &mut [HeapPosition], in_lower: bool, mut index: usize) {
+	let node = heap[index];
+
+	// moving up
+	while index > 0 {
+		let parent = (index - 1) / 2;
+		if !is_above(in_lower, node.0, heap[parent].0) {
+			break;
+		}
+
+		heap[index] = heap[parent];
+		positions[heap[index].1 as usize].1 = index as PeriodType;
+		index = parent;
 	}
 
-	let half = slice.len() / 2;
+	// moving down
+	loop {
+		let left = 2 * index + 1;
+		if left >= heap.len() {
+			break;
+		}
 
-	// It's not a mistake. We really need a bit-to-bit comparison of float values here
-	if value.to_bits() == slice[half].to_bits() {
-		padding + half
-	} else if value > slice[half] {
-		find_insert_index(value, &slice[(half + 1)..], padding + half + 1)
-	} else {
-		find_insert_index(value, &slice[..half], padding)
+		let right = left + 1;
+		let child = if right < heap.len() && is_above(in_lower, heap[right].0, heap[left].0) {
+			right
+		} else {
+			left
+		};
+
+		if !is_above(in_lower, heap[child].0, node.0) {
+			break;
+		}
+
+		heap[index] = heap[child];
+		positions[heap[index].1 as usize].1 = index as PeriodType;
+		index = child;
 	}
+
+	heap[index] = node;
+	positions[node.1 as usize] = (in_lower, index as PeriodType);
 }
+
 ///
 /// [Simple Moving Median](https://en.wikipedia.org/wiki/Moving_average#Moving_median) of specified `length` for timeseries of type [`ValueType`]
 ///
@@ -77,17 +103,20 @@ fn find_insert_index(value: ValueType, slice: &[ValueType], padding: usize) -> u
 ///
 /// O(log(`length`))
 ///
-/// This method is relatively slower compare to the most of the other methods.
+/// Window values are kept in two indexed heaps: max-heap for the lower half and min-heap for the upper half.
+/// Each step replaces the oldest value in place and restores heaps order, so the median is always on the heaps tops.
 ///
 /// [`ValueType`]: crate::core::ValueType
 /// [`PeriodType`]: crate::core::PeriodType
 #[derive(Debug, Clone)]
 #[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 pub struct SMM {
-	half: PeriodType,
-	half_m1: PeriodType,
+	is_even: bool,
+	index: PeriodType,
 	window: Window<ValueType>,
-	slice: Vec<ValueType>,
+	lower: Vec<HeapNode>,
+	upper: Vec<HeapNode>,
+	positions: Vec<HeapPosition>,
 }
 
 impl SMM {
@@ -99,18 +128,13 @@ impl SMM {
 	}
 
 	/// Returns last result value. Useful for implementing in other methods and indicators.
-	#[allow(unsafe_code)]
 	#[inline]
 	#[must_use]
 	pub fn get_last_value(&self) -> ValueType {
-		if cfg!(feature = "unsafe_performance") {
-			unsafe {
-				(self.slice.get_unchecked(self.half as usize)
-					+ self.slice.get_unchecked(self.half_m1 as usize))
-					* 0.5
-			}
+		if self.is_even {
+			(self.lower[0].0 + self.upper[0].0) * 0.5
 		} else {
-			(self.slice[self.half as usize] + self.slice[self.half_m1 as usize]) * 0.5
+			self.lower[0].0
 		}
 	}
 }
@@ -128,14 +152,26 @@ impl Method for SMM {
 		match length {
 			0 => Err(Error::WrongMethodParameters),
 			length => {
-				let half = length / 2;
+				// lower heap always holds the middle value when `length` is odd
+				let lower_length = length - length / 2;
+
+				let positions = (0..length)
+					.map(|slot| {
+						if slot < lower_length {
+							(true, slot)
+						} else {
+							(false, slot - lower_length)
+						}
+					})
+					.collect();
 
-				let is_even = length % 2 == 0;
 				Ok(Self {
-					half,
-					half_m1: half.saturating_sub(is_even as PeriodType),
+					is_even: length % 2 == 0,
+					index: 0,
 					window: Window::new(length, value),
-					slice: vec![value; length as usize],
+					lower: (0..lower_length).map(|slot| (value, slot)).collect(),
+					upper: (lower_length..length).map(|slot| (value, slot)).collect(),
+					positions,
 				})
 			}
 		}
@@ -148,50 +184,62 @@ impl Method for SMM {
 			"SMM method cannot operate with NAN values"
 		);
 
-		let old_value = self.window.push(value);
-
-		let old_index = find_index(old_value, &self.slice, 0);
-		let index = find_insert_index(value, &self.slice, 0);
-
-		// if the old index is before current, then we should offset current value by 1 back
-		let index = index - (old_index < index) as usize;
-		#[allow(unsafe_code)]
-		if cfg!(feature = "unsafe_performance") {
-			if index != old_index {
-				let is_after = (index > old_index) as usize;
-				let start = (old_index + 1) * is_after + index * (1 - is_after);
-				let dest = old_index * is_after + (index + 1) * (1 - is_after);
-
-				let count = index.saturating_sub(old_index) * is_after
-					+ old_index.saturating_sub(index) * (1 - is_after);
-
-				unsafe {
-					std::ptr::copy(
-						self.slice.as_ptr().add(start),
-						self.slice.as_mut_ptr().add(dest),
-						count,
-					);
-				}
-			}
+		// `Window` writes values into its buffer in a round-robin order, so `index` is always the slot of the oldest value
+		let slot = self.index;
+		self.window.push(value);
+		self.index = (self.index + 1) % self.window.len();
 
-			unsafe {
-				let q = self.slice.get_unchecked_mut(index);
-				*q = value;
-			}
+		let (in_lower, position) = self.positions[slot as usize];
+		let position = position as usize;
+
+		if in_lower {
+			self.lower[position].0 = value;
+			sift(&mut self.lower, &mut self.positions, true, position);
 		} else {
-			// moving values inside the sorted slice
-			match index.cmp(&old_index) {
-				Ordering::Greater => self.slice.copy_within((old_index + 1)..=index, old_index),
-				Ordering::Less => self.slice.copy_within(index..old_index, index + 1),
-				Ordering::Equal => {}
-			};
+			self.upper[position].0 = value;
+			sift(&mut self.upper, &mut self.positions, false, position);
+		}
 
-			// inserting new value
-			self.slice[index] = value;
+		// only the replaced value may break the order between the heaps, so a single exchange of the tops is enough
+		if !self.upper.is_empty() && self.lower[0].0 > self.upper[0].0 {
+			core::mem::swap(&mut self.lower[0], &mut self.upper[0]);
+
+			sift(&mut self.lower, &mut self.positions, true, 0);
+			sift(&mut self.upper, &mut self.positions, false, 0);
 		}
 
 		self.get_last_value()
 	}
+
+	fn min_periods(&self) -> PeriodType {
+		self.window.len()
+	}
+
+	fn reset(&mut self, value: Self::Input) -> Result<(), Error> {
+		if !value.is_finite() {
+			return Err(Error::InvalidCandles);
+		}
+
+		let length = self.window.len();
+		let lower_length = length - length / 2;
+
+		self.index = 0;
+		self.window.fill(value);
+
+		for slot in 0..length {
+			let position = if slot < lower_length {
+				self.lower[slot as usize] = (value, slot);
+				(true, slot)
+			} else {
+				self.upper[(slot - lower_length) as usize] = (value, slot);
+				(false, slot - lower_length)
+			};
+
+			self.positions[slot as usize] = position;
+		}
+
+		Ok(())
+	}
 }
 
 #[cfg(test)]
@@ -200,6 +248,7 @@ mod tests {
 	use crate::core::ValueType;
 	use crate::helpers::{assert_eq_float, RandomCandles};
 	use crate::methods::tests::test_const;
+	use alloc::vec::Vec;
 
 	#[test]
 	fn test_smm_const() {
@@ -258,4 +307,35 @@ mod tests {
 			});
 		});
 	}
+
+	#[test]
+	fn test_smm_duplicates() {
+		let candles = RandomCandles::default();
+
+		// rounded values produce a lot of equal values inside the window
+		let src: Vec<ValueType> = candles
+			.take(300)
+			.map(|x| (x.close * 10.0).round() / 10.0)
+			.collect();
+
+		(1..255).for_each(|ma_length| {
+			let mut ma = TestingMethod::new(ma_length, src[0]).unwrap();
+			let ma_length = ma_length as usize;
+
+			src.iter().enumerate().for_each(|(i, &x)| {
+				let value = ma.next(x);
+
+				let mut slice: Vec<ValueType> =
+					(0..ma_length).map(|j| src[i.saturating_sub(j)]).collect();
+				slice.sort_by(|a, b| a.partial_cmp(b).unwrap());
+
+				let value2 = if ma_length % 2 == 0 {
+					(slice[ma_length / 2] + slice[ma_length / 2 - 1]) / 2.0
+				} else {
+					slice[ma_length / 2]
+				};
+				assert_eq_float(value2, value);
+			});
+		});
+	}
 }
@@ -1,10 +1,14 @@
 use crate::core::Method;
 use crate::core::{Error, PeriodType, ValueType, Window};
-use crate::methods::SMA;
+use crate::helpers::simd;
+use alloc::vec::Vec;
 
 #[cfg(feature = "serde")]
 use serde::{Deserialize, Serialize};
 
+#[cfg(not(feature = "std"))]
+use crate::core::Float as _;
+
 /// Moving [Standart Deviation](https://en.wikipedia.org/wiki/Standard_deviation) over the window of size `length` for timeseries of type [`ValueType`]
 ///
 /// # Parameters
@@ -33,24 +37,42 @@ use serde::{Deserialize, Serialize};
 /// stdev.next(1.0);
 /// stdev.next(2.0);
 ///
-/// assert_eq!(stdev.next(3.0), 1.0);
-/// assert_eq!(stdev.next(4.0), 1.0);
+/// assert!((stdev.next(3.0) - 1.0).abs() < 1e-6);
+/// assert!((stdev.next(4.0) - 1.0).abs() < 1e-6);
 /// ```
 ///
 /// # Performance
 ///
 /// O(1)
 ///
+/// [`apply_slice`](crate::core::Method::apply_slice) evaluates the whole slice over the contiguous buffer instead of the ring one, so it is preferable for the batch computations.
+/// With `simd` feature it uses SIMD instructions on `x86_64`. Results are exactly the same as of [`next`](crate::core::Method::next).
+///
+/// # Precision
+///
+/// Uses the rolling version of [Welford's online algorithm](https://en.wikipedia.org/wiki/Algorithms_for_calculating_variance#Welford's_online_algorithm),
+/// so it keeps the sum of squared deviations from the mean instead of the raw sum of squares.
+/// This prevents catastrophic cancellation on long-running streams with large price magnitudes.
+///
 /// [`ValueType`]: crate::core::ValueType
 /// [`PeriodType`]: crate::core::PeriodType
 #[derive(Debug, Clone)]
 #[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 pub struct StDev {
-	val_sum: ValueType,
-	sq_val_sum: ValueType,
+	mean: ValueType,
+	m2: ValueType,
+	divider: ValueType,
 	k: ValueType,
 	window: Window<ValueType>,
-	ma: SMA,
+}
+
+impl StDev {
+	/// Returns current mean value over the window. Useful for implementing in other methods and indicators.
+	#[inline]
+	#[must_use]
+	pub const fn get_mean(&self) -> ValueType {
+		self.mean
+	}
 }
 
 impl Method for StDev {
@@ -61,33 +83,64 @@ impl Method for StDev {
 	fn new(length: Self::Params, value: Self::Input) -> Result<Self, Error> {
 		match length {
 			0 | 1 => Err(Error::WrongMethodParameters),
-			length => {
-				let k = ((length - 1) as ValueType).recip();
-
-				let float_length = length as ValueType;
-				let val_sum = value * float_length;
-				Ok(Self {
-					val_sum,
-					sq_val_sum: value * val_sum,
-					k,
-					window: Window::new(length, value),
-					ma: SMA::new(length, value)?,
-				})
-			}
+			length => Ok(Self {
+				mean: value,
+				m2: 0.,
+				divider: (length as ValueType).recip(),
+				k: ((length - 1) as ValueType).recip(),
+				window: Window::new(length, value),
+			}),
 		}
 	}
 
 	#[inline]
 	fn next(&mut self, value: Self::Input) -> Self::Output {
 		let prev_value = self.window.push(value);
-		self.sq_val_sum += value * value - prev_value * prev_value;
-		self.val_sum += value - prev_value;
-		let ma_value = self.ma.next(value);
+		let prev_mean = self.mean;
+		let delta = value - prev_value;
+
+		self.mean += delta * self.divider;
+		// M2 += (x_new - x_old) * ((x_new - mean_new) + (x_old - mean_old))
+		self.m2 += delta * (value - self.mean + prev_value - prev_mean);
+
+		(self.m2.abs() * self.k).sqrt()
+	}
+
+	fn apply_slice(&mut self, slice: &[Self::Input]) -> Vec<Self::Output> {
+		let length = self.window.len() as usize;
+		let buf: Vec<ValueType> = self.window.iter().chain(slice.iter().copied()).collect();
+
+		// independent differences and the final square roots are calculated in separate vectorized passes
+		let mut result = vec![0.; slice.len()];
+		simd::sub_mul(&buf[length..], &buf, 1., &mut result);
+
+		for (x, (&value, &prev_value)) in result.iter_mut().zip(buf[length..].iter().zip(&buf)) {
+			let prev_mean = self.mean;
+			let delta = *x;
+
+			self.mean += delta * self.divider;
+			self.m2 += delta * (value - self.mean + prev_value - prev_mean);
+
+			*x = self.m2;
+		}
+
+		simd::abs_mul_sqrt(&mut result, self.k);
+
+		self.window.push_slice(slice);
 
-		// let sum = self.sq_val_sum - ma_value * self.val_sum;
-		let sum = self.val_sum.mul_add(-ma_value, self.sq_val_sum);
+		result
+	}
 
-		(sum.abs() * self.k).sqrt()
+	fn min_periods(&self) -> PeriodType {
+		self.window.len()
+	}
+
+	fn reset(&mut self, value: Self::Input) -> Result<(), Error> {
+		self.mean = value;
+		self.m2 = 0.;
+		self.window.fill(value);
+
+		Ok(())
 	}
 }
 
@@ -95,9 +148,10 @@ impl Method for StDev {
 #[allow(clippy::suboptimal_flops)]
 mod tests {
 	use super::{Method, StDev as TestingMethod};
-	use crate::core::ValueType;
+	use crate::core::{PeriodType, ValueType};
 	use crate::helpers::{assert_eq_float, RandomCandles};
 	use crate::methods::tests::test_const_float;
+	use alloc::vec::Vec;
 
 	#[test]
 	fn test_st_dev_const() {
@@ -140,4 +194,58 @@ mod tests {
 			});
 		});
 	}
+
+	#[test]
+	fn test_st_dev_apply_slice() {
+		let candles = RandomCandles::default();
+
+		let src: Vec<ValueType> = candles.take(300).map(|x| x.close).collect();
+
+		(2..255).for_each(|ma_length| {
+			let mut ma = TestingMethod::new(ma_length, src[0]).unwrap();
+			let mut ma2 = TestingMethod::new(ma_length, src[0]).unwrap();
+
+			src.chunks(ma_length as usize * 3 % 41 + 1)
+				.for_each(|chunk| {
+					let values = ma2.apply_slice(chunk);
+					assert_eq!(values.len(), chunk.len());
+
+					chunk.iter().zip(values).for_each(|(&x, value2)| {
+						assert_eq!(ma.next(x).to_bits(), value2.to_bits());
+					});
+				});
+		});
+	}
+
+	#[test]
+	#[cfg(not(feature = "value_type_f32"))]
+	fn test_st_dev_long_stream() {
+		const LENGTH: PeriodType = 20;
+		const BASE: ValueType = 1e6;
+
+		let mut candles = RandomCandles::default();
+		let first = BASE + candles.first().close;
+		let mut method = TestingMethod::new(LENGTH, first).unwrap();
+		let mut last_values = [first; LENGTH as usize];
+
+		candles.take(2_000_000).enumerate().for_each(|(i, x)| {
+			let x = BASE + x.close;
+			let value = method.next(x);
+			last_values[i % LENGTH as usize] = x;
+
+			if i % 100_000 == 0 {
+				let avg = last_values.iter().sum::<ValueType>() / LENGTH as ValueType;
+				let diff_sq_sum: ValueType = last_values.iter().map(|&v| (v - avg).powi(2)).sum();
+				let value2 = (diff_sq_sum / (LENGTH - 1) as ValueType).sqrt();
+
+				assert!(
+					(value - value2).abs() < 1e-6,
+					"at step {}: {} vs {}",
+					i,
+					value,
+					value2
+				);
+			}
+		});
+	}
 }
@@ -0,0 +1,217 @@
+use crate::core::Method;
+use crate::core::{Error, PeriodType, ValueType, Window};
+
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
+
+/// Raw stochastic %K value over the last `length` values for timeseries of type [`ValueType`]
+///
+/// Calculates `(value - lowest) / (highest - lowest)` where `highest` and `lowest` are taken over the window of size `length`.
+///
+/// When `highest` is equal to `lowest`, then returns `0.0`.
+///
+/// # Parameters
+///
+/// Has a single parameter `length`: [`PeriodType`]
+///
+/// `length` should be > 0
+///
+/// # Input type
+///
+/// Input type is [`ValueType`]
+///
+/// # Output type
+///
+/// Output type is [`ValueType`]
+///
+/// Output value is always in range \[0.0; 1.0\]
+///
+/// # Examples
+///
+/// ```
+/// use yata::prelude::*;
+/// use yata::methods::Stochastic;
+///
+/// let values = [1.0, 2.0, 3.0, 2.0, 1.0, 0.5, 2.0, 3.0];
+/// let r      = [0.0, 1.0, 1.0, 0.0, 0.0, 0.0, 1.0, 1.0];
+///
+/// let mut stoch = Stochastic::new(3, values[0]).unwrap();
+///
+/// (0..values.len()).for_each(|i| {
+///     let v = stoch.next(values[i]);
+///     assert_eq!(v, r[i]);
+/// });
+/// ```
+///
+/// # Performance
+///
+/// O(`length`)
+///
+/// This method is relatively slow compare to the other methods.
+///
+/// # See also
+///
+/// [`Highest`], [`Lowest`], [`HighestLowestDelta`]
+///
+/// [`ValueType`]: crate::core::ValueType
+/// [`PeriodType`]: crate::core::PeriodType
+/// [`Highest`]: crate::methods::Highest
+/// [`Lowest`]: crate::methods::Lowest
+/// [`HighestLowestDelta`]: crate::methods::HighestLowestDelta
+#[derive(Debug, Clone)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct Stochastic {
+	highest: ValueType,
+	lowest: ValueType,
+	window: Window<ValueType>,
+}
+
+impl Stochastic {
+	/// Returns current highest value over the window. Useful for implementing in other methods and indicators.
+	#[inline]
+	#[must_use]
+	pub const fn get_highest(&self) -> ValueType {
+		self.highest
+	}
+
+	/// Returns current lowest value over the window. Useful for implementing in other methods and indicators.
+	#[inline]
+	#[must_use]
+	pub const fn get_lowest(&self) -> ValueType {
+		self.lowest
+	}
+}
+
+impl Method for Stochastic {
+	type Params = PeriodType;
+	type Input = ValueType;
+	type Output = Self::Input;
+
+	fn new(length: Self::Params, value: Self::Input) -> Result<Self, Error> {
+		if !value.is_finite() {
+			return Err(Error::InvalidCandles);
+		}
+
+		match length {
+			0 => Err(Error::WrongMethodParameters),
+			length => Ok(Self {
+				window: Window::new(length, value),
+				highest: value,
+				lowest: value,
+			}),
+		}
+	}
+
+	#[inline]
+	fn next(&mut self, value: Self::Input) -> Self::Output {
+		assert!(
+			value.is_finite(),
+			"Stochastic method cannot operate with NAN values"
+		);
+
+		let left_value = self.window.push(value);
+
+		let mut search = false;
+		if value >= self.highest {
+			self.highest = value;
+		// It's not a mistake. We really need a bit-to-bit comparison of float values here
+		} else if left_value.to_bits() == self.highest.to_bits() {
+			search = true;
+		}
+
+		if value <= self.lowest {
+			self.lowest = value;
+		// It's not a mistake. We really need a bit-to-bit comparison of float values here
+		} else if left_value.to_bits() == self.lowest.to_bits() {
+			search = true;
+		}
+
+		if search {
+			let (min, max) = self
+				.window
+				.iter()
+				.fold((value, value), |(min, max), v| (min.min(v), max.max(v)));
+			self.highest = max;
+			self.lowest = min;
+		}
+
+		// we need to check division by zero, so we can really just check if `highest` is equal to `lowest` without using any kind of round error checks
+		if self.highest.to_bits() == self.lowest.to_bits() {
+			0.
+		} else {
+			(value - self.lowest) / (self.highest - self.lowest)
+		}
+	}
+
+	fn min_periods(&self) -> PeriodType {
+		self.window.len()
+	}
+
+	fn reset(&mut self, value: Self::Input) -> Result<(), Error> {
+		if !value.is_finite() {
+			return Err(Error::InvalidCandles);
+		}
+
+		self.window.fill(value);
+		self.highest = value;
+		self.lowest = value;
+
+		Ok(())
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	use super::{Method, Stochastic as TestingMethod};
+	use crate::core::ValueType;
+	use crate::helpers::{assert_eq_float, RandomCandles};
+	use crate::methods::tests::test_const;
+	use alloc::vec::Vec;
+
+	#[test]
+	fn test_stochastic_const() {
+		for i in 1..255 {
+			let input = (i as ValueType + 56.0) / 16.3251;
+			let mut method = TestingMethod::new(i, input).unwrap();
+
+			test_const(&mut method, input, 0.0);
+		}
+	}
+
+	#[test]
+	fn test_stochastic1() {
+		let mut candles = RandomCandles::default();
+
+		let mut ma = TestingMethod::new(1, candles.first().close).unwrap();
+
+		candles.take(100).for_each(|x| {
+			assert_eq_float(0.0, ma.next(x.close));
+		});
+	}
+
+	#[test]
+	fn test_stochastic() {
+		let candles = RandomCandles::default();
+
+		let src: Vec<ValueType> = candles.take(300).map(|x| x.close).collect();
+
+		(2..255).for_each(|length| {
+			let mut ma = TestingMethod::new(length, src[0]).unwrap();
+			let length = length as usize;
+
+			src.iter().enumerate().for_each(|(i, &x)| {
+				let value1 = ma.next(x);
+				let min = (0..length).fold(src[i], |m, j| m.min(src[i.saturating_sub(j)]));
+				let max = (0..length).fold(src[i], |m, j| m.max(src[i.saturating_sub(j)]));
+				let value2 = if max.to_bits() == min.to_bits() {
+					0.0
+				} else {
+					(x - min) / (max - min)
+				};
+
+				assert_eq_float(value2, value1);
+				assert!((0.0..=1.0).contains(&value1));
+			});
+		});
+	}
+}
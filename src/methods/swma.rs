@@ -3,6 +3,9 @@ use crate::core::{Error, Method, PeriodType, ValueType, Window};
 #[cfg(feature = "serde")]
 use serde::{Deserialize, Serialize};
 
+#[cfg(not(feature = "std"))]
+use crate::core::Float as _;
+
 /// Symmetrically Weighted Moving Average of specified `length` for timeseries of [`ValueType`].
 ///
 /// F.e. if `length` = 4, then weights are: [ 1.0, 2.0, 2.0, 1.0 ].
@@ -104,6 +107,24 @@ impl Method for SWMA {
 
 		self.numerator * self.invert_sum
 	}
+
+	fn reset(&mut self, value: Self::Input) -> Result<(), Error> {
+		let left_length = self.left_window.len() as usize;
+		let right_length = self.right_window.len() as usize;
+
+		let sum = ((left_length * (left_length + 1)) / 2 + (right_length * (right_length + 1) / 2))
+			as ValueType;
+
+		self.left_total = -value * left_length as ValueType;
+		self.left_window.fill(value);
+
+		self.right_total = value * right_length as ValueType;
+		self.right_window.fill(value);
+
+		self.numerator = value * sum;
+
+		Ok(())
+	}
 }
 
 #[cfg(test)]
@@ -114,6 +135,7 @@ mod tests {
 	use crate::helpers::{assert_eq_float, RandomCandles};
 	use crate::methods::tests::test_const;
 	use crate::methods::Conv;
+	use alloc::vec::Vec;
 
 	#[test]
 	fn test_swma_const() {
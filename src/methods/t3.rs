@@ -0,0 +1,185 @@
+use crate::core::Method;
+use crate::core::{Error, PeriodType, ValueType};
+use crate::methods::EMA;
+
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
+
+#[cfg(not(feature = "std"))]
+use crate::core::Float as _;
+
+/// [Tillson T3 Moving Average](https://www.technicalindicators.net/indicators-technical-analysis/150-t3-moving-average) of specified `length` for timeseries of type [`ValueType`]
+///
+/// It is a generalized [`DEMA`](crate::methods::DEMA) `GD = EMA * (1 + v) - EMA(EMA) * v` applied three times,
+/// where `v` is a volume factor. Default volume factor is `0.7`.
+///
+/// # Parameters
+///
+/// Has a single parameter `length`: [`PeriodType`]
+///
+/// `length` should be > 0
+///
+/// Use [`T3::with_volume_factor`] to set a custom volume factor in range \[`0.0`; `1.0`\].
+///
+/// # Input type
+///
+/// Input type is [`ValueType`]
+///
+/// # Output type
+///
+/// Output type is [`ValueType`]
+///
+/// # Examples
+///
+/// ```
+/// use yata::prelude::*;
+/// use yata::methods::{T3, EMA};
+///
+/// let mut t3 = T3::new(5, 1.0).unwrap();
+/// assert_eq!(t3.next(1.0), 1.0);
+///
+/// // with zero volume factor it is just a triple EMA
+/// let mut t3 = T3::with_volume_factor(5, 0.0, 1.0).unwrap();
+/// let mut tma = EMA::new(5, 1.0).unwrap()
+///     .then(EMA::new(5, 1.0).unwrap())
+///     .then(EMA::new(5, 1.0).unwrap());
+///
+/// assert_eq!(t3.next(4.0), tma.next(4.0));
+/// ```
+///
+/// # Performance
+///
+/// O(1)
+///
+/// # See also
+///
+/// [`EMA`], [`DEMA`](crate::methods::DEMA), [`TEMA`](crate::methods::TEMA)
+///
+/// [`ValueType`]: crate::core::ValueType
+/// [`PeriodType`]: crate::core::PeriodType
+#[derive(Debug, Clone, Copy)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct T3 {
+	emas: [EMA; 6],
+	factor: ValueType,
+}
+
+impl T3 {
+	/// Default volume factor
+	pub const VOLUME_FACTOR: ValueType = 0.7;
+
+	/// Creates a new T3 with the custom `volume_factor`
+	pub fn with_volume_factor(
+		length: PeriodType,
+		volume_factor: ValueType,
+		value: ValueType,
+	) -> Result<Self, Error> {
+		if !(0.0..=1.0).contains(&volume_factor) {
+			return Err(Error::WrongMethodParameters);
+		}
+
+		let ema = EMA::new(length, value)?;
+
+		Ok(Self {
+			emas: [ema; 6],
+			factor: volume_factor,
+		})
+	}
+}
+
+impl Method for T3 {
+	type Params = PeriodType;
+	type Input = ValueType;
+	type Output = Self::Input;
+
+	fn new(length: Self::Params, value: Self::Input) -> Result<Self, Error> {
+		Self::with_volume_factor(length, Self::VOLUME_FACTOR, value)
+	}
+
+	#[inline]
+	fn next(&mut self, value: Self::Input) -> Self::Output {
+		let factor = self.factor;
+		let mut value = value;
+
+		for pair in self.emas.chunks_exact_mut(2) {
+			let e1 = pair[0].next(value);
+			let e2 = pair[1].next(e1);
+
+			// generalized DEMA: e1 * (1 + v) - e2 * v
+			value = (e1 - e2).mul_add(factor, e1);
+		}
+
+		value
+	}
+
+	fn reset(&mut self, value: Self::Input) -> Result<(), Error> {
+		self.emas.iter_mut().try_for_each(|ema| ema.reset(value))
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	use super::{Method, T3 as TestingMethod};
+	use crate::core::ValueType;
+	use crate::helpers::{assert_eq_float, RandomCandles};
+	use crate::methods::tests::test_const_float;
+	use alloc::vec::Vec;
+
+	#[test]
+	fn test_t3_const() {
+		for i in 1..255 {
+			let input = (i as ValueType + 56.0) / 16.3251;
+			let mut method = TestingMethod::new(i, input).unwrap();
+
+			let output = method.next(input);
+			test_const_float(&mut method, input, output);
+		}
+	}
+
+	#[test]
+	fn test_t31() {
+		let mut candles = RandomCandles::default();
+
+		let mut ma = TestingMethod::new(1, candles.first().close).unwrap();
+
+		candles.take(100).for_each(|x| {
+			assert_eq_float(x.close, ma.next(x.close));
+		});
+	}
+
+	#[test]
+	fn test_t3() {
+		let candles = RandomCandles::default();
+
+		let src: Vec<ValueType> = candles.take(300).map(|x| x.close).collect();
+		let v = TestingMethod::VOLUME_FACTOR;
+
+		// classic formula over 6 EMAs
+		let c1 = -v * v * v;
+		let c2 = 3. * v * v + 3. * v * v * v;
+		let c3 = -6. * v * v - 3. * v - 3. * v * v * v;
+		let c4 = 1. + 3. * v + v * v * v + 3. * v * v;
+
+		(1..255).for_each(|length| {
+			let mut ma = TestingMethod::new(length, src[0]).unwrap();
+
+			let alpha = 2. / (length + 1) as ValueType;
+			let mut emas = [src[0]; 6];
+
+			src.iter().for_each(|&x| {
+				let mut input = x;
+				for e in &mut emas {
+					*e = alpha * input + (1. - alpha) * *e;
+					input = *e;
+				}
+
+				let value = c1 * emas[5] + c2 * emas[4] + c3 * emas[3] + c4 * emas[2];
+
+				assert_eq_float(value, ma.next(x));
+			});
+		});
+
+		assert!(TestingMethod::with_volume_factor(3, 1.5, src[0]).is_err());
+		assert!(TestingMethod::new(0, src[0]).is_err());
+	}
+}
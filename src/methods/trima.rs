@@ -67,6 +67,11 @@ impl Method for TRIMA {
 	fn next(&mut self, value: Self::Input) -> Self::Output {
 		self.sma2.next(self.sma1.next(value))
 	}
+
+	fn reset(&mut self, value: Self::Input) -> Result<(), Error> {
+		self.sma1.reset(value)?;
+		self.sma2.reset(value)
+	}
 }
 
 #[cfg(test)]
@@ -75,6 +80,7 @@ mod tests {
 	use crate::core::ValueType;
 	use crate::helpers::{assert_eq_float, RandomCandles};
 	use crate::methods::tests::test_const;
+	use alloc::vec::Vec;
 
 	#[test]
 	fn test_trima_const() {
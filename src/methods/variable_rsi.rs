@@ -0,0 +1,168 @@
+use crate::core::Method;
+use crate::core::{Error, PeriodType, ValueType, Window};
+
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
+
+/// Relative strength of timeseries of type [`ValueType`] over the variable count of the last changes
+///
+/// Calculates the sum of the positive changes divided by the sum of the absolute changes over the last `length` changes,
+/// where `length` is provided with every input value, so the period may be adapted on every step.
+///
+/// # Parameters
+///
+/// Has a single parameter `max_length`: [`PeriodType`]
+///
+/// `max_length` should be > 0 and < [`PeriodType::MAX`](crate::core::PeriodType)
+///
+/// # Input type
+///
+/// Input type is a tuple of value of type [`ValueType`] and `length` of type [`PeriodType`]
+///
+/// `length` of the initial input value is ignored
+///
+/// `length` is clamped into range \[1; `max_length`\]
+///
+/// # Output type
+///
+/// Output type is [`ValueType`]
+///
+/// Output value is always in range \[0.0; 1.0\]. When there were no changes over the last `length` values, then returns `0.5`
+///
+/// # Examples
+///
+/// ```
+/// use yata::prelude::*;
+/// use yata::methods::VariableRSI;
+///
+/// let mut rsi = VariableRSI::new(3, (1.0, 3)).unwrap();
+///
+/// assert_eq!(rsi.next((2.0, 3)), 1.0);
+/// assert_eq!(rsi.next((1.0, 3)), 0.5);
+/// assert_eq!(rsi.next((4.0, 3)), 0.8);
+/// assert_eq!(rsi.next((4.0, 1)), 0.5);
+/// assert_eq!(rsi.next((3.0, 2)), 0.0);
+/// ```
+///
+/// # Performance
+///
+/// O(`length`)
+///
+/// # See also
+///
+/// [`RelativeStrengthIndex`](crate::indicators::RelativeStrengthIndex)
+///
+/// [`ValueType`]: crate::core::ValueType
+/// [`PeriodType`]: crate::core::PeriodType
+#[derive(Debug, Clone)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct VariableRSI {
+	max_length: PeriodType,
+	prev_value: ValueType,
+	window: Window<ValueType>,
+}
+
+impl Method for VariableRSI {
+	type Params = PeriodType;
+	type Input = (ValueType, PeriodType);
+	type Output = ValueType;
+
+	fn new(max_length: Self::Params, (value, _): Self::Input) -> Result<Self, Error> {
+		match max_length {
+			0 | PeriodType::MAX => Err(Error::WrongMethodParameters),
+			max_length => Ok(Self {
+				max_length,
+				prev_value: value,
+				window: Window::new(max_length, 0.),
+			}),
+		}
+	}
+
+	#[inline]
+	fn next(&mut self, (value, length): Self::Input) -> Self::Output {
+		let change = value - self.prev_value;
+		self.prev_value = value;
+		self.window.push(change);
+
+		let length = length.clamp(1, self.max_length);
+		let (pos, neg) = ((self.max_length - length)..self.max_length)
+			.map(|i| self.window[i])
+			.fold((0., 0.), |(pos, neg), change: ValueType| {
+				(pos + change.max(0.), neg - change.min(0.))
+			});
+
+		if pos + neg > 0. {
+			pos / (pos + neg)
+		} else {
+			0.5
+		}
+	}
+
+	fn reset(&mut self, (value, _): Self::Input) -> Result<(), Error> {
+		self.prev_value = value;
+		self.window.fill(0.);
+
+		Ok(())
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	use super::{Method, VariableRSI as TestingMethod};
+	use crate::core::{PeriodType, ValueType};
+	use crate::helpers::{assert_eq_float, RandomCandles};
+	use crate::methods::tests::test_const;
+	use alloc::vec::Vec;
+
+	#[test]
+	fn test_variable_rsi_wrong_params() {
+		assert!(TestingMethod::new(0, (1.0, 1)).is_err());
+		assert!(TestingMethod::new(PeriodType::MAX, (1.0, 1)).is_err());
+	}
+
+	#[test]
+	fn test_variable_rsi_const() {
+		for i in 1..255 {
+			let input = (i as ValueType + 56.0) / 16.3251;
+			let mut method = TestingMethod::new(i, (input, i)).unwrap();
+
+			test_const(&mut method, (input, i), 0.5);
+		}
+	}
+
+	#[test]
+	#[allow(clippy::cast_possible_truncation)]
+	fn test_variable_rsi() {
+		let candles = RandomCandles::default();
+
+		let src: Vec<ValueType> = candles.take(300).map(|x| x.close).collect();
+
+		(1..255).for_each(|max_length| {
+			let mut rsi = TestingMethod::new(max_length, (src[0], max_length)).unwrap();
+
+			src.iter().enumerate().for_each(|(i, &x)| {
+				let length = (i * 7 % 255) as PeriodType;
+				let value1 = rsi.next((x, length));
+
+				let length = length.clamp(1, max_length) as usize;
+				let (pos, neg) = (0..length).fold((0.0, 0.0), |(pos, neg), j| {
+					let change = if j < i {
+						src[i - j] - src[i - j - 1]
+					} else {
+						0.0
+					};
+					(pos + change.max(0.0), neg + (-change).max(0.0))
+				});
+
+				let value2 = if pos + neg > 0.0 {
+					pos / (pos + neg)
+				} else {
+					0.5
+				};
+
+				assert_eq_float(value2, value1);
+				assert!((0.0..=1.0).contains(&value1));
+			});
+		});
+	}
+}
@@ -80,6 +80,14 @@ impl Method for LinearVolatility {
 
 		self.volatility
 	}
+
+	fn reset(&mut self, value: Self::Input) -> Result<(), Error> {
+		self.window.fill(0.);
+		self.prev_value = value;
+		self.volatility = 0.;
+
+		Ok(())
+	}
 }
 
 #[cfg(test)]
@@ -89,6 +97,7 @@ mod tests {
 	use crate::helpers::{assert_eq_float, RandomCandles};
 	use crate::methods::tests::test_const;
 	use crate::methods::Derivative;
+	use alloc::vec::Vec;
 
 	#[test]
 	fn test_volatility_const() {
@@ -4,6 +4,9 @@ use crate::core::{Error, PeriodType, ValueType, Window};
 #[cfg(feature = "serde")]
 use serde::{Deserialize, Serialize};
 
+#[cfg(not(feature = "std"))]
+use crate::core::Float as _;
+
 /// [Volume Weighed Moving Average](https://en.wikipedia.org/wiki/Moving_average#Weighted_moving_average) of specified `length`
 /// for timeseries of type ([`ValueType`], [`ValueType`]) which represents pair of values (`value`, `weight`)
 ///
@@ -77,6 +80,20 @@ impl Method for VWMA {
 
 		self.sum / self.vol_sum
 	}
+
+	fn min_periods(&self) -> PeriodType {
+		self.window.len()
+	}
+
+	fn reset(&mut self, value: Self::Input) -> Result<(), Error> {
+		let length = self.window.len() as ValueType;
+
+		self.sum = value.0 * value.1 * length;
+		self.vol_sum = value.1 * length;
+		self.window.fill(value);
+
+		Ok(())
+	}
 }
 
 #[cfg(test)]
@@ -86,6 +103,7 @@ mod tests {
 	use crate::core::ValueType;
 	use crate::helpers::{assert_eq_float, RandomCandles};
 	use crate::methods::tests::test_const;
+	use alloc::vec::Vec;
 
 	#[test]
 	fn test_vwma_const() {
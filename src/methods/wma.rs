@@ -1,9 +1,14 @@
 use crate::core::Method;
 use crate::core::{Error, PeriodType, ValueType, Window};
+use crate::helpers::simd;
+use alloc::vec::Vec;
 
 #[cfg(feature = "serde")]
 use serde::{Deserialize, Serialize};
 
+#[cfg(not(feature = "std"))]
+use crate::core::Float as _;
+
 /// [Weighed Moving Average](https://en.wikipedia.org/wiki/Moving_average#Weighted_moving_average) of specified `length` for timeseries of type [`ValueType`].
 ///
 /// # Parameters
@@ -40,6 +45,9 @@ use serde::{Deserialize, Serialize};
 ///
 /// O(1)
 ///
+/// [`apply_slice`](crate::core::Method::apply_slice) evaluates the whole slice over the contiguous buffer instead of the ring one, so it is preferable for the batch computations.
+/// With `simd` feature it uses SIMD instructions on `x86_64`. Results are exactly the same as of [`next`](crate::core::Method::next).
+///
 /// # See also
 ///
 /// [Volume Weighted Moving Average](crate::methods::VWMA) for computing weighted moving average with custom weights over every value
@@ -88,6 +96,65 @@ impl Method for WMA {
 
 		self.numerator * self.invert_sum
 	}
+
+	#[inline]
+	fn peek(&self, value: Self::Input) -> Self::Output {
+		(self.numerator + self.float_length.mul_add(value, self.total)) * self.invert_sum
+	}
+
+	fn apply_slice(&mut self, slice: &[Self::Input]) -> Vec<Self::Output> {
+		let length = self.window.len() as usize;
+		let buf: Vec<ValueType> = self.window.iter().chain(slice.iter().copied()).collect();
+
+		// independent differences and the final scaling are calculated in separate vectorized passes
+		let mut result = vec![0.; slice.len()];
+		simd::sub_mul(&buf, &buf[length..], 1., &mut result);
+
+		for (x, &value) in result.iter_mut().zip(slice) {
+			self.numerator += self.float_length.mul_add(value, self.total);
+			self.total += *x;
+			*x = self.numerator;
+		}
+
+		simd::mul_assign(&mut result, self.invert_sum);
+
+		self.window.push_slice(slice);
+
+		result
+	}
+
+	fn min_periods(&self) -> PeriodType {
+		self.window.len()
+	}
+
+	fn reset(&mut self, value: Self::Input) -> Result<(), Error> {
+		let length = self.window.len() as usize;
+		let sum = ((length * (length + 1)) / 2) as ValueType;
+
+		self.total = -value * self.float_length;
+		self.numerator = value * sum;
+		self.window.fill(value);
+
+		Ok(())
+	}
+
+	fn save_undo(&self, stack: &mut Vec<ValueType>) -> bool {
+		stack.push(self.window.first());
+		stack.push(self.total);
+		stack.push(self.numerator);
+
+		true
+	}
+
+	fn restore_undo(&mut self, stack: &mut Vec<ValueType>) {
+		if let (Some(numerator), Some(total), Some(oldest)) =
+			(stack.pop(), stack.pop(), stack.pop())
+		{
+			self.numerator = numerator;
+			self.total = total;
+			self.window.undo_push(oldest);
+		}
+	}
 }
 
 #[cfg(test)]
@@ -98,6 +165,7 @@ mod tests {
 	use crate::helpers::{assert_eq_float, RandomCandles};
 	use crate::methods::tests::test_const;
 	use crate::methods::Conv;
+	use alloc::vec::Vec;
 
 	#[test]
 	fn test_wma_const() {
@@ -147,4 +215,26 @@ mod tests {
 			});
 		});
 	}
+
+	#[test]
+	fn test_wma_apply_slice() {
+		let candles = RandomCandles::default();
+
+		let src: Vec<ValueType> = candles.take(300).map(|x| x.close).collect();
+
+		(1..255).for_each(|ma_length| {
+			let mut ma = TestingMethod::new(ma_length, src[0]).unwrap();
+			let mut ma2 = TestingMethod::new(ma_length, src[0]).unwrap();
+
+			src.chunks(ma_length as usize * 3 % 41 + 1)
+				.for_each(|chunk| {
+					let values = ma2.apply_slice(chunk);
+					assert_eq!(values.len(), chunk.len());
+
+					chunk.iter().zip(values).for_each(|(&x, value2)| {
+						assert_eq!(ma.next(x).to_bits(), value2.to_bits());
+					});
+				});
+		});
+	}
 }
@@ -1,6 +1,7 @@
 use crate::core::Method;
 use crate::core::{Error, PeriodType, ValueType};
 use crate::methods::EMA;
+use alloc::vec::Vec;
 
 #[cfg(feature = "serde")]
 use serde::{Deserialize, Serialize};
@@ -67,6 +68,23 @@ impl Method for WSMA {
 	fn next(&mut self, value: Self::Input) -> Self::Output {
 		self.0.next(value)
 	}
+
+	#[inline]
+	fn peek(&self, value: Self::Input) -> Self::Output {
+		self.0.peek(value)
+	}
+
+	fn reset(&mut self, value: Self::Input) -> Result<(), Error> {
+		self.0.reset(value)
+	}
+
+	fn save_undo(&self, stack: &mut Vec<ValueType>) -> bool {
+		self.0.save_undo(stack)
+	}
+
+	fn restore_undo(&mut self, stack: &mut Vec<ValueType>) {
+		self.0.restore_undo(stack);
+	}
 }
 
 #[cfg(test)]
@@ -75,6 +93,7 @@ mod tests {
 	use crate::core::ValueType;
 	use crate::helpers::{assert_eq_float, RandomCandles};
 	use crate::methods::tests::test_const_float;
+	use alloc::vec::Vec;
 
 	use super::WSMA as TestingMethod;
 
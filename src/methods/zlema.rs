@@ -0,0 +1,142 @@
+use crate::core::Method;
+use crate::core::{Error, PeriodType, ValueType, Window};
+use crate::methods::EMA;
+
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
+
+#[cfg(not(feature = "std"))]
+use crate::core::Float as _;
+
+/// [Zero Lag Exponential Moving Average](https://en.wikipedia.org/wiki/Zero_lag_exponential_moving_average) of specified `length` for timeseries of type [`ValueType`]
+///
+/// It is an [`EMA`] over the de-lagged input `2 * value - value[lag]`, where `lag` is (`length` - 1) / 2.
+///
+/// # Parameters
+///
+/// Has a single parameter `length`: [`PeriodType`]
+///
+/// `length` should be > 0
+///
+/// # Input type
+///
+/// Input type is [`ValueType`]
+///
+/// # Output type
+///
+/// Output type is [`ValueType`]
+///
+/// # Examples
+///
+/// ```
+/// use yata::prelude::*;
+/// use yata::methods::ZLEMA;
+///
+/// // ZLEMA of length=3, so lag=1
+/// let mut zlema = ZLEMA::new(3, 1.0).unwrap();
+///
+/// assert_eq!(zlema.next(2.0), 2.0);
+/// assert_eq!(zlema.next(3.0), 3.0);
+/// assert_eq!(zlema.next(3.0), 3.0);
+/// ```
+///
+/// # Performance
+///
+/// O(1)
+///
+/// # See also
+///
+/// [`EMA`], [`DEMA`](crate::methods::DEMA), [`TEMA`](crate::methods::TEMA)
+///
+/// [`ValueType`]: crate::core::ValueType
+/// [`PeriodType`]: crate::core::PeriodType
+#[derive(Debug, Clone)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct ZLEMA {
+	ema: EMA,
+	window: Window<ValueType>,
+}
+
+impl Method for ZLEMA {
+	type Params = PeriodType;
+	type Input = ValueType;
+	type Output = Self::Input;
+
+	fn new(length: Self::Params, value: Self::Input) -> Result<Self, Error> {
+		match length {
+			0 => Err(Error::WrongMethodParameters),
+			length => Ok(Self {
+				ema: EMA::new(length, value)?,
+				window: Window::new((length - 1) / 2 + 1, value),
+			}),
+		}
+	}
+
+	#[inline]
+	fn next(&mut self, value: Self::Input) -> Self::Output {
+		self.window.push(value);
+		let lagged = self.window.oldest();
+
+		self.ema.next(value.mul_add(2., -lagged))
+	}
+
+	fn min_periods(&self) -> PeriodType {
+		self.window.len()
+	}
+
+	fn reset(&mut self, value: Self::Input) -> Result<(), Error> {
+		self.window.fill(value);
+		self.ema.reset(value)
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	use super::{Method, ZLEMA as TestingMethod};
+	use crate::core::ValueType;
+	use crate::helpers::{assert_eq_float, RandomCandles};
+	use crate::methods::tests::test_const_float;
+	use crate::methods::EMA;
+	use alloc::vec::Vec;
+
+	#[test]
+	fn test_zlema_const() {
+		for i in 1..255 {
+			let input = (i as ValueType + 56.0) / 16.3251;
+			let mut method = TestingMethod::new(i, input).unwrap();
+
+			let output = method.next(input);
+			test_const_float(&mut method, input, output);
+		}
+	}
+
+	#[test]
+	fn test_zlema1() {
+		let mut candles = RandomCandles::default();
+
+		let mut ma = TestingMethod::new(1, candles.first().close).unwrap();
+
+		candles.take(100).for_each(|x| {
+			assert_eq_float(x.close, ma.next(x.close));
+		});
+	}
+
+	#[test]
+	fn test_zlema() {
+		let candles = RandomCandles::default();
+
+		let src: Vec<ValueType> = candles.take(300).map(|x| x.close).collect();
+
+		(1..255).for_each(|length| {
+			let mut ma = TestingMethod::new(length, src[0]).unwrap();
+			let mut ema = EMA::new(length, src[0]).unwrap();
+			let lag = ((length - 1) / 2) as usize;
+
+			src.iter().enumerate().for_each(|(i, &x)| {
+				let lagged = if i < lag { src[0] } else { src[i - lag] };
+
+				assert_eq_float(ema.next(2. * x - lagged), ma.next(x));
+			});
+		});
+	}
+}
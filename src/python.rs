@@ -0,0 +1,137 @@
+#![allow(unsafe_code, clippy::needless_pass_by_value)]
+//! [`pyo3`](https://docs.rs/pyo3) bindings with a batch API over [`numpy`](https://numpy.org) arrays
+//!
+//! Enabled by `python` feature. The extension module is linked by `yata-python` crate in `bindings/python` directory of the repository
+//! and built by [`maturin`](https://www.maturin.rs) with `maturin build -m bindings/python/Cargo.toml`.
+//! Every column is a one-dimensional `numpy` array of [`ValueType`], so [`pandas`](https://pandas.pydata.org) columns are passed by `to_numpy()`:
+//!
+//! ```python
+//! import pandas as pd
+//! import yata
+//!
+//! df = pd.read_csv("candles.csv")
+//! df["ema"] = yata.method("ema", 10, df.close.to_numpy())
+//!
+//! macd = yata.Indicator("MACD")
+//! macd.set("period1", "10")
+//! values, signals = macd.over_columns(*(df[c].to_numpy() for c in ["open", "high", "low", "close", "volume"]))
+//! df = df.assign(**values, **signals)
+//! ```
+
+use crate::core::{Error, PeriodType, ValueType};
+use crate::helpers::{method as regular_method, RegularMethods};
+use crate::indicators::{DynIndicator, INDICATORS};
+use alloc::string::ToString;
+use alloc::vec::Vec;
+use numpy::{IntoPyArray, PyArray1, PyReadonlyArray1};
+use pyo3::exceptions::PyValueError;
+use pyo3::prelude::*;
+use pyo3::types::PyDict;
+
+/// Python module `yata`
+#[pymodule]
+fn yata(m: &Bound<'_, PyModule>) -> PyResult<()> {
+	m.add_function(wrap_pyfunction!(indicators, m)?)?;
+	m.add_function(wrap_pyfunction!(method, m)?)?;
+	m.add_class::<Indicator>()?;
+
+	Ok(())
+}
+
+/// Returns names of all the indicators, which may be created by `Indicator` constructor
+#[pyfunction]
+#[must_use]
+pub fn indicators() -> Vec<&'static str> {
+	INDICATORS.to_vec()
+}
+
+/// Evaluates [regular method](RegularMethods) `name` with window `length` over the whole array of `values`
+///
+/// The method is initialized by the first value of the array.
+#[pyfunction]
+pub fn method<'py>(
+	py: Python<'py>,
+	name: &str,
+	length: PeriodType,
+	values: PyReadonlyArray1<'py, ValueType>,
+) -> PyResult<Bound<'py, PyArray1<ValueType>>> {
+	let method_type: RegularMethods = name.parse().map_err(PyValueError::new_err)?;
+	let values = values.as_slice()?;
+
+	let result = match values.first() {
+		Some(&first) => regular_method(method_type, length, first)?.apply_slice(values),
+		None => Vec::new(),
+	};
+
+	Ok(result.into_pyarray(py))
+}
+
+/// Indicator created by its name
+///
+/// All the errors are raised as `ValueError`s with the message of [`Error`].
+#[pyclass(name = "Indicator", unsendable)]
+#[derive(Debug)]
+pub struct Indicator(DynIndicator);
+
+#[pymethods]
+impl Indicator {
+	/// Creates the default **Configuration** of the indicator by its name
+	#[new]
+	pub fn new(name: &str) -> PyResult<Self> {
+		Ok(Self(DynIndicator::new(name)?))
+	}
+
+	/// Returns the name of the indicator
+	#[getter]
+	#[must_use]
+	pub fn name(&self) -> &'static str {
+		self.0.name()
+	}
+
+	/// Sets the **Configuration** parameter `name` by its string `value` and drops current **State**
+	pub fn set(&mut self, name: &str, value: &str) -> PyResult<()> {
+		Ok(self.0.set(name, value)?)
+	}
+
+	/// Drops current **State**, so it is initialized again by the next candle
+	pub fn reset(&mut self) {
+		self.0.reset();
+	}
+
+	/// Evaluates the indicator over the whole history given by the arrays of the candles values
+	///
+	/// Returns a pair of dicts of the named raw values and signals arrays, see [`DynIndicator::over_columns`].
+	pub fn over_columns<'py>(
+		&mut self,
+		py: Python<'py>,
+		open: PyReadonlyArray1<'py, ValueType>,
+		high: PyReadonlyArray1<'py, ValueType>,
+		low: PyReadonlyArray1<'py, ValueType>,
+		close: PyReadonlyArray1<'py, ValueType>,
+		volume: PyReadonlyArray1<'py, ValueType>,
+	) -> PyResult<(Bound<'py, PyDict>, Bound<'py, PyDict>)> {
+		let columns = self.0.over_columns(
+			open.as_slice()?,
+			high.as_slice()?,
+			low.as_slice()?,
+			close.as_slice()?,
+			volume.as_slice()?,
+		)?;
+
+		let (values, signals) = (PyDict::new(py), PyDict::new(py));
+		for (name, column) in columns.values {
+			values.set_item(name, column.into_pyarray(py))?;
+		}
+		for (name, column) in columns.signals {
+			signals.set_item(name, column.into_pyarray(py))?;
+		}
+
+		Ok((values, signals))
+	}
+}
+
+impl From<Error> for PyErr {
+	fn from(error: Error) -> Self {
+		PyValueError::new_err(error.to_string())
+	}
+}
@@ -0,0 +1,370 @@
+use super::distributions::normal_cdf;
+use crate::core::{Error, Method, PeriodType, ValueType, Window};
+use alloc::vec;
+use alloc::vec::Vec;
+use core::convert::TryFrom;
+
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
+
+#[cfg(not(feature = "std"))]
+use crate::core::Float as _;
+
+/// Result of the [Augmented Dickey-Fuller](https://en.wikipedia.org/wiki/Augmented_Dickey%E2%80%93Fuller_test) test
+///
+/// Null hypothesis is that the series has a unit root, so it is not stationary.
+/// The regression includes a constant, but no trend.
+#[derive(Debug, Clone, Copy, PartialEq)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct AdfTest {
+	/// t-statistic of the lagged level coefficient. The more negative it is, the stronger is the rejection
+	pub statistic: ValueType,
+
+	/// Approximate p-value by the `MacKinnon` regression surface
+	pub p_value: ValueType,
+
+	/// Count of lagged differences included in the regression
+	pub lags: usize,
+}
+
+impl AdfTest {
+	/// Asymptotic critical values of the [`statistic`](AdfTest::statistic) at 1%, 5% and 10% significance levels
+	pub const CRITICAL_VALUES: [ValueType; 3] = [-3.43, -2.86, -2.57];
+
+	/// Returns `true` if the unit root hypothesis is rejected at the significance `level` (f.e. `0.05`), so the series is stationary
+	#[must_use]
+	pub fn is_stationary(&self, level: ValueType) -> bool {
+		self.p_value < level
+	}
+}
+
+/// Runs the [Augmented Dickey-Fuller](https://en.wikipedia.org/wiki/Augmented_Dickey%E2%80%93Fuller_test) test over the `values` with `lags` lagged differences
+///
+/// Usually it is applied to the prices (levels), not to the returns.
+///
+/// Returns [`Error::NotReady`] if there are less than `2 * lags + 4` values
+/// and [`Error::InvalidCandles`] if the regression is degenerate (f.e. the `values` are constant).
+///
+/// # Examples
+///
+/// ```
+/// use yata::core::ValueType;
+/// use yata::stats::adf;
+///
+/// // strongly mean-reverting series
+/// let values: Vec<ValueType> = (0..200).map(|i| if i % 2 == 0 { 1.0 } else { -1.0 } + (i % 7) as ValueType * 0.1).collect();
+///
+/// let test = adf(&values, 1).unwrap();
+/// assert!(test.statistic < -3.43);
+/// assert!(test.is_stationary(0.01));
+///
+/// assert!(adf(&values[..5], 1).is_err());
+/// ```
+pub fn adf(values: &[ValueType], lags: usize) -> Result<AdfTest, Error> {
+	let min_length = 2 * lags + 4;
+	if values.len() < min_length {
+		return Err(Error::NotReady(
+			PeriodType::try_from(min_length).unwrap_or(PeriodType::MAX),
+		));
+	}
+
+	let statistic = adf_statistic(values, lags).ok_or(Error::InvalidCandles)?;
+
+	Ok(AdfTest {
+		statistic,
+		p_value: mackinnon_p_value(statistic),
+		lags,
+	})
+}
+
+/// Rolling [Augmented Dickey-Fuller](https://en.wikipedia.org/wiki/Augmented_Dickey%E2%80%93Fuller_test) test statistic over the last `length` values
+///
+/// See [`adf`] for the details.
+///
+/// # Parameters
+///
+/// Has a tuple of 2 parameters (`length`: [`PeriodType`], `lags`: [`PeriodType`])
+///
+/// `length` should be >= `2 * lags + 4`
+///
+/// # Input type
+///
+/// Input type is [`ValueType`]
+///
+/// # Output type
+///
+/// Output type is [`ValueType`]: the test statistic, or `NaN` if the regression over the window is degenerate
+///
+/// # Examples
+///
+/// ```
+/// use yata::prelude::*;
+/// use yata::core::ValueType;
+/// use yata::stats::{adf, RollingADF};
+///
+/// let values: Vec<ValueType> = (0..100).map(|i| ((i * 7) % 11) as ValueType).collect();
+/// let mut rolling = RollingADF::new((50, 1), values[0]).unwrap();
+///
+/// // window is filled with the same value
+/// assert!(rolling.next(values[0]).is_nan());
+///
+/// let last = rolling.over(values[1..].iter().copied()).last().copied().unwrap();
+/// assert!((last - adf(&values[50..], 1).unwrap().statistic).abs() < 1e-6);
+/// ```
+///
+/// # Performance
+///
+/// O(`length` * `lags`^2)
+///
+/// [`ValueType`]: crate::core::ValueType
+/// [`PeriodType`]: crate::core::PeriodType
+#[derive(Debug, Clone)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct RollingADF {
+	lags: usize,
+	window: Window<ValueType>,
+}
+
+impl Method for RollingADF {
+	type Params = (PeriodType, PeriodType);
+	type Input = ValueType;
+	type Output = Self::Input;
+
+	fn new((length, lags): Self::Params, value: Self::Input) -> Result<Self, Error> {
+		let lags = lags as usize;
+
+		if (length as usize) < 2 * lags + 4 {
+			return Err(Error::WrongMethodParameters);
+		}
+
+		Ok(Self {
+			lags,
+			window: Window::new(length, value),
+		})
+	}
+
+	#[inline]
+	fn next(&mut self, value: Self::Input) -> Self::Output {
+		self.window.push(value);
+		let lags = self.lags;
+
+		adf_statistic(self.window.make_contiguous(), lags).unwrap_or(ValueType::NAN)
+	}
+
+	fn min_periods(&self) -> PeriodType {
+		self.window.len()
+	}
+
+	fn reset(&mut self, value: Self::Input) -> Result<(), Error> {
+		self.window.fill(value);
+
+		Ok(())
+	}
+}
+
+/// Evaluates the t-statistic of the lagged level coefficient by the ordinary least squares
+fn adf_statistic(values: &[ValueType], lags: usize) -> Option<ValueType> {
+	// regression: diff[t] = c + b * level[t - 1] + sum(g[i] * diff[t - i])
+	let k = lags + 2;
+	let row = |t: usize| {
+		let mut row = vec![1.0, values[t - 1]];
+		row.extend((1..=lags).map(|i| values[t - i] - values[t - i - 1]));
+		row
+	};
+
+	let mut xtx = vec![0.0; k * k];
+	let mut xty = vec![0.0; k];
+	for t in lags + 1..values.len() {
+		let x = row(t);
+		let y = values[t] - values[t - 1];
+
+		for i in 0..k {
+			xty[i] += x[i] * y;
+			for j in 0..k {
+				xtx[i * k + j] += x[i] * x[j];
+			}
+		}
+	}
+
+	let inverse = invert(xtx, k)?;
+	let beta: Vec<ValueType> = (0..k)
+		.map(|i| (0..k).map(|j| inverse[i * k + j] * xty[j]).sum())
+		.collect();
+
+	let observations = values.len() - lags - 1;
+	let rss: ValueType = (lags + 1..values.len())
+		.map(|t| {
+			let fitted: ValueType = row(t).iter().zip(&beta).map(|(x, b)| x * b).sum();
+			let residual = values[t] - values[t - 1] - fitted;
+			residual * residual
+		})
+		.sum();
+
+	let variance = rss / (observations - k) as ValueType;
+	let std_error = (variance * inverse[k + 1]).sqrt();
+
+	if std_error > 0.0 {
+		Some(beta[1] / std_error)
+	} else {
+		None
+	}
+}
+
+/// Inverts the square `k` x `k` matrix by the Gauss-Jordan elimination
+///
+/// Returns `None` if the matrix is singular.
+fn invert(mut matrix: Vec<ValueType>, k: usize) -> Option<Vec<ValueType>> {
+	let mut inverse = vec![0.0; k * k];
+	for i in 0..k {
+		inverse[i * k + i] = 1.0;
+	}
+
+	let scale = matrix
+		.iter()
+		.fold(0.0, |max: ValueType, v| max.max(v.abs()));
+
+	for col in 0..k {
+		let pivot_row = (col..k).max_by(|&a, &b| {
+			matrix[a * k + col]
+				.abs()
+				.total_cmp(&matrix[b * k + col].abs())
+		})?;
+
+		let pivot = matrix[pivot_row * k + col];
+		if pivot.abs() <= scale * ValueType::EPSILON * k as ValueType {
+			return None;
+		}
+
+		for j in 0..k {
+			matrix.swap(col * k + j, pivot_row * k + j);
+			inverse.swap(col * k + j, pivot_row * k + j);
+		}
+
+		for j in 0..k {
+			matrix[col * k + j] /= pivot;
+			inverse[col * k + j] /= pivot;
+		}
+
+		for i in (0..k).filter(|&i| i != col) {
+			let factor = matrix[i * k + col];
+			for j in 0..k {
+				matrix[i * k + j] -= factor * matrix[col * k + j];
+				inverse[i * k + j] -= factor * inverse[col * k + j];
+			}
+		}
+	}
+
+	Some(inverse)
+}
+
+/// Approximate p-value of the ADF statistic for the regression with a constant
+///
+/// Uses coefficients of the `MacKinnon` (1994) regression surface.
+fn mackinnon_p_value(statistic: ValueType) -> ValueType {
+	const MAX_STATISTIC: ValueType = 2.74;
+	const MIN_STATISTIC: ValueType = -18.83;
+	const STAR_STATISTIC: ValueType = -1.61;
+	const SMALL_P: [ValueType; 3] = [2.1659, 1.4412, 0.038_269];
+	const LARGE_P: [ValueType; 4] = [1.7339, 0.932_02, -0.127_45, -0.010_368];
+
+	if statistic > MAX_STATISTIC {
+		return 1.0;
+	}
+
+	if statistic < MIN_STATISTIC {
+		return 0.0;
+	}
+
+	let coefficients: &[ValueType] = if statistic <= STAR_STATISTIC {
+		&SMALL_P
+	} else {
+		&LARGE_P
+	};
+
+	let z = coefficients
+		.iter()
+		.rev()
+		.fold(0.0, |sum: ValueType, &c| sum.mul_add(statistic, c));
+
+	normal_cdf(z)
+}
+
+#[cfg(test)]
+mod tests {
+	use super::{adf, mackinnon_p_value, AdfTest, RollingADF};
+	use crate::core::{Method, ValueType};
+	use crate::helpers::Rng;
+	use alloc::vec::Vec;
+
+	#[test]
+	fn test_adf() {
+		let mut rng = Rng::default();
+		let noise: Vec<ValueType> = (0..500).map(|_| rng.normal()).collect();
+
+		// AR(1) with coefficient 0.5 is stationary
+		let ar: Vec<ValueType> = noise
+			.iter()
+			.scan(0.0, |x, &e| {
+				*x = 0.5 * *x + e;
+				Some(*x)
+			})
+			.collect();
+
+		// random walk has a unit root
+		let walk: Vec<ValueType> = noise
+			.iter()
+			.scan(0.0, |x, &e| {
+				*x += e;
+				Some(*x)
+			})
+			.collect();
+
+		for lags in 0..4 {
+			let test = adf(&ar, lags).unwrap();
+			assert_eq!(test.lags, lags);
+			assert!(test.statistic < AdfTest::CRITICAL_VALUES[0]);
+			assert!(test.is_stationary(0.01));
+
+			let test = adf(&walk, lags).unwrap();
+			assert!(test.statistic > AdfTest::CRITICAL_VALUES[2]);
+			assert!(!test.is_stationary(0.1));
+		}
+
+		assert!(adf(&[1.0; 100], 1).is_err());
+		assert!(adf(&ar[..5], 1).is_err());
+		assert!(adf(&ar[..6], 1).is_ok());
+	}
+
+	#[test]
+	fn test_mackinnon_p_value() {
+		assert!((mackinnon_p_value(-3.43) - 0.01).abs() < 0.002);
+		assert!((mackinnon_p_value(-2.86) - 0.05).abs() < 0.002);
+		assert!((mackinnon_p_value(-2.57) - 0.1).abs() < 0.002);
+		assert!((mackinnon_p_value(-1.61) - mackinnon_p_value(-1.609)).abs() < 0.01);
+		assert_eq!(mackinnon_p_value(3.0), 1.0);
+		assert_eq!(mackinnon_p_value(-20.0), 0.0);
+	}
+
+	#[test]
+	fn test_rolling_adf() {
+		let mut rng = Rng::default();
+		let values: Vec<ValueType> = (0..200).map(|_| rng.normal()).collect();
+
+		for (length, lags) in [(4, 0), (10, 1), (50, 3)] {
+			let mut rolling = RollingADF::new((length, lags), values[0]).unwrap();
+
+			for (i, &value) in values.iter().enumerate().skip(1) {
+				let statistic = rolling.next(value);
+
+				if i >= length as usize {
+					let window = &values[i + 1 - length as usize..=i];
+					let expected = adf(window, lags as usize).unwrap().statistic;
+					assert!((statistic - expected).abs() < 1e-3 * expected.abs().max(1.0));
+				}
+			}
+		}
+
+		assert!(RollingADF::new((9, 3), 1.0).is_err());
+		assert!(RollingADF::new((10, 3), 1.0).is_ok());
+	}
+}
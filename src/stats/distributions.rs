@@ -0,0 +1,135 @@
+use crate::core::ValueType;
+
+#[cfg(not(feature = "std"))]
+use crate::core::Float as _;
+
+const MAX_ITERATIONS: usize = 300;
+
+/// Natural logarithm of the gamma function for `x` > 0 (Lanczos approximation)
+fn ln_gamma(x: ValueType) -> ValueType {
+	const COEFFICIENTS: [ValueType; 9] = [
+		0.999_999_999_999_809_9,
+		676.520_368_121_885_1,
+		-1_259.139_216_722_402_8,
+		771.323_428_777_653_1,
+		-176.615_029_162_140_6,
+		12.507_343_278_686_905,
+		-0.138_571_095_265_720_12,
+		9.984_369_578_019_572e-6,
+		1.505_632_735_149_311_6e-7,
+	];
+
+	let x = x - 1.0;
+	let t = x + 7.5;
+	let sum = COEFFICIENTS[1..]
+		.iter()
+		.zip(1_u8..)
+		.fold(COEFFICIENTS[0], |sum, (&c, i)| {
+			sum + c / (x + ValueType::from(i))
+		});
+
+	// ln(sqrt(2 * PI))
+	(x + 0.5).mul_add(t.ln(), 0.918_938_533_204_672_7) - t + sum.ln()
+}
+
+/// Regularized upper incomplete gamma function `Q(a, x)`
+#[allow(clippy::many_single_char_names)]
+pub fn gamma_q(a: ValueType, x: ValueType) -> ValueType {
+	if x <= 0.0 {
+		return 1.0;
+	}
+
+	let ln_prefix = a.mul_add(x.ln(), -x) - ln_gamma(a);
+
+	if x < a + 1.0 {
+		// series for P(a, x)
+		let mut term = 1.0 / a;
+		let mut sum = term;
+		for n in 1..MAX_ITERATIONS {
+			term *= x / (a + n as ValueType);
+			sum += term;
+
+			if term.abs() < sum.abs() * ValueType::EPSILON {
+				break;
+			}
+		}
+
+		1.0 - sum * ln_prefix.exp()
+	} else {
+		// continued fraction for Q(a, x) by the modified Lentz's method
+		let tiny = ValueType::MIN_POSITIVE / ValueType::EPSILON;
+		let mut b = x + 1.0 - a;
+		let mut c = 1.0 / tiny;
+		let mut d = 1.0 / b;
+		let mut h = d;
+
+		for n in 1..MAX_ITERATIONS {
+			let n = n as ValueType;
+			let an = -n * (n - a);
+			b += 2.0;
+
+			d = an.mul_add(d, b);
+			if d.abs() < tiny {
+				d = tiny;
+			}
+			c = b + an / c;
+			if c.abs() < tiny {
+				c = tiny;
+			}
+
+			d = 1.0 / d;
+			let delta = d * c;
+			h *= delta;
+
+			if (delta - 1.0).abs() < ValueType::EPSILON {
+				break;
+			}
+		}
+
+		ln_prefix.exp() * h
+	}
+}
+
+/// Survival function (`1 - CDF`) of the chi-squared distribution with `k` degrees of freedom
+pub fn chi_squared_sf(x: ValueType, k: usize) -> ValueType {
+	gamma_q(k as ValueType / 2.0, x / 2.0)
+}
+
+/// Cumulative distribution function of the standard normal distribution
+pub fn normal_cdf(x: ValueType) -> ValueType {
+	// erfc(z) = Q(0.5, z^2) for z >= 0
+	let tail = 0.5 * gamma_q(0.5, x * x / 2.0);
+
+	if x < 0.0 {
+		tail
+	} else {
+		1.0 - tail
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	use super::{chi_squared_sf, ln_gamma, normal_cdf};
+	use crate::core::ValueType;
+
+	fn assert_near(expected: ValueType, value: ValueType) {
+		assert!((expected - value).abs() < 1e-4, "{} != {}", expected, value);
+	}
+
+	#[test]
+	fn test_distributions() {
+		assert_near(0.0, ln_gamma(1.0));
+		assert_near(0.0, ln_gamma(2.0));
+		assert_near(24.0_f32.ln().into(), ln_gamma(5.0));
+
+		assert_near(0.5, normal_cdf(0.0));
+		assert_near(0.975, normal_cdf(1.959_964));
+		assert_near(0.025, normal_cdf(-1.959_964));
+		assert_near(0.841_345, normal_cdf(1.0));
+
+		assert_near(0.05, chi_squared_sf(3.841_459, 1));
+		assert_near(0.05, chi_squared_sf(18.307_04, 10));
+		assert_near(0.5, chi_squared_sf(9.341_818, 10));
+		assert_near(1.0, chi_squared_sf(0.0, 3));
+	}
+}
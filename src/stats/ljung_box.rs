@@ -0,0 +1,130 @@
+use super::distributions::chi_squared_sf;
+use crate::core::{Error, PeriodType, ValueType};
+use alloc::vec::Vec;
+use core::convert::TryFrom;
+
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
+
+/// Result of the [Ljung-Box](https://en.wikipedia.org/wiki/Ljung%E2%80%93Box_test) test
+///
+/// Null hypothesis is that the series has no autocorrelation up to the tested lag.
+#[derive(Debug, Clone, Copy, PartialEq)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct LjungBoxTest {
+	/// Q-statistic
+	pub statistic: ValueType,
+
+	/// p-value by the chi-squared distribution with `lags` degrees of freedom
+	pub p_value: ValueType,
+
+	/// Count of tested autocorrelation lags
+	pub lags: usize,
+}
+
+impl LjungBoxTest {
+	/// Returns `true` if the hypothesis of no autocorrelation is rejected at the significance `level` (f.e. `0.05`)
+	#[must_use]
+	pub fn is_autocorrelated(&self, level: ValueType) -> bool {
+		self.p_value < level
+	}
+}
+
+/// Runs the [Ljung-Box](https://en.wikipedia.org/wiki/Ljung%E2%80%93Box_test) test for autocorrelation of the `returns` up to `lags` lag
+///
+/// Returns [`Error::WrongMethodParameters`] if `lags` is zero, [`Error::NotReady`] if there are not more than `lags` returns
+/// and [`Error::InvalidCandles`] if the `returns` are constant.
+///
+/// # Examples
+///
+/// ```
+/// use yata::core::ValueType;
+/// use yata::stats::ljung_box;
+///
+/// // returns which flip their sign every time
+/// let returns: Vec<ValueType> = (0..100).map(|i| if i % 2 == 0 { 0.01 } else { -0.01 }).collect();
+///
+/// let test = ljung_box(&returns, 5).unwrap();
+/// assert!(test.is_autocorrelated(0.01));
+/// ```
+pub fn ljung_box(returns: &[ValueType], lags: usize) -> Result<LjungBoxTest, Error> {
+	if lags == 0 {
+		return Err(Error::WrongMethodParameters);
+	}
+
+	let n = returns.len();
+	if n <= lags {
+		return Err(Error::NotReady(
+			PeriodType::try_from(lags + 1).unwrap_or(PeriodType::MAX),
+		));
+	}
+
+	let mean = returns.iter().sum::<ValueType>() / n as ValueType;
+	let deviations: Vec<ValueType> = returns.iter().map(|r| r - mean).collect();
+	let variance: ValueType = deviations.iter().map(|d| d * d).sum();
+
+	if variance <= 0.0 {
+		return Err(Error::InvalidCandles);
+	}
+
+	let sum: ValueType = (1..=lags)
+		.map(|k| {
+			let autocorrelation = deviations[k..]
+				.iter()
+				.zip(&deviations)
+				.map(|(a, b)| a * b)
+				.sum::<ValueType>()
+				/ variance;
+
+			autocorrelation * autocorrelation / (n - k) as ValueType
+		})
+		.sum();
+
+	let statistic = (n * (n + 2)) as ValueType * sum;
+
+	Ok(LjungBoxTest {
+		statistic,
+		p_value: chi_squared_sf(statistic, lags),
+		lags,
+	})
+}
+
+#[cfg(test)]
+mod tests {
+	use super::ljung_box;
+	use crate::core::ValueType;
+	use crate::helpers::Rng;
+	use alloc::vec::Vec;
+
+	#[test]
+	fn test_ljung_box() {
+		let mut rng = Rng::default();
+		let noise: Vec<ValueType> = (0..500).map(|_| rng.normal()).collect();
+
+		let ar: Vec<ValueType> = noise
+			.iter()
+			.scan(0.0, |x, &e| {
+				*x = 0.8 * *x + e;
+				Some(*x)
+			})
+			.collect();
+
+		for lags in [1, 5, 10] {
+			let test = ljung_box(&noise, lags).unwrap();
+			assert_eq!(test.lags, lags);
+			assert!(test.statistic >= 0.0);
+			assert!(!test.is_autocorrelated(0.05));
+
+			let test = ljung_box(&ar, lags).unwrap();
+			assert!(test.is_autocorrelated(0.001));
+		}
+
+		// single lag of 3 values: autocorrelation is -0.5, so Q = 3 * 5 * 0.25 / 2
+		let test = ljung_box(&[1.0, 3.0, 2.0], 1).unwrap();
+		assert!((test.statistic - 1.875).abs() < 1e-6);
+
+		assert!(ljung_box(&noise, 0).is_err());
+		assert!(ljung_box(&noise[..5], 5).is_err());
+		assert!(ljung_box(&[1.0; 10], 2).is_err());
+	}
+}
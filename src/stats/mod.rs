@@ -0,0 +1,36 @@
+#![warn(missing_docs, missing_debug_implementations)]
+//! Statistical tests over timeseries
+//!
+//! Oscillators and mean-reversion strategies assume the series returns back to its mean,
+//! while trend-following ones assume the opposite. The tests allow to validate these assumptions before trusting the signals:
+//!
+//! * [`adf`] (and its rolling version [`RollingADF`]) tests the series for stationarity;
+//! * [`ljung_box`] tests the returns for autocorrelation;
+//! * [`variance_ratio`] tests the returns for the random walk against mean-reversion or trending.
+//!
+//! # Examples
+//!
+//! ```
+//! use yata::core::ValueType;
+//! use yata::helpers::RandomCandles;
+//! use yata::stats::{adf, ljung_box, variance_ratio};
+//!
+//! let closes: Vec<ValueType> = RandomCandles::new().take(300).map(|candle| candle.close).collect();
+//! let returns: Vec<ValueType> = closes.windows(2).map(|w| (w[1] / w[0]).ln()).collect();
+//!
+//! let stationarity = adf(&closes, 2).unwrap();
+//! let autocorrelation = ljung_box(&returns, 10).unwrap();
+//! let random_walk = variance_ratio(&returns, 5).unwrap();
+//!
+//! println!("ADF p-value: {}", stationarity.p_value);
+//! println!("Ljung-Box p-value: {}", autocorrelation.p_value);
+//! println!("Variance ratio: {}", random_walk.ratio);
+//! ```
+
+mod adf;
+pub use adf::{adf, AdfTest, RollingADF};
+mod distributions;
+mod ljung_box;
+pub use ljung_box::{ljung_box, LjungBoxTest};
+mod variance_ratio;
+pub use variance_ratio::{variance_ratio, VarianceRatioTest};
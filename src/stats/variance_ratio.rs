@@ -0,0 +1,152 @@
+use super::distributions::normal_cdf;
+use crate::core::{Error, PeriodType, ValueType};
+use core::convert::TryFrom;
+
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
+
+#[cfg(not(feature = "std"))]
+use crate::core::Float as _;
+
+/// Result of the Lo-MacKinlay variance ratio test
+///
+/// Null hypothesis is that the prices follow a random walk, so the variance of `period`-bar returns
+/// is `period` times the variance of single-bar returns.
+#[derive(Debug, Clone, Copy, PartialEq)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct VarianceRatioTest {
+	/// Variance ratio. Values below `1.0` mean mean-reversion, values above `1.0` mean trending (momentum)
+	pub ratio: ValueType,
+
+	/// z-statistic of the ratio under the homoscedastic random walk hypothesis
+	pub statistic: ValueType,
+
+	/// Two-sided p-value of the z-statistic
+	pub p_value: ValueType,
+
+	/// Count of bars in the aggregated returns
+	pub period: usize,
+}
+
+impl VarianceRatioTest {
+	/// Returns `true` if the random walk hypothesis is rejected at the significance `level` (f.e. `0.05`)
+	#[must_use]
+	pub fn is_random_walk_rejected(&self, level: ValueType) -> bool {
+		self.p_value < level
+	}
+
+	/// Returns `true` if the random walk hypothesis is rejected at the significance `level` in favour of the mean-reversion
+	#[must_use]
+	pub fn is_mean_reverting(&self, level: ValueType) -> bool {
+		self.ratio < 1.0 && self.is_random_walk_rejected(level)
+	}
+}
+
+/// Runs the Lo-MacKinlay variance ratio test over single-bar `returns` aggregated by `period` bars
+///
+/// The `returns` are expected to be logarithmic, but simple returns also work for small values.
+/// Overlapping `period`-bar returns with the bias correction are used.
+///
+/// Returns [`Error::WrongMethodParameters`] if `period` < 2, [`Error::NotReady`] if there are not more than `period` returns
+/// and [`Error::InvalidCandles`] if the `returns` are constant.
+///
+/// # Examples
+///
+/// ```
+/// use yata::core::ValueType;
+/// use yata::stats::variance_ratio;
+///
+/// // returns which flip their sign every time, so prices revert to the mean
+/// let returns: Vec<ValueType> = (0..200).map(|i| if i % 2 == 0 { 0.01 } else { -0.01 }).collect();
+///
+/// let test = variance_ratio(&returns, 2).unwrap();
+/// assert!(test.ratio < 0.1);
+/// assert!(test.is_mean_reverting(0.01));
+/// ```
+pub fn variance_ratio(returns: &[ValueType], period: usize) -> Result<VarianceRatioTest, Error> {
+	if period < 2 {
+		return Err(Error::WrongMethodParameters);
+	}
+
+	let n = returns.len();
+	if n <= period {
+		return Err(Error::NotReady(
+			PeriodType::try_from(period + 1).unwrap_or(PeriodType::MAX),
+		));
+	}
+
+	let (q, count) = (period as ValueType, n as ValueType);
+	let mean = returns.iter().sum::<ValueType>() / count;
+
+	let variance = returns
+		.iter()
+		.map(|r| (r - mean).powi(2))
+		.sum::<ValueType>()
+		/ (count - 1.0);
+	if variance <= 0.0 {
+		return Err(Error::InvalidCandles);
+	}
+
+	let divider = q * (count - q + 1.0) * (1.0 - q / count);
+	let aggregated_variance = returns
+		.windows(period)
+		.map(|window| q.mul_add(-mean, window.iter().sum::<ValueType>()).powi(2))
+		.sum::<ValueType>()
+		/ divider;
+
+	let ratio = aggregated_variance / variance;
+	let deviation = (2.0 * q.mul_add(2.0, -1.0) * (q - 1.0) / (3.0 * q * count)).sqrt();
+	let statistic = (ratio - 1.0) / deviation;
+
+	Ok(VarianceRatioTest {
+		ratio,
+		statistic,
+		p_value: 2.0 * (1.0 - normal_cdf(statistic.abs())),
+		period,
+	})
+}
+
+#[cfg(test)]
+mod tests {
+	use super::variance_ratio;
+	use crate::core::ValueType;
+	use crate::helpers::Rng;
+	use alloc::vec::Vec;
+
+	#[test]
+	fn test_variance_ratio() {
+		let mut rng = Rng::default();
+		let noise: Vec<ValueType> = (0..1000).map(|_| rng.normal() * 0.01).collect();
+
+		// positively autocorrelated returns
+		let momentum: Vec<ValueType> = noise
+			.iter()
+			.scan(0.0, |x, &e| {
+				*x = 0.3 * *x + e;
+				Some(*x)
+			})
+			.collect();
+
+		// negatively autocorrelated returns
+		let reverting: Vec<ValueType> = noise.windows(2).map(|w| w[1] - 0.5 * w[0]).collect();
+
+		for period in [2, 5, 10] {
+			let test = variance_ratio(&noise, period).unwrap();
+			assert_eq!(test.period, period);
+			assert!((test.ratio - 1.0).abs() < 0.2);
+			assert!(!test.is_random_walk_rejected(0.01));
+
+			let test = variance_ratio(&momentum, period).unwrap();
+			assert!(test.ratio > 1.0);
+			assert!(test.is_random_walk_rejected(0.01));
+			assert!(!test.is_mean_reverting(0.01));
+
+			let test = variance_ratio(&reverting, period).unwrap();
+			assert!(test.is_mean_reverting(0.01));
+		}
+
+		assert!(variance_ratio(&noise, 1).is_err());
+		assert!(variance_ratio(&noise[..5], 5).is_err());
+		assert!(variance_ratio(&[0.5; 10], 2).is_err());
+	}
+}
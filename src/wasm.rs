@@ -0,0 +1,99 @@
+#![allow(unsafe_code)]
+//! [`wasm-bindgen`](https://docs.rs/wasm-bindgen) bindings of the [indicators registry](crate::indicators::create)
+//!
+//! Enabled by `wasm` feature. It is a thin layer over [`DynIndicator`] for browser-based charting tools.
+//! The package is linked by `yata-wasm` crate in `bindings/wasm` directory of the repository
+//! and built by [`wasm-pack`](https://rustwasm.github.io/wasm-pack) with `wasm-pack build bindings/wasm --out-name yata`:
+//!
+//! ```js
+//! import { Indicator, indicators } from "yata";
+//!
+//! console.log(indicators()); // ["AccumulationDistribution", "Alligator", ...]
+//!
+//! const macd = new Indicator("MACD");
+//! macd.set("period1", "10");
+//!
+//! const result = macd.next({ open: 1.0, high: 2.0, low: 0.5, close: 1.5, volume: 100.0 });
+//! console.log(result.values.macd, result.signals["macd/sigline cross"]);
+//! ```
+
+use crate::core::{Candle, Error, ValueType};
+use crate::indicators::{DynIndicator, INDICATORS};
+use alloc::string::{String, ToString};
+use js_sys::{Array, Reflect, JSON};
+use wasm_bindgen::prelude::*;
+
+/// Returns names of all the indicators, which may be created by [`Indicator`] constructor
+#[wasm_bindgen]
+#[must_use]
+pub fn indicators() -> Array {
+	INDICATORS
+		.iter()
+		.map(|&name| JsValue::from_str(name))
+		.collect()
+}
+
+/// Indicator created by its name
+///
+/// All the errors are thrown as JavaScript `Error`s with the message of [`Error`].
+#[wasm_bindgen]
+#[derive(Debug)]
+pub struct Indicator(DynIndicator);
+
+#[wasm_bindgen]
+impl Indicator {
+	/// Creates the default **Configuration** of the indicator by its name
+	#[wasm_bindgen(constructor)]
+	pub fn new(name: &str) -> Result<Self, JsValue> {
+		Ok(Self(DynIndicator::new(name)?))
+	}
+
+	/// Returns the name of the indicator
+	#[wasm_bindgen(getter)]
+	#[must_use]
+	pub fn name(&self) -> String {
+		self.0.name().to_string()
+	}
+
+	/// Sets the **Configuration** parameter `name` by its string `value` and drops current **State**
+	pub fn set(&mut self, name: &str, value: &str) -> Result<(), JsValue> {
+		Ok(self.0.set(name, value)?)
+	}
+
+	/// Drops current **State**, so it is initialized again by the next candle
+	pub fn reset(&mut self) {
+		self.0.reset();
+	}
+
+	/// Evaluates the indicator over the `candle` object with numeric `open`, `high`, `low`, `close` and `volume` fields
+	///
+	/// Returns an object of the named raw values and signals ratios,
+	/// f.e. `{values: {macd: 0.1, sigline: 0.05}, signals: {"macd/sigline cross": null}}`.
+	pub fn next(&mut self, candle: &JsValue) -> Result<JsValue, JsValue> {
+		let candle = Candle {
+			open: field(candle, "open")?,
+			high: field(candle, "high")?,
+			low: field(candle, "low")?,
+			close: field(candle, "close")?,
+			volume: field(candle, "volume")?,
+		};
+
+		let json = self.0.next_json(candle)?;
+		JSON::parse(&json)
+	}
+}
+
+#[allow(trivial_numeric_casts, clippy::cast_possible_truncation)]
+fn field(candle: &JsValue, name: &str) -> Result<ValueType, JsValue> {
+	Reflect::get(candle, &JsValue::from_str(name))
+		.ok()
+		.and_then(|value| value.as_f64())
+		.map(|value| value as ValueType)
+		.ok_or_else(|| js_sys::Error::new(&format!("candle has no numeric `{name}` field")).into())
+}
+
+impl From<Error> for JsValue {
+	fn from(error: Error) -> Self {
+		js_sys::Error::new(&error.to_string()).into()
+	}
+}